@@ -1,22 +1,28 @@
-#[cfg(feature = "ai")]
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::process;
 
-use clap::{Parser, Subcommand};
+use anstream::{AutoStream, ColorChoice};
+use anstyle::AnsiColor;
+use clap::{Args, Parser, Subcommand};
 
-use doc_engine_scan::{scan_with_config, ScanConfig, ProjectScope, ProjectType, StdoutSink, FileSink, ReportFormat};
+use doc_engine_scan::{diff_reports, format_timings, load_rules, report_schema, scan_with_config, CheckId, ConfigFile, CONFIG_FILE_NAME, DiffStatus, ReportDiff, RuleDef, RuleSet, ScanConfig, ScanReport, Severity, ProjectScope, ProjectType, StdoutSink, FileSink, ReportFormat};
 use doc_engine_scan::api::traits::ReportSink;
 #[cfg(feature = "kafka")]
 use doc_engine_scan::{KafkaConfig, KafkaSink};
-use doc_engine_scaffold::{scaffold_from_srs, ScaffoldConfig};
+#[cfg(feature = "sqlite")]
+use doc_engine_scan::SqliteSink;
+use doc_engine_scaffold::{scaffold_from_srs, scaffold_from_srs_content, ScaffoldConfig};
 
 #[cfg(feature = "ai")]
 use doc_engine_compliance_chat::{ComplianceChat, ComplianceChatConfig};
 #[cfg(feature = "ai")]
 use doc_engine_compliance_audit::{ComplianceAuditor, AuditConfig};
 #[cfg(feature = "ai")]
-use doc_engine_command_generator::{CommandGenerator, CommandGeneratorConfig, GenerateCommandsRequest, RequirementContext};
+use doc_engine_command_generator::{CommandCache, CommandGenerator, CommandGeneratorConfig, GenerateCommandsRequest, RequirementContext};
+#[cfg(feature = "ai")]
+use doc_engine_command_generator::api::cache::CACHED_REASON;
 
 #[derive(Parser)]
 #[command(name = "doc-engine", version, about = "Documentation compliance engine")]
@@ -28,71 +34,25 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Scan a project for documentation compliance
-    Scan {
-        /// Path to the project root
-        path: PathBuf,
-
-        /// Output as JSON
-        #[arg(long)]
-        json: bool,
-
-        /// Run only specific checks (e.g., "1-13" or "1,5,10")
-        #[arg(long)]
-        checks: Option<String>,
-
-        /// Project type: open-source or internal
-        #[arg(long = "type", value_name = "TYPE")]
-        project_type: Option<String>,
-
-        /// Project scope: small, medium, or large
-        #[arg(long)]
-        scope: String,
+    Scan(Box<ScanArgs>),
+    /// Compare two saved scan reports and list regressed and fixed checks
+    Diff {
+        /// Path to the older JSON report
+        old: PathBuf,
 
-        /// Path to custom rules file
-        #[arg(long)]
-        rules: Option<PathBuf>,
-
-        /// Filter checks by SDLC phase/category (comma-separated, e.g. "testing,module")
-        #[arg(long)]
-        phase: Option<String>,
+        /// Path to the newer JSON report
+        new: PathBuf,
 
-        /// Filter module checks to specific modules (comma-separated, e.g. "scan,cli")
+        /// Output the diff as JSON
         #[arg(long)]
-        module: Option<String>,
-
-        /// Save report to file (default: docs/7-operations/compliance/documentation_audit_report_v{version}.json)
-        #[arg(long, short)]
-        output: Option<PathBuf>,
-
-        /// Path to kafka.toml config file
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-config", value_name = "PATH")]
-        kafka_config: Option<PathBuf>,
-
-        /// Kafka broker address (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-broker", value_name = "ADDR")]
-        kafka_broker: Option<String>,
-
-        /// Kafka topic name (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-topic", value_name = "TOPIC")]
-        kafka_topic: Option<String>,
-
-        /// Kafka client ID (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-client-id", value_name = "ID")]
-        kafka_client_id: Option<String>,
-
-        /// Kafka partition (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-partition", value_name = "N")]
-        kafka_partition: Option<i32>,
-
-        /// Kafka produce timeout in ms (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-timeout", value_name = "MS")]
-        kafka_timeout: Option<i32>,
+        json: bool,
+    },
+    /// Print the JSON Schema of the `--json` scan report
+    Schema,
+    /// List the rules in the active set or explain one of them
+    Rules {
+        #[command(subcommand)]
+        action: RulesAction,
     },
     /// AI-powered compliance analysis (requires --features ai)
     #[cfg(feature = "ai")]
@@ -102,7 +62,7 @@ enum Commands {
     },
     /// Generate SDLC spec file scaffold from an SRS document
     Scaffold {
-        /// Path to the SRS markdown file
+        /// Path to the SRS markdown file (`-` reads from stdin)
         srs_path: PathBuf,
 
         /// Output directory (defaults to current directory)
@@ -110,14 +70,18 @@ enum Commands {
         output: Option<PathBuf>,
 
         /// Overwrite existing files
-        #[arg(long)]
+        #[arg(long, conflicts_with = "merge")]
         force: bool,
 
+        /// Regenerate only the <!-- BEGIN GENERATED --> region of existing files, keeping user edits
+        #[arg(long)]
+        merge: bool,
+
         /// Generate only specific SDLC phases (comma-separated: requirements,design,testing,deployment)
         #[arg(long)]
         phase: Option<String>,
 
-        /// Generate only specific file types (comma-separated: yaml,spec,arch,test,exec,deploy,plan)
+        /// Generate only specific file types (comma-separated: yaml,spec,arch,test,exec,deploy,plan,matrix)
         #[arg(long = "type", value_name = "TYPE")]
         file_type: Option<String>,
 
@@ -133,12 +97,188 @@ enum Commands {
         #[arg(long = "command-map", value_name = "PATH")]
         command_map: Option<PathBuf>,
 
+        /// Directory of <type>.tera templates (spec.tera, arch.tera, ...) overriding the builtin markdown
+        #[arg(long = "template-dir", value_name = "DIR")]
+        template_dir: Option<PathBuf>,
+
+        /// Print the files that would be created or skipped without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
         /// Save scaffold report as JSON
         #[arg(long)]
         report: Option<PathBuf>,
     },
 }
 
+#[derive(Args)]
+struct ScanArgs {
+    /// Path to the project root
+    path: PathBuf,
+
+    /// Output as JSON
+    #[arg(long, conflicts_with_all = ["html", "markdown", "github", "csv", "junit"])]
+    json: bool,
+
+    /// Output as a self-contained HTML page (redirect to a file to share it)
+    #[arg(long, conflicts_with_all = ["markdown", "github", "csv", "junit"])]
+    html: bool,
+
+    /// Output as GitHub-flavored Markdown, sized for a PR comment
+    #[arg(long, conflicts_with_all = ["github", "csv", "junit"])]
+    markdown: bool,
+
+    /// Emit GitHub Actions workflow commands (inline annotations in CI)
+    #[arg(long, conflicts_with_all = ["csv", "junit"])]
+    github: bool,
+
+    /// Output as CSV, one row per violation
+    #[arg(long, conflicts_with = "junit")]
+    csv: bool,
+
+    /// Add a `skipped` row per skipped check to CSV output (--csv or --emit csv:DEST)
+    #[arg(long = "csv-skipped")]
+    csv_skipped: bool,
+
+    /// Output as JUnit XML, one test case per check (for CI test reports)
+    #[arg(long)]
+    junit: bool,
+
+    /// Run only specific checks (e.g., "1-13" or "1,5,10")
+    #[arg(long)]
+    checks: Option<String>,
+
+    /// Project type: open-source or internal
+    #[arg(long = "type", value_name = "TYPE")]
+    project_type: Option<String>,
+
+    /// Project scope: small, medium, or large (required here or in doc-engine.toml)
+    #[arg(long)]
+    scope: Option<String>,
+
+    /// Config file to read instead of the nearest doc-engine.toml above the project path
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Path to custom rules file
+    #[arg(long)]
+    rules: Option<PathBuf>,
+
+    /// Filter checks by SDLC phase/category (comma-separated, e.g. "testing,module")
+    #[arg(long)]
+    phase: Option<String>,
+
+    /// Filter module checks to specific modules (comma-separated, e.g. "scan,cli")
+    #[arg(long)]
+    module: Option<String>,
+
+    /// Save report to file (default: docs/7-operations/compliance/documentation_audit_report_v{version}.json)
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+
+    /// Also write the report as FORMAT to DEST, `-` for stdout (repeatable, e.g. "text:report.txt")
+    #[arg(long, value_name = "FORMAT:DEST")]
+    emit: Vec<String>,
+
+    /// Disable the result cache and evaluate every check
+    #[arg(long = "no-cache")]
+    no_cache: bool,
+
+    /// Directory for the result cache of unchanged checks (default: <path>/target/doc-engine-cache)
+    #[arg(long = "cache-dir", visible_alias = "cache", value_name = "DIR")]
+    cache_dir: Option<PathBuf>,
+
+    /// Number of worker threads for running checks (default: number of CPU cores)
+    #[arg(long, value_name = "N")]
+    jobs: Option<usize>,
+
+    /// Fail any single check still running after this many milliseconds (default: no limit)
+    #[arg(long = "check-timeout-ms", value_name = "MS")]
+    check_timeout_ms: Option<u64>,
+
+    /// Leave out paths matching this glob (repeatable, e.g. "docs/third_party/**")
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Exit 1 only if a violation at or above this severity exists: info, warning, or error
+    #[arg(long = "fail-on", value_name = "SEVERITY")]
+    fail_on: Option<String>,
+
+    /// Print the slowest checks after the text report
+    #[arg(long)]
+    timings: bool,
+
+    /// Show only failing checks and the summary line in the text report
+    #[arg(long, short)]
+    quiet: bool,
+
+    /// Color the text report: auto (terminal without NO_COLOR), always, or never
+    #[arg(long, value_name = "WHEN", default_value = "auto")]
+    color: String,
+
+    /// Record a check's violations at this severity (repeatable, e.g. "57=warning")
+    #[arg(long = "severity", value_name = "CHECK=LEVEL")]
+    severity: Vec<String>,
+
+    /// Append the scan to a SQLite history file, creating it on first use
+    #[cfg(feature = "sqlite")]
+    #[arg(long, value_name = "PATH")]
+    db: Option<PathBuf>,
+
+    /// Path to kafka.toml config file
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-config", value_name = "PATH")]
+    kafka_config: Option<PathBuf>,
+
+    /// Kafka broker address (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-broker", value_name = "ADDR")]
+    kafka_broker: Option<String>,
+
+    /// Kafka topic name (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-topic", value_name = "TOPIC")]
+    kafka_topic: Option<String>,
+
+    /// Kafka client ID (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-client-id", value_name = "ID")]
+    kafka_client_id: Option<String>,
+
+    /// Kafka partition (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-partition", value_name = "N")]
+    kafka_partition: Option<i32>,
+
+    /// Kafka produce timeout in ms (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-timeout", value_name = "MS")]
+    kafka_timeout: Option<i32>,
+}
+
+#[derive(Subcommand)]
+enum RulesAction {
+    /// Print id, handler, category, severity and description of every rule
+    List {
+        /// Path to custom rules file (default: the embedded rules)
+        #[arg(long)]
+        rules: Option<PathBuf>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the full rationale of one rule and the files it inspects
+    Explain {
+        /// Check id (e.g. 146)
+        id: u8,
+
+        /// Path to custom rules file (default: the embedded rules)
+        #[arg(long)]
+        rules: Option<PathBuf>,
+    },
+}
+
 #[cfg(feature = "ai")]
 #[derive(Subcommand)]
 enum AiAction {
@@ -158,7 +298,7 @@ enum AiAction {
     },
     /// Generate test commands for requirements missing them
     GenerateCommands {
-        /// Path to the SRS markdown file
+        /// Path to the SRS markdown file (`-` reads from stdin)
         srs_path: PathBuf,
 
         /// Output file (default: stdout)
@@ -169,9 +309,13 @@ enum AiAction {
         #[arg(long)]
         merge: Option<PathBuf>,
 
-        /// Process all requirements, not just those missing commands
+        /// Process all requirements, not just those missing commands (regenerates cached ones)
         #[arg(long)]
         all: bool,
+
+        /// Neither read nor update the command cache next to the command map
+        #[arg(long = "no-cache")]
+        no_cache: bool,
     },
 }
 
@@ -203,9 +347,182 @@ fn parse_checks(input: &str) -> Result<Vec<u8>, String> {
     Ok(result)
 }
 
+/// Parse `--severity` overrides of the form `<check>=<level>`; later entries
+/// for the same check win.
+fn parse_severity_overrides(specs: &[String]) -> Result<HashMap<CheckId, Severity>, String> {
+    let mut overrides = HashMap::new();
+    for spec in specs {
+        let (check, level) = spec.split_once('=')
+            .ok_or_else(|| format!("expected <check>=<level>, got '{}'", spec))?;
+        let id: u8 = check.trim().parse()
+            .map_err(|_| format!("Invalid check number: '{}'", check.trim()))?;
+        let severity = Severity::from_name(level.trim())
+            .ok_or_else(|| format!("unknown severity '{}' (use 'info', 'warning', or 'error')", level.trim()))?;
+        overrides.insert(CheckId(id), severity);
+    }
+    Ok(overrides)
+}
+
+/// Parse `--color <auto|always|never>`.
+fn parse_color_choice(name: &str) -> Result<ColorChoice, String> {
+    match name {
+        "auto" => Ok(ColorChoice::Auto),
+        "always" => Ok(ColorChoice::Always),
+        "never" => Ok(ColorChoice::Never),
+        other => Err(format!("unknown color choice '{}' (use 'auto', 'always', or 'never')", other)),
+    }
+}
+
+/// Whether stdout should be colored under `choice`; `auto` defers to
+/// `anstream`, which checks for a terminal and honors `NO_COLOR`/`CLICOLOR`.
+fn stdout_color(choice: ColorChoice) -> bool {
+    choice.write_global();
+    AutoStream::choice(&std::io::stdout()) != ColorChoice::Never
+}
+
+/// Parse an `--emit <format>:<dest>` spec; a `-` destination (returned as
+/// `None`) is stdout.
+fn parse_emit_spec(spec: &str) -> Result<(ReportFormat, Option<PathBuf>), String> {
+    let (name, dest) = spec.split_once(':')
+        .ok_or_else(|| format!("expected <format>:<dest>, got '{}'", spec))?;
+    let format = ReportFormat::from_name(name)
+        .ok_or_else(|| format!("unknown format '{}' (use 'text', 'json', 'html', 'markdown', 'github', 'csv', or 'junit')", name))?;
+    match dest {
+        "" => Err(format!("missing destination in '{}' (use a path or '-' for stdout)", spec)),
+        "-" => Ok((format, None)),
+        path => Ok((format, Some(PathBuf::from(path)))),
+    }
+}
+
+/// Returns true when the SRS path is `-`, meaning the content comes from stdin.
+fn is_stdin_path(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Read SRS content from `path`, or from stdin when `path` is `-`.
+fn read_srs(path: &Path) -> Result<String, String> {
+    if !is_stdin_path(path) {
+        return std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read SRS '{}': {}", path.display(), e));
+    }
+    let mut content = String::new();
+    std::io::stdin()
+        .read_to_string(&mut content)
+        .map_err(|e| format!("cannot read SRS from stdin: {}", e))?;
+    if content.trim().is_empty() {
+        return Err("SRS content from stdin is empty".to_string());
+    }
+    Ok(content)
+}
+
+/// Exit code for a completed scan. Without a threshold any failed check
+/// exits 1; with one, only a violation at or above it does.
+fn exit_code(report: &ScanReport, fail_on: Option<&Severity>) -> i32 {
+    let failing = match fail_on {
+        Some(threshold) => report.max_severity().is_some_and(|s| s >= *threshold),
+        None => report.summary.failed > 0,
+    };
+    if failing { 1 } else { 0 }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use doc_engine_scan::{CheckEntry, CheckId, CheckResult, ScanSummary, Violation};
+
+    fn report_with(severities: &[Severity]) -> ScanReport {
+        let results: Vec<CheckEntry> = severities.iter().enumerate().map(|(i, sev)| {
+            let id = CheckId(i as u8 + 1);
+            CheckEntry {
+                id,
+                category: "structure".to_string(),
+                description: "test".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![Violation {
+                        check_id: id,
+                        path: None,
+                        message: "bad".to_string(),
+                        severity: sev.clone(),
+                        rule_type: String::new(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: String::new(),
+                    }],
+                },
+                duration_ms: None,
+            }
+        }).collect();
+        let failed = results.len() as u8;
+        ScanReport {
+            standard: "ISO/IEC/IEEE 15289:2019".to_string(),
+            clause: "9.2".to_string(),
+            tool: "doc-engine".to_string(),
+            tool_version: "0.1.0".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            project_root: "/tmp/project".to_string(),
+            results,
+            summary: ScanSummary { total: failed, passed: 0, failed, skipped: 0 },
+            project_type: ProjectType::OpenSource,
+            project_scope: ProjectScope::Large,
+            cache: None,
+            duration_ms: 0,
+        }
+    }
+
+    #[test]
+    fn test_exit_code_default_fails_on_any_failure() {
+        assert_eq!(exit_code(&report_with(&[Severity::Info]), None), 1);
+        assert_eq!(exit_code(&report_with(&[]), None), 0);
+    }
+
+    #[test]
+    fn test_exit_code_fail_on_error_ignores_warnings() {
+        let report = report_with(&[Severity::Warning, Severity::Info]);
+        assert_eq!(exit_code(&report, Some(&Severity::Error)), 0);
+    }
+
+    #[test]
+    fn test_exit_code_fail_on_warning() {
+        let report = report_with(&[Severity::Warning, Severity::Info]);
+        assert_eq!(exit_code(&report, Some(&Severity::Warning)), 1);
+        assert_eq!(exit_code(&report_with(&[Severity::Info]), Some(&Severity::Warning)), 0);
+    }
+
+    #[test]
+    fn test_is_stdin_path() {
+        assert!(is_stdin_path(Path::new("-")));
+        assert!(!is_stdin_path(Path::new("./-")));
+        assert!(!is_stdin_path(Path::new("srs.md")));
+    }
+
+    #[test]
+    fn test_parse_severity_overrides() {
+        let specs = vec!["57=warning".to_string(), "3 = info".to_string(), "57=error".to_string()];
+        let overrides = parse_severity_overrides(&specs).unwrap();
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides[&CheckId(57)], Severity::Error);
+        assert_eq!(overrides[&CheckId(3)], Severity::Info);
+        assert!(parse_severity_overrides(&["57".to_string()]).is_err());
+        assert!(parse_severity_overrides(&["57=fatal".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_color_choice() {
+        assert_eq!(parse_color_choice("auto"), Ok(ColorChoice::Auto));
+        assert_eq!(parse_color_choice("always"), Ok(ColorChoice::Always));
+        assert_eq!(parse_color_choice("never"), Ok(ColorChoice::Never));
+        assert!(parse_color_choice("sometimes").is_err());
+    }
+
+    #[test]
+    fn test_parse_emit_spec() {
+        assert_eq!(parse_emit_spec("json:-").unwrap(), (ReportFormat::Json, None));
+        assert_eq!(parse_emit_spec("text:out/report.txt").unwrap(), (ReportFormat::Text { quiet: false, color: false }, Some(PathBuf::from("out/report.txt"))));
+        assert_eq!(parse_emit_spec("junit:junit.xml").unwrap(), (ReportFormat::JUnit, Some(PathBuf::from("junit.xml"))));
+        assert!(parse_emit_spec("json").is_err());
+        assert!(parse_emit_spec("sarif:report.sarif").is_err());
+        assert!(parse_emit_spec("json:").is_err());
+    }
 
     #[test]
     fn test_parse_single() {
@@ -363,25 +680,147 @@ fn format_command_map_toml(map: &HashMap<String, String>) -> String {
     out
 }
 
+fn severity_name(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+/// Render the rule table printed by `doc-engine rules list`.
+fn format_rules_text(rules: &RuleSet) -> String {
+    let mut out = String::new();
+    for rule in &rules.rules {
+        out.push_str(&format!(
+            "{:>3}  {:<40} {:<14} {:<8} {}\n",
+            rule.id,
+            rule.rule_type.to_tag(),
+            rule.category,
+            severity_name(&rule.severity),
+            rule.description,
+        ));
+    }
+    out.push_str(&format!("\n{} rules\n", rules.rules.len()));
+    out
+}
+
+/// The rule list as JSON objects for `doc-engine rules list --json`.
+fn rules_to_json(rules: &RuleSet) -> serde_json::Value {
+    serde_json::Value::Array(rules.rules.iter().map(|rule| serde_json::json!({
+        "id": rule.id,
+        "handler": rule.rule_type.to_tag(),
+        "category": rule.category,
+        "severity": severity_name(&rule.severity),
+        "description": rule.description,
+    })).collect())
+}
+
+/// Render the detail printed by `doc-engine rules explain`.
+fn format_rule_explain(rule: &RuleDef) -> String {
+    let mut out = format!("Check {}: {}\n\n", rule.id, rule.description);
+    out.push_str(&format!("  handler:  {}\n", rule.rule_type.to_tag()));
+    out.push_str(&format!("  category: {}\n", rule.category));
+    out.push_str(&format!("  severity: {}\n", severity_name(&rule.severity)));
+    if let Some(scope) = &rule.scope {
+        out.push_str(&format!("  scope:    {:?}\n", scope).to_lowercase());
+    }
+    if !rule.depends_on.is_empty() {
+        let deps: Vec<String> = rule.depends_on.iter().map(u8::to_string).collect();
+        out.push_str(&format!("  depends:  {}\n", deps.join(", ")));
+    }
+    let inspects = match rule.rule_type.target() {
+        Some(target) => target.to_string(),
+        None => "chosen by the handler (see rationale)".to_string(),
+    };
+    out.push_str(&format!("  inspects: {}\n", inspects));
+    out.push_str(&format!("  fix hint: {}\n", rule.fix_hint.clone()
+        .unwrap_or_else(|| rule.rule_type.auto_fix_hint())));
+    out.push_str("\nRationale:\n");
+    match &rule.rationale {
+        Some(text) => {
+            for line in text.lines() {
+                out.push_str(&format!("  {}\n", line));
+            }
+        }
+        None => out.push_str(&format!("  {}\n", rule.description)),
+    }
+    out
+}
+
+/// Load a JSON scan report saved by `doc-engine scan`.
+fn read_report(path: &Path) -> Result<ScanReport, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read report '{}': {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("'{}' is not a doc-engine JSON report: {}", path.display(), e))
+}
+
+/// Render a report diff as text, regressions first; `color` styles statuses with `anstyle`.
+fn format_diff_text(diff: &ReportDiff, color: bool) -> String {
+    let paint = |ansi: AnsiColor, text: &str| {
+        if color {
+            let style = ansi.on_default();
+            format!("{style}{text}{style:#}")
+        } else {
+            text.to_string()
+        }
+    };
+
+    let mut out = format!("Diff {} -> {}\n", diff.old_timestamp, diff.new_timestamp);
+    let sections = [
+        (DiffStatus::Regressed, "REGRESSED", AnsiColor::Red),
+        (DiffStatus::Fixed, "FIXED", AnsiColor::Green),
+        (DiffStatus::Added, "ADDED", AnsiColor::Cyan),
+        (DiffStatus::Removed, "REMOVED", AnsiColor::Yellow),
+    ];
+    for (status, label, ansi) in sections {
+        for check in diff.with_status(status) {
+            out.push_str(&format!(
+                "  [{}] {}: {} ({} -> {})\n",
+                paint(ansi, label),
+                check.id,
+                check.description,
+                check.old.as_deref().unwrap_or("-"),
+                check.new.as_deref().unwrap_or("-"),
+            ));
+        }
+    }
+
+    let s = &diff.summary;
+    out.push_str(&format!(
+        "\n{}, {}, {} unchanged, {} added, {} removed\n",
+        paint(AnsiColor::Red, &format!("{} regressed", s.regressed)),
+        paint(AnsiColor::Green, &format!("{} fixed", s.fixed)),
+        s.unchanged,
+        s.added,
+        s.removed,
+    ));
+    out
+}
+
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Scan {
-            path, json, checks, project_type, scope, rules, phase, module, output,
-            #[cfg(feature = "kafka")]
-            kafka_config,
-            #[cfg(feature = "kafka")]
-            kafka_broker,
-            #[cfg(feature = "kafka")]
-            kafka_topic,
-            #[cfg(feature = "kafka")]
-            kafka_client_id,
-            #[cfg(feature = "kafka")]
-            kafka_partition,
-            #[cfg(feature = "kafka")]
-            kafka_timeout,
-        } => {
+        Commands::Scan(args) => {
+            let ScanArgs {
+                path, json, html, markdown, github, csv, csv_skipped, junit, checks, project_type, scope, config, rules, phase, module, output, emit, no_cache, cache_dir, jobs, check_timeout_ms, exclude, fail_on, timings, quiet, color, severity,
+                #[cfg(feature = "sqlite")]
+                db,
+                #[cfg(feature = "kafka")]
+                kafka_config,
+                #[cfg(feature = "kafka")]
+                kafka_broker,
+                #[cfg(feature = "kafka")]
+                kafka_topic,
+                #[cfg(feature = "kafka")]
+                kafka_client_id,
+                #[cfg(feature = "kafka")]
+                kafka_partition,
+                #[cfg(feature = "kafka")]
+                kafka_timeout,
+            } = *args;
             // Canonicalize path early so auto-detection can read LICENSE
             let root = match path.canonicalize() {
                 Ok(p) => p,
@@ -391,6 +830,31 @@ fn main() {
                 }
             };
 
+            // Resolution order: defaults -> config file -> env -> CLI flags
+            let mut file_config = match config.or_else(|| ConfigFile::discover(&root)) {
+                Some(ref p) => match ConfigFile::from_file(p) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        process::exit(2);
+                    }
+                },
+                None => ConfigFile::default(),
+            };
+            file_config.merge_env();
+
+            let project_type = project_type.or(file_config.project_type);
+            let checks = checks.or(file_config.checks);
+            let rules = rules.or(file_config.rules);
+            let phase = phase.or(file_config.phases.map(|p| p.join(",")));
+            let module = module.or(file_config.modules.map(|m| m.join(",")));
+            let exclude = if exclude.is_empty() { file_config.exclude.unwrap_or_default() } else { exclude };
+            let severity: Vec<String> = file_config.severity.unwrap_or_default().into_iter().chain(severity).collect();
+            let Some(scope) = scope.or(file_config.scope) else {
+                eprintln!("Error: no project scope given (pass --scope or set scope in {})", CONFIG_FILE_NAME);
+                process::exit(2);
+            };
+
             // Parse project type: explicit --type overrides, None = auto-detect from LICENSE
             let pt = match project_type.as_deref() {
                 Some("internal") => Some(ProjectType::Internal),
@@ -413,6 +877,27 @@ fn main() {
                 }
             };
 
+            // Parse --fail-on threshold; omitted keeps "any failed check exits 1"
+            let fail_on = match fail_on.as_deref() {
+                Some(name) => match Severity::from_name(name) {
+                    Some(sev) => Some(sev),
+                    None => {
+                        eprintln!("Error: unknown severity '{}' (use 'info', 'warning', or 'error')", name);
+                        process::exit(2);
+                    }
+                },
+                None => None,
+            };
+
+            if jobs == Some(0) {
+                eprintln!("Error: --jobs must be at least 1");
+                process::exit(2);
+            }
+            if check_timeout_ms == Some(0) {
+                eprintln!("Error: --check-timeout-ms must be at least 1");
+                process::exit(2);
+            }
+
             // Parse check filter
             let check_ids = match checks {
                 Some(ref s) => match parse_checks(s) {
@@ -453,6 +938,76 @@ fn main() {
                 None => None,
             };
 
+            // Parse --severity overrides; file entries come first so flags win per check
+            let severity_overrides = match parse_severity_overrides(&severity) {
+                Ok(o) => o,
+                Err(e) => {
+                    eprintln!("Error parsing --severity: {}", e);
+                    process::exit(2);
+                }
+            };
+
+            // Parse --emit specs; the stdout and report-file flags desugar into the same list
+            let mut emits = Vec::new();
+            for spec in &emit {
+                match parse_emit_spec(spec) {
+                    Ok((ReportFormat::Csv { .. }, dest)) => emits.push((ReportFormat::Csv { include_skipped: csv_skipped }, dest)),
+                    Ok(parsed) => emits.push(parsed),
+                    Err(e) => {
+                        eprintln!("Error parsing --emit: {}", e);
+                        process::exit(2);
+                    }
+                }
+            }
+            let stdout_flag = if json {
+                Some(ReportFormat::Json)
+            } else if html {
+                Some(ReportFormat::Html)
+            } else if markdown {
+                Some(ReportFormat::Markdown)
+            } else if github {
+                Some(ReportFormat::GithubActions)
+            } else if csv {
+                Some(ReportFormat::Csv { include_skipped: csv_skipped })
+            } else if junit {
+                Some(ReportFormat::JUnit)
+            } else {
+                None
+            };
+            let emits_stdout = emits.iter().any(|(_, dest)| dest.is_none());
+            if stdout_flag.is_some() && emits_stdout {
+                eprintln!("Error: --emit <format>:- conflicts with --json, --html, --markdown, --github, --csv and --junit");
+                process::exit(2);
+            }
+            if !emits_stdout {
+                emits.insert(0, (stdout_flag.unwrap_or(ReportFormat::Text { quiet: false, color: false }), None));
+            }
+            if let Some(path) = output {
+                emits.push((ReportFormat::Json, Some(path)));
+            }
+
+            // Text emits honor --quiet everywhere, color only on stdout
+            let color = match parse_color_choice(&color) {
+                Ok(choice) => stdout_color(choice),
+                Err(e) => {
+                    eprintln!("Error parsing --color: {}", e);
+                    process::exit(2);
+                }
+            };
+            for (format, dest) in emits.iter_mut() {
+                if let ReportFormat::Text { quiet: q, color: c } = format {
+                    *q = quiet;
+                    *c = color && dest.is_none();
+                }
+            }
+
+            // Resolve cache directory: --no-cache disables, --cache-dir overrides the default
+            let cache_dir = if no_cache {
+                None
+            } else {
+                Some(cache_dir.unwrap_or_else(|| root.join("target/doc-engine-cache")))
+            };
+
             let config = ScanConfig {
                 project_type: pt,
                 project_scope: ps,
@@ -460,31 +1015,50 @@ fn main() {
                 rules_path: rules,
                 phases,
                 module_filter,
+                cache_dir,
+                jobs,
+                exclude_globs: exclude,
+                severity_overrides,
+                check_timeout_ms,
             };
 
             match scan_with_config(&root, &config) {
                 Ok(report) => {
-                    let format = if json { ReportFormat::Json } else { ReportFormat::Text };
-                    let stdout_sink = StdoutSink { format };
-                    if let Err(e) = stdout_sink.emit(&report) {
-                        eprintln!("Error: {}", e);
-                        process::exit(2);
+                    if report.results.is_empty() && !config.exclude_globs.is_empty() {
+                        eprintln!(
+                            "Warning: --exclude patterns match every file under '{}'; nothing was scanned",
+                            root.display()
+                        );
                     }
 
-                    // Persist report: use --output if provided, otherwise default to
+                    // Persist report: without --output or another JSON file destination, default to
                     // docs/7-operations/compliance/documentation_audit_report_v{version}.json
-                    let out_path = output.unwrap_or_else(|| {
-                        root.join(format!(
+                    if !emits.iter().any(|(format, dest)| *format == ReportFormat::Json && dest.is_some()) {
+                        emits.push((ReportFormat::Json, Some(root.join(format!(
                             "docs/7-operations/compliance/documentation_audit_report_v{}.json",
                             report.tool_version
-                        ))
-                    });
-                    let file_sink = FileSink { path: out_path };
-                    if let Err(e) = file_sink.emit(&report) {
-                        eprintln!("Error: {}", e);
-                        process::exit(2);
+                        )))));
+                    }
+
+                    // Scan once, fan the report out to every sink
+                    let text_output = emits.iter().any(|(format, dest)| matches!(format, ReportFormat::Text { .. }) && dest.is_none());
+                    let mut saved = Vec::new();
+                    let mut sinks: Vec<Box<dyn ReportSink>> = Vec::new();
+                    for (format, dest) in emits {
+                        match dest {
+                            Some(path) => {
+                                saved.push(path.clone());
+                                sinks.push(Box::new(FileSink { path, format }));
+                            }
+                            None => sinks.push(Box::new(StdoutSink { format })),
+                        }
+                    }
+
+                    // SQLite sink: append to the local scan history when --db is set
+                    #[cfg(feature = "sqlite")]
+                    if let Some(ref path) = db {
+                        sinks.push(Box::new(SqliteSink { path: path.clone() }));
                     }
-                    eprintln!("Report saved to {}", file_sink.path.display());
 
                     // Kafka sink: emit report if any kafka flag is present
                     #[cfg(feature = "kafka")]
@@ -517,26 +1091,95 @@ fn main() {
                             if let Some(v) = kafka_partition { kconfig.partition = v; }
                             if let Some(v) = kafka_timeout { kconfig.timeout_ms = v; }
 
-                            let kafka_sink = KafkaSink { config: kconfig };
-                            if let Err(e) = kafka_sink.emit(&report) {
-                                eprintln!("Kafka error: {}", e);
-                                process::exit(2);
-                            }
+                            sinks.push(Box::new(KafkaSink { config: kconfig }));
                         }
                     }
 
-                    if report.summary.failed > 0 {
-                        process::exit(1);
-                    } else {
-                        process::exit(0);
+                    for sink in &sinks {
+                        if let Err(e) = sink.emit(&report) {
+                            eprintln!("Error: {}", e);
+                            process::exit(2);
+                        }
+                    }
+                    if timings && text_output {
+                        print!("{}", format_timings(&report));
+                    }
+                    for path in &saved {
+                        eprintln!("Report saved to {}", path.display());
+                    }
+                    #[cfg(feature = "sqlite")]
+                    if let Some(ref path) = db {
+                        eprintln!("Scan appended to {}", path.display());
+                    }
+                    if let Some(ref stats) = report.cache {
+                        eprintln!("Cache: {} hits, {} misses", stats.hits, stats.misses);
+                    }
+
+                    process::exit(exit_code(&report, fail_on.as_ref()));
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(2);
+                }
+            }
+        }
+        Commands::Diff { old, new, json } => {
+            let (old_report, new_report) = match (read_report(&old), read_report(&new)) {
+                (Ok(o), Ok(n)) => (o, n),
+                (Err(e), _) | (_, Err(e)) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(2);
+                }
+            };
+
+            let diff = diff_reports(&old_report, &new_report);
+            if json {
+                match serde_json::to_string_pretty(&diff) {
+                    Ok(s) => println!("{}", s),
+                    Err(e) => {
+                        eprintln!("Error: JSON serialization failed: {}", e);
+                        process::exit(2);
                     }
                 }
+            } else {
+                print!("{}", format_diff_text(&diff, stdout_color(ColorChoice::Auto)));
+            }
+
+            // Regressions fail the command so CI can gate on them
+            process::exit(if diff.summary.regressed > 0 { 1 } else { 0 });
+        }
+        Commands::Rules { action } => {
+            let rules_path = match &action {
+                RulesAction::List { rules, .. } | RulesAction::Explain { rules, .. } => rules.clone(),
+            };
+            let ruleset = match load_rules(rules_path.as_deref()) {
+                Ok(r) => r,
                 Err(e) => {
                     eprintln!("Error: {}", e);
                     process::exit(2);
                 }
+            };
+            match action {
+                RulesAction::List { json: true, .. } => {
+                    println!("{}", serde_json::to_string_pretty(&rules_to_json(&ruleset)).unwrap_or_default());
+                }
+                RulesAction::List { .. } => print!("{}", format_rules_text(&ruleset)),
+                RulesAction::Explain { id, .. } => match ruleset.rules.iter().find(|r| r.id == id) {
+                    Some(rule) => print!("{}", format_rule_explain(rule)),
+                    None => {
+                        eprintln!("Error: no rule with id {}", id);
+                        process::exit(2);
+                    }
+                },
             }
         }
+        Commands::Schema => match serde_json::to_string_pretty(&report_schema()) {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("Error: JSON serialization failed: {}", e);
+                process::exit(2);
+            }
+        },
         #[cfg(feature = "ai")]
         Commands::Ai { action } => {
             let rt = tokio::runtime::Runtime::new().unwrap_or_else(|e| {
@@ -594,7 +1237,7 @@ fn main() {
                             }
                         }
                     }
-                    AiAction::GenerateCommands { srs_path, output, merge, all } => {
+                    AiAction::GenerateCommands { srs_path, output, merge, all, no_cache } => {
                         let service = match CommandGenerator::new(CommandGeneratorConfig::from_env()).await {
                             Ok(s) => s,
                             Err(e) => {
@@ -604,10 +1247,10 @@ fn main() {
                         };
 
                         // 1. Read and parse the SRS.
-                        let srs_content = match std::fs::read_to_string(&srs_path) {
+                        let srs_content = match read_srs(&srs_path) {
                             Ok(c) => c,
                             Err(e) => {
-                                eprintln!("Error: cannot read SRS '{}': {}", srs_path.display(), e);
+                                eprintln!("Error: {}", e);
                                 process::exit(2);
                             }
                         };
@@ -618,6 +1261,10 @@ fn main() {
                                 process::exit(2);
                             }
                         };
+                        if domains.is_empty() {
+                            eprintln!("Error: no domains with requirements found in SRS '{}'", srs_path.display());
+                            process::exit(2);
+                        }
 
                         // 2. Optionally load existing command map.
                         let existing_map: HashMap<String, String> = match merge {
@@ -680,7 +1327,7 @@ fn main() {
                             process::exit(0);
                         }
 
-                        eprintln!("Sending {} requirements to LLM...", reqs_for_ai.len());
+                        eprintln!("Generating commands for {} requirements...", reqs_for_ai.len());
 
                         // 4. Call the AI service.
                         let request = GenerateCommandsRequest {
@@ -691,13 +1338,31 @@ fn main() {
                                 Test framework: cargo test. Binary: doc-engine.".to_string(),
                         };
 
-                        let response = match service.generate_commands(&request).await {
+                        // The cache lives next to the command map being written or merged;
+                        // --all regenerates every command but still refreshes the cache.
+                        let mut cache = match (no_cache, output.as_ref().or(merge.as_ref())) {
+                            (false, Some(map_path)) => Some(CommandCache::load(&CommandCache::path_for(map_path))),
+                            _ => None,
+                        };
+                        let result = match cache.as_mut() {
+                            Some(cache) if !all => service.generate_commands_cached(&request, cache).await,
+                            _ => service.generate_commands(&request).await,
+                        };
+                        let response = match result {
                             Ok(r) => r,
                             Err(e) => {
                                 eprintln!("Error: {}", e);
                                 process::exit(1);
                             }
                         };
+                        if let Some(ref mut cache) = cache {
+                            if all {
+                                cache.record(&request.requirements, &response);
+                            }
+                            if let Err(e) = cache.save() {
+                                eprintln!("Warning: {}", e);
+                            }
+                        }
 
                         // 5. Merge: existing entries always win.
                         let mut merged = existing_map.clone();
@@ -725,21 +1390,39 @@ fn main() {
                             }
                         }
 
+                        let cached = response.skipped.iter().filter(|s| s.reason == CACHED_REASON).count();
                         eprintln!(
-                            "\nGenerated {} commands, skipped {}",
+                            "\nGenerated {} commands ({} from cache), skipped {}",
                             response.commands.len(),
-                            response.skipped.len()
+                            cached,
+                            response.skipped.len() - cached
                         );
                     }
                 }
             });
         }
-        Commands::Scaffold { srs_path, output, force, phase, file_type, feature, exclude_feature, command_map, report } => {
-            let srs_resolved = match srs_path.canonicalize() {
-                Ok(p) => p,
-                Err(e) => {
-                    eprintln!("Error: cannot resolve SRS path '{}': {}", srs_path.display(), e);
-                    process::exit(2);
+        Commands::Scaffold { srs_path, output, force, phase, file_type, feature, exclude_feature, command_map, template_dir, dry_run, merge, report } => {
+            let stdin_content = if is_stdin_path(&srs_path) {
+                match read_srs(&srs_path) {
+                    Ok(c) => Some(c),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        process::exit(2);
+                    }
+                }
+            } else {
+                None
+            };
+
+            let srs_resolved = if stdin_content.is_some() {
+                srs_path.clone()
+            } else {
+                match srs_path.canonicalize() {
+                    Ok(p) => p,
+                    Err(e) => {
+                        eprintln!("Error: cannot resolve SRS path '{}': {}", srs_path.display(), e);
+                        process::exit(2);
+                    }
                 }
             };
 
@@ -760,7 +1443,7 @@ fn main() {
                 None => vec![],
             };
 
-            let valid_types = ["yaml", "spec", "arch", "test", "exec", "deploy", "plan"];
+            let valid_types = ["yaml", "spec", "arch", "test", "exec", "deploy", "plan", "matrix"];
             let file_types: Vec<String> = match file_type {
                 Some(ref s) => {
                     let parsed: Vec<String> = s.split(',').map(|t| t.trim().to_lowercase()).collect();
@@ -792,23 +1475,55 @@ fn main() {
                 features,
                 exclude_features,
                 command_map_path: command_map,
+                template_dir,
+                dry_run,
+                merge,
+            };
+
+            let outcome = match stdin_content {
+                Some(ref content) => scaffold_from_srs_content(&config, content),
+                None => scaffold_from_srs(&config),
             };
 
-            match scaffold_from_srs(&config) {
+            match outcome {
                 Ok(result) => {
+                    let prefix = if result.dry_run { "[dry-run] " } else { "" };
                     for path in &result.created {
-                        println!("  + {}", path.display());
+                        println!("{}  + {}", prefix, path.display());
+                    }
+                    for path in &result.merged {
+                        println!("{}  * {}", prefix, path.display());
                     }
                     for path in &result.skipped {
-                        println!("  ~ {}", path.display());
+                        println!("{}  ~ {}", prefix, path.display());
+                    }
+                    for note in &result.notes {
+                        eprintln!("Note: {}", note);
+                    }
+                    let merged = if merge {
+                        format!(", {} merged", result.merged.len())
+                    } else {
+                        String::new()
+                    };
+                    if result.dry_run {
+                        println!(
+                            "\n[dry-run] Scaffold plan: {} domains, {} requirements, {} files would be created{}, {} skipped",
+                            result.domain_count,
+                            result.requirement_count,
+                            result.created.len(),
+                            merged,
+                            result.skipped.len(),
+                        );
+                    } else {
+                        println!(
+                            "\nScaffold complete: {} domains, {} requirements, {} files created{}, {} skipped",
+                            result.domain_count,
+                            result.requirement_count,
+                            result.created.len(),
+                            merged,
+                            result.skipped.len(),
+                        );
                     }
-                    println!(
-                        "\nScaffold complete: {} domains, {} requirements, {} files created, {} skipped",
-                        result.domain_count,
-                        result.requirement_count,
-                        result.created.len(),
-                        result.skipped.len(),
-                    );
 
                     if let Some(ref report_path) = report {
                         let json = serde_json::to_string_pretty(&result).unwrap_or_else(|e| {
@@ -97,6 +97,218 @@ fn test_cli_json() {
     assert!(val["project_root"].is_string());
 }
 
+#[test]
+fn test_cli_html() {
+    let tmp = common::create_minimal_project();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--html")
+        .arg("--checks")
+        .arg("1,2,3")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("<!DOCTYPE html>"));
+    assert!(stdout.contains("<details"));
+    assert!(stdout.contains("3/3 passed"));
+    assert!(stdout.trim_end().ends_with("</html>"));
+}
+
+#[test]
+fn test_cli_html_conflicts_with_json() {
+    let tmp = common::create_minimal_project();
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--html")
+        .arg("--json")
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn test_cli_markdown() {
+    let tmp = common::create_minimal_project();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--markdown")
+        .arg("--checks")
+        .arg("1,2,3")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("## doc-engine scan results"));
+    assert!(stdout.contains("**3/3 passed**"));
+    assert!(stdout.contains("| ID | Check | Status | Message |"));
+}
+
+#[test]
+fn test_cli_json_timings() {
+    let tmp = common::create_minimal_project();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--json")
+        .arg("--no-cache")
+        .arg("--checks")
+        .arg("1,2,3")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let val: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let total = val["duration_ms"].as_u64().expect("report duration_ms");
+    for entry in val["results"].as_array().unwrap() {
+        let check = entry["duration_ms"].as_u64().expect("check duration_ms");
+        assert!(check <= total, "check {} took {} ms of a {} ms scan", entry["id"], check, total);
+    }
+}
+
+#[test]
+fn test_cli_timings_table() {
+    let tmp = common::create_minimal_project();
+    let plain = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--checks")
+        .arg("1,2,3")
+        .output()
+        .unwrap();
+    assert!(!String::from_utf8(plain.stdout).unwrap().contains("Slowest checks"));
+
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--no-cache")
+        .arg("--timings")
+        .arg("--checks")
+        .arg("1,2,3")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let table = &stdout[stdout.find("Slowest checks").expect("timings table")..];
+    let durations: Vec<u64> = table.lines()
+        .filter_map(|line| line.trim().split_once(" ms "))
+        .filter_map(|(ms, _)| ms.trim().parse().ok())
+        .collect();
+    assert_eq!(durations.len(), 3);
+    assert!(durations.windows(2).all(|w| w[0] >= w[1]));
+}
+
+#[test]
+fn test_cli_github_annotations() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--github")
+        .arg("--checks")
+        .arg("1")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("::error"), "{}", stdout);
+    assert!(stdout.lines().all(|l| l.starts_with("::")));
+}
+
+#[test]
+fn test_cli_csv() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--csv")
+        .arg("--checks")
+        .arg("1")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("check_id,category,severity,file,line,message"));
+    assert!(lines.next().is_some_and(|l| l.starts_with("1,")), "{}", stdout);
+}
+
+#[test]
+fn test_cli_junit() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--junit")
+        .arg("--checks")
+        .arg("1")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("<?xml"), "{}", stdout);
+    assert!(stdout.contains("<testsuite name=\"doc-engine\" tests=\"1\" failures=\"1\" skipped=\"0\">"), "{}", stdout);
+    assert!(stdout.contains("<failure message="), "{}", stdout);
+}
+
+#[test]
+fn test_cli_quiet_hides_passing_checks() {
+    let tmp = common::create_minimal_project();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--checks")
+        .arg("1,2,3")
+        .arg("--quiet")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout, "3/3 passed, 0 failed, 0 skipped\n");
+}
+
+#[test]
+fn test_cli_color_never_and_always() {
+    let tmp = common::create_minimal_project();
+    let scan = |choice: &str| {
+        let output = cmd()
+            .arg("scan")
+            .arg(tmp.path())
+            .arg("--scope")
+            .arg("large")
+            .arg("--checks")
+            .arg("1")
+            .arg("--color")
+            .arg(choice)
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let plain = scan("never");
+    assert!(plain.contains("[PASS] 1:"), "{}", plain);
+    assert!(!plain.contains('\x1b'));
+    assert!(scan("always").contains("[\x1b[32mPASS\x1b[0m] 1:"));
+}
+
 #[test]
 fn test_cli_checks_range() {
     let tmp = common::create_minimal_project();
@@ -157,6 +369,68 @@ path = "README.md"
         .success();
 }
 
+fn warning_only_rules(root: &std::path::Path) -> std::path::PathBuf {
+    let rules_path = root.join("warning_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 1
+category = "custom"
+description = "NOTICE file exists"
+severity = "warning"
+type = "file_exists"
+path = "NOTICE"
+"#).unwrap();
+    rules_path
+}
+
+#[test]
+fn test_cli_fail_on_error_ignores_warnings() {
+    let tmp = common::create_minimal_project();
+    let rules_path = warning_only_rules(tmp.path());
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--rules")
+        .arg(&rules_path)
+        .arg("--fail-on")
+        .arg("error")
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn test_cli_fail_on_warning() {
+    let tmp = common::create_minimal_project();
+    let rules_path = warning_only_rules(tmp.path());
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--rules")
+        .arg(&rules_path)
+        .arg("--fail-on")
+        .arg("warning")
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn test_cli_fail_on_unknown_severity() {
+    let tmp = common::create_minimal_project();
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--fail-on")
+        .arg("fatal")
+        .assert()
+        .code(2);
+}
+
 #[test]
 fn test_cli_traceability_checks() {
     let tmp = common::create_minimal_project();
@@ -963,3 +1237,355 @@ fn test_cli_output_json_stdout_matches_file() {
     // Both contain the same results
     assert_eq!(stdout_val["summary"]["total"], file_val["summary"]["total"]);
 }
+
+#[test]
+fn test_cli_cache_hits_on_rescan() {
+    let tmp = common::create_minimal_project();
+    let rules_path = tmp.path().join("custom_rules.toml");
+    std::fs::write(&rules_path, r##"
+[[rules]]
+id = 1
+category = "content"
+description = "README has a heading"
+severity = "info"
+type = "file_content_matches"
+path = "README.md"
+pattern = "#"
+"##).unwrap();
+
+    let run = || {
+        cmd()
+            .arg("scan")
+            .arg(tmp.path())
+            .arg("--scope")
+            .arg("large")
+            .arg("--json")
+            .arg("--rules")
+            .arg(&rules_path)
+            .output()
+            .unwrap()
+    };
+
+    let first: serde_json::Value = serde_json::from_slice(&run().stdout).unwrap();
+    assert_eq!(first["cache"]["hits"], 0);
+    assert_eq!(first["cache"]["misses"], 1);
+    assert!(tmp.path().join("target/doc-engine-cache").is_dir());
+
+    let second = run();
+    let val: serde_json::Value = serde_json::from_slice(&second.stdout).unwrap();
+    assert_eq!(val["cache"]["hits"], 1);
+    assert_eq!(val["cache"]["misses"], 0);
+    let stderr = String::from_utf8(second.stderr).unwrap();
+    assert!(stderr.contains("Cache: 1 hits, 0 misses"));
+}
+
+#[test]
+fn test_cli_cache_reuses_every_check_on_unchanged_tree() {
+    let tmp = common::create_minimal_project();
+    let cache_dir = tempfile::TempDir::new().unwrap();
+    let out_dir = tempfile::TempDir::new().unwrap();
+    let run = || {
+        let output = cmd()
+            .arg("scan")
+            .arg(tmp.path())
+            .arg("--scope")
+            .arg("large")
+            .arg("--json")
+            .arg("--cache")
+            .arg(cache_dir.path())
+            .arg("--output")
+            .arg(out_dir.path().join("report.json"))
+            .output()
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+
+    let first = run();
+    let second = run();
+    assert_eq!(second["cache"]["misses"], 0);
+    assert_eq!(second["cache"]["hits"], first["cache"]["misses"]);
+    assert_eq!(second["summary"], first["summary"]);
+}
+
+#[test]
+fn test_cli_no_cache() {
+    let tmp = common::create_minimal_project();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--json")
+        .arg("--checks")
+        .arg("1")
+        .arg("--no-cache")
+        .output()
+        .unwrap();
+
+    let val: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(val.get("cache").is_none());
+    assert!(!tmp.path().join("target/doc-engine-cache").exists());
+}
+
+#[test]
+fn test_cli_exclude_everything_reports_empty_scan() {
+    let tmp = common::create_minimal_project();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--json")
+        .arg("--exclude")
+        .arg("**")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    let val: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(val["results"].as_array().unwrap().len(), 0);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("nothing was scanned"), "{}", stderr);
+}
+
+#[test]
+fn test_cli_exclude_invalid_glob() {
+    let tmp = common::create_minimal_project();
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--exclude")
+        .arg("docs/[oops")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid exclude glob"));
+}
+
+/// Write a minimal saved report whose checks pass (`true`) or fail (`false`).
+fn write_report(dir: &std::path::Path, name: &str, outcomes: &[(u8, bool)]) -> std::path::PathBuf {
+    let results: Vec<serde_json::Value> = outcomes
+        .iter()
+        .map(|&(id, passed)| {
+            let result = if passed {
+                serde_json::json!({"status": "pass"})
+            } else {
+                serde_json::json!({"status": "fail", "violations": [{
+                    "check_id": id, "path": null, "message": "broken", "severity": "error"
+                }]})
+            };
+            serde_json::json!({
+                "id": id, "category": "structure", "description": format!("check {id}"), "result": result
+            })
+        })
+        .collect();
+    let report = serde_json::json!({
+        "standard": "ISO/IEC/IEEE 15289:2019",
+        "clause": "9.2",
+        "tool": "doc-engine",
+        "tool_version": "0.1.0",
+        "timestamp": format!("{name}-time"),
+        "project_root": "/tmp/project",
+        "results": results,
+        "summary": {"total": outcomes.len(), "passed": 0, "failed": 0, "skipped": 0},
+        "project_type": "open_source",
+        "project_scope": "large",
+    });
+    let path = dir.join(format!("{name}.json"));
+    std::fs::write(&path, report.to_string()).unwrap();
+    path
+}
+
+#[test]
+fn test_cli_diff_fixed_check() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let old = write_report(tmp.path(), "old", &[(1, false), (2, true)]);
+    let new = write_report(tmp.path(), "new", &[(1, true), (2, true)]);
+
+    cmd()
+        .arg("diff")
+        .arg(&old)
+        .arg(&new)
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[FIXED] 1: check 1 (fail -> pass)"))
+        .stdout(predicate::str::contains("0 regressed, 1 fixed, 1 unchanged"));
+}
+
+#[test]
+fn test_cli_diff_regressed_check_json() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let old = write_report(tmp.path(), "old", &[(1, true)]);
+    let new = write_report(tmp.path(), "new", &[(1, false)]);
+
+    let output = cmd()
+        .arg("diff")
+        .arg(&old)
+        .arg(&new)
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let val: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(val["summary"]["regressed"], 1);
+    assert_eq!(val["checks"][0]["status"], "regressed");
+    assert_eq!(val["checks"][0]["old"], "pass");
+    assert_eq!(val["checks"][0]["new"], "fail");
+}
+
+#[test]
+fn test_cli_diff_bad_report() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let old = write_report(tmp.path(), "old", &[(1, true)]);
+    let bad = tmp.path().join("bad.json");
+    std::fs::write(&bad, "not json").unwrap();
+
+    cmd()
+        .arg("diff")
+        .arg(&old)
+        .arg(&bad)
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("is not a doc-engine JSON report"));
+}
+
+fn scanned_scope(mut command: Command) -> serde_json::Value {
+    let output = command.env_remove("DOC_ENGINE_SCOPE").output().unwrap();
+    let val: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    val["project_scope"].clone()
+}
+
+#[test]
+fn test_cli_config_file_sets_scope() {
+    let tmp = common::create_minimal_project();
+    std::fs::write(tmp.path().join("doc-engine.toml"), "scope = \"large\"\nchecks = \"1-3\"\n").unwrap();
+
+    let mut command = cmd();
+    command.arg("scan").arg(tmp.path()).arg("--json");
+    assert_eq!(scanned_scope(command), "large");
+}
+
+#[test]
+fn test_cli_scope_flag_overrides_config_file() {
+    let tmp = common::create_minimal_project();
+    let config = tmp.path().join("ci.toml");
+    std::fs::write(&config, "scope = \"large\"\nchecks = \"1-3\"\n").unwrap();
+
+    let mut command = cmd();
+    command
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--config")
+        .arg(&config)
+        .arg("--scope")
+        .arg("small")
+        .arg("--json");
+    assert_eq!(scanned_scope(command), "small");
+}
+
+#[test]
+fn test_cli_missing_scope_exit_2() {
+    let tmp = common::create_minimal_project();
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .env_remove("DOC_ENGINE_SCOPE")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("no project scope given"));
+}
+
+#[test]
+fn test_cli_emit_multiple_sinks() {
+    let tmp = common::create_minimal_project();
+    let json_path = tmp.path().join("out/report.json");
+    let text_path = tmp.path().join("out/report.txt");
+    std::fs::create_dir_all(tmp.path().join("out")).unwrap();
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--checks")
+        .arg("1-3")
+        .arg("--emit")
+        .arg(format!("json:{}", json_path.display()))
+        .arg("--emit")
+        .arg(format!("text:{}", text_path.display()))
+        .assert()
+        .stdout(predicate::str::contains("doc-engine scan results"));
+
+    let val: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert!(val.get("summary").is_some());
+    assert!(std::fs::read_to_string(&text_path).unwrap().contains("doc-engine scan results"));
+    // An explicit JSON file sink replaces the default audit report
+    assert!(!tmp.path().join("docs/7-operations/compliance").exists()
+        || std::fs::read_dir(tmp.path().join("docs/7-operations/compliance")).unwrap()
+            .all(|e| !e.unwrap().file_name().to_string_lossy().starts_with("documentation_audit_report")));
+}
+
+#[test]
+fn test_cli_emit_unknown_format_exit_2() {
+    let tmp = common::create_minimal_project();
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--scope")
+        .arg("large")
+        .arg("--emit")
+        .arg("sarif:report.sarif")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Error parsing --emit"));
+}
+
+#[test]
+fn test_cli_rules_list_json() {
+    let output = cmd()
+        .args(["rules", "list", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let rules: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(rules.len(), default_rule_count());
+    let rule = rules.iter().find(|r| r["id"] == 146).unwrap();
+    assert_eq!(rule["handler"], "builtin:no_placeholder_markers");
+    assert_eq!(rule["category"], "content");
+    assert_eq!(rule["severity"], "warning");
+}
+
+#[test]
+fn test_cli_rules_list_text() {
+    cmd()
+        .args(["rules", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("builtin:heading_hierarchy"))
+        .stdout(predicate::str::contains(format!("{} rules", default_rule_count())));
+}
+
+#[test]
+fn test_cli_rules_explain() {
+    cmd()
+        .args(["rules", "explain", "146"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Check 146:"))
+        .stdout(predicate::str::contains("fenced code blocks"));
+    cmd()
+        .args(["rules", "explain", "2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("inspects: docs/README.md"));
+}
+
+#[test]
+fn test_cli_rules_explain_unknown_id_exit_2() {
+    cmd()
+        .args(["rules", "explain", "250"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("no rule with id 250"));
+}
@@ -1,5 +1,7 @@
 mod common;
 
+use std::collections::HashMap;
+
 use doc_engine_scan::{default_rule_count, scan_with_config, ScanConfig, ProjectScope, ProjectType, CheckResult};
 
 #[test]
@@ -12,6 +14,11 @@ fn test_check_filter_single() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -28,6 +35,11 @@ fn test_check_filter_range() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 3);
@@ -43,6 +55,11 @@ fn test_check_filter_none() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), default_rule_count());
@@ -58,6 +75,11 @@ fn test_project_type_internal_skips() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     // Checks 31 and 32 are open_source only, should be skipped for internal
@@ -87,6 +109,11 @@ path = "README.md"
         rules_path: Some(rules_path),
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -103,6 +130,11 @@ fn test_scope_small_integration() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     // With scope=small, medium and large rules should be skipped
@@ -129,6 +161,11 @@ fn test_scope_medium_runs_small_and_medium() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 3);
@@ -150,6 +187,11 @@ fn test_scope_report_field() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.project_scope, ProjectScope::Small);
@@ -165,6 +207,11 @@ fn test_scope_json_output() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     let json = doc_engine_scan::format_report_json(&report);
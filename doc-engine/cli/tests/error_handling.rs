@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::Path;
 use doc_engine_scan::{scan_with_config, ScanConfig, ScanError, ProjectType, ProjectScope};
 
@@ -10,6 +11,11 @@ fn test_nonexistent_path() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let result = scan_with_config(Path::new("/nonexistent/path/xyz"), &config);
     assert!(result.is_err());
@@ -29,6 +35,11 @@ fn test_bad_rules_path() {
         rules_path: Some("/nonexistent/rules.toml".into()),
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let result = scan_with_config(tmp.path(), &config);
     assert!(result.is_err());
@@ -51,6 +62,11 @@ fn test_malformed_rules() {
         rules_path: Some(rules_path),
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let result = scan_with_config(tmp.path(), &config);
     assert!(result.is_err());
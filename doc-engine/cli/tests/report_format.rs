@@ -1,6 +1,8 @@
 mod common;
 
-use doc_engine_scan::{scan_with_config, format_report_text, format_report_json, ScanConfig, ScanReport, ProjectScope};
+use std::collections::HashMap;
+
+use doc_engine_scan::{scan_with_config, format_report_text, format_report_json, report_schema, CheckResult, ScanConfig, ScanReport, ProjectScope};
 
 #[test]
 fn test_text_contains_header() {
@@ -12,6 +14,11 @@ fn test_text_contains_header() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     let text = format_report_text(&report);
@@ -28,6 +35,11 @@ fn test_text_contains_summary() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     let text = format_report_text(&report);
@@ -46,6 +58,11 @@ fn test_json_valid() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     let json = format_report_json(&report);
@@ -63,6 +80,11 @@ fn test_json_has_results_and_summary() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     let json = format_report_json(&report);
@@ -90,6 +112,11 @@ fn test_json_roundtrip() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     let json = format_report_json(&report);
@@ -107,3 +134,31 @@ fn test_json_roundtrip() {
     assert_eq!(deserialized.timestamp, report.timestamp);
     assert_eq!(deserialized.project_root, report.project_root);
 }
+
+#[test]
+fn test_json_matches_report_schema() {
+    let tmp = common::create_minimal_project();
+    let config = ScanConfig {
+        project_type: None,
+        project_scope: ProjectScope::Large,
+        checks: None,
+        rules_path: None,
+        phases: None,
+        module_filter: None,
+        cache_dir: Some(tmp.path().join("cache")),
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(report.results.iter().any(|e| matches!(e.result, CheckResult::Fail { .. })));
+
+    let schema = report_schema();
+    let validator = jsonschema::JSONSchema::compile(&schema).unwrap();
+    let instance: serde_json::Value = serde_json::from_str(&format_report_json(&report)).unwrap();
+    if let Err(errors) = validator.validate(&instance) {
+        let errors: Vec<String> = errors.map(|e| format!("{} at {}", e, e.instance_path)).collect();
+        panic!("report does not match its schema: {:?}", errors);
+    };
+}
@@ -140,6 +140,90 @@ fn e2e_scaffold_force_flag() {
         .stdout(predicate::str::contains("0 skipped"));
 }
 
+#[test]
+fn e2e_scaffold_dry_run_flag() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let srs_path = tmp.path().join("srs.md");
+    fs::write(&srs_path, FIXTURE_SRS).unwrap();
+
+    let output_dir = tmp.path().join("output");
+
+    cmd()
+        .arg("scaffold")
+        .arg(&srs_path)
+        .arg("--output")
+        .arg(&output_dir)
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[dry-run]   + docs/1-requirements/brd.spec.yaml"))
+        .stdout(predicate::str::contains("23 files would be created"))
+        .stdout(predicate::str::contains("0 skipped"));
+
+    assert!(!output_dir.exists());
+
+    // A real run creates exactly what was planned
+    cmd()
+        .arg("scaffold")
+        .arg(&srs_path)
+        .arg("--output")
+        .arg(&output_dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("23 files created"));
+}
+
+#[test]
+fn e2e_scaffold_merge_flag() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let srs_path = tmp.path().join("srs.md");
+    fs::write(&srs_path, FIXTURE_SRS).unwrap();
+
+    let output_dir = tmp.path().join("output");
+    cmd()
+        .arg("scaffold")
+        .arg(&srs_path)
+        .arg("--output")
+        .arg(&output_dir)
+        .assert()
+        .success();
+
+    let plan = output_dir.join("docs/5-testing/test_plan.md");
+    fs::write(&plan, "# Team notes\n\n<!-- BEGIN GENERATED -->\nold\n<!-- END GENERATED -->\n").unwrap();
+
+    cmd()
+        .arg("scaffold")
+        .arg(&srs_path)
+        .arg("--output")
+        .arg(&output_dir)
+        .arg("--merge")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("  * docs/5-testing/test_plan.md"))
+        .stdout(predicate::str::contains("1 merged, 22 skipped"))
+        .stderr(predicate::str::contains("Note: docs/1-requirements/brd.spec.yaml: no"));
+
+    let merged = fs::read_to_string(&plan).unwrap();
+    assert!(merged.starts_with("# Team notes\n\n<!-- BEGIN GENERATED -->\n"));
+    assert!(!merged.contains("\nold\n"));
+}
+
+#[test]
+fn e2e_scaffold_merge_conflicts_with_force() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let srs_path = tmp.path().join("srs.md");
+    fs::write(&srs_path, FIXTURE_SRS).unwrap();
+
+    cmd()
+        .arg("scaffold")
+        .arg(&srs_path)
+        .arg("--force")
+        .arg("--merge")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
 #[test]
 fn e2e_scaffold_short_output_flag() {
     let tmp = tempfile::TempDir::new().unwrap();
@@ -187,6 +271,56 @@ fn e2e_scaffold_empty_srs() {
         .stderr(predicate::str::contains("no domains"));
 }
 
+#[test]
+fn e2e_scaffold_srs_from_stdin() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let output_dir = tmp.path().join("output");
+
+    cmd()
+        .arg("scaffold")
+        .arg("-")
+        .arg("--output")
+        .arg(&output_dir)
+        .write_stdin(FIXTURE_SRS)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 domains"))
+        .stdout(predicate::str::contains("3 requirements"))
+        .stdout(predicate::str::contains("23 files created"));
+
+    assert!(output_dir.join("docs/1-requirements/rule_loading/rule_loading.spec.yaml").exists());
+}
+
+#[test]
+fn e2e_scaffold_srs_from_empty_stdin() {
+    let tmp = tempfile::TempDir::new().unwrap();
+
+    cmd()
+        .arg("scaffold")
+        .arg("-")
+        .arg("--output")
+        .arg(tmp.path().join("out"))
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("SRS content from stdin is empty"));
+}
+
+#[test]
+fn e2e_scaffold_srs_from_stdin_not_srs() {
+    let tmp = tempfile::TempDir::new().unwrap();
+
+    cmd()
+        .arg("scaffold")
+        .arg("-")
+        .arg("--output")
+        .arg(tmp.path().join("out"))
+        .write_stdin("just some text\nwith no headings\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("no domains"));
+}
+
 #[test]
 fn e2e_scaffold_large_srs() {
     let tmp = tempfile::TempDir::new().unwrap();
@@ -1259,3 +1393,28 @@ fn e2e_scaffold_without_exclude_includes_gated() {
     assert!(output_dir.join("docs/1-requirements/ai_powered_compliance_analysis").is_dir());
     assert!(output_dir.join("docs/1-requirements/experimental_subsystem").is_dir());
 }
+
+#[test]
+fn e2e_scaffold_frontmatter_and_table_requirements() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let srs_path = tmp.path().join("srs.md");
+    fs::write(&srs_path, FRONTMATTER_MIXED_SRS).unwrap();
+
+    let output_dir = tmp.path().join("output");
+
+    cmd()
+        .arg("scaffold")
+        .arg(&srs_path)
+        .arg("--output")
+        .arg(&output_dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 domains"))
+        .stdout(predicate::str::contains("3 requirements"));
+
+    let yaml = fs::read_to_string(
+        output_dir.join("docs/1-requirements/cli_interface/cli_interface.spec.yaml"),
+    ).unwrap();
+    assert!(yaml.contains("CLI accepts scan subcommand"));
+    assert!(yaml.contains("--json emits JSON"));
+}
@@ -304,6 +304,46 @@ All operations synchronous.
 The --json flag enables JSON output.
 ";
 
+/// Fixture mixing YAML frontmatter and attribute-table requirements in one domain.
+pub const FRONTMATTER_MIXED_SRS: &str = "\
+### 4.1 CLI Interface
+
+#### FR-500: Scan command
+
+---
+priority: Must
+state: Approved
+verification: Demonstration
+traces_to: STK-01 -> cli/src/main.rs
+acceptance: CLI accepts scan subcommand
+---
+
+The CLI shall accept a scan subcommand.
+
+#### FR-501: JSON flag
+
+| Attribute | Value |
+|-----------|-------|
+| **Priority** | Should |
+| **State** | Proposed |
+| **Verification** | Test |
+| **Acceptance** | --json emits JSON |
+
+The --json flag enables JSON output.
+
+#### NFR-200: Synchronous execution
+
+---
+priority: Could
+verification: Inspection
+traces_to:
+  - STK-03
+  - STK-04
+---
+
+All operations synchronous.
+";
+
 /// Fixture with 4 domains: 2 regular + 1 title-based feature-gated + 1 narrative-based feature-gated.
 pub const FEATURE_GATED_FIXTURE_SRS: &str = "\
 ### 4.1 Rule Loading
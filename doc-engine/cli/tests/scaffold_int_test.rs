@@ -24,6 +24,9 @@ fn scaffold_to_tmp(srs_content: &str) -> (tempfile::TempDir, PathBuf, ScaffoldCo
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     (tmp, output_dir, config)
 }
@@ -47,6 +50,9 @@ fn test_parser_crlf_line_endings() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -88,6 +94,9 @@ Additional narrative after the inner table.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -121,6 +130,9 @@ Description with accented characters: éàü.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -159,6 +171,9 @@ Desc B.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -200,6 +215,9 @@ All checks in a single traversal.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -211,8 +229,9 @@ All checks in a single traversal.
         tmp.path().join("out/docs/1-requirements/performance/performance.spec.yaml"),
     ).unwrap();
     let val: serde_yml::Value = serde_yml::from_str(&yaml).unwrap();
-    assert_eq!(val["requirements"].as_sequence().unwrap().len(), 2);
-    assert_eq!(val["requirements"][0]["sourceId"], "NFR-200");
+    assert!(val["requirements"].as_sequence().unwrap().is_empty());
+    assert_eq!(val["nonFunctionalRequirements"].as_sequence().unwrap().len(), 2);
+    assert_eq!(val["nonFunctionalRequirements"][0]["sourceId"], "NFR-200");
 }
 
 #[test]
@@ -240,6 +259,9 @@ fn test_parser_many_domains_file_count() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -271,6 +293,9 @@ fn test_parse_real_project_srs() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -364,6 +389,9 @@ Support 128 checks.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -412,6 +440,9 @@ Desc B.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -437,6 +468,9 @@ fn test_error_empty_srs() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let err = scaffold_from_srs(&config).unwrap_err();
@@ -471,6 +505,9 @@ No FR/NFR blocks at all.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let err = scaffold_from_srs(&config).unwrap_err();
@@ -488,6 +525,9 @@ fn test_error_nonexistent_srs_path() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let err = scaffold_from_srs(&config).unwrap_err();
@@ -948,14 +988,91 @@ fn test_default_attribute_values_in_yaml() {
     assert_eq!(reqs[0]["acceptance"], "CLI accepts scan subcommand");
 
     // NFR-200: only has Priority, rest should get defaults
-    assert_eq!(reqs[1]["priority"], "Must");
-    assert_eq!(reqs[1]["status"], "Proposed"); // default
-    assert_eq!(reqs[1]["verification"], "Test"); // default
-    assert_eq!(reqs[1]["acceptance"], ""); // default empty
+    let nfrs = val["nonFunctionalRequirements"].as_sequence().unwrap();
+    assert_eq!(nfrs[0]["id"], "REQ-002");
+    assert_eq!(nfrs[0]["priority"], "Must");
+    assert_eq!(nfrs[0]["status"], "Proposed"); // default
+    assert_eq!(nfrs[0]["verification"], "Test"); // default
+    assert_eq!(nfrs[0]["acceptance"], ""); // default empty
 
     // FR-501: has Priority + State, rest defaults
-    assert_eq!(reqs[2]["status"], "Proposed");
-    assert_eq!(reqs[2]["verification"], "Test"); // default
+    assert_eq!(reqs[1]["id"], "REQ-003");
+    assert_eq!(reqs[1]["status"], "Proposed");
+    assert_eq!(reqs[1]["verification"], "Test"); // default
+}
+
+#[test]
+fn test_frontmatter_and_table_requirements_in_yaml() {
+    let (_tmp, output_dir, config) = scaffold_to_tmp(FRONTMATTER_MIXED_SRS);
+    let result = scaffold_from_srs(&config).unwrap();
+    assert_eq!(result.domain_count, 1);
+    assert_eq!(result.requirement_count, 3);
+
+    let yaml = fs::read_to_string(
+        output_dir.join("docs/1-requirements/cli_interface/cli_interface.spec.yaml"),
+    ).unwrap();
+    let val: serde_yml::Value = serde_yml::from_str(&yaml).unwrap();
+    let reqs = val["requirements"].as_sequence().unwrap();
+    assert_eq!(reqs.len(), 2);
+    let nfrs = val["nonFunctionalRequirements"].as_sequence().unwrap();
+    assert_eq!(nfrs.len(), 1);
+
+    // FR-500: frontmatter
+    assert_eq!(reqs[0]["priority"], "Must");
+    assert_eq!(reqs[0]["status"], "Approved");
+    assert_eq!(reqs[0]["verification"], "Demonstration");
+    assert_eq!(reqs[0]["acceptance"], "CLI accepts scan subcommand");
+
+    // FR-501: attribute table
+    assert_eq!(reqs[1]["priority"], "Should");
+    assert_eq!(reqs[1]["status"], "Proposed");
+    assert_eq!(reqs[1]["verification"], "Test");
+    assert_eq!(reqs[1]["acceptance"], "--json emits JSON");
+
+    // NFR-200: frontmatter with defaults for missing keys
+    assert_eq!(nfrs[0]["sourceId"], "NFR-200");
+    assert_eq!(nfrs[0]["priority"], "Could");
+    assert_eq!(nfrs[0]["status"], "Proposed"); // default
+    assert_eq!(nfrs[0]["verification"], "Inspection");
+}
+
+#[test]
+fn test_brd_lists_requirement_kinds() {
+    // MIXED_ATTRS_SRS: FR-500, NFR-200, FR-501
+    let (_tmp, output_dir, config) = scaffold_to_tmp(MIXED_ATTRS_SRS);
+    scaffold_from_srs(&config).unwrap();
+
+    let yaml = fs::read_to_string(output_dir.join("docs/1-requirements/brd.spec.yaml")).unwrap();
+    let val: serde_yml::Value = serde_yml::from_str(&yaml).unwrap();
+    let reqs = val["domains"][0]["requirements"].as_sequence().unwrap();
+    let kinds: Vec<(&str, &str)> = reqs.iter()
+        .map(|r| (r["id"].as_str().unwrap(), r["kind"].as_str().unwrap()))
+        .collect();
+    assert_eq!(kinds, vec![
+        ("FR-500", "functional"),
+        ("NFR-200", "non-functional"),
+        ("FR-501", "functional"),
+    ]);
+
+    let md = fs::read_to_string(output_dir.join("docs/1-requirements/brd.spec")).unwrap();
+    assert!(md.contains("  - FR-500 (functional): Scan command"));
+    assert!(md.contains("  - NFR-200 (non-functional): Synchronous execution"));
+    assert!(md.contains("  - FR-501 (functional): JSON flag"));
+}
+
+#[test]
+fn test_feature_spec_markdown_groups_by_kind() {
+    let (_tmp, output_dir, config) = scaffold_to_tmp(MIXED_ATTRS_SRS);
+    scaffold_from_srs(&config).unwrap();
+
+    let md = fs::read_to_string(
+        output_dir.join("docs/1-requirements/cli_interface/cli_interface.spec"),
+    ).unwrap();
+    let functional = md.find("### Functional").expect("functional section");
+    let non_functional = md.find("### Non-Functional").expect("non-functional section");
+    assert!(functional < non_functional);
+    assert!(md[functional..non_functional].contains("| REQ-003 | FR-501 |"));
+    assert!(md[non_functional..].contains("| REQ-002 | NFR-200 |"));
 }
 
 #[test]
@@ -1056,6 +1173,9 @@ Report.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1189,6 +1309,9 @@ Exit code behavior.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     scaffold_from_srs(&config).unwrap();
 
@@ -1528,6 +1651,9 @@ fn test_force_overwrite_updates_content() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     // First run
@@ -1572,6 +1698,9 @@ fn test_skip_existing_mixed_scenario() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1603,6 +1732,9 @@ fn test_output_dir_created_automatically() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1628,6 +1760,9 @@ fn test_phase_filter_single_testing() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1668,6 +1803,9 @@ fn test_phase_filter_requirements_includes_brd() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1696,6 +1834,9 @@ fn test_phase_filter_multiple_phases() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1729,6 +1870,9 @@ fn test_phase_filter_deployment_only() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1756,6 +1900,9 @@ fn test_phase_filter_with_force() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     scaffold_from_srs(&config_all).unwrap();
 
@@ -1769,6 +1916,9 @@ fn test_phase_filter_with_force() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result = scaffold_from_srs(&config_phase).unwrap();
 
@@ -1793,6 +1943,9 @@ fn test_phase_filter_design_only() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1827,6 +1980,9 @@ fn test_phase_filter_all_four_equals_no_filter() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result_all = scaffold_from_srs(&config_all).unwrap();
 
@@ -1845,6 +2001,9 @@ fn test_phase_filter_all_four_equals_no_filter() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result_explicit = scaffold_from_srs(&config_explicit).unwrap();
 
@@ -1942,6 +2101,9 @@ fn test_phase_filter_skip_existing_in_filtered_phase() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1973,6 +2135,9 @@ fn test_phase_filter_skip_then_force() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let r1 = scaffold_from_srs(&config1).unwrap();
     assert_eq!(r1.created.len(), 4);
@@ -1987,6 +2152,9 @@ fn test_phase_filter_skip_then_force() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let r2 = scaffold_from_srs(&config2).unwrap();
     assert_eq!(r2.skipped.len(), 4);
@@ -2002,6 +2170,9 @@ fn test_phase_filter_skip_then_force() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let r3 = scaffold_from_srs(&config3).unwrap();
     assert_eq!(r3.created.len(), 4);
@@ -2026,6 +2197,9 @@ fn test_phase_filter_yaml_files_valid() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
         };
         let result = scaffold_from_srs(&config).unwrap();
 
@@ -2169,6 +2343,9 @@ Scans complete in under one second.
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -2203,6 +2380,9 @@ fn test_phase_filter_mixed_attrs_design() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -2242,6 +2422,9 @@ fn test_phase_filter_result_counts_formula() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
         };
         let result = scaffold_from_srs(&config).unwrap();
 
@@ -2269,6 +2452,9 @@ fn test_type_filter_exec_only() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result = scaffold_from_srs(&config).unwrap();
 
@@ -2298,6 +2484,9 @@ fn test_type_filter_yaml_only() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result = scaffold_from_srs(&config).unwrap();
 
@@ -2328,6 +2517,9 @@ fn test_type_filter_multiple() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result = scaffold_from_srs(&config).unwrap();
 
@@ -2357,6 +2549,9 @@ fn test_type_filter_empty_means_all() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result = scaffold_from_srs(&config).unwrap();
 
@@ -2379,6 +2574,9 @@ fn test_type_filter_combined_with_phase() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result = scaffold_from_srs(&config).unwrap();
 
@@ -2422,6 +2620,9 @@ fn test_phase_filter_metadata_unchanged() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
         };
         let result = scaffold_from_srs(&config).unwrap();
 
@@ -2477,6 +2678,9 @@ fn test_scaffold_result_json_with_phase_filter() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     let result = scaffold_from_srs(&config).unwrap();
     let json = serde_json::to_string_pretty(&result).unwrap();
@@ -2507,6 +2711,9 @@ fn test_exclude_features_blanket_excludes_all_gated() {
         features: vec![],
         exclude_features: Some(vec![]),
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -2534,6 +2741,9 @@ fn test_exclude_features_targeted_excludes_specific() {
         features: vec![],
         exclude_features: Some(vec!["ai".into()]),
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -2561,6 +2771,9 @@ fn test_features_positive_filter_only_ai() {
         features: vec!["ai".into()],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -2597,6 +2810,9 @@ FR-701 = \"doc-engine scaffold srs.md\"
         features: vec![],
         exclude_features: None,
         command_map_path: Some(cmd_map_path),
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     scaffold_from_srs(&config).unwrap();
 
@@ -2656,6 +2872,9 @@ FR-701 = \"doc-engine scaffold srs.md\"
         features: vec![],
         exclude_features: None,
         command_map_path: Some(cmd_map_path),
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
     scaffold_from_srs(&config).unwrap();
 
@@ -2731,6 +2950,9 @@ fn test_features_filter_default_includes_all() {
         features: vec![],
         exclude_features: None,
         command_map_path: None,
+        template_dir: None,
+        dry_run: false,
+        merge: false,
     };
 
     let result = scaffold_from_srs(&config).unwrap();
@@ -1,5 +1,7 @@
 mod common;
 
+use std::collections::HashMap;
+
 use doc_engine_scan::{default_rule_count, scan_with_config, ScanConfig, ProjectScope, ProjectType, CheckResult};
 
 #[test]
@@ -12,6 +14,11 @@ fn test_scan_minimal_project() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     // A minimal compliant project should have many passes
@@ -36,6 +43,11 @@ fn test_scan_report_iso15289_metadata() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
 
@@ -66,6 +78,11 @@ fn test_scan_report_project_root_matches_input() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     // project_root should contain the temp dir path
@@ -88,6 +105,11 @@ fn test_scan_empty_dir() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     // Should have many failures but no panics
@@ -105,11 +127,49 @@ fn test_scan_returns_all_checks() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), default_rule_count());
 }
 
+#[test]
+fn test_parallel_scan_is_deterministic() {
+    let tmp = common::create_minimal_project();
+    let run = |jobs: Option<usize>| {
+        let config = ScanConfig {
+            project_type: None,
+            project_scope: ProjectScope::Large,
+            checks: None,
+            rules_path: None,
+            phases: None,
+            module_filter: None,
+            cache_dir: None,
+            jobs,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
+        };
+        let mut report = scan_with_config(tmp.path(), &config).unwrap();
+        // Timestamp and wall-clock durations are the only fields expected to differ between runs
+        report.timestamp = String::new();
+        report.duration_ms = 0;
+        for entry in &mut report.results {
+            entry.duration_ms = None;
+        }
+        serde_json::to_string_pretty(&report).unwrap()
+    };
+
+    let first = run(None);
+    assert_eq!(run(None), first);
+    assert_eq!(run(None), first);
+    assert_eq!(run(Some(1)), first, "single-threaded scan must match the parallel one");
+}
+
 #[test]
 fn test_traceability_checks_pass_minimal() {
     let tmp = common::create_minimal_project();
@@ -120,6 +180,11 @@ fn test_traceability_checks_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 3);
@@ -141,6 +206,11 @@ fn test_traceability_checks_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 3);
@@ -162,6 +232,11 @@ fn test_backlog_checks_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 3);
@@ -183,6 +258,11 @@ fn test_module_checks_pass_no_modules() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 5);
@@ -204,6 +284,11 @@ fn test_internal_usage_skip_open_source() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -223,6 +308,11 @@ fn test_planning_checks_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 6);
@@ -244,6 +334,11 @@ fn test_srs_29148_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -263,6 +358,11 @@ fn test_srs_29148_fail_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -282,6 +382,11 @@ fn test_arch_42010_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -301,6 +406,11 @@ fn test_arch_42010_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -320,6 +430,11 @@ fn test_test_29119_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -339,6 +454,11 @@ fn test_test_29119_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -358,6 +478,11 @@ fn test_dev_guide_26514_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -377,6 +502,11 @@ fn test_dev_guide_26514_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -396,6 +526,11 @@ fn test_backlog_sections_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -415,6 +550,11 @@ fn test_backlog_sections_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -436,6 +576,11 @@ fn test_backlog_sections_fail_missing_sections() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -458,6 +603,11 @@ fn test_backlog_existence_and_sections_combined() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 3);
@@ -479,6 +629,11 @@ fn test_prod_12207_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -498,6 +653,11 @@ fn test_prod_12207_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -517,6 +677,11 @@ fn test_prod_25010_supp_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -536,6 +701,11 @@ fn test_prod_25010_supp_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -555,6 +725,11 @@ fn test_prod_25040_pass_minimal() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -574,6 +749,11 @@ fn test_prod_25040_skip_empty() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -595,6 +775,11 @@ fn test_prod_12207_fail_missing_sections() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -619,6 +804,11 @@ fn test_prod_25010_supp_fail_missing_sections() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -643,6 +833,11 @@ fn test_prod_25040_fail_missing_sections() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 1);
@@ -665,6 +860,11 @@ fn test_prod_readiness_all_checks_combined() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 5);
@@ -686,6 +886,11 @@ fn test_scan_summary_math() {
         rules_path: None,
         phases: None,
         module_filter: None,
+        cache_dir: None,
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::types::{
+    CommandGeneratorError, GenerateCommandsResponse, RequirementContext, SkippedRequirement,
+};
+
+/// Reason recorded in `GenerateCommandsResponse::skipped` for a cache hit.
+pub const CACHED_REASON: &str = "cached";
+
+/// 64-bit FNV-1a hash rendered as hex; stable across builds and platforms.
+fn fnv1a(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Hash of the requirement fields that determine its generated command.
+pub fn requirement_hash(req: &RequirementContext) -> String {
+    fnv1a(format!("{}\0{}\0{}", req.id, req.acceptance, req.verification).as_bytes())
+}
+
+/// One cached LLM answer: a command or an `INSPECTION:`/`ANALYSIS:`/`SKIP:` line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedCommand {
+    hash: String,
+    output: String,
+}
+
+/// On-disk cache of generated commands keyed by requirement ID.
+///
+/// An entry is reused only while the requirement's ID, acceptance criteria
+/// and verification method hash to the value it was generated from.
+#[derive(Debug)]
+pub struct CommandCache {
+    path: PathBuf,
+    entries: HashMap<String, CachedCommand>,
+}
+
+impl CommandCache {
+    /// Cache file stored next to a command map: `commands.toml` caches in
+    /// `commands.cache.json`.
+    pub fn path_for(command_map: &Path) -> PathBuf {
+        command_map.with_extension("cache.json")
+    }
+
+    /// Load the cache from `path`; a missing or unreadable file starts empty.
+    pub fn load(path: &Path) -> Self {
+        let entries = fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        Self { path: path.to_path_buf(), entries }
+    }
+
+    /// The cached output for `req`, if it is unchanged since it was stored.
+    pub fn lookup(&self, req: &RequirementContext) -> Option<&str> {
+        self.entries
+            .get(&req.id)
+            .filter(|entry| entry.hash == requirement_hash(req))
+            .map(|entry| entry.output.as_str())
+    }
+
+    /// Record the LLM's answers for `requirements`. Requirements the response
+    /// does not mention are left uncached.
+    pub fn record(&mut self, requirements: &[RequirementContext], response: &GenerateCommandsResponse) {
+        for req in requirements {
+            let output = response.commands.get(&req.id).cloned().or_else(|| {
+                response.skipped.iter()
+                    .find(|s| s.id == req.id && s.reason != CACHED_REASON)
+                    .map(|s| s.reason.clone())
+            });
+            if let Some(output) = output {
+                self.entries.insert(req.id.clone(), CachedCommand { hash: requirement_hash(req), output });
+            }
+        }
+    }
+
+    /// Split `requirements` into a response built from cache hits and the
+    /// requirements that still need the LLM.
+    ///
+    /// Every hit is listed under `skipped` with reason [`CACHED_REASON`];
+    /// cached commands also appear under `commands`.
+    pub fn partition(
+        &self,
+        requirements: &[RequirementContext],
+    ) -> (GenerateCommandsResponse, Vec<RequirementContext>) {
+        let mut hits = GenerateCommandsResponse { commands: HashMap::new(), skipped: Vec::new() };
+        let mut misses = Vec::new();
+        for req in requirements {
+            match self.lookup(req) {
+                Some(output) => {
+                    if !is_non_command(output) {
+                        hits.commands.insert(req.id.clone(), output.to_string());
+                    }
+                    hits.skipped.push(SkippedRequirement { id: req.id.clone(), reason: CACHED_REASON.to_string() });
+                }
+                None => misses.push(req.clone()),
+            }
+        }
+        (hits, misses)
+    }
+
+    /// Write the cache back to its file.
+    pub fn save(&self) -> Result<(), CommandGeneratorError> {
+        let json = serde_json::to_string_pretty(&self.entries)
+            .map_err(|e| CommandGeneratorError::Serialization(e.to_string()))?;
+        fs::write(&self.path, json).map_err(|e| {
+            CommandGeneratorError::Io(format!("cannot write '{}': {}", self.path.display(), e))
+        })
+    }
+}
+
+/// True for the LLM's non-command answers.
+pub(crate) fn is_non_command(output: &str) -> bool {
+    output.starts_with("INSPECTION:") || output.starts_with("ANALYSIS:") || output.starts_with("SKIP:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req(id: &str, acceptance: &str) -> RequirementContext {
+        RequirementContext {
+            id: id.to_string(),
+            title: "Title".to_string(),
+            verification: "Test".to_string(),
+            acceptance: acceptance.to_string(),
+            traces_to: String::new(),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_hash_ignores_title_but_not_acceptance() {
+        let mut retitled = req("FR-100", "loads rules");
+        retitled.title = "Other".to_string();
+        assert_eq!(requirement_hash(&req("FR-100", "loads rules")), requirement_hash(&retitled));
+        assert_ne!(requirement_hash(&req("FR-100", "loads rules")), requirement_hash(&req("FR-100", "loads more")));
+    }
+
+    #[test]
+    fn test_record_save_and_reload() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = CommandCache::path_for(&tmp.path().join("commands.toml"));
+        assert_eq!(path.file_name().unwrap(), "commands.cache.json");
+
+        let reqs = vec![req("FR-100", "a"), req("NFR-100", "b")];
+        let response = GenerateCommandsResponse {
+            commands: HashMap::from([("FR-100".to_string(), "cargo test rules".to_string())]),
+            skipped: vec![SkippedRequirement { id: "NFR-100".to_string(), reason: "INSPECTION: layout".to_string() }],
+        };
+        let mut cache = CommandCache::load(&path);
+        cache.record(&reqs, &response);
+        cache.save().unwrap();
+
+        let (hits, misses) = CommandCache::load(&path).partition(&[req("FR-100", "a"), req("NFR-100", "b"), req("FR-200", "c")]);
+        assert_eq!(hits.commands.len(), 1);
+        assert_eq!(hits.commands["FR-100"], "cargo test rules");
+        assert_eq!(hits.skipped.len(), 2);
+        assert!(hits.skipped.iter().all(|s| s.reason == CACHED_REASON));
+        assert_eq!(misses.len(), 1);
+        assert_eq!(misses[0].id, "FR-200");
+    }
+}
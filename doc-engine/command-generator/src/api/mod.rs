@@ -1,8 +1,10 @@
 pub mod types;
 pub mod service;
+pub mod cache;
 
 pub use types::{
     CommandGeneratorConfig, CommandGeneratorError,
     GenerateCommandsRequest, GenerateCommandsResponse, RequirementContext, SkippedRequirement,
 };
-pub use service::CommandGenerator;
+pub use service::{CommandGenerator, CompletionBackend};
+pub use cache::CommandCache;
@@ -1,18 +1,46 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_trait::async_trait;
+
+use crate::api::cache::{is_non_command, CommandCache};
 use crate::api::types::{
     CommandGeneratorConfig, CommandGeneratorError,
     GenerateCommandsRequest, GenerateCommandsResponse, SkippedRequirement,
 };
 
+/// Completion backend the generator sends its prompts to.
+#[async_trait]
+pub trait CompletionBackend: Send + Sync {
+    /// Complete a single system + user prompt and return the raw reply.
+    async fn complete(&self, system: &str, user: &str) -> Result<String, CommandGeneratorError>;
+}
+
+/// [`CompletionBackend`] backed by the configured LLM provider.
+struct LlmBackend {
+    llm: Arc<dyn llm_provider::LlmService>,
+    model: String,
+}
+
+#[async_trait]
+impl CompletionBackend for LlmBackend {
+    async fn complete(&self, system: &str, user: &str) -> Result<String, CommandGeneratorError> {
+        let response = llm_provider::CompletionBuilder::new(&self.model)
+            .system(system)
+            .user(user)
+            .execute(&*self.llm)
+            .await
+            .map_err(|e| CommandGeneratorError::Llm(e.to_string()))?;
+        Ok(response.content.unwrap_or_default())
+    }
+}
+
 /// LLM-powered test command generator.
 ///
 /// Generates CLI commands to verify SRS requirements by sending batches
 /// of requirement contexts to an LLM.
 pub struct CommandGenerator {
-    llm: Arc<dyn llm_provider::LlmService>,
-    config: CommandGeneratorConfig,
+    backend: Arc<dyn CompletionBackend>,
 }
 
 impl CommandGenerator {
@@ -38,7 +66,13 @@ impl CommandGenerator {
                 .map_err(|e| CommandGeneratorError::Init(e.to_string()))?,
         );
 
-        Ok(Self { llm, config })
+        Ok(Self::with_backend(Arc::new(LlmBackend { llm, model: config.model })))
+    }
+
+    /// Create a generator that sends its prompts to `backend` instead of the
+    /// environment-configured LLM.
+    pub fn with_backend(backend: Arc<dyn CompletionBackend>) -> Self {
+        Self { backend }
     }
 
     /// Generate test commands for requirements that are missing them.
@@ -60,16 +94,36 @@ You are a test automation engineer for a Rust workspace. \
 You generate precise CLI commands to verify software requirements. \
 You respond ONLY with a JSON object — no commentary, no markdown fences.";
 
-        let response = llm_provider::CompletionBuilder::new(&self.config.model)
-            .system(system_prompt)
-            .user(&prompt)
-            .execute(&*self.llm)
-            .await
-            .map_err(|e| CommandGeneratorError::Llm(e.to_string()))?;
-
-        let raw = response.content.unwrap_or_default();
+        let raw = self.backend.complete(system_prompt, &prompt).await?;
         parse_generate_commands_response(&raw)
     }
+
+    /// Generate test commands, reusing `cache` for unchanged requirements.
+    ///
+    /// Only cache misses are sent to the LLM, and their answers are recorded
+    /// in `cache` (the caller saves it). Hits are listed under `skipped` with
+    /// reason `"cached"`; cached commands are also returned under `commands`.
+    pub async fn generate_commands_cached(
+        &self,
+        request: &GenerateCommandsRequest,
+        cache: &mut CommandCache,
+    ) -> Result<GenerateCommandsResponse, CommandGeneratorError> {
+        let (mut response, misses) = cache.partition(&request.requirements);
+        if misses.is_empty() {
+            return Ok(response);
+        }
+
+        let miss_request = GenerateCommandsRequest {
+            requirements: misses,
+            project_context: request.project_context.clone(),
+        };
+        let generated = self.generate_commands(&miss_request).await?;
+        cache.record(&miss_request.requirements, &generated);
+
+        response.commands.extend(generated.commands);
+        response.skipped.extend(generated.skipped);
+        Ok(response)
+    }
 }
 
 /// Build the batch prompt that lists all requirements for the LLM.
@@ -134,7 +188,7 @@ pub fn parse_generate_commands_response(
 
     for (id, val) in commands_obj {
         let cmd = val.as_str().unwrap_or_default().to_string();
-        if cmd.starts_with("INSPECTION:") || cmd.starts_with("ANALYSIS:") || cmd.starts_with("SKIP:") {
+        if is_non_command(&cmd) {
             skipped.push(SkippedRequirement {
                 id: id.clone(),
                 reason: cmd,
@@ -169,8 +223,32 @@ pub fn extract_json_block(raw: &str) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use crate::api::types::RequirementContext;
 
+    /// Answers for the sample requirements named in the prompt; counts calls.
+    struct StubBackend {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl CompletionBackend for StubBackend {
+        async fn complete(&self, _system: &str, user: &str) -> Result<String, CommandGeneratorError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let mut commands = serde_json::Map::new();
+            for (id, answer) in [
+                ("FR-100", "cargo test -p doc-engine-scan rules"),
+                ("FR-200", "cargo run -- scan ."),
+                ("NFR-100", "INSPECTION: review crate layout"),
+            ] {
+                if user.contains(&format!("## {}:", id)) {
+                    commands.insert(id.to_string(), answer.into());
+                }
+            }
+            Ok(serde_json::json!({ "commands": commands }).to_string())
+        }
+    }
+
     fn sample_requirements() -> Vec<RequirementContext> {
         vec![
             RequirementContext {
@@ -271,4 +349,37 @@ mod tests {
         assert!(resp.commands.is_empty());
         assert_eq!(resp.skipped.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_cached_second_run_makes_no_llm_calls() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cache_path = CommandCache::path_for(&tmp.path().join("commands.toml"));
+        let stub = Arc::new(StubBackend { calls: AtomicUsize::new(0) });
+        let generator = CommandGenerator::with_backend(stub.clone());
+        let request = GenerateCommandsRequest {
+            requirements: sample_requirements(),
+            project_context: "Rust workspace".to_string(),
+        };
+
+        let mut cache = CommandCache::load(&cache_path);
+        let first = generator.generate_commands_cached(&request, &mut cache).await.unwrap();
+        cache.save().unwrap();
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.commands.len(), 2);
+
+        let mut cache = CommandCache::load(&cache_path);
+        let second = generator.generate_commands_cached(&request, &mut cache).await.unwrap();
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.commands, first.commands);
+        assert_eq!(second.skipped.len(), 3);
+        assert!(second.skipped.iter().all(|s| s.reason == "cached"));
+
+        // A changed acceptance criterion re-queries only that requirement
+        let mut changed = request.clone();
+        changed.requirements[0].acceptance = "Engine loads embedded rules at startup".to_string();
+        let third = generator.generate_commands_cached(&changed, &mut cache).await.unwrap();
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(third.skipped.iter().filter(|s| s.reason == "cached").count(), 2);
+        assert_eq!(third.commands["FR-100"], "cargo test -p doc-engine-scan rules");
+    }
 }
@@ -58,6 +58,8 @@ pub enum CommandGeneratorError {
     Serialization(String),
     /// Invalid argument.
     InvalidArgument(String),
+    /// Reading or writing the command cache failed.
+    Io(String),
 }
 
 impl fmt::Display for CommandGeneratorError {
@@ -68,6 +70,7 @@ impl fmt::Display for CommandGeneratorError {
             Self::Llm(msg) => write!(f, "LLM error: {}", msg),
             Self::Serialization(msg) => write!(f, "serialization error: {}", msg),
             Self::InvalidArgument(msg) => write!(f, "invalid argument: {}", msg),
+            Self::Io(msg) => write!(f, "I/O error: {}", msg),
         }
     }
 }
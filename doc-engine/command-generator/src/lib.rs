@@ -1,6 +1,6 @@
 pub mod api;
 
 pub use api::{
-    CommandGenerator, CommandGeneratorConfig, CommandGeneratorError,
+    CommandCache, CommandGenerator, CommandGeneratorConfig, CommandGeneratorError, CompletionBackend,
     GenerateCommandsRequest, GenerateCommandsResponse, RequirementContext, SkippedRequirement,
 };
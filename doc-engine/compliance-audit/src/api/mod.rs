@@ -1,5 +1,5 @@
 pub mod types;
 pub mod service;
 
-pub use types::{AuditError, AuditResponse};
+pub use types::{AuditError, AuditFinding, AuditResponse};
 pub use service::ComplianceAuditor;
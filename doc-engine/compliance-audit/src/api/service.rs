@@ -5,6 +5,7 @@ use tool::Tool;
 
 use crate::api::types::{AuditError, AuditResponse};
 use crate::core::agents::AuditAgentManager;
+use crate::core::findings::{self, FINDINGS_INSTRUCTIONS};
 use crate::core::tools::ComplianceScanTool;
 use crate::spi::AuditConfig;
 
@@ -71,7 +72,8 @@ impl ComplianceAuditor {
         let analysis_prompt = format!(
             "Analyse the following compliance scan results. \
              Summarise the compliance status, prioritise failures by severity, \
-             and list actionable recommendations:\n\n{}",
+             and list actionable recommendations.\n\n{}\n\nScan results:\n\n{}",
+            FINDINGS_INSTRUCTIONS,
             serde_json::to_string_pretty(&output.content).unwrap_or_default()
         );
 
@@ -82,9 +84,10 @@ impl ComplianceAuditor {
             .await
             .map_err(|e| AuditError::Llm(e.to_string()))?;
 
-        let summary = response.content.unwrap_or_default();
+        // 3. Split the structured findings block from the prose summary.
+        let (summary, findings) = findings::split_findings(&response.content.unwrap_or_default());
 
-        // 3. Extract bullet-point recommendations from the LLM response.
+        // 4. Extract bullet-point recommendations from the LLM response.
         let recommendations: Vec<String> = summary
             .lines()
             .filter(|l| l.starts_with("- ") || l.starts_with("* "))
@@ -99,6 +102,7 @@ impl ComplianceAuditor {
             summary,
             scan_results: output.content,
             recommendations,
+            findings,
         })
     }
 }
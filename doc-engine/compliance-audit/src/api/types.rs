@@ -1,7 +1,23 @@
 use std::fmt;
 
+use doc_engine_scan::{CheckId, Severity};
 use serde::{Deserialize, Serialize};
 
+/// A single machine-actionable finding from an AI audit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditFinding {
+    /// The compliance check the finding relates to, if any.
+    pub check_id: Option<CheckId>,
+    /// How urgently the finding should be addressed.
+    pub severity: Severity,
+    /// One-line description of the problem.
+    pub title: String,
+    /// Explanation of the problem and the standard clause it relates to.
+    pub detail: String,
+    /// Concrete change that would resolve the finding.
+    pub suggested_fix: String,
+}
+
 /// Response from an AI-powered compliance audit.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditResponse {
@@ -11,6 +27,9 @@ pub struct AuditResponse {
     pub scan_results: serde_json::Value,
     /// Extracted actionable recommendations.
     pub recommendations: Vec<String>,
+    /// Structured findings; empty if the LLM returned none or malformed JSON.
+    #[serde(default)]
+    pub findings: Vec<AuditFinding>,
 }
 
 /// Errors produced by the compliance audit subsystem.
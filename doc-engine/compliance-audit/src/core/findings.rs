@@ -0,0 +1,107 @@
+use doc_engine_scan::{CheckId, Severity};
+use serde::Deserialize;
+
+use crate::api::types::AuditFinding;
+
+/// Appended to the analysis prompt so the LLM emits findings the auditor can parse.
+pub const FINDINGS_INSTRUCTIONS: &str = "\
+After the summary, output a fenced ```json block containing a JSON array of findings, \
+one per failing check or other issue. Each finding is an object with the keys \
+\"check_id\" (the numeric check id, or null), \"severity\" (\"error\", \"warning\" or \"info\"), \
+\"title\", \"detail\" and \"suggested_fix\". Output nothing after the block.";
+
+/// A finding as the LLM writes it; severity is free text until validated.
+#[derive(Deserialize)]
+struct RawFinding {
+    #[serde(default)]
+    check_id: Option<u8>,
+    severity: String,
+    title: String,
+    #[serde(default)]
+    detail: String,
+    #[serde(default)]
+    suggested_fix: String,
+}
+
+impl From<RawFinding> for AuditFinding {
+    fn from(raw: RawFinding) -> Self {
+        Self {
+            check_id: raw.check_id.map(CheckId),
+            severity: Severity::from_name(&raw.severity.to_lowercase()).unwrap_or(Severity::Warning),
+            title: raw.title,
+            detail: raw.detail,
+            suggested_fix: raw.suggested_fix,
+        }
+    }
+}
+
+/// Split an LLM audit response into its prose summary and structured findings.
+///
+/// The findings come from the first ```json fenced block, which is removed
+/// from the summary. A missing or malformed block yields no findings.
+pub fn split_findings(response: &str) -> (String, Vec<AuditFinding>) {
+    let Some(start) = response.find("```json") else {
+        return (response.trim().to_string(), Vec::new());
+    };
+    let body_start = start + "```json".len();
+    let (body, rest) = match response[body_start..].find("```") {
+        Some(end) => (&response[body_start..body_start + end], &response[body_start + end + 3..]),
+        None => (&response[body_start..], ""),
+    };
+
+    let findings = serde_json::from_str::<Vec<RawFinding>>(body)
+        .map(|raw| raw.into_iter().map(AuditFinding::from).collect())
+        .unwrap_or_default();
+    let summary = match rest.trim() {
+        "" => response[..start].trim().to_string(),
+        rest => format!("{}\n\n{}", response[..start].trim(), rest),
+    };
+    (summary, findings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_findings_block_and_strips_it_from_summary() {
+        let response = "\
+The project is mostly compliant.
+
+- Add an SRS
+
+```json
+[
+  {\"check_id\": 12, \"severity\": \"Error\", \"title\": \"Missing SRS\",
+   \"detail\": \"ISO 29148 requires an SRS.\", \"suggested_fix\": \"Create docs/1-requirements/srs.md\"},
+  {\"check_id\": null, \"severity\": \"info\", \"title\": \"Consider a glossary\",
+   \"detail\": \"Terms are undefined.\", \"suggested_fix\": \"Add a glossary section\"}
+]
+```
+";
+        let (summary, findings) = split_findings(response);
+        assert_eq!(summary, "The project is mostly compliant.\n\n- Add an SRS");
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].check_id, Some(CheckId(12)));
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert_eq!(findings[0].title, "Missing SRS");
+        assert_eq!(findings[0].suggested_fix, "Create docs/1-requirements/srs.md");
+        assert_eq!(findings[1].check_id, None);
+        assert_eq!(findings[1].severity, Severity::Info);
+    }
+
+    #[test]
+    fn malformed_json_yields_no_findings() {
+        let response = "Summary text.\n\n```json\n[{\"check_id\": 12, \"severity\": }\n```\n";
+        let (summary, findings) = split_findings(response);
+        assert_eq!(summary, "Summary text.");
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn response_without_block_is_all_summary() {
+        let (summary, findings) = split_findings("All checks pass.\n");
+        assert_eq!(summary, "All checks pass.");
+        assert!(findings.is_empty());
+    }
+}
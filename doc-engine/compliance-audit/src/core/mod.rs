@@ -1,2 +1,3 @@
 pub mod agents;
+pub mod findings;
 pub mod tools;
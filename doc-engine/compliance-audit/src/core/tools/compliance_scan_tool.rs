@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::collections::HashMap;
 use std::path::Path;
 
 use async_trait::async_trait;
@@ -96,6 +97,11 @@ impl Tool for ComplianceScanTool {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
 
         let path = Path::new(path_str);
@@ -2,5 +2,5 @@ pub mod api;
 pub mod core;
 pub mod spi;
 
-pub use api::{ComplianceAuditor, AuditError, AuditResponse};
+pub use api::{ComplianceAuditor, AuditError, AuditFinding, AuditResponse};
 pub use spi::AuditConfig;
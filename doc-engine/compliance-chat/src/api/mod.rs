@@ -1,5 +1,5 @@
 pub mod types;
 pub mod service;
 
-pub use types::ChatError;
-pub use service::ComplianceChat;
+pub use types::{ChatError, ChatMessage, ChatRole};
+pub use service::{ChatBackend, ComplianceChat};
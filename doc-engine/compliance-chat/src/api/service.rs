@@ -1,18 +1,46 @@
 use std::sync::Arc;
 
 use agent_controller::AgentDescriptor;
+use async_trait::async_trait;
 
-use crate::api::types::ChatError;
+use crate::api::types::{ChatError, ChatMessage, ChatRole};
 use crate::core::agents::ChatAgentManager;
 use crate::spi::ComplianceChatConfig;
 
+/// Completion backend the chat sends conversations to.
+#[async_trait]
+pub trait ChatBackend: Send + Sync {
+    /// Complete `messages` (oldest first) under the `system` prompt and return the reply.
+    async fn complete(&self, system: &str, messages: &[ChatMessage]) -> Result<String, ChatError>;
+}
+
+/// [`ChatBackend`] backed by the configured LLM provider.
+struct LlmBackend {
+    llm: Arc<dyn llm_provider::LlmService>,
+    model: String,
+}
+
+#[async_trait]
+impl ChatBackend for LlmBackend {
+    async fn complete(&self, system: &str, messages: &[ChatMessage]) -> Result<String, ChatError> {
+        let response = llm_provider::CompletionBuilder::new(&self.model)
+            .system(system)
+            .user(&render_transcript(messages))
+            .execute(&*self.llm)
+            .await
+            .map_err(|e| ChatError::Llm(e.to_string()))?;
+
+        Ok(response.content.unwrap_or_default())
+    }
+}
+
 /// Interactive compliance chat agent.
 ///
 /// Sends messages to the active compliance agent and returns responses.
 pub struct ComplianceChat {
-    llm: Arc<dyn llm_provider::LlmService>,
-    config: ComplianceChatConfig,
-    manager: ChatAgentManager,
+    backend: Arc<dyn ChatBackend>,
+    system_prompt: String,
+    history_size: usize,
 }
 
 impl ComplianceChat {
@@ -39,27 +67,143 @@ impl ComplianceChat {
         );
 
         let manager = ChatAgentManager::new(llm.clone(), config.clone());
-        Ok(Self {
-            llm,
-            config,
-            manager,
-        })
+        let system_prompt = manager
+            .active_agent()
+            .ok_or(ChatError::NoAgent)?
+            .system_prompt()
+            .to_string();
+
+        Ok(Self::with_backend(
+            Arc::new(LlmBackend { llm, model: config.model }),
+            system_prompt,
+            config.history_size,
+        ))
+    }
+
+    /// Create a chat that sends conversations to `backend` instead of the
+    /// environment-configured LLM, keeping at most `history_size` messages.
+    pub fn with_backend(
+        backend: Arc<dyn ChatBackend>,
+        system_prompt: impl Into<String>,
+        history_size: usize,
+    ) -> Self {
+        Self {
+            backend,
+            system_prompt: system_prompt.into(),
+            history_size,
+        }
     }
 
     /// Send a chat message to the active compliance agent.
     pub async fn chat(&self, message: &str) -> Result<String, ChatError> {
-        let agent = self
-            .manager
-            .active_agent()
-            .ok_or(ChatError::NoAgent)?;
+        self.chat_with_history(&[ChatMessage::user(message)]).await
+    }
 
-        let response = llm_provider::CompletionBuilder::new(&self.config.model)
-            .system(agent.system_prompt())
-            .user(message)
-            .execute(&*self.llm)
-            .await
-            .map_err(|e| ChatError::Llm(e.to_string()))?;
+    /// Send a conversation (oldest first, ending with the new user message)
+    /// to the active compliance agent.
+    ///
+    /// Only the most recent `history_size` messages are sent; see [`recent_window`].
+    pub async fn chat_with_history(&self, messages: &[ChatMessage]) -> Result<String, ChatError> {
+        if messages.is_empty() {
+            return Err(ChatError::EmptyConversation);
+        }
+        let window = recent_window(messages, self.history_size);
+        self.backend.complete(&self.system_prompt, window).await
+    }
+}
 
-        Ok(response.content.unwrap_or_default())
+/// The last `max` messages (at least one), with leading assistant turns
+/// dropped so the window opens on a user message.
+pub fn recent_window(messages: &[ChatMessage], max: usize) -> &[ChatMessage] {
+    let start = messages.len().saturating_sub(max.max(1));
+    let window = &messages[start..];
+    match window.iter().position(|m| m.role == ChatRole::User) {
+        Some(first_user) => &window[first_user..],
+        None => window,
+    }
+}
+
+/// Flatten a conversation into one prompt: earlier turns as a transcript,
+/// then the latest message. A single message is sent as-is.
+fn render_transcript(messages: &[ChatMessage]) -> String {
+    let (last, earlier) = match messages.split_last() {
+        Some(split) => split,
+        None => return String::new(),
+    };
+    if earlier.is_empty() {
+        return last.content.clone();
+    }
+
+    let mut prompt = String::from("Conversation so far:\n\n");
+    for m in earlier {
+        let speaker = match m.role {
+            ChatRole::User => "User",
+            ChatRole::Assistant => "Assistant",
+        };
+        prompt.push_str(&format!("{}: {}\n\n", speaker, m.content));
+    }
+    prompt.push_str("Latest message:\n\n");
+    prompt.push_str(&last.content);
+    prompt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Replies with the number of messages it received.
+    struct CountingBackend;
+
+    #[async_trait]
+    impl ChatBackend for CountingBackend {
+        async fn complete(&self, _system: &str, messages: &[ChatMessage]) -> Result<String, ChatError> {
+            Ok(messages.len().to_string())
+        }
+    }
+
+    fn chat(history_size: usize) -> ComplianceChat {
+        ComplianceChat::with_backend(Arc::new(CountingBackend), "system", history_size)
+    }
+
+    fn conversation(turns: usize) -> Vec<ChatMessage> {
+        (0..turns)
+            .map(|i| if i % 2 == 0 {
+                ChatMessage::user(format!("question {i}"))
+            } else {
+                ChatMessage::assistant(format!("answer {i}"))
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn chat_with_history_forwards_every_message() {
+        let reply = chat(20).chat_with_history(&conversation(5)).await.unwrap();
+        assert_eq!(reply, "5");
+    }
+
+    #[tokio::test]
+    async fn chat_sends_single_message() {
+        assert_eq!(chat(20).chat("hello").await.unwrap(), "1");
+    }
+
+    #[tokio::test]
+    async fn chat_with_history_trims_oldest_turns() {
+        // Last 4 of 7 open on an assistant turn, which is dropped too
+        let reply = chat(4).chat_with_history(&conversation(7)).await.unwrap();
+        assert_eq!(reply, "3");
+    }
+
+    #[tokio::test]
+    async fn chat_with_history_rejects_empty_conversation() {
+        let err = chat(20).chat_with_history(&[]).await.unwrap_err();
+        assert!(matches!(err, ChatError::EmptyConversation));
+    }
+
+    #[test]
+    fn render_transcript_labels_earlier_turns() {
+        let prompt = render_transcript(&conversation(3));
+        assert!(prompt.starts_with("Conversation so far:\n\nUser: question 0\n\nAssistant: answer 1\n\n"));
+        assert!(prompt.ends_with("Latest message:\n\nquestion 2"));
+        assert_eq!(render_transcript(&conversation(1)), "question 0");
     }
 }
@@ -1,5 +1,53 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
+/// Author of a [`ChatMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    User,
+    Assistant,
+}
+
+impl ChatRole {
+    /// Lowercase name, as serialized.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Assistant => "assistant",
+        }
+    }
+
+    /// Parse a lowercase role name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "user" => Some(Self::User),
+            "assistant" => Some(Self::Assistant),
+            _ => None,
+        }
+    }
+}
+
+/// One turn of a chat conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    /// A message from the user.
+    pub fn user(content: impl Into<String>) -> Self {
+        Self { role: ChatRole::User, content: content.into() }
+    }
+
+    /// A reply from the assistant.
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self { role: ChatRole::Assistant, content: content.into() }
+    }
+}
+
 /// Errors produced by the compliance chat subsystem.
 #[derive(Debug)]
 pub enum ChatError {
@@ -11,6 +59,8 @@ pub enum ChatError {
     NoAgent,
     /// LLM completion failed.
     Llm(String),
+    /// `chat_with_history` was called without any messages.
+    EmptyConversation,
 }
 
 impl fmt::Display for ChatError {
@@ -20,6 +70,7 @@ impl fmt::Display for ChatError {
             Self::Init(msg) => write!(f, "AI init failed: {}", msg),
             Self::NoAgent => write!(f, "no active agent configured"),
             Self::Llm(msg) => write!(f, "LLM error: {}", msg),
+            Self::EmptyConversation => write!(f, "no messages to send"),
         }
     }
 }
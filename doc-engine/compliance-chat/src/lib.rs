@@ -2,5 +2,5 @@ pub mod api;
 pub mod core;
 pub mod spi;
 
-pub use api::{ComplianceChat, ChatError};
+pub use api::{ChatBackend, ChatError, ChatMessage, ChatRole, ComplianceChat};
 pub use spi::ComplianceChatConfig;
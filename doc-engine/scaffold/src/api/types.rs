@@ -14,6 +14,16 @@ pub enum ReqKind {
     NonFunctional,
 }
 
+impl ReqKind {
+    /// Label used in generated specs: `functional` or `non-functional`.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReqKind::Functional => "functional",
+            ReqKind::NonFunctional => "non-functional",
+        }
+    }
+}
+
 /// A single FR-xxx or NFR-xxx block extracted from the SRS.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -56,7 +66,7 @@ pub struct SrsDomain {
 
 /// Configuration for the scaffold operation.
 pub struct ScaffoldConfig {
-    /// Path to the SRS markdown file.
+    /// Path to the SRS markdown file (`-` when the content is read from stdin).
     pub srs_path: PathBuf,
     /// Output directory (spec files are placed under this root).
     pub output_dir: PathBuf,
@@ -67,8 +77,8 @@ pub struct ScaffoldConfig {
     /// When empty/None, all phases are generated.
     pub phases: Vec<String>,
     /// Optional file-type filter: only generate files of these types.
-    /// Valid values: "yaml", "spec", "arch", "test", "exec", "deploy".
-    /// When empty, all file types are generated.
+    /// Valid values: "yaml", "spec", "arch", "test", "exec", "deploy", "plan", "matrix".
+    /// When empty, all file types except the opt-in "matrix" are generated.
     pub file_types: Vec<String>,
     /// Include ONLY domains gated behind these features (e.g. vec!["ai"]). Empty = no positive filter.
     pub features: Vec<String>,
@@ -76,6 +86,14 @@ pub struct ScaffoldConfig {
     pub exclude_features: Option<Vec<String>>,
     /// Optional path to a TOML command map file (`[commands]` table: FR-ID → CLI command).
     pub command_map_path: Option<PathBuf>,
+    /// Optional directory of `<type>.tera` templates (e.g. `spec.tera`,
+    /// `manual.exec.tera`) that replace the builtin markdown generators per file type.
+    pub template_dir: Option<PathBuf>,
+    /// Plan only: report `created`/`skipped` without writing files or creating directories.
+    pub dry_run: bool,
+    /// Regenerate only the `<!-- BEGIN GENERATED -->`/`<!-- END GENERATED -->`
+    /// region of existing files, keeping user content outside the markers.
+    pub merge: bool,
 }
 
 /// Result of a scaffold operation (ISO/IEC/IEEE 15289:2019 clause 9).
@@ -91,12 +109,15 @@ pub struct ScaffoldResult {
     pub tool_version: String,
     /// ISO 8601 UTC timestamp of report generation.
     pub timestamp: String,
-    /// Canonicalized absolute path to the SRS source file.
+    /// Canonicalized absolute path to the SRS source file, or `-` for stdin.
     pub srs_source: String,
     /// Phase filter applied (empty = all phases).
     pub phases: Vec<String>,
     /// Whether `--force` was set during scaffold.
     pub force: bool,
+    /// Whether this was a `--dry-run`; `created` then lists files that would be written.
+    #[serde(default)]
+    pub dry_run: bool,
     /// Number of domains processed.
     pub domain_count: usize,
     /// Total number of requirements extracted.
@@ -105,6 +126,12 @@ pub struct ScaffoldResult {
     pub created: Vec<PathBuf>,
     /// Files that were skipped (already existed and --force not set).
     pub skipped: Vec<PathBuf>,
+    /// Existing files whose generated region was replaced by `--merge`.
+    #[serde(default)]
+    pub merged: Vec<PathBuf>,
+    /// Human-readable notes, e.g. files `--merge` skipped for lack of markers.
+    #[serde(default)]
+    pub notes: Vec<String>,
 }
 
 /// Error type for scaffold operations.
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::api::types::{SrsDomain, SrsRequirement};
+use crate::api::types::{ReqKind, SrsDomain, SrsRequirement};
 
 /// Escape pipe characters for markdown table cells.
 fn escape_pipe(s: &str) -> String {
@@ -236,25 +236,40 @@ pub(crate) fn generate_feature_spec_md(domain: &SrsDomain) -> String {
     out.push_str(&format!("**Status:** Draft\n"));
     out.push_str(&format!("**Section:** {}\n\n", domain.section));
     out.push_str("## Requirements\n\n");
-    out.push_str("| ID | Source | Title | Priority | Verification | Acceptance |\n");
-    out.push_str("|-----|--------|-------|----------|--------------|------------|\n");
 
-    for (idx, req) in domain.requirements.iter().enumerate() {
-        let req_id = format!("REQ-{:03}", idx + 1);
-        out.push_str(&format!(
-            "| {} | {} | {} | {} | {} | {} |\n",
-            req_id,
-            req.id,
-            escape_pipe(&req.title),
-            req.priority.as_deref().unwrap_or("Unknown"),
-            req.verification.as_deref().unwrap_or("Test"),
-            escape_pipe(req.acceptance.as_deref().unwrap_or("—")),
-        ));
+    // REQ-NNN ids follow SRS order across both groups
+    let numbered: Vec<(String, &SrsRequirement)> = domain.requirements.iter()
+        .enumerate()
+        .map(|(idx, req)| (format!("REQ-{:03}", idx + 1), req))
+        .collect();
+
+    for (kind, heading) in [
+        (ReqKind::Functional, "Functional"),
+        (ReqKind::NonFunctional, "Non-Functional"),
+    ] {
+        let group: Vec<_> = numbered.iter().filter(|(_, req)| req.kind == kind).collect();
+        if group.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("### {}\n\n", heading));
+        out.push_str("| ID | Source | Title | Priority | Verification | Acceptance |\n");
+        out.push_str("|-----|--------|-------|----------|--------------|------------|\n");
+        for (req_id, req) in group {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                req_id,
+                req.id,
+                escape_pipe(&req.title),
+                req.priority.as_deref().unwrap_or("Unknown"),
+                req.verification.as_deref().unwrap_or("Test"),
+                escape_pipe(req.acceptance.as_deref().unwrap_or("—")),
+            ));
+        }
+        out.push('\n');
     }
 
-    out.push_str("\n## Acceptance Criteria\n\n");
-    for (idx, req) in domain.requirements.iter().enumerate() {
-        let req_id = format!("REQ-{:03}", idx + 1);
+    out.push_str("## Acceptance Criteria\n\n");
+    for (req_id, req) in &numbered {
         out.push_str(&format!(
             "- **{}** ({}): {}\n",
             req_id,
@@ -479,6 +494,9 @@ pub(crate) fn generate_brd_md(domains: &[SrsDomain]) -> String {
             d.section, d.title, d.slug,
         ));
         out.push_str(&format!("- **Requirements:** {}\n", d.requirements.len()));
+        for r in &d.requirements {
+            out.push_str(&format!("  - {} ({}): {}\n", r.id, r.kind.label(), r.title));
+        }
         out.push_str(&format!(
             "- **Spec:** `docs/1-requirements/{slug}/{slug}.spec.yaml`\n",
             slug = d.slug,
@@ -499,6 +517,37 @@ pub(crate) fn generate_brd_md(domains: &[SrsDomain]) -> String {
     out
 }
 
+/// Generate `traceability_matrix.md`: one row per requirement with the paths
+/// of its domain's spec, arch, test and deploy files, `-` where the phase is
+/// filtered out.
+pub(crate) fn generate_traceability_matrix_md(domains: &[SrsDomain], include_phase: &dyn Fn(&str) -> bool) -> String {
+    let artifact = |phase: &str, path: String| -> String {
+        if include_phase(phase) { format!("`{}`", path) } else { "-".to_string() }
+    };
+
+    let mut out = String::new();
+    out.push_str("# Traceability Matrix\n\n");
+    out.push_str("> ISO/IEC/IEEE 29148:2018 — Requirement traceability\n\n");
+    out.push_str("| ID | Title | Spec | Architecture | Test | Deployment |\n");
+    out.push_str("|----|-------|------|--------------|------|------------|\n");
+    for d in domains {
+        for req in &d.requirements {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                req.id,
+                escape_pipe(&req.title),
+                artifact("requirements", format!("docs/1-requirements/{slug}/{slug}.spec", slug = d.slug)),
+                artifact("design", format!("docs/3-design/{slug}/{slug}.arch", slug = d.slug)),
+                artifact("testing", format!("docs/5-testing/{slug}/{slug}.test", slug = d.slug)),
+                artifact("deployment", format!("docs/6-deployment/{slug}/{slug}.deploy", slug = d.slug)),
+            ));
+        }
+    }
+    let total: usize = domains.iter().map(|d| d.requirements.len()).sum();
+    out.push_str(&format!("\n**Total requirements:** {}\n", total));
+    out
+}
+
 /// Generate a project-level `test_plan.md` conforming to ISO/IEC/IEEE 29119-3:2021 Clause 7.
 ///
 /// Aggregates all SRS domains into a master test plan with sections that
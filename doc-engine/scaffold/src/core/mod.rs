@@ -1,14 +1,20 @@
 pub mod parser;
 pub(crate) mod yaml_gen;
 pub(crate) mod markdown_gen;
+pub(crate) mod template;
 
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::api::types::{ScaffoldError, iso8601_now};
 use crate::api::types::{ScaffoldConfig, ScaffoldResult};
 
+/// Opening marker of the auto-managed region `--merge` regenerates.
+pub const BEGIN_GENERATED: &str = "<!-- BEGIN GENERATED -->";
+/// Closing marker of the auto-managed region `--merge` regenerates.
+pub const END_GENERATED: &str = "<!-- END GENERATED -->";
+
 /// Load a TOML command map file (`[commands]` table) into a HashMap.
 ///
 /// The file must contain a `[commands]` table mapping requirement IDs to CLI commands:
@@ -54,6 +60,21 @@ pub fn load_command_map(path: &std::path::Path) -> Result<HashMap<String, String
 /// Reads the SRS, extracts domains and requirements, then generates:
 /// - Per-domain `.spec.yaml`/`.spec`, `.arch.yaml`/`.arch`, `.test.yaml`/`.test`, `.deploy.yaml`/`.deploy`
 /// - A BRD master inventory `brd.spec.yaml`/`brd.spec`
+/// - A project test plan `test_plan.md`
+/// - With the `matrix` file type, `traceability_matrix.md` mapping every
+///   requirement to its spec/arch/test/deploy files
+///
+/// When `config.template_dir` is set, per-domain markdown files whose type has
+/// a `<type>.tera` template there are rendered from it instead of the builtin
+/// generator; types without a template fall back to the builtin.
+///
+/// When `config.dry_run` is set, `created`/`skipped` are computed exactly as
+/// for a real run but nothing is written and no directory is created.
+///
+/// When `config.merge` is set (and `config.force` is not), existing files that
+/// contain [`BEGIN_GENERATED`]/[`END_GENERATED`] markers have only the region
+/// between them regenerated and are listed in `merged`; existing files without
+/// markers are skipped and a note is recorded.
 pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, ScaffoldError> {
     let content = fs::read_to_string(&config.srs_path).map_err(|e| {
         ScaffoldError::Path(format!(
@@ -63,7 +84,22 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
         ))
     })?;
 
-    let domains = parser::parse_srs(&content)?;
+    scaffold_from_srs_content(config, &content)
+}
+
+/// Generate SDLC spec file scaffold from in-memory SRS content.
+///
+/// Same as [`scaffold_from_srs`] but skips reading `config.srs_path`; the path
+/// is only recorded as `srs_source` in the result (e.g. `-` for stdin).
+pub fn scaffold_from_srs_content(config: &ScaffoldConfig, content: &str) -> Result<ScaffoldResult, ScaffoldError> {
+    if content.trim().is_empty() {
+        return Err(ScaffoldError::Parse(format!(
+            "SRS content from '{}' is empty",
+            config.srs_path.display(),
+        )));
+    }
+
+    let domains = parser::parse_srs(content)?;
 
     let domains: Vec<_> = if !config.features.is_empty() {
         domains.into_iter().filter(|d| {
@@ -94,6 +130,11 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
         None => HashMap::new(),
     };
 
+    let templates = match config.template_dir {
+        Some(ref dir) => Some(template::Templates::load(dir)?),
+        None => None,
+    };
+
     let mut result = ScaffoldResult {
         standard: "ISO/IEC/IEEE 15289:2019".to_string(),
         clause: "9".to_string(),
@@ -103,10 +144,13 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
         srs_source: config.srs_path.display().to_string(),
         phases: config.phases.clone(),
         force: config.force,
+        dry_run: config.dry_run,
         domain_count: domains.len(),
         requirement_count: domains.iter().map(|d| d.requirements.len()).sum(),
         created: Vec::new(),
         skipped: Vec::new(),
+        merged: Vec::new(),
+        notes: Vec::new(),
     };
 
     let include_phase = |phase: &str| -> bool {
@@ -121,6 +165,14 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
         // Spec files: 4 YAML + 4 markdown + 2 exec per domain (filtered by phase and type)
         let mut files: Vec<(String, String)> = Vec::new();
 
+        // User template for this file type if present, else the builtin generator.
+        let markdown = |file_type: &str, builtin: &dyn Fn() -> String| -> Result<String, ScaffoldError> {
+            match templates.as_ref().and_then(|t| t.render(file_type, domain)) {
+                Some(rendered) => rendered,
+                None => Ok(builtin()),
+            }
+        };
+
         if include_phase("requirements") {
             if include_type("yaml") {
                 files.push((
@@ -131,7 +183,7 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
             if include_type("spec") {
                 files.push((
                     format!("docs/1-requirements/{}/{}.spec", domain.slug, domain.slug),
-                    markdown_gen::generate_feature_spec_md(domain),
+                    markdown("spec", &|| markdown_gen::generate_feature_spec_md(domain))?,
                 ));
             }
         }
@@ -146,7 +198,7 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
             if include_type("arch") {
                 files.push((
                     format!("docs/3-design/{}/{}.arch", domain.slug, domain.slug),
-                    markdown_gen::generate_arch_spec_md(domain),
+                    markdown("arch", &|| markdown_gen::generate_arch_spec_md(domain))?,
                 ));
             }
         }
@@ -161,17 +213,17 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
             if include_type("test") {
                 files.push((
                     format!("docs/5-testing/{}/{}.test", domain.slug, domain.slug),
-                    markdown_gen::generate_test_spec_md(domain),
+                    markdown("test", &|| markdown_gen::generate_test_spec_md(domain))?,
                 ));
             }
             if include_type("exec") {
                 files.push((
                     format!("docs/5-testing/{}/{}.manual.exec", domain.slug, domain.slug),
-                    markdown_gen::generate_manual_exec_md(domain, &command_map),
+                    markdown("manual.exec", &|| markdown_gen::generate_manual_exec_md(domain, &command_map))?,
                 ));
                 files.push((
                     format!("docs/5-testing/{}/{}.auto.exec", domain.slug, domain.slug),
-                    markdown_gen::generate_auto_exec_md(domain),
+                    markdown("auto.exec", &|| markdown_gen::generate_auto_exec_md(domain))?,
                 ));
             }
         }
@@ -186,13 +238,13 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
             if include_type("deploy") {
                 files.push((
                     format!("docs/6-deployment/{}/{}.deploy", domain.slug, domain.slug),
-                    markdown_gen::generate_deploy_spec_md(domain),
+                    markdown("deploy", &|| markdown_gen::generate_deploy_spec_md(domain))?,
                 ));
             }
         }
 
         for (rel_path, content) in files {
-            write_file(&config.output_dir, &rel_path, &content, config.force, &mut result)?;
+            write_file(config, &rel_path, &content, &mut result)?;
         }
     }
 
@@ -214,35 +266,50 @@ pub fn scaffold_from_srs(config: &ScaffoldConfig) -> Result<ScaffoldResult, Scaf
         }
 
         for (rel_path, content) in brd_files {
-            write_file(&config.output_dir, &rel_path, &content, config.force, &mut result)?;
+            write_file(config, &rel_path, &content, &mut result)?;
         }
     }
 
     // Project-level test plan (only when testing phase is included)
     if include_phase("testing") && include_type("plan") {
         let content = markdown_gen::generate_test_plan_project_md(&domains);
-        write_file(&config.output_dir, "docs/5-testing/test_plan.md", &content, config.force, &mut result)?;
+        write_file(config, "docs/5-testing/test_plan.md", &content, &mut result)?;
+    }
+
+    // Requirement-to-artifact matrix; opt-in, so only an explicit `matrix` type generates it
+    if include_phase("requirements") && config.file_types.iter().any(|t| t == "matrix") {
+        let content = markdown_gen::generate_traceability_matrix_md(&domains, &include_phase);
+        write_file(config, "docs/1-requirements/traceability_matrix.md", &content, &mut result)?;
     }
 
     Ok(result)
 }
 
-/// Write a file at `output_dir/rel_path`, creating parent dirs as needed.
-/// If the file exists and `force` is false, skip it.
+/// Write a file at `config.output_dir/rel_path`, creating parent dirs as needed.
+/// If the file exists and `config.force` is false, skip it, or with
+/// `config.merge` regenerate only its marked region. With `config.dry_run`
+/// the file is only recorded, never written.
 fn write_file(
-    output_dir: &PathBuf,
+    config: &ScaffoldConfig,
     rel_path: &str,
     content: &str,
-    force: bool,
     result: &mut ScaffoldResult,
 ) -> Result<(), ScaffoldError> {
-    let full_path = output_dir.join(rel_path);
+    let full_path = config.output_dir.join(rel_path);
 
-    if full_path.exists() && !force {
+    if full_path.exists() && !config.force {
+        if config.merge {
+            return merge_file(config, &full_path, rel_path, content, result);
+        }
         result.skipped.push(PathBuf::from(rel_path));
         return Ok(());
     }
 
+    if config.dry_run {
+        result.created.push(PathBuf::from(rel_path));
+        return Ok(());
+    }
+
     if let Some(parent) = full_path.parent() {
         fs::create_dir_all(parent).map_err(|e| {
             ScaffoldError::Path(format!(
@@ -261,10 +328,62 @@ fn write_file(
     Ok(())
 }
 
+/// Replace the generated region of an existing file with the fresh one.
+/// Files without a complete marker pair are skipped with a note.
+fn merge_file(
+    config: &ScaffoldConfig,
+    full_path: &Path,
+    rel_path: &str,
+    content: &str,
+    result: &mut ScaffoldResult,
+) -> Result<(), ScaffoldError> {
+    let existing = fs::read_to_string(full_path).map_err(ScaffoldError::Io)?;
+
+    let merged = match merge_generated(&existing, content) {
+        Some(m) => m,
+        None => {
+            result.skipped.push(PathBuf::from(rel_path));
+            result.notes.push(format!(
+                "{}: no {} / {} markers, left unchanged",
+                rel_path, BEGIN_GENERATED, END_GENERATED
+            ));
+            return Ok(());
+        }
+    };
+
+    if !config.dry_run && merged != existing {
+        fs::write(full_path, &merged).map_err(ScaffoldError::Io)?;
+    }
+
+    result.merged.push(PathBuf::from(rel_path));
+    Ok(())
+}
+
+/// Splice the generated region of `generated` into `existing`, keeping
+/// everything outside `existing`'s markers. When `generated` carries no
+/// markers of its own, all of it becomes the region. Returns `None` if
+/// `existing` lacks a BEGIN marker followed by an END marker.
+fn merge_generated(existing: &str, generated: &str) -> Option<String> {
+    let (head, _, tail) = split_generated(existing)?;
+
+    let region = match split_generated(generated) {
+        Some((_, inner, _)) => inner.to_string(),
+        None => format!("\n{}\n", generated.trim_end()),
+    };
+
+    Some(format!("{}{}{}", head, region, tail))
+}
+
+/// Split `text` into (up to and including BEGIN, between markers, END onwards).
+fn split_generated(text: &str) -> Option<(&str, &str, &str)> {
+    let begin = text.find(BEGIN_GENERATED)? + BEGIN_GENERATED.len();
+    let end = begin + text[begin..].find(END_GENERATED)?;
+    Some((&text[..begin], &text[begin..end], &text[end..]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::Path;
 
     fn fixture_srs() -> String {
         "\
@@ -298,6 +417,9 @@ The binary embeds rules.
             features: vec![],
             exclude_features: None,
             command_map_path: None,
+            template_dir: None,
+            dry_run: false,
+            merge: false,
         };
         (config, output_dir)
     }
@@ -371,6 +493,86 @@ The binary embeds rules.
         assert!(result.skipped.is_empty());
     }
 
+    #[test]
+    fn test_scaffold_dry_run_writes_nothing() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, output_dir) = setup_config(tmp.path());
+        config.dry_run = true;
+
+        let planned = scaffold_from_srs(&config).unwrap();
+        assert!(planned.dry_run);
+        assert!(!output_dir.exists());
+
+        config.dry_run = false;
+        let result = scaffold_from_srs(&config).unwrap();
+        assert_eq!(planned.created, result.created);
+        assert_eq!(planned.skipped, result.skipped);
+    }
+
+    #[test]
+    fn test_scaffold_dry_run_reports_skipped() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, _output_dir) = setup_config(tmp.path());
+        scaffold_from_srs(&config).unwrap();
+
+        config.dry_run = true;
+        let planned = scaffold_from_srs(&config).unwrap();
+        assert_eq!(planned.skipped.len(), 13);
+        assert!(planned.created.is_empty());
+    }
+
+    #[test]
+    fn test_scaffold_merge_preserves_user_content() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, output_dir) = setup_config(tmp.path());
+        config.file_types = vec!["spec".to_string()];
+        scaffold_from_srs(&config).unwrap();
+
+        let rel = "docs/1-requirements/rule_loading/rule_loading.spec";
+        let path = output_dir.join(rel);
+        fs::write(
+            &path,
+            format!(
+                "# My notes\n\nHand-written intro.\n\n{}\nstale content\n{}\n\nFooter kept.\n",
+                BEGIN_GENERATED, END_GENERATED
+            ),
+        )
+        .unwrap();
+
+        config.merge = true;
+        let result = scaffold_from_srs(&config).unwrap();
+        assert!(result.merged.contains(&PathBuf::from(rel)));
+        assert!(result.created.is_empty());
+
+        let merged = fs::read_to_string(&path).unwrap();
+        assert!(merged.starts_with("# My notes\n\nHand-written intro.\n\n"));
+        assert!(merged.ends_with("\n\nFooter kept.\n"));
+        assert!(!merged.contains("stale content"));
+        assert!(merged.contains("FR-100"));
+
+        // A second merge run is stable
+        scaffold_from_srs(&config).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), merged);
+    }
+
+    #[test]
+    fn test_scaffold_merge_skips_unmarked_with_note() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, output_dir) = setup_config(tmp.path());
+        scaffold_from_srs(&config).unwrap();
+
+        let rel = "docs/5-testing/test_plan.md";
+        fs::write(output_dir.join(rel), "# Edited plan\n").unwrap();
+
+        config.merge = true;
+        let result = scaffold_from_srs(&config).unwrap();
+        assert!(result.merged.is_empty());
+        assert_eq!(result.skipped.len(), 13);
+        assert_eq!(result.notes.len(), 13);
+        assert!(result.notes.iter().any(|n| n.starts_with(rel)));
+        assert_eq!(fs::read_to_string(output_dir.join(rel)).unwrap(), "# Edited plan\n");
+    }
+
     #[test]
     fn test_scaffold_empty_srs_error() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -386,12 +588,61 @@ The binary embeds rules.
             features: vec![],
             exclude_features: None,
             command_map_path: None,
+            template_dir: None,
+            dry_run: false,
+            merge: false,
         };
 
         let err = scaffold_from_srs(&config).unwrap_err();
         assert!(err.to_string().contains("no domains"));
     }
 
+    #[test]
+    fn test_scaffold_from_content() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let output_dir = tmp.path().join("output");
+        let config = ScaffoldConfig {
+            srs_path: PathBuf::from("-"),
+            output_dir: output_dir.clone(),
+            force: false,
+            phases: vec![],
+            file_types: vec![],
+            features: vec![],
+            exclude_features: None,
+            command_map_path: None,
+            template_dir: None,
+            dry_run: false,
+            merge: false,
+        };
+
+        let result = scaffold_from_srs_content(&config, &fixture_srs()).unwrap();
+        assert_eq!(result.domain_count, 1);
+        assert_eq!(result.srs_source, "-");
+        assert!(output_dir.join("docs/1-requirements/rule_loading/rule_loading.spec.yaml").exists());
+    }
+
+    #[test]
+    fn test_scaffold_from_empty_content_error() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = ScaffoldConfig {
+            srs_path: PathBuf::from("-"),
+            output_dir: tmp.path().join("out"),
+            force: false,
+            phases: vec![],
+            file_types: vec![],
+            features: vec![],
+            exclude_features: None,
+            command_map_path: None,
+            template_dir: None,
+            dry_run: false,
+            merge: false,
+        };
+
+        let err = scaffold_from_srs_content(&config, "  \n").unwrap_err();
+        assert!(err.to_string().contains("is empty"));
+        assert!(!tmp.path().join("out").exists());
+    }
+
     #[test]
     fn test_scaffold_phase_filter_testing_only() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -443,4 +694,85 @@ The binary embeds rules.
         // Empty phases = all phases: 1 domain × 10 + 2 BRD + 1 test plan = 13
         assert_eq!(result.created.len(), 13);
     }
+
+    #[test]
+    fn test_scaffold_custom_spec_template_overrides_default() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, output_dir) = setup_config(tmp.path());
+        let template_dir = tmp.path().join("templates");
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(
+            template_dir.join("spec.tera"),
+            "# House Spec: {{domain.title}}\n{{#requirements}}* {{req.id}} ({{req.priority}}): {{req.acceptance}}\n{{/requirements}}",
+        )
+        .unwrap();
+        config.template_dir = Some(template_dir);
+
+        let result = scaffold_from_srs(&config).unwrap();
+        assert_eq!(result.created.len(), 13);
+
+        let spec = fs::read_to_string(output_dir.join("docs/1-requirements/rule_loading/rule_loading.spec")).unwrap();
+        assert_eq!(spec, "# House Spec: Rule Loading\n* FR-100 (Must): Engine loads rules\n");
+
+        // Types without a template fall back to the builtin generator
+        let arch = fs::read_to_string(output_dir.join("docs/3-design/rule_loading/rule_loading.arch")).unwrap();
+        assert_eq!(arch, markdown_gen::generate_arch_spec_md(&parser::parse_srs(&fixture_srs()).unwrap()[0]));
+    }
+
+    #[test]
+    fn test_scaffold_template_unknown_placeholder_error() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, _output_dir) = setup_config(tmp.path());
+        let template_dir = tmp.path().join("templates");
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(template_dir.join("arch.tera"), "{{domain.owner}}").unwrap();
+        config.template_dir = Some(template_dir);
+
+        let err = scaffold_from_srs(&config).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("arch.tera"), "{}", msg);
+        assert!(msg.contains("available variables: domain.section"), "{}", msg);
+    }
+
+    #[test]
+    fn test_scaffold_missing_template_dir_error() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, _output_dir) = setup_config(tmp.path());
+        config.template_dir = Some(tmp.path().join("no_such_dir"));
+
+        let err = scaffold_from_srs(&config).unwrap_err();
+        assert!(err.to_string().contains("cannot read template directory"));
+    }
+
+    #[test]
+    fn test_scaffold_traceability_matrix_lists_each_fr_once() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut config, output_dir) = setup_config(tmp.path());
+        let srs = format!(
+            "{}\n#### FR-101: Custom rules\n\n| Attribute | Value |\n|-----------|-------|\n| **Priority** | Should |\n\n\
+             ### 4.2 Reporting\n\n#### FR-200: JSON output\n\n| Attribute | Value |\n|-----------|-------|\n| **Priority** | Must |\n",
+            fixture_srs(),
+        );
+        fs::write(&config.srs_path, srs).unwrap();
+        config.file_types = vec!["matrix".to_string()];
+        config.phases = vec!["requirements".to_string(), "testing".to_string()];
+
+        let result = scaffold_from_srs(&config).unwrap();
+        assert_eq!(result.created, vec![PathBuf::from("docs/1-requirements/traceability_matrix.md")]);
+
+        let matrix = fs::read_to_string(output_dir.join("docs/1-requirements/traceability_matrix.md")).unwrap();
+        for id in ["FR-100", "FR-101", "FR-200"] {
+            let rows: Vec<&str> = matrix.lines().filter(|l| l.starts_with(&format!("| {} |", id))).collect();
+            assert_eq!(rows.len(), 1, "{} in\n{}", id, matrix);
+        }
+        assert!(matrix.contains("| FR-200 | JSON output | `docs/1-requirements/reporting/reporting.spec` | - | `docs/5-testing/reporting/reporting.test` | - |"));
+    }
+
+    #[test]
+    fn test_scaffold_matrix_not_generated_by_default() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (config, output_dir) = setup_config(tmp.path());
+        scaffold_from_srs(&config).unwrap();
+        assert!(!output_dir.join("docs/1-requirements/traceability_matrix.md").exists());
+    }
 }
@@ -28,9 +28,67 @@ fn extract_attr(line: &str, re: &Regex) -> Option<String> {
     re.captures(line).map(|c| c[1].trim().to_string())
 }
 
+/// Requirement attributes read from a YAML frontmatter block.
+#[derive(Default)]
+struct Frontmatter {
+    priority: Option<String>,
+    state: Option<String>,
+    verification: Option<String>,
+    traces_to: Option<String>,
+    acceptance: Option<String>,
+}
+
+/// Parse the YAML between `---` fences under a requirement heading.
+///
+/// Keys match the attribute table rows (`priority`, `state`, `verification`,
+/// `traces_to`, `acceptance`); `traceability` and `acceptance_criteria` are
+/// accepted as aliases. List values are joined with `, `.
+fn parse_frontmatter(id: &str, yaml: &str) -> Result<Frontmatter, ScaffoldError> {
+    let value: serde_yml::Value = serde_yml::from_str(yaml).map_err(|e| {
+        ScaffoldError::Parse(format!("{}: invalid YAML frontmatter: {}", id, e))
+    })?;
+    let map = match value {
+        serde_yml::Value::Mapping(map) => map,
+        serde_yml::Value::Null => return Ok(Frontmatter::default()),
+        _ => {
+            return Err(ScaffoldError::Parse(format!(
+                "{}: YAML frontmatter must be a mapping of attributes",
+                id
+            )))
+        }
+    };
+    let get = |keys: &[&str]| keys.iter().find_map(|k| map.get(*k).and_then(yaml_text));
+    Ok(Frontmatter {
+        priority: get(&["priority"]),
+        state: get(&["state"]),
+        verification: get(&["verification"]),
+        traces_to: get(&["traces_to", "traceability"]),
+        acceptance: get(&["acceptance", "acceptance_criteria"]),
+    })
+}
+
+/// Render a YAML scalar or list of scalars as attribute text.
+fn yaml_text(value: &serde_yml::Value) -> Option<String> {
+    let text = match value {
+        serde_yml::Value::String(s) => s.trim().to_string(),
+        serde_yml::Value::Number(n) => n.to_string(),
+        serde_yml::Value::Bool(b) => b.to_string(),
+        serde_yml::Value::Sequence(items) => items
+            .iter()
+            .filter_map(yaml_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => return None,
+    };
+    if text.is_empty() { None } else { Some(text) }
+}
+
 /// Parse an SRS markdown document into a list of domains with their requirements.
 ///
 /// Sections without any FR/NFR blocks are dropped (no empty spec files).
+/// Each requirement's attributes come from a `---`-fenced YAML frontmatter
+/// block directly under its heading when present, otherwise from its
+/// attribute table; the format is detected per requirement.
 pub fn parse_srs(content: &str) -> Result<Vec<SrsDomain>, ScaffoldError> {
     let section_heading_re = Regex::new(r"^###\s+(\d+\.\d+)\s+(.+)$").unwrap();
     let fr_heading_re = Regex::new(r"^####\s+((?:FR|NFR)-\d+):\s+(.+)$").unwrap();
@@ -109,6 +167,28 @@ pub fn parse_srs(content: &str) -> Result<Vec<SrsDomain>, ScaffoldError> {
             let mut narrative_lines: Vec<&str> = Vec::new();
             let mut past_table = false;
 
+            // YAML frontmatter: `---` as the first non-blank line under the heading
+            let mut j = i;
+            while j < lines.len() && lines[j].trim().is_empty() {
+                j += 1;
+            }
+            if j < lines.len() && lines[j].trim() == "---" {
+                let close = lines[j + 1..]
+                    .iter()
+                    .position(|l| l.trim() == "---")
+                    .map(|offset| j + 1 + offset)
+                    .ok_or_else(|| {
+                        ScaffoldError::Parse(format!("{}: YAML frontmatter has no closing '---'", id))
+                    })?;
+                let fm = parse_frontmatter(&id, &lines[j + 1..close].join("\n"))?;
+                priority = fm.priority;
+                state = fm.state;
+                verification = fm.verification;
+                traces_to = fm.traces_to;
+                acceptance = fm.acceptance;
+                i = close + 1;
+            }
+
             while i < lines.len() {
                 let bline = lines[i];
                 // Stop at any heading
@@ -509,4 +589,82 @@ Description.
             "pipe chars inside acceptance text must be preserved"
         );
     }
+
+    #[test]
+    fn test_parse_yaml_frontmatter() {
+        let srs = "\
+### 4.1 Rule Loading
+
+#### FR-100: Default rules
+
+---
+priority: Must
+state: Approved
+verification: Test
+traces_to: [STK-01, STK-02]
+acceptance: \"Engine loads rules | even with pipes\"
+---
+
+The binary shall embed a default rules.toml.
+";
+        let domains = parse_srs(srs).unwrap();
+        let req = &domains[0].requirements[0];
+        assert_eq!(req.priority.as_deref(), Some("Must"));
+        assert_eq!(req.state.as_deref(), Some("Approved"));
+        assert_eq!(req.verification.as_deref(), Some("Test"));
+        assert_eq!(req.traces_to.as_deref(), Some("STK-01, STK-02"));
+        assert_eq!(req.acceptance.as_deref(), Some("Engine loads rules | even with pipes"));
+        assert_eq!(req.description, "The binary shall embed a default rules.toml.");
+    }
+
+    #[test]
+    fn test_parse_mixed_frontmatter_and_table() {
+        let srs = "\
+### 4.1 Rule Loading
+
+#### FR-100: Frontmatter requirement
+---
+priority: Should
+verification: Inspection
+---
+
+#### FR-101: Table requirement
+
+| Attribute | Value |
+|-----------|-------|
+| **Priority** | Must |
+| **Verification** | Test |
+
+#### NFR-100: Frontmatter NFR
+
+---
+priority: Could
+acceptance_criteria: Loads in under 10ms
+---
+";
+        let domains = parse_srs(srs).unwrap();
+        let reqs = &domains[0].requirements;
+        assert_eq!(reqs.len(), 3);
+        assert_eq!(reqs[0].priority.as_deref(), Some("Should"));
+        assert_eq!(reqs[0].verification.as_deref(), Some("Inspection"));
+        assert_eq!(reqs[1].priority.as_deref(), Some("Must"));
+        assert_eq!(reqs[1].verification.as_deref(), Some("Test"));
+        assert_eq!(reqs[2].kind, ReqKind::NonFunctional);
+        assert_eq!(reqs[2].priority.as_deref(), Some("Could"));
+        assert_eq!(reqs[2].acceptance.as_deref(), Some("Loads in under 10ms"));
+    }
+
+    #[test]
+    fn test_parse_frontmatter_unclosed_error() {
+        let srs = "### 4.1 Rule Loading\n\n#### FR-100: Open\n\n---\npriority: Must\n";
+        let err = parse_srs(srs).unwrap_err();
+        assert!(err.to_string().contains("FR-100: YAML frontmatter has no closing '---'"));
+    }
+
+    #[test]
+    fn test_parse_frontmatter_invalid_yaml_error() {
+        let srs = "### 4.1 Rule Loading\n\n#### FR-100: Bad\n\n---\npriority: [Must\n---\n";
+        let err = parse_srs(srs).unwrap_err();
+        assert!(err.to_string().contains("FR-100: invalid YAML frontmatter"));
+    }
 }
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::api::types::{ScaffoldError, SrsDomain, SrsRequirement};
+
+/// File extension of user templates in `ScaffoldConfig::template_dir`.
+const TEMPLATE_EXT: &str = ".tera";
+
+/// Opening and closing tags of the per-requirement block.
+const REQUIREMENTS_OPEN: &str = "#requirements";
+const REQUIREMENTS_CLOSE: &str = "{{/requirements}}";
+
+/// Variables available everywhere in a template.
+const DOMAIN_VARS: &[&str] = &[
+    "domain.section",
+    "domain.title",
+    "domain.slug",
+    "domain.feature_gate",
+    "domain.requirement_count",
+];
+
+/// Variables available inside `{{#requirements}}...{{/requirements}}`.
+const REQUIREMENT_VARS: &[&str] = &[
+    "req.id",
+    "req.title",
+    "req.kind",
+    "req.priority",
+    "req.state",
+    "req.verification",
+    "req.traces_to",
+    "req.acceptance",
+    "req.description",
+];
+
+/// User templates loaded from a template directory, keyed by file type.
+///
+/// A template is named after the suffix of the file it replaces, e.g.
+/// `spec.tera` for `<slug>.spec` and `manual.exec.tera` for `<slug>.manual.exec`.
+pub(crate) struct Templates {
+    by_type: HashMap<String, String>,
+}
+
+impl Templates {
+    /// Read every `*.tera` file in `dir`.
+    pub(crate) fn load(dir: &Path) -> Result<Self, ScaffoldError> {
+        let entries = fs::read_dir(dir).map_err(|e| {
+            ScaffoldError::Path(format!(
+                "cannot read template directory '{}': {}",
+                dir.display(),
+                e
+            ))
+        })?;
+
+        let mut by_type = HashMap::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let Some(file_type) = name.strip_suffix(TEMPLATE_EXT) else {
+                continue;
+            };
+            by_type.insert(file_type.to_string(), fs::read_to_string(&path)?);
+        }
+        Ok(Self { by_type })
+    }
+
+    /// Render the template for `file_type` against `domain`, or `None` when
+    /// the directory has no template for that type.
+    pub(crate) fn render(&self, file_type: &str, domain: &SrsDomain) -> Option<Result<String, ScaffoldError>> {
+        self.by_type
+            .get(file_type)
+            .map(|template| render(&format!("{}{}", file_type, TEMPLATE_EXT), template, domain))
+    }
+}
+
+/// Substitute `{{placeholder}}` variables in `template`.
+///
+/// `{{#requirements}}...{{/requirements}}` repeats its body once per
+/// requirement of the domain. Unknown placeholders are an error listing the
+/// available variables.
+pub(crate) fn render(name: &str, template: &str, domain: &SrsDomain) -> Result<String, ScaffoldError> {
+    render_part(name, template, domain, None)
+}
+
+fn render_part(
+    name: &str,
+    template: &str,
+    domain: &SrsDomain,
+    req: Option<&SrsRequirement>,
+) -> Result<String, ScaffoldError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            ScaffoldError::Parse(format!("template '{}': unclosed '{{{{'", name))
+        })?;
+        let tag = after[..end].trim();
+        rest = &after[end + 2..];
+
+        if tag == REQUIREMENTS_OPEN {
+            if req.is_some() {
+                return Err(ScaffoldError::Parse(format!(
+                    "template '{}': {{{{#requirements}}}} blocks cannot be nested",
+                    name
+                )));
+            }
+            let close = rest.find(REQUIREMENTS_CLOSE).ok_or_else(|| {
+                ScaffoldError::Parse(format!(
+                    "template '{}': {{{{#requirements}}}} has no matching {}",
+                    name, REQUIREMENTS_CLOSE
+                ))
+            })?;
+            let body = &rest[..close];
+            for r in &domain.requirements {
+                out.push_str(&render_part(name, body, domain, Some(r))?);
+            }
+            rest = &rest[close + REQUIREMENTS_CLOSE.len()..];
+            continue;
+        }
+
+        let value = domain_var(domain, tag)
+            .or_else(|| req.and_then(|r| requirement_var(r, tag)))
+            .ok_or_else(|| unknown_placeholder(name, tag))?;
+        out.push_str(&value);
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn domain_var(domain: &SrsDomain, var: &str) -> Option<String> {
+    Some(match var {
+        "domain.section" => domain.section.clone(),
+        "domain.title" => domain.title.clone(),
+        "domain.slug" => domain.slug.clone(),
+        "domain.feature_gate" => domain.feature_gate.clone().unwrap_or_default(),
+        "domain.requirement_count" => domain.requirements.len().to_string(),
+        _ => return None,
+    })
+}
+
+fn requirement_var(req: &SrsRequirement, var: &str) -> Option<String> {
+    Some(match var {
+        "req.id" => req.id.clone(),
+        "req.title" => req.title.clone(),
+        "req.kind" => req.kind.label().to_string(),
+        "req.priority" => req.priority.clone().unwrap_or_default(),
+        "req.state" => req.state.clone().unwrap_or_default(),
+        "req.verification" => req.verification.clone().unwrap_or_default(),
+        "req.traces_to" => req.traces_to.clone().unwrap_or_default(),
+        "req.acceptance" => req.acceptance.clone().unwrap_or_default(),
+        "req.description" => req.description.clone(),
+        _ => return None,
+    })
+}
+
+fn unknown_placeholder(name: &str, tag: &str) -> ScaffoldError {
+    ScaffoldError::Parse(format!(
+        "template '{}': unknown placeholder '{{{{{}}}}}'; available variables: {}; \
+         inside {{{{#requirements}}}}...{{{{/requirements}}}}: {}",
+        name,
+        tag,
+        DOMAIN_VARS.join(", "),
+        REQUIREMENT_VARS.join(", "),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::ReqKind;
+
+    fn make_domain() -> SrsDomain {
+        SrsDomain {
+            section: "4.1".to_string(),
+            title: "Rule Loading".to_string(),
+            slug: "rule_loading".to_string(),
+            requirements: vec![
+                SrsRequirement {
+                    id: "FR-100".to_string(),
+                    title: "Default rules".to_string(),
+                    kind: ReqKind::Functional,
+                    priority: Some("Must".to_string()),
+                    state: None,
+                    verification: Some("Test".to_string()),
+                    traces_to: None,
+                    acceptance: None,
+                    description: String::new(),
+                },
+                SrsRequirement {
+                    id: "NFR-100".to_string(),
+                    title: "Fast load".to_string(),
+                    kind: ReqKind::NonFunctional,
+                    priority: None,
+                    state: None,
+                    verification: None,
+                    traces_to: None,
+                    acceptance: None,
+                    description: String::new(),
+                },
+            ],
+            feature_gate: None,
+        }
+    }
+
+    #[test]
+    fn test_render_domain_and_requirement_vars() {
+        let template = "# {{ domain.title }} ({{domain.requirement_count}})\n{{#requirements}}- {{req.id}} [{{req.kind}}] {{req.priority}}\n{{/requirements}}";
+        let out = render("spec.tera", template, &make_domain()).unwrap();
+        assert_eq!(out, "# Rule Loading (2)\n- FR-100 [functional] Must\n- NFR-100 [non-functional] \n");
+    }
+
+    #[test]
+    fn test_render_unknown_placeholder_lists_variables() {
+        let err = render("spec.tera", "{{domain.owner}}", &make_domain()).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("unknown placeholder '{{domain.owner}}'"), "{}", msg);
+        assert!(msg.contains("domain.slug"), "{}", msg);
+        assert!(msg.contains("req.acceptance"), "{}", msg);
+    }
+
+    #[test]
+    fn test_render_requirement_var_outside_block_is_unknown() {
+        let err = render("spec.tera", "{{req.id}}", &make_domain()).unwrap_err();
+        assert!(err.to_string().contains("unknown placeholder"));
+    }
+
+    #[test]
+    fn test_render_unclosed_block_error() {
+        let err = render("spec.tera", "{{#requirements}}{{req.id}}", &make_domain()).unwrap_err();
+        assert!(err.to_string().contains("no matching {{/requirements}}"));
+    }
+}
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::api::types::SrsDomain;
+use crate::api::types::{ReqKind, SrsDomain};
 
 // --- Serializable YAML structs ---
 
@@ -10,7 +10,11 @@ struct FeatureSpec {
     kind: String,
     domain: String,
     section: String,
+    /// Functional (FR-xxx) requirements.
     requirements: Vec<FeatureReq>,
+    /// Non-functional (NFR-xxx) requirements.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    non_functional_requirements: Vec<FeatureReq>,
 }
 
 #[derive(Serialize)]
@@ -98,33 +102,47 @@ struct BrdDomainEntry {
     arch_file: String,
     test_file: String,
     deploy_file: String,
+    requirements: Vec<BrdReqEntry>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BrdReqEntry {
+    id: String,
+    title: String,
+    kind: String,
 }
 
 // --- Generator functions ---
 
 /// Generate a `.spec.yaml` file for a domain.
+///
+/// Functional and non-functional requirements are listed separately;
+/// `REQ-NNN` ids follow SRS order across both so test specs still match.
 pub(crate) fn generate_feature_spec_yaml(domain: &SrsDomain) -> String {
-    let spec = FeatureSpec {
-        kind: "feature_request".to_string(),
-        domain: domain.title.clone(),
-        section: domain.section.clone(),
-        requirements: domain
+    let reqs = |kind: ReqKind| -> Vec<FeatureReq> {
+        domain
             .requirements
             .iter()
             .enumerate()
-            .map(|(idx, req)| {
-                let req_id = format!("REQ-{:03}", idx + 1);
-                FeatureReq {
-                    id: req_id,
-                    source_id: req.id.clone(),
-                    title: req.title.clone(),
-                    priority: req.priority.clone().unwrap_or_else(|| "Unknown".to_string()),
-                    status: req.state.clone().unwrap_or_else(|| "Proposed".to_string()),
-                    verification: req.verification.clone().unwrap_or_else(|| "Test".to_string()),
-                    acceptance: req.acceptance.clone().unwrap_or_default(),
-                }
+            .filter(|(_, req)| req.kind == kind)
+            .map(|(idx, req)| FeatureReq {
+                id: format!("REQ-{:03}", idx + 1),
+                source_id: req.id.clone(),
+                title: req.title.clone(),
+                priority: req.priority.clone().unwrap_or_else(|| "Unknown".to_string()),
+                status: req.state.clone().unwrap_or_else(|| "Proposed".to_string()),
+                verification: req.verification.clone().unwrap_or_else(|| "Test".to_string()),
+                acceptance: req.acceptance.clone().unwrap_or_default(),
             })
-            .collect(),
+            .collect()
+    };
+    let spec = FeatureSpec {
+        kind: "feature_request".to_string(),
+        domain: domain.title.clone(),
+        section: domain.section.clone(),
+        requirements: reqs(ReqKind::Functional),
+        non_functional_requirements: reqs(ReqKind::NonFunctional),
     };
     serde_yml::to_string(&spec).unwrap_or_default()
 }
@@ -218,6 +236,15 @@ pub(crate) fn generate_brd_yaml(domains: &[SrsDomain]) -> String {
                 arch_file: format!("docs/3-design/{}/{}.arch.yaml", d.slug, d.slug),
                 test_file: format!("docs/5-testing/{}/{}.test.yaml", d.slug, d.slug),
                 deploy_file: format!("docs/6-deployment/{}/{}.deploy.yaml", d.slug, d.slug),
+                requirements: d
+                    .requirements
+                    .iter()
+                    .map(|r| BrdReqEntry {
+                        id: r.id.clone(),
+                        title: r.title.clone(),
+                        kind: r.kind.label().to_string(),
+                    })
+                    .collect(),
             })
             .collect(),
     };
@@ -1,5 +1,6 @@
 pub use crate::api::types::{ScaffoldConfig, ScaffoldResult, ScaffoldError};
 pub use crate::api::types::{SrsDomain, SrsRequirement, ReqKind};
-pub use crate::core::scaffold_from_srs;
+pub use crate::core::{scaffold_from_srs, scaffold_from_srs_content};
 pub use crate::core::parser::parse_srs;
 pub use crate::core::load_command_map;
+pub use crate::core::{BEGIN_GENERATED, END_GENERATED};
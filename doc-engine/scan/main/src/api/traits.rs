@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use super::types::{CheckId, CheckResult, ScanConfig, ScanContext, ScanError, ScanReport};
+use super::types::{CheckId, CheckResult, ProgressEvent, ScanConfig, ScanContext, ScanError, ScanReport};
 
 /// Abstracts file system traversal for project scanning.
 ///
@@ -29,6 +29,25 @@ pub trait CheckRunner: Send + Sync {
     fn description(&self) -> &str;
     /// Execute the check against the given [`ScanContext`] and return the result.
     fn run(&self, ctx: &ScanContext) -> CheckResult;
+    /// Return the root-relative paths whose content determines the result.
+    ///
+    /// The scan cache reuses the previous result while every input is
+    /// unchanged, so a path that appears or disappears must change this list.
+    /// Defaults to all scanned files except the audit reports the CLI writes
+    /// on each run.
+    fn inputs(&self, ctx: &ScanContext) -> Vec<PathBuf> {
+        ctx.files.iter()
+            .filter(|path| !is_audit_report(path))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Audit status reports are rewritten by every scan and feed no check.
+fn is_audit_report(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("documentation_audit_report_v") && name.ends_with(".json"))
 }
 
 /// Engine for running documentation compliance scans.
@@ -36,8 +55,19 @@ pub trait CheckRunner: Send + Sync {
 /// Implementors walk a project directory, execute compliance checks, and
 /// produce a [`ScanReport`].
 pub trait ComplianceEngine {
+    /// Scan a project directory, reporting each check's start and finish to
+    /// `on_event` as the scan proceeds.
+    fn scan_with_progress(
+        &self,
+        root: &Path,
+        config: &ScanConfig,
+        on_event: &mut dyn FnMut(ProgressEvent),
+    ) -> Result<ScanReport, ScanError>;
+
     /// Scan a project directory with the supplied [`ScanConfig`].
-    fn scan_with_config(&self, root: &Path, config: &ScanConfig) -> Result<ScanReport, ScanError>;
+    fn scan_with_config(&self, root: &Path, config: &ScanConfig) -> Result<ScanReport, ScanError> {
+        self.scan_with_progress(root, config, &mut |_| {})
+    }
 }
 
 /// Writes a completed scan report to an external destination.
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 use std::fmt;
+use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::SystemTime;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -11,7 +15,7 @@ use serde::{Deserialize, Serialize};
 // ---------------------------------------------------------------------------
 
 /// Newtype wrapping check number 1-65
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct CheckId(pub u8);
 
 impl fmt::Display for CheckId {
@@ -24,13 +28,47 @@ impl fmt::Display for CheckId {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReportFormat {
     /// Human-readable plain text grouped by category.
-    Text,
+    Text {
+        /// Show only failing checks and the summary line.
+        quiet: bool,
+        /// Wrap PASS/FAIL/SKIP in ANSI color codes.
+        color: bool,
+    },
     /// Pretty-printed JSON (serde_json).
     Json,
+    /// Self-contained HTML page with collapsible categories.
+    Html,
+    /// GitHub-flavored Markdown tables, sized for PR comments.
+    Markdown,
+    /// GitHub Actions workflow commands, one annotation per violation.
+    GithubActions,
+    /// RFC 4180 CSV, one row per violation.
+    Csv {
+        /// Also emit a `skipped` row for each skipped check.
+        include_skipped: bool,
+    },
+    /// JUnit XML, one test case per check, for CI test-report viewers.
+    JUnit,
+}
+
+impl ReportFormat {
+    /// Parse a CLI format name (`text`, `json`, `html`, `markdown`, `github`, `csv`, `junit`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(ReportFormat::Text { quiet: false, color: false }),
+            "json" => Some(ReportFormat::Json),
+            "html" => Some(ReportFormat::Html),
+            "markdown" => Some(ReportFormat::Markdown),
+            "github" => Some(ReportFormat::GithubActions),
+            "csv" => Some(ReportFormat::Csv { include_skipped: false }),
+            "junit" => Some(ReportFormat::JUnit),
+            _ => None,
+        }
+    }
 }
 
 /// Severity level of a check violation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     /// A blocking error that must be fixed before release.
@@ -41,8 +79,41 @@ pub enum Severity {
     Info,
 }
 
+impl Severity {
+    /// Parse a CLI severity name (`info`, `warning`, `error`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "info" => Some(Severity::Info),
+            "warning" => Some(Severity::Warning),
+            "error" => Some(Severity::Error),
+            _ => None,
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Severity::Info => 0,
+            Severity::Warning => 1,
+            Severity::Error => 2,
+        }
+    }
+}
+
+/// Severities are ordered `Info < Warning < Error`.
+impl Ord for Severity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// A single violation found by a check.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Violation {
     /// The check that produced this violation.
     pub check_id: CheckId,
@@ -67,7 +138,7 @@ pub struct Violation {
 }
 
 /// Outcome of running a single check.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "status", rename_all = "lowercase")]
 pub enum CheckResult {
     /// The check passed with no violations.
@@ -114,7 +185,7 @@ impl From<io::Error> for ScanError {
 }
 
 /// Project type for filtering checks.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectType {
     /// An open-source project with community-facing requirements.
@@ -124,7 +195,7 @@ pub enum ProjectType {
 }
 
 /// Project scope tier for filtering checks by project size.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectScope {
     /// Small project (5-10 modules): minimal structure.
@@ -141,8 +212,8 @@ pub struct ScanContext {
     pub root: PathBuf,
     /// Relative paths of all files discovered under `root`.
     pub files: Vec<PathBuf>,
-    /// Cached file contents keyed by path.
-    pub file_contents: HashMap<PathBuf, String>,
+    /// Memoized file contents shared by all checks; read through [`ScanContext::read`].
+    pub file_contents: FileCache,
     /// The project type used to filter applicable checks.
     pub project_type: ProjectType,
     /// The project scope tier used to filter checks by project size.
@@ -151,6 +222,58 @@ pub struct ScanContext {
     pub module_filter: Option<Vec<String>>,
 }
 
+impl ScanContext {
+    /// Read a file once per scan and share its contents between checks.
+    ///
+    /// `path` may be absolute or relative to `root`. The first read of a path
+    /// hits the filesystem; later reads, including failed ones, return the
+    /// cached outcome.
+    pub fn read(&self, path: &Path) -> io::Result<Arc<str>> {
+        if path.is_absolute() {
+            self.file_contents.read(path)
+        } else {
+            self.file_contents.read(&self.root.join(path))
+        }
+    }
+}
+
+type CachedRead = Result<Arc<str>, (io::ErrorKind, String)>;
+
+/// Per-scan memoizing file reader.
+///
+/// Each path is read from disk at most once, even when checks run on
+/// parallel workers; concurrent readers of the same path wait for the first.
+#[derive(Debug, Default)]
+pub struct FileCache {
+    entries: Mutex<HashMap<PathBuf, Arc<OnceLock<CachedRead>>>>,
+    disk_reads: AtomicUsize,
+}
+
+impl FileCache {
+    /// Read `path`, returning the cached contents after the first call.
+    pub fn read(&self, path: &Path) -> io::Result<Arc<str>> {
+        let slot = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            Arc::clone(entries.entry(path.to_path_buf()).or_default())
+        };
+        let cached = slot.get_or_init(|| {
+            self.disk_reads.fetch_add(1, Ordering::Relaxed);
+            fs::read_to_string(path)
+                .map(Arc::from)
+                .map_err(|e| (e.kind(), e.to_string()))
+        });
+        match cached {
+            Ok(content) => Ok(Arc::clone(content)),
+            Err((kind, message)) => Err(io::Error::new(*kind, message.clone())),
+        }
+    }
+
+    /// Number of filesystem reads performed so far.
+    pub fn disk_reads(&self) -> usize {
+        self.disk_reads.load(Ordering::Relaxed)
+    }
+}
+
 /// Returns the current UTC time as an ISO 8601 string (e.g. "2026-02-10T14:30:00Z").
 ///
 /// Uses Howard Hinnant's civil_from_days algorithm on `std::time::SystemTime`.
@@ -203,10 +326,24 @@ pub struct ScanConfig {
     pub phases: Option<Vec<String>>,
     /// Optional module name filter; `None` checks all discovered modules.
     pub module_filter: Option<Vec<String>>,
+    /// Optional directory for the result cache; `None` disables caching.
+    pub cache_dir: Option<PathBuf>,
+    /// Worker threads for check execution; `None` uses one per CPU core.
+    pub jobs: Option<usize>,
+    /// Glob patterns for paths to leave out of the scan, relative to the root.
+    /// A pattern matching a directory excludes everything below it.
+    pub exclude_globs: Vec<String>,
+    /// Severity to record for every violation of a check, replacing the
+    /// rule's own. A failing check overridden to `info` is advisory: it keeps
+    /// its violations but counts as passed in the summary.
+    pub severity_overrides: HashMap<CheckId, Severity>,
+    /// Wall-clock limit for a single check in milliseconds; a check still
+    /// running at the deadline is recorded as failed. `None` disables the limit.
+    pub check_timeout_ms: Option<u64>,
 }
 
 /// Enriched check entry with metadata per DR-01.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CheckEntry {
     /// Unique numeric identifier for the check.
     pub id: CheckId,
@@ -216,10 +353,14 @@ pub struct CheckEntry {
     pub description: String,
     /// The outcome of running this check.
     pub result: CheckResult,
+    /// Wall-clock time spent running this check; `None` when the check was
+    /// skipped or its result came from the cache.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
 }
 
 /// Summary of scan results.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScanSummary {
     /// Total number of checks executed.
     pub total: u8,
@@ -231,8 +372,29 @@ pub struct ScanSummary {
     pub skipped: u8,
 }
 
+/// Scan progress reported to the callback of
+/// [`scan_with_progress`](crate::scan_with_progress), on the scanning thread.
+#[derive(Debug, Clone)]
+pub enum ProgressEvent {
+    /// Checks have been selected; `total` of them will report `CheckFinished`.
+    ScanStarted { total: usize },
+    /// A check is about to run. Not sent for skipped or cached checks.
+    CheckStarted { id: CheckId, description: String },
+    /// A check's result is final, whether it ran, was skipped or came from the cache.
+    CheckFinished { id: CheckId, description: String, result: CheckResult },
+}
+
+/// Scan cache hit/miss counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CacheStats {
+    /// Checks whose result was reused from the cache.
+    pub hits: usize,
+    /// Checks that were evaluated and written to the cache.
+    pub misses: usize,
+}
+
 /// Complete scan report (ISO/IEC/IEEE 15289:2019 clause 9.2).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScanReport {
     /// ISO standard identifier.
     pub standard: String,
@@ -254,6 +416,96 @@ pub struct ScanReport {
     pub project_type: ProjectType,
     /// The project scope tier that was used during this scan.
     pub project_scope: ProjectScope,
+    /// Cache statistics; `None` when caching was disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheStats>,
+    /// Wall-clock time of the whole scan, including discovery.
+    #[serde(default)]
+    pub duration_ms: u64,
+}
+
+impl ScanReport {
+    /// Highest severity among the violations of failed checks; `None` when
+    /// no check failed.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.results
+            .iter()
+            .filter_map(|entry| match &entry.result {
+                CheckResult::Fail { violations } => Some(violations),
+                _ => None,
+            })
+            .flatten()
+            .map(|v| v.severity.clone())
+            .max()
+    }
+}
+
+/// How one check's outcome moved between two scans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffStatus {
+    /// Failed in the new scan but not in the old one.
+    Regressed,
+    /// Failed in the old scan but not in the new one.
+    Fixed,
+    /// Same failing/non-failing state in both scans.
+    Unchanged,
+    /// Only present in the new scan.
+    Added,
+    /// Only present in the old scan.
+    Removed,
+}
+
+/// One check's entry in a [`ReportDiff`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckDiff {
+    /// Check identifier shared by both reports.
+    pub id: CheckId,
+    /// Category label, taken from the newer report when present.
+    pub category: String,
+    /// Check description, taken from the newer report when present.
+    pub description: String,
+    /// Classification of the change.
+    pub status: DiffStatus,
+    /// Outcome in the old report (`pass`, `fail`, `skip`); `None` when added.
+    pub old: Option<String>,
+    /// Outcome in the new report; `None` when removed.
+    pub new: Option<String>,
+}
+
+/// Per-status check counts of a [`ReportDiff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffSummary {
+    /// Checks that newly fail.
+    pub regressed: usize,
+    /// Checks that no longer fail.
+    pub fixed: usize,
+    /// Checks whose failing state did not change.
+    pub unchanged: usize,
+    /// Checks only in the new report.
+    pub added: usize,
+    /// Checks only in the old report.
+    pub removed: usize,
+}
+
+/// Check-by-check comparison of two scan reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportDiff {
+    /// Timestamp of the old report.
+    pub old_timestamp: String,
+    /// Timestamp of the new report.
+    pub new_timestamp: String,
+    /// One entry per check id in either report, ordered by id.
+    pub checks: Vec<CheckDiff>,
+    /// Counts per status.
+    pub summary: DiffSummary,
+}
+
+impl ReportDiff {
+    /// Checks with the given status, in id order.
+    pub fn with_status(&self, status: DiffStatus) -> impl Iterator<Item = &CheckDiff> {
+        self.checks.iter().filter(move |c| c.status == status)
+    }
 }
 
 /// Parsed rule set from TOML.
@@ -286,6 +538,15 @@ pub struct RuleDef {
     pub module_filter: Option<Vec<String>>,
     /// Optional custom fix hint from TOML; overrides auto-generated hint.
     pub fix_hint: Option<String>,
+    /// Optional numeric threshold for heuristic builtins; `None` uses the handler default.
+    pub threshold: Option<f64>,
+    /// Optional list of required topics for content-coverage builtins; `None` uses the handler default.
+    pub topics: Option<Vec<String>>,
+    /// Optional list of accepted values for value-validating builtins; `None` uses the handler default.
+    pub allowed_values: Option<Vec<String>>,
+    /// Longer explanation shown by `rules explain`, from the TOML `rationale` key
+    /// or the `# Check N:` comment block above the rule.
+    pub rationale: Option<String>,
 }
 
 /// The type of a rule -- declarative or builtin.
@@ -362,6 +623,23 @@ pub enum RuleType {
 }
 
 impl RuleType {
+    /// Return the path or glob a declarative rule inspects; `None` for builtins,
+    /// whose inputs are chosen by the handler.
+    pub fn target(&self) -> Option<&str> {
+        match self {
+            RuleType::FileExists { path }
+            | RuleType::DirExists { path }
+            | RuleType::DirNotExists { path, .. }
+            | RuleType::FileContentMatches { path, .. }
+            | RuleType::FileContentNotMatches { path, .. } => Some(path),
+            RuleType::GlobContentMatches { glob, .. }
+            | RuleType::GlobContentNotMatches { glob, .. }
+            | RuleType::GlobNamingMatches { glob, .. }
+            | RuleType::GlobNamingNotMatches { glob, .. } => Some(glob),
+            RuleType::Builtin { .. } => None,
+        }
+    }
+
     /// Return a stable machine-readable tag for this rule type.
     pub fn to_tag(&self) -> String {
         match self {
@@ -433,6 +711,66 @@ mod tests {
         assert!(ProjectScope::Small < ProjectScope::Large);
     }
 
+    #[test]
+    fn test_severity_ordering() {
+        assert!(Severity::Info < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+        assert_eq!(Severity::from_name("error"), Some(Severity::Error));
+        assert_eq!(Severity::from_name("fatal"), None);
+    }
+
+    fn report_with(results: Vec<CheckEntry>) -> ScanReport {
+        ScanReport {
+            standard: "ISO/IEC/IEEE 15289:2019".to_string(),
+            clause: "9.2".to_string(),
+            tool: "doc-engine".to_string(),
+            tool_version: "0.1.0".to_string(),
+            timestamp: iso8601_now(),
+            project_root: "/tmp/project".to_string(),
+            results,
+            summary: ScanSummary { total: 0, passed: 0, failed: 0, skipped: 0 },
+            project_type: ProjectType::OpenSource,
+            project_scope: ProjectScope::Large,
+            cache: None,
+            duration_ms: 0,
+        }
+    }
+
+    fn failed_entry(id: u8, severities: &[Severity]) -> CheckEntry {
+        CheckEntry {
+            id: CheckId(id),
+            category: "structure".to_string(),
+            description: "test".to_string(),
+            result: CheckResult::Fail {
+                violations: severities.iter().map(|sev| Violation {
+                    check_id: CheckId(id),
+                    path: None,
+                    message: "bad".to_string(),
+                    severity: sev.clone(),
+                    rule_type: String::new(),
+                    expected: None,
+                    actual: None,
+                    fix_hint: String::new(),
+                }).collect(),
+            },
+            duration_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_max_severity_picks_highest() {
+        let report = report_with(vec![
+            failed_entry(1, &[Severity::Info]),
+            failed_entry(2, &[Severity::Warning, Severity::Info]),
+        ]);
+        assert_eq!(report.max_severity(), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn test_max_severity_none_without_failures() {
+        assert_eq!(report_with(vec![]).max_severity(), None);
+    }
+
     #[test]
     fn test_project_scope_equality() {
         assert_eq!(ProjectScope::Small, ProjectScope::Small);
@@ -448,4 +786,44 @@ mod tests {
         let deserialized: ProjectScope = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized, ProjectScope::Medium);
     }
+
+    #[test]
+    fn test_file_cache_reads_each_path_once() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("a.md");
+        fs::write(&path, "first").unwrap();
+        let cache = FileCache::default();
+        assert_eq!(&*cache.read(&path).unwrap(), "first");
+        fs::write(&path, "second").unwrap();
+        assert_eq!(&*cache.read(&path).unwrap(), "first");
+        assert_eq!(cache.disk_reads(), 1);
+    }
+
+    #[test]
+    fn test_file_cache_caches_missing_files() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("missing.md");
+        let cache = FileCache::default();
+        let err = cache.read(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(cache.read(&path).is_err());
+        assert_eq!(cache.disk_reads(), 1);
+    }
+
+    #[test]
+    fn test_scan_context_read_resolves_relative_paths() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        fs::write(tmp.path().join("README.md"), "hello").unwrap();
+        let ctx = ScanContext {
+            root: tmp.path().to_path_buf(),
+            files: vec![],
+            file_contents: FileCache::default(),
+            project_type: ProjectType::OpenSource,
+            project_scope: ProjectScope::Small,
+            module_filter: None,
+        };
+        assert_eq!(&*ctx.read(Path::new("README.md")).unwrap(), "hello");
+        assert_eq!(&*ctx.read(&tmp.path().join("README.md")).unwrap(), "hello");
+        assert_eq!(ctx.file_contents.disk_reads(), 1);
+    }
 }
@@ -1,4 +1,5 @@
-use std::fs;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
 use std::sync::LazyLock;
 
 use regex::Regex;
@@ -9,6 +10,16 @@ use crate::api::types::{CheckId, CheckResult, ScanContext, Violation};
 
 static ADR_NAMING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{3}-[a-z0-9_-]+\.md$").unwrap());
 static ADR_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{3}-").unwrap());
+static ADR_NUMBER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d{3})-").unwrap());
+static STATUS_INLINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?im)^\s*(?:\*\*)?Status(?:\*\*)?\s*:\s*(?:\*\*)?\s*([A-Za-z]+)").unwrap()
+});
+static SUPERSEDED_BY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)superseded\s+by\s+\[?(?:ADR[- ]?(\d+)|(\d{3})-[a-z0-9_-]+\.md)").unwrap()
+});
+static ADR_REF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bADR[- ]?(\d+)\b|\b(\d{3})-[a-z0-9_-]+\.md").unwrap()
+});
 
 /// Check 49: adr_naming
 /// ADR files follow NNN-title.md naming convention
@@ -101,7 +112,7 @@ impl CheckRunner for AdrIndexCompleteness {
             return CheckResult::Skip { reason: "No ADR index file found".to_string() };
         };
 
-        let index_content = match fs::read_to_string(&index_path) {
+        let index_content = match ctx.read(&index_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -155,12 +166,153 @@ impl CheckRunner for AdrIndexCompleteness {
     }
 }
 
+/// Status line of an ADR: either a `Status: X` header or the first line
+/// under a `## Status` heading. Returns the first word, e.g. `Superseded`.
+fn adr_status(content: &str) -> Option<String> {
+    if let Some(caps) = STATUS_INLINE_RE.captures(content) {
+        return Some(caps[1].to_string());
+    }
+
+    let mut lines = content.lines().map(str::trim);
+    lines.find(|l| l.starts_with('#') && l.trim_start_matches('#').trim().eq_ignore_ascii_case("status"))?;
+    lines.find(|l| !l.is_empty())
+        .and_then(|l| l.trim_matches('*').split_whitespace().next())
+        .map(|w| w.trim_matches(|c: char| !c.is_ascii_alphabetic()).to_string())
+}
+
+/// ADR number named by the first `Superseded by ADR-NNN` (or `Superseded by NNN-title.md`) link.
+fn superseded_by(content: &str) -> Option<u32> {
+    SUPERSEDED_BY_RE.captures(content)
+        .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .and_then(|m| m.as_str().parse().ok())
+}
+
+/// Every ADR number mentioned as `ADR-NNN` or linked as `NNN-title.md`.
+fn referenced_adrs(content: &str) -> BTreeSet<u32> {
+    ADR_REF_RE.captures_iter(content)
+        .filter_map(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .filter_map(|m| m.as_str().parse().ok())
+        .collect()
+}
+
+struct AdrRecord {
+    path: PathBuf,
+    status: Option<String>,
+    superseded_by: Option<u32>,
+    references: BTreeSet<u32>,
+}
+
+/// Check 147: adr_status_consistency
+/// Superseded ADRs name an existing successor that references them back (FR-924)
+pub struct AdrStatusConsistency {
+    pub def: RuleDef,
+}
+
+impl AdrStatusConsistency {
+    fn violation(&self, path: &std::path::Path, message: String) -> Violation {
+        Violation {
+            check_id: CheckId(self.def.id),
+            path: Some(path.to_path_buf()),
+            message,
+            severity: self.def.severity.clone(),
+            rule_type: self.def.rule_type.to_tag(),
+            expected: None,
+            actual: None,
+            fix_hint: self.def.fix_hint.clone()
+                .unwrap_or_else(|| "Add 'Superseded by ADR-NNN' to the old ADR and 'Supersedes ADR-NNN' to its successor".to_string()),
+        }
+    }
+}
+
+impl CheckRunner for AdrStatusConsistency {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let adr_dir = ctx.root.join("docs/3-design/adr");
+        if !adr_dir.is_dir() {
+            return CheckResult::Skip { reason: "ADR directory does not exist".to_string() };
+        }
+
+        let mut adrs: BTreeMap<u32, AdrRecord> = BTreeMap::new();
+        for file in &ctx.files {
+            let s = file.to_string_lossy();
+            if !s.starts_with("docs/3-design/adr/") || !s.ends_with(".md") {
+                continue;
+            }
+            let filename = file.file_name()
+                .map(|f| f.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let number: u32 = match ADR_NUMBER_RE.captures(&filename).and_then(|c| c[1].parse().ok()) {
+                Some(n) => n,
+                None => continue,
+            };
+            let content = match ctx.read(&ctx.root.join(file)) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            let mut references = referenced_adrs(&content);
+            references.remove(&number);
+            adrs.insert(number, AdrRecord {
+                path: file.to_path_buf(),
+                status: adr_status(&content),
+                superseded_by: superseded_by(&content),
+                references,
+            });
+        }
+
+        if adrs.is_empty() {
+            return CheckResult::Skip { reason: "No ADR files found".to_string() };
+        }
+
+        let mut violations = Vec::new();
+        for (number, adr) in &adrs {
+            let superseded = adr.status.as_deref()
+                .is_some_and(|s| s.eq_ignore_ascii_case("superseded"));
+            if !superseded {
+                continue;
+            }
+
+            let successor = match adr.superseded_by {
+                Some(n) => n,
+                None => {
+                    violations.push(self.violation(&adr.path, format!(
+                        "ADR-{:03} is marked Superseded but names no successor", number
+                    )));
+                    continue;
+                }
+            };
+
+            match adrs.get(&successor) {
+                None => violations.push(self.violation(&adr.path, format!(
+                    "ADR-{:03} is superseded by ADR-{:03}, which does not exist", number, successor
+                ))),
+                Some(next) if successor == *number => violations.push(self.violation(&next.path, format!(
+                    "ADR-{:03} names itself as its successor", number
+                ))),
+                Some(next) if !next.references.contains(number) => violations.push(self.violation(&next.path, format!(
+                    "ADR-{:03} supersedes ADR-{:03} but does not reference it", successor, number
+                ))),
+                Some(_) => {}
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
@@ -176,6 +328,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -183,7 +339,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -267,4 +423,67 @@ mod tests {
         let ctx = make_ctx(tmp.path(), files);
         assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
     }
+
+    // --- AdrStatusConsistency (check 147) ---
+
+    fn write_adrs(root: &std::path::Path, adrs: &[(&str, &str)]) -> Vec<PathBuf> {
+        let adr_dir = root.join("docs/3-design/adr");
+        fs::create_dir_all(&adr_dir).unwrap();
+        adrs.iter()
+            .map(|(name, content)| {
+                fs::write(adr_dir.join(name), content).unwrap();
+                PathBuf::from(format!("docs/3-design/adr/{}", name))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_adr_status_consistency_valid_chain() {
+        let tmp = TempDir::new().unwrap();
+        let files = write_adrs(tmp.path(), &[
+            ("001-use-make.md", "# ADR-001\n\n## Status\n\nSuperseded by [ADR-002](002-use-cargo-xtask.md)\n"),
+            ("002-use-cargo-xtask.md", "# ADR-002\n\n**Status**: Superseded by ADR-003\n\nSupersedes ADR-001.\n"),
+            ("003-use-just.md", "# ADR-003\n\nStatus: Accepted\n\nSupersedes [ADR-002](002-use-cargo-xtask.md).\n"),
+        ]);
+        let handler = AdrStatusConsistency { def: make_def(147) };
+        let ctx = make_ctx(tmp.path(), files);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_adr_status_consistency_dangling_supersession() {
+        let tmp = TempDir::new().unwrap();
+        let files = write_adrs(tmp.path(), &[
+            ("001-use-make.md", "# ADR-001\n\n## Status\n\nSuperseded by ADR-007\n"),
+            ("002-use-rust.md", "# ADR-002\n\n## Status\n\nSuperseded\n"),
+        ]);
+        let handler = AdrStatusConsistency { def: make_def(147) };
+        let ctx = make_ctx(tmp.path(), files);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 2);
+                assert!(violations[0].message.contains("ADR-007, which does not exist"));
+                assert!(violations[1].message.contains("names no successor"));
+            }
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_adr_status_consistency_successor_without_backlink() {
+        let tmp = TempDir::new().unwrap();
+        let files = write_adrs(tmp.path(), &[
+            ("001-use-make.md", "# ADR-001\n\nStatus: Superseded by ADR-002\n"),
+            ("002-use-just.md", "# ADR-002\n\nStatus: Accepted\n"),
+        ]);
+        let handler = AdrStatusConsistency { def: make_def(147) };
+        let ctx = make_ctx(tmp.path(), files);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].path, Some(PathBuf::from("docs/3-design/adr/002-use-just.md")));
+            }
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
 }
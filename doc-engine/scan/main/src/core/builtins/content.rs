@@ -1,11 +1,11 @@
-use std::fs;
+use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 
 use regex::Regex;
 
 use crate::api::types::RuleDef;
 use crate::api::traits::CheckRunner;
-use crate::api::types::{CheckId, CheckResult, ScanContext, Violation};
+use crate::api::types::{CheckId, CheckResult, ScanContext, Severity, Violation};
 use crate::core::regex_utils::find_hardcoded_path;
 
 static TLDR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\*\*TLDR\*\*|## TLDR|## TL;DR").unwrap());
@@ -14,6 +14,9 @@ static GLOSSARY_VALID_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\*\*[
 static GLOSSARY_TERM_CAPTURE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\*\*([^*]+)\*\*").unwrap());
 static GLOSSARY_TERM_DEF_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\*\*([^*]+)\*\*\s*[-—–:]\s*(.*)").unwrap());
 static ACRONYM_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Z]{2,}$").unwrap());
+static ATX_HEADING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^ {0,3}(#{1,6})(?:[ \t]+(.*?))?[ \t]*$").unwrap());
+static AUDIENCE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*\*\*Audience\*\*:(.*)$").unwrap());
+static LIST_ITEM_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(?:[-*+]|\d{1,9}[.)])(?:\s|$)").unwrap());
 
 /// Checks 35-36: tldr_conditional
 /// 35: Docs >=200 lines should have TLDR
@@ -42,7 +45,7 @@ impl CheckRunner for TldrConditional {
         let mut violations = Vec::new();
         for file in &docs_files {
             let full = ctx.root.join(file);
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&full) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -118,7 +121,7 @@ impl CheckRunner for GlossaryFormat {
             return CheckResult::Skip { reason: "docs/glossary.md not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&glossary_path) {
+        let content = match ctx.read(&glossary_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -174,7 +177,7 @@ impl CheckRunner for GlossaryAlphabetized {
             return CheckResult::Skip { reason: "docs/glossary.md not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&glossary_path) {
+        let content = match ctx.read(&glossary_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -238,7 +241,7 @@ impl CheckRunner for GlossaryAcronyms {
             return CheckResult::Skip { reason: "docs/glossary.md not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&glossary_path) {
+        let content = match ctx.read(&glossary_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -286,6 +289,162 @@ impl CheckRunner for GlossaryAcronyms {
     }
 }
 
+/// Check 145: glossary_no_duplicates
+/// Each glossary term is defined once (case-insensitive); duplicates whose
+/// definitions differ are reported one severity level higher.
+pub struct GlossaryNoDuplicates {
+    pub def: RuleDef,
+}
+
+/// Definition text compared for conflicts: case, spacing and a trailing
+/// period are ignored.
+fn normalized_definition(definition: &str) -> String {
+    definition.trim().trim_end_matches('.').split_whitespace()
+        .collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+impl CheckRunner for GlossaryNoDuplicates {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let glossary_path = ctx.root.join("docs/glossary.md");
+        if !glossary_path.exists() {
+            return CheckResult::Skip { reason: "docs/glossary.md not found".to_string() };
+        }
+
+        let content = match ctx.read(&glossary_path) {
+            Ok(c) => c,
+            Err(e) => {
+                return CheckResult::Skip {
+                    reason: format!("Cannot read glossary: {}", e),
+                };
+            }
+        };
+
+        // Entries grouped by lowercased term, in order of first appearance
+        let mut order: Vec<String> = Vec::new();
+        let mut entries: HashMap<String, Vec<(usize, String, String)>> = HashMap::new();
+        for (i, line) in content.lines().enumerate() {
+            if let Some(caps) = GLOSSARY_TERM_DEF_RE.captures(line.trim()) {
+                let term = caps[1].trim().to_string();
+                let key = term.to_lowercase();
+                if !entries.contains_key(&key) {
+                    order.push(key.clone());
+                }
+                entries.entry(key).or_default().push((i + 1, term, normalized_definition(&caps[2])));
+            }
+        }
+
+        let mut violations = Vec::new();
+        for key in order {
+            let group = &entries[&key];
+            if group.len() < 2 {
+                continue;
+            }
+            let lines = group.iter().map(|(line, _, _)| line.to_string()).collect::<Vec<_>>().join(", ");
+            let conflicting = group.iter().any(|(_, _, def)| *def != group[0].2);
+            let (message, severity) = if conflicting {
+                let severity = match self.def.severity {
+                    Severity::Info => Severity::Warning,
+                    _ => Severity::Error,
+                };
+                (format!("Term '{}' is defined {} times with conflicting definitions (lines {})", group[0].1, group.len(), lines), severity)
+            } else {
+                (format!("Term '{}' is defined {} times (lines {})", group[0].1, group.len(), lines), self.def.severity.clone())
+            };
+            violations.push(Violation {
+                check_id: CheckId(self.def.id),
+                path: Some("docs/glossary.md".into()),
+                message,
+                severity,
+                rule_type: self.def.rule_type.to_tag(),
+                expected: Some("one entry per term".to_string()),
+                actual: Some(format!("lines {}", lines)),
+                fix_hint: self.def.fix_hint.clone()
+                    .unwrap_or_else(|| "Merge the duplicate entries into a single definition".to_string()),
+            });
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Check 132: glossary_completeness
+/// Glossary term count is proportionate to the size of the doc tree.
+/// The rule's `threshold` is the minimum terms-per-doc ratio (default 0.1).
+pub struct GlossaryCompleteness {
+    pub def: RuleDef,
+}
+
+/// Default minimum ratio of glossary terms to markdown docs under `docs/`.
+const DEFAULT_GLOSSARY_RATIO: f64 = 0.1;
+
+impl CheckRunner for GlossaryCompleteness {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let glossary_path = ctx.root.join("docs/glossary.md");
+        if !glossary_path.exists() {
+            return CheckResult::Skip { reason: "docs/glossary.md not found".to_string() };
+        }
+
+        let content = match ctx.read(&glossary_path) {
+            Ok(c) => c,
+            Err(e) => {
+                return CheckResult::Skip {
+                    reason: format!("Cannot read glossary: {}", e),
+                };
+            }
+        };
+
+        let doc_count = ctx.files.iter()
+            .filter(|f| {
+                let s = f.to_string_lossy().replace('\\', "/");
+                s.starts_with("docs/") && s.ends_with(".md") && s != "docs/glossary.md"
+            })
+            .count();
+        if doc_count == 0 {
+            return CheckResult::Skip { reason: "No markdown docs under docs/".to_string() };
+        }
+
+        let term_count = content.lines()
+            .filter(|line| GLOSSARY_TERM_RE.is_match(line.trim()))
+            .count();
+
+        let threshold = self.def.threshold.unwrap_or(DEFAULT_GLOSSARY_RATIO);
+        let ratio = term_count as f64 / doc_count as f64;
+        if ratio >= threshold {
+            return CheckResult::Pass;
+        }
+
+        let expected_terms = (threshold * doc_count as f64).ceil() as usize;
+        CheckResult::Fail {
+            violations: vec![Violation {
+                check_id: CheckId(self.def.id),
+                path: Some("docs/glossary.md".into()),
+                message: format!(
+                    "Glossary has {} terms for {} docs (ratio {:.2}, minimum {:.2}); it may be under-maintained",
+                    term_count, doc_count, ratio, threshold
+                ),
+                severity: self.def.severity.clone(),
+                rule_type: self.def.rule_type.to_tag(),
+                expected: Some(format!("at least {} terms", expected_terms)),
+                actual: Some(format!("{} terms", term_count)),
+                fix_hint: self.def.fix_hint.clone()
+                    .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
+            }],
+        }
+    }
+}
+
 /// Check 75: readme_line_count
 /// Root README.md should be under 100 lines.
 pub struct ReadmeLineCount {
@@ -303,7 +462,7 @@ impl CheckRunner for ReadmeLineCount {
             return CheckResult::Skip { reason: "README.md not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&readme) {
+        let content = match ctx.read(&readme) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -316,33 +475,708 @@ impl CheckRunner for ReadmeLineCount {
         if line_count <= 100 {
             CheckResult::Pass
         } else {
-            CheckResult::Fail {
-                violations: vec![Violation {
-                    check_id: CheckId(self.def.id),
-                    path: Some("README.md".into()),
-                    message: format!(
-                        "README.md has {} lines; should be under 100 lines",
-                        line_count
-                    ),
-                    severity: self.def.severity.clone(),
-                    rule_type: self.def.rule_type.to_tag(),
-                    expected: None,
-                    actual: None,
-                    fix_hint: self.def.fix_hint.clone()
-                        .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
-                }],
+            CheckResult::Fail {
+                violations: vec![Violation {
+                    check_id: CheckId(self.def.id),
+                    path: Some("README.md".into()),
+                    message: format!(
+                        "README.md has {} lines; should be under 100 lines",
+                        line_count
+                    ),
+                    severity: self.def.severity.clone(),
+                    rule_type: self.def.rule_type.to_tag(),
+                    expected: None,
+                    actual: None,
+                    fix_hint: self.def.fix_hint.clone()
+                        .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
+                }],
+            }
+        }
+    }
+}
+
+/// Check 129: hardcoded_path_detection
+/// No hardcoded absolute paths in documentation (FR-832)
+pub struct HardcodedPathDetection {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for HardcodedPathDetection {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let docs_files: Vec<_> = ctx.files.iter()
+            .filter(|f| {
+                let s = f.to_string_lossy();
+                s.starts_with("docs/") && s.ends_with(".md")
+            })
+            .collect();
+
+        if docs_files.is_empty() {
+            return CheckResult::Skip { reason: "No .md files in docs/".to_string() };
+        }
+
+        let mut violations = Vec::new();
+        for file in &docs_files {
+            let full = ctx.root.join(file);
+            let content = match ctx.read(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let mut in_code_fence = false;
+            for (i, line) in content.lines().enumerate() {
+                if line.trim_start().starts_with("```") {
+                    in_code_fence = !in_code_fence;
+                    continue;
+                }
+                if in_code_fence {
+                    continue;
+                }
+
+                if let Some(m) = find_hardcoded_path(line) {
+                    violations.push(Violation {
+                        check_id: CheckId(self.def.id),
+                        path: Some(file.to_path_buf()),
+                        message: format!(
+                            "Line {}: hardcoded absolute path '{}'",
+                            i + 1,
+                            m.as_str()
+                        ),
+                        severity: self.def.severity.clone(),
+                        rule_type: self.def.rule_type.to_tag(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: self.def.fix_hint.clone()
+                            .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
+                    });
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Default CLI surface for Check 142, one `BINARY [SUBCOMMAND...] [--flag...]`
+/// entry per command; overridden by the rule's `allowed_values`.
+const DEFAULT_KNOWN_COMMANDS: &[&str] = &[
+    "doc-engine scan --json --html --markdown --github --csv --csv-skipped --checks --type --scope --config --rules --phase --module --output -o --emit \
+     --no-cache --cache-dir --cache --jobs --check-timeout-ms --exclude --fail-on --timings --quiet -q --color --severity --db --kafka-config --kafka-broker \
+     --kafka-topic --kafka-client-id --kafka-partition --kafka-timeout",
+    "doc-engine scaffold --output -o --force --phase --type --feature --exclude-feature \
+     --command-map --template-dir --dry-run --merge --report",
+    "doc-engine diff --json",
+    "doc-engine schema",
+    "doc-engine rules list --rules --json",
+    "doc-engine rules explain --rules",
+    "doc-engine ai chat",
+    "doc-engine ai audit --scope",
+    "doc-engine ai generate-commands --output -o --merge --all --no-cache",
+];
+
+/// Flags every command accepts.
+const GLOBAL_FLAGS: &[&str] = &["--help", "-h", "--version", "-V"];
+
+/// Tokens that end one command and start another in a span.
+const SHELL_SEPARATORS: &[&str] = &["|", "||", "&&", ";", ">", ">>", "<", "2>&1"];
+
+/// One known command: its subcommand path and accepted flags.
+struct KnownCommand {
+    binary: String,
+    path: Vec<String>,
+    flags: HashSet<String>,
+}
+
+fn parse_known_commands(entries: &[String]) -> Vec<KnownCommand> {
+    entries.iter().filter_map(|entry| {
+        let mut tokens = entry.split_whitespace();
+        let binary = tokens.next()?.to_string();
+        let (flags, path): (Vec<&str>, Vec<&str>) = tokens.partition(|t| t.starts_with('-'));
+        Some(KnownCommand {
+            binary,
+            path: path.into_iter().map(str::to_string).collect(),
+            flags: flags.into_iter().map(str::to_string).collect(),
+        })
+    }).collect()
+}
+
+/// Iterator over single-backtick code spans in a line.
+/// Skips double/triple backtick runs.
+///
+/// Mirrors scaffold's `BacktickScanner`.
+struct BacktickScanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for BacktickScanner<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let bytes = self.text.as_bytes();
+        let len = bytes.len();
+        while self.pos < len {
+            if bytes[self.pos] == b'`' {
+                let start = self.pos;
+                while self.pos < len && bytes[self.pos] == b'`' {
+                    self.pos += 1;
+                }
+                if self.pos - start != 1 {
+                    continue;
+                }
+                let content_start = self.pos;
+                while self.pos < len && bytes[self.pos] != b'`' {
+                    self.pos += 1;
+                }
+                if self.pos < len {
+                    let span = &self.text[content_start..self.pos];
+                    self.pos += 1;
+                    if !span.is_empty() {
+                        return Some(span);
+                    }
+                }
+            } else {
+                self.pos += 1;
+            }
+        }
+        None
+    }
+}
+
+/// Whether a backtick span looks like a runnable CLI command: starts with a
+/// letter, has arguments, and is not a key-value pair.
+///
+/// Mirrors scaffold's `is_command_like`.
+fn is_command_like(span: &str) -> bool {
+    span.as_bytes().first().is_some_and(|b| b.is_ascii_alphabetic())
+        && span.contains(' ')
+        && !span.contains(": ")
+        && !span.contains("= ")
+}
+
+/// Unrecognized tokens in a command span that invokes a known binary.
+///
+/// Checks the subcommand path first; flags are only checked once the
+/// subcommand is recognized. Positional arguments after a complete
+/// subcommand (paths, values) are not checked.
+fn unknown_tokens(span: &str, known: &[KnownCommand]) -> Vec<String> {
+    let mut tokens = span.split_whitespace()
+        .take_while(|t| !SHELL_SEPARATORS.contains(t))
+        .map(|t| t.trim_matches(|c| c == '[' || c == ']'));
+    let Some(binary) = tokens.next() else { return vec![] };
+    let commands: Vec<&KnownCommand> = known.iter().filter(|k| k.binary == binary).collect();
+    if commands.is_empty() {
+        return vec![];
+    }
+    let args: Vec<&str> = tokens.collect();
+
+    // Walk the leading words down the subcommand tree
+    let words: Vec<&str> = args.iter().copied().take_while(|t| !t.starts_with('-')).collect();
+    let mut matched = commands.iter().find(|k| k.path.is_empty()).copied();
+    for depth in 0..words.len() {
+        let prefix = &words[..=depth];
+        if let Some(exact) = commands.iter().find(|k| k.path == prefix) {
+            matched = Some(*exact);
+            continue;
+        }
+        let deeper = commands.iter().any(|k| k.path.len() > prefix.len() && k.path[..prefix.len()] == *prefix);
+        if deeper {
+            continue;
+        }
+        // Positional argument of the subcommand matched so far
+        if matched.is_some_and(|k| k.path.len() == depth) {
+            break;
+        }
+        // Placeholder such as `<command>`: nothing to verify
+        if words[depth].starts_with('<') {
+            return vec![];
+        }
+        return vec![words[depth].to_string()];
+    }
+    let Some(command) = matched else { return vec![] };
+
+    args.iter()
+        .filter(|t| t.starts_with('-') && t.len() > 1)
+        .map(|t| t.split('=').next().unwrap_or(t))
+        .filter(|flag| !command.flags.contains(*flag) && !GLOBAL_FLAGS.contains(flag))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Check 142: doc_commands_valid
+/// Example commands in docs invoke known subcommands and flags (FR-919)
+pub struct DocCommandsValid {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for DocCommandsValid {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let md_files: Vec<_> = ctx.files.iter()
+            .filter(|f| f.to_string_lossy().ends_with(".md"))
+            .collect();
+
+        if md_files.is_empty() {
+            return CheckResult::Skip { reason: "No .md files found".to_string() };
+        }
+
+        let entries: Vec<String> = match &self.def.allowed_values {
+            Some(values) => values.clone(),
+            None => DEFAULT_KNOWN_COMMANDS.iter().map(|s| s.to_string()).collect(),
+        };
+        let known = parse_known_commands(&entries);
+
+        let mut violations = Vec::new();
+        for file in &md_files {
+            let full = ctx.root.join(file);
+            let content = match ctx.read(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            for (line_no, line) in lines_outside_fences(&content) {
+
+                let spans = BacktickScanner { text: line, pos: 0 }.filter(|s| is_command_like(s));
+                for span in spans {
+                    for token in unknown_tokens(span, &known) {
+                        violations.push(Violation {
+                            check_id: CheckId(self.def.id),
+                            path: Some(file.to_path_buf()),
+                            message: format!(
+                                "Line {}: `{}` uses unrecognized '{}'",
+                                line_no,
+                                span,
+                                token
+                            ),
+                            severity: self.def.severity.clone(),
+                            rule_type: self.def.rule_type.to_tag(),
+                            expected: None,
+                            actual: Some(token),
+                            fix_hint: self.def.fix_hint.clone()
+                                .unwrap_or_else(|| "Update the example to match the CLI's current subcommands and flags".to_string()),
+                        });
+                    }
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Default deepest allowed heading level for Check 143.
+const DEFAULT_MAX_HEADING_DEPTH: usize = 4;
+
+/// ATX headings of a Markdown document as `(1-based line, level, text)`,
+/// ignoring lines inside ``` or ~~~ fenced code blocks.
+fn atx_headings(content: &str) -> Vec<(usize, usize, String)> {
+    let mut headings = Vec::new();
+    let mut fence: Option<&str> = None;
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let marker = if trimmed.starts_with("```") {
+            Some("```")
+        } else if trimmed.starts_with("~~~") {
+            Some("~~~")
+        } else {
+            None
+        };
+        match (fence, marker) {
+            (None, Some(m)) => { fence = Some(m); continue; }
+            (Some(open), Some(m)) if open == m => { fence = None; continue; }
+            (Some(_), _) => continue,
+            _ => {}
+        }
+
+        if let Some(caps) = ATX_HEADING_RE.captures(line) {
+            let text = caps.get(2)
+                .map(|m| m.as_str().trim_end_matches('#').trim_end().to_string())
+                .unwrap_or_default();
+            headings.push((i + 1, caps[1].len(), text));
+        }
+    }
+    headings
+}
+
+/// Check 143: heading_hierarchy
+/// Markdown headings stay within a maximum depth and never skip a level (FR-920)
+///
+/// The rule's `threshold` is the deepest allowed level (default 4).
+pub struct HeadingHierarchy {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for HeadingHierarchy {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let md_files: Vec<_> = ctx.files.iter()
+            .filter(|f| f.to_string_lossy().ends_with(".md"))
+            .collect();
+
+        if md_files.is_empty() {
+            return CheckResult::Skip { reason: "No .md files found".to_string() };
+        }
+
+        let max_depth = self.def.threshold
+            .map(|t| t as usize)
+            .unwrap_or(DEFAULT_MAX_HEADING_DEPTH);
+
+        let mut violations = Vec::new();
+        for file in &md_files {
+            let full = ctx.root.join(file);
+            let content = match ctx.read(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let mut previous: Option<usize> = None;
+            for (line, level, text) in atx_headings(&content) {
+                let mut problems = Vec::new();
+                if level > max_depth {
+                    problems.push((
+                        format!("is level {} (max {})", level, max_depth),
+                        format!("Restructure the section so it needs at most {} levels", max_depth),
+                    ));
+                }
+                if let Some(prev) = previous.filter(|p| level > p + 1) {
+                    problems.push((
+                        format!("skips from level {} to level {}", prev, level),
+                        format!("Use a level {} heading here", prev + 1),
+                    ));
+                }
+                previous = Some(level);
+
+                for (problem, hint) in problems {
+                    violations.push(Violation {
+                        check_id: CheckId(self.def.id),
+                        path: Some(file.to_path_buf()),
+                        message: format!("Line {}: heading '{}' {}", line, text, problem),
+                        severity: self.def.severity.clone(),
+                        rule_type: self.def.rule_type.to_tag(),
+                        expected: None,
+                        actual: Some(format!("{} {}", "#".repeat(level), text)),
+                        fix_hint: self.def.fix_hint.clone().unwrap_or(hint),
+                    });
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Default placeholder markers for Check 146; overridden by the rule's `allowed_values`.
+const DEFAULT_PLACEHOLDER_MARKERS: &[&str] = &["TODO", "FIXME", "TBD", "XXX"];
+
+/// Case-insensitive whole-word matcher for any of `markers`.
+fn placeholder_marker_regex(markers: &[String]) -> Option<Regex> {
+    let alternatives: Vec<String> = markers.iter()
+        .map(|m| m.trim())
+        .filter(|m| !m.is_empty())
+        .map(regex::escape)
+        .collect();
+    if alternatives.is_empty() {
+        return None;
+    }
+    Regex::new(&format!(r"(?i)\b(?:{})\b", alternatives.join("|"))).ok()
+}
+
+/// Check 146: no_placeholder_markers
+/// Published docs contain no TODO/FIXME/TBD/XXX placeholders (FR-923)
+pub struct NoPlaceholderMarkers {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for NoPlaceholderMarkers {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let docs_files: Vec<_> = ctx.files.iter()
+            .filter(|f| {
+                let s = f.to_string_lossy();
+                s.starts_with("docs/") && s.ends_with(".md")
+            })
+            .collect();
+
+        if docs_files.is_empty() {
+            return CheckResult::Skip { reason: "No .md files in docs/".to_string() };
+        }
+
+        let markers: Vec<String> = match &self.def.allowed_values {
+            Some(values) => values.clone(),
+            None => DEFAULT_PLACEHOLDER_MARKERS.iter().map(|s| s.to_string()).collect(),
+        };
+        let marker_re = match placeholder_marker_regex(&markers) {
+            Some(re) => re,
+            None => return CheckResult::Skip { reason: "No placeholder markers configured".to_string() },
+        };
+
+        let mut violations = Vec::new();
+        for file in &docs_files {
+            let full = ctx.root.join(file);
+            let content = match ctx.read(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            for (line_no, line) in lines_outside_fences(&content) {
+
+                for m in marker_re.find_iter(line) {
+                    violations.push(Violation {
+                        check_id: CheckId(self.def.id),
+                        path: Some(file.to_path_buf()),
+                        message: format!("Line {}: placeholder marker '{}'", line_no, m.as_str()),
+                        severity: self.def.severity.clone(),
+                        rule_type: self.def.rule_type.to_tag(),
+                        expected: None,
+                        actual: Some(line.trim().to_string()),
+                        fix_hint: self.def.fix_hint.clone()
+                            .unwrap_or_else(|| "Finish the section or track the open item in an issue instead of the docs".to_string()),
+                    });
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Default number of leading lines Check 148 searches for the marker; overridden by `threshold`.
+const DEFAULT_AUDIENCE_MAX_LINE: usize = 10;
+
+/// Default audiences for Check 148; overridden by the rule's `allowed_values`.
+const DEFAULT_AUDIENCES: &[&str] = &[
+    "All", "Developers", "Users", "Architects", "Contributors", "Maintainers",
+    "Operators", "Project stakeholders", "Compliance officers", "Release managers",
+    "Documentation maintainers",
+];
+
+/// Check 148: audience_marker_present
+/// Every doc declares `**Audience**:` near the top with known values (FR-925).
+/// `allowed_values` replaces the audience set; entries starting with `!`
+/// instead name files to skip (e.g. `!README.md`, `!index.md`).
+pub struct AudienceMarkerPresent {
+    pub def: RuleDef,
+}
+
+impl AudienceMarkerPresent {
+    fn violation(&self, path: &std::path::Path, message: String, actual: Option<String>) -> Violation {
+        Violation {
+            check_id: CheckId(self.def.id),
+            path: Some(path.to_path_buf()),
+            message,
+            severity: self.def.severity.clone(),
+            rule_type: self.def.rule_type.to_tag(),
+            expected: None,
+            actual,
+            fix_hint: self.def.fix_hint.clone()
+                .unwrap_or_else(|| "Add '**Audience**: <who>' under the title".to_string()),
+        }
+    }
+}
+
+impl CheckRunner for AudienceMarkerPresent {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let (excluded, mut audiences): (Vec<String>, Vec<String>) = self.def.allowed_values
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|v| v.starts_with('!'));
+        let excluded: HashSet<String> = excluded.iter().map(|v| v.trim_start_matches('!').trim().to_string()).collect();
+        if audiences.is_empty() {
+            audiences = DEFAULT_AUDIENCES.iter().map(|s| s.to_string()).collect();
+        }
+        let allowed: HashSet<String> = audiences.iter().map(|a| a.trim().to_lowercase()).collect();
+        let max_line = self.def.threshold
+            .map(|t| t as usize)
+            .unwrap_or(DEFAULT_AUDIENCE_MAX_LINE);
+
+        let docs_files: Vec<_> = ctx.files.iter()
+            .filter(|f| {
+                let s = f.to_string_lossy();
+                s.starts_with("docs/") && s.ends_with(".md")
+            })
+            .filter(|f| {
+                let name = f.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                !excluded.contains(&name)
+            })
+            .collect();
+
+        if docs_files.is_empty() {
+            return CheckResult::Skip { reason: "No .md files in docs/".to_string() };
+        }
+
+        let mut violations = Vec::new();
+        for file in &docs_files {
+            let content = match ctx.read(&ctx.root.join(file)) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let value = content.lines()
+                .take(max_line)
+                .find_map(|line| AUDIENCE_RE.captures(line).map(|c| c[1].trim().to_string()));
+            let value = match value {
+                Some(v) => v,
+                None => {
+                    violations.push(self.violation(
+                        file,
+                        format!("No '**Audience**:' line in the first {} lines", max_line),
+                        None,
+                    ));
+                    continue;
+                }
+            };
+
+            let entries: Vec<&str> = value.split(',')
+                .map(|a| a.trim().trim_end_matches('.').trim())
+                .filter(|a| !a.is_empty())
+                .collect();
+            if entries.is_empty() {
+                violations.push(self.violation(file, "Audience is empty".to_string(), None));
+                continue;
+            }
+            for entry in entries {
+                if !allowed.contains(&entry.to_lowercase()) {
+                    violations.push(self.violation(
+                        file,
+                        format!("Unknown audience '{}' (allowed: {})", entry, audiences.join(", ")),
+                        Some(value.clone()),
+                    ));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Width of a line's leading whitespace, tabs counting as 4.
+fn indent_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+/// Where a Markdown line sits relative to ``` / ~~~ fenced code blocks.
+enum FenceLine<'a> {
+    /// Prose outside any fenced block.
+    Text,
+    /// An opening fence with its info string.
+    Open(&'a str),
+    /// A line inside a fenced block, or its closing fence.
+    Code,
+}
+
+/// Classify each line of a Markdown document as `(1-based line, text, role)`.
+///
+/// Closing fences need the same character, at least as many of them, and
+/// nothing after. Lines indented 4+ columns outside a list are indented code
+/// blocks, not fences; inside a list (until the next unindented non-item
+/// line) they are fences nested in an item.
+fn fence_lines(content: &str) -> Vec<(usize, &str, FenceLine<'_>)> {
+    let mut lines = Vec::new();
+    let mut open: Option<(char, usize)> = None;
+    let mut in_list = false;
+
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+        let run = marker.map_or(0, |m| trimmed.chars().take_while(|c| *c == m).count());
+
+        if let Some((ch, len)) = open {
+            if marker == Some(ch) && run >= len && trimmed[run..].trim().is_empty() {
+                open = None;
             }
+            lines.push((i + 1, line, FenceLine::Code));
+            continue;
         }
+
+        let indent = indent_width(line);
+        if LIST_ITEM_RE.is_match(line) {
+            in_list = true;
+        } else if indent == 0 && !trimmed.is_empty() {
+            in_list = false;
+        }
+
+        let info = trimmed[run..].trim();
+        let role = match marker {
+            // A backtick in the info string makes this inline code, not a fence
+            Some(ch) if run >= 3 && (indent < 4 || in_list) && !(ch == '`' && info.contains('`')) => {
+                open = Some((ch, run));
+                FenceLine::Open(info)
+            }
+            _ => FenceLine::Text,
+        };
+        lines.push((i + 1, line, role));
     }
+    lines
 }
 
-/// Check 129: hardcoded_path_detection
-/// No hardcoded absolute paths in documentation (FR-832)
-pub struct HardcodedPathDetection {
+/// Lines of a Markdown document outside fenced code blocks, as `(1-based line, text)`.
+fn lines_outside_fences(content: &str) -> Vec<(usize, &str)> {
+    fence_lines(content)
+        .into_iter()
+        .filter(|(_, _, role)| matches!(role, FenceLine::Text))
+        .map(|(n, line, _)| (n, line))
+        .collect()
+}
+
+/// Opening ``` / ~~~ fences of a Markdown document as `(1-based line, info string)`.
+fn opening_fences(content: &str) -> Vec<(usize, String)> {
+    fence_lines(content)
+        .into_iter()
+        .filter_map(|(n, _, role)| match role {
+            FenceLine::Open(info) => Some((n, info.to_string())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check 149: code_fence_language
+/// Fenced code blocks in docs/ name a language for syntax highlighting (FR-926).
+/// Any token counts, so `text` or `plain` mark deliberately unhighlighted blocks.
+pub struct CodeFenceLanguage {
     pub def: RuleDef,
 }
 
-impl CheckRunner for HardcodedPathDetection {
+impl CheckRunner for CodeFenceLanguage {
     fn id(&self) -> CheckId { CheckId(self.def.id) }
     fn category(&self) -> &str { &self.def.category }
     fn description(&self) -> &str { &self.def.description }
@@ -361,39 +1195,26 @@ impl CheckRunner for HardcodedPathDetection {
 
         let mut violations = Vec::new();
         for file in &docs_files {
-            let full = ctx.root.join(file);
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&ctx.root.join(file)) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
 
-            let mut in_code_fence = false;
-            for (i, line) in content.lines().enumerate() {
-                if line.trim_start().starts_with("```") {
-                    in_code_fence = !in_code_fence;
-                    continue;
-                }
-                if in_code_fence {
+            for (line, info) in opening_fences(&content) {
+                if !info.is_empty() {
                     continue;
                 }
-
-                if let Some(m) = find_hardcoded_path(line) {
-                    violations.push(Violation {
-                        check_id: CheckId(self.def.id),
-                        path: Some(file.to_path_buf()),
-                        message: format!(
-                            "Line {}: hardcoded absolute path '{}'",
-                            i + 1,
-                            m.as_str()
-                        ),
-                        severity: self.def.severity.clone(),
-                        rule_type: self.def.rule_type.to_tag(),
-                        expected: None,
-                        actual: None,
-                        fix_hint: self.def.fix_hint.clone()
-                            .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
-                    });
-                }
+                violations.push(Violation {
+                    check_id: CheckId(self.def.id),
+                    path: Some(file.to_path_buf()),
+                    message: format!("Line {}: code fence has no language", line),
+                    severity: self.def.severity.clone(),
+                    rule_type: self.def.rule_type.to_tag(),
+                    expected: Some("a language after the opening fence, e.g. ```rust".to_string()),
+                    actual: None,
+                    fix_hint: self.def.fix_hint.clone()
+                        .unwrap_or_else(|| "Name the language after the opening fence; use 'text' for plain output".to_string()),
+                });
             }
         }
 
@@ -408,9 +1229,10 @@ impl CheckRunner for HardcodedPathDetection {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
@@ -426,6 +1248,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -433,7 +1259,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -572,6 +1398,114 @@ mod tests {
         assert!(matches!(handler.run(&ctx), CheckResult::Fail { .. }));
     }
 
+    // --- GlossaryNoDuplicates (check 145) ---
+
+    #[test]
+    fn test_glossary_no_duplicates_pass() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/glossary.md"),
+            "**API** - Application Programming Interface\n**CLI** - Command Line Interface\n"
+        ).unwrap();
+        let handler = GlossaryNoDuplicates { def: make_def(145) };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_glossary_no_duplicates_exact_duplicate() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/glossary.md"),
+            "# Glossary\n\n**API** - Application Programming Interface\n**CLI** - Command Line Interface\n**api** - application programming interface.\n"
+        ).unwrap();
+        let handler = GlossaryNoDuplicates { def: make_def(145) };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].message, "Term 'API' is defined 2 times (lines 3, 5)");
+                assert_eq!(violations[0].severity, Severity::Warning);
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_glossary_no_duplicates_conflicting_definitions() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/glossary.md"),
+            "**API** - Application Programming Interface\n**API** - Automated Packaging Infrastructure\n"
+        ).unwrap();
+        let handler = GlossaryNoDuplicates { def: make_def(145) };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].message.contains("conflicting definitions (lines 1, 2)"), "{}", violations[0].message);
+                assert_eq!(violations[0].severity, Severity::Error);
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    // --- GlossaryCompleteness (check 132) ---
+
+    fn make_doc_files(count: usize) -> Vec<PathBuf> {
+        (0..count).map(|i| PathBuf::from(format!("docs/doc_{}.md", i))).collect()
+    }
+
+    #[test]
+    fn test_glossary_completeness_pass() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/glossary.md"),
+            "**API** - Application\n**CLI** - Command\n"
+        ).unwrap();
+        let handler = GlossaryCompleteness { def: make_def(132) };
+        let ctx = make_ctx(tmp.path(), make_doc_files(20));
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_glossary_completeness_fail() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/glossary.md"),
+            "**API** - Application\n**CLI** - Command\n**SDK** - Software\n"
+        ).unwrap();
+        let handler = GlossaryCompleteness { def: make_def(132) };
+        let ctx = make_ctx(tmp.path(), make_doc_files(200));
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert!(violations[0].message.contains("3 terms for 200 docs"));
+                assert_eq!(violations[0].expected.as_deref(), Some("at least 20 terms"));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_glossary_completeness_custom_threshold() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/glossary.md"), "**API** - Application\n").unwrap();
+        let mut def = make_def(132);
+        def.threshold = Some(0.0);
+        let handler = GlossaryCompleteness { def };
+        let ctx = make_ctx(tmp.path(), make_doc_files(200));
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_glossary_completeness_skip() {
+        let tmp = TempDir::new().unwrap();
+        let handler = GlossaryCompleteness { def: make_def(132) };
+        let ctx = make_ctx(tmp.path(), make_doc_files(5));
+        assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
+    }
+
     // --- ReadmeLineCount (check 75) ---
 
     #[test]
@@ -673,4 +1607,254 @@ mod tests {
         let ctx = make_ctx(tmp.path(), vec![]);
         assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
     }
+
+    // --- DocCommandsValid (check 142) ---
+
+    fn run_doc_commands(content: &str, allowed_values: Option<Vec<String>>) -> CheckResult {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/guide.md"), content).unwrap();
+        let mut def = make_def(142);
+        def.allowed_values = allowed_values;
+        let handler = DocCommandsValid { def };
+        let ctx = make_ctx(tmp.path(), vec![PathBuf::from("docs/guide.md")]);
+        handler.run(&ctx)
+    }
+
+    #[test]
+    fn test_doc_commands_known_pass() {
+        let content = "Run `doc-engine scan . --scope small --json` or `doc-engine scaffold srs.md --dry-run`.\n\
+                       Pipe it: `doc-engine scan <PATH> --json | jq .summary`; see `doc-engine ai generate-commands srs.md --all`.\n";
+        assert!(matches!(run_doc_commands(content, None), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_doc_commands_unknown_flag_fail() {
+        let content = "# Usage\n\nRun `doc-engine scan . --foo --scope=small`.\n";
+        match run_doc_commands(content, None) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].message.starts_with("Line 3:"), "{}", violations[0].message);
+                assert_eq!(violations[0].actual.as_deref(), Some("--foo"));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_doc_commands_unknown_subcommand_fail() {
+        let content = "Run `doc-engine lint . --json` and `doc-engine ai explain x`.\n\n```\ndoc-engine lint `x y`\n```\n";
+        match run_doc_commands(content, None) {
+            CheckResult::Fail { violations } => {
+                let tokens: Vec<_> = violations.iter().filter_map(|v| v.actual.as_deref()).collect();
+                assert_eq!(tokens, vec!["lint", "explain"]);
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_doc_commands_in_tilde_and_long_fences_ignored() {
+        let content = "~~~\n`doc-engine lint .`\n~~~\n\n````\n```\n`doc-engine scan . --foo`\n```\n````\n";
+        assert!(matches!(run_doc_commands(content, None), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_doc_commands_custom_known_commands() {
+        let known = Some(vec!["mytool build --release".to_string()]);
+        assert!(matches!(run_doc_commands("`mytool build --release`, `cargo run --foo`\n", known.clone()), CheckResult::Pass));
+        assert!(matches!(run_doc_commands("`mytool build --debug`\n", known), CheckResult::Fail { .. }));
+    }
+
+    // --- HeadingHierarchy (check 143) ---
+
+    fn run_headings(content: &str, threshold: Option<f64>) -> CheckResult {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("guide.md"), content).unwrap();
+        let mut def = make_def(143);
+        def.threshold = threshold;
+        let handler = HeadingHierarchy { def };
+        handler.run(&make_ctx(tmp.path(), vec![PathBuf::from("guide.md")]))
+    }
+
+    #[test]
+    fn test_heading_hierarchy_valid_doc_pass() {
+        let content = "# Guide\n\n## Install\n\n### Linux\n\n#### Debian\n\n## Usage\n\n```bash\n# not a heading\n###### nor this\n```\n\n~~~\n# also code\n~~~\n\n### Flags\n";
+        assert!(matches!(run_headings(content, None), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_heading_hierarchy_too_deep_fail() {
+        let content = "# Guide\n\n## A\n\n### B\n\n#### C\n\n##### Too deep\n";
+        match run_headings(content, None) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].message, "Line 9: heading 'Too deep' is level 5 (max 4)");
+            }
+            other => panic!("expected Fail, got {:?}", other),
+        }
+        // A lower configured maximum flags level 4 as well
+        match run_headings(content, Some(3.0)) {
+            CheckResult::Fail { violations } => assert_eq!(violations.len(), 2),
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_heading_hierarchy_skipped_level_fail() {
+        let content = "# Guide\n\n### Skipped\n\n## Back\n\n#### Skipped again ##\n";
+        match run_headings(content, None) {
+            CheckResult::Fail { violations } => {
+                let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+                assert_eq!(messages, vec![
+                    "Line 3: heading 'Skipped' skips from level 1 to level 3",
+                    "Line 7: heading 'Skipped again' skips from level 2 to level 4",
+                ]);
+                assert_eq!(violations[0].path.as_deref(), Some(std::path::Path::new("guide.md")));
+            }
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
+
+    // --- NoPlaceholderMarkers (check 146) ---
+
+    fn run_placeholders(content: &str, markers: Option<Vec<String>>) -> CheckResult {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/guide.md"), content).unwrap();
+        let mut def = make_def(146);
+        def.allowed_values = markers;
+        let handler = NoPlaceholderMarkers { def };
+        handler.run(&make_ctx(tmp.path(), vec![PathBuf::from("docs/guide.md")]))
+    }
+
+    #[test]
+    fn test_placeholder_marker_in_prose_fail() {
+        let content = "# Guide\n\nInstall steps: todo.\n\nLimits are tbd, see FIXME below.\n";
+        match run_placeholders(content, None) {
+            CheckResult::Fail { violations } => {
+                let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+                assert_eq!(messages, vec![
+                    "Line 3: placeholder marker 'todo'",
+                    "Line 5: placeholder marker 'tbd'",
+                    "Line 5: placeholder marker 'FIXME'",
+                ]);
+                assert_eq!(violations[0].path.as_deref(), Some(std::path::Path::new("docs/guide.md")));
+            }
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_placeholder_marker_in_code_fence_pass() {
+        let content = "# Guide\n\nTodoList and mastodon are fine.\n\n```rust\n// TODO: handle errors\nlet xxx = 1;\n```\n";
+        assert!(matches!(run_placeholders(content, None), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_placeholder_marker_in_tilde_and_long_fences_pass() {
+        let content = "# Guide\n\n~~~\nTODO: tilde fence\n~~~\n\n````markdown\n```\nFIXME: nested fence\n```\n````\n";
+        assert!(matches!(run_placeholders(content, None), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_placeholder_custom_markers() {
+        let markers = Some(vec!["WIP".to_string()]);
+        assert!(matches!(run_placeholders("Section is WIP.\n", markers.clone()), CheckResult::Fail { .. }));
+        assert!(matches!(run_placeholders("Section is TODO.\n", markers), CheckResult::Pass));
+    }
+
+    // --- AudienceMarkerPresent (check 148) ---
+
+    fn run_audience(files: &[(&str, &str)], allowed_values: Option<Vec<String>>) -> CheckResult {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        for (name, content) in files {
+            fs::write(tmp.path().join("docs").join(name), content).unwrap();
+        }
+        let mut def = make_def(148);
+        def.allowed_values = allowed_values;
+        let handler = AudienceMarkerPresent { def };
+        let paths = files.iter().map(|(name, _)| PathBuf::from(format!("docs/{}", name))).collect();
+        handler.run(&make_ctx(tmp.path(), paths))
+    }
+
+    #[test]
+    fn test_audience_marker_compliant_pass() {
+        let files = [("guide.md", "# Guide\n\n**Audience**: Developers, architects\n\nBody.\n")];
+        assert!(matches!(run_audience(&files, None), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_audience_marker_missing_or_late_fail() {
+        let late = format!("# Guide\n{}**Audience**: Developers\n", "text\n".repeat(12));
+        let files = [("guide.md", "# Guide\n\nNo marker here.\n"), ("late.md", late.as_str()), ("empty.md", "# E\n\n**Audience**:  \n")];
+        match run_audience(&files, None) {
+            CheckResult::Fail { violations } => {
+                let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+                assert_eq!(messages, vec![
+                    "No '**Audience**:' line in the first 10 lines",
+                    "No '**Audience**:' line in the first 10 lines",
+                    "Audience is empty",
+                ]);
+            }
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_audience_marker_unknown_value_fail() {
+        let files = [("guide.md", "# Guide\n\n**Audience**: Developers, Martians\n")];
+        match run_audience(&files, None) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].message.starts_with("Unknown audience 'Martians'"));
+            }
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_audience_marker_custom_set_and_exclusions() {
+        let files = [("README.md", "# Docs hub\n"), ("guide.md", "# Guide\n**Audience**: Users\n")];
+        let allowed = Some(vec!["Users".to_string(), "!README.md".to_string()]);
+        assert!(matches!(run_audience(&files, allowed), CheckResult::Pass));
+        let allowed = Some(vec!["All".to_string()]);
+        assert!(matches!(run_audience(&files, allowed), CheckResult::Fail { .. }));
+    }
+
+    // --- CodeFenceLanguage (check 149) ---
+
+    fn run_code_fence(content: &str) -> CheckResult {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/guide.md"), content).unwrap();
+        let handler = CodeFenceLanguage { def: make_def(149) };
+        handler.run(&make_ctx(tmp.path(), vec![PathBuf::from("docs/guide.md")]))
+    }
+
+    fn fence_messages(result: CheckResult) -> Vec<String> {
+        match result {
+            CheckResult::Fail { violations } => violations.into_iter().map(|v| v.message).collect(),
+            other => panic!("expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_code_fence_labeled_pass() {
+        let content = "# Guide\n\n```rust\nfn main() {}\n```\n\n```text\noutput\n```\n\n~~~plain\n```\n~~~\n\n    ```\n    indented code, not a fence\n";
+        assert!(matches!(run_code_fence(content), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_code_fence_unlabeled_fail() {
+        let content = "# Guide\n\n```\nplain\n```\n\n````bash\n```\n````\n";
+        assert_eq!(fence_messages(run_code_fence(content)), vec!["Line 3: code fence has no language"]);
+    }
+
+    #[test]
+    fn test_code_fence_in_nested_list_fail() {
+        let content = "# Steps\n\n1. Install\n   - Run:\n\n     ```\n     make\n     ```\n\n   - Then:\n\n     ```sh\n     make test\n     ```\n";
+        assert_eq!(fence_messages(run_code_fence(content)), vec!["Line 6: code fence has no language"]);
+    }
 }
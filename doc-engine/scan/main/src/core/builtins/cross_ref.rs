@@ -1,5 +1,5 @@
-use std::fs;
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 use regex::Regex;
@@ -9,6 +9,8 @@ use crate::api::traits::CheckRunner;
 use crate::api::types::{CheckId, CheckResult, ScanContext, Violation};
 
 static LINK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\(([^)]+)\)").unwrap());
+static HEADING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^ {0,3}#{1,6}\s+(.*?)(?:\s+#+)?\s*$").unwrap());
+static HTML_ANCHOR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"<a\s[^>]*(?:name|id)\s*=\s*"([^"]+)""#).unwrap());
 
 /// Checks 44-45: link_resolution
 /// 44: All internal markdown links resolve to existing files (error)
@@ -37,7 +39,7 @@ impl CheckRunner for LinkResolution {
         let mut violations = Vec::new();
         for file in &md_files {
             let full = ctx.root.join(file);
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&full) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -113,12 +115,180 @@ impl CheckRunner for LinkResolution {
     }
 }
 
+/// Check 139: anchor_resolution
+/// Every `#fragment` in an internal markdown link names a heading (or an
+/// explicit `<a name>`/`<a id>` anchor) in the target file. Missing target
+/// files are left to checks 44-45.
+pub struct AnchorResolution {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for AnchorResolution {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let md_files: Vec<_> = ctx.files.iter()
+            .filter(|f| {
+                let s = f.to_string_lossy();
+                s.starts_with("docs/") && s.ends_with(".md")
+            })
+            .collect();
+
+        if md_files.is_empty() {
+            return CheckResult::Skip { reason: "No .md files in docs/".to_string() };
+        }
+
+        let mut anchors_by_file: HashMap<PathBuf, Vec<String>> = HashMap::new();
+        let mut violations = Vec::new();
+        for file in &md_files {
+            let content = match ctx.read(&ctx.root.join(file)) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let file_dir = file.parent().unwrap_or(Path::new(""));
+
+            for caps in LINK_RE.captures_iter(&content) {
+                let target = &caps[2];
+                if target.starts_with("http://") || target.starts_with("https://")
+                    || target.starts_with("mailto:")
+                {
+                    continue;
+                }
+
+                let Some((target_path, fragment)) = target.split_once('#') else {
+                    continue;
+                };
+                if fragment.is_empty() || (!target_path.is_empty() && !target_path.ends_with(".md")) {
+                    continue;
+                }
+
+                let resolved = if target_path.is_empty() {
+                    ctx.root.join(file)
+                } else if target_path.starts_with('/') {
+                    ctx.root.join(target_path.trim_start_matches('/'))
+                } else {
+                    ctx.root.join(file_dir).join(target_path)
+                };
+                if !anchors_by_file.contains_key(&resolved) {
+                    let Ok(target_content) = ctx.read(&resolved) else {
+                        continue;
+                    };
+                    anchors_by_file.insert(resolved.clone(), document_anchors(&target_content));
+                }
+                let anchors = &anchors_by_file[&resolved];
+
+                let wanted = fragment.to_lowercase();
+                if anchors.iter().any(|a| a.to_lowercase() == wanted) {
+                    continue;
+                }
+
+                let closest = anchors.iter()
+                    .min_by_key(|a| edit_distance(&wanted, &a.to_lowercase()));
+                let suggestion = match closest {
+                    Some(anchor) => format!("; did you mean '#{}'?", anchor),
+                    None => "; the target has no headings".to_string(),
+                };
+                violations.push(Violation {
+                    check_id: CheckId(self.def.id),
+                    path: Some(file.to_path_buf()),
+                    message: format!(
+                        "Dead anchor '#{}' in link '{}' from {}{}",
+                        fragment, target, file.display(), suggestion,
+                    ),
+                    severity: self.def.severity.clone(),
+                    rule_type: self.def.rule_type.to_tag(),
+                    expected: closest.map(|a| format!("#{}", a)),
+                    actual: Some(format!("#{}", fragment)),
+                    fix_hint: self.def.fix_hint.clone()
+                        .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
+                });
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Anchors a markdown document exposes: GitHub heading slugs (with `-1`, `-2`
+/// suffixes for repeats) plus explicit HTML anchors. Fenced code is ignored.
+fn document_anchors(content: &str) -> Vec<String> {
+    let mut anchors = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut in_fence = false;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        if let Some(caps) = HEADING_RE.captures(line) {
+            let slug = github_slug(&caps[1]);
+            let count = seen.entry(slug.clone()).or_insert(0);
+            anchors.push(if *count == 0 { slug } else { format!("{}-{}", slug, count) });
+            *count += 1;
+        }
+        for caps in HTML_ANCHOR_RE.captures_iter(line) {
+            anchors.push(caps[1].to_string());
+        }
+    }
+    anchors
+}
+
+/// Slugify heading text the way GitHub does: render inline links to their
+/// text, lowercase, drop punctuation other than `-` and `_`, and turn spaces
+/// into hyphens.
+fn github_slug(heading: &str) -> String {
+    let text = LINK_RE.replace_all(heading, "$1");
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Levenshtein distance between two strings, by character.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
@@ -134,6 +304,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -141,7 +315,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -196,4 +370,78 @@ mod tests {
         let ctx = make_ctx(tmp.path(), vec![PathBuf::from("docs/index.md")]);
         assert!(matches!(handler.run(&ctx), CheckResult::Pass));
     }
+
+    // --- AnchorResolution (check 139) ---
+
+    fn anchor_ctx(tmp: &TempDir, index: &str, other: &str) -> ScanContext {
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/index.md"), index).unwrap();
+        fs::write(tmp.path().join("docs/other.md"), other).unwrap();
+        make_ctx(tmp.path(), vec![PathBuf::from("docs/index.md"), PathBuf::from("docs/other.md")])
+    }
+
+    #[test]
+    fn test_anchor_resolves_pass() {
+        let tmp = TempDir::new().unwrap();
+        let ctx = anchor_ctx(&tmp,
+            "See [setup](other.md#getting-started-v2) and [top](#overview)\n\n# Overview\n",
+            "# Other\n\n## Getting Started (v2)\n",
+        );
+        let handler = AnchorResolution { def: make_def(139) };
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_dead_anchor_suggests_closest_heading() {
+        let tmp = TempDir::new().unwrap();
+        let ctx = anchor_ctx(&tmp,
+            "See [setup](other.md#instalation)\n",
+            "# Other\n\n## Installation\n\n## Usage\n",
+        );
+        let handler = AnchorResolution { def: make_def(139) };
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].message.contains("'#instalation'"));
+                assert!(violations[0].message.contains("docs/index.md"));
+                assert_eq!(violations[0].expected.as_deref(), Some("#installation"));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_anchor_duplicate_headings_and_html_anchors() {
+        let tmp = TempDir::new().unwrap();
+        let ctx = anchor_ctx(&tmp,
+            "[a](other.md#notes-1) [b](other.md#legacy) [c](other.md#in-code)\n",
+            "# Notes\n\n# Notes\n\n<a name=\"legacy\"></a>\n\n```\n# in code\n```\n",
+        );
+        let handler = AnchorResolution { def: make_def(139) };
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].actual.as_deref(), Some("#in-code"));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_anchor_external_and_missing_targets_skipped() {
+        let tmp = TempDir::new().unwrap();
+        let ctx = anchor_ctx(&tmp,
+            "[a](https://example.com/page.md#nope) [b](mailto:x@y.z#nope) [c](missing.md#nope)\n",
+            "# Other\n",
+        );
+        let handler = AnchorResolution { def: make_def(139) };
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_github_slug() {
+        assert_eq!(github_slug("Getting Started (v2)"), "getting-started-v2");
+        assert_eq!(github_slug("`scan` & [links](x.md)"), "scan--links");
+        assert_eq!(github_slug("snake_case API"), "snake_case-api");
+    }
 }
@@ -18,6 +18,7 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
         "sdlc_phase_numbering" => Some(Box::new(structure::SdlcPhaseNumbering { def: def.clone() })),
         "checklist_completeness" => Some(Box::new(structure::ChecklistCompleteness { def: def.clone() })),
         "open_source_community_files" => Some(Box::new(structure::OpenSourceCommunityFiles { def: def.clone() })),
+        "community_files_cross_linked" => Some(Box::new(structure::CommunityFilesCrossLinked { def: def.clone() })),
         "open_source_github_templates" => Some(Box::new(structure::OpenSourceGithubTemplates { def: def.clone() })),
 
         // Naming handlers
@@ -30,6 +31,8 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
         "glossary_format" => Some(Box::new(content::GlossaryFormat { def: def.clone() })),
         "glossary_alphabetized" => Some(Box::new(content::GlossaryAlphabetized { def: def.clone() })),
         "glossary_acronyms" => Some(Box::new(content::GlossaryAcronyms { def: def.clone() })),
+        "glossary_no_duplicates" => Some(Box::new(content::GlossaryNoDuplicates { def: def.clone() })),
+        "glossary_completeness" => Some(Box::new(content::GlossaryCompleteness { def: def.clone() })),
 
         // Navigation handlers
         "w3h_hub" => Some(Box::new(navigation::W3hHub { def: def.clone() })),
@@ -38,16 +41,19 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
 
         // Cross-reference handlers
         "link_resolution" => Some(Box::new(cross_ref::LinkResolution { def: def.clone() })),
+        "anchor_resolution" => Some(Box::new(cross_ref::AnchorResolution { def: def.clone() })),
 
         // ADR handlers
         "adr_naming" => Some(Box::new(adr::AdrNaming { def: def.clone() })),
         "adr_index_completeness" => Some(Box::new(adr::AdrIndexCompleteness { def: def.clone() })),
+        "adr_status_consistency" => Some(Box::new(adr::AdrStatusConsistency { def: def.clone() })),
 
         // Traceability handlers
         "phase_artifact_presence" => Some(Box::new(traceability::PhaseArtifactPresence { def: def.clone() })),
         "design_traces_requirements" => Some(Box::new(traceability::DesignTracesRequirements { def: def.clone() })),
         "plan_traces_design" => Some(Box::new(traceability::PlanTracesDesign { def: def.clone() })),
         "backlog_traces_requirements" => Some(Box::new(traceability::BacklogTracesRequirements { def: def.clone() })),
+        "requirements_traced_to_tests" => Some(Box::new(traceability::RequirementsTracedToTests { def: def.clone() })),
 
         // Structure handlers (new)
         "templates_populated" => Some(Box::new(structure::TemplatesPopulated { def: def.clone() })),
@@ -58,6 +64,11 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
         // Content handlers (new)
         "readme_line_count" => Some(Box::new(content::ReadmeLineCount { def: def.clone() })),
         "hardcoded_path_detection" => Some(Box::new(content::HardcodedPathDetection { def: def.clone() })),
+        "doc_commands_valid" => Some(Box::new(content::DocCommandsValid { def: def.clone() })),
+        "heading_hierarchy" => Some(Box::new(content::HeadingHierarchy { def: def.clone() })),
+        "no_placeholder_markers" => Some(Box::new(content::NoPlaceholderMarkers { def: def.clone() })),
+        "audience_marker_present" => Some(Box::new(content::AudienceMarkerPresent { def: def.clone() })),
+        "code_fence_language" => Some(Box::new(content::CodeFenceLanguage { def: def.clone() })),
 
         // Naming handlers (new)
         "fr_naming" => Some(Box::new(naming::FrNaming { def: def.clone() })),
@@ -86,7 +97,55 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
         "verification_report_29119_sections" => Some(Box::new(requirements::VerificationReport29119Sections { def: def.clone() })),
         "srs_no_tech_details" => Some(Box::new(requirements::SrsNoTechDetails { def: def.clone() })),
         "srs_no_downstream_refs" => Some(Box::new(requirements::SrsNoDownstreamRefs { def: def.clone() })),
+        "requirement_single_domain" => Some(Box::new(requirements::RequirementSingleDomain { def: def.clone() })),
+        "disposal_plan_data_handling" => Some(Box::new(requirements::DisposalPlanDataHandling { def: def.clone() })),
+        "hub_phases_ordered" => Some(Box::new(navigation::HubPhasesOrdered { def: def.clone() })),
+        "verification_method_valid" => Some(Box::new(requirements::VerificationMethodValid { def: def.clone() })),
+        "arch_quality_attributes" => Some(Box::new(requirements::ArchQualityAttributes { def: def.clone() })),
+        "requirements_trace_upward" => Some(Box::new(requirements::RequirementsTraceUpward { def: def.clone() })),
+        "checklist_version_current" => Some(Box::new(structure::ChecklistVersionCurrent { def: def.clone() })),
 
+        #[cfg(test)]
+        "test_panic" => Some(Box::new(PanickingCheck { def: def.clone() })),
+        #[cfg(test)]
+        "test_sleep" => Some(Box::new(SleepingCheck { def: def.clone() })),
         _ => None,
     }
 }
+
+/// Test-only handler that always panics, for exercising the engine's crash guard.
+#[cfg(test)]
+pub(crate) struct PanickingCheck {
+    pub def: RuleDef,
+}
+
+#[cfg(test)]
+impl CheckRunner for PanickingCheck {
+    fn id(&self) -> crate::api::types::CheckId { crate::api::types::CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, _ctx: &crate::api::types::ScanContext) -> crate::api::types::CheckResult {
+        panic!("handler exploded")
+    }
+}
+
+/// Test-only handler that sleeps for `threshold` milliseconds, then passes,
+/// for exercising the engine's check timeout.
+#[cfg(test)]
+pub(crate) struct SleepingCheck {
+    pub def: RuleDef,
+}
+
+#[cfg(test)]
+impl CheckRunner for SleepingCheck {
+    fn id(&self) -> crate::api::types::CheckId { crate::api::types::CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, _ctx: &crate::api::types::ScanContext) -> crate::api::types::CheckResult {
+        let ms = self.def.threshold.unwrap_or(0.0) as u64;
+        std::thread::sleep(std::time::Duration::from_millis(ms));
+        crate::api::types::CheckResult::Pass
+    }
+}
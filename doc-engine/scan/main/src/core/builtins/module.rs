@@ -137,7 +137,7 @@ impl CheckRunner for ModuleReadmeW3h {
                 continue; // skip modules without docs/README.md
             }
 
-            let content = match fs::read_to_string(&readme) {
+            let content = match ctx.read(&readme) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -370,7 +370,7 @@ mod tests {
     use super::*;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use tempfile::TempDir;
 
     fn make_def(id: u8, handler: &str) -> RuleDef {
@@ -385,6 +385,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -392,7 +396,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -712,7 +716,7 @@ mod tests {
         let ctx = ScanContext {
             root: tmp.path().to_path_buf(),
             files: vec![],
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: Some(vec!["core".to_string()]),
@@ -9,9 +9,9 @@ use crate::api::types::{CheckId, CheckResult, ScanContext, Violation};
 static PHASE_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+-").unwrap());
 static GUIDE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-z_]+_[a-z]+_guide\.md$").unwrap());
 static TESTING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"_testing_").unwrap());
-static FR_DETECT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bFR[-_]\d").unwrap());
-static FR_VALID_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bFR_\d{3}\b").unwrap());
-static FR_HYPHEN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bFR-\d").unwrap());
+static FR_DETECT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bN?FR[-_]\d").unwrap());
+static FR_VALID_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bN?FR_\d{3}\b").unwrap());
+static FR_HYPHEN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bN?FR-\d").unwrap());
 
 /// Checks 21-23: snake_lower_case
 /// 21: All filenames in docs/ are lowercase
@@ -233,9 +233,9 @@ impl CheckRunner for TestingFilePlacement {
 }
 
 /// Check 76: fr_naming
-/// FR artifacts follow FR_NNN naming convention (FR-803).
-/// Scan ctx.files for paths matching (?i)\bFR[-_]\d. If none, Pass.
-/// If found, validate they match FR_\d{3} (underscore, 3 digits). Flag FR- (hyphen).
+/// FR/NFR artifacts follow FR_NNN / NFR_NNN naming convention (FR-803).
+/// Scan ctx.files for paths matching (?i)\bN?FR[-_]\d. If none, Pass.
+/// If found, validate they match N?FR_\d{3} (underscore, 3 digits). Flag FR-/NFR- (hyphen).
 pub struct FrNaming {
     pub def: RuleDef,
 }
@@ -262,7 +262,7 @@ impl CheckRunner for FrNaming {
                     check_id: CheckId(self.def.id),
                     path: Some(file.to_path_buf()),
                     message: format!(
-                        "Path '{}' uses FR-NNN (hyphen); should use FR_NNN (underscore)",
+                        "Path '{}' uses FR-NNN/NFR-NNN (hyphen); should use FR_NNN/NFR_NNN (underscore)",
                         path_str
                     ),
                     severity: self.def.severity.clone(),
@@ -277,7 +277,7 @@ impl CheckRunner for FrNaming {
                     check_id: CheckId(self.def.id),
                     path: Some(file.to_path_buf()),
                     message: format!(
-                        "Path '{}' has non-standard FR naming; expected FR_NNN (3 digits)",
+                        "Path '{}' has non-standard FR naming; expected FR_NNN or NFR_NNN (3 digits)",
                         path_str
                     ),
                     severity: self.def.severity.clone(),
@@ -303,7 +303,7 @@ mod tests {
     use super::*;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
@@ -319,6 +319,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -326,7 +330,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -461,6 +465,16 @@ mod tests {
         assert!(matches!(handler.run(&ctx), CheckResult::Fail { .. }));
     }
 
+    #[test]
+    fn test_fr_naming_nfr_prefix() {
+        let tmp = TempDir::new().unwrap();
+        let handler = FrNaming { def: make_def(76) };
+        let ctx = make_ctx(tmp.path(), vec![PathBuf::from("docs/NFR_001/design.md")]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+        let ctx = make_ctx(tmp.path(), vec![PathBuf::from("docs/NFR-001/design.md")]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Fail { .. }));
+    }
+
     #[test]
     fn test_fr_naming_fail_wrong_digits() {
         let tmp = TempDir::new().unwrap();
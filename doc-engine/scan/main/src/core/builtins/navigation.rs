@@ -13,6 +13,9 @@ static W3H_WHY_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)#{1,3}\s+
 static W3H_HOW_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)#{1,3}\s+.*how").unwrap());
 static NAV_PHASE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+-[a-z_]+)$").unwrap());
 static DEEP_LINK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\]\(docs/\d+-[^)]+\)").unwrap());
+static HUB_PHASE_LINK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\]\((?:\./)?((\d+)-[a-z_]+)(?:[/#][^)]*)?\)").unwrap()
+});
 
 fn w3h_re(keyword: &str) -> &'static LazyLock<Regex> {
     match keyword {
@@ -41,7 +44,7 @@ impl CheckRunner for W3hHub {
             return CheckResult::Skip { reason: "docs/README.md not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&hub_path) {
+        let content = match ctx.read(&hub_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -102,7 +105,7 @@ impl CheckRunner for HubLinksPhases {
             return CheckResult::Skip { reason: "docs/README.md not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&hub_path) {
+        let content = match ctx.read(&hub_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -172,7 +175,7 @@ impl CheckRunner for NoDeepLinks {
             return CheckResult::Skip { reason: "README.md not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&readme_path) {
+        let content = match ctx.read(&readme_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -237,7 +240,7 @@ impl CheckRunner for W3hExtended {
                 continue; // skip files that don't exist
             }
 
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&full) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -276,12 +279,70 @@ impl CheckRunner for W3hExtended {
     }
 }
 
+/// Check 135: hub_phases_ordered
+/// Hub document lists SDLC phase links in ascending numeric order
+pub struct HubPhasesOrdered {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for HubPhasesOrdered {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let hub_path = ctx.root.join("docs/README.md");
+        let content = match ctx.read(&hub_path) {
+            Ok(c) => c,
+            Err(_) => {
+                return CheckResult::Skip { reason: "docs/README.md not found".to_string() };
+            }
+        };
+
+        // Phase links in order of first appearance
+        let mut phases: Vec<(u32, String)> = Vec::new();
+        for cap in HUB_PHASE_LINK_RE.captures_iter(&content) {
+            let name = cap[1].to_string();
+            let Ok(number) = cap[2].parse::<u32>() else { continue };
+            if !phases.iter().any(|(_, n)| *n == name) {
+                phases.push((number, name));
+            }
+        }
+
+        if phases.len() < 2 {
+            return CheckResult::Skip {
+                reason: "Fewer than two phase links in docs/README.md".to_string(),
+            };
+        }
+
+        match phases.windows(2).find(|pair| pair[1].0 < pair[0].0) {
+            None => CheckResult::Pass,
+            Some(pair) => CheckResult::Fail {
+                violations: vec![Violation {
+                    check_id: CheckId(self.def.id),
+                    path: Some("docs/README.md".into()),
+                    message: format!(
+                        "Hub lists phase '{}' after '{}'; phases should appear in ascending numeric order",
+                        pair[1].1, pair[0].1
+                    ),
+                    severity: self.def.severity.clone(),
+                    rule_type: self.def.rule_type.to_tag(),
+                    expected: Some(format!("'{}' before '{}'", pair[1].1, pair[0].1)),
+                    actual: Some(format!("'{}' before '{}'", pair[0].1, pair[1].1)),
+                    fix_hint: self.def.fix_hint.clone()
+                        .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
+                }],
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
@@ -297,6 +358,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -304,7 +369,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -371,6 +436,58 @@ mod tests {
         assert!(matches!(handler.run(&ctx), CheckResult::Fail { .. }));
     }
 
+    // --- HubPhasesOrdered (check 135) ---
+
+    #[test]
+    fn test_hub_phases_ordered_pass() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/README.md"),
+            "# Hub\n- [Ideation](0-ideation/)\n- [Requirements](1-requirements/srs.md)\n- [Design](3-design/)\n- [Ops](10-operations/)\n"
+        ).unwrap();
+        let handler = HubPhasesOrdered { def: make_def(135) };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_hub_phases_ordered_fail_reports_first_pair() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/README.md"),
+            "# Hub\n- [Requirements](1-requirements/)\n- [Ideation](0-ideation/)\n- [Design](3-design/)\n- [Planning](2-planning/)\n"
+        ).unwrap();
+        let handler = HubPhasesOrdered { def: make_def(135) };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].message.contains("'0-ideation' after '1-requirements'"));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_hub_phases_ordered_skip_single_phase() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/README.md"),
+            "# Hub\n- [Requirements](./1-requirements/)\n- [Again](1-requirements/srs.md)\n"
+        ).unwrap();
+        let handler = HubPhasesOrdered { def: make_def(135) };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
+    }
+
+    #[test]
+    fn test_hub_phases_ordered_skip_no_hub() {
+        let tmp = TempDir::new().unwrap();
+        let handler = HubPhasesOrdered { def: make_def(135) };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
+    }
+
     // --- NoDeepLinks (check 43) ---
 
     #[test]
@@ -429,4 +546,28 @@ mod tests {
         // No hub files exist — pass (nothing to check)
         assert!(matches!(handler.run(&ctx), CheckResult::Pass));
     }
+
+    // --- Shared file cache ---
+
+    #[test]
+    fn test_hub_checks_share_one_read() {
+        let tmp = TempDir::new().unwrap();
+        let docs = tmp.path().join("docs");
+        fs::create_dir_all(docs.join("0-overview")).unwrap();
+        fs::create_dir_all(docs.join("1-requirements")).unwrap();
+        fs::write(docs.join("README.md"),
+            "# Hub\n## Who\nTeam\n## What\nProduct\n## Why\nReason\n## How\nProcess\n\
+             - [Overview](0-overview/)\n- [Requirements](1-requirements/)\n"
+        ).unwrap();
+        let ctx = make_ctx(tmp.path(), vec![]);
+        let handlers: Vec<Box<dyn CheckRunner>> = vec![
+            Box::new(W3hHub { def: make_def(41) }),
+            Box::new(HubLinksPhases { def: make_def(42) }),
+            Box::new(HubPhasesOrdered { def: make_def(135) }),
+        ];
+        for handler in &handlers {
+            assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+        }
+        assert_eq!(ctx.file_contents.disk_reads(), 1);
+    }
 }
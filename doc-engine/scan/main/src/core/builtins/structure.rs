@@ -10,6 +10,23 @@ use crate::api::types::{CheckId, CheckResult, ScanContext, Violation};
 
 static PHASE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+)-").unwrap());
 static CHECKBOX_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"- \[([ xX])\]").unwrap());
+static MD_LINK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[[^\]]*\]\(([^)\s]+)[^)]*\)").unwrap());
+static REF_LINK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^ {0,3}\[[^\]]+\]:\s*(\S+)").unwrap());
+static CHECKLIST_VERSION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\*\*Checklist Version\*\*\s*[:|]?\s*v?(\d+(?:\.\d+)*)").unwrap()
+});
+
+/// Default number of version components compared by Check 138 (major.minor.patch).
+const DEFAULT_VERSION_COMPONENTS: f64 = 3.0;
+
+/// Default community file link graph for Check 141, as `SOURCE -> TARGET` edges.
+const DEFAULT_COMMUNITY_LINKS: &[&str] = &[
+    "README.md -> CONTRIBUTING.md",
+    "README.md -> SECURITY.md",
+    "README.md -> LICENSE",
+    "CONTRIBUTING.md -> CODE_OF_CONDUCT.md",
+    "SUPPORT.md -> CONTRIBUTING.md",
+];
 
 /// Checks 4-5: module_docs_plural
 /// Check 4: All module doc folders use docs/ (plural), not doc/
@@ -200,7 +217,7 @@ impl CheckRunner for ChecklistCompleteness {
             return CheckResult::Skip { reason: "Compliance checklist not found".to_string() };
         }
 
-        let content = match fs::read_to_string(&checklist_path) {
+        let content = match ctx.read(&checklist_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -236,6 +253,85 @@ impl CheckRunner for ChecklistCompleteness {
     }
 }
 
+/// Check 138: checklist_version_current
+/// The compliance checklist's `**Checklist Version**` matches the engine version
+pub struct ChecklistVersionCurrent {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for ChecklistVersionCurrent {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let checklist_path = ctx.root.join("docs/3-design/compliance/compliance_checklist.md");
+        if !checklist_path.exists() {
+            return CheckResult::Skip { reason: "Compliance checklist not found".to_string() };
+        }
+
+        let content = match ctx.read(&checklist_path) {
+            Ok(c) => c,
+            Err(e) => {
+                return CheckResult::Skip {
+                    reason: format!("Cannot read checklist: {}", e),
+                };
+            }
+        };
+
+        let engine_version = env!("CARGO_PKG_VERSION");
+        // threshold selects how many components must match: 1 = major, 2 = major.minor, 3 = exact
+        let components = self.def.threshold.unwrap_or(DEFAULT_VERSION_COMPONENTS).max(1.0) as usize;
+
+        let (message, actual) = match CHECKLIST_VERSION_RE.captures(&content) {
+            Some(caps) => {
+                let checklist_version = &caps[1];
+                if versions_match(checklist_version, engine_version, components) {
+                    return CheckResult::Pass;
+                }
+                (
+                    format!(
+                        "Checklist version {} does not match engine version {}",
+                        checklist_version, engine_version
+                    ),
+                    checklist_version.to_string(),
+                )
+            }
+            None => (
+                format!(
+                    "Checklist does not declare a **Checklist Version** (engine version {})",
+                    engine_version
+                ),
+                "missing".to_string(),
+            ),
+        };
+
+        CheckResult::Fail {
+            violations: vec![Violation {
+                check_id: CheckId(self.def.id),
+                path: Some("docs/3-design/compliance/compliance_checklist.md".into()),
+                message,
+                severity: self.def.severity.clone(),
+                rule_type: self.def.rule_type.to_tag(),
+                expected: Some(engine_version.to_string()),
+                actual: Some(actual),
+                fix_hint: self.def.fix_hint.clone()
+                    .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
+            }],
+        }
+    }
+}
+
+/// Compare the first `components` numeric parts of two dotted versions;
+/// missing parts count as 0.
+fn versions_match(a: &str, b: &str, components: usize) -> bool {
+    let parts = |v: &str| -> Vec<u64> {
+        v.split('.').map(|p| p.parse().unwrap_or(0)).collect()
+    };
+    let (a, b) = (parts(a), parts(b));
+    (0..components).all(|i| a.get(i).copied().unwrap_or(0) == b.get(i).copied().unwrap_or(0))
+}
+
 /// Check 31: open_source_community_files
 /// CODE_OF_CONDUCT.md and SUPPORT.md exist (open-source only)
 pub struct OpenSourceCommunityFiles {
@@ -274,6 +370,90 @@ impl CheckRunner for OpenSourceCommunityFiles {
     }
 }
 
+/// Check 141: community_files_cross_linked
+/// Root community files link to each other along the expected graph
+/// (open-source only). Edges come from the rule's `allowed_values` as
+/// `SOURCE -> TARGET` entries, defaulting to `DEFAULT_COMMUNITY_LINKS`.
+/// Edges whose files are missing are left to the presence checks.
+pub struct CommunityFilesCrossLinked {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for CommunityFilesCrossLinked {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let edges: Vec<String> = match &self.def.allowed_values {
+            Some(v) => v.clone(),
+            None => DEFAULT_COMMUNITY_LINKS.iter().map(|s| s.to_string()).collect(),
+        };
+        let edges: Vec<(&str, &str)> = edges.iter()
+            .filter_map(|e| e.split_once("->"))
+            .map(|(source, target)| (source.trim(), target.trim()))
+            .filter(|(source, target)| !source.is_empty() && !target.is_empty())
+            .collect();
+
+        let mut checked = 0;
+        let mut violations = Vec::new();
+        for (source, target) in &edges {
+            if !ctx.root.join(source).is_file() || !ctx.root.join(target).exists() {
+                continue;
+            }
+            let content = match ctx.read(&ctx.root.join(source)) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            checked += 1;
+
+            let linked = MD_LINK_RE.captures_iter(&content)
+                .chain(REF_LINK_RE.captures_iter(&content))
+                .any(|caps| link_names_file(&caps[1], target));
+            if !linked {
+                violations.push(Violation {
+                    check_id: CheckId(self.def.id),
+                    path: Some((*source).into()),
+                    message: format!("{} does not link to {}", source, target),
+                    severity: self.def.severity.clone(),
+                    rule_type: self.def.rule_type.to_tag(),
+                    expected: Some(format!("a link to {}", target)),
+                    actual: None,
+                    fix_hint: self.def.fix_hint.clone()
+                        .unwrap_or_else(|| format!("Add a link to {} in {}", target, source)),
+                });
+            }
+        }
+
+        if checked == 0 {
+            return CheckResult::Skip { reason: "No linked community file pairs present".to_string() };
+        }
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// True when a link target points at the root file `file`: a relative or
+/// root-absolute path, or a repository URL whose last path segment is the
+/// file name. Anchors and query strings are ignored; matching is case-insensitive.
+fn link_names_file(link: &str, file: &str) -> bool {
+    let link = link.trim_matches(|c| c == '<' || c == '>');
+    let path = link.split(['#', '?']).next().unwrap_or(link);
+    if path.starts_with("mailto:") {
+        return false;
+    }
+    let is_url = path.starts_with("http://") || path.starts_with("https://");
+    let name = if is_url {
+        path.trim_end_matches('/').rsplit('/').next().unwrap_or(path)
+    } else {
+        path.trim_start_matches("./").trim_start_matches('/')
+    };
+    name.eq_ignore_ascii_case(file)
+}
+
 /// Check 32: open_source_github_templates
 /// .github/ISSUE_TEMPLATE/ and PULL_REQUEST_TEMPLATE.md exist (open-source only)
 pub struct OpenSourceGithubTemplates {
@@ -371,7 +551,7 @@ mod tests {
     use super::*;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
@@ -387,6 +567,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -394,7 +578,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -510,6 +694,84 @@ mod tests {
         assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
     }
 
+    // --- ChecklistVersionCurrent (check 138) ---
+
+    fn write_checklist(root: &std::path::Path, content: &str) {
+        let dir = root.join("docs/3-design/compliance");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("compliance_checklist.md"), content).unwrap();
+    }
+
+    #[test]
+    fn test_checklist_version_pass() {
+        let tmp = TempDir::new().unwrap();
+        write_checklist(tmp.path(), &format!(
+            "# Compliance Checklist\n\n**Checklist Version**: {}\n", env!("CARGO_PKG_VERSION")
+        ));
+        let handler = ChecklistVersionCurrent { def: make_def(138, "checklist_version_current") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_checklist_version_pass_table_row() {
+        let tmp = TempDir::new().unwrap();
+        write_checklist(tmp.path(), &format!(
+            "| Field | Value |\n|---|---|\n| **Checklist Version** | v{} |\n", env!("CARGO_PKG_VERSION")
+        ));
+        let handler = ChecklistVersionCurrent { def: make_def(138, "checklist_version_current") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_checklist_version_fail_mismatch() {
+        let tmp = TempDir::new().unwrap();
+        write_checklist(tmp.path(), "**Checklist Version**: 0.0.0-old\n");
+        let handler = ChecklistVersionCurrent { def: make_def(138, "checklist_version_current") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].message.contains("0.0.0"));
+                assert!(violations[0].message.contains(env!("CARGO_PKG_VERSION")));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_checklist_version_fail_missing_marker() {
+        let tmp = TempDir::new().unwrap();
+        write_checklist(tmp.path(), "# Compliance Checklist\n\n- [x] one\n");
+        let handler = ChecklistVersionCurrent { def: make_def(138, "checklist_version_current") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations[0].actual.as_deref(), Some("missing"));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_checklist_version_skip_missing() {
+        let tmp = TempDir::new().unwrap();
+        let handler = ChecklistVersionCurrent { def: make_def(138, "checklist_version_current") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
+    }
+
+    #[test]
+    fn test_versions_match_components() {
+        assert!(versions_match("1.2.3", "1.2.3", 3));
+        assert!(!versions_match("1.2.3", "1.2.4", 3));
+        assert!(versions_match("1.2.3", "1.2.4", 2));
+        assert!(!versions_match("1.1.0", "1.2.0", 2));
+        assert!(versions_match("1.1", "1.9.9", 1));
+        assert!(versions_match("1.2", "1.2.0", 3));
+    }
+
     // --- OpenSourceCommunityFiles (check 31) ---
 
     #[test]
@@ -570,4 +832,62 @@ mod tests {
         let ctx = make_ctx(tmp.path(), vec![]);
         assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
     }
+
+    // --- CommunityFilesCrossLinked (check 141) ---
+
+    fn write_community_files(root: &std::path::Path) {
+        fs::write(root.join("README.md"), "See [contributing](CONTRIBUTING.md), [security](./SECURITY.md#reporting) and [license][lic].\n\n[lic]: LICENSE\n").unwrap();
+        fs::write(root.join("CONTRIBUTING.md"), "Follow the [Code of Conduct](https://github.com/org/repo/blob/main/CODE_OF_CONDUCT.md).").unwrap();
+        fs::write(root.join("SUPPORT.md"), "Read [CONTRIBUTING](/CONTRIBUTING.md) first.").unwrap();
+        fs::write(root.join("SECURITY.md"), "security").unwrap();
+        fs::write(root.join("CODE_OF_CONDUCT.md"), "conduct").unwrap();
+        fs::write(root.join("LICENSE"), "MIT").unwrap();
+    }
+
+    #[test]
+    fn test_community_cross_linked_pass() {
+        let tmp = TempDir::new().unwrap();
+        write_community_files(tmp.path());
+        let handler = CommunityFilesCrossLinked { def: make_def(141, "community_files_cross_linked") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_community_cross_linked_reports_missing_link() {
+        let tmp = TempDir::new().unwrap();
+        write_community_files(tmp.path());
+        fs::write(tmp.path().join("CONTRIBUTING.md"), "No links here.").unwrap();
+        let handler = CommunityFilesCrossLinked { def: make_def(141, "community_files_cross_linked") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].message, "CONTRIBUTING.md does not link to CODE_OF_CONDUCT.md");
+                assert_eq!(violations[0].path, Some(PathBuf::from("CONTRIBUTING.md")));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_community_cross_linked_custom_graph() {
+        let tmp = TempDir::new().unwrap();
+        write_community_files(tmp.path());
+        let mut def = make_def(141, "community_files_cross_linked");
+        def.allowed_values = Some(vec!["SECURITY.md -> SUPPORT.md".to_string()]);
+        let handler = CommunityFilesCrossLinked { def };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Fail { .. }));
+    }
+
+    #[test]
+    fn test_community_cross_linked_skip_without_files() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("README.md"), "# Project").unwrap();
+        let handler = CommunityFilesCrossLinked { def: make_def(141, "community_files_cross_linked") };
+        let ctx = make_ctx(tmp.path(), vec![]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
+    }
+
 }
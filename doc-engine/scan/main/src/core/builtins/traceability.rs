@@ -1,6 +1,8 @@
+use std::collections::HashSet;
 use std::fs;
 use std::sync::LazyLock;
 
+use doc_engine_scaffold::{parse_srs, ReqKind};
 use regex::Regex;
 
 use crate::api::types::RuleDef;
@@ -16,6 +18,9 @@ static PLAN_ARCH_RE: LazyLock<Regex> = LazyLock::new(|| {
 static BACKLOG_REQ_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(?i)srs\.md|requirements\.md|requirements\b|FR-\d|STK-\d|SRS|1-requirements|BL-\d").unwrap()
 });
+static FR_REF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\bFR-\d+\b").unwrap()
+});
 
 /// Check 51: phase_artifact_presence
 /// Populated SDLC phase directories contain their expected artifact.
@@ -151,7 +156,7 @@ impl CheckRunner for DesignTracesRequirements {
 
         for file in &qualifying_files {
             let full = ctx.root.join(file);
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&full) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -217,7 +222,7 @@ impl CheckRunner for PlanTracesDesign {
 
         for file in &qualifying_files {
             let full = ctx.root.join(file);
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&full) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -265,7 +270,7 @@ impl CheckRunner for BacklogTracesRequirements {
             return CheckResult::Skip { reason: "docs/2-planning/backlog.md does not exist".to_string() };
         }
 
-        let content = match fs::read_to_string(&backlog_path) {
+        let content = match ctx.read(&backlog_path) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -294,12 +299,100 @@ impl CheckRunner for BacklogTracesRequirements {
     }
 }
 
+/// Check 144: requirements_traced_to_tests
+/// Every FR in the SRS is referenced by a testing artifact (FR-921).
+pub struct RequirementsTracedToTests {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for RequirementsTracedToTests {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let srs_path = ctx.root.join("docs/1-requirements/srs.md");
+        if !srs_path.exists() {
+            return CheckResult::Skip { reason: "docs/1-requirements/srs.md not found".to_string() };
+        }
+
+        let content = match ctx.read(&srs_path) {
+            Ok(c) => c,
+            Err(e) => {
+                return CheckResult::Skip {
+                    reason: format!("Cannot read srs.md: {}", e),
+                };
+            }
+        };
+
+        let domains = match parse_srs(&content) {
+            Ok(d) => d,
+            Err(e) => {
+                return CheckResult::Skip {
+                    reason: format!("Cannot parse srs.md: {}", e),
+                };
+            }
+        };
+        let fr_ids: Vec<String> = domains.iter()
+            .flat_map(|d| &d.requirements)
+            .filter(|r| r.kind == ReqKind::Functional)
+            .map(|r| r.id.clone())
+            .collect();
+        if fr_ids.is_empty() {
+            return CheckResult::Skip { reason: "No FR-xxx requirements in srs.md".to_string() };
+        }
+
+        // Testing artifacts: docs/5-testing/**/*.test and **/*.md
+        let testing_files: Vec<_> = ctx.files.iter()
+            .filter(|f| {
+                let s = f.to_string_lossy().replace('\\', "/");
+                s.starts_with("docs/5-testing/") && (s.ends_with(".md") || s.ends_with(".test"))
+            })
+            .collect();
+        if testing_files.is_empty() {
+            return CheckResult::Skip { reason: "No testing artifacts in docs/5-testing/".to_string() };
+        }
+
+        let mut referenced: HashSet<String> = HashSet::new();
+        for file in &testing_files {
+            if let Ok(text) = ctx.read(&ctx.root.join(file)) {
+                referenced.extend(FR_REF_RE.find_iter(&text).map(|m| m.as_str().to_string()));
+            }
+        }
+
+        let untraced: Vec<String> = fr_ids.into_iter()
+            .filter(|id| !referenced.contains(id))
+            .collect();
+        if untraced.is_empty() {
+            return CheckResult::Pass;
+        }
+
+        CheckResult::Fail {
+            violations: vec![Violation {
+                check_id: CheckId(self.def.id),
+                path: Some("docs/1-requirements/srs.md".into()),
+                message: format!(
+                    "{} requirement(s) not referenced in docs/5-testing/: {}",
+                    untraced.len(),
+                    untraced.join(", ")
+                ),
+                severity: self.def.severity.clone(),
+                rule_type: self.def.rule_type.to_tag(),
+                expected: Some("every FR-xxx referenced by a testing artifact".to_string()),
+                actual: Some(untraced.join(", ")),
+                fix_hint: self.def.fix_hint.clone()
+                    .unwrap_or_else(|| "Add test cases referencing these requirement ids under docs/5-testing/".to_string()),
+            }],
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::api::types::{RuleDef, RuleType};
     use crate::api::types::{ProjectScope, ProjectType, Severity};
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use std::path::{Path, PathBuf};
     use tempfile::TempDir;
 
@@ -315,6 +408,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -322,7 +419,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
             module_filter: None,
@@ -576,4 +673,70 @@ mod tests {
         let ctx = make_ctx(tmp.path(), vec![]);
         assert!(matches!(handler.run(&ctx), CheckResult::Skip { .. }));
     }
+
+    // =========================================================================
+    // Check 144 — RequirementsTracedToTests
+    // =========================================================================
+
+    const THREE_FR_SRS: &str = "\
+# SRS
+
+### 4.1 Scanning
+
+#### FR-100: Scan a project
+
+| Attribute | Value |
+|-----------|-------|
+| **Priority** | Must |
+
+#### FR-101: Report results
+
+| Attribute | Value |
+|-----------|-------|
+| **Priority** | Must |
+
+#### FR-1010: Export results
+
+| Attribute | Value |
+|-----------|-------|
+| **Priority** | Could |
+";
+
+    #[test]
+    fn test_requirements_traced_names_untraced_id() {
+        let tmp = TempDir::new().unwrap();
+        write_file(tmp.path(), "docs/1-requirements/srs.md", THREE_FR_SRS);
+        write_file(tmp.path(), "docs/5-testing/scan.test", "TC-1 verifies FR-100\n");
+        write_file(tmp.path(), "docs/5-testing/plans/export.md", "| TC-2 | FR-1010 | export |\n");
+
+        let handler = RequirementsTracedToTests { def: make_def(144, "traceability", "requirements_traced_to_tests") };
+        let ctx = make_ctx(tmp.path(), vec![
+            PathBuf::from("docs/1-requirements/srs.md"),
+            PathBuf::from("docs/5-testing/scan.test"),
+            PathBuf::from("docs/5-testing/plans/export.md"),
+        ]);
+        match handler.run(&ctx) {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                // FR-1010 is traced, but that must not count as a reference to FR-101
+                assert_eq!(violations[0].actual.as_deref(), Some("FR-101"));
+                assert!(violations[0].message.contains("FR-101"));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_requirements_traced_all_referenced_pass() {
+        let tmp = TempDir::new().unwrap();
+        write_file(tmp.path(), "docs/1-requirements/srs.md", THREE_FR_SRS);
+        write_file(tmp.path(), "docs/5-testing/testing_strategy.md", "Covers FR-100, FR-101 and FR-1010.\n");
+
+        let handler = RequirementsTracedToTests { def: make_def(144, "traceability", "requirements_traced_to_tests") };
+        let ctx = make_ctx(tmp.path(), vec![
+            PathBuf::from("docs/1-requirements/srs.md"),
+            PathBuf::from("docs/5-testing/testing_strategy.md"),
+        ]);
+        assert!(matches!(handler.run(&ctx), CheckResult::Pass));
+    }
 }
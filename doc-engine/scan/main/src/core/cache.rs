@@ -0,0 +1,290 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::types::{CacheStats, CheckResult, ScanContext, ScanError};
+
+/// File name of the on-disk cache inside the configured cache directory.
+const CACHE_FILE: &str = "doc-engine-scan-cache.json";
+
+/// Content hash of the input paths of a check, keyed by relative path.
+/// Directories hash to `"dir"` and absent paths to `"missing"`.
+pub(crate) type Fingerprint = BTreeMap<String, String>;
+
+/// 64-bit FNV-1a hash rendered as hex. Stable across builds and platforms,
+/// unlike `std::collections::hash_map::DefaultHasher`.
+pub(crate) fn content_hash(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Cached result of one check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Hash of the fingerprint the result was computed from; checks that
+    /// default to every scanned file would otherwise store the whole tree.
+    inputs: String,
+    /// The check outcome for those inputs.
+    result: CheckResult,
+}
+
+/// Serialized form of the cache file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Hash of the rule set, scan settings and engine version the entries
+    /// were produced with.
+    rules_hash: String,
+    /// Cached results keyed by check ID.
+    entries: HashMap<u8, CacheEntry>,
+}
+
+/// Scan cache keyed by the content of each check's declared inputs
+/// ([`CheckRunner::inputs`](crate::api::traits::CheckRunner::inputs)).
+pub(crate) struct ScanCache {
+    path: PathBuf,
+    rules_hash: String,
+    entries: HashMap<u8, CacheEntry>,
+    /// Input hashes computed during this scan, shared across checks.
+    hashes: HashMap<PathBuf, String>,
+    hits: usize,
+    misses: usize,
+}
+
+impl ScanCache {
+    /// Load the cache from `dir`. The whole cache is discarded when `key`
+    /// (the rule set plus scan settings) or the engine version differs from
+    /// the one it was written with.
+    pub(crate) fn load(dir: &Path, key: &str) -> Self {
+        let rules_hash = content_hash(
+            format!("{}\n{}", env!("CARGO_PKG_VERSION"), key).as_bytes(),
+        );
+        let path = dir.join(CACHE_FILE);
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<CacheFile>(&s).ok())
+            .filter(|file| file.rules_hash == rules_hash)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+
+        ScanCache { path, rules_hash, entries, hashes: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    /// Compute the fingerprint of a check's input paths.
+    ///
+    /// Adding or deleting an input changes the key set, so a check whose
+    /// inputs come from a glob (or the whole file list) is invalidated when
+    /// a matching file appears or disappears. The cache file itself is never
+    /// an input.
+    pub(crate) fn fingerprint(&mut self, inputs: Vec<PathBuf>, ctx: &ScanContext) -> Fingerprint {
+        let mut fingerprint = Fingerprint::new();
+        for rel in inputs {
+            let full = ctx.root.join(&rel);
+            if full == self.path {
+                continue;
+            }
+            let hash = self.hashes.entry(rel.clone()).or_insert_with(|| input_hash(&full));
+            fingerprint.insert(rel.to_string_lossy().replace('\\', "/"), hash.clone());
+        }
+        fingerprint
+    }
+
+    /// Return the cached result for `check_id` if its inputs are unchanged.
+    pub(crate) fn lookup(&mut self, check_id: u8, fingerprint: &Fingerprint) -> Option<CheckResult> {
+        match self.entries.get(&check_id) {
+            Some(entry) if entry.inputs == fingerprint_hash(fingerprint) => {
+                self.hits += 1;
+                Some(entry.result.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Record a freshly computed result for `check_id`.
+    pub(crate) fn store(&mut self, check_id: u8, fingerprint: Fingerprint, result: &CheckResult) {
+        self.misses += 1;
+        let inputs = fingerprint_hash(&fingerprint);
+        self.entries.insert(check_id, CacheEntry { inputs, result: result.clone() });
+    }
+
+    /// Hit/miss counters for this scan.
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats { hits: self.hits, misses: self.misses }
+    }
+
+    /// Write the cache back to disk, creating the cache directory if needed.
+    pub(crate) fn save(&self) -> Result<(), ScanError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = CacheFile { rules_hash: self.rules_hash.clone(), entries: self.entries.clone() };
+        let json = serde_json::to_string(&file)
+            .map_err(|e| ScanError::Config(format!("Cannot serialize scan cache: {}", e)))?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+/// Hash of one input path: file content, `"dir"` or `"missing"`.
+fn input_hash(full: &Path) -> String {
+    if full.is_dir() {
+        return "dir".to_string();
+    }
+    match fs::read(full) {
+        Ok(bytes) => content_hash(&bytes),
+        Err(_) => "missing".to_string(),
+    }
+}
+
+/// Single hash over a fingerprint's `path -> hash` pairs, in path order.
+fn fingerprint_hash(fingerprint: &Fingerprint) -> String {
+    let mut joined = String::new();
+    for (path, hash) in fingerprint {
+        joined.push_str(path);
+        joined.push('\0');
+        joined.push_str(hash);
+        joined.push('\n');
+    }
+    content_hash(joined.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::traits::CheckRunner;
+    use crate::api::types::{FileCache, ProjectScope, ProjectType, RuleDef, RuleType, Severity};
+    use crate::core::declarative::DeclarativeCheck;
+    use tempfile::TempDir;
+
+    fn make_rule(rule_type: RuleType) -> RuleDef {
+        RuleDef {
+            id: 1,
+            category: "content".to_string(),
+            description: "test".to_string(),
+            severity: Severity::Error,
+            rule_type,
+            project_type: None,
+            scope: None,
+            depends_on: vec![],
+            module_filter: None,
+            fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
+        }
+    }
+
+    fn make_ctx(root: &Path, files: Vec<PathBuf>) -> ScanContext {
+        ScanContext {
+            root: root.to_path_buf(),
+            files,
+            file_contents: FileCache::default(),
+            project_type: ProjectType::OpenSource,
+            project_scope: ProjectScope::Large,
+            module_filter: None,
+        }
+    }
+
+    #[test]
+    fn test_content_hash_stable() {
+        assert_eq!(content_hash(b""), "cbf29ce484222325");
+        assert_eq!(content_hash(b"a"), content_hash(b"a"));
+        assert_ne!(content_hash(b"a"), content_hash(b"b"));
+    }
+
+    /// Fingerprint `rule`'s inputs with a fresh cache, as each scan does.
+    fn fingerprint_of(rule: &RuleDef, ctx: &ScanContext) -> Fingerprint {
+        let check = DeclarativeCheck { def: rule.clone() };
+        ScanCache::load(&ctx.root.join("cache"), "rules").fingerprint(check.inputs(ctx), ctx)
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_added_and_deleted_inputs() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/a.md"), "one").unwrap();
+        fs::write(tmp.path().join("docs/b.md"), "two").unwrap();
+        let glob_rule = make_rule(RuleType::GlobNamingMatches {
+            glob: "docs/*.md".to_string(),
+            pattern: "x".to_string(),
+        });
+
+        let one = fingerprint_of(&glob_rule, &make_ctx(tmp.path(), vec![PathBuf::from("docs/a.md")]));
+        let two = fingerprint_of(&glob_rule, &make_ctx(
+            tmp.path(),
+            vec![PathBuf::from("docs/a.md"), PathBuf::from("docs/b.md")],
+        ));
+        assert_ne!(one, two);
+
+        let exists_rule = make_rule(RuleType::FileExists { path: "docs/a.md".to_string() });
+        let ctx = make_ctx(tmp.path(), vec![]);
+        let present = fingerprint_of(&exists_rule, &ctx);
+        fs::remove_file(tmp.path().join("docs/a.md")).unwrap();
+        let deleted = fingerprint_of(&exists_rule, &ctx);
+        assert_eq!(deleted["docs/a.md"], "missing");
+        assert_ne!(present, deleted);
+    }
+
+    #[test]
+    fn test_fingerprint_skips_cache_file() {
+        let tmp = TempDir::new().unwrap();
+        let files = vec![PathBuf::from("cache").join(CACHE_FILE), PathBuf::from("README.md")];
+        let ctx = make_ctx(tmp.path(), files.clone());
+        let fingerprint = ScanCache::load(&tmp.path().join("cache"), "rules").fingerprint(files, &ctx);
+        assert_eq!(fingerprint.keys().collect::<Vec<_>>(), vec!["README.md"]);
+    }
+
+    #[test]
+    fn test_fingerprint_glob_tracks_content() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/a.md"), "one").unwrap();
+        let ctx = make_ctx(tmp.path(), vec![PathBuf::from("docs/a.md")]);
+        let rule = make_rule(RuleType::GlobContentMatches {
+            glob: "docs/*.md".to_string(),
+            pattern: "x".to_string(),
+        });
+
+        let before = fingerprint_of(&rule, &ctx);
+        fs::write(tmp.path().join("docs/a.md"), "two").unwrap();
+        let after = fingerprint_of(&rule, &ctx);
+        assert_eq!(before.len(), 1);
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn test_save_and_reload_hit() {
+        let tmp = TempDir::new().unwrap();
+        let mut fp = Fingerprint::new();
+        fp.insert("README.md".to_string(), content_hash(b"x"));
+
+        let mut cache = ScanCache::load(tmp.path(), "rules-v1");
+        assert!(cache.lookup(7, &fp).is_none());
+        cache.store(7, fp.clone(), &CheckResult::Pass);
+        cache.save().unwrap();
+
+        let mut reloaded = ScanCache::load(tmp.path(), "rules-v1");
+        assert!(matches!(reloaded.lookup(7, &fp), Some(CheckResult::Pass)));
+        assert_eq!(reloaded.stats().hits, 1);
+    }
+
+    #[test]
+    fn test_rules_change_invalidates() {
+        let tmp = TempDir::new().unwrap();
+        let mut fp = Fingerprint::new();
+        fp.insert("README.md".to_string(), content_hash(b"x"));
+
+        let mut cache = ScanCache::load(tmp.path(), "rules-v1");
+        cache.store(7, fp.clone(), &CheckResult::Pass);
+        cache.save().unwrap();
+
+        let mut reloaded = ScanCache::load(tmp.path(), "rules-v2");
+        assert!(reloaded.lookup(7, &fp).is_none());
+    }
+}
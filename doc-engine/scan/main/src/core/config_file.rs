@@ -0,0 +1,130 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::api::types::ScanError;
+
+/// File name searched for by [`ConfigFile::discover`].
+pub const CONFIG_FILE_NAME: &str = "doc-engine.toml";
+
+/// Scan settings read from a `doc-engine.toml` file.
+///
+/// Values are kept in their CLI spelling (e.g. `checks = "1-13"`) so the CLI
+/// validates file, environment and flag values the same way. Resolution
+/// order is: built-in defaults, then the file, then `DOC_ENGINE_*`
+/// environment variables, then command-line flags.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Project scope: `small`, `medium`, or `large`.
+    pub scope: Option<String>,
+    /// Project type: `open-source` or `internal`.
+    #[serde(rename = "type")]
+    pub project_type: Option<String>,
+    /// Check selection in `--checks` syntax.
+    pub checks: Option<String>,
+    /// SDLC phase/category filter.
+    pub phases: Option<Vec<String>>,
+    /// Module name filter.
+    pub modules: Option<Vec<String>>,
+    /// Custom rules file; relative paths resolve against the config file's directory.
+    pub rules: Option<PathBuf>,
+    /// Glob patterns to leave out of the scan.
+    pub exclude: Option<Vec<String>>,
+    /// Per-check severity overrides in `--severity` syntax (`"57=warning"`).
+    pub severity: Option<Vec<String>>,
+}
+
+impl ConfigFile {
+    /// Load a config file, resolving `rules` relative to the file's directory.
+    pub fn from_file(path: &Path) -> Result<Self, ScanError> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            ScanError::Config(format!("cannot read '{}': {}", path.display(), e))
+        })?;
+        let mut config: ConfigFile = toml::from_str(&text).map_err(|e| {
+            ScanError::Config(format!("invalid config file '{}': {}", path.display(), e))
+        })?;
+        if let (Some(rules), Some(dir)) = (config.rules.as_mut(), path.parent()) {
+            if rules.is_relative() {
+                *rules = dir.join(&*rules);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Find the nearest `doc-engine.toml` in `start` or any of its ancestors.
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Override values with `DOC_ENGINE_SCOPE`, `DOC_ENGINE_TYPE`,
+    /// `DOC_ENGINE_CHECKS`, `DOC_ENGINE_PHASE`, `DOC_ENGINE_MODULE`,
+    /// `DOC_ENGINE_RULES`, `DOC_ENGINE_EXCLUDE` and `DOC_ENGINE_SEVERITY` when
+    /// they are set. List variables are comma-separated.
+    pub fn merge_env(&mut self) {
+        self.merge_vars(|name| std::env::var(name).ok());
+    }
+
+    fn merge_vars(&mut self, var: impl Fn(&str) -> Option<String>) {
+        let list = |value: String| value.split(',').map(|s| s.trim().to_string()).collect();
+        if let Some(v) = var("DOC_ENGINE_SCOPE") { self.scope = Some(v); }
+        if let Some(v) = var("DOC_ENGINE_TYPE") { self.project_type = Some(v); }
+        if let Some(v) = var("DOC_ENGINE_CHECKS") { self.checks = Some(v); }
+        if let Some(v) = var("DOC_ENGINE_PHASE") { self.phases = Some(list(v)); }
+        if let Some(v) = var("DOC_ENGINE_MODULE") { self.modules = Some(list(v)); }
+        if let Some(v) = var("DOC_ENGINE_RULES") { self.rules = Some(PathBuf::from(v)); }
+        if let Some(v) = var("DOC_ENGINE_EXCLUDE") { self.exclude = Some(list(v)); }
+        if let Some(v) = var("DOC_ENGINE_SEVERITY") { self.severity = Some(list(v)); }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file_resolves_rules_relative_to_file() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join(CONFIG_FILE_NAME);
+        fs::write(&path, "scope = \"large\"\ntype = \"internal\"\nrules = \"rules.toml\"\nexclude = [\"vendor/**\"]\n").unwrap();
+
+        let config = ConfigFile::from_file(&path).unwrap();
+        assert_eq!(config.scope.as_deref(), Some("large"));
+        assert_eq!(config.project_type.as_deref(), Some("internal"));
+        assert_eq!(config.rules, Some(tmp.path().join("rules.toml")));
+        assert_eq!(config.exclude, Some(vec!["vendor/**".to_string()]));
+    }
+
+    #[test]
+    fn test_from_file_rejects_unknown_keys() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join(CONFIG_FILE_NAME);
+        fs::write(&path, "scopes = \"large\"\n").unwrap();
+        assert!(matches!(ConfigFile::from_file(&path), Err(ScanError::Config(_))));
+    }
+
+    #[test]
+    fn test_discover_walks_up_from_start() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        fs::write(tmp.path().join(CONFIG_FILE_NAME), "").unwrap();
+        let nested = tmp.path().join("a/b");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(ConfigFile::discover(&nested), Some(tmp.path().join(CONFIG_FILE_NAME)));
+    }
+
+    #[test]
+    fn test_env_overrides_file_values() {
+        let mut config = ConfigFile { scope: Some("large".into()), ..ConfigFile::default() };
+        config.merge_vars(|name| match name {
+            "DOC_ENGINE_SCOPE" => Some("medium".into()),
+            "DOC_ENGINE_PHASE" => Some("testing, module".into()),
+            _ => None,
+        });
+        assert_eq!(config.scope.as_deref(), Some("medium"));
+        assert_eq!(config.phases, Some(vec!["testing".to_string(), "module".to_string()]));
+    }
+}
@@ -1,5 +1,4 @@
-use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use regex::Regex;
 
@@ -44,6 +43,27 @@ impl CheckRunner for DeclarativeCheck {
             }
         }
     }
+
+    fn inputs(&self, ctx: &ScanContext) -> Vec<PathBuf> {
+        match &self.def.rule_type {
+            RuleType::FileExists { path }
+            | RuleType::DirExists { path }
+            | RuleType::DirNotExists { path, .. }
+            | RuleType::FileContentMatches { path, .. }
+            | RuleType::FileContentNotMatches { path, .. } => vec![PathBuf::from(path)],
+            RuleType::GlobContentMatches { glob, .. }
+            | RuleType::GlobContentNotMatches { glob, .. }
+            | RuleType::GlobNamingMatches { glob, .. }
+            | RuleType::GlobNamingNotMatches { glob, .. } => match glob_to_regex(glob) {
+                Some(glob_re) => ctx.files.iter()
+                    .filter(|f| glob_re.is_match(&f.to_string_lossy()))
+                    .cloned()
+                    .collect(),
+                None => Vec::new(),
+            },
+            RuleType::Builtin { .. } => Vec::new(),
+        }
+    }
 }
 
 impl DeclarativeCheck {
@@ -123,7 +143,7 @@ impl DeclarativeCheck {
             };
         }
 
-        let content = match fs::read_to_string(&full) {
+        let content = match ctx.read(&full) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -161,7 +181,7 @@ impl DeclarativeCheck {
             return CheckResult::Pass;
         }
 
-        let content = match fs::read_to_string(&full) {
+        let content = match ctx.read(&full) {
             Ok(c) => c,
             Err(e) => {
                 return CheckResult::Skip {
@@ -223,7 +243,7 @@ impl DeclarativeCheck {
         let mut violations = Vec::new();
         for file in &matching_files {
             let full = ctx.root.join(file);
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&full) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -294,7 +314,7 @@ impl DeclarativeCheck {
         let mut violations = Vec::new();
         for file in &matching_files {
             let full = ctx.root.join(file);
-            let content = match fs::read_to_string(&full) {
+            let content = match ctx.read(&full) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
@@ -510,7 +530,7 @@ pub fn glob_to_regex(glob: &str) -> Option<Regex> {
 mod tests {
     use super::*;
     use crate::api::types::Severity;
-    use std::collections::HashMap;
+    use crate::api::types::FileCache;
     use tempfile::TempDir;
 
     fn make_rule_def(id: u8, rule_type: RuleType) -> RuleDef {
@@ -525,6 +545,10 @@ mod tests {
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }
     }
 
@@ -532,7 +556,7 @@ mod tests {
         ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: crate::api::types::ProjectType::OpenSource,
             project_scope: crate::api::types::ProjectScope::Large,
             module_filter: None,
@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use crate::api::types::{CheckDiff, CheckEntry, CheckResult, DiffStatus, DiffSummary, ReportDiff, ScanReport};
+
+/// Compare two scan reports check by check.
+///
+/// A check is `regressed` when it fails in `new` but not in `old`, `fixed`
+/// when it failed in `old` and no longer fails in `new` (passed or skipped),
+/// and `unchanged` otherwise. Checks present in only one report are `added`
+/// or `removed`.
+pub(crate) fn diff_reports(old: &ScanReport, new: &ScanReport) -> ReportDiff {
+    let mut by_id: BTreeMap<u8, (Option<&CheckEntry>, Option<&CheckEntry>)> = BTreeMap::new();
+    for entry in &old.results {
+        by_id.entry(entry.id.0).or_default().0 = Some(entry);
+    }
+    for entry in &new.results {
+        by_id.entry(entry.id.0).or_default().1 = Some(entry);
+    }
+
+    let mut summary = DiffSummary::default();
+    let checks: Vec<CheckDiff> = by_id
+        .into_values()
+        .filter_map(|pair| {
+            let (status, meta) = match pair {
+                (Some(o), Some(n)) => {
+                    let status = match (is_fail(o), is_fail(n)) {
+                        (false, true) => DiffStatus::Regressed,
+                        (true, false) => DiffStatus::Fixed,
+                        _ => DiffStatus::Unchanged,
+                    };
+                    (status, n)
+                }
+                (None, Some(n)) => (DiffStatus::Added, n),
+                (Some(o), None) => (DiffStatus::Removed, o),
+                (None, None) => return None,
+            };
+            match status {
+                DiffStatus::Regressed => summary.regressed += 1,
+                DiffStatus::Fixed => summary.fixed += 1,
+                DiffStatus::Unchanged => summary.unchanged += 1,
+                DiffStatus::Added => summary.added += 1,
+                DiffStatus::Removed => summary.removed += 1,
+            }
+            Some(CheckDiff {
+                id: meta.id,
+                category: meta.category.clone(),
+                description: meta.description.clone(),
+                status,
+                old: pair.0.map(|e| outcome(&e.result).to_string()),
+                new: pair.1.map(|e| outcome(&e.result).to_string()),
+            })
+        })
+        .collect();
+
+    ReportDiff {
+        old_timestamp: old.timestamp.clone(),
+        new_timestamp: new.timestamp.clone(),
+        checks,
+        summary,
+    }
+}
+
+fn is_fail(entry: &CheckEntry) -> bool {
+    matches!(entry.result, CheckResult::Fail { .. })
+}
+
+fn outcome(result: &CheckResult) -> &'static str {
+    match result {
+        CheckResult::Pass => "pass",
+        CheckResult::Fail { .. } => "fail",
+        CheckResult::Skip { .. } => "skip",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::{CheckId, ProjectScope, ProjectType, ScanSummary, Severity, Violation};
+
+    fn entry(id: u8, result: CheckResult) -> CheckEntry {
+        CheckEntry {
+            id: CheckId(id),
+            category: "structure".to_string(),
+            description: format!("check {id}"),
+            result,
+            duration_ms: None,
+        }
+    }
+
+    fn fail(id: u8) -> CheckResult {
+        CheckResult::Fail {
+            violations: vec![Violation {
+                check_id: CheckId(id),
+                path: None,
+                message: "violation msg".to_string(),
+                severity: Severity::Error,
+                rule_type: String::new(),
+                expected: None,
+                actual: None,
+                fix_hint: String::new(),
+            }],
+        }
+    }
+
+    fn make_report(entries: Vec<CheckEntry>) -> ScanReport {
+        ScanReport {
+            standard: "ISO/IEC/IEEE 15289:2019".to_string(),
+            clause: "9.2".to_string(),
+            tool: "doc-engine".to_string(),
+            tool_version: "0.1.0".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            project_root: "/tmp/test".to_string(),
+            summary: ScanSummary { total: entries.len() as u8, passed: 0, failed: 0, skipped: 0 },
+            results: entries,
+            project_type: ProjectType::OpenSource,
+            project_scope: ProjectScope::Large,
+            cache: None,
+            duration_ms: 0,
+        }
+    }
+
+    #[test]
+    fn test_diff_fixed_check() {
+        let old = make_report(vec![entry(1, fail(1)), entry(2, CheckResult::Pass)]);
+        let new = make_report(vec![entry(1, CheckResult::Pass), entry(2, CheckResult::Pass)]);
+        let diff = diff_reports(&old, &new);
+
+        let fixed: Vec<_> = diff.with_status(DiffStatus::Fixed).collect();
+        assert_eq!(fixed.len(), 1);
+        assert_eq!(fixed[0].id, CheckId(1));
+        assert_eq!(fixed[0].old.as_deref(), Some("fail"));
+        assert_eq!(fixed[0].new.as_deref(), Some("pass"));
+        assert_eq!(diff.summary, DiffSummary { fixed: 1, unchanged: 1, ..Default::default() });
+    }
+
+    #[test]
+    fn test_diff_regressed_check() {
+        let old = make_report(vec![entry(1, CheckResult::Pass), entry(2, CheckResult::Skip { reason: "n/a".into() })]);
+        let new = make_report(vec![entry(1, fail(1)), entry(2, fail(2))]);
+        let diff = diff_reports(&old, &new);
+
+        let regressed: Vec<u8> = diff.with_status(DiffStatus::Regressed).map(|c| c.id.0).collect();
+        assert_eq!(regressed, vec![1, 2]);
+        assert_eq!(diff.summary.regressed, 2);
+        assert_eq!(diff.summary.fixed, 0);
+    }
+
+    #[test]
+    fn test_diff_added_and_removed_checks() {
+        let old = make_report(vec![entry(3, CheckResult::Pass), entry(1, fail(1))]);
+        let new = make_report(vec![entry(1, fail(1)), entry(4, CheckResult::Pass)]);
+        let diff = diff_reports(&old, &new);
+
+        let statuses: Vec<(u8, DiffStatus)> = diff.checks.iter().map(|c| (c.id.0, c.status)).collect();
+        assert_eq!(statuses, vec![
+            (1, DiffStatus::Unchanged),
+            (3, DiffStatus::Removed),
+            (4, DiffStatus::Added),
+        ]);
+        let removed = &diff.checks[1];
+        assert_eq!(removed.new, None);
+        assert_eq!(diff.checks[2].old, None);
+    }
+}
@@ -1,12 +1,19 @@
 use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use crate::api::traits::ComplianceEngine;
-use crate::api::types::{ScanConfig, ScanReport, ScanSummary, CheckEntry};
+use rayon::prelude::*;
+
+use crate::api::traits::{CheckRunner, ComplianceEngine};
+use crate::api::types::{ProgressEvent, ScanConfig, ScanReport, ScanSummary, CheckEntry};
 use crate::api::traits::FileScanner;
-use crate::api::types::{CheckResult, ProjectType, ScanContext, ScanError, iso8601_now};
+use crate::api::types::{CheckId, CheckResult, FileCache, ProjectType, RuleDef, ScanContext, ScanError, Severity, Violation, iso8601_now};
 use super::rules::{self, DEFAULT_RULES};
-use super::scanner::FileSystemScanner;
+use super::scanner::{exclude_files, FileSystemScanner};
+use super::cache::ScanCache;
 
 /// Detect project type from LICENSE file content.
 ///
@@ -56,15 +63,121 @@ pub fn detect_project_type(root: &Path) -> ProjectType {
     }
 }
 
+/// A check scheduled in a dependency wave, with its rule definition if any.
+type WaveEntry<'a> = (&'a Arc<dyn CheckRunner>, Option<&'a RuleDef>);
+
+/// Milliseconds elapsed since `started`, saturating at `u64::MAX`.
+fn elapsed_ms(started: Instant) -> u64 {
+    u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
+/// `CheckFinished` event for a report entry.
+fn finished(entry: &CheckEntry) -> ProgressEvent {
+    ProgressEvent::CheckFinished {
+        id: entry.id,
+        description: entry.description.clone(),
+        result: entry.result.clone(),
+    }
+}
+
+/// Record `overrides[id]` as the severity of every violation in `result`.
+fn apply_severity_override(id: CheckId, result: CheckResult, overrides: &HashMap<CheckId, Severity>) -> CheckResult {
+    match (result, overrides.get(&id)) {
+        (CheckResult::Fail { mut violations }, Some(severity)) => {
+            for violation in &mut violations {
+                violation.severity = severity.clone();
+            }
+            CheckResult::Fail { violations }
+        }
+        (result, _) => result,
+    }
+}
+
+/// Whether a check result fails the scan. A failing check overridden to
+/// `info` is advisory and does not.
+fn is_failure(id: CheckId, result: &CheckResult, overrides: &HashMap<CheckId, Severity>) -> bool {
+    matches!(result, CheckResult::Fail { .. }) && overrides.get(&id) != Some(&Severity::Info)
+}
+
+/// Run one check, turning a panic into a failing result so a buggy handler
+/// cannot abort the whole scan. The flag is true when the check crashed;
+/// such results are never cached.
+fn run_guarded(runner: &dyn CheckRunner, ctx: &ScanContext) -> (CheckResult, bool) {
+    match panic::catch_unwind(AssertUnwindSafe(|| runner.run(ctx))) {
+        Ok(result) => (result, false),
+        Err(payload) => {
+            let reason = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            let violation = Violation {
+                check_id: runner.id(),
+                path: None,
+                message: format!("check crashed: {}", reason),
+                severity: Severity::Error,
+                rule_type: String::new(),
+                expected: None,
+                actual: None,
+                fix_hint: String::new(),
+            };
+            (CheckResult::Fail { violations: vec![violation] }, true)
+        }
+    }
+}
+
+/// Run one check like [`run_guarded`], giving up after `timeout_ms` when set.
+///
+/// The check runs on its own thread; past the deadline the scan records a
+/// failing result and moves on while the thread is left to finish in the
+/// background. Timed-out results are flagged like crashes and never cached.
+fn run_with_timeout(runner: &Arc<dyn CheckRunner>, ctx: &Arc<ScanContext>, timeout_ms: Option<u64>) -> (CheckResult, bool) {
+    let Some(timeout_ms) = timeout_ms else {
+        return run_guarded(runner.as_ref(), ctx);
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let (worker_runner, worker_ctx) = (Arc::clone(runner), Arc::clone(ctx));
+    let spawned = thread::Builder::new()
+        .name(format!("check-{}", runner.id().0))
+        .spawn(move || {
+            let _ = tx.send(run_guarded(worker_runner.as_ref(), &worker_ctx));
+        });
+    if spawned.is_err() {
+        return run_guarded(runner.as_ref(), ctx);
+    }
+
+    match rx.recv_timeout(Duration::from_millis(timeout_ms)) {
+        Ok(outcome) => outcome,
+        Err(_) => {
+            let violation = Violation {
+                check_id: runner.id(),
+                path: None,
+                message: format!("check timed out after {} ms", timeout_ms),
+                severity: Severity::Error,
+                rule_type: String::new(),
+                expected: None,
+                actual: None,
+                fix_hint: "Raise --check-timeout-ms or look for a cycle (e.g. a symlink loop) in the inputs of this check".to_string(),
+            };
+            (CheckResult::Fail { violations: vec![violation] }, true)
+        }
+    }
+}
+
 /// Doc-engine compliance engine.
 pub struct DocComplianceEngine;
 
 impl ComplianceEngine for DocComplianceEngine {
-    fn scan_with_config(&self, root: &Path, config: &ScanConfig) -> Result<ScanReport, ScanError> {
+    fn scan_with_progress(
+        &self,
+        root: &Path,
+        config: &ScanConfig,
+        on_event: &mut dyn FnMut(ProgressEvent),
+    ) -> Result<ScanReport, ScanError> {
         // Validate root path exists
         if !root.exists() {
             return Err(ScanError::Path(format!("Path '{}' does not exist", root.display())));
         }
+        let started = Instant::now();
 
         // 0. Resolve project type: explicit config overrides auto-detection from LICENSE
         let resolved_pt = match &config.project_type {
@@ -84,25 +197,70 @@ impl ComplianceEngine for DocComplianceEngine {
 
         // 2. Parse rules and build registry
         let ruleset = rules::parse_rules(&rules_toml)?;
-        let registry = rules::build_registry(&ruleset.rules)?;
+        let registry: Vec<Arc<dyn CheckRunner>> = rules::build_registry(&ruleset.rules)?
+            .into_iter()
+            .map(Arc::from)
+            .collect();
+        if let Some(id) = config.severity_overrides.keys().find(|id| !registry.iter().any(|r| r.id() == **id)) {
+            return Err(ScanError::Config(format!("Severity override for unknown check {}", id)));
+        }
 
-        // 3. Scanner discovers all files (single traversal per NFR-201)
+        // 3. Scanner discovers all files (single traversal per NFR-201), minus --exclude globs
         let scanner = FileSystemScanner;
-        let files = scanner.scan_files(root);
+        let discovered = scanner.scan_files(root);
+        let discovered_count = discovered.len();
+        let files = exclude_files(discovered, &config.exclude_globs)?;
+        if files.is_empty() && discovered_count > 0 {
+            // Every file is excluded: report an empty scan instead of failing every check
+            on_event(ProgressEvent::ScanStarted { total: 0 });
+            return Ok(ScanReport {
+                standard: "ISO/IEC/IEEE 15289:2019".to_string(),
+                clause: "9.2".to_string(),
+                tool: "doc-engine".to_string(),
+                tool_version: env!("CARGO_PKG_VERSION").to_string(),
+                timestamp: iso8601_now(),
+                project_root: root.display().to_string(),
+                results: Vec::new(),
+                summary: ScanSummary { total: 0, passed: 0, failed: 0, skipped: 0 },
+                project_type: resolved_pt,
+                project_scope: config.project_scope,
+                cache: None,
+                duration_ms: elapsed_ms(started),
+            });
+        }
 
-        // 4. Create ScanContext
-        let ctx = ScanContext {
+        // 4. Create ScanContext; shared so a timed-out check can outlive the scan
+        let ctx = Arc::new(ScanContext {
             root: root.to_path_buf(),
             files,
-            file_contents: HashMap::new(),
+            file_contents: FileCache::default(),
             project_type: resolved_pt.clone(),
             project_scope: config.project_scope,
             module_filter: config.module_filter.clone(),
-        };
+        });
+
+        // 5. Load the result cache when enabled; settings that change check
+        //    outcomes without touching any input file are part of its key
+        let mut cache = config.cache_dir.as_deref().map(|dir| {
+            let key = format!(
+                "{}\n{:?} {:?} {:?} {:?}",
+                rules_toml, resolved_pt, config.project_scope, config.module_filter, config.exclude_globs
+            );
+            ScanCache::load(dir, &key)
+        });
+
+        // 6. Build the worker pool; `jobs = None` uses one thread per core
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(config.jobs.unwrap_or(0))
+            .build()
+            .map_err(|e| ScanError::Config(format!("Cannot start check thread pool: {}", e)))?;
 
-        // 5. Filter and run checks
+        // Filter checks; rules that survive are grouped into dependency waves.
+        // depends_on only references lower IDs, so a check's wave is one past
+        // the latest wave among its parents.
         let mut results = Vec::new();
-        let mut failed_checks: HashSet<u8> = HashSet::new();
+        let mut waves: Vec<Vec<WaveEntry>> = Vec::new();
+        let mut wave_of: HashMap<u8, usize> = HashMap::new();
         for runner in &registry {
             let check_id = runner.id().0;
 
@@ -135,6 +293,7 @@ impl ComplianceEngine for DocComplianceEngine {
                                     rule_pt, resolved_pt
                                 ),
                             },
+                            duration_ms: None,
                         });
                         continue;
                     }
@@ -153,48 +312,142 @@ impl ComplianceEngine for DocComplianceEngine {
                                     rule_scope, config.project_scope
                                 ),
                             },
+                            duration_ms: None,
                         });
                         continue;
                     }
                 }
+            }
+
+            let wave = rule_def
+                .map(|rule| {
+                    rule.depends_on.iter()
+                        .filter_map(|dep_id| wave_of.get(dep_id))
+                        .map(|w| w + 1)
+                        .max()
+                        .unwrap_or(0)
+                })
+                .unwrap_or(0);
+            wave_of.insert(check_id, wave);
+            if waves.len() <= wave {
+                waves.resize_with(wave + 1, Vec::new);
+            }
+            waves[wave].push((runner, rule_def));
+        }
+
+        // Checks skipped above are already final
+        on_event(ProgressEvent::ScanStarted {
+            total: results.len() + waves.iter().map(Vec::len).sum::<usize>(),
+        });
+        for entry in &results {
+            on_event(finished(entry));
+        }
+
+        // 7. Run each wave in parallel, reusing cached results for checks whose inputs are unchanged
+        let mut failed_checks: HashSet<u8> = HashSet::new();
+        for wave in waves {
+            let mut pending = Vec::new();
+            for (runner, rule_def) in wave {
+                let check_id = runner.id().0;
 
                 // Check dependency graph: skip if any parent check failed
-                if let Some(&dep_id) = rule.depends_on.iter().find(|dep_id| failed_checks.contains(dep_id)) {
-                    results.push(CheckEntry {
+                let failed_dep = rule_def
+                    .and_then(|rule| rule.depends_on.iter().find(|dep_id| failed_checks.contains(dep_id)));
+                if let Some(&dep_id) = failed_dep {
+                    let entry = CheckEntry {
                         id: runner.id(),
                         category: runner.category().to_string(),
                         description: runner.description().to_string(),
                         result: CheckResult::Skip {
                             reason: format!("Skipped: dependency check {} failed", dep_id),
                         },
-                    });
+                        duration_ms: None,
+                    };
+                    on_event(finished(&entry));
+                    results.push(entry);
                     continue;
                 }
-            }
 
-            // 6. Run the check
-            let result = runner.run(&ctx);
+                let fingerprint = cache.as_mut().map(|cache| cache.fingerprint(runner.inputs(&ctx), &ctx));
+                let cached = match (cache.as_mut(), &fingerprint) {
+                    (Some(cache), Some(fp)) => cache.lookup(check_id, fp),
+                    _ => None,
+                };
+                match cached {
+                    Some(result) => {
+                        let result = apply_severity_override(runner.id(), result, &config.severity_overrides);
+                        if is_failure(runner.id(), &result, &config.severity_overrides) {
+                            failed_checks.insert(check_id);
+                        }
+                        let entry = CheckEntry {
+                            id: runner.id(),
+                            category: runner.category().to_string(),
+                            description: runner.description().to_string(),
+                            result,
+                            duration_ms: None,
+                        };
+                        on_event(finished(&entry));
+                        results.push(entry);
+                    }
+                    None => pending.push((runner, fingerprint)),
+                }
+            }
 
-            // Track failures for dependency resolution
-            if matches!(result, CheckResult::Fail { .. }) {
-                failed_checks.insert(check_id);
+            for (runner, _) in &pending {
+                on_event(ProgressEvent::CheckStarted {
+                    id: runner.id(),
+                    description: runner.description().to_string(),
+                });
             }
 
-            results.push(CheckEntry {
-                id: runner.id(),
-                category: runner.category().to_string(),
-                description: runner.description().to_string(),
-                result,
+            let outcomes: Vec<(CheckResult, bool, u64)> = pool.install(|| {
+                pending.par_iter().map(|(runner, _)| {
+                    let check_started = Instant::now();
+                    let (result, crashed) = run_with_timeout(runner, &ctx, config.check_timeout_ms);
+                    (result, crashed, elapsed_ms(check_started))
+                }).collect()
             });
+
+            for ((runner, fingerprint), (result, crashed, duration_ms)) in pending.into_iter().zip(outcomes) {
+                let check_id = runner.id().0;
+                if let (Some(cache), Some(fp), false) = (cache.as_mut(), fingerprint, crashed) {
+                    cache.store(check_id, fp, &result);
+                }
+
+                // Track failures for dependency resolution
+                let result = apply_severity_override(runner.id(), result, &config.severity_overrides);
+                if is_failure(runner.id(), &result, &config.severity_overrides) {
+                    failed_checks.insert(check_id);
+                }
+
+                let entry = CheckEntry {
+                    id: runner.id(),
+                    category: runner.category().to_string(),
+                    description: runner.description().to_string(),
+                    result,
+                    duration_ms: Some(duration_ms),
+                };
+                on_event(finished(&entry));
+                results.push(entry);
+            }
         }
 
-        // 7. Compute summary
+        // Waves complete out of ID order; sort so output stays deterministic
+        results.sort_by_key(|entry| entry.id.0);
+
+        // Persist the cache; a write failure only costs the next scan its hits
+        let cache_stats = cache.map(|cache| {
+            let _ = cache.save();
+            cache.stats()
+        });
+
+        // 8. Compute summary
         let total = results.len() as u8;
-        let passed = results.iter().filter(|e| matches!(e.result, CheckResult::Pass)).count() as u8;
-        let failed = results.iter().filter(|e| matches!(e.result, CheckResult::Fail { .. })).count() as u8;
+        let failed = results.iter().filter(|e| is_failure(e.id, &e.result, &config.severity_overrides)).count() as u8;
         let skipped = results.iter().filter(|e| matches!(e.result, CheckResult::Skip { .. })).count() as u8;
+        let passed = total - failed - skipped;
 
-        // 8. Return ScanReport
+        // 9. Return ScanReport
         Ok(ScanReport {
             standard: "ISO/IEC/IEEE 15289:2019".to_string(),
             clause: "9.2".to_string(),
@@ -206,6 +459,8 @@ impl ComplianceEngine for DocComplianceEngine {
             summary: ScanSummary { total, passed, failed, skipped },
             project_type: resolved_pt,
             project_scope: config.project_scope,
+            cache: cache_stats,
+            duration_ms: elapsed_ms(started),
         })
     }
 }
@@ -215,7 +470,7 @@ mod tests {
     use super::*;
     use crate::api::traits::ComplianceEngine;
     use crate::core::rules::default_rule_count;
-    use crate::api::types::{ProjectScope, ProjectType};
+    use crate::api::types::{CacheStats, ProjectScope, ProjectType};
     use tempfile::TempDir;
 
     #[test]
@@ -228,6 +483,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let result = engine.scan_with_config(std::path::Path::new("/nonexistent/path/xyz"), &config);
         assert!(result.is_err());
@@ -245,6 +505,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         let expected = default_rule_count();
@@ -263,6 +528,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 3);
@@ -282,6 +552,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 2);
@@ -301,6 +576,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(
@@ -369,6 +649,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.project_type, ProjectType::OpenSource);
@@ -385,6 +670,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.project_type, ProjectType::Internal);
@@ -401,6 +691,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 1);
@@ -418,6 +713,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 1);
@@ -435,6 +735,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 3);
@@ -456,6 +761,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.project_scope, ProjectScope::Medium);
@@ -472,6 +782,11 @@ mod tests {
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 1);
@@ -516,6 +831,11 @@ depends_on = [1]
             rules_path: Some(rules_path),
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 2);
@@ -562,6 +882,11 @@ depends_on = [1]
             rules_path: Some(rules_path),
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 2);
@@ -570,6 +895,78 @@ depends_on = [1]
         assert!(matches!(report.results[1].result, CheckResult::Fail { .. }));
     }
 
+
+    #[test]
+    fn test_parallel_waves_respect_dependencies() {
+        // 1 fails, 2 depends on 1 (skipped), 3 depends on 2 (skipped, not failed → runs),
+        // 4 is independent and runs in the first wave alongside 1
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("exists.md"), "content").unwrap();
+        let rules_toml = r#"
+[[rules]]
+id = 1
+category = "test"
+description = "parent check"
+severity = "error"
+type = "file_exists"
+path = "nonexistent.md"
+
+[[rules]]
+id = 2
+category = "test"
+description = "child check"
+severity = "error"
+type = "file_exists"
+path = "exists.md"
+depends_on = [1]
+
+[[rules]]
+id = 3
+category = "test"
+description = "grandchild check"
+severity = "error"
+type = "file_exists"
+path = "exists.md"
+depends_on = [2]
+
+[[rules]]
+id = 4
+category = "test"
+description = "independent check"
+severity = "error"
+type = "file_exists"
+path = "exists.md"
+"#;
+        let rules_path = tmp.path().join("rules.toml");
+        std::fs::write(&rules_path, rules_toml).unwrap();
+        let engine = DocComplianceEngine;
+        let config = ScanConfig {
+            project_type: Some(ProjectType::OpenSource),
+            project_scope: ProjectScope::Large,
+            checks: None,
+            rules_path: Some(rules_path),
+            phases: None,
+            module_filter: None,
+            cache_dir: None,
+            jobs: Some(4),
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
+        };
+        let report = engine.scan_with_config(tmp.path(), &config).unwrap();
+        let ids: Vec<u8> = report.results.iter().map(|e| e.id.0).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        assert!(matches!(report.results[0].result, CheckResult::Fail { .. }));
+        assert!(matches!(report.results[1].result, CheckResult::Skip { .. }));
+        assert!(matches!(report.results[2].result, CheckResult::Pass));
+        assert!(matches!(report.results[3].result, CheckResult::Pass));
+    }
+
+    #[test]
+    fn test_scan_context_is_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ScanContext>();
+    }
     #[test]
     fn test_dependency_filtered_parent() {
         // Check 1 (parent) is filtered out by --checks
@@ -605,6 +1002,11 @@ depends_on = [1]
             rules_path: Some(rules_path),
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 1);
@@ -657,6 +1059,11 @@ depends_on = [2]
             rules_path: Some(rules_path),
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 3);
@@ -682,6 +1089,11 @@ depends_on = [2]
             rules_path: None,
             phases: None,
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         let expected = default_rule_count();
@@ -704,6 +1116,11 @@ depends_on = [2]
             rules_path: None,
             phases: Some(vec!["structure".to_string()]),
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert!(!report.results.is_empty());
@@ -723,6 +1140,11 @@ depends_on = [2]
             rules_path: None,
             phases: Some(vec!["nonexistent_phase".to_string()]),
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert!(report.results.is_empty());
@@ -739,6 +1161,11 @@ depends_on = [2]
             rules_path: None,
             phases: Some(vec!["structure".to_string(), "naming".to_string()]),
             module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         for entry in &report.results {
@@ -760,8 +1187,400 @@ depends_on = [2]
             rules_path: None,
             phases: None,
             module_filter: Some(vec!["scan".to_string()]),
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 1);
     }
+
+    #[test]
+    fn test_cache_reuses_declarative_results() {
+        // Both checks take README.md as their only input
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("README.md"), "# Title\n").unwrap();
+        let rules_toml = r#"
+[[rules]]
+id = 1
+category = "test"
+description = "content check"
+severity = "error"
+type = "file_content_matches"
+path = "README.md"
+pattern = "^# "
+
+[[rules]]
+id = 2
+category = "test"
+description = "existence check"
+severity = "error"
+type = "file_exists"
+path = "README.md"
+"#;
+        let rules_path = tmp.path().join("rules.toml");
+        std::fs::write(&rules_path, rules_toml).unwrap();
+        let cache_dir = tmp.path().join("cache");
+        let engine = DocComplianceEngine;
+        let config = ScanConfig {
+            project_type: Some(ProjectType::OpenSource),
+            project_scope: ProjectScope::Large,
+            checks: None,
+            rules_path: Some(rules_path.clone()),
+            phases: None,
+            module_filter: None,
+            cache_dir: Some(cache_dir.clone()),
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
+        };
+
+        let first = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(first.cache, Some(CacheStats { hits: 0, misses: 2 }));
+
+        let second = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(second.cache, Some(CacheStats { hits: 2, misses: 0 }));
+        assert!(matches!(second.results[0].result, CheckResult::Pass));
+        assert!(second.results.iter().all(|entry| entry.duration_ms.is_none()));
+
+        // Changing the file content re-evaluates the check
+        std::fs::write(tmp.path().join("README.md"), "no heading\n").unwrap();
+        let third = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(third.cache, Some(CacheStats { hits: 0, misses: 2 }));
+        assert!(matches!(third.results[0].result, CheckResult::Fail { .. }));
+
+        // Deleting the file re-evaluates the existence check
+        std::fs::remove_file(tmp.path().join("README.md")).unwrap();
+        let fourth = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(fourth.cache, Some(CacheStats { hits: 0, misses: 2 }));
+        assert!(matches!(fourth.results[1].result, CheckResult::Fail { .. }));
+
+        // Changing the rule set invalidates the whole cache
+        std::fs::write(&rules_path, rules_toml.replace("content check", "heading check")).unwrap();
+        let fifth = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(fifth.cache, Some(CacheStats { hits: 0, misses: 2 }));
+    }
+
+    #[test]
+    fn test_cache_builtin_default_inputs() {
+        // Builtins default to every scanned file as input
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("README.md"), "# Title\n").unwrap();
+        std::fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        std::fs::write(tmp.path().join("docs/guide.md"), "# Guide\n").unwrap();
+        let rules_path = tmp.path().join("rules.toml");
+        std::fs::write(&rules_path, r#"
+[[rules]]
+id = 75
+category = "content"
+description = "Root README.md under 100 lines"
+severity = "info"
+type = "builtin"
+handler = "readme_line_count"
+"#).unwrap();
+        let engine = DocComplianceEngine;
+        let config = ScanConfig {
+            project_type: Some(ProjectType::OpenSource),
+            project_scope: ProjectScope::Large,
+            checks: None,
+            rules_path: Some(rules_path),
+            phases: None,
+            module_filter: None,
+            cache_dir: Some(tmp.path().join("cache")),
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
+        };
+
+        let first = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(first.cache, Some(CacheStats { hits: 0, misses: 1 }));
+        let unchanged = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(unchanged.cache, Some(CacheStats { hits: 1, misses: 0 }));
+        assert!(matches!(unchanged.results[0].result, CheckResult::Pass));
+
+        // Adding or deleting any scanned file re-runs the check
+        std::fs::write(tmp.path().join("docs/new.md"), "# New\n").unwrap();
+        let added = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(added.cache, Some(CacheStats { hits: 0, misses: 1 }));
+        std::fs::remove_file(tmp.path().join("docs/guide.md")).unwrap();
+        let deleted = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(deleted.cache, Some(CacheStats { hits: 0, misses: 1 }));
+
+        // Modifying an input re-runs the check with the new content
+        std::fs::write(tmp.path().join("README.md"), "line\n".repeat(150)).unwrap();
+        let modified = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(modified.cache, Some(CacheStats { hits: 0, misses: 1 }));
+        assert!(matches!(modified.results[0].result, CheckResult::Fail { .. }));
+    }
+
+    #[test]
+    fn test_progress_events_cover_every_check() {
+        let tmp = TempDir::new().unwrap();
+        let engine = DocComplianceEngine;
+        let config = ScanConfig {
+            project_type: Some(ProjectType::OpenSource),
+            project_scope: ProjectScope::Small,
+            checks: Some((1..=20).collect()),
+            rules_path: None,
+            phases: None,
+            module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
+        };
+        let mut events = Vec::new();
+        let report = engine.scan_with_progress(tmp.path(), &config, &mut |event| events.push(event)).unwrap();
+
+        assert!(matches!(events[0], ProgressEvent::ScanStarted { total } if total == report.results.len()));
+        let finished = events.iter().filter(|e| matches!(e, ProgressEvent::CheckFinished { .. })).count();
+        assert_eq!(finished, report.results.len());
+        let started = events.iter().filter(|e| matches!(e, ProgressEvent::CheckStarted { .. })).count();
+        assert_eq!(started, report.results.iter().filter(|e| e.duration_ms.is_some()).count());
+    }
+
+    #[test]
+    fn test_cache_disabled_reports_no_stats() {
+        let tmp = TempDir::new().unwrap();
+        let engine = DocComplianceEngine;
+        let config = ScanConfig {
+            project_type: Some(ProjectType::OpenSource),
+            project_scope: ProjectScope::Large,
+            checks: Some(vec![1]),
+            rules_path: None,
+            phases: None,
+            module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
+        };
+        let report = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert!(report.cache.is_none());
+    }
+
+    fn exclude_config(checks: Vec<u8>, exclude_globs: &[&str]) -> ScanConfig {
+        ScanConfig {
+            project_type: Some(ProjectType::OpenSource),
+            project_scope: ProjectScope::Large,
+            checks: Some(checks),
+            rules_path: None,
+            phases: None,
+            module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: exclude_globs.iter().map(|g| g.to_string()).collect(),
+            severity_overrides: HashMap::new(),
+            check_timeout_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_exclude_globs_skip_vendored_docs() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::create_dir_all(tmp.path().join("docs/third_party")).unwrap();
+        // The vendored file is still a valid link target; its own links are not audited
+        std::fs::write(tmp.path().join("docs/README.md"), "[Vendored](third_party/guide.md)\n").unwrap();
+        std::fs::write(tmp.path().join("docs/third_party/guide.md"), "[Broken](missing.md)\n").unwrap();
+        let engine = DocComplianceEngine;
+
+        let report = engine.scan_with_config(tmp.path(), &exclude_config(vec![44], &[])).unwrap();
+        assert!(matches!(report.results[0].result, CheckResult::Fail { .. }));
+
+        let config = exclude_config(vec![44], &["docs/third_party"]);
+        let report = engine.scan_with_config(tmp.path(), &config).unwrap();
+        assert!(matches!(report.results[0].result, CheckResult::Pass), "{:?}", report.results[0].result);
+    }
+
+    #[test]
+    fn test_exclude_globs_matching_everything_gives_empty_scan() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("README.md"), "# Project\n").unwrap();
+        let engine = DocComplianceEngine;
+        let report = engine.scan_with_config(tmp.path(), &exclude_config(vec![1, 2], &["**"])).unwrap();
+        assert!(report.results.is_empty());
+        assert_eq!(report.summary.total, 0);
+    }
+
+    #[test]
+    fn test_exclude_globs_invalid_pattern() {
+        let tmp = TempDir::new().unwrap();
+        let engine = DocComplianceEngine;
+        let result = engine.scan_with_config(tmp.path(), &exclude_config(vec![1], &["docs/[oops"]));
+        assert!(matches!(result, Err(ScanError::Config(_))));
+    }
+
+    fn override_config(overrides: &[(u8, Severity)]) -> ScanConfig {
+        ScanConfig {
+            project_type: Some(ProjectType::OpenSource),
+            project_scope: ProjectScope::Large,
+            checks: Some(vec![1]),
+            rules_path: None,
+            phases: None,
+            module_filter: None,
+            cache_dir: None,
+            jobs: None,
+            exclude_globs: Vec::new(),
+            severity_overrides: overrides.iter().map(|(id, sev)| (CheckId(*id), sev.clone())).collect(),
+            check_timeout_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_severity_override_to_info_is_advisory() {
+        let tmp = TempDir::new().unwrap();
+        let engine = DocComplianceEngine;
+
+        let report = engine.scan_with_config(tmp.path(), &override_config(&[])).unwrap();
+        assert_eq!(report.summary.failed, 1);
+
+        let report = engine.scan_with_config(tmp.path(), &override_config(&[(1, Severity::Info)])).unwrap();
+        assert_eq!(report.summary.failed, 0);
+        assert_eq!(report.summary.passed, 1);
+        match &report.results[0].result {
+            CheckResult::Fail { violations } => {
+                assert!(violations.iter().all(|v| v.severity == Severity::Info));
+            }
+            other => panic!("Expected Fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_severity_override_to_warning_still_fails() {
+        let tmp = TempDir::new().unwrap();
+        let report = DocComplianceEngine
+            .scan_with_config(tmp.path(), &override_config(&[(1, Severity::Warning)]))
+            .unwrap();
+        assert_eq!(report.summary.failed, 1);
+        assert_eq!(report.max_severity(), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn test_severity_override_unknown_check() {
+        let tmp = TempDir::new().unwrap();
+        let result = DocComplianceEngine.scan_with_config(tmp.path(), &override_config(&[(250, Severity::Info)]));
+        assert!(matches!(result, Err(ScanError::Config(_))));
+    }
+
+    #[test]
+    fn test_panicking_check_reports_crash_and_scan_continues() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("README.md"), "# Demo\n").unwrap();
+        let rules_toml = r#"
+[[rules]]
+id = 1
+category = "test"
+description = "readme exists"
+severity = "error"
+type = "file_exists"
+path = "README.md"
+
+[[rules]]
+id = 2
+category = "test"
+description = "buggy handler"
+severity = "error"
+type = "builtin"
+handler = "test_panic"
+
+[[rules]]
+id = 3
+category = "test"
+description = "readme exists again"
+severity = "error"
+type = "file_exists"
+path = "README.md"
+"#;
+        let rules_path = tmp.path().join("rules.toml");
+        std::fs::write(&rules_path, rules_toml).unwrap();
+        let mut config = override_config(&[]);
+        config.checks = None;
+        config.rules_path = Some(rules_path);
+        config.cache_dir = Some(tmp.path().join("cache"));
+
+        let report = DocComplianceEngine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(report.results.len(), 3);
+        assert!(matches!(report.results[0].result, CheckResult::Pass));
+        assert!(matches!(report.results[2].result, CheckResult::Pass));
+        match &report.results[1].result {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].message, "check crashed: handler exploded");
+                assert_eq!(violations[0].severity, Severity::Error);
+            }
+            other => panic!("expected synthetic failure, got {:?}", other),
+        }
+        assert_eq!(report.summary.failed, 1);
+        assert_eq!(report.summary.passed, 2);
+
+        // A crash is never cached, so the next scan runs the check again
+        let report = DocComplianceEngine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(report.cache.unwrap().hits, 2);
+    }
+
+    #[test]
+    fn test_check_timeout_reports_slow_check_and_scan_continues() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("README.md"), "# Demo\n").unwrap();
+        let rules_toml = r#"
+[[rules]]
+id = 1
+category = "test"
+description = "readme exists"
+severity = "error"
+type = "file_exists"
+path = "README.md"
+
+[[rules]]
+id = 2
+category = "test"
+description = "stuck handler"
+severity = "error"
+type = "builtin"
+handler = "test_sleep"
+threshold = 5000
+
+[[rules]]
+id = 3
+category = "test"
+description = "quick handler"
+severity = "error"
+type = "builtin"
+handler = "test_sleep"
+threshold = 0
+"#;
+        let rules_path = tmp.path().join("rules.toml");
+        std::fs::write(&rules_path, rules_toml).unwrap();
+        let mut config = override_config(&[]);
+        config.checks = None;
+        config.rules_path = Some(rules_path);
+        config.cache_dir = Some(tmp.path().join("cache"));
+        config.check_timeout_ms = Some(200);
+
+        let started = Instant::now();
+        let report = DocComplianceEngine.scan_with_config(tmp.path(), &config).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(4), "scan waited for the stuck check");
+        assert_eq!(report.results.len(), 3);
+        assert!(matches!(report.results[0].result, CheckResult::Pass));
+        assert!(matches!(report.results[2].result, CheckResult::Pass));
+        match &report.results[1].result {
+            CheckResult::Fail { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].message, "check timed out after 200 ms");
+            }
+            other => panic!("expected timeout failure, got {:?}", other),
+        }
+        assert_eq!(report.summary.failed, 1);
+
+        // A timeout is never cached, so the next scan runs the check again
+        let report = DocComplianceEngine.scan_with_config(tmp.path(), &config).unwrap();
+        assert_eq!(report.cache.unwrap().hits, 2);
+        assert!(matches!(report.results[1].result, CheckResult::Fail { .. }));
+    }
 }
@@ -6,3 +6,6 @@ pub(crate) mod declarative;
 pub(crate) mod builtins;
 pub(crate) mod regex_utils;
 pub(crate) mod sink;
+pub(crate) mod cache;
+pub(crate) mod diff;
+pub(crate) mod config_file;
@@ -1,27 +1,71 @@
 use std::collections::BTreeMap;
 
+use anstyle::AnsiColor;
+
 use crate::api::types::ScanReport;
 use crate::api::traits::Reporter;
-use crate::api::types::CheckResult;
+use crate::api::types::{CheckResult, Severity};
+
+/// Plain-text reporter grouped by category. `quiet` keeps only failing checks
+/// and the summary line; `color` styles PASS/FAIL/SKIP with `anstyle`. With
+/// `color` off the output carries no escape sequences.
+#[derive(Default)]
+pub struct TextReporter {
+    pub quiet: bool,
+    pub color: bool,
+}
 
-pub struct TextReporter;
 pub struct JsonReporter;
+pub struct HtmlReporter;
+pub struct MarkdownReporter;
+
+/// GitHub Actions reporter: one `::error`/`::warning`/`::notice` workflow
+/// command per violation so failures show up as inline annotations.
+/// Violations carry no line number, so annotations attach to the file, or to
+/// the repository when the violation has no path.
+pub struct GithubActionsReporter;
+
+/// CSV reporter: a `check_id,category,severity,file,line,message` header, then
+/// one RFC 4180 row per violation. Passing checks emit no rows; skipped checks
+/// emit one `skipped` row with the reason when `include_skipped` is set.
+/// Violations carry no line number, so `line` is always empty.
+pub struct CsvReporter {
+    pub include_skipped: bool,
+}
+
+/// JUnit XML reporter: one `<testsuite>` whose `tests`/`failures`/`skipped`
+/// counts come from the scan summary, with one `<testcase>` per check named
+/// by its id and classed by its category.
+pub struct JUnitReporter;
 
 impl Reporter for TextReporter {
     fn report(&self, report: &ScanReport) -> String {
         let mut output = String::new();
+        let paint = |color: AnsiColor, text: &str| {
+            if self.color {
+                let style = color.on_default();
+                format!("{style}{text}{style:#}")
+            } else {
+                text.to_string()
+            }
+        };
 
-        output.push_str(&format!(
-            "doc-engine scan results (project type: {:?}, scope: {:?})\n",
-            report.project_type, report.project_scope
-        ));
-        output.push_str(&"=".repeat(60));
-        output.push('\n');
-        output.push('\n');
+        if !self.quiet {
+            output.push_str(&format!(
+                "doc-engine scan results (project type: {:?}, scope: {:?})\n",
+                report.project_type, report.project_scope
+            ));
+            output.push_str(&"=".repeat(60));
+            output.push('\n');
+            output.push('\n');
+        }
 
-        // Group results by category
+        // Group results by category; quiet mode keeps failures only
         let mut by_category: BTreeMap<&str, Vec<_>> = BTreeMap::new();
         for entry in &report.results {
+            if self.quiet && !matches!(entry.result, CheckResult::Fail { .. }) {
+                continue;
+            }
             by_category.entry(&entry.category).or_default().push(entry);
         }
 
@@ -30,9 +74,9 @@ impl Reporter for TextReporter {
 
             for entry in entries {
                 let status = match &entry.result {
-                    CheckResult::Pass => "PASS",
-                    CheckResult::Fail { .. } => "FAIL",
-                    CheckResult::Skip { .. } => "SKIP",
+                    CheckResult::Pass => paint(AnsiColor::Green, "PASS"),
+                    CheckResult::Fail { .. } => paint(AnsiColor::Red, "FAIL"),
+                    CheckResult::Skip { .. } => paint(AnsiColor::Yellow, "SKIP"),
                 };
 
                 output.push_str(&format!(
@@ -86,6 +130,389 @@ impl Reporter for JsonReporter {
     }
 }
 
+/// Inline stylesheet so the HTML report is a single self-contained file.
+const HTML_STYLE: &str = "\
+body{font-family:system-ui,sans-serif;margin:2rem;color:#1f2328;}\
+.banner{padding:1rem;border-radius:6px;margin-bottom:1.5rem;font-weight:600;}\
+.banner.ok{background:#dafbe1;}.banner.failing{background:#ffebe9;}\
+details{border:1px solid #d0d7de;border-radius:6px;margin-bottom:.75rem;padding:.5rem 1rem;}\
+summary{cursor:pointer;font-weight:600;}\
+ul{list-style:none;padding-left:0;}li{margin:.4rem 0;}\
+.status{display:inline-block;min-width:3.5rem;font-weight:700;}\
+.pass .status{color:#1a7f37;}.fail .status{color:#cf222e;}.skip .status{color:#6e7781;}\
+.detail{margin:.2rem 0 0 3.5rem;color:#57606a;}";
+
+/// Escape text for use in HTML or XML element content and attribute values.
+fn markup_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+impl Reporter for HtmlReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut output = String::new();
+        output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        output.push_str(&format!(
+            "<title>doc-engine report: {}</title>\n",
+            markup_escape(&report.project_root)
+        ));
+        output.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", HTML_STYLE));
+
+        output.push_str(&format!(
+            "<h1>doc-engine scan results</h1>\n<p>{} &middot; {:?} / {:?} &middot; {} {}</p>\n",
+            markup_escape(&report.project_root),
+            report.project_type,
+            report.project_scope,
+            markup_escape(&report.tool_version),
+            markup_escape(&report.timestamp),
+        ));
+
+        let banner = if report.summary.failed == 0 { "ok" } else { "failing" };
+        output.push_str(&format!(
+            "<div class=\"banner {}\">{}/{} passed, {} failed, {} skipped</div>\n",
+            banner,
+            report.summary.passed,
+            report.summary.total,
+            report.summary.failed,
+            report.summary.skipped,
+        ));
+
+        // Group results by category; categories with failures start expanded
+        let mut by_category: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+        for entry in &report.results {
+            by_category.entry(&entry.category).or_default().push(entry);
+        }
+
+        for (category, entries) in &by_category {
+            let failed = entries
+                .iter()
+                .filter(|e| matches!(e.result, CheckResult::Fail { .. }))
+                .count();
+            let open = if failed > 0 { " open" } else { "" };
+            output.push_str(&format!(
+                "<details{}>\n<summary>{} ({} checks, {} failed)</summary>\n<ul>\n",
+                open,
+                markup_escape(category),
+                entries.len(),
+                failed,
+            ));
+
+            for entry in entries {
+                let (class, status) = match &entry.result {
+                    CheckResult::Pass => ("pass", "PASS"),
+                    CheckResult::Fail { .. } => ("fail", "FAIL"),
+                    CheckResult::Skip { .. } => ("skip", "SKIP"),
+                };
+                output.push_str(&format!(
+                    "<li class=\"{}\"><span class=\"status\">{}</span> {}: {}",
+                    class,
+                    status,
+                    entry.id,
+                    markup_escape(&entry.description),
+                ));
+
+                if let CheckResult::Fail { violations } = &entry.result {
+                    for v in violations {
+                        let location = v.path.as_ref()
+                            .map(|p| format!("{}: ", markup_escape(&p.to_string_lossy())))
+                            .unwrap_or_default();
+                        output.push_str(&format!(
+                            "\n<div class=\"detail\">{}{}</div>",
+                            location,
+                            markup_escape(&v.message),
+                        ));
+                        if !v.fix_hint.is_empty() {
+                            output.push_str(&format!(
+                                "\n<div class=\"detail\">Fix: {}</div>",
+                                markup_escape(&v.fix_hint),
+                            ));
+                        }
+                    }
+                }
+
+                if let CheckResult::Skip { reason } = &entry.result {
+                    output.push_str(&format!(
+                        "\n<div class=\"detail\">{}</div>",
+                        markup_escape(reason),
+                    ));
+                }
+                output.push_str("</li>\n");
+            }
+            output.push_str("</ul>\n</details>\n");
+        }
+
+        output.push_str("</body>\n</html>\n");
+        output
+    }
+}
+
+/// Violations listed per failing check before the rest are summarized.
+const MARKDOWN_MAX_VIOLATIONS: usize = 5;
+
+/// Output budget in bytes, kept below GitHub's 65,536-character comment limit.
+const MARKDOWN_MAX_BYTES: usize = 60_000;
+
+/// Escape text for a Markdown table cell: pipes would split the cell and
+/// newlines would end the row.
+fn markdown_cell(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace('\n', " ")
+}
+
+impl Reporter for MarkdownReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut output = String::new();
+        let icon = if report.summary.failed == 0 { ":white_check_mark:" } else { ":x:" };
+        output.push_str("## doc-engine scan results\n\n");
+        output.push_str(&format!(
+            "{} **{}/{} passed**, {} failed, {} skipped\n",
+            icon,
+            report.summary.passed,
+            report.summary.total,
+            report.summary.failed,
+            report.summary.skipped,
+        ));
+
+        let mut by_category: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+        for entry in &report.results {
+            by_category.entry(&entry.category).or_default().push(entry);
+        }
+
+        let total_rows = report.results.len();
+        let mut rows_written = 0;
+        'categories: for (category, entries) in &by_category {
+            output.push_str(&format!(
+                "\n### {}\n\n| ID | Check | Status | Message |\n|---|---|---|---|\n",
+                markdown_cell(category),
+            ));
+
+            for entry in entries {
+                let (status, message) = match &entry.result {
+                    CheckResult::Pass => (":white_check_mark:", String::new()),
+                    CheckResult::Skip { reason } => (":fast_forward:", markdown_cell(reason)),
+                    CheckResult::Fail { violations } => {
+                        let mut lines: Vec<String> = violations
+                            .iter()
+                            .take(MARKDOWN_MAX_VIOLATIONS)
+                            .map(|v| match &v.path {
+                                Some(p) => markdown_cell(&format!("{}: {}", p.display(), v.message)),
+                                None => markdown_cell(&v.message),
+                            })
+                            .collect();
+                        if violations.len() > MARKDOWN_MAX_VIOLATIONS {
+                            lines.push(format!("… and {} more", violations.len() - MARKDOWN_MAX_VIOLATIONS));
+                        }
+                        (":x:", lines.join("<br>"))
+                    }
+                };
+                let row = format!(
+                    "| {} | {} | {} | {} |\n",
+                    entry.id,
+                    markdown_cell(&entry.description),
+                    status,
+                    message,
+                );
+                if output.len() + row.len() > MARKDOWN_MAX_BYTES {
+                    output.push_str(&format!(
+                        "\n… and {} more checks (output truncated)\n",
+                        total_rows - rows_written,
+                    ));
+                    break 'categories;
+                }
+                output.push_str(&row);
+                rows_written += 1;
+            }
+        }
+
+        output
+    }
+}
+
+/// Workflow command for a severity; `Info` maps to `notice`.
+fn github_command(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "notice",
+    }
+}
+
+/// Escape a workflow command message (the text after `::`).
+fn github_escape_data(text: &str) -> String {
+    text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value (`file=`).
+fn github_escape_property(text: &str) -> String {
+    github_escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+impl Reporter for GithubActionsReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut output = String::new();
+        for entry in &report.results {
+            let CheckResult::Fail { violations } = &entry.result else {
+                continue;
+            };
+            for v in violations {
+                let location = v.path.as_ref()
+                    .map(|p| format!(" file={}", github_escape_property(&p.to_string_lossy().replace('\\', "/"))))
+                    .unwrap_or_default();
+                output.push_str(&format!(
+                    "::{}{}::{}\n",
+                    github_command(&v.severity),
+                    location,
+                    github_escape_data(&v.message),
+                ));
+            }
+        }
+        output
+    }
+}
+
+/// Quote a CSV field per RFC 4180 when it contains a comma, quote, CR or LF.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn severity_name(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+impl Reporter for CsvReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut output = String::from("check_id,category,severity,file,line,message\r\n");
+        let mut row = |id: &str, category: &str, severity: &str, file: &str, message: &str| {
+            let fields = [id, category, severity, file, "", message].map(csv_field);
+            output.push_str(&fields.join(","));
+            output.push_str("\r\n");
+        };
+        for entry in &report.results {
+            let id = entry.id.to_string();
+            match &entry.result {
+                CheckResult::Fail { violations } => {
+                    for v in violations {
+                        let file = v.path.as_ref()
+                            .map(|p| p.to_string_lossy().replace('\\', "/"))
+                            .unwrap_or_default();
+                        row(&id, &entry.category, severity_name(&v.severity), &file, &v.message);
+                    }
+                }
+                CheckResult::Skip { reason } if self.include_skipped => {
+                    row(&id, &entry.category, "skipped", "", reason);
+                }
+                _ => {}
+            }
+        }
+        output
+    }
+}
+
+impl Reporter for JUnitReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let summary = &report.summary;
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str(&format!(
+            "<testsuites name=\"doc-engine\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+            summary.total, summary.failed, summary.skipped,
+        ));
+        output.push_str(&format!(
+            "  <testsuite name=\"doc-engine\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+            summary.total, summary.failed, summary.skipped,
+        ));
+
+        for entry in &report.results {
+            let open = format!(
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                markup_escape(&entry.category),
+                markup_escape(&format!("{}: {}", entry.id, entry.description)),
+            );
+            match &entry.result {
+                CheckResult::Pass => {
+                    output.push_str(&open);
+                    output.push_str("/>\n");
+                }
+                CheckResult::Fail { violations } => {
+                    let body = violations.iter()
+                        .map(|v| match &v.path {
+                            Some(p) => format!("{}: {}", p.to_string_lossy().replace('\\', "/"), v.message),
+                            None => v.message.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    output.push_str(&open);
+                    output.push_str(">\n");
+                    output.push_str(&format!(
+                        "      <failure message=\"{} violation(s)\">{}</failure>\n",
+                        violations.len(),
+                        markup_escape(&body),
+                    ));
+                    output.push_str("    </testcase>\n");
+                }
+                CheckResult::Skip { reason } => {
+                    output.push_str(&open);
+                    output.push_str(">\n");
+                    output.push_str(&format!("      <skipped message=\"{}\"/>\n", markup_escape(reason)));
+                    output.push_str("    </testcase>\n");
+                }
+            }
+        }
+
+        output.push_str("  </testsuite>\n");
+        output.push_str("</testsuites>\n");
+        output
+    }
+}
+
+/// Rows shown in the `--timings` table.
+const TIMINGS_MAX_ROWS: usize = 10;
+
+/// "Slowest checks" table for `--timings`, appended after the text report.
+///
+/// Lists checks that actually ran, slowest first (ties by ID); skipped and
+/// cached checks have no duration and are left out.
+pub fn timings_text(report: &ScanReport) -> String {
+    let mut timed: Vec<_> = report.results.iter()
+        .filter_map(|entry| entry.duration_ms.map(|ms| (ms, entry)))
+        .collect();
+    timed.sort_by(|(a_ms, a), (b_ms, b)| b_ms.cmp(a_ms).then(a.id.0.cmp(&b.id.0)));
+
+    let mut output = String::new();
+    output.push_str(&format!("\nSlowest checks (scan took {} ms)\n", report.duration_ms));
+    output.push_str(&"-".repeat(60));
+    output.push('\n');
+    for (ms, entry) in timed.iter().take(TIMINGS_MAX_ROWS) {
+        output.push_str(&format!("  {:>8} ms  {:>3}  {}\n", ms, entry.id.0, entry.description));
+    }
+    output
+}
+
+/// JSON Schema (draft-07) of the report `JsonReporter` emits, derived from
+/// the serde types so it cannot drift from the serialized shape.
+pub fn report_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(ScanReport)).expect("JSON Schema is always serializable")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,6 +535,8 @@ mod tests {
             summary: ScanSummary { total, passed, failed, skipped },
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
+            cache: None,
+            duration_ms: 0,
         }
     }
 
@@ -119,9 +548,10 @@ mod tests {
                 category: "structure".to_string(),
                 description: "docs/ exists".to_string(),
                 result: CheckResult::Pass,
+                duration_ms: None,
             },
         ]);
-        let text = TextReporter.report(&report);
+        let text = TextReporter::default().report(&report);
         assert!(text.contains("[PASS]"));
         assert!(!text.contains("[FAIL]"));
     }
@@ -134,6 +564,7 @@ mod tests {
                 category: "structure".to_string(),
                 description: "check pass".to_string(),
                 result: CheckResult::Pass,
+                duration_ms: None,
             },
             CheckEntry {
                 id: CheckId(2),
@@ -151,15 +582,17 @@ mod tests {
                         fix_hint: String::new(),
                     }],
                 },
+                duration_ms: None,
             },
             CheckEntry {
                 id: CheckId(3),
                 category: "structure".to_string(),
                 description: "check skip".to_string(),
                 result: CheckResult::Skip { reason: "not applicable".to_string() },
+                duration_ms: None,
             },
         ]);
-        let text = TextReporter.report(&report);
+        let text = TextReporter::default().report(&report);
         assert!(text.contains("[PASS]"));
         assert!(text.contains("[FAIL]"));
         assert!(text.contains("[SKIP]"));
@@ -175,18 +608,76 @@ mod tests {
                 category: "a".to_string(),
                 description: "d".to_string(),
                 result: CheckResult::Pass,
+                duration_ms: None,
             },
             CheckEntry {
                 id: CheckId(2),
                 category: "a".to_string(),
                 description: "d".to_string(),
                 result: CheckResult::Fail { violations: vec![] },
+                duration_ms: None,
             },
         ]);
-        let text = TextReporter.report(&report);
+        let text = TextReporter::default().report(&report);
         assert!(text.contains("1/2 passed, 1 failed, 0 skipped"));
     }
 
+    #[test]
+    fn test_text_quiet_keeps_only_failures() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "check pass".to_string(),
+                result: CheckResult::Pass,
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "naming".to_string(),
+                description: "check fail".to_string(),
+                result: CheckResult::Fail { violations: vec![] },
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(3),
+                category: "structure".to_string(),
+                description: "check skip".to_string(),
+                result: CheckResult::Skip { reason: "not applicable".to_string() },
+                duration_ms: None,
+            },
+        ]);
+        let text = TextReporter { quiet: true, color: false }.report(&report);
+        assert_eq!(text, "## naming\n  [FAIL] 2: check fail\n\n1/3 passed, 1 failed, 1 skipped\n");
+    }
+
+    #[test]
+    fn test_text_color_never_has_no_ansi_codes() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "a".to_string(),
+                description: "d".to_string(),
+                result: CheckResult::Pass,
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "a".to_string(),
+                description: "d".to_string(),
+                result: CheckResult::Fail { violations: vec![] },
+                duration_ms: None,
+            },
+        ]);
+        let plain = TextReporter { quiet: false, color: false }.report(&report);
+        assert!(!plain.contains('\x1b'));
+
+        let colored = TextReporter { quiet: false, color: true }.report(&report);
+        assert!(colored.contains("[\x1b[32mPASS\x1b[0m]"));
+        assert!(colored.contains("[\x1b[31mFAIL\x1b[0m]"));
+        assert_eq!(colored.replace("\x1b[32m", "").replace("\x1b[31m", "").replace("\x1b[0m", ""), plain);
+    }
+
     #[test]
     fn test_json_valid() {
         let report = make_report(vec![
@@ -195,6 +686,7 @@ mod tests {
                 category: "a".to_string(),
                 description: "d".to_string(),
                 result: CheckResult::Pass,
+                duration_ms: None,
             },
         ]);
         let json = JsonReporter.report(&report);
@@ -210,6 +702,7 @@ mod tests {
                 category: "structure".to_string(),
                 description: "docs/ exists".to_string(),
                 result: CheckResult::Pass,
+                duration_ms: None,
             },
             CheckEntry {
                 id: CheckId(2),
@@ -227,6 +720,7 @@ mod tests {
                         fix_hint: String::new(),
                     }],
                 },
+                duration_ms: None,
             },
         ]);
         let json = JsonReporter.report(&report);
@@ -236,4 +730,296 @@ mod tests {
         assert_eq!(deserialized.summary.passed, 1);
         assert_eq!(deserialized.summary.failed, 1);
     }
+
+    /// Assert every non-void element is closed in order.
+    fn assert_balanced_tags(html: &str) {
+        let mut stack: Vec<String> = Vec::new();
+        let mut rest = html;
+        while let Some(start) = rest.find('<') {
+            let end = rest[start..].find('>').expect("unterminated tag") + start;
+            let tag = &rest[start + 1..end];
+            rest = &rest[end + 1..];
+            if tag.starts_with('!') {
+                continue;
+            }
+            let name: String = tag.trim_start_matches('/')
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect();
+            if name == "meta" {
+                continue;
+            }
+            if tag.starts_with('/') {
+                assert_eq!(stack.pop().as_deref(), Some(name.as_str()), "unbalanced </{}>", name);
+            } else {
+                stack.push(name);
+            }
+        }
+        assert!(stack.is_empty(), "unclosed tags: {:?}", stack);
+    }
+
+    #[test]
+    fn test_html_escapes_messages_and_balances_tags() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "docs/ exists".to_string(),
+                result: CheckResult::Pass,
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "content".to_string(),
+                description: "no inline scripts".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![Violation {
+                        check_id: CheckId(2),
+                        path: Some("docs/a&b.md".into()),
+                        message: "found <script>alert('x')</script>".to_string(),
+                        severity: Severity::Error,
+                        rule_type: String::new(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: String::new(),
+                    }],
+                },
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(3),
+                category: "content".to_string(),
+                description: "skipped".to_string(),
+                result: CheckResult::Skip { reason: "not applicable".to_string() },
+                duration_ms: None,
+            },
+        ]);
+        let html = HtmlReporter.report(&report);
+
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("found &lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+        assert!(html.contains("docs/a&amp;b.md: "));
+        assert!(html.contains("1/3 passed, 1 failed, 1 skipped"));
+        assert!(html.contains("<details open>\n<summary>content (2 checks, 1 failed)</summary>"));
+        assert!(html.contains("<details>\n<summary>structure (1 checks, 0 failed)</summary>"));
+        assert!(html.contains("<li class=\"fail\">"));
+        assert!(!html.contains("<link") && !html.contains("src="));
+        assert_balanced_tags(&html);
+    }
+
+    #[test]
+    fn test_markdown_escapes_pipes_and_truncates_violations() {
+        let violations: Vec<Violation> = (0..8)
+            .map(|i| Violation {
+                check_id: CheckId(2),
+                path: None,
+                message: format!("row {} has a | b", i),
+                severity: Severity::Error,
+                rule_type: String::new(),
+                expected: None,
+                actual: None,
+                fix_hint: String::new(),
+            })
+            .collect();
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "docs/ exists".to_string(),
+                result: CheckResult::Pass,
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "content".to_string(),
+                description: "tables well-formed".to_string(),
+                result: CheckResult::Fail { violations },
+                duration_ms: None,
+            },
+        ]);
+        let md = MarkdownReporter.report(&report);
+
+        assert!(md.contains(":x: **1/2 passed**, 1 failed, 0 skipped"));
+        assert!(md.contains("### content\n\n| ID | Check | Status | Message |"));
+        assert!(md.contains("row 0 has a \\| b<br>"));
+        assert!(md.contains("row 4 has a \\| b<br>… and 3 more |"));
+        assert!(!md.contains("row 5"));
+        assert!(md.contains("| 1 | docs/ exists | :white_check_mark: |  |"));
+        // Every table row keeps exactly four cells
+        for line in md.lines().filter(|l| l.starts_with("| ")) {
+            assert_eq!(line.replace("\\|", "").matches('|').count(), 5, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_markdown_stays_under_comment_limit() {
+        let entries = (0..200u8)
+            .map(|i| CheckEntry {
+                id: CheckId(i),
+                category: "content".to_string(),
+                description: "x".repeat(400),
+                result: CheckResult::Skip { reason: "y".repeat(400) },
+                duration_ms: None,
+            })
+            .collect();
+        let md = MarkdownReporter.report(&make_report(entries));
+
+        assert!(md.len() <= MARKDOWN_MAX_BYTES + 100);
+        assert!(md.contains("more checks (output truncated)"));
+    }
+
+    #[test]
+    fn test_github_actions_workflow_commands() {
+        let violation = |path: Option<&str>, message: &str, severity: Severity| Violation {
+            check_id: CheckId(2),
+            path: path.map(Into::into),
+            message: message.to_string(),
+            severity,
+            rule_type: String::new(),
+            expected: None,
+            actual: None,
+            fix_hint: String::new(),
+        };
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "docs/ exists".to_string(),
+                result: CheckResult::Pass,
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "structure".to_string(),
+                description: "README exists".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![
+                        violation(Some("docs\\README.md"), "README.md is missing", Severity::Error),
+                        violation(None, "glossary is 50% complete\nadd terms", Severity::Warning),
+                    ],
+                },
+                duration_ms: None,
+            },
+        ]);
+        let output = GithubActionsReporter.report(&report);
+
+        assert_eq!(
+            output,
+            "::error file=docs/README.md::README.md is missing\n\
+             ::warning::glossary is 50%25 complete%0Aadd terms\n"
+        );
+    }
+
+    #[test]
+    fn test_timings_sorted_slowest_first() {
+        let timed = |id: u8, ms: Option<u64>| CheckEntry {
+            id: CheckId(id),
+            category: "structure".to_string(),
+            description: format!("check {}", id),
+            result: CheckResult::Pass,
+            duration_ms: ms,
+        };
+        let mut report = make_report(vec![timed(1, Some(3)), timed(2, None), timed(3, Some(40)), timed(4, Some(3))]);
+        report.duration_ms = 45;
+        let output = timings_text(&report);
+        assert!(output.contains("Slowest checks (scan took 45 ms)"));
+        let ids: Vec<&str> = output.lines().skip(3).map(|l| l.split_whitespace().nth(2).unwrap()).collect();
+        assert_eq!(ids, vec!["3", "1", "4"]);
+    }
+
+    #[test]
+    fn test_junit_counts_failures_skips_and_escapes() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "docs/ exists".to_string(),
+                result: CheckResult::Pass,
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "content".to_string(),
+                description: "glossary <terms>".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![Violation {
+                        check_id: CheckId(2),
+                        path: Some("docs\\glossary.md".into()),
+                        message: "term \"R&D\" is <undefined>".to_string(),
+                        severity: Severity::Warning,
+                        rule_type: String::new(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: String::new(),
+                    }],
+                },
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(3),
+                category: "adr".to_string(),
+                description: "adr index".to_string(),
+                result: CheckResult::Skip { reason: "no 'adr' directory".to_string() },
+                duration_ms: None,
+            },
+        ]);
+
+        let xml = JUnitReporter.report(&report);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains("<testsuite name=\"doc-engine\" tests=\"3\" failures=\"1\" skipped=\"1\">"));
+        assert!(xml.contains("<testcase classname=\"structure\" name=\"1: docs/ exists\"/>"));
+        assert!(xml.contains("<testcase classname=\"content\" name=\"2: glossary &lt;terms&gt;\">"));
+        assert!(xml.contains(
+            "<failure message=\"1 violation(s)\">docs/glossary.md: term &quot;R&amp;D&quot; is &lt;undefined&gt;</failure>"
+        ));
+        assert!(xml.contains("<skipped message=\"no &#39;adr&#39; directory\"/>"));
+        assert_eq!(xml.matches("<testcase").count(), 3);
+        assert!(xml.ends_with("  </testsuite>\n</testsuites>\n"));
+    }
+
+    #[test]
+    fn test_csv_quotes_commas_and_newlines() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "docs/ exists".to_string(),
+                result: CheckResult::Pass,
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "content".to_string(),
+                description: "glossary".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![Violation {
+                        check_id: CheckId(2),
+                        path: Some("docs\\glossary.md".into()),
+                        message: "term \"API\" undefined, see\nline two".to_string(),
+                        severity: Severity::Warning,
+                        rule_type: String::new(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: String::new(),
+                    }],
+                },
+                duration_ms: None,
+            },
+            CheckEntry {
+                id: CheckId(3),
+                category: "adr".to_string(),
+                description: "adr index".to_string(),
+                result: CheckResult::Skip { reason: "no ADR directory".to_string() },
+                duration_ms: None,
+            },
+        ]);
+
+        assert_eq!(
+            CsvReporter { include_skipped: false }.report(&report),
+            "check_id,category,severity,file,line,message\r\n\
+             2,content,warning,docs/glossary.md,,\"term \"\"API\"\" undefined, see\nline two\"\r\n"
+        );
+        let with_skipped = CsvReporter { include_skipped: true }.report(&report);
+        assert!(with_skipped.ends_with("3,adr,skipped,,,no ADR directory\r\n"));
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 use crate::api::traits::CheckRunner;
@@ -34,6 +36,10 @@ struct RawRule {
     depends_on: Option<Vec<u8>>,
     module_filter: Option<Vec<String>>,
     fix_hint: Option<String>,
+    threshold: Option<f64>,
+    topics: Option<Vec<String>>,
+    allowed_values: Option<Vec<String>>,
+    rationale: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -168,16 +174,68 @@ fn convert_raw_rule(raw: RawRule) -> Result<RuleDef, ScanError> {
         depends_on: raw.depends_on.unwrap_or_default(),
         module_filter: raw.module_filter,
         fix_hint: raw.fix_hint,
+        threshold: raw.threshold,
+        topics: raw.topics,
+        allowed_values: raw.allowed_values,
+        rationale: raw.rationale,
     })
 }
 
+/// Collect the comment block documenting each rule, keyed by check id.
+///
+/// A block belongs to rule N when it has a `# Check N: Title` line and is the
+/// last comment block before that rule's `[[rules]]` header; its text after the
+/// closing `# ===` rule line is the rationale; a title-only block yields none.
+/// Category banners such as `# Checks 1-13: Structure` never match.
+fn comment_rationales(toml_str: &str) -> HashMap<u8, String> {
+    let mut rationales = HashMap::new();
+    let mut block: Vec<&str> = Vec::new();
+    let mut pending: Option<Vec<&str>> = None;
+    let mut in_block = false;
+
+    for line in toml_str.lines() {
+        let trimmed = line.trim();
+        if let Some(comment) = trimmed.strip_prefix('#') {
+            if !in_block {
+                block.clear();
+                in_block = true;
+            }
+            block.push(comment.trim());
+            continue;
+        }
+        in_block = false;
+
+        if trimmed == "[[rules]]" {
+            pending = Some(std::mem::take(&mut block));
+        } else if let Some(id) = trimmed.strip_prefix("id").and_then(|r| r.trim_start().strip_prefix('=')) {
+            let (Some(lines), Ok(id)) = (pending.take(), id.trim().parse::<u8>()) else { continue };
+            let title = format!("Check {}:", id);
+            let Some(start) = lines.iter().position(|l| l.starts_with(&title)) else { continue };
+            let body: Vec<&str> = lines[start + 1..].iter()
+                .copied()
+                .skip_while(|l| l.starts_with('=') || l.is_empty())
+                .collect();
+            let text = body.join("\n").trim().to_string();
+            if !text.is_empty() {
+                rationales.insert(id, text);
+            }
+        }
+    }
+    rationales
+}
+
 pub fn parse_rules(toml_str: &str) -> Result<RuleSet, ScanError> {
     let raw: RawRuleFile = toml::from_str(toml_str)
         .map_err(|e| ScanError::Config(format!("TOML parse error: {}", e)))?;
 
+    let mut comments = comment_rationales(toml_str);
     let mut rules = Vec::with_capacity(raw.rules.len());
     for raw_rule in raw.rules {
-        rules.push(convert_raw_rule(raw_rule)?);
+        let mut rule = convert_raw_rule(raw_rule)?;
+        if rule.rationale.is_none() {
+            rule.rationale = comments.remove(&rule.id);
+        }
+        rules.push(rule);
     }
 
     // Validate depends_on references
@@ -463,6 +521,10 @@ path = "x"
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }];
         let reg = build_registry(&rules).unwrap();
         assert_eq!(reg.len(), 1);
@@ -482,6 +544,10 @@ path = "x"
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }];
         let reg = build_registry(&rules).unwrap();
         assert_eq!(reg.len(), 1);
@@ -501,6 +567,10 @@ path = "x"
             depends_on: vec![],
             module_filter: None,
             fix_hint: None,
+            threshold: None,
+            topics: None,
+            allowed_values: None,
+            rationale: None,
         }];
         let result = build_registry(&rules);
         assert!(result.is_err());
@@ -521,6 +591,10 @@ path = "x"
                 depends_on: vec![],
                 module_filter: None,
                 fix_hint: None,
+                threshold: None,
+                topics: None,
+                allowed_values: None,
+                rationale: None,
             },
             RuleDef {
                 id: 1,
@@ -533,6 +607,10 @@ path = "x"
                 depends_on: vec![],
                 module_filter: None,
                 fix_hint: None,
+                threshold: None,
+                topics: None,
+                allowed_values: None,
+                rationale: None,
             },
         ];
         let reg = build_registry(&rules).unwrap();
@@ -782,4 +860,145 @@ module_filter = []
         let rs = parse_rules(toml).unwrap();
         assert_eq!(rs.rules[0].module_filter, Some(vec![]));
     }
+
+    #[test]
+    fn test_parse_with_threshold() {
+        let toml = r#"
+[[rules]]
+id = 132
+category = "content"
+description = "test"
+severity = "warning"
+type = "builtin"
+handler = "glossary_completeness"
+scope = "medium"
+threshold = 0.25
+"#;
+        let rs = parse_rules(toml).unwrap();
+        assert_eq!(rs.rules[0].threshold, Some(0.25));
+    }
+
+    #[test]
+    fn test_parse_without_threshold() {
+        let toml = r#"
+[[rules]]
+id = 132
+category = "content"
+description = "test"
+severity = "warning"
+type = "builtin"
+handler = "glossary_completeness"
+scope = "medium"
+"#;
+        let rs = parse_rules(toml).unwrap();
+        assert_eq!(rs.rules[0].threshold, None);
+    }
+
+    #[test]
+    fn test_parse_with_topics() {
+        let toml = r#"
+[[rules]]
+id = 134
+category = "operations"
+description = "test"
+severity = "warning"
+type = "builtin"
+handler = "disposal_plan_data_handling"
+scope = "large"
+topics = ["legal hold", "secure deletion"]
+"#;
+        let rs = parse_rules(toml).unwrap();
+        assert_eq!(
+            rs.rules[0].topics,
+            Some(vec!["legal hold".to_string(), "secure deletion".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_with_allowed_values() {
+        let toml = r#"
+[[rules]]
+id = 136
+category = "requirements"
+description = "test"
+severity = "warning"
+type = "builtin"
+handler = "verification_method_valid"
+scope = "large"
+allowed_values = ["Test", "Simulation"]
+"#;
+        let rs = parse_rules(toml).unwrap();
+        assert_eq!(
+            rs.rules[0].allowed_values,
+            Some(vec!["Test".to_string(), "Simulation".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_rationale_from_comment_block() {
+        let toml = r#"
+# =============================================================================
+# Checks 1-2: Structure
+# =============================================================================
+
+[[rules]]
+id = 1
+category = "structure"
+description = "docs exists"
+severity = "error"
+type = "dir_exists"
+path = "docs"
+
+# =============================================================================
+# Check 2: Content — Hub (FR-100)
+# =============================================================================
+# The hub links every phase.
+# One violation per missing link.
+
+[[rules]]
+id = 2
+category = "content"
+description = "hub links phases"
+severity = "warning"
+type = "builtin"
+handler = "hub_links_phases"
+
+[[rules]]
+id = 3
+category = "content"
+description = "explicit"
+severity = "warning"
+type = "builtin"
+handler = "w3h_hub"
+rationale = "Set in the rule itself."
+"#;
+        let rs = parse_rules(toml).unwrap();
+        assert_eq!(rs.rules[0].rationale, None);
+        assert_eq!(
+            rs.rules[1].rationale.as_deref(),
+            Some("The hub links every phase.\nOne violation per missing link.")
+        );
+        assert_eq!(rs.rules[2].rationale.as_deref(), Some("Set in the rule itself."));
+    }
+
+    #[test]
+    fn test_every_builtin_handler_has_a_default_rule() {
+        let handler_re = regex::Regex::new(r#"^\s*"(\w+)" => Some\(Box::new"#).unwrap();
+        let registered: Vec<&str> = include_str!("builtins/mod.rs")
+            .lines()
+            .filter_map(|l| handler_re.captures(l).and_then(|c| c.get(1)).map(|m| m.as_str()))
+            .filter(|name| !["test_panic", "test_sleep"].contains(name))
+            .collect();
+        assert!(registered.len() > 50, "handler extraction found only {}", registered.len());
+
+        let rs = parse_rules(DEFAULT_RULES).unwrap();
+        let listed: std::collections::HashSet<String> = rs.rules.iter()
+            .filter_map(|r| match &r.rule_type {
+                RuleType::Builtin { handler } => Some(handler.clone()),
+                _ => None,
+            })
+            .collect();
+        let unlisted: Vec<&str> = registered.into_iter().filter(|h| !listed.contains(*h)).collect();
+        assert!(unlisted.is_empty(), "handlers without a rule in rules.toml: {:?}", unlisted);
+    }
 }
@@ -1,6 +1,7 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use walkdir::WalkDir;
 
 use crate::api::traits::FileScanner;
@@ -16,8 +17,9 @@ impl FileScanner for FileSystemScanner {
             .into_iter()
             .filter_entry(|e| {
                 let name = e.file_name().to_string_lossy();
-                // Skip hidden directories, target/, node_modules/
-                if e.file_type().is_dir()
+                // Skip hidden directories, target/, node_modules/ below the root
+                if e.depth() > 0
+                    && e.file_type().is_dir()
                     && (name.starts_with('.') || name == "target" || name == "node_modules")
                 {
                     return false;
@@ -50,6 +52,41 @@ impl FileScanner for FileSystemScanner {
     }
 }
 
+/// Drop files that match any of `patterns`, or that sit below a directory
+/// that does.
+///
+/// Patterns are matched against root-relative paths with `/` separators;
+/// `*` stays within one path component and `**` crosses components.
+pub fn exclude_files(files: Vec<PathBuf>, patterns: &[String]) -> Result<Vec<PathBuf>, ScanError> {
+    if patterns.is_empty() {
+        return Ok(files);
+    }
+    let set = build_glob_set(patterns)?;
+    Ok(files.into_iter()
+        .filter(|file| !is_excluded(&set, file))
+        .collect())
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, ScanError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern.trim_end_matches('/'))
+            .literal_separator(true)
+            .build()
+            .map_err(|e| ScanError::Config(format!("Invalid exclude glob '{}': {}", pattern, e)))?;
+        builder.add(glob);
+    }
+    builder.build()
+        .map_err(|e| ScanError::Config(format!("Invalid exclude globs: {}", e)))
+}
+
+fn is_excluded(set: &GlobSet, file: &Path) -> bool {
+    let normalized = PathBuf::from(file.to_string_lossy().replace('\\', "/"));
+    normalized.ancestors()
+        .filter(|p| !p.as_os_str().is_empty())
+        .any(|p| set.is_match(p))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +137,16 @@ mod tests {
         assert!(names.iter().any(|n| n == "visible.txt"));
     }
 
+    #[test]
+    fn test_scan_hidden_root() {
+        let tmp = tempfile::Builder::new().prefix(".hidden_").tempdir().unwrap();
+        fs::write(tmp.path().join("visible.txt"), "content").unwrap();
+
+        let scanner = FileSystemScanner;
+        let files = scanner.scan_files(tmp.path());
+        assert_eq!(names(&files), vec!["visible.txt"]);
+    }
+
     #[test]
     fn test_scan_skips_target() {
         let tmp = make_tmp();
@@ -163,4 +210,47 @@ mod tests {
         let scanner = FileSystemScanner;
         assert!(scanner.read_file(&tmp.path().join("nope.txt")).is_err());
     }
+
+    fn globs(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|p| p.to_string()).collect()
+    }
+
+    fn names(files: &[PathBuf]) -> Vec<String> {
+        files.iter().map(|f| f.to_string_lossy().replace('\\', "/")).collect()
+    }
+
+    #[test]
+    fn test_exclude_files_by_directory() {
+        let files = vec![
+            PathBuf::from("docs/README.md"),
+            PathBuf::from("docs/third_party/lib/guide.md"),
+            PathBuf::from("third_party.md"),
+        ];
+        let kept = exclude_files(files, &globs(&["docs/third_party"])).unwrap();
+        assert_eq!(names(&kept), vec!["docs/README.md", "third_party.md"]);
+    }
+
+    #[test]
+    fn test_exclude_files_star_stays_in_component() {
+        let files = vec![
+            PathBuf::from("docs/draft.md"),
+            PathBuf::from("docs/nested/draft.md"),
+        ];
+        let kept = exclude_files(files.clone(), &globs(&["docs/*.md"])).unwrap();
+        assert_eq!(names(&kept), vec!["docs/nested/draft.md"]);
+        let kept = exclude_files(files, &globs(&["**/draft.md"])).unwrap();
+        assert!(kept.is_empty());
+    }
+
+    #[test]
+    fn test_exclude_files_no_patterns_keeps_all() {
+        let files = vec![PathBuf::from("docs/README.md")];
+        assert_eq!(exclude_files(files.clone(), &[]).unwrap(), files);
+    }
+
+    #[test]
+    fn test_exclude_files_invalid_glob() {
+        let err = exclude_files(vec![], &globs(&["docs/[oops"])).unwrap_err();
+        assert!(err.to_string().contains("docs/[oops"), "{}", err);
+    }
 }
@@ -2,7 +2,20 @@ use std::path::PathBuf;
 
 use crate::api::traits::{ReportSink, Reporter};
 use crate::api::types::{ReportFormat, ScanError, ScanReport};
-use crate::core::reporter::{TextReporter, JsonReporter};
+use crate::core::reporter::{TextReporter, JsonReporter, HtmlReporter, MarkdownReporter, GithubActionsReporter, CsvReporter, JUnitReporter};
+
+/// Render a report in the given format.
+fn render(format: ReportFormat, report: &ScanReport) -> String {
+    match format {
+        ReportFormat::Text { quiet, color } => TextReporter { quiet, color }.report(report),
+        ReportFormat::Json => JsonReporter.report(report),
+        ReportFormat::Html => HtmlReporter.report(report),
+        ReportFormat::Markdown => MarkdownReporter.report(report),
+        ReportFormat::GithubActions => GithubActionsReporter.report(report),
+        ReportFormat::Csv { include_skipped } => CsvReporter { include_skipped }.report(report),
+        ReportFormat::JUnit => JUnitReporter.report(report),
+    }
+}
 
 /// Writes the formatted report to stdout.
 pub struct StdoutSink {
@@ -12,33 +25,35 @@ pub struct StdoutSink {
 
 impl ReportSink for StdoutSink {
     fn emit(&self, report: &ScanReport) -> Result<(), ScanError> {
-        let output = match self.format {
-            ReportFormat::Text => TextReporter.report(report),
-            ReportFormat::Json => JsonReporter.report(report),
-        };
-        print!("{}", output);
+        print!("{}", render(self.format, report));
         Ok(())
     }
 }
 
-/// Writes the report as pretty-printed JSON to a file.
+/// Writes the formatted report to a file.
 ///
 /// Creates parent directories if they do not exist.
 pub struct FileSink {
     /// The file path to write the report to.
     pub path: PathBuf,
+    /// The output format to use.
+    pub format: ReportFormat,
 }
 
 impl ReportSink for FileSink {
     fn emit(&self, report: &ScanReport) -> Result<(), ScanError> {
-        let json = serde_json::to_string_pretty(report)
-            .map_err(|e| ScanError::Config(format!("JSON serialization failed: {}", e)))?;
+        let output = match self.format {
+            // Fail loudly rather than writing the JsonReporter's inline error object
+            ReportFormat::Json => serde_json::to_string_pretty(report)
+                .map_err(|e| ScanError::Config(format!("JSON serialization failed: {}", e)))?,
+            format => render(format, report),
+        };
         if let Some(parent) = self.path.parent() {
             if !parent.exists() {
                 std::fs::create_dir_all(parent)?;
             }
         }
-        std::fs::write(&self.path, &json)?;
+        std::fs::write(&self.path, &output)?;
         Ok(())
     }
 }
@@ -61,6 +76,116 @@ impl ReportSink for KafkaSink {
     }
 }
 
+/// Schema for [`SqliteSink`]; mirrors the `projects` and `scans` tables of the
+/// ui server's `db.rs` so a local history file can later be imported there.
+#[cfg(feature = "sqlite")]
+const SQLITE_SCHEMA: &str = "CREATE TABLE IF NOT EXISTS projects (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        root_path TEXT NOT NULL,
+        scope TEXT NOT NULL DEFAULT 'Small',
+        project_type TEXT NOT NULL DEFAULT 'OpenSource',
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        deleted INTEGER NOT NULL DEFAULT 0,
+        last_scan_id TEXT
+    );
+
+    CREATE TABLE IF NOT EXISTS scans (
+        id TEXT PRIMARY KEY,
+        project_id TEXT NOT NULL REFERENCES projects(id),
+        engine TEXT NOT NULL,
+        status TEXT NOT NULL DEFAULT 'queued',
+        started_at TEXT NOT NULL,
+        finished_at TEXT,
+        report_json TEXT,
+        config_json TEXT
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_scans_project ON scans(project_id, started_at DESC);";
+
+/// Appends the report as a completed scan to a SQLite database file.
+///
+/// Creates the file and schema on first use. The scanned root becomes a
+/// `projects` row (reused on later scans of the same root) and each report a
+/// `scans` row whose `report_json` holds the summary and per-check results.
+#[cfg(feature = "sqlite")]
+pub struct SqliteSink {
+    /// The database file to append to.
+    pub path: PathBuf,
+}
+
+#[cfg(feature = "sqlite")]
+impl ReportSink for SqliteSink {
+    fn emit(&self, report: &ScanReport) -> Result<(), ScanError> {
+        use rusqlite::{params, Connection, OptionalExtension};
+
+        let sql_err = |e: rusqlite::Error| {
+            ScanError::Config(format!("SQLite error in '{}': {}", self.path.display(), e))
+        };
+        let json = serde_json::to_string(report)
+            .map_err(|e| ScanError::Config(format!("JSON serialization failed: {}", e)))?;
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let mut conn = Connection::open(&self.path).map_err(sql_err)?;
+        conn.execute_batch("PRAGMA foreign_keys=ON;").map_err(sql_err)?;
+        conn.execute_batch(SQLITE_SCHEMA).map_err(sql_err)?;
+
+        let tx = conn.transaction().map_err(sql_err)?;
+        let existing: Option<String> = tx
+            .query_row(
+                "SELECT id FROM projects WHERE root_path = ?1 AND deleted = 0",
+                params![report.project_root],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(sql_err)?;
+        let project_id = match existing {
+            Some(id) => id,
+            None => {
+                let id = uuid::Uuid::new_v4().to_string();
+                let name = std::path::Path::new(&report.project_root)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| report.project_root.clone());
+                tx.execute(
+                    "INSERT INTO projects (id, name, root_path, scope, project_type, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)",
+                    params![
+                        id,
+                        name,
+                        report.project_root,
+                        format!("{:?}", report.project_scope),
+                        format!("{:?}", report.project_type),
+                        report.timestamp,
+                    ],
+                )
+                .map_err(sql_err)?;
+                id
+            }
+        };
+
+        let scan_id = uuid::Uuid::new_v4().to_string();
+        tx.execute(
+            "INSERT INTO scans (id, project_id, engine, status, started_at, finished_at, report_json)
+             VALUES (?1, ?2, ?3, 'completed', ?4, ?4, ?5)",
+            params![scan_id, project_id, report.tool, report.timestamp, json],
+        )
+        .map_err(sql_err)?;
+        tx.execute(
+            "UPDATE projects SET last_scan_id = ?1, updated_at = ?2 WHERE id = ?3",
+            params![scan_id, report.timestamp, project_id],
+        )
+        .map_err(sql_err)?;
+        tx.commit().map_err(sql_err)?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +206,7 @@ mod tests {
                 category: "structure".to_string(),
                 description: "docs/ exists".to_string(),
                 result: CheckResult::Pass,
+                duration_ms: None,
             }],
             summary: ScanSummary {
                 total: 1,
@@ -90,12 +216,14 @@ mod tests {
             },
             project_type: ProjectType::OpenSource,
             project_scope: ProjectScope::Large,
+            cache: None,
+            duration_ms: 0,
         }
     }
 
     #[test]
     fn test_stdout_sink_text() {
-        let sink = StdoutSink { format: ReportFormat::Text };
+        let sink = StdoutSink { format: ReportFormat::Text { quiet: false, color: false } };
         let report = make_report();
         assert!(sink.emit(&report).is_ok());
     }
@@ -114,7 +242,7 @@ mod tests {
         std::fs::create_dir_all(&dir).unwrap();
         let path = dir.join("report.json");
 
-        let sink = FileSink { path: path.clone() };
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Json };
         let report = make_report();
         sink.emit(&report).unwrap();
 
@@ -131,7 +259,7 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
         let path = dir.join("a").join("b").join("report.json");
 
-        let sink = FileSink { path: path.clone() };
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Json };
         let report = make_report();
         sink.emit(&report).unwrap();
 
@@ -147,7 +275,7 @@ mod tests {
         std::fs::create_dir_all(&dir).unwrap();
         let path = dir.join("report.json");
 
-        let sink = FileSink { path: path.clone() };
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Json };
         let report = make_report();
         sink.emit(&report).unwrap();
 
@@ -160,6 +288,22 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn test_file_sink_text_format() {
+        let dir = std::env::temp_dir().join("doc_engine_sink_test_text");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("report.txt");
+
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Text { quiet: false, color: false } };
+        sink.emit(&make_report()).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("doc-engine scan results"));
+        assert!(serde_json::from_str::<serde_json::Value>(&contents).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[cfg(feature = "kafka")]
     #[test]
     fn test_kafka_sink_construction() {
@@ -187,4 +331,37 @@ mod tests {
         let result = sink.emit(&report);
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_sink_appends_scans_to_one_project() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("history").join("docs.sqlite");
+        let sink = super::SqliteSink { path: path.clone() };
+        let report = make_report();
+        sink.emit(&report).unwrap();
+        sink.emit(&report).unwrap();
+
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        let projects: i64 = conn.query_row("SELECT COUNT(*) FROM projects", [], |r| r.get(0)).unwrap();
+        assert_eq!(projects, 1);
+        let (scope, last_scan_id): (String, Option<String>) = conn
+            .query_row("SELECT scope, last_scan_id FROM projects", [], |r| Ok((r.get(0)?, r.get(1)?)))
+            .unwrap();
+        assert_eq!(scope, "Large");
+        assert!(last_scan_id.is_some());
+
+        let mut stmt = conn.prepare("SELECT engine, status, report_json FROM scans").unwrap();
+        let rows: Vec<(String, String, String)> = stmt
+            .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, "doc-engine");
+        assert_eq!(rows[0].1, "completed");
+        let stored: ScanReport = serde_json::from_str(&rows[0].2).unwrap();
+        assert_eq!(stored.summary.passed, 1);
+        assert_eq!(stored.results.len(), 1);
+    }
 }
@@ -2,18 +2,25 @@ use std::path::Path;
 
 use crate::api::traits::{ComplianceEngine, Reporter};
 use crate::core::engine::DocComplianceEngine;
-use crate::core::reporter::{TextReporter, JsonReporter};
+use crate::core::reporter::{TextReporter, JsonReporter, HtmlReporter, MarkdownReporter, GithubActionsReporter, CsvReporter, JUnitReporter, timings_text};
+use crate::core::reporter;
 
 // Re-export all public types from API
 pub use crate::api::types::{
-    CheckId, CheckResult, ProjectScope, ProjectType, Severity, Violation, ScanContext, ScanError,
-    ScanConfig, ScanReport, ScanSummary, CheckEntry, RuleSet, RuleDef, RuleType, ReportFormat,
+    CheckId, CheckResult, ProjectScope, ProjectType, Severity, Violation, ScanContext, ScanError, FileCache,
+    ScanConfig, ScanReport, ScanSummary, CacheStats, CheckEntry, RuleSet, RuleDef, RuleType, ReportFormat,
+    ReportDiff, CheckDiff, DiffStatus, DiffSummary, ProgressEvent,
 };
 
+// Re-export config file discovery and loading
+pub use crate::core::config_file::{ConfigFile, CONFIG_FILE_NAME};
+
 // Re-export sink implementations
 pub use crate::core::sink::{StdoutSink, FileSink};
 #[cfg(feature = "kafka")]
 pub use crate::core::sink::KafkaSink;
+#[cfg(feature = "sqlite")]
+pub use crate::core::sink::SqliteSink;
 #[cfg(feature = "kafka")]
 pub use swe_messaging::KafkaConfig;
 
@@ -23,6 +30,20 @@ pub use crate::core::engine::detect_project_type;
 // Re-export rule_count for test assertions
 pub use crate::core::rules::default_rule_count;
 
+/// Load the rule set a scan would use: the embedded `rules.toml`, or the
+/// custom rules file at `rules_path`.
+///
+/// Backs `doc-engine rules list` and `doc-engine rules explain`.
+pub fn load_rules(rules_path: Option<&Path>) -> Result<RuleSet, ScanError> {
+    let toml_str = match rules_path {
+        Some(path) => std::fs::read_to_string(path).map_err(|e| {
+            ScanError::Config(format!("Cannot read rules file '{}': {}", path.display(), e))
+        })?,
+        None => crate::core::rules::DEFAULT_RULES.to_string(),
+    };
+    crate::core::rules::parse_rules(&toml_str)
+}
+
 /// Scan a project directory with custom configuration.
 ///
 /// Runs every enabled compliance check against the files found under `root`
@@ -31,11 +52,78 @@ pub fn scan_with_config(root: &Path, config: &ScanConfig) -> Result<ScanReport,
     DocComplianceEngine.scan_with_config(root, config)
 }
 
+/// Scan a project directory, reporting progress as checks start and finish.
+///
+/// `on_event` is called synchronously on the calling thread: once with the
+/// total check count, then per check. [`scan_with_config`] is this with a
+/// no-op callback.
+pub fn scan_with_progress(
+    root: &Path,
+    config: &ScanConfig,
+    on_event: &mut dyn FnMut(ProgressEvent),
+) -> Result<ScanReport, ScanError> {
+    DocComplianceEngine.scan_with_progress(root, config, on_event)
+}
+
+/// Compare two scan reports check by check.
+///
+/// Classifies each check id as regressed, fixed, unchanged, added, or removed.
+pub fn diff_reports(old: &ScanReport, new: &ScanReport) -> ReportDiff {
+    crate::core::diff::diff_reports(old, new)
+}
+
 /// Format a scan report as human-readable text.
 ///
 /// Groups results by category and appends a pass/fail/skip summary line.
 pub fn format_report_text(report: &ScanReport) -> String {
-    TextReporter.report(report)
+    TextReporter::default().report(report)
+}
+
+/// Format the "slowest checks" table printed by `--timings`.
+///
+/// Lists the ten slowest checks that ran, with the total scan duration.
+pub fn format_timings(report: &ScanReport) -> String {
+    timings_text(report)
+}
+
+/// Format a scan report as a self-contained HTML page.
+///
+/// Categories render as collapsible sections under a summary banner; all
+/// report text is HTML-escaped and the stylesheet is inlined.
+pub fn format_report_html(report: &ScanReport) -> String {
+    HtmlReporter.report(report)
+}
+
+/// Format a scan report as GitHub-flavored Markdown for PR comments.
+///
+/// Renders one table per category; long violation lists and oversized
+/// reports are truncated to stay within GitHub's comment size limit.
+pub fn format_report_markdown(report: &ScanReport) -> String {
+    MarkdownReporter.report(report)
+}
+
+/// Format a scan report as GitHub Actions workflow commands.
+///
+/// Emits one `::error`, `::warning` or `::notice` line per violation, with
+/// `file=` set when the violation has a path.
+pub fn format_report_github(report: &ScanReport) -> String {
+    GithubActionsReporter.report(report)
+}
+
+/// Format a scan report as CSV.
+///
+/// Emits a `check_id,category,severity,file,line,message` header and one
+/// row per violation; `include_skipped` adds a `skipped` row per skipped check.
+pub fn format_report_csv(report: &ScanReport, include_skipped: bool) -> String {
+    CsvReporter { include_skipped }.report(report)
+}
+
+/// Format a scan report as JUnit XML.
+///
+/// Emits one `<testsuite>` carrying the summary counts, with one `<testcase>`
+/// per check: failures list their violations and skipped checks their reason.
+pub fn format_report_junit(report: &ScanReport) -> String {
+    JUnitReporter.report(report)
 }
 
 /// Format a scan report as JSON.
@@ -44,3 +132,11 @@ pub fn format_report_text(report: &ScanReport) -> String {
 pub fn format_report_json(report: &ScanReport) -> String {
     JsonReporter.report(report)
 }
+
+/// JSON Schema describing the output of [`format_report_json`].
+///
+/// Covers `ScanReport` and the `ScanSummary`, `CheckEntry` and `Violation`
+/// types it contains; printed by `doc-engine schema`.
+pub fn report_schema() -> serde_json::Value {
+    reporter::report_schema()
+}
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use super::types::{CheckId, CheckResult, ScanConfig, ScanContext, ScanError, ScanReport};
+use super::types::{CheckEntry, CheckId, CheckResult, ProgressEvent, ScanConfig, ScanContext, ScanError, ScanReport};
 
 /// Abstracts file system traversal for project scanning.
 ///
@@ -51,6 +51,20 @@ pub trait ReportSink {
     fn emit(&self, report: &ScanReport) -> Result<(), ScanError>;
 }
 
+/// Receives scan results while the scan runs, for output that should not
+/// wait for the whole report (e.g. JSON Lines on a very large workspace).
+///
+/// The streaming counterpart of [`ReportSink`]. Entries arrive from worker
+/// threads in completion order, not check-id order.
+pub trait StreamingReportSink: Send + Sync {
+    /// Emit one finished check. `member` is the workspace member path for
+    /// recursive scans, `None` for the scanned root.
+    fn emit_entry(&self, member: Option<&str>, entry: &CheckEntry) -> Result<(), ScanError>;
+
+    /// Emit the closing summary once every entry has been emitted.
+    fn finish(&self, report: &ScanReport) -> Result<(), ScanError>;
+}
+
 /// Engine for running Rust package structure compliance scans.
 ///
 /// Implementors walk a project directory, execute compliance checks, and
@@ -58,6 +72,17 @@ pub trait ReportSink {
 pub trait ComplianceEngine {
     /// Scan a project directory using the default [`ScanConfig`].
     fn scan(&self, root: &Path) -> Result<ScanReport, ScanError>;
+    /// Scan a project directory, reporting each check's start and finish to
+    /// `on_event` as the scan proceeds.
+    fn scan_with_progress(
+        &self,
+        root: &Path,
+        config: &ScanConfig,
+        on_event: &mut dyn FnMut(ProgressEvent),
+    ) -> Result<ScanReport, ScanError>;
+
     /// Scan a project directory with the supplied [`ScanConfig`].
-    fn scan_with_config(&self, root: &Path, config: &ScanConfig) -> Result<ScanReport, ScanError>;
+    fn scan_with_config(&self, root: &Path, config: &ScanConfig) -> Result<ScanReport, ScanError> {
+        self.scan_with_progress(root, config, &mut |_| {})
+    }
 }
@@ -3,6 +3,7 @@ use std::fmt;
 use std::io;
 use std::path::PathBuf;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -10,7 +11,7 @@ use serde::{Deserialize, Serialize};
 // ---------------------------------------------------------------------------
 
 /// Newtype wrapping check number 1-44.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct CheckId(pub u8);
 
 impl fmt::Display for CheckId {
@@ -26,10 +27,42 @@ pub enum ReportFormat {
     Text,
     /// Pretty-printed JSON (serde_json).
     Json,
+    /// JUnit XML, one test case per check, for CI test-report viewers.
+    Junit,
+    /// SARIF 2.1.0, one result per violation, for code-scanning annotations.
+    Sarif {
+        /// Also emit a `pass` result for each passing check.
+        include_passing: bool,
+    },
+    /// JSON Lines: one object per check entry, then a summary line.
+    JsonLines,
+    /// GitHub Actions workflow commands, one annotation per violation.
+    GithubActions,
+    /// RFC 4180 CSV, one row per violation.
+    Csv {
+        /// Also emit a `skipped` row for each skipped check.
+        include_skipped: bool,
+    },
+}
+
+impl ReportFormat {
+    /// Parse a CLI format name (`text`, `json`, `jsonl`, `junit`, `sarif`, `github`, `csv`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(ReportFormat::Text),
+            "json" => Some(ReportFormat::Json),
+            "jsonl" => Some(ReportFormat::JsonLines),
+            "junit" => Some(ReportFormat::Junit),
+            "sarif" => Some(ReportFormat::Sarif { include_passing: false }),
+            "github" => Some(ReportFormat::GithubActions),
+            "csv" => Some(ReportFormat::Csv { include_skipped: false }),
+            _ => None,
+        }
+    }
 }
 
 /// Severity level of a check violation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     /// A blocking error that must be fixed before release.
@@ -40,8 +73,41 @@ pub enum Severity {
     Info,
 }
 
+impl Severity {
+    /// Parse a CLI severity name (`info`, `warning`, `error`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "info" => Some(Severity::Info),
+            "warning" => Some(Severity::Warning),
+            "error" => Some(Severity::Error),
+            _ => None,
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Severity::Info => 0,
+            Severity::Warning => 1,
+            Severity::Error => 2,
+        }
+    }
+}
+
+/// Severities are ordered `Info < Warning < Error`.
+impl Ord for Severity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// A single violation found by a check.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Violation {
     /// The check that produced this violation.
     pub check_id: CheckId,
@@ -63,10 +129,29 @@ pub struct Violation {
     /// Actionable remediation hint.
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub fix_hint: String,
+    /// Set when the violation matches a baseline entry; suppressed violations
+    /// are still reported but do not fail their check.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub suppressed: bool,
+}
+
+/// A known violation recorded in a baseline file.
+///
+/// A violation matches an entry when its check id, path and message are all
+/// equal; paths are compared with `/` separators.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// The check that produced the violation.
+    pub check_id: CheckId,
+    /// The violation's file path, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// The violation message.
+    pub message: String,
 }
 
 /// Outcome of running a single check.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "status", rename_all = "lowercase")]
 pub enum CheckResult {
     /// The check passed with no violations.
@@ -113,7 +198,7 @@ impl From<io::Error> for ScanError {
 }
 
 /// The kind of Rust project detected from Cargo.toml.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectKind {
     /// A library crate (has `[lib]` only).
@@ -187,6 +272,9 @@ pub struct ScanContext {
     pub project_kind: ProjectKind,
     /// Parsed Cargo.toml manifest, if available.
     pub cargo_manifest: Option<CargoManifest>,
+    /// Parsed manifests of the `[workspace] members`, keyed by member path
+    /// relative to `root`; empty when the root declares no workspace.
+    pub member_manifests: Vec<(String, CargoManifest)>,
 }
 
 impl ScanContext {
@@ -212,6 +300,10 @@ pub struct ScanConfig {
     pub rules_path: Option<PathBuf>,
     /// Recursively scan workspace members.
     pub recursive: bool,
+    /// Include the declared dependency inventory in the report.
+    pub with_deps: bool,
+    /// Optional baseline JSON file; matching violations are marked suppressed.
+    pub baseline_path: Option<PathBuf>,
 }
 
 impl Default for ScanConfig {
@@ -221,12 +313,14 @@ impl Default for ScanConfig {
             checks: None,
             rules_path: None,
             recursive: false,
+            with_deps: false,
+            baseline_path: None,
         }
     }
 }
 
 /// Enriched check entry with metadata.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CheckEntry {
     /// Unique numeric identifier for the check.
     pub id: CheckId,
@@ -239,7 +333,7 @@ pub struct CheckEntry {
 }
 
 /// Summary of scan results.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScanSummary {
     /// Total number of checks executed.
     pub total: u8,
@@ -249,10 +343,42 @@ pub struct ScanSummary {
     pub failed: u8,
     /// Number of checks that were skipped.
     pub skipped: u8,
+    /// Number of checks that failed only on baselined violations; these are
+    /// not counted in `failed`.
+    #[serde(default)]
+    pub suppressed: u8,
+}
+
+/// Scan progress reported to the callback of
+/// [`scan_with_progress`](crate::scan_with_progress), on the calling thread.
+#[derive(Debug, Clone)]
+pub enum ProgressEvent {
+    /// Checks have been selected; `total` of them will report `CheckFinished`.
+    /// Recursive scans send one per workspace member as well as the root.
+    ScanStarted {
+        /// Number of checks selected for this scan.
+        total: usize,
+    },
+    /// A check is about to run. Not sent for checks skipped by project kind.
+    CheckStarted {
+        /// The check that is starting.
+        id: CheckId,
+        /// Human-readable description of the check.
+        description: String,
+    },
+    /// A check's result is final, with baselined violations already suppressed.
+    CheckFinished {
+        /// The check that finished.
+        id: CheckId,
+        /// Human-readable description of the check.
+        description: String,
+        /// The final outcome of the check.
+        result: CheckResult,
+    },
 }
 
 /// Complete scan report.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScanReport {
     /// Per-check results in execution order.
     pub results: Vec<CheckEntry>,
@@ -263,10 +389,31 @@ pub struct ScanReport {
     /// Per-member reports for workspace recursive scans.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub member_reports: Vec<MemberReport>,
+    /// Declared dependencies from Cargo.toml; populated only when
+    /// `ScanConfig::with_deps` is set. Informational, not a check.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<DepInfo>,
+}
+
+impl ScanReport {
+    /// Highest severity among the violations of failed checks, ignoring
+    /// baselined violations. `None` when no unsuppressed violation exists.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.results
+            .iter()
+            .filter_map(|entry| match &entry.result {
+                CheckResult::Fail { violations } => Some(violations),
+                _ => None,
+            })
+            .flatten()
+            .filter(|v| !v.suppressed)
+            .map(|v| v.severity.clone())
+            .max()
+    }
 }
 
 /// Report for a single workspace member.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MemberReport {
     /// Workspace member name (relative path).
     pub member: String,
@@ -276,6 +423,57 @@ pub struct MemberReport {
     pub summary: ScanSummary,
     /// The project kind detected for this member.
     pub project_kind: ProjectKind,
+    /// Declared dependencies of this member (with `ScanConfig::with_deps`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<DepInfo>,
+}
+
+/// Result of [`scan_workspace`](crate::scan_workspace): the workspace root
+/// and every member scanned separately, with a combined summary.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceReport {
+    /// Rollup across the root and all members, counted once per check id:
+    /// a check fails if it fails anywhere, passes if it passes somewhere and
+    /// fails nowhere, and is skipped only if every scope skipped it.
+    pub summary: ScanSummary,
+    /// Scan of the workspace root. For a virtual workspace this covers only
+    /// workspace-level checks, since there is no root package.
+    pub root: ScanReport,
+    /// `(member path, report)` in `[workspace] members` order, globs expanded.
+    pub members: Vec<(String, ScanReport)>,
+    /// The root manifest has `[workspace]` but no `[package]`.
+    pub virtual_workspace: bool,
+    /// Member paths resolving outside the workspace directory; not scanned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_members: Vec<String>,
+}
+
+impl WorkspaceReport {
+    /// Highest unsuppressed violation severity across the root and members.
+    pub fn max_severity(&self) -> Option<Severity> {
+        std::iter::once(&self.root)
+            .chain(self.members.iter().map(|(_, r)| r))
+            .filter_map(ScanReport::max_severity)
+            .max()
+    }
+
+    /// Flatten into a single [`ScanReport`] shaped like a `--recursive` scan:
+    /// root results and summary, with each member as a [`MemberReport`].
+    pub fn to_scan_report(&self) -> ScanReport {
+        let mut report = self.root.clone();
+        report.member_reports = self
+            .members
+            .iter()
+            .map(|(member, r)| MemberReport {
+                member: member.clone(),
+                results: r.results.clone(),
+                summary: r.summary.clone(),
+                project_kind: r.project_kind.clone(),
+                dependencies: r.dependencies.clone(),
+            })
+            .collect();
+        report
+    }
 }
 
 /// Parsed rule set from TOML.
@@ -302,6 +500,11 @@ pub struct RuleDef {
     pub project_kind: Option<ProjectKind>,
     /// Optional custom fix hint from TOML; overrides auto-generated hint.
     pub fix_hint: Option<String>,
+    /// Builtin-specific parameters from the rule's `params` table (e.g. `max_depth`).
+    pub params: toml::Table,
+    /// Longer explanation shown by `rules explain`, from the TOML `rationale` key
+    /// or the `(Check N)` comment block above the rule.
+    pub rationale: Option<String>,
 }
 
 /// The type of a rule -- declarative or builtin.
@@ -390,6 +593,24 @@ pub enum RuleType {
 }
 
 impl RuleType {
+    /// Return the path or glob a declarative rule inspects; `None` for builtins,
+    /// whose inputs are chosen by the handler.
+    pub fn target(&self) -> Option<&str> {
+        match self {
+            RuleType::FileExists { path }
+            | RuleType::DirExists { path }
+            | RuleType::DirNotExists { path, .. }
+            | RuleType::FileContentMatches { path, .. }
+            | RuleType::FileContentNotMatches { path, .. } => Some(path),
+            RuleType::GlobContentMatches { glob, .. }
+            | RuleType::GlobContentNotMatches { glob, .. }
+            | RuleType::GlobNamingMatches { glob, .. }
+            | RuleType::GlobNamingNotMatches { glob, .. } => Some(glob),
+            RuleType::CargoKeyExists { .. } | RuleType::CargoKeyMatches { .. } => Some("Cargo.toml"),
+            RuleType::Builtin { .. } => None,
+        }
+    }
+
     /// Return a stable machine-readable tag for this rule type.
     pub fn to_tag(&self) -> String {
         match self {
@@ -455,6 +676,10 @@ pub struct CargoManifest {
     pub has_lib: bool,
     /// The `[lib].path` value if set.
     pub lib_path: Option<String>,
+    /// Whether `[lib]` declares `proc-macro = true` (or a `proc-macro` crate type).
+    pub proc_macro: bool,
+    /// The `[lib].crate-type` values if set.
+    pub lib_crate_types: Vec<String>,
     /// Binary targets from `[[bin]]`.
     pub bins: Vec<BinTarget>,
     /// Test targets from `[[test]]`.
@@ -469,6 +694,9 @@ pub struct CargoManifest {
     pub edition: Option<String>,
     /// Workspace member paths from `[workspace] members = [...]`.
     pub workspace_members: Vec<String>,
+    /// Declared dependencies from `[dependencies]`, `[dev-dependencies]`
+    /// and `[build-dependencies]`.
+    pub dependencies: Vec<DepInfo>,
 }
 
 /// A binary target from `[[bin]]`.
@@ -506,3 +734,43 @@ pub struct ExampleTarget {
     /// Source path.
     pub path: Option<String>,
 }
+
+/// Dependency table a declared dependency comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DepKind {
+    /// `[dependencies]`
+    Normal,
+    /// `[dev-dependencies]`
+    Dev,
+    /// `[build-dependencies]`
+    Build,
+}
+
+/// Where a declared dependency is fetched from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DepSource {
+    /// A registry such as crates.io, selected by `version`.
+    Registry,
+    /// A local crate declared with `path`.
+    Path,
+    /// A repository declared with `git`.
+    Git,
+    /// Inherited from the workspace with `workspace = true`.
+    Workspace,
+}
+
+/// A dependency declared in Cargo.toml.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DepInfo {
+    /// Dependency key (the name used in the manifest).
+    pub name: String,
+    /// Version requirement, if any. `None` for path/git dependencies without
+    /// a version and for `workspace = true` entries.
+    pub version_req: Option<String>,
+    /// Which dependency table declared it.
+    pub kind: DepKind,
+    /// Where the dependency comes from.
+    pub source: DepSource,
+}
@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::api::traits::CheckRunner;
-use crate::api::types::{RuleDef, CheckId, CheckResult, ScanContext, Violation};
+use crate::api::types::{CargoManifest, RuleDef, CheckId, CheckResult, DepKind, DepSource, ScanContext, Violation};
 
 fn make_violation(
     def: &RuleDef,
@@ -22,6 +22,7 @@ fn make_violation(
         fix_hint: fix_hint.map(String::from)
             .unwrap_or_else(|| def.fix_hint.clone()
                 .unwrap_or_else(|| def.rule_type.auto_fix_hint())),
+        suppressed: false,
     }
 }
 
@@ -508,3 +509,157 @@ impl CheckRunner for TestPathsResolve {
         }
     }
 }
+
+/// Check 55: dependencies carry a version requirement other than `*`.
+pub struct NoWildcardDeps {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for NoWildcardDeps {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let manifest = match &ctx.cargo_manifest {
+            Some(m) => m,
+            None => {
+                return CheckResult::Skip {
+                    reason: "No Cargo.toml found".to_string(),
+                };
+            }
+        };
+
+        // Git and path dependencies are pinned by their source; workspace
+        // entries are checked where the workspace declares them
+        let violations: Vec<Violation> = manifest.dependencies.iter()
+            .filter(|dep| dep.source == DepSource::Registry)
+            .filter_map(|dep| {
+                let req = dep.version_req.as_deref().map(str::trim).unwrap_or("");
+                if !req.is_empty() && req != "*" {
+                    return None;
+                }
+                let table = match dep.kind {
+                    DepKind::Normal => "dependencies",
+                    DepKind::Dev => "dev-dependencies",
+                    DepKind::Build => "build-dependencies",
+                };
+                let (problem, actual) = if req.is_empty() {
+                    ("has no version requirement", "missing")
+                } else {
+                    ("uses the wildcard version requirement \"*\"", "*")
+                };
+                Some(make_violation(
+                    &self.def,
+                    Some(Path::new("Cargo.toml")),
+                    &format!("Dependency '{}' in [{}] {}", dep.name, table, problem),
+                    Some("a bounded version requirement such as \"1.2\""),
+                    Some(actual),
+                    Some(&format!("Set '{}' to the version you build and test against, e.g. {} = \"1.2\"", dep.name, dep.name)),
+                ))
+            })
+            .collect();
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Edition Cargo assumes when `package.edition` is absent.
+const DEFAULT_EDITION: &str = "2015";
+
+/// Effective edition of `manifest`, resolving `edition.workspace = true`
+/// against the root's `[workspace.package]`. `None` if inheritance cannot be
+/// resolved or the value is not a string.
+fn resolved_edition(manifest: &CargoManifest, root: Option<&toml::Value>) -> Option<String> {
+    if let Some(ref edition) = manifest.edition {
+        return Some(edition.clone());
+    }
+    let value = manifest.raw.as_ref()
+        .and_then(|raw| raw.get("package"))
+        .and_then(|p| p.get("edition"));
+    match value {
+        None => Some(DEFAULT_EDITION.to_string()),
+        Some(v) if v.get("workspace").and_then(|w| w.as_bool()) == Some(true) => root
+            .and_then(|r| r.get("workspace"))
+            .and_then(|w| w.get("package"))
+            .and_then(|p| p.get("edition"))
+            .and_then(|e| e.as_str())
+            .map(String::from),
+        Some(_) => None,
+    }
+}
+
+/// Check 57: every workspace member (and a root package) uses the same edition.
+pub struct WorkspaceConsistentEdition {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for WorkspaceConsistentEdition {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let root = match &ctx.cargo_manifest {
+            Some(m) if m.has_workspace => m,
+            _ => {
+                return CheckResult::Skip {
+                    reason: "No [workspace] in Cargo.toml".to_string(),
+                };
+            }
+        };
+        if ctx.member_manifests.is_empty() {
+            return CheckResult::Skip {
+                reason: "No workspace members found".to_string(),
+            };
+        }
+
+        // A root with [package] is itself a member; unresolvable editions are ignored
+        let root_package = root.package_name.as_ref().map(|_| (".".to_string(), root));
+        let editions: Vec<(&str, String)> = root_package.iter()
+            .map(|(name, m)| (name.as_str(), *m))
+            .chain(ctx.member_manifests.iter().map(|(name, m)| (name.as_str(), m)))
+            .filter_map(|(name, m)| resolved_edition(m, root.raw.as_ref()).map(|e| (name, e)))
+            .collect();
+
+        // Majority edition; ties go to the newer edition
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+        for (_, edition) in &editions {
+            match counts.iter_mut().find(|(e, _)| e == edition) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((edition.as_str(), 1)),
+            }
+        }
+        if counts.len() <= 1 {
+            return CheckResult::Pass;
+        }
+        let majority = counts.iter().max_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(b.0))).unwrap().0;
+
+        let outliers: Vec<String> = editions.iter()
+            .filter(|(_, e)| e != majority)
+            .map(|(name, e)| format!("{} ({})", name, e))
+            .collect();
+
+        CheckResult::Fail {
+            violations: vec![make_violation(
+                &self.def,
+                Some(Path::new("Cargo.toml")),
+                &format!(
+                    "Workspace members use different editions; most use {}, outliers: {}",
+                    majority,
+                    outliers.join(", ")
+                ),
+                Some(&format!("edition {} in every member", majority)),
+                Some(&outliers.join(", ")),
+                Some(&format!(
+                    "Set [workspace.package] edition = \"{}\" and edition.workspace = true in each member",
+                    majority
+                )),
+            )],
+        }
+    }
+}
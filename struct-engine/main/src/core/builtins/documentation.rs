@@ -22,6 +22,7 @@ fn make_violation(
         fix_hint: fix_hint.map(String::from)
             .unwrap_or_else(|| def.fix_hint.clone()
                 .unwrap_or_else(|| def.rule_type.auto_fix_hint())),
+        suppressed: false,
     }
 }
 
@@ -97,3 +98,68 @@ impl CheckRunner for ExamplesDirLib {
         }
     }
 }
+
+/// Opt-in check: every example under `examples/` opens with a `//!` or `//`
+/// comment block explaining what it demonstrates.
+///
+/// Examples are `examples/*.rs` files and the `main.rs` of multi-file examples
+/// (`examples/<name>/main.rs`), matching Cargo's auto-discovery.
+pub struct ExamplesDocumented {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for ExamplesDocumented {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let examples: Vec<&std::path::PathBuf> = ctx.files().iter()
+            .filter(|f| {
+                let s = f.to_string_lossy().replace('\\', "/");
+                match s.strip_prefix("examples/") {
+                    Some(rest) => rest.ends_with(".rs")
+                        && (!rest.contains('/') || (rest.matches('/').count() == 1 && rest.ends_with("/main.rs"))),
+                    None => false,
+                }
+            })
+            .collect();
+
+        if examples.is_empty() {
+            return CheckResult::Skip {
+                reason: "No example files found in examples/".to_string(),
+            };
+        }
+
+        let mut violations = Vec::new();
+        for file in examples {
+            let full = ctx.root.join(file);
+            let content = match std::fs::read_to_string(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let documented = content.lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .is_some_and(|line| line.starts_with("//"));
+            if !documented {
+                let s = file.to_string_lossy().replace('\\', "/");
+                violations.push(make_violation(
+                    &self.def,
+                    Some(file),
+                    &format!("Example '{}' has no leading comment explaining what it demonstrates", s),
+                    Some("leading //! or // comment block"),
+                    Some("no leading comment"),
+                    Some("Start the example with a //! comment block describing what it demonstrates"),
+                ));
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
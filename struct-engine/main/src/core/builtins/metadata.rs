@@ -1,5 +1,273 @@
 // Metadata checks.
-// All metadata checks (9-18) are handled declaratively via cargo_key_exists
+// Most metadata checks (9-18) are handled declaratively via cargo_key_exists
 // and cargo_key_matches rule types, except for check 13 (license_field_exists)
-// which is in cargo_toml.rs.
-// This module is a placeholder for future metadata-specific builtins.
+// which is in cargo_toml.rs. This module holds metadata builtins that judge
+// field values rather than presence (checks 50-51).
+
+use std::path::Path;
+
+use crate::api::traits::CheckRunner;
+use crate::api::types::{RuleDef, CheckId, CheckResult, ScanContext, Violation};
+
+/// Default `min_length` for `description_quality`, in characters.
+const DEFAULT_MIN_DESCRIPTION_LENGTH: usize = 20;
+
+/// Default `placeholders` for `description_quality`, compared case-insensitively.
+const DEFAULT_DESCRIPTION_PLACEHOLDERS: &[&str] = &["TODO", "TBD", "FIXME", "A Rust crate"];
+
+fn make_violation(
+    def: &RuleDef,
+    path: Option<&Path>,
+    message: &str,
+    expected: Option<&str>,
+    actual: Option<&str>,
+    fix_hint: Option<&str>,
+) -> Violation {
+    Violation {
+        check_id: CheckId(def.id),
+        path: path.map(|p| p.to_path_buf()),
+        message: message.to_string(),
+        severity: def.severity.clone(),
+        rule_type: def.rule_type.to_tag(),
+        expected: expected.map(String::from),
+        actual: actual.map(String::from),
+        fix_hint: fix_hint.map(String::from)
+            .unwrap_or_else(|| def.fix_hint.clone()
+                .unwrap_or_else(|| def.rule_type.auto_fix_hint())),
+        suppressed: false,
+    }
+}
+
+/// Opt-in check: `package.description` is present and says something about the crate.
+///
+/// Fails when the description is missing, shorter than `min_length` characters
+/// (default 20), equal to the package name, or equal to one of `placeholders`
+/// (default `TODO`, `TBD`, `FIXME`, `A Rust crate`; case-insensitive, trailing
+/// period ignored). Crates with `publish = false` are skipped.
+pub struct DescriptionQuality {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for DescriptionQuality {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let min_length = match self.def.params.get("min_length") {
+            None => DEFAULT_MIN_DESCRIPTION_LENGTH,
+            Some(v) => match v.as_integer().and_then(|n| usize::try_from(n).ok()) {
+                Some(n) => n,
+                None => {
+                    return CheckResult::Skip {
+                        reason: format!("Invalid min_length '{}': expected a non-negative integer", v),
+                    };
+                }
+            },
+        };
+        let placeholders: Vec<&str> = match self.def.params.get("placeholders") {
+            None => DEFAULT_DESCRIPTION_PLACEHOLDERS.to_vec(),
+            Some(v) => match v.as_array() {
+                Some(items) => items.iter().filter_map(|p| p.as_str()).collect(),
+                None => {
+                    return CheckResult::Skip {
+                        reason: format!("Invalid placeholders '{}': expected an array of strings", v),
+                    };
+                }
+            },
+        };
+
+        let manifest = match &ctx.cargo_manifest {
+            Some(m) => m,
+            None => {
+                return CheckResult::Skip {
+                    reason: "No Cargo.toml found".to_string(),
+                };
+            }
+        };
+        let raw = match &manifest.raw {
+            Some(r) => r,
+            None => {
+                return CheckResult::Skip {
+                    reason: "Cargo.toml not parsed".to_string(),
+                };
+            }
+        };
+        let package = match raw.get("package") {
+            Some(p) => p,
+            None => {
+                return CheckResult::Skip {
+                    reason: "No [package] section in Cargo.toml".to_string(),
+                };
+            }
+        };
+        if !is_publishable(package) {
+            return CheckResult::Skip {
+                reason: "Crate is not publishable (package.publish)".to_string(),
+            };
+        }
+
+        let description = match package.get("description") {
+            None => None,
+            Some(toml::Value::String(s)) => Some(s.as_str()),
+            // `description.workspace = true`: use the root's [workspace.package] value
+            Some(v) if v.get("workspace").and_then(|w| w.as_bool()) == Some(true) => {
+                match raw.get("workspace")
+                    .and_then(|w| w.get("package"))
+                    .and_then(|p| p.get("description"))
+                    .and_then(|d| d.as_str())
+                {
+                    Some(s) => Some(s),
+                    None => {
+                        return CheckResult::Skip {
+                            reason: "package.description is inherited from the workspace".to_string(),
+                        };
+                    }
+                }
+            }
+            Some(v) => {
+                return CheckResult::Skip {
+                    reason: format!("Invalid package.description '{}': expected a string", v),
+                };
+            }
+        };
+
+        let expected = format!("at least {} characters, not a placeholder", min_length);
+        let fail = |message: String, actual: &str| CheckResult::Fail {
+            violations: vec![make_violation(
+                &self.def,
+                Some(Path::new("Cargo.toml")),
+                &message,
+                Some(&expected),
+                Some(actual),
+                Some("Describe what the crate does in one sentence, as shown on crates.io"),
+            )],
+        };
+
+        let description = match description {
+            Some(d) => d.trim(),
+            None => return fail("package.description is missing".to_string(), "missing"),
+        };
+        let actual = format!("'{}'", description);
+        let normalized = description.trim_end_matches('.').trim();
+
+        if let Some(name) = manifest.package_name.as_deref() {
+            if normalized.eq_ignore_ascii_case(name) {
+                return fail(format!("package.description {} only repeats the crate name", actual), &actual);
+            }
+        }
+        if placeholders.iter().any(|p| normalized.eq_ignore_ascii_case(p.trim_end_matches('.').trim())) {
+            return fail(format!("package.description {} is a placeholder", actual), &actual);
+        }
+        let length = description.chars().count();
+        if length < min_length {
+            return fail(
+                format!("package.description {} is {} characters; at least {} expected", actual, length, min_length),
+                &actual,
+            );
+        }
+
+        CheckResult::Pass
+    }
+}
+
+/// Whether `[package]` allows publishing: `publish = false` and `publish = []` opt out.
+fn is_publishable(package: &toml::Value) -> bool {
+    match package.get("publish") {
+        Some(toml::Value::Boolean(b)) => *b,
+        Some(toml::Value::Array(registries)) => !registries.is_empty(),
+        _ => true,
+    }
+}
+
+/// Check 51: `package.rust-version` is declared and is a valid version.
+///
+/// Cargo accepts `MAJOR.MINOR` or `MAJOR.MINOR.PATCH` with numeric components
+/// and no pre-release or build suffix. `rust-version.workspace = true` is
+/// resolved against `[workspace.package]` when the root manifest is scanned;
+/// otherwise the inheritance itself counts as a declaration. Workspace-only
+/// manifests without `[package]` are skipped.
+pub struct MsrvDeclared {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for MsrvDeclared {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let raw = match ctx.cargo_manifest.as_ref().and_then(|m| m.raw.as_ref()) {
+            Some(r) => r,
+            None => {
+                return CheckResult::Skip {
+                    reason: "No Cargo.toml found".to_string(),
+                };
+            }
+        };
+        let package = match raw.get("package") {
+            Some(p) => p,
+            None => {
+                return CheckResult::Skip {
+                    reason: "No [package] section in Cargo.toml".to_string(),
+                };
+            }
+        };
+
+        let fail = |message: &str, actual: &str| CheckResult::Fail {
+            violations: vec![make_violation(
+                &self.def,
+                Some(Path::new("Cargo.toml")),
+                message,
+                Some("MAJOR.MINOR or MAJOR.MINOR.PATCH"),
+                Some(actual),
+                Some("Add rust-version = \"1.70\" (the oldest toolchain you test against) to [package]"),
+            )],
+        };
+
+        let version = match package.get("rust-version") {
+            None => return fail("package.rust-version is missing", "missing"),
+            Some(toml::Value::String(s)) => s.as_str(),
+            Some(v) if v.get("workspace").and_then(|w| w.as_bool()) == Some(true) => {
+                match raw.get("workspace")
+                    .and_then(|w| w.get("package"))
+                    .and_then(|p| p.get("rust-version"))
+                {
+                    Some(toml::Value::String(s)) => s.as_str(),
+                    Some(v) => {
+                        let actual = v.to_string();
+                        return fail(
+                            &format!("workspace.package.rust-version {} is not a string", actual),
+                            &actual,
+                        );
+                    }
+                    // Inherited from a workspace root outside this manifest
+                    None => return CheckResult::Pass,
+                }
+            }
+            Some(v) => {
+                let actual = v.to_string();
+                return fail(&format!("package.rust-version {} is not a string", actual), &actual);
+            }
+        };
+
+        if is_valid_rust_version(version) {
+            CheckResult::Pass
+        } else {
+            let actual = format!("'{}'", version);
+            fail(&format!("package.rust-version {} is not a valid version", actual), &actual)
+        }
+    }
+}
+
+/// Whether `version` has the form Cargo accepts for `rust-version`:
+/// two or three dot-separated numeric components without leading zeros.
+fn is_valid_rust_version(version: &str) -> bool {
+    let parts: Vec<&str> = version.split('.').collect();
+    (2..=3).contains(&parts.len())
+        && parts.iter().all(|p| {
+            !p.is_empty()
+                && p.bytes().all(|b| b.is_ascii_digit())
+                && (p.len() == 1 || !p.starts_with('0'))
+        })
+}
@@ -25,6 +25,16 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
         "no_undeclared_benches" => Some(Box::new(cargo_toml::NoUndeclaredBenches { def: def.clone() })),
         "example_targets_if_dir" => Some(Box::new(cargo_toml::ExampleTargetsIfDir { def: def.clone() })),
         "test_paths_resolve" => Some(Box::new(cargo_toml::TestPathsResolve { def: def.clone() })),
+        "no_wildcard_deps" => Some(Box::new(cargo_toml::NoWildcardDeps { def: def.clone() })),
+        "workspace_consistent_edition" => Some(Box::new(cargo_toml::WorkspaceConsistentEdition { def: def.clone() })),
+
+        // Source layout handlers
+        "max_module_depth" => Some(Box::new(source_layout::MaxModuleDepth { def: def.clone() })),
+        "macro_density" => Some(Box::new(source_layout::MacroDensity { def: def.clone() })),
+        "unsafe_safety_comments" => Some(Box::new(source_layout::UnsafeSafetyComments { def: def.clone() })),
+        "proc_macro_crate" => Some(Box::new(source_layout::ProcMacroCrate { def: def.clone() })),
+        "forbid_unsafe_or_exception" => Some(Box::new(source_layout::ForbidUnsafeOrException { def: def.clone() })),
+        "no_orphan_modules" => Some(Box::new(source_layout::NoOrphanModules { def: def.clone() })),
 
         // Test organization handlers
         "test_file_suffixes" => Some(Box::new(test_org::TestFileSuffixes { def: def.clone() })),
@@ -33,6 +43,11 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
         "int_tests_location" => Some(Box::new(test_org::IntTestsLocation { def: def.clone() })),
         "unit_tests_colocated" => Some(Box::new(test_org::UnitTestsColocated { def: def.clone() })),
         "no_test_in_src" => Some(Box::new(test_org::NoTestInSrc { def: def.clone() })),
+        "test_modules_private" => Some(Box::new(test_org::TestModulesPrivate { def: def.clone() })),
+
+        // Metadata handlers
+        "description_quality" => Some(Box::new(metadata::DescriptionQuality { def: def.clone() })),
+        "msrv_declared" => Some(Box::new(metadata::MsrvDeclared { def: def.clone() })),
 
         // Naming handlers
         "module_names_match" => Some(Box::new(naming::ModuleNamesMatch { def: def.clone() })),
@@ -41,6 +56,7 @@ pub fn get_handler(name: &str, def: &RuleDef) -> Option<Box<dyn CheckRunner>> {
         // Documentation handlers
         "doc_dir_exists" => Some(Box::new(documentation::DocDirExists { def: def.clone() })),
         "examples_dir_lib" => Some(Box::new(documentation::ExamplesDirLib { def: def.clone() })),
+        "examples_documented" => Some(Box::new(documentation::ExamplesDocumented { def: def.clone() })),
 
         _ => None,
     }
@@ -24,6 +24,7 @@ fn make_violation(
         fix_hint: fix_hint.map(String::from)
             .unwrap_or_else(|| def.fix_hint.clone()
                 .unwrap_or_else(|| def.rule_type.auto_fix_hint())),
+        suppressed: false,
     }
 }
 
@@ -1,4 +1,1016 @@
 // Source layout checks.
-// All source layout checks (1, 2, 4, 6, 7) are handled declaratively
+// Most source layout checks (1, 2, 4, 6, 7) are handled declaratively
 // via dir_exists, dir_not_exists, and file_exists rule types.
-// This module is a placeholder for future layout-specific builtins.
+// This module holds layout-specific builtins.
+
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+
+use regex::Regex;
+
+use crate::api::traits::CheckRunner;
+use crate::api::types::{RuleDef, CheckId, CheckResult, ProjectKind, ScanContext, Violation};
+
+/// Default `max_depth` for `max_module_depth` when the rule sets no params.
+const DEFAULT_MAX_MODULE_DEPTH: usize = 5;
+
+/// Default `max_ratio` for `macro_density`: flag files more than half macro.
+const DEFAULT_MAX_MACRO_RATIO: f64 = 0.5;
+
+/// Default `max_files` for `macro_density`: how many offenders to report.
+const DEFAULT_MAX_MACRO_FILES: usize = 10;
+
+/// Default `min_lines` for `macro_density`: smaller files are too short to judge.
+const DEFAULT_MIN_MACRO_LINES: usize = 10;
+
+/// Default `justification_path` for `forbid_unsafe_or_exception`.
+const DEFAULT_UNSAFE_JUSTIFICATION: &str = "docs/unsafe-justification.md";
+
+fn make_violation(
+    def: &RuleDef,
+    path: Option<&Path>,
+    message: &str,
+    expected: Option<&str>,
+    actual: Option<&str>,
+    fix_hint: Option<&str>,
+) -> Violation {
+    Violation {
+        check_id: CheckId(def.id),
+        path: path.map(|p| p.to_path_buf()),
+        message: message.to_string(),
+        severity: def.severity.clone(),
+        rule_type: def.rule_type.to_tag(),
+        expected: expected.map(String::from),
+        actual: actual.map(String::from),
+        fix_hint: fix_hint.map(String::from)
+            .unwrap_or_else(|| def.fix_hint.clone()
+                .unwrap_or_else(|| def.rule_type.auto_fix_hint())),
+        suppressed: false,
+    }
+}
+
+/// Opt-in check: no `.rs` file is nested more than `max_depth` directories below
+/// `src/` (or `main/src/`).
+///
+/// Params: `max_depth` (default 5) and `exclude_paths`, a list of path prefixes
+/// (e.g. test fixtures) skipped with the same semantics as `glob_naming_not_matches`.
+pub struct MaxModuleDepth {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for MaxModuleDepth {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let max_depth = match self.def.params.get("max_depth") {
+            None => DEFAULT_MAX_MODULE_DEPTH,
+            Some(v) => match v.as_integer().and_then(|n| usize::try_from(n).ok()) {
+                Some(n) => n,
+                None => {
+                    return CheckResult::Skip {
+                        reason: format!("Invalid max_depth '{}': expected a non-negative integer", v),
+                    };
+                }
+            },
+        };
+        let exclude_paths: Vec<&str> = self.def.params.get("exclude_paths")
+            .and_then(|v| v.as_array())
+            .map(|paths| paths.iter().filter_map(|p| p.as_str()).collect())
+            .unwrap_or_default();
+
+        let mut violations = Vec::new();
+
+        for file in ctx.files() {
+            let s = file.to_string_lossy().replace('\\', "/");
+            if !s.ends_with(".rs") || exclude_paths.iter().any(|prefix| s.starts_with(prefix)) {
+                continue;
+            }
+            let rel = match s.strip_prefix("main/src/").or_else(|| s.strip_prefix("src/")) {
+                Some(rel) => rel,
+                None => continue,
+            };
+
+            // Directories between the source root and the file
+            let depth = rel.matches('/').count();
+            if depth > max_depth {
+                violations.push(make_violation(
+                    &self.def,
+                    Some(file),
+                    &format!(
+                        "'{}' is nested {} directories deep (max {})",
+                        s, depth, max_depth
+                    ),
+                    Some(&format!("depth <= {}", max_depth)),
+                    Some(&format!("depth {}", depth)),
+                    Some("Flatten the module tree or move the file closer to the crate root"),
+                ));
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Opt-in check: flag source files whose code is mostly macro invocations, where
+/// the other structure checks see little of the real structure.
+///
+/// The ratio is the share of non-blank, non-comment lines that fall inside a
+/// macro invocation (`name!(..)`, `name![..]`, `name! {..}`). Delimiters are
+/// matched without lexing string literals, so the ratio is an estimate.
+///
+/// Params: `max_ratio` (default 0.5), `max_files` (default 10) capping how many
+/// of the densest files are reported, `min_lines` (default 10) below which a
+/// file is not judged, and `exclude_paths` as for `max_module_depth`.
+pub struct MacroDensity {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for MacroDensity {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let max_ratio = match self.def.params.get("max_ratio") {
+            None => DEFAULT_MAX_MACRO_RATIO,
+            Some(v) => match v.as_float().or_else(|| v.as_integer().map(|n| n as f64)) {
+                Some(r) if (0.0..=1.0).contains(&r) => r,
+                _ => {
+                    return CheckResult::Skip {
+                        reason: format!("Invalid max_ratio '{}': expected a number between 0 and 1", v),
+                    };
+                }
+            },
+        };
+        let max_files = match self.def.params.get("max_files") {
+            None => DEFAULT_MAX_MACRO_FILES,
+            Some(v) => match v.as_integer().and_then(|n| usize::try_from(n).ok()) {
+                Some(n) if n > 0 => n,
+                _ => {
+                    return CheckResult::Skip {
+                        reason: format!("Invalid max_files '{}': expected a positive integer", v),
+                    };
+                }
+            },
+        };
+        let min_lines = match self.def.params.get("min_lines") {
+            None => DEFAULT_MIN_MACRO_LINES,
+            Some(v) => match v.as_integer().and_then(|n| usize::try_from(n).ok()) {
+                Some(n) => n,
+                None => {
+                    return CheckResult::Skip {
+                        reason: format!("Invalid min_lines '{}': expected a non-negative integer", v),
+                    };
+                }
+            },
+        };
+        let exclude_paths: Vec<&str> = self.def.params.get("exclude_paths")
+            .and_then(|v| v.as_array())
+            .map(|paths| paths.iter().filter_map(|p| p.as_str()).collect())
+            .unwrap_or_default();
+
+        // Start of a macro invocation: `name!(`, `name![` or `name! {`
+        let macro_call_re = Regex::new(r"\b[A-Za-z_][A-Za-z0-9_]*!\s*[(\[{]").unwrap();
+        let mut offenders: Vec<(&Path, String, usize, usize, f64)> = Vec::new();
+
+        for file in ctx.files() {
+            let s = file.to_string_lossy().replace('\\', "/");
+            if !s.ends_with(".rs") || exclude_paths.iter().any(|prefix| s.starts_with(prefix)) {
+                continue;
+            }
+            if !(s.starts_with("main/src/") || s.starts_with("src/")) {
+                continue;
+            }
+            let full = ctx.root.join(file);
+            let content = match std::fs::read_to_string(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            let (macro_lines, code_lines) = macro_line_counts(&content, &macro_call_re);
+            if code_lines == 0 || code_lines < min_lines {
+                continue;
+            }
+            let ratio = macro_lines as f64 / code_lines as f64;
+            if ratio > max_ratio {
+                offenders.push((file.as_path(), s, macro_lines, code_lines, ratio));
+            }
+        }
+
+        if offenders.is_empty() {
+            return CheckResult::Pass;
+        }
+
+        // Densest first; ties keep file order
+        offenders.sort_by(|a, b| b.4.total_cmp(&a.4));
+        let violations = offenders.iter().take(max_files).map(|(file, s, macro_lines, code_lines, ratio)| {
+            make_violation(
+                &self.def,
+                Some(*file),
+                &format!(
+                    "'{}' is {:.0}% macro invocations ({} of {} code lines); structure checks are less meaningful here",
+                    s, ratio * 100.0, macro_lines, code_lines
+                ),
+                Some(&format!("ratio <= {:.2}", max_ratio)),
+                Some(&format!("ratio {:.2}", ratio)),
+                Some("Review this file by hand, or move logic out of macro bodies into plain functions"),
+            )
+        }).collect();
+
+        CheckResult::Fail { violations }
+    }
+}
+
+/// Count `(lines inside macro invocations, non-blank non-comment lines)` in `content`.
+fn macro_line_counts(content: &str, macro_call_re: &Regex) -> (usize, usize) {
+    let mut depth = 0usize;
+    let mut macro_lines = 0;
+    let mut code_lines = 0;
+
+    for line in content.lines() {
+        let code = line.split("//").next().unwrap_or("").trim();
+        if code.is_empty() {
+            continue;
+        }
+        code_lines += 1;
+
+        let mut in_macro = depth > 0;
+        let mut rest = code;
+        loop {
+            if depth == 0 {
+                match macro_call_re.find(rest) {
+                    // Continue from the opening delimiter, the match's last char
+                    Some(m) => {
+                        in_macro = true;
+                        rest = &rest[m.end() - 1..];
+                    }
+                    None => break,
+                }
+            }
+            let mut closed_at = None;
+            for (i, c) in rest.char_indices() {
+                match c {
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => {
+                        depth = depth.saturating_sub(1);
+                        if depth == 0 {
+                            closed_at = Some(i + 1);
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            match closed_at {
+                Some(end) => rest = &rest[end..],
+                None => break,
+            }
+        }
+
+        if in_macro {
+            macro_lines += 1;
+        }
+    }
+
+    (macro_lines, code_lines)
+}
+
+/// Opt-in check: every `unsafe` block, impl or function definition under `src/`
+/// carries a `SAFETY:` comment on the same line or in the comment lines directly
+/// above it (attribute lines in between are skipped).
+///
+/// Function and trait declarations may use a `# Safety` doc section instead.
+/// Bodiless `unsafe fn` signatures (trait methods, extern items), `unsafe fn(..)`
+/// pointer types and `#[unsafe(..)]` attributes are not flagged. Sources are
+/// tokenized, so `unsafe` inside strings, char literals and comments is ignored.
+///
+/// Params: `exclude_paths` as for `max_module_depth`.
+pub struct UnsafeSafetyComments {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for UnsafeSafetyComments {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let exclude_paths: Vec<&str> = self.def.params.get("exclude_paths")
+            .and_then(|v| v.as_array())
+            .map(|paths| paths.iter().filter_map(|p| p.as_str()).collect())
+            .unwrap_or_default();
+
+        let mut violations = Vec::new();
+
+        for file in ctx.files() {
+            let s = file.to_string_lossy().replace('\\', "/");
+            if !s.ends_with(".rs") || exclude_paths.iter().any(|prefix| s.starts_with(prefix)) {
+                continue;
+            }
+            if !(s.starts_with("main/src/") || s.starts_with("src/")) {
+                continue;
+            }
+            let full = ctx.root.join(file);
+            let content = match std::fs::read_to_string(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            for (line_no, kind) in undocumented_unsafe(&content) {
+                violations.push(make_violation(
+                    &self.def,
+                    Some(file),
+                    &format!("unsafe {} without a SAFETY: comment at {}:{}", kind, s, line_no),
+                    Some("// SAFETY: comment on or directly above the unsafe code"),
+                    Some(&format!("{}:{}", s, line_no)),
+                    Some("Add a // SAFETY: comment explaining why the invariants hold"),
+                ));
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Attributes that define a procedural macro.
+const PROC_MACRO_ATTRS: &[&str] = &["proc_macro", "proc_macro_derive", "proc_macro_attribute"];
+
+/// Check 53: crates that define procedural macros declare `[lib] proc-macro = true`,
+/// are not also built as a normal library, and export nothing but macros.
+///
+/// A crate is a proc-macro crate when `[lib]` sets `proc-macro = true` (or a
+/// `proc-macro` crate type) or its library source carries `#[proc_macro]`,
+/// `#[proc_macro_derive]` or `#[proc_macro_attribute]`; other crates are skipped.
+/// The library source is `[lib].path`, else `main/src/lib.rs` or `src/lib.rs`.
+pub struct ProcMacroCrate {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for ProcMacroCrate {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let manifest = match &ctx.cargo_manifest {
+            Some(m) => m,
+            None => return CheckResult::Skip { reason: "No Cargo.toml found".to_string() },
+        };
+
+        let lib_file = match &manifest.lib_path {
+            Some(path) => Some(path.clone()),
+            None => ["main/src/lib.rs", "src/lib.rs"].iter()
+                .find(|p| ctx.root.join(p).is_file())
+                .map(|p| p.to_string()),
+        };
+        let scan = lib_file.as_ref()
+            .and_then(|f| std::fs::read_to_string(ctx.root.join(f)).ok())
+            .map(|content| scan_proc_macro_source(&content))
+            .unwrap_or_default();
+
+        if !manifest.proc_macro && scan.macro_line.is_none() {
+            return CheckResult::Skip { reason: "Not a proc-macro crate".to_string() };
+        }
+
+        let cargo_path = Path::new("Cargo.toml");
+        let mut violations = Vec::new();
+
+        if let (false, Some(line), Some(file)) = (manifest.proc_macro, scan.macro_line, &lib_file) {
+            violations.push(make_violation(
+                &self.def,
+                Some(cargo_path),
+                &format!("{}:{} defines a procedural macro but [lib] does not declare proc-macro = true", file, line),
+                Some("[lib] proc-macro = true"),
+                Some("no proc-macro lib flag"),
+                Some("Add `proc-macro = true` under [lib] in Cargo.toml"),
+            ));
+        }
+
+        let normal_types: Vec<&str> = manifest.lib_crate_types.iter()
+            .map(|t| t.as_str())
+            .filter(|t| *t != "proc-macro")
+            .collect();
+        if manifest.proc_macro && !normal_types.is_empty() {
+            violations.push(make_violation(
+                &self.def,
+                Some(cargo_path),
+                &format!("Proc-macro crate is also declared as a normal library: crate-type = {:?}", normal_types),
+                Some("crate-type = [\"proc-macro\"] or no crate-type"),
+                Some(&format!("{:?}", manifest.lib_crate_types)),
+                Some("Remove the non-proc-macro entries from [lib] crate-type"),
+            ));
+        }
+
+        if let Some(file) = &lib_file {
+            for (line, kind, name) in &scan.exports {
+                violations.push(make_violation(
+                    &self.def,
+                    Some(Path::new(file)),
+                    &format!("public {} `{}` in proc-macro crate at {}:{}", kind, name, file, line),
+                    Some("only #[proc_macro*] functions are public"),
+                    Some(&format!("pub {} {}", kind, name)),
+                    Some("Move non-macro public API into a separate library crate"),
+                ));
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Library crates either forbid unsafe code at the crate root
+/// (`#![forbid(unsafe_code)]` or `#![deny(unsafe_code)]`) or document why
+/// they cannot in a justification file.
+///
+/// Binaries and workspace roots are skipped, as are crates without a library
+/// source. Params: `justification_path` (default
+/// `docs/unsafe-justification.md`), relative to the crate root.
+pub struct ForbidUnsafeOrException {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for ForbidUnsafeOrException {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        if ctx.project_kind != ProjectKind::Library && ctx.project_kind != ProjectKind::Both {
+            return CheckResult::Skip {
+                reason: "Only applicable to library projects".to_string(),
+            };
+        }
+
+        let lib_file = ctx.cargo_manifest.as_ref()
+            .and_then(|m| m.lib_path.clone())
+            .or_else(|| ["main/src/lib.rs", "src/lib.rs"].iter()
+                .find(|p| ctx.root.join(p).is_file())
+                .map(|p| p.to_string()));
+        let content = match lib_file.as_ref().and_then(|f| std::fs::read_to_string(ctx.root.join(f)).ok()) {
+            Some(content) => content,
+            None => return CheckResult::Skip { reason: "No library crate root found".to_string() },
+        };
+
+        let forbid_re = Regex::new(r"(?m)^\s*#!\[\s*(forbid|deny)\s*\(([^)]*,)?\s*unsafe_code\s*[,)]").unwrap();
+        if forbid_re.is_match(&content) {
+            return CheckResult::Pass;
+        }
+
+        let justification = self.def.params.get("justification_path")
+            .and_then(|v| v.as_str())
+            .unwrap_or(DEFAULT_UNSAFE_JUSTIFICATION);
+        if ctx.root.join(justification).is_file() {
+            return CheckResult::Pass;
+        }
+
+        let lib_file = lib_file.unwrap_or_default();
+        CheckResult::Fail {
+            violations: vec![make_violation(
+                &self.def,
+                Some(Path::new(&lib_file)),
+                &format!(
+                    "{} neither forbids unsafe code nor has a justification in {}",
+                    lib_file, justification
+                ),
+                Some("#![forbid(unsafe_code)] or #![deny(unsafe_code)]"),
+                Some("no unsafe_code lint attribute"),
+                Some(&format!(
+                    "Add `#![forbid(unsafe_code)]` to {}, or explain the unsafe code in {}",
+                    lib_file, justification
+                )),
+            )],
+        }
+    }
+}
+
+/// Check 56: every `.rs` file under `src/` (or `main/src/`) is reachable from a
+/// crate root through `mod` declarations.
+///
+/// Crate roots are the library (`[lib].path`, else `lib.rs`), declared `[[bin]]`
+/// paths, `main.rs` and auto-discovered `src/bin/*.rs` / `src/bin/*/main.rs`.
+/// `mod foo;` resolves to `foo.rs` or `foo/mod.rs` next to the declaring module,
+/// honouring inline `mod bar { .. }` nesting and `#[path = ".."]`. Attributes
+/// are otherwise ignored, so `#[cfg(..)]`-gated modules count as reachable.
+/// Params: `exclude_paths`, path prefixes that are never reported.
+pub struct NoOrphanModules {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for NoOrphanModules {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        let exclude_paths: Vec<&str> = self.def.params.get("exclude_paths")
+            .and_then(|v| v.as_array())
+            .map(|paths| paths.iter().filter_map(|p| p.as_str()).collect())
+            .unwrap_or_default();
+
+        let sources: Vec<String> = ctx.files().iter()
+            .map(|f| f.to_string_lossy().replace('\\', "/"))
+            .filter(|s| s.ends_with(".rs") && (s.starts_with("main/src/") || s.starts_with("src/")))
+            .collect();
+        let existing: HashSet<&str> = sources.iter().map(String::as_str).collect();
+
+        let mut roots: Vec<String> = Vec::new();
+        if let Some(manifest) = &ctx.cargo_manifest {
+            roots.extend(manifest.lib_path.iter().cloned());
+            roots.extend(manifest.bins.iter().filter_map(|b| b.path.clone()));
+        }
+        for dir in ["main/src", "src"] {
+            roots.push(format!("{}/lib.rs", dir));
+            roots.push(format!("{}/main.rs", dir));
+        }
+        roots.extend(sources.iter()
+            .filter(|s| {
+                let bin = s.strip_prefix("main/src/bin/").or_else(|| s.strip_prefix("src/bin/"));
+                bin.is_some_and(|rest| !rest.contains('/') || (rest.matches('/').count() == 1 && rest.ends_with("/main.rs")))
+            })
+            .cloned());
+        let roots: Vec<String> = roots.into_iter()
+            .map(|r| normalize_path(Path::new(&r)))
+            .filter(|r| existing.contains(r.as_str()))
+            .collect();
+
+        if roots.is_empty() {
+            return CheckResult::Skip { reason: "No crate root found under src/".to_string() };
+        }
+
+        // Walk the module tree; each entry is (file, whether it owns its directory)
+        let mut reachable: HashSet<String> = HashSet::new();
+        let mut queue: Vec<(String, bool)> = roots.into_iter().map(|r| (r, true)).collect();
+        while let Some((file, owns_dir)) = queue.pop() {
+            if !reachable.insert(file.clone()) {
+                continue;
+            }
+            let content = match std::fs::read_to_string(ctx.root.join(&file)) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            let path = Path::new(&file);
+            let dir = path.parent().unwrap_or(Path::new(""));
+            let owns_dir = owns_dir || path.file_name().is_some_and(|n| n == "mod.rs");
+            let module_dir = if owns_dir {
+                dir.to_path_buf()
+            } else {
+                dir.join(path.file_stem().unwrap_or_default())
+            };
+
+            for decl in mod_declarations(&content) {
+                let nested: PathBuf = decl.inline.iter().collect();
+                let candidates: Vec<(PathBuf, bool)> = match &decl.path_attr {
+                    Some(p) if decl.inline.is_empty() => vec![(dir.join(p), true)],
+                    Some(p) => vec![(module_dir.join(&nested).join(p), true)],
+                    None => {
+                        let base = module_dir.join(&nested);
+                        vec![
+                            (base.join(format!("{}.rs", decl.name)), false),
+                            (base.join(&decl.name).join("mod.rs"), true),
+                        ]
+                    }
+                };
+                for (candidate, owns) in candidates {
+                    let candidate = normalize_path(&candidate);
+                    if existing.contains(candidate.as_str()) {
+                        queue.push((candidate, owns));
+                    }
+                }
+            }
+        }
+
+        let violations: Vec<Violation> = sources.iter()
+            .filter(|s| !reachable.contains(*s))
+            .filter(|s| !exclude_paths.iter().any(|prefix| s.starts_with(prefix)))
+            .map(|s| make_violation(
+                &self.def,
+                Some(Path::new(s)),
+                &format!("'{}' is not reachable from any crate root through `mod` declarations", s),
+                Some("declared with `mod` in a parent module"),
+                Some("orphaned"),
+                Some("Declare the module with `mod` in its parent, or delete the file if it is dead code"),
+            ))
+            .collect();
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
+
+/// Resolve `.` and `..` lexically and join with `/`.
+fn normalize_path(path: &Path) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => { parts.pop(); }
+            other => parts.push(other.as_os_str().to_string_lossy().into_owned()),
+        }
+    }
+    parts.join("/")
+}
+
+/// An out-of-line `mod name;` declaration.
+struct ModDecl {
+    /// Names of the inline `mod x { .. }` blocks enclosing the declaration.
+    inline: Vec<String>,
+    name: String,
+    /// Value of a `#[path = ".."]` attribute on the declaration.
+    path_attr: Option<String>,
+}
+
+fn mod_declarations(content: &str) -> Vec<ModDecl> {
+    let tokens = lex_rust(content).tokens;
+    let lines: Vec<&str> = content.lines().collect();
+    let path_re = Regex::new(r#"#\s*\[\s*path\s*=\s*"([^"]*)"\s*\]"#).unwrap();
+    let ident_at = |k: usize| match tokens.get(k) {
+        Some((_, Token::Ident(n))) => Some(n.as_str()),
+        _ => None,
+    };
+    let punct_at = |k: usize, p: char| matches!(tokens.get(k), Some((_, Token::Punct(c))) if *c == p);
+
+    let mut decls = Vec::new();
+    // Enclosing inline modules with the brace depth inside them
+    let mut inline: Vec<(String, i32)> = Vec::new();
+    let mut depth = 0i32;
+    let mut path_attr: Option<String> = None;
+
+    for (k, (line, token)) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('#') if punct_at(k + 1, '[') && ident_at(k + 2) == Some("path") && punct_at(k + 3, '=') => {
+                path_attr = lines.get(*line)
+                    .and_then(|l| path_re.captures(l))
+                    .map(|c| c[1].to_string());
+            }
+            Token::Ident(kw) if kw == "mod" => {
+                let Some(name) = ident_at(k + 1) else { continue };
+                let name = name.trim_start_matches("r#").to_string();
+                if punct_at(k + 2, ';') {
+                    let names = inline.iter().map(|(n, _)| n.clone()).collect();
+                    decls.push(ModDecl { inline: names, name, path_attr: path_attr.take() });
+                } else if punct_at(k + 2, '{') {
+                    inline.push((name, depth + 1));
+                    path_attr = None;
+                }
+            }
+            Token::Punct('{') => depth += 1,
+            Token::Punct('}') => {
+                if inline.last().is_some_and(|(_, d)| *d == depth) {
+                    inline.pop();
+                }
+                depth -= 1;
+                path_attr = None;
+            }
+            Token::Punct(';') => path_attr = None,
+            _ => {}
+        }
+    }
+    decls
+}
+
+/// What `proc_macro_crate` needs from a library source.
+#[derive(Default)]
+struct ProcMacroSource {
+    /// 1-based line of the first `#[proc_macro*]` attribute.
+    macro_line: Option<usize>,
+    /// `(1-based line, kind, name)` of each top-level unrestricted `pub` item
+    /// that is not a `#[proc_macro*]` function.
+    exports: Vec<(usize, String, String)>,
+}
+
+fn scan_proc_macro_source(content: &str) -> ProcMacroSource {
+    let tokens = lex_rust(content).tokens;
+    let ident_at = |k: usize| match tokens.get(k) {
+        Some((_, Token::Ident(n))) => Some(n.as_str()),
+        _ => None,
+    };
+    let mut scan = ProcMacroSource::default();
+    let mut depth = 0i32;
+    // Whether the item being read carries a `#[proc_macro*]` attribute
+    let mut macro_attr = false;
+
+    for (k, (line, token)) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('{') => depth += 1,
+            Token::Punct('}') => {
+                depth -= 1;
+                if depth == 0 {
+                    macro_attr = false;
+                }
+            }
+            Token::Punct(';') if depth == 0 => macro_attr = false,
+            Token::Punct('#') if depth == 0 => {
+                let is_bracket = matches!(tokens.get(k + 1), Some((_, Token::Punct('['))));
+                if is_bracket && ident_at(k + 2).is_some_and(|n| PROC_MACRO_ATTRS.contains(&n)) {
+                    macro_attr = true;
+                    if scan.macro_line.is_none() {
+                        scan.macro_line = Some(line + 1);
+                    }
+                }
+            }
+            Token::Ident(kw) if kw == "pub" && depth == 0 => {
+                // `pub(crate)` and friends are not exported
+                if matches!(tokens.get(k + 1), Some((_, Token::Punct('(')))) {
+                    continue;
+                }
+                // Skip qualifiers: `pub const unsafe extern "C" fn`
+                let mut j = k + 1;
+                loop {
+                    match (ident_at(j), tokens.get(j)) {
+                        (Some("unsafe" | "async" | "extern"), _) | (None, Some((_, Token::Str))) => j += 1,
+                        (Some("const"), _) if matches!(ident_at(j + 1), Some("fn" | "unsafe" | "async" | "extern")) => j += 1,
+                        _ => break,
+                    }
+                }
+                let kind = ident_at(j).unwrap_or("item");
+                if kind == "fn" && macro_attr {
+                    continue;
+                }
+                let name = if kind == "use" {
+                    tokens[j + 1..].iter()
+                        .take_while(|(_, t)| *t != Token::Punct(';'))
+                        .map(|(_, t)| match t {
+                            Token::Ident(n) => n.clone(),
+                            Token::Punct(c) => c.to_string(),
+                            Token::Str => "\"..\"".to_string(),
+                        })
+                        .collect()
+                } else {
+                    ident_at(j + 1).unwrap_or("").to_string()
+                };
+                scan.exports.push((line + 1, kind.to_string(), name));
+            }
+            _ => {}
+        }
+    }
+    scan
+}
+
+/// A lexical token; literals and numbers are dropped, comments are collected per line.
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Str,
+    Punct(char),
+}
+
+/// Tokens with their 0-based line, plus the comment text on each line.
+struct Lexed {
+    tokens: Vec<(usize, Token)>,
+    comments: Vec<String>,
+}
+
+/// Minimal Rust tokenizer: enough to tell code from comments, strings
+/// (including raw and byte strings), char literals and lifetimes.
+fn lex_rust(content: &str) -> Lexed {
+    let chars: Vec<char> = content.chars().collect();
+    let mut tokens = Vec::new();
+    let mut comments = vec![String::new(); content.lines().count() + 1];
+    let mut line = 0;
+    let mut i = 0;
+
+    // Skip a quoted body starting after the opening quote; `raw_hashes` is
+    // Some(n) for raw strings closed by `"` followed by n `#`.
+    let skip_string = |i: &mut usize, line: &mut usize, raw_hashes: Option<usize>| {
+        while *i < chars.len() {
+            let c = chars[*i];
+            *i += 1;
+            match c {
+                '\n' => *line += 1,
+                '\\' if raw_hashes.is_none() => {
+                    if chars.get(*i) == Some(&'\n') {
+                        *line += 1;
+                    }
+                    *i += 1;
+                }
+                '"' => {
+                    let hashes = raw_hashes.unwrap_or(0);
+                    if chars[*i..].iter().take(hashes).filter(|&&h| h == '#').count() == hashes {
+                        *i += hashes;
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            '/' if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    comments[line].push(chars[i]);
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                let mut depth = 0;
+                while i < chars.len() {
+                    if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                        depth += 1;
+                        i += 2;
+                    } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        if chars[i] == '\n' {
+                            line += 1;
+                        } else {
+                            comments[line].push(chars[i]);
+                        }
+                        i += 1;
+                    }
+                }
+            }
+            '"' => {
+                tokens.push((line, Token::Str));
+                i += 1;
+                skip_string(&mut i, &mut line, None);
+            }
+            '\'' => {
+                if next == Some('\\') {
+                    // Escaped char literal: '\n', '\'', '\u{..}'
+                    i += 3;
+                    while i < chars.len() && chars[i] != '\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                } else if chars.get(i + 2) == Some(&'\'') {
+                    i += 3;
+                } else {
+                    // Lifetime or label; its name is lexed as an identifier
+                    tokens.push((line, Token::Punct('\'')));
+                    i += 1;
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let ident: String = chars[start..i].iter().collect();
+                let after = chars.get(i).copied();
+                match (ident.as_str(), after) {
+                    ("r" | "br" | "cr", Some('"' | '#')) => {
+                        let hashes = chars[i..].iter().take_while(|&&h| h == '#').count();
+                        if chars.get(i + hashes) == Some(&'"') {
+                            tokens.push((line, Token::Str));
+                            i += hashes + 1;
+                            skip_string(&mut i, &mut line, Some(hashes));
+                        } else if ident == "r" && hashes == 1 {
+                            // Raw identifier `r#name`: never a keyword
+                            let start = i + 1;
+                            i = start;
+                            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                                i += 1;
+                            }
+                            let name: String = chars[start..i].iter().collect();
+                            tokens.push((line, Token::Ident(format!("r#{}", name))));
+                        } else {
+                            tokens.push((line, Token::Ident(ident)));
+                        }
+                    }
+                    ("b" | "c", Some('"')) => {
+                        tokens.push((line, Token::Str));
+                        i += 1;
+                        skip_string(&mut i, &mut line, None);
+                    }
+                    ("b", Some('\'')) => {
+                        i += 1;
+                        if chars.get(i) == Some(&'\\') {
+                            i += 1;
+                        }
+                        i += 1;
+                        while i < chars.len() && chars[i] != '\'' {
+                            i += 1;
+                        }
+                        i += 1;
+                    }
+                    _ => tokens.push((line, Token::Ident(ident))),
+                }
+            }
+            c if c.is_ascii_digit() => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+            }
+            c if c.is_whitespace() => i += 1,
+            c => {
+                tokens.push((line, Token::Punct(c)));
+                i += 1;
+            }
+        }
+    }
+
+    Lexed { tokens, comments }
+}
+
+/// `(1-based line, kind)` of each `unsafe` block, impl or definition in
+/// `content` that lacks a safety comment.
+fn undocumented_unsafe(content: &str) -> Vec<(usize, &'static str)> {
+    let lexed = lex_rust(content);
+    let tokens = &lexed.tokens;
+    let lines: Vec<&str> = content.lines().collect();
+    let is_ident = |k: usize, name: &str| matches!(tokens.get(k), Some((_, Token::Ident(n))) if n == name);
+    let is_punct = |k: usize, p: char| matches!(tokens.get(k), Some((_, Token::Punct(c))) if *c == p);
+
+    // Whether the `fn` at token `k` is followed by a body rather than `;`
+    let fn_has_body = |k: usize| {
+        let mut depth = 0i32;
+        for (_, token) in &tokens[k..] {
+            match token {
+                Token::Punct('(' | '[') => depth += 1,
+                Token::Punct(')' | ']') => depth -= 1,
+                Token::Punct(';') if depth == 0 => return false,
+                Token::Punct('{') if depth == 0 => return true,
+                _ => {}
+            }
+        }
+        false
+    };
+
+    let mut has_code = vec![false; lexed.comments.len()];
+    for (line, _) in tokens {
+        has_code[*line] = true;
+    }
+
+    let mut found = Vec::new();
+    for (k, (line, token)) in tokens.iter().enumerate() {
+        if !matches!(token, Token::Ident(name) if name == "unsafe") {
+            continue;
+        }
+        let mut next = k + 1;
+        if is_ident(next, "extern") {
+            next += 1;
+            if matches!(tokens.get(next), Some((_, Token::Str))) {
+                next += 1;
+            }
+        }
+        let kind = if is_punct(next, '{') {
+            if next == k + 1 { "block" } else { "extern block" }
+        } else if is_ident(next, "impl") {
+            "impl"
+        } else if is_ident(next, "trait") || (is_ident(next, "auto") && is_ident(next + 1, "trait")) {
+            "trait"
+        } else if is_ident(next, "fn") {
+            // `unsafe fn(..)` is a pointer type; a bodiless signature declares, not defines
+            if is_punct(next + 1, '(') || !fn_has_body(next) {
+                continue;
+            }
+            "fn"
+        } else {
+            // `#[unsafe(..)]` attributes and `unsafe` in macro input
+            continue;
+        };
+
+        let declaration = matches!(kind, "fn" | "trait");
+        let documented = |l: usize| {
+            let comment = &lexed.comments[l];
+            comment.contains("SAFETY:") || (declaration && comment.contains("# Safety"))
+        };
+        let mut ok = documented(*line);
+        let mut l = *line;
+        while !ok && l > 0 {
+            l -= 1;
+            if documented(l) {
+                ok = true;
+                break;
+            }
+            let comment_only = !has_code[l] && !lexed.comments[l].is_empty();
+            let attribute = lines.get(l).is_some_and(|t| t.trim_start().starts_with("#["));
+            if !(comment_only || attribute) {
+                break;
+            }
+        }
+        if !ok {
+            found.push((line + 1, kind));
+        }
+    }
+    found
+}
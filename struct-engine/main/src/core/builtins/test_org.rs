@@ -24,6 +24,7 @@ fn make_violation(
         fix_hint: fix_hint.map(String::from)
             .unwrap_or_else(|| def.fix_hint.clone()
                 .unwrap_or_else(|| def.rule_type.auto_fix_hint())),
+        suppressed: false,
     }
 }
 
@@ -366,3 +367,91 @@ impl CheckRunner for NoTestInSrc {
         }
     }
 }
+
+/// Check 46: `#[cfg(test)]` modules are private and no public module is named `tests`/`test`.
+pub struct TestModulesPrivate {
+    pub def: RuleDef,
+}
+
+impl CheckRunner for TestModulesPrivate {
+    fn id(&self) -> CheckId { CheckId(self.def.id) }
+    fn category(&self) -> &str { &self.def.category }
+    fn description(&self) -> &str { &self.def.description }
+
+    fn run(&self, ctx: &ScanContext) -> CheckResult {
+        // Only bare `pub` widens the public API; `pub(crate)` and friends do not
+        let pub_mod_re = Regex::new(r"^\s*pub\s+mod\s+(\w+)\s*[;{]").unwrap();
+        let cfg_test_re = Regex::new(r"#\[cfg\((?:all\()?\s*test\b").unwrap();
+
+        let source_files: Vec<_> = ctx.files().iter()
+            .filter(|f| {
+                let s = f.to_string_lossy().replace('\\', "/");
+                (s.starts_with("src/") || s.starts_with("main/src/"))
+                    && s.ends_with(".rs")
+            })
+            .collect();
+
+        let mut violations = Vec::new();
+        for file in &source_files {
+            let full = ctx.root.join(file);
+            let content = match std::fs::read_to_string(&full) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            // Attributes stack on the lines directly above the declaration
+            let mut cfg_test_pending = false;
+            for (idx, line) in content.lines().enumerate() {
+                let trimmed = line.trim_start();
+                if trimmed.starts_with("#[") {
+                    if cfg_test_re.is_match(trimmed) {
+                        cfg_test_pending = true;
+                    }
+                    continue;
+                }
+                if trimmed.is_empty() || trimmed.starts_with("//") {
+                    continue;
+                }
+
+                let cfg_test = cfg_test_pending;
+                cfg_test_pending = false;
+
+                let Some(cap) = pub_mod_re.captures(line) else { continue };
+                let module = &cap[1];
+                let line_no = idx + 1;
+
+                if cfg_test {
+                    violations.push(make_violation(
+                        &self.def,
+                        Some(file),
+                        &format!(
+                            "Module '{}' is gated by #[cfg(test)] but declared pub (line {})",
+                            module, line_no
+                        ),
+                        Some(&format!("mod {}", module)),
+                        Some(&format!("pub mod {}", module)),
+                        Some("Drop `pub` from #[cfg(test)] modules; test code does not belong in the public API"),
+                    ));
+                } else if module == "tests" || module == "test" {
+                    violations.push(make_violation(
+                        &self.def,
+                        Some(file),
+                        &format!(
+                            "Public module '{}' is not gated by #[cfg(test)] (line {})",
+                            module, line_no
+                        ),
+                        Some(&format!("#[cfg(test)] mod {}", module)),
+                        Some(&format!("pub mod {}", module)),
+                        Some("Gate the module with #[cfg(test)] and make it private"),
+                    ));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail { violations }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use crate::api::types::{CargoManifest, BinTarget, TestTarget, BenchTarget, ExampleTarget, ScanError};
+use crate::api::types::{CargoManifest, BinTarget, TestTarget, BenchTarget, ExampleTarget, DepInfo, DepKind, DepSource, ScanError};
 
 /// Parse a Cargo.toml file into a CargoManifest.
 pub fn parse_cargo_toml(root: &Path) -> Result<Option<CargoManifest>, ScanError> {
@@ -33,6 +33,22 @@ pub fn parse_cargo_toml(root: &Path) -> Result<Option<CargoManifest>, ScanError>
         .and_then(|v| v.as_str())
         .map(String::from);
 
+    let lib_crate_types: Vec<String> = raw.get("lib")
+        .and_then(|l| l.get("crate-type").or_else(|| l.get("crate_type")))
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let proc_macro = raw.get("lib")
+        .and_then(|l| l.get("proc-macro").or_else(|| l.get("proc_macro")))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+        || lib_crate_types.iter().any(|t| t == "proc-macro");
+
     let bins = raw.get("bin")
         .and_then(|b| b.as_array())
         .map(|arr| {
@@ -85,11 +101,29 @@ pub fn parse_cargo_toml(root: &Path) -> Result<Option<CargoManifest>, ScanError>
         })
         .unwrap_or_default();
 
+    let mut dependencies = Vec::new();
+    for (table, kind) in [
+        ("dependencies", DepKind::Normal),
+        ("dev-dependencies", DepKind::Dev),
+        ("build-dependencies", DepKind::Build),
+    ] {
+        if let Some(deps) = raw.get(table).and_then(|d| d.as_table()) {
+            dependencies.extend(deps.iter().map(|(name, spec)| DepInfo {
+                name: name.clone(),
+                version_req: dep_version_req(spec),
+                kind,
+                source: dep_source(spec),
+            }));
+        }
+    }
+
     Ok(Some(CargoManifest {
         raw: Some(raw),
         package_name,
         has_lib,
         lib_path,
+        proc_macro,
+        lib_crate_types,
         bins,
         tests,
         benches,
@@ -97,9 +131,35 @@ pub fn parse_cargo_toml(root: &Path) -> Result<Option<CargoManifest>, ScanError>
         has_workspace,
         edition,
         workspace_members,
+        dependencies,
     }))
 }
 
+/// Version requirement of a dependency spec: either `name = "1.0"` or
+/// `name = { version = "1.0", ... }`.
+fn dep_version_req(spec: &toml::Value) -> Option<String> {
+    match spec {
+        toml::Value::String(v) => Some(v.clone()),
+        toml::Value::Table(t) => t.get("version").and_then(|v| v.as_str()).map(String::from),
+        _ => None,
+    }
+}
+
+/// Source of a dependency spec; `git` and `path` take precedence over a
+/// fallback `version`.
+fn dep_source(spec: &toml::Value) -> DepSource {
+    let has = |key: &str| spec.get(key).is_some();
+    if has("git") {
+        DepSource::Git
+    } else if has("path") {
+        DepSource::Path
+    } else if spec.get("workspace").and_then(|w| w.as_bool()) == Some(true) {
+        DepSource::Workspace
+    } else {
+        DepSource::Registry
+    }
+}
+
 /// Look up a dotted key path in a TOML Value (e.g. "package.name").
 pub fn lookup_toml_key<'a>(value: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
     let parts: Vec<&str> = key.split('.').collect();
@@ -149,6 +209,26 @@ path = "src/lib.rs"
         let manifest = parse_cargo_toml(tmp.path()).unwrap().unwrap();
         assert!(manifest.has_lib);
         assert_eq!(manifest.lib_path.as_deref(), Some("src/lib.rs"));
+        assert!(!manifest.proc_macro);
+    }
+
+    #[test]
+    fn test_parse_proc_macro_cargo_toml() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), r#"
+[package]
+name = "mymacros"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+proc-macro = true
+crate-type = ["proc-macro"]
+"#).unwrap();
+
+        let manifest = parse_cargo_toml(tmp.path()).unwrap().unwrap();
+        assert!(manifest.proc_macro);
+        assert_eq!(manifest.lib_crate_types, vec!["proc-macro".to_string()]);
     }
 
     #[test]
@@ -229,6 +309,42 @@ members = ["crate-a", "crate-b"]
         assert_eq!(manifest.workspace_members, vec!["crate-a".to_string(), "crate-b".to_string()]);
     }
 
+    #[test]
+    fn test_parse_dependencies() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), r#"
+[package]
+name = "test-pkg"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+regex = "1.10"
+local = { path = "../local" }
+
+[dev-dependencies]
+tempfile = "3"
+
+[build-dependencies]
+cc = "1.0"
+"#).unwrap();
+
+        let manifest = parse_cargo_toml(tmp.path()).unwrap().unwrap();
+        assert_eq!(manifest.dependencies.len(), 5);
+        let serde = manifest.dependencies.iter().find(|d| d.name == "serde").unwrap();
+        assert_eq!(serde.version_req.as_deref(), Some("1"));
+        assert_eq!(serde.kind, DepKind::Normal);
+        assert_eq!(serde.source, DepSource::Registry);
+        let local = manifest.dependencies.iter().find(|d| d.name == "local").unwrap();
+        assert!(local.version_req.is_none());
+        assert_eq!(local.source, DepSource::Path);
+        let tempfile = manifest.dependencies.iter().find(|d| d.name == "tempfile").unwrap();
+        assert_eq!(tempfile.kind, DepKind::Dev);
+        let cc = manifest.dependencies.iter().find(|d| d.name == "cc").unwrap();
+        assert_eq!(cc.kind, DepKind::Build);
+    }
+
     #[test]
     fn test_lookup_toml_key() {
         let val: toml::Value = r#"
@@ -0,0 +1,117 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::api::types::ScanError;
+
+/// File name searched for by [`ConfigFile::discover`].
+pub const CONFIG_FILE_NAME: &str = "struct-engine.toml";
+
+/// Scan settings read from a `struct-engine.toml` file.
+///
+/// Values are kept in their CLI spelling (e.g. `checks = "1-13"`) so the CLI
+/// validates file, environment and flag values the same way. Resolution
+/// order is: built-in defaults, then the file, then `STRUCT_ENGINE_*`
+/// environment variables, then command-line flags.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Project kind: `library`, `binary`, `both`, or `workspace`.
+    pub kind: Option<String>,
+    /// Check selection in `--checks` syntax.
+    pub checks: Option<String>,
+    /// Custom rules file; relative paths resolve against the config file's directory.
+    pub rules: Option<PathBuf>,
+    /// Baseline file; relative paths resolve against the config file's directory.
+    pub baseline: Option<PathBuf>,
+    /// Recursively scan workspace members.
+    pub recursive: bool,
+    /// Include the declared dependency inventory in the report.
+    pub with_deps: bool,
+}
+
+impl ConfigFile {
+    /// Load a config file, resolving `rules` and `baseline` relative to the
+    /// file's directory.
+    pub fn from_file(path: &Path) -> Result<Self, ScanError> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            ScanError::Config(format!("cannot read '{}': {}", path.display(), e))
+        })?;
+        let mut config: ConfigFile = toml::from_str(&text).map_err(|e| {
+            ScanError::Config(format!("invalid config file '{}': {}", path.display(), e))
+        })?;
+        if let Some(dir) = path.parent() {
+            for file in [config.rules.as_mut(), config.baseline.as_mut()].into_iter().flatten() {
+                if file.is_relative() {
+                    *file = dir.join(&*file);
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Find the nearest `struct-engine.toml` in `start` or any of its ancestors.
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Override values with `STRUCT_ENGINE_KIND`, `STRUCT_ENGINE_CHECKS`,
+    /// `STRUCT_ENGINE_RULES` and `STRUCT_ENGINE_BASELINE` when they are set.
+    pub fn merge_env(&mut self) {
+        self.merge_vars(|name| std::env::var(name).ok());
+    }
+
+    fn merge_vars(&mut self, var: impl Fn(&str) -> Option<String>) {
+        if let Some(v) = var("STRUCT_ENGINE_KIND") { self.kind = Some(v); }
+        if let Some(v) = var("STRUCT_ENGINE_CHECKS") { self.checks = Some(v); }
+        if let Some(v) = var("STRUCT_ENGINE_RULES") { self.rules = Some(PathBuf::from(v)); }
+        if let Some(v) = var("STRUCT_ENGINE_BASELINE") { self.baseline = Some(PathBuf::from(v)); }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file_resolves_paths_relative_to_file() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join(CONFIG_FILE_NAME);
+        fs::write(&path, "kind = \"binary\"\nrules = \"rules.toml\"\nbaseline = \"/abs/baseline.json\"\nrecursive = true\n").unwrap();
+
+        let config = ConfigFile::from_file(&path).unwrap();
+        assert_eq!(config.kind.as_deref(), Some("binary"));
+        assert_eq!(config.rules, Some(tmp.path().join("rules.toml")));
+        assert_eq!(config.baseline, Some(PathBuf::from("/abs/baseline.json")));
+        assert!(config.recursive);
+        assert!(!config.with_deps);
+    }
+
+    #[test]
+    fn test_from_file_rejects_unknown_keys() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join(CONFIG_FILE_NAME);
+        fs::write(&path, "scope = \"large\"\n").unwrap();
+        assert!(matches!(ConfigFile::from_file(&path), Err(ScanError::Config(_))));
+    }
+
+    #[test]
+    fn test_discover_walks_up_from_start() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        fs::write(tmp.path().join(CONFIG_FILE_NAME), "").unwrap();
+        let nested = tmp.path().join("crates/core");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(ConfigFile::discover(&nested), Some(tmp.path().join(CONFIG_FILE_NAME)));
+    }
+
+    #[test]
+    fn test_env_overrides_file_values() {
+        let mut config = ConfigFile { kind: Some("binary".into()), ..ConfigFile::default() };
+        config.merge_vars(|name| (name == "STRUCT_ENGINE_KIND").then(|| "library".to_string()));
+        assert_eq!(config.kind.as_deref(), Some("library"));
+    }
+}
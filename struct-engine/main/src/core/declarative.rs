@@ -66,6 +66,7 @@ impl DeclarativeCheck {
             actual: actual.map(String::from),
             fix_hint: self.def.fix_hint.clone()
                 .unwrap_or_else(|| self.def.rule_type.auto_fix_hint()),
+            suppressed: false,
         }
     }
 
@@ -609,6 +610,8 @@ mod tests {
             rule_type,
             project_kind: None,
             fix_hint: None,
+            params: toml::Table::new(),
+            rationale: None,
         }
     }
 
@@ -619,6 +622,7 @@ mod tests {
             file_contents: HashMap::new(),
             project_kind: crate::api::types::ProjectKind::Library,
             cargo_manifest: None,
+            member_manifests: Vec::new(),
         }
     }
 
@@ -629,6 +633,7 @@ mod tests {
             file_contents: HashMap::new(),
             project_kind: crate::api::types::ProjectKind::Library,
             cargo_manifest: Some(manifest),
+            member_manifests: Vec::new(),
         }
     }
 
@@ -742,6 +747,8 @@ name = "test"
             package_name: Some("test".to_string()),
             has_lib: false,
             lib_path: None,
+            proc_macro: false,
+            lib_crate_types: vec![],
             bins: vec![],
             tests: vec![],
             benches: vec![],
@@ -749,6 +756,7 @@ name = "test"
             has_workspace: false,
             edition: None,
             workspace_members: vec![],
+            dependencies: vec![],
         };
         let def = make_rule_def(9, RuleType::CargoKeyExists { key: "package.name".to_string() });
         let check = DeclarativeCheck { def };
@@ -768,6 +776,8 @@ name = "test"
             package_name: Some("test".to_string()),
             has_lib: false,
             lib_path: None,
+            proc_macro: false,
+            lib_crate_types: vec![],
             bins: vec![],
             tests: vec![],
             benches: vec![],
@@ -775,6 +785,7 @@ name = "test"
             has_workspace: false,
             edition: None,
             workspace_members: vec![],
+            dependencies: vec![],
         };
         let def = make_rule_def(12, RuleType::CargoKeyExists { key: "package.description".to_string() });
         let check = DeclarativeCheck { def };
@@ -805,6 +816,8 @@ name = "my_package"
             package_name: Some("my_package".to_string()),
             has_lib: false,
             lib_path: None,
+            proc_macro: false,
+            lib_crate_types: vec![],
             bins: vec![],
             tests: vec![],
             benches: vec![],
@@ -812,6 +825,7 @@ name = "my_package"
             has_workspace: false,
             edition: None,
             workspace_members: vec![],
+            dependencies: vec![],
         };
         let def = make_rule_def(27, RuleType::CargoKeyMatches {
             key: "package.name".to_string(),
@@ -834,6 +848,8 @@ name = "MyPackage"
             package_name: Some("MyPackage".to_string()),
             has_lib: false,
             lib_path: None,
+            proc_macro: false,
+            lib_crate_types: vec![],
             bins: vec![],
             tests: vec![],
             benches: vec![],
@@ -841,6 +857,7 @@ name = "MyPackage"
             has_workspace: false,
             edition: None,
             workspace_members: vec![],
+            dependencies: vec![],
         };
         let def = make_rule_def(27, RuleType::CargoKeyMatches {
             key: "package.name".to_string(),
@@ -1,10 +1,11 @@
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Mutex};
 
 use rayon::prelude::*;
 
-use crate::api::traits::{ComplianceEngine, FileScanner};
-use crate::api::types::{ScanConfig, ScanReport, ScanSummary, CheckEntry, CheckResult, ProjectKind, ScanContext, ScanError, FileIndex, MemberReport};
+use crate::api::traits::{ComplianceEngine, FileScanner, StreamingReportSink};
+use crate::api::types::{ProgressEvent, ScanConfig, ScanReport, ScanSummary, CheckEntry, CheckResult, ProjectKind, ScanContext, ScanError, FileIndex, MemberReport, BaselineEntry, Violation, RuleSet, WorkspaceReport};
 use super::cargo_manifest;
 use super::rules::{self, DEFAULT_RULES};
 use super::scanner::FileSystemScanner;
@@ -42,6 +43,80 @@ pub fn detect_project_kind(root: &Path) -> ProjectKind {
     }
 }
 
+/// Load a baseline file written by [`baseline_entries`].
+pub fn load_baseline(path: &Path) -> Result<Vec<BaselineEntry>, ScanError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ScanError::Config(format!("Cannot read baseline file '{}': {}", path.display(), e))
+    })?;
+    serde_json::from_str(&content).map_err(|e| {
+        ScanError::Config(format!("Invalid baseline file '{}': {}", path.display(), e))
+    })
+}
+
+/// Fingerprint a violation the way it is stored in a baseline.
+fn baseline_entry(v: &Violation) -> BaselineEntry {
+    BaselineEntry {
+        check_id: v.check_id,
+        path: v.path.as_ref().map(|p| p.to_string_lossy().replace('\\', "/")),
+        message: v.message.clone(),
+    }
+}
+
+/// Collect every violation in `report` (including workspace members) as
+/// baseline entries, so the current state can be snapshotted.
+pub fn baseline_entries(report: &ScanReport) -> Vec<BaselineEntry> {
+    let member_results = report.member_reports.iter().flat_map(|m| &m.results);
+    let mut seen = HashSet::new();
+    report.results.iter()
+        .chain(member_results)
+        .filter_map(|entry| match &entry.result {
+            CheckResult::Fail { violations } => Some(violations),
+            _ => None,
+        })
+        .flatten()
+        .map(baseline_entry)
+        .filter(|e| seen.insert(e.clone()))
+        .collect()
+}
+
+/// Write the violations in `report` to `path` as a baseline file.
+///
+/// Creates parent directories if they do not exist.
+pub fn write_baseline(report: &ScanReport, path: &Path) -> Result<(), ScanError> {
+    let json = serde_json::to_string_pretty(&baseline_entries(report))
+        .map_err(|e| ScanError::Config(format!("JSON serialization failed: {}", e)))?;
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Mark violations that match a baseline entry as suppressed.
+fn apply_baseline(results: &mut [CheckEntry], baseline: &HashSet<BaselineEntry>) {
+    for entry in results {
+        if let CheckResult::Fail { violations } = &mut entry.result {
+            for v in violations {
+                if baseline.contains(&baseline_entry(v)) {
+                    v.suppressed = true;
+                }
+            }
+        }
+    }
+}
+
+/// True when a check failed only on suppressed violations.
+pub(crate) fn is_fully_suppressed(result: &CheckResult) -> bool {
+    match result {
+        CheckResult::Fail { violations } => {
+            !violations.is_empty() && violations.iter().all(|v| v.suppressed)
+        }
+        _ => false,
+    }
+}
+
 /// Struct-engine compliance engine.
 pub struct StructComplianceEngine;
 
@@ -51,6 +126,63 @@ impl ComplianceEngine for StructComplianceEngine {
     }
 
     fn scan_with_config(&self, root: &Path, config: &ScanConfig) -> Result<ScanReport, ScanError> {
+        // Same as a no-op progress callback, without the relay thread
+        self.scan_member(root, config, None, None, None)
+    }
+
+    fn scan_with_progress(
+        &self,
+        root: &Path,
+        config: &ScanConfig,
+        on_event: &mut dyn FnMut(ProgressEvent),
+    ) -> Result<ScanReport, ScanError> {
+        // Checks run on rayon workers; relay their events so on_event is only
+        // ever called here, on the caller's thread
+        let (tx, rx) = mpsc::channel();
+        std::thread::scope(|s| {
+            let scan = s.spawn(move || {
+                let progress = move |event: ProgressEvent| {
+                    let _ = tx.send(event);
+                };
+                self.scan_member(root, config, None, None, Some(&progress))
+            });
+            for event in rx {
+                on_event(event);
+            }
+            match scan.join() {
+                Ok(report) => report,
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        })
+    }
+}
+
+impl StructComplianceEngine {
+    /// Scan like [`ComplianceEngine::scan_with_config`], passing each check
+    /// entry to `sink` as soon as it finishes (in completion order, from worker
+    /// threads). Workspace member entries are labelled with the member path.
+    ///
+    /// The full report is still returned; [`StreamingReportSink::finish`] is
+    /// left to the caller.
+    pub fn scan_streaming(
+        &self,
+        root: &Path,
+        config: &ScanConfig,
+        sink: &dyn StreamingReportSink,
+    ) -> Result<ScanReport, ScanError> {
+        self.scan_member(root, config, Some(sink), None, None)
+    }
+
+    /// Scan `root`, streaming entries labelled `member` to `stream` and
+    /// reporting check progress to `progress` if given.
+    fn scan_member(
+        &self,
+        root: &Path,
+        config: &ScanConfig,
+        stream: Option<&dyn StreamingReportSink>,
+        member: Option<&str>,
+        progress: Option<&(dyn Fn(ProgressEvent) + Sync)>,
+    ) -> Result<ScanReport, ScanError> {
         // Validate root path exists
         if !root.exists() {
             return Err(ScanError::Path(format!("Path '{}' does not exist", root.display())));
@@ -62,20 +194,19 @@ impl ComplianceEngine for StructComplianceEngine {
             None => detect_project_kind(root),
         };
 
-        // 1. Load rules (embedded default or external path)
-        let rules_toml = match &config.rules_path {
-            Some(path) => {
-                std::fs::read_to_string(path).map_err(|e| {
-                    ScanError::Config(format!("Cannot read rules file '{}': {}", path.display(), e))
-                })?
-            }
-            None => DEFAULT_RULES.to_string(),
-        };
+        // 1. Load the embedded rules; a custom file overrides them by check id
+        let ruleset = load_ruleset(config)?;
 
-        // 2. Parse rules and build registry
-        let ruleset = rules::parse_rules(&rules_toml)?;
+        // 2. Validate handler names and build registry
+        rules::validate_handlers(&ruleset.rules)?;
         let registry = rules::build_registry(&ruleset.rules)?;
 
+        // Load the baseline up front so a bad path fails before scanning
+        let baseline: Option<HashSet<BaselineEntry>> = match &config.baseline_path {
+            Some(path) => Some(load_baseline(path)?.into_iter().collect()),
+            None => None,
+        };
+
         // 3. Scanner discovers all files (single traversal), build FileIndex
         let scanner = FileSystemScanner;
         let files = scanner.scan_files(root);
@@ -85,6 +216,19 @@ impl ComplianceEngine for StructComplianceEngine {
         let cargo_manifest = cargo_manifest::parse_cargo_toml(root)
             .unwrap_or(None);
 
+        // Sibling manifests for workspace-level checks
+        let member_manifests = match cargo_manifest.as_ref().filter(|m| m.has_workspace) {
+            Some(m) => discover_members(root, &m.workspace_members).0
+                .into_iter()
+                .filter_map(|member| {
+                    cargo_manifest::parse_cargo_toml(&root.join(&member))
+                        .unwrap_or(None)
+                        .map(|manifest| (member, manifest))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
         // 5. Create ScanContext
         let ctx = ScanContext {
             root: root.to_path_buf(),
@@ -92,9 +236,19 @@ impl ComplianceEngine for StructComplianceEngine {
             file_contents: HashMap::new(),
             project_kind: resolved_kind.clone(),
             cargo_manifest: cargo_manifest.clone(),
+            member_manifests,
         };
 
-        // 6. Filter and run checks in parallel via rayon
+        if let Some(progress) = progress {
+            let total = registry.iter()
+                .filter(|runner| config.checks.as_ref().is_none_or(|c| c.contains(&runner.id().0)))
+                .count();
+            progress(ProgressEvent::ScanStarted { total });
+        }
+
+        // 6. Filter and run checks in parallel via rayon, streaming each
+        //    finished entry (with baselined violations suppressed) if asked
+        let stream_error: Mutex<Option<ScanError>> = Mutex::new(None);
         let results: Vec<CheckEntry> = registry
             .par_iter()
             .filter_map(|runner| {
@@ -108,58 +262,87 @@ impl ComplianceEngine for StructComplianceEngine {
                 }
 
                 // Filter by project_kind: find the matching rule def
-                let rule_def = ruleset.rules.iter().find(|r| r.id == check_id);
-                if let Some(rule) = rule_def {
-                    if let Some(ref rule_kind) = rule.project_kind {
-                        if *rule_kind != resolved_kind {
-                            return Some(CheckEntry {
+                let rule_kind = ruleset.rules.iter()
+                    .find(|r| r.id == check_id)
+                    .and_then(|r| r.project_kind.as_ref())
+                    .filter(|kind| **kind != resolved_kind);
+                let result = match rule_kind {
+                    Some(rule_kind) => CheckResult::Skip {
+                        reason: format!(
+                            "Skipped: requires {:?} project (detected {:?})",
+                            rule_kind, resolved_kind
+                        ),
+                    },
+                    // Run the check
+                    None => {
+                        if let Some(progress) = progress {
+                            progress(ProgressEvent::CheckStarted {
                                 id: runner.id(),
-                                category: runner.category().to_string(),
                                 description: runner.description().to_string(),
-                                result: CheckResult::Skip {
-                                    reason: format!(
-                                        "Skipped: requires {:?} project (detected {:?})",
-                                        rule_kind, resolved_kind
-                                    ),
-                                },
                             });
                         }
+                        runner.run(&ctx)
                     }
-                }
-
-                // Run the check
-                Some(CheckEntry {
+                };
+                let mut entry = CheckEntry {
                     id: runner.id(),
                     category: runner.category().to_string(),
                     description: runner.description().to_string(),
-                    result: runner.run(&ctx),
-                })
+                    result,
+                };
+
+                // 7. Suppress baselined violations
+                if let Some(ref baseline) = baseline {
+                    apply_baseline(std::slice::from_mut(&mut entry), baseline);
+                }
+                if let Some(progress) = progress {
+                    progress(ProgressEvent::CheckFinished {
+                        id: entry.id,
+                        description: entry.description.clone(),
+                        result: entry.result.clone(),
+                    });
+                }
+                if let Some(sink) = stream {
+                    if let Err(e) = sink.emit_entry(member, &entry) {
+                        // Keep the first failure; it is returned once all checks finish
+                        let mut first = stream_error.lock().unwrap_or_else(|e| e.into_inner());
+                        if first.is_none() {
+                            *first = Some(e);
+                        }
+                    }
+                }
+                Some(entry)
             })
             .collect();
+        if let Some(e) = stream_error.into_inner().unwrap_or_else(|e| e.into_inner()) {
+            return Err(e);
+        }
 
-        // 7. Compute summary
+        // Compute summary
         let total = results.len() as u8;
         let passed = results.iter().filter(|e| matches!(e.result, CheckResult::Pass)).count() as u8;
-        let failed = results.iter().filter(|e| matches!(e.result, CheckResult::Fail { .. })).count() as u8;
+        let suppressed = results.iter().filter(|e| is_fully_suppressed(&e.result)).count() as u8;
+        let failed = results.iter().filter(|e| matches!(e.result, CheckResult::Fail { .. })).count() as u8 - suppressed;
         let skipped = results.iter().filter(|e| matches!(e.result, CheckResult::Skip { .. })).count() as u8;
 
         // 8. Recursive workspace member scanning
         let member_reports: Vec<MemberReport> = if config.recursive {
             if let Some(ref manifest) = cargo_manifest {
-                manifest.workspace_members.par_iter()
+                let (members, _) = discover_members(root, &manifest.workspace_members);
+                members.par_iter()
                     .filter_map(|member| {
                         let member_root = root.join(member);
-                        if !member_root.exists() { return None; }
                         let member_config = ScanConfig {
                             recursive: false,
                             ..config.clone()
                         };
-                        match StructComplianceEngine.scan_with_config(&member_root, &member_config) {
+                        match self.scan_member(&member_root, &member_config, stream, Some(member.as_str()), progress) {
                             Ok(report) => Some(MemberReport {
                                 member: member.clone(),
                                 results: report.results,
                                 summary: report.summary,
                                 project_kind: report.project_kind,
+                                dependencies: report.dependencies,
                             }),
                             Err(_) => None,
                         }
@@ -172,16 +355,170 @@ impl ComplianceEngine for StructComplianceEngine {
             vec![]
         };
 
-        // 9. Return ScanReport
+        // 9. Dependency inventory (informational, opt-in)
+        let dependencies = if config.with_deps {
+            cargo_manifest.map(|m| m.dependencies).unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        // 10. Return ScanReport
         Ok(ScanReport {
             results,
-            summary: ScanSummary { total, passed, failed, skipped },
+            summary: ScanSummary { total, passed, failed, skipped, suppressed },
             project_kind: resolved_kind,
             member_reports,
+            dependencies,
         })
     }
 }
 
+/// Load the embedded rules, letting a custom rules file override them by check id.
+pub(crate) fn load_ruleset(config: &ScanConfig) -> Result<RuleSet, ScanError> {
+    let defaults = rules::parse_rules(DEFAULT_RULES)?;
+    Ok(match &config.rules_path {
+        Some(path) => rules::merge_rules(defaults, rules::load_rules_file(path)?),
+        None => defaults,
+    })
+}
+
+/// Resolve `[workspace] members` entries to existing member directories.
+///
+/// Trailing `*` globs (`crates/*`) expand to subdirectories holding a
+/// `Cargo.toml`. `.` is dropped because the root scan already covers the root
+/// package, and missing paths are ignored. Returns `(members, external)`
+/// where `external` lists entries that resolve outside `root` (e.g.
+/// `../shared`); those belong to another tree and are not scanned.
+fn discover_members(root: &Path, entries: &[String]) -> (Vec<String>, Vec<String>) {
+    let canonical_root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let mut members: Vec<String> = Vec::new();
+    let mut external: Vec<String> = Vec::new();
+
+    for entry in entries {
+        let entry = entry.trim_end_matches('/');
+        let candidates: Vec<String> = match entry.strip_suffix('*') {
+            Some(prefix) => {
+                let mut dirs: Vec<String> = std::fs::read_dir(root.join(prefix))
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .filter(|d| d.path().join("Cargo.toml").is_file())
+                    .map(|d| format!("{}{}", prefix, d.file_name().to_string_lossy()))
+                    .collect();
+                dirs.sort();
+                dirs
+            }
+            None => vec![entry.to_string()],
+        };
+
+        for member in candidates {
+            if member.is_empty() || member == "." {
+                continue;
+            }
+            let resolved: PathBuf = match root.join(&member).canonicalize() {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+            if !resolved.starts_with(&canonical_root) {
+                if !external.contains(&member) {
+                    external.push(member);
+                }
+            } else if resolved != canonical_root && !members.contains(&member) {
+                members.push(member);
+            }
+        }
+    }
+    (members, external)
+}
+
+/// Per-check rollup across several scopes (root and members).
+///
+/// Each check id counts once: failed if it has unsuppressed violations in any
+/// scope, suppressed if it failed only on baselined violations, passed if it
+/// passed somewhere, and skipped if every scope skipped it.
+fn aggregate_summary<'a>(scopes: impl Iterator<Item = &'a [CheckEntry]>) -> ScanSummary {
+    // 0 = skip, 1 = pass, 2 = suppressed, 3 = fail
+    let mut worst: HashMap<u8, u8> = HashMap::new();
+    for entries in scopes {
+        for entry in entries {
+            let rank = match &entry.result {
+                CheckResult::Skip { .. } => 0,
+                CheckResult::Pass => 1,
+                result if is_fully_suppressed(result) => 2,
+                CheckResult::Fail { .. } => 3,
+            };
+            let slot = worst.entry(entry.id.0).or_insert(rank);
+            *slot = (*slot).max(rank);
+        }
+    }
+    let count = |rank: u8| worst.values().filter(|r| **r == rank).count() as u8;
+    ScanSummary {
+        total: worst.len() as u8,
+        passed: count(1),
+        failed: count(3),
+        skipped: count(0),
+        suppressed: count(2),
+    }
+}
+
+/// Scan a workspace root and each of its members separately.
+///
+/// Members come from `[workspace] members` in the root `Cargo.toml` (see
+/// [`discover_members`]); a root without `[workspace]` yields no members. In a
+/// virtual workspace (no `[package]`) the root runs only the checks whose rule
+/// targets the `workspace` project kind, so package-level checks are not
+/// reported against a directory that has no package. `config.recursive` is
+/// ignored; every member is scanned non-recursively.
+pub fn scan_workspace(root: &Path, config: &ScanConfig) -> Result<WorkspaceReport, ScanError> {
+    if !root.exists() {
+        return Err(ScanError::Path(format!("Path '{}' does not exist", root.display())));
+    }
+    let engine = StructComplianceEngine;
+    let manifest = cargo_manifest::parse_cargo_toml(root).unwrap_or(None);
+    let virtual_workspace = manifest
+        .as_ref()
+        .is_some_and(|m| m.has_workspace && m.package_name.is_none());
+
+    let mut root_config = ScanConfig { recursive: false, ..config.clone() };
+    if virtual_workspace {
+        let workspace_checks: Vec<u8> = load_ruleset(config)?
+            .rules
+            .iter()
+            .filter(|r| r.project_kind == Some(ProjectKind::Workspace))
+            .map(|r| r.id)
+            .filter(|id| config.checks.as_ref().is_none_or(|c| c.contains(id)))
+            .collect();
+        root_config.checks = Some(workspace_checks);
+    }
+    let root_report = engine.scan_member(root, &root_config, None, None, None)?;
+
+    let (member_paths, external_members) = match manifest.as_ref().filter(|m| m.has_workspace) {
+        Some(m) => discover_members(root, &m.workspace_members),
+        None => (vec![], vec![]),
+    };
+    let member_config = ScanConfig { recursive: false, ..config.clone() };
+    let members = member_paths
+        .par_iter()
+        .map(|member| {
+            engine
+                .scan_member(&root.join(member), &member_config, None, Some(member.as_str()), None)
+                .map(|report| (member.clone(), report))
+        })
+        .collect::<Result<Vec<_>, ScanError>>()?;
+
+    let summary = aggregate_summary(
+        std::iter::once(root_report.results.as_slice())
+            .chain(members.iter().map(|(_, r)| r.results.as_slice())),
+    );
+    Ok(WorkspaceReport {
+        summary,
+        root: root_report,
+        members,
+        virtual_workspace,
+        external_members,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +553,8 @@ mod tests {
             checks: Some(vec![1, 2, 3]),
             rules_path: None,
             recursive: false,
+            with_deps: false,
+            baseline_path: None,
         };
         let report = engine.scan_with_config(tmp.path(), &config).unwrap();
         assert_eq!(report.results.len(), 3);
@@ -223,6 +562,25 @@ mod tests {
         assert_eq!(ids, vec![1, 2, 3]);
     }
 
+    #[test]
+    fn test_scan_with_progress_reports_every_check() {
+        let tmp = TempDir::new().unwrap();
+        let engine = StructComplianceEngine;
+        let config = ScanConfig {
+            project_kind: Some(ProjectKind::Library),
+            checks: Some((1..=20).collect()),
+            ..ScanConfig::default()
+        };
+        let mut events = Vec::new();
+        let report = engine.scan_with_progress(tmp.path(), &config, &mut |event| events.push(event)).unwrap();
+
+        assert!(matches!(events[0], ProgressEvent::ScanStarted { total } if total == report.results.len()));
+        let finished = events.iter().filter(|e| matches!(e, ProgressEvent::CheckFinished { .. })).count();
+        assert_eq!(finished, report.results.len());
+        let started = events.iter().filter(|e| matches!(e, ProgressEvent::CheckStarted { .. })).count();
+        assert!(started <= finished);
+    }
+
     #[test]
     fn test_summary_counts() {
         let tmp = TempDir::new().unwrap();
@@ -312,4 +670,80 @@ path = "src/lib.rs"
         let report = engine.scan(tmp.path()).unwrap();
         assert_eq!(report.project_kind, ProjectKind::Library);
     }
+
+    fn write_crate(root: &Path, dir: &str, name: &str) {
+        std::fs::create_dir_all(root.join(dir).join("src")).unwrap();
+        std::fs::write(
+            root.join(dir).join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n"),
+        ).unwrap();
+        std::fs::write(root.join(dir).join("src/lib.rs"), "").unwrap();
+    }
+
+    #[test]
+    fn test_scan_workspace_virtual_with_glob_and_external_member() {
+        let outer = TempDir::new().unwrap();
+        let ws = outer.path().join("ws");
+        std::fs::create_dir_all(&ws).unwrap();
+        std::fs::write(ws.join("Cargo.toml"), r#"
+[workspace]
+members = ["crates/*", "tools/cli", "../shared", "missing"]
+"#).unwrap();
+        write_crate(&ws, "crates/b", "b");
+        write_crate(&ws, "crates/a", "a");
+        write_crate(&ws, "tools/cli", "cli");
+        write_crate(outer.path(), "shared", "shared");
+
+        let report = scan_workspace(&ws, &ScanConfig::default()).unwrap();
+
+        assert!(report.virtual_workspace);
+        let names: Vec<&str> = report.members.iter().map(|(m, _)| m.as_str()).collect();
+        assert_eq!(names, vec!["crates/a", "crates/b", "tools/cli"]);
+        assert_eq!(report.external_members, vec!["../shared".to_string()]);
+
+        // The virtual root only runs workspace-level checks
+        let rules = rules::parse_rules(DEFAULT_RULES).unwrap();
+        for entry in &report.root.results {
+            let rule = rules.rules.iter().find(|r| r.id == entry.id.0).unwrap();
+            assert_eq!(rule.project_kind, Some(ProjectKind::Workspace));
+        }
+
+        // Each check id is counted once across all scopes
+        let s = &report.summary;
+        assert_eq!(s.total as usize, default_rule_count());
+        assert_eq!(s.total, s.passed + s.failed + s.skipped + s.suppressed);
+        assert_eq!(report.to_scan_report().member_reports.len(), 3);
+    }
+
+    #[test]
+    fn test_scan_workspace_rollup_counts_failure_anywhere() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), r#"
+[package]
+name = "root"
+version = "0.1.0"
+
+[workspace]
+members = [".", "member"]
+"#).unwrap();
+        std::fs::create_dir_all(tmp.path().join("src")).unwrap();
+        std::fs::write(tmp.path().join("src/lib.rs"), "").unwrap();
+        // The member has no src/ at all, so it fails checks the root passes
+        std::fs::create_dir_all(tmp.path().join("member")).unwrap();
+        std::fs::write(tmp.path().join("member/Cargo.toml"), "[package]\nname = \"member\"\n").unwrap();
+
+        let config = ScanConfig { checks: Some(vec![1, 2, 3]), ..ScanConfig::default() };
+        let report = scan_workspace(tmp.path(), &config).unwrap();
+
+        assert!(!report.virtual_workspace);
+        assert_eq!(report.members.len(), 1, "'.' is covered by the root scan");
+        assert_eq!(report.summary.total, 3);
+        let member_failed: Vec<u8> = report.members[0].1.results.iter()
+            .filter(|e| matches!(e.result, CheckResult::Fail { .. }))
+            .map(|e| e.id.0)
+            .collect();
+        assert!(!member_failed.is_empty());
+        assert!(report.summary.failed as usize >= member_failed.len());
+        assert!(report.summary.failed >= report.root.summary.failed);
+    }
 }
@@ -6,3 +6,4 @@ pub(crate) mod declarative;
 pub(crate) mod cargo_manifest;
 pub(crate) mod builtins;
 pub(crate) mod sink;
+pub(crate) mod config_file;
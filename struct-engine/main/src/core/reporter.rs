@@ -1,10 +1,49 @@
 use std::collections::BTreeMap;
 
+use serde_json::{json, Value};
+
 use crate::api::traits::Reporter;
-use crate::api::types::{ScanReport, CheckResult};
+use crate::api::types::{ScanReport, CheckEntry, CheckResult, DepKind, RuleDef, Severity, WorkspaceReport};
+use super::engine::is_fully_suppressed;
+use super::rules::{parse_rules, DEFAULT_RULES};
 
 pub struct TextReporter;
 pub struct JsonReporter;
+pub struct JunitReporter;
+
+/// GitHub Actions reporter: one `::error`/`::warning`/`::notice` workflow
+/// command per unsuppressed violation, so failures show up as inline
+/// annotations. Violations carry no line number, so annotations attach to
+/// the file, or to the repository when the violation has no path.
+pub struct GithubActionsReporter;
+
+/// CSV reporter: a `check_id,category,severity,file,line,message` header, then
+/// one RFC 4180 row per unsuppressed violation (root entries first, then each
+/// workspace member's, with file paths prefixed by the member). Passing checks
+/// emit no rows; skipped checks emit one `skipped` row with the reason when
+/// `include_skipped` is set. Violations carry no line number, so `line` is
+/// always empty.
+pub struct CsvReporter {
+    pub include_skipped: bool,
+}
+
+/// JSON Lines reporter: one `"type": "check"` object per entry (root entries
+/// first, then each workspace member's), followed by one `"type": "summary"`
+/// object. The streaming sink writes the same lines as checks finish.
+pub struct JsonLinesReporter;
+
+/// SARIF 2.1.0 reporter for code-scanning integrations.
+///
+/// Rule metadata comes from `rules`, which should be the rule set the scan
+/// ran with; a check whose id or description does not match a rule is
+/// described from its report entry instead. Skipped checks never produce
+/// results.
+pub struct SarifReporter {
+    /// Rule definitions used to populate the `tool.driver.rules` array.
+    pub rules: Vec<RuleDef>,
+    /// Emit a `pass` result for each passing check.
+    pub include_passing: bool,
+}
 
 impl Reporter for TextReporter {
     fn report(&self, report: &ScanReport) -> String {
@@ -30,6 +69,7 @@ impl Reporter for TextReporter {
             for entry in entries {
                 let status = match &entry.result {
                     CheckResult::Pass => "PASS",
+                    result if is_fully_suppressed(result) => "BASELINED",
                     CheckResult::Fail { .. } => "FAIL",
                     CheckResult::Skip { .. } => "SKIP",
                 };
@@ -44,10 +84,11 @@ impl Reporter for TextReporter {
                         let path_str = v.path.as_ref()
                             .map(|p| p.to_string_lossy().to_string())
                             .unwrap_or_default();
+                        let marker = if v.suppressed { "[baselined] " } else { "" };
                         if path_str.is_empty() {
-                            output.push_str(&format!("    -> {}\n", v.message));
+                            output.push_str(&format!("    -> {}{}\n", marker, v.message));
                         } else {
-                            output.push_str(&format!("    -> {}: {}\n", path_str, v.message));
+                            output.push_str(&format!("    -> {}{}: {}\n", marker, path_str, v.message));
                         }
                         if !v.fix_hint.is_empty() {
                             output.push_str(&format!("       Fix: {}\n", v.fix_hint));
@@ -69,6 +110,30 @@ impl Reporter for TextReporter {
             report.summary.failed,
             report.summary.skipped,
         ));
+        if report.summary.suppressed > 0 {
+            output.push_str(&format!(
+                "{} check(s) failed only on baselined violations\n",
+                report.summary.suppressed,
+            ));
+        }
+
+        // Render dependency inventory (--with-deps)
+        if !report.dependencies.is_empty() {
+            output.push_str("\nDependencies:\n");
+            for dep in &report.dependencies {
+                let kind = match dep.kind {
+                    DepKind::Normal => "normal",
+                    DepKind::Dev => "dev",
+                    DepKind::Build => "build",
+                };
+                output.push_str(&format!(
+                    "  {} {} ({})\n",
+                    dep.name,
+                    dep.version_req.as_deref().unwrap_or("*"),
+                    kind
+                ));
+            }
+        }
 
         // Render workspace member results
         if !report.member_reports.is_empty() {
@@ -82,6 +147,7 @@ impl Reporter for TextReporter {
                 for entry in &mr.results {
                     let status = match &entry.result {
                         CheckResult::Pass => "PASS",
+                        result if is_fully_suppressed(result) => "BASELINED",
                         CheckResult::Fail { .. } => "FAIL",
                         CheckResult::Skip { .. } => "SKIP",
                     };
@@ -96,10 +162,11 @@ impl Reporter for TextReporter {
                             let path_str = v.path.as_ref()
                                 .map(|p| p.to_string_lossy().to_string())
                                 .unwrap_or_default();
+                            let marker = if v.suppressed { "[baselined] " } else { "" };
                             if path_str.is_empty() {
-                                output.push_str(&format!("    -> {}\n", v.message));
+                                output.push_str(&format!("    -> {}{}\n", marker, v.message));
                             } else {
-                                output.push_str(&format!("    -> {}: {}\n", path_str, v.message));
+                                output.push_str(&format!("    -> {}{}: {}\n", marker, path_str, v.message));
                             }
                             if !v.fix_hint.is_empty() {
                                 output.push_str(&format!("       Fix: {}\n", v.fix_hint));
@@ -124,6 +191,45 @@ impl Reporter for TextReporter {
     }
 }
 
+/// Render a [`WorkspaceReport`] as text: the combined summary first, then one
+/// section per scope (root, then each member) in the [`TextReporter`] layout.
+pub fn workspace_text(report: &WorkspaceReport) -> String {
+    let mut output = String::new();
+    let s = &report.summary;
+    output.push_str(&format!(
+        "struct-engine workspace results ({} member(s){})\n",
+        report.members.len(),
+        if report.virtual_workspace { ", virtual" } else { "" },
+    ));
+    output.push_str(&"=".repeat(60));
+    output.push('\n');
+    output.push_str(&format!(
+        "Combined: {}/{} passed, {} failed, {} skipped\n",
+        s.passed, s.total, s.failed, s.skipped,
+    ));
+    if s.suppressed > 0 {
+        output.push_str(&format!("{} check(s) failed only on baselined violations\n", s.suppressed));
+    }
+    for member in &report.external_members {
+        output.push_str(&format!("Not scanned (outside workspace): {}\n", member));
+    }
+
+    let scopes = std::iter::once((".", &report.root))
+        .chain(report.members.iter().map(|(m, r)| (m.as_str(), r)));
+    for (member, scope) in scopes {
+        output.push_str(&format!(
+            "\n{} {} ({}/{} passed, {} failed)\n",
+            "\u{2550}".repeat(3),
+            member,
+            scope.summary.passed,
+            scope.summary.total,
+            scope.summary.failed,
+        ));
+        output.push_str(&TextReporter.report(scope));
+    }
+    output
+}
+
 impl Reporter for JsonReporter {
     fn report(&self, report: &ScanReport) -> String {
         serde_json::to_string_pretty(report).unwrap_or_else(|e| {
@@ -132,6 +238,390 @@ impl Reporter for JsonReporter {
     }
 }
 
+impl Reporter for JsonLinesReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut output = String::new();
+        for entry in &report.results {
+            output.push_str(&jsonl_entry_line(None, entry));
+            output.push('\n');
+        }
+        for member in &report.member_reports {
+            for entry in &member.results {
+                output.push_str(&jsonl_entry_line(Some(&member.member), entry));
+                output.push('\n');
+            }
+        }
+        output.push_str(&jsonl_summary_line(report));
+        output.push('\n');
+        output
+    }
+}
+
+/// One JSON Lines record for a check entry, tagged with its workspace member.
+pub(crate) fn jsonl_entry_line(member: Option<&str>, entry: &CheckEntry) -> String {
+    let mut value = serde_json::to_value(entry).unwrap_or_else(|e| {
+        json!({"error": format!("JSON serialization failed: {}", e)})
+    });
+    if let Value::Object(ref mut map) = value {
+        map.insert("type".to_string(), json!("check"));
+        map.insert("member".to_string(), json!(member));
+    }
+    value.to_string()
+}
+
+/// The closing JSON Lines record: overall and per-member summaries, plus the
+/// dependency inventory when present.
+pub(crate) fn jsonl_summary_line(report: &ScanReport) -> String {
+    let members: Vec<Value> = report.member_reports.iter()
+        .map(|m| json!({"member": m.member, "project_kind": m.project_kind, "summary": m.summary}))
+        .collect();
+    let mut value = json!({
+        "type": "summary",
+        "project_kind": report.project_kind,
+        "summary": report.summary,
+        "members": members,
+    });
+    if !report.dependencies.is_empty() {
+        value["dependencies"] = json!(report.dependencies);
+    }
+    value.to_string()
+}
+
+impl Reporter for JunitReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut by_category: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+        for entry in &report.results {
+            by_category.entry(&entry.category).or_default().push(entry);
+        }
+
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str(&format!(
+            "<testsuites name=\"struct-engine\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+            report.summary.total, report.summary.failed, report.summary.skipped,
+        ));
+
+        for (category, entries) in &by_category {
+            let failures = entries.iter()
+                .filter(|e| matches!(e.result, CheckResult::Fail { .. }) && !is_fully_suppressed(&e.result))
+                .count();
+            let skipped = entries.iter().filter(|e| matches!(e.result, CheckResult::Skip { .. })).count();
+            output.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+                xml_escape(category), entries.len(), failures, skipped,
+            ));
+
+            for entry in entries {
+                let name = xml_escape(&format!("{}: {}", entry.id, entry.description));
+                let classname = xml_escape(category);
+                match &entry.result {
+                    CheckResult::Fail { violations } if !is_fully_suppressed(&entry.result) => {
+                        let violations: Vec<_> = violations.iter().filter(|v| !v.suppressed).collect();
+                        output.push_str(&format!(
+                            "    <testcase classname=\"{}\" name=\"{}\">\n", classname, name
+                        ));
+                        let body = violations.iter().map(|v| {
+                            match &v.path {
+                                Some(p) => format!("{}: {}", p.to_string_lossy(), v.message),
+                                None => v.message.clone(),
+                            }
+                        }).collect::<Vec<_>>().join("\n");
+                        output.push_str(&format!(
+                            "      <failure message=\"{} violation(s)\">{}</failure>\n",
+                            violations.len(), xml_escape(&body),
+                        ));
+                        output.push_str("    </testcase>\n");
+                    }
+                    CheckResult::Skip { reason } => {
+                        output.push_str(&format!(
+                            "    <testcase classname=\"{}\" name=\"{}\">\n", classname, name
+                        ));
+                        output.push_str(&format!("      <skipped message=\"{}\"/>\n", xml_escape(reason)));
+                        output.push_str("    </testcase>\n");
+                    }
+                    // Passing checks, and failures made up only of baselined violations
+                    _ => {
+                        output.push_str(&format!(
+                            "    <testcase classname=\"{}\" name=\"{}\"/>\n", classname, name
+                        ));
+                    }
+                }
+            }
+            output.push_str("  </testsuite>\n");
+        }
+
+        output.push_str("</testsuites>\n");
+        output
+    }
+}
+
+impl SarifReporter {
+    /// Build a reporter over the embedded default rule set.
+    ///
+    /// Scans run with a custom rules file should build the reporter from
+    /// that rule set instead, so the `rules` array matches the scan.
+    pub fn with_default_rules(include_passing: bool) -> Self {
+        let rules = parse_rules(DEFAULT_RULES).map(|set| set.rules).unwrap_or_default();
+        SarifReporter { rules, include_passing }
+    }
+
+    fn rule_for(&self, entry: &CheckEntry) -> Option<&RuleDef> {
+        self.rules
+            .iter()
+            .find(|r| r.id == entry.id.0 && r.description == entry.description)
+    }
+
+    fn sarif_rule(&self, entry: &CheckEntry) -> Value {
+        let mut rule = json!({
+            "id": sarif_rule_id(entry),
+            "name": entry.category,
+            "shortDescription": { "text": entry.description },
+            "properties": { "category": entry.category },
+        });
+        if let Some(def) = self.rule_for(entry) {
+            rule["defaultConfiguration"] = json!({ "level": sarif_level(&def.severity) });
+            if let Some(hint) = &def.fix_hint {
+                rule["help"] = json!({ "text": hint });
+            }
+        }
+        rule
+    }
+}
+
+impl Reporter for SarifReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        // Top-level results first, then workspace members with paths made root-relative
+        let mut scopes: Vec<(Option<&str>, &[CheckEntry])> = vec![(None, &report.results)];
+        for member in &report.member_reports {
+            scopes.push((Some(&member.member), &member.results));
+        }
+
+        let mut rule_ids: Vec<u8> = Vec::new();
+        let mut rules: Vec<Value> = Vec::new();
+        let mut results: Vec<Value> = Vec::new();
+
+        for (member, entries) in scopes {
+            for entry in entries {
+                if matches!(entry.result, CheckResult::Skip { .. }) {
+                    continue;
+                }
+
+                let rule_index = match rule_ids.iter().position(|id| *id == entry.id.0) {
+                    Some(i) => i,
+                    None => {
+                        rule_ids.push(entry.id.0);
+                        rules.push(self.sarif_rule(entry));
+                        rules.len() - 1
+                    }
+                };
+                let rule_id = sarif_rule_id(entry);
+
+                match &entry.result {
+                    CheckResult::Pass if self.include_passing => {
+                        results.push(json!({
+                            "ruleId": rule_id,
+                            "ruleIndex": rule_index,
+                            "kind": "pass",
+                            "level": "none",
+                            "message": { "text": entry.description },
+                        }));
+                    }
+                    CheckResult::Fail { violations } => {
+                        for v in violations {
+                            let mut result = json!({
+                                "ruleId": rule_id,
+                                "ruleIndex": rule_index,
+                                "level": sarif_level(&v.severity),
+                                "message": { "text": v.message },
+                            });
+                            if v.suppressed {
+                                result["suppressions"] = json!([{
+                                    "kind": "external",
+                                    "justification": "Matched a baseline entry",
+                                }]);
+                            }
+                            if let Some(path) = &v.path {
+                                let mut uri = path.to_string_lossy().replace('\\', "/");
+                                if let Some(member) = member {
+                                    uri = format!("{}/{}", member.trim_end_matches('/'), uri);
+                                }
+                                result["locations"] = json!([{
+                                    "physicalLocation": {
+                                        "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+                                    },
+                                }]);
+                            }
+                            results.push(result);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let sarif = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "struct-engine",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        });
+
+        serde_json::to_string_pretty(&sarif).unwrap_or_else(|e| {
+            format!("{{\"error\": \"SARIF serialization failed: {}\"}}", e)
+        })
+    }
+}
+
+impl Reporter for GithubActionsReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut scopes: Vec<(Option<&str>, &[CheckEntry])> = vec![(None, &report.results)];
+        for member in &report.member_reports {
+            scopes.push((Some(&member.member), &member.results));
+        }
+
+        let mut output = String::new();
+        for (member, entries) in scopes {
+            for entry in entries {
+                let CheckResult::Fail { violations } = &entry.result else {
+                    continue;
+                };
+                for v in violations.iter().filter(|v| !v.suppressed) {
+                    let location = match &v.path {
+                        Some(path) => {
+                            let mut file = path.to_string_lossy().replace('\\', "/");
+                            if let Some(member) = member {
+                                file = format!("{}/{}", member.trim_end_matches('/'), file);
+                            }
+                            format!(" file={}", github_escape_property(&file))
+                        }
+                        None => String::new(),
+                    };
+                    output.push_str(&format!(
+                        "::{}{}::{}\n",
+                        github_command(&v.severity),
+                        location,
+                        github_escape_data(&v.message),
+                    ));
+                }
+            }
+        }
+        output
+    }
+}
+
+impl Reporter for CsvReporter {
+    fn report(&self, report: &ScanReport) -> String {
+        let mut scopes: Vec<(Option<&str>, &[CheckEntry])> = vec![(None, &report.results)];
+        for member in &report.member_reports {
+            scopes.push((Some(&member.member), &member.results));
+        }
+
+        let mut output = String::from("check_id,category,severity,file,line,message\r\n");
+        let mut row = |id: &str, category: &str, severity: &str, file: &str, message: &str| {
+            let fields = [id, category, severity, file, "", message].map(csv_field);
+            output.push_str(&fields.join(","));
+            output.push_str("\r\n");
+        };
+        for (member, entries) in scopes {
+            for entry in entries {
+                let id = entry.id.0.to_string();
+                match &entry.result {
+                    CheckResult::Fail { violations } => {
+                        for v in violations.iter().filter(|v| !v.suppressed) {
+                            let file = match (&v.path, member) {
+                                (Some(path), Some(member)) => format!(
+                                    "{}/{}",
+                                    member.trim_end_matches('/'),
+                                    path.to_string_lossy().replace('\\', "/")
+                                ),
+                                (Some(path), None) => path.to_string_lossy().replace('\\', "/"),
+                                (None, _) => String::new(),
+                            };
+                            row(&id, &entry.category, severity_name(&v.severity), &file, &v.message);
+                        }
+                    }
+                    CheckResult::Skip { reason } if self.include_skipped => {
+                        row(&id, &entry.category, "skipped", member.unwrap_or(""), reason);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Quote a CSV field per RFC 4180 when it contains a comma, quote, CR or LF.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn severity_name(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+/// Stable SARIF rule id for a check (`SE001`, `SE002`, ...).
+fn sarif_rule_id(entry: &CheckEntry) -> String {
+    format!("SE{:03}", entry.id.0)
+}
+
+fn sarif_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// Workflow command for a severity; `Info` maps to `notice`.
+fn github_command(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "notice",
+    }
+}
+
+/// Escape a workflow command message (the text after `::`).
+fn github_escape_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value (`file=`).
+fn github_escape_property(s: &str) -> String {
+    github_escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Escape the five XML special characters for attribute and text content.
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// JSON Schema (draft-07) of the report `JsonReporter` emits, derived from
+/// the serde types so it cannot drift from the serialized shape.
+pub fn report_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(ScanReport)).expect("JSON Schema is always serializable")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,9 +634,10 @@ mod tests {
         let skipped = entries.iter().filter(|e| matches!(e.result, CheckResult::Skip { .. })).count() as u8;
         ScanReport {
             results: entries,
-            summary: ScanSummary { total, passed, failed, skipped },
+            summary: ScanSummary { total, passed, failed, skipped, suppressed: 0 },
             project_kind: ProjectKind::Library,
             member_reports: vec![],
+            dependencies: vec![],
         }
     }
 
@@ -188,6 +679,7 @@ mod tests {
                         expected: None,
                         actual: None,
                         fix_hint: String::new(),
+                        suppressed: false,
                     }],
                 },
             },
@@ -241,6 +733,79 @@ mod tests {
         assert!(val.is_object());
     }
 
+    #[test]
+    fn test_jsonl_one_line_per_entry_then_summary() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "Cargo.toml exists".to_string(),
+                result: CheckResult::Pass,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "naming".to_string(),
+                description: "skip check".to_string(),
+                result: CheckResult::Skip { reason: "n/a".to_string() },
+            },
+        ]);
+        let output = JsonLinesReporter.report(&report);
+        let lines: Vec<serde_json::Value> = output.lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["type"], "check");
+        assert_eq!(lines[0]["id"], 1);
+        assert!(lines[0]["member"].is_null());
+        assert_eq!(lines[1]["result"]["status"], "skip");
+        assert_eq!(lines[2]["type"], "summary");
+        assert_eq!(lines[2]["summary"]["total"], 2);
+    }
+
+    #[test]
+    fn test_junit_output() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "Cargo.toml exists".to_string(),
+                result: CheckResult::Pass,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "naming".to_string(),
+                description: "snake_case <files>".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![Violation {
+                        check_id: CheckId(2),
+                        path: Some("src/Foo.rs".into()),
+                        message: "not snake_case".to_string(),
+                        severity: Severity::Error,
+                        rule_type: String::new(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: String::new(),
+                        suppressed: false,
+                    }],
+                },
+            },
+            CheckEntry {
+                id: CheckId(3),
+                category: "structure".to_string(),
+                description: "bin target".to_string(),
+                result: CheckResult::Skip { reason: "library".to_string() },
+            },
+        ]);
+        let xml = JunitReporter.report(&report);
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<testsuites name=\"struct-engine\" tests=\"3\" failures=\"1\" skipped=\"1\">"));
+        assert!(xml.contains("<testsuite name=\"naming\" tests=\"1\" failures=\"1\" skipped=\"0\">"));
+        assert!(xml.contains("name=\"2: snake_case &lt;files&gt;\""));
+        assert!(xml.contains("src/Foo.rs: not snake_case</failure>"));
+        assert!(xml.contains("<skipped message=\"library\"/>"));
+        assert!(xml.trim_end().ends_with("</testsuites>"));
+    }
+
     #[test]
     fn test_json_roundtrip() {
         let report = make_report(vec![
@@ -264,6 +829,7 @@ mod tests {
                         expected: None,
                         actual: None,
                         fix_hint: String::new(),
+                        suppressed: false,
                     }],
                 },
             },
@@ -275,4 +841,219 @@ mod tests {
         assert_eq!(deserialized.summary.passed, 1);
         assert_eq!(deserialized.summary.failed, 1);
     }
+
+    fn make_sarif_report() -> ScanReport {
+        make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "Cargo.toml exists at root".to_string(),
+                result: CheckResult::Pass,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "structure".to_string(),
+                description: "main/src/ directory exists".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![Violation {
+                        check_id: CheckId(2),
+                        path: Some("main\\src".into()),
+                        message: "main/src/ directory does not exist".to_string(),
+                        severity: Severity::Error,
+                        rule_type: String::new(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: String::new(),
+                        suppressed: false,
+                    }],
+                },
+            },
+            CheckEntry {
+                id: CheckId(3),
+                category: "structure".to_string(),
+                description: "bin target".to_string(),
+                result: CheckResult::Skip { reason: "library".to_string() },
+            },
+        ])
+    }
+
+    #[test]
+    fn test_sarif_output() {
+        let sarif = SarifReporter::with_default_rules(false).report(&make_sarif_report());
+        let val: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        assert_eq!(val["version"], "2.1.0");
+
+        let run = &val["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "struct-engine");
+
+        // Only the failing check produces a result; skipped checks never do
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "SE002");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["message"]["text"], "main/src/ directory does not exist");
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "main/src"
+        );
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        let index = results[0]["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(rules[index]["id"], "SE002");
+        assert_eq!(rules[index]["defaultConfiguration"]["level"], "error");
+        assert!(rules.iter().all(|r| r["id"] != "SE003"));
+    }
+
+    #[test]
+    fn test_sarif_include_passing() {
+        let sarif = SarifReporter::with_default_rules(true).report(&make_sarif_report());
+        let val: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        let results = val["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "SE001");
+        assert_eq!(results[0]["kind"], "pass");
+        assert!(results[0].get("locations").is_none());
+    }
+
+    #[test]
+    fn test_sarif_rules_from_custom_rule_set() {
+        let custom = parse_rules(r#"
+[[rules]]
+id = 2
+category = "structure"
+description = "main/src/ directory exists"
+severity = "warning"
+type = "dir_exists"
+path = "main/src"
+fix_hint = "Create main/src/"
+"#).unwrap();
+        let reporter = SarifReporter { rules: custom.rules, include_passing: false };
+        let val: serde_json::Value = serde_json::from_str(&reporter.report(&make_sarif_report())).unwrap();
+        let rules = val["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap();
+        let rule = rules.iter().find(|r| r["id"] == "SE002").unwrap();
+        assert_eq!(rule["defaultConfiguration"]["level"], "warning");
+        assert_eq!(rule["help"]["text"], "Create main/src/");
+    }
+
+    #[test]
+    fn test_workspace_text_prints_combined_summary_first() {
+        let member = make_report(vec![CheckEntry {
+            id: CheckId(2),
+            category: "structure".to_string(),
+            description: "main/src/ directory exists".to_string(),
+            result: CheckResult::Pass,
+        }]);
+        let report = WorkspaceReport {
+            summary: ScanSummary { total: 2, passed: 1, failed: 1, skipped: 0, suppressed: 0 },
+            root: make_sarif_report(),
+            members: vec![("crates/a".to_string(), member)],
+            virtual_workspace: true,
+            external_members: vec!["../shared".to_string()],
+        };
+        let text = workspace_text(&report);
+
+        let combined = text.find("Combined: 1/2 passed, 1 failed, 0 skipped").unwrap();
+        let root = text.find("\u{2550}\u{2550}\u{2550} . (").unwrap();
+        let member = text.find("\u{2550}\u{2550}\u{2550} crates/a (1/1 passed, 0 failed)").unwrap();
+        assert!(combined < root && root < member);
+        assert!(text.contains("(1 member(s), virtual)"));
+        assert!(text.contains("Not scanned (outside workspace): ../shared"));
+    }
+
+    #[test]
+    fn test_sarif_custom_rule_falls_back_to_entry() {
+        let report = make_report(vec![CheckEntry {
+            id: CheckId(1),
+            category: "custom".to_string(),
+            description: "README mentions license".to_string(),
+            result: CheckResult::Pass,
+        }]);
+        let sarif = SarifReporter::with_default_rules(true).report(&report);
+        let val: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        let rule = &val["runs"][0]["tool"]["driver"]["rules"][0];
+        assert_eq!(rule["shortDescription"]["text"], "README mentions license");
+        assert!(rule.get("defaultConfiguration").is_none());
+    }
+
+    fn github_violation(path: Option<&str>, message: &str, severity: Severity, suppressed: bool) -> Violation {
+        Violation {
+            check_id: CheckId(2),
+            path: path.map(Into::into),
+            message: message.to_string(),
+            severity,
+            rule_type: String::new(),
+            expected: None,
+            actual: None,
+            fix_hint: String::new(),
+            suppressed,
+        }
+    }
+
+    #[test]
+    fn test_github_actions_workflow_commands() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "Cargo.toml exists at root".to_string(),
+                result: CheckResult::Pass,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "structure".to_string(),
+                description: "main/src/ directory exists".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![
+                        github_violation(Some("main\\src"), "main/src/ directory does not exist", Severity::Error, false),
+                        github_violation(None, "edition is 2018\nuse 100% 2021", Severity::Warning, false),
+                        github_violation(Some("old.rs"), "baselined", Severity::Error, true),
+                    ],
+                },
+            },
+        ]);
+        let output = GithubActionsReporter.report(&report);
+
+        assert_eq!(
+            output,
+            "::error file=main/src::main/src/ directory does not exist\n\
+             ::warning::edition is 2018%0Ause 100%25 2021\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_quotes_commas_and_newlines() {
+        let report = make_report(vec![
+            CheckEntry {
+                id: CheckId(1),
+                category: "structure".to_string(),
+                description: "Cargo.toml exists at root".to_string(),
+                result: CheckResult::Pass,
+            },
+            CheckEntry {
+                id: CheckId(2),
+                category: "cargo_metadata".to_string(),
+                description: "edition is set".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![
+                        github_violation(Some("Cargo.toml"), "edition is \"2018\", expected\n2021", Severity::Warning, false),
+                        github_violation(Some("old.rs"), "baselined", Severity::Error, true),
+                    ],
+                },
+            },
+            CheckEntry {
+                id: CheckId(3),
+                category: "structure".to_string(),
+                description: "tests/ exists".to_string(),
+                result: CheckResult::Skip { reason: "not a library".to_string() },
+            },
+        ]);
+
+        assert_eq!(
+            CsvReporter { include_skipped: false }.report(&report),
+            "check_id,category,severity,file,line,message\r\n\
+             2,cargo_metadata,warning,Cargo.toml,,\"edition is \"\"2018\"\", expected\n2021\"\r\n"
+        );
+        let with_skipped = CsvReporter { include_skipped: true }.report(&report);
+        assert!(with_skipped.ends_with("3,structure,skipped,,,not a library\r\n"));
+    }
 }
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::path::Path;
+
 use serde::Deserialize;
 
 use crate::api::traits::CheckRunner;
@@ -31,6 +34,9 @@ struct RawRule {
     project_kind: Option<String>,
     key: Option<String>,
     fix_hint: Option<String>,
+    #[serde(default)]
+    params: toml::Table,
+    rationale: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,7 +63,7 @@ fn parse_project_kind(s: &str) -> Result<ProjectKind, ScanError> {
     }
 }
 
-fn convert_raw_rule(raw: RawRule) -> Result<RuleDef, ScanError> {
+fn convert_raw_rule(mut raw: RawRule) -> Result<RuleDef, ScanError> {
     let severity = parse_severity(&raw.severity)?;
     let project_kind = raw.project_kind.as_deref().map(parse_project_kind).transpose()?;
 
@@ -139,6 +145,12 @@ fn convert_raw_rule(raw: RawRule) -> Result<RuleDef, ScanError> {
             let handler = raw.handler.ok_or_else(|| ScanError::Config(
                 format!("Rule {}: builtin requires 'handler'", raw.id)
             ))?;
+            // Builtins read exclusions from params; honour the top-level key as well.
+            if let Some(paths) = raw.exclude_paths.take() {
+                raw.params.entry("exclude_paths").or_insert_with(|| {
+                    toml::Value::Array(paths.into_iter().map(toml::Value::String).collect())
+                });
+            }
             RuleType::Builtin { handler }
         }
         "cargo_key_exists" => {
@@ -169,21 +181,112 @@ fn convert_raw_rule(raw: RawRule) -> Result<RuleDef, ScanError> {
         rule_type,
         project_kind,
         fix_hint: raw.fix_hint,
+        params: raw.params,
+        rationale: raw.rationale,
     })
 }
 
+/// Collect the comment block documenting each rule, keyed by check id.
+///
+/// A block belongs to rule N when its banner names `(Check N)` and it is the
+/// last comment block before that rule's `[[rules]]` header; its text after the
+/// closing `# ===` line is the rationale, and a banner-only block yields none.
+/// Category banners such as `(Checks 1-8)` never match.
+fn comment_rationales(toml_str: &str) -> HashMap<u8, String> {
+    let mut rationales = HashMap::new();
+    let mut block: Vec<&str> = Vec::new();
+    let mut pending: Option<Vec<&str>> = None;
+    let mut in_block = false;
+
+    for line in toml_str.lines() {
+        let trimmed = line.trim();
+        if let Some(comment) = trimmed.strip_prefix('#') {
+            if !in_block {
+                block.clear();
+                in_block = true;
+            }
+            block.push(comment.trim());
+            continue;
+        }
+        in_block = false;
+
+        if trimmed == "[[rules]]" {
+            pending = Some(std::mem::take(&mut block));
+        } else if let Some(id) = trimmed.strip_prefix("id").and_then(|r| r.trim_start().strip_prefix('=')) {
+            let (Some(lines), Ok(id)) = (pending.take(), id.trim().parse::<u8>()) else { continue };
+            let banner = format!("(Check {})", id);
+            let Some(start) = lines.iter().position(|l| l.contains(&banner)) else { continue };
+            let body: Vec<&str> = lines[start + 1..].iter()
+                .copied()
+                .skip_while(|l| l.starts_with('=') || l.is_empty())
+                .collect();
+            let text = body.join("\n").trim().to_string();
+            if !text.is_empty() {
+                rationales.insert(id, text);
+            }
+        }
+    }
+    rationales
+}
+
 pub fn parse_rules(toml_str: &str) -> Result<RuleSet, ScanError> {
     let raw: RawRuleFile = toml::from_str(toml_str)
         .map_err(|e| ScanError::Config(format!("TOML parse error: {}", e)))?;
 
+    let mut comments = comment_rationales(toml_str);
     let mut rules = Vec::with_capacity(raw.rules.len());
     for raw_rule in raw.rules {
-        rules.push(convert_raw_rule(raw_rule)?);
+        let mut rule = convert_raw_rule(raw_rule)?;
+        if rule.rationale.is_none() {
+            rule.rationale = comments.remove(&rule.id);
+        }
+        rules.push(rule);
     }
 
     Ok(RuleSet { rules })
 }
 
+/// Read and parse a custom rules file.
+pub fn load_rules_file(path: &Path) -> Result<RuleSet, ScanError> {
+    let toml_str = std::fs::read_to_string(path).map_err(|e| {
+        ScanError::Config(format!("Cannot read rules file '{}': {}", path.display(), e))
+    })?;
+    parse_rules(&toml_str)
+}
+
+/// Overlay `custom` on `base` by check id.
+///
+/// A custom rule with an id already in `base` replaces that rule in place;
+/// new ids are appended in file order.
+pub fn merge_rules(base: RuleSet, custom: RuleSet) -> RuleSet {
+    let mut rules = base.rules;
+    for rule in custom.rules {
+        match rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule,
+            None => rules.push(rule),
+        }
+    }
+    RuleSet { rules }
+}
+
+/// Check that every builtin rule names a registered handler, listing all
+/// unknown names in one error.
+pub fn validate_handlers(rules: &[RuleDef]) -> Result<(), ScanError> {
+    let unknown: Vec<String> = rules.iter()
+        .filter_map(|def| match &def.rule_type {
+            RuleType::Builtin { handler } if builtins::get_handler(handler, def).is_none() => {
+                Some(format!("'{}' (rule {})", handler, def.id))
+            }
+            _ => None,
+        })
+        .collect();
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(ScanError::Config(format!("Unknown builtin handlers: {}", unknown.join(", "))))
+    }
+}
+
 pub fn build_registry(rules: &[RuleDef]) -> Result<Vec<Box<dyn CheckRunner>>, ScanError> {
     let mut runners: Vec<Box<dyn CheckRunner>> = Vec::with_capacity(rules.len());
 
@@ -305,6 +408,28 @@ type = "cargo_key_exists"
         assert!(matches!(parse_rules(toml).unwrap_err(), ScanError::Config(_)));
     }
 
+    #[test]
+    fn test_parse_builtin_params() {
+        let toml = r#"
+[[rules]]
+id = 47
+category = "structure"
+description = "d"
+severity = "warning"
+type = "builtin"
+handler = "max_module_depth"
+exclude_paths = ["main/src/fixtures/"]
+
+[rules.params]
+max_depth = 3
+"#;
+        let rs = parse_rules(toml).unwrap();
+        let params = &rs.rules[0].params;
+        assert_eq!(params.get("max_depth").and_then(|v| v.as_integer()), Some(3));
+        let excluded = params.get("exclude_paths").and_then(|v| v.as_array()).unwrap();
+        assert_eq!(excluded[0].as_str(), Some("main/src/fixtures/"));
+    }
+
     #[test]
     fn test_parse_default_rules_valid() {
         let rs = parse_rules(DEFAULT_RULES).unwrap();
@@ -321,6 +446,8 @@ type = "cargo_key_exists"
             rule_type: RuleType::FileExists { path: "Cargo.toml".to_string() },
             project_kind: None,
             fix_hint: None,
+            params: toml::Table::new(),
+            rationale: None,
         }];
         let reg = build_registry(&rules).unwrap();
         assert_eq!(reg.len(), 1);
@@ -337,6 +464,8 @@ type = "cargo_key_exists"
             rule_type: RuleType::Builtin { handler: "crate_root_exists".to_string() },
             project_kind: None,
             fix_hint: None,
+            params: toml::Table::new(),
+            rationale: None,
         }];
         let reg = build_registry(&rules).unwrap();
         assert_eq!(reg.len(), 1);
@@ -353,6 +482,8 @@ type = "cargo_key_exists"
             rule_type: RuleType::Builtin { handler: "nonexistent".to_string() },
             project_kind: None,
             fix_hint: None,
+            params: toml::Table::new(),
+            rationale: None,
         }];
         let result = build_registry(&rules);
         assert!(result.is_err());
@@ -369,6 +500,8 @@ type = "cargo_key_exists"
                 rule_type: RuleType::FileExists { path: "x".to_string() },
                 project_kind: None,
                 fix_hint: None,
+                params: toml::Table::new(),
+                rationale: None,
             },
             RuleDef {
                 id: 1,
@@ -378,6 +511,8 @@ type = "cargo_key_exists"
                 rule_type: RuleType::DirExists { path: "y".to_string() },
                 project_kind: None,
                 fix_hint: None,
+                params: toml::Table::new(),
+                rationale: None,
             },
         ];
         let reg = build_registry(&rules).unwrap();
@@ -391,4 +526,109 @@ type = "cargo_key_exists"
         let reg = build_registry(&rs.rules).unwrap();
         assert_eq!(reg.len(), default_rule_count());
     }
+
+    fn file_exists_rule(id: u8, path: &str) -> RuleDef {
+        RuleDef {
+            id,
+            category: "structure".to_string(),
+            description: format!("{} exists", path),
+            severity: Severity::Error,
+            rule_type: RuleType::FileExists { path: path.to_string() },
+            project_kind: None,
+            fix_hint: None,
+            params: toml::Table::new(),
+            rationale: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_rules_overrides_by_id() {
+        let base = RuleSet { rules: vec![file_exists_rule(1, "Cargo.toml"), file_exists_rule(2, "README.md")] };
+        let custom = RuleSet { rules: vec![file_exists_rule(2, "NOTICE"), file_exists_rule(60, "AUTHORS")] };
+        let merged = merge_rules(base, custom);
+        let ids: Vec<u8> = merged.rules.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![1, 2, 60]);
+        assert!(matches!(merged.rules[1].rule_type, RuleType::FileExists { ref path } if path == "NOTICE"));
+    }
+
+    #[test]
+    fn test_validate_handlers_lists_all_unknown() {
+        let toml = r#"
+[[rules]]
+id = 60
+category = "custom"
+description = "d"
+severity = "warning"
+type = "builtin"
+handler = "no_such_handler"
+
+[[rules]]
+id = 61
+category = "custom"
+description = "d"
+severity = "warning"
+type = "builtin"
+handler = "test_file_suffixes"
+
+[[rules]]
+id = 62
+category = "custom"
+description = "d"
+severity = "warning"
+type = "builtin"
+handler = "another_missing"
+"#;
+        let rs = parse_rules(toml).unwrap();
+        let err = validate_handlers(&rs.rules).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("'no_such_handler' (rule 60)"), "{}", msg);
+        assert!(msg.contains("'another_missing' (rule 62)"), "{}", msg);
+        assert!(!msg.contains("test_file_suffixes"), "{}", msg);
+    }
+
+    #[test]
+    fn test_validate_handlers_default_rules() {
+        let rs = parse_rules(DEFAULT_RULES).unwrap();
+        assert!(validate_handlers(&rs.rules).is_ok());
+    }
+
+    #[test]
+    fn test_rationale_from_comment_block() {
+        let rs = parse_rules(DEFAULT_RULES).unwrap();
+        let rule = |id: u8| rs.rules.iter().find(|r| r.id == id).unwrap();
+        assert!(rule(51).rationale.as_deref().unwrap().starts_with("Goes beyond Check 16"));
+        // Category banner only, or a banner without body text
+        assert_eq!(rule(1).rationale, None);
+        assert_eq!(rule(46).rationale, None);
+
+        let custom = parse_rules(r#"
+[[rules]]
+id = 60
+category = "structure"
+description = "custom"
+severity = "warning"
+type = "file_exists"
+path = "README.md"
+rationale = "Set in the rule itself."
+"#).unwrap();
+        assert_eq!(custom.rules[0].rationale.as_deref(), Some("Set in the rule itself."));
+    }
+
+    #[test]
+    fn test_every_builtin_handler_has_a_listed_rule() {
+        let handler_re = regex::Regex::new(r#"^\s*"(\w+)" => Some\(Box::new"#).unwrap();
+        let registered: Vec<&str> = include_str!("builtins/mod.rs")
+            .lines()
+            .filter_map(|l| handler_re.captures(l).and_then(|c| c.get(1)).map(|m| m.as_str()))
+            .collect();
+        assert!(registered.len() > 20, "handler extraction found only {}", registered.len());
+
+        // Active rules, plus the opt-in rules documented as commented-out blocks
+        let listed: std::collections::HashSet<&str> = DEFAULT_RULES.lines()
+            .filter_map(|l| l.trim_start_matches(['#', ' ']).strip_prefix("handler = "))
+            .map(|h| h.trim_matches('"'))
+            .collect();
+        let unlisted: Vec<&str> = registered.into_iter().filter(|h| !listed.contains(h)).collect();
+        assert!(unlisted.is_empty(), "handlers without a rule in rules.toml: {:?}", unlisted);
+    }
 }
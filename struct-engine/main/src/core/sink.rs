@@ -1,8 +1,27 @@
+use std::io::Write;
 use std::path::PathBuf;
 
-use crate::api::traits::{ReportSink, Reporter};
-use crate::api::types::{ReportFormat, ScanError, ScanReport};
-use crate::core::reporter::{TextReporter, JsonReporter};
+use crate::api::traits::{ReportSink, Reporter, StreamingReportSink};
+use crate::api::types::{CheckEntry, ReportFormat, RuleDef, ScanError, ScanReport};
+use crate::core::reporter::{
+    jsonl_entry_line, jsonl_summary_line, CsvReporter, GithubActionsReporter, JsonLinesReporter,
+    JsonReporter, JunitReporter, SarifReporter, TextReporter,
+};
+
+/// Render a report with the reporter for `format`.
+pub(crate) fn render(format: ReportFormat, report: &ScanReport) -> String {
+    match format {
+        ReportFormat::Text => TextReporter.report(report),
+        ReportFormat::Json => JsonReporter.report(report),
+        ReportFormat::Junit => JunitReporter.report(report),
+        ReportFormat::JsonLines => JsonLinesReporter.report(report),
+        ReportFormat::GithubActions => GithubActionsReporter.report(report),
+        ReportFormat::Csv { include_skipped } => CsvReporter { include_skipped }.report(report),
+        ReportFormat::Sarif { include_passing } => {
+            SarifReporter::with_default_rules(include_passing).report(report)
+        }
+    }
+}
 
 /// Writes the formatted report to stdout.
 pub struct StdoutSink {
@@ -12,33 +31,94 @@ pub struct StdoutSink {
 
 impl ReportSink for StdoutSink {
     fn emit(&self, report: &ScanReport) -> Result<(), ScanError> {
-        let output = match self.format {
-            ReportFormat::Text => TextReporter.report(report),
-            ReportFormat::Json => JsonReporter.report(report),
-        };
-        print!("{}", output);
+        print!("{}", render(self.format, report));
+        Ok(())
+    }
+}
+
+/// Streams JSON Lines to stdout: one line per check entry as soon as it is
+/// computed, then a summary line once the scan finishes.
+///
+/// Each line is flushed immediately so consumers piping the output see
+/// results while a large workspace is still being scanned.
+pub struct JsonLinesSink;
+
+impl JsonLinesSink {
+    fn write_line(line: &str) -> Result<(), ScanError> {
+        let mut out = std::io::stdout().lock();
+        writeln!(out, "{}", line)?;
+        out.flush()?;
         Ok(())
     }
 }
 
-/// Writes the report as pretty-printed JSON to a file.
+impl StreamingReportSink for JsonLinesSink {
+    fn emit_entry(&self, member: Option<&str>, entry: &CheckEntry) -> Result<(), ScanError> {
+        Self::write_line(&jsonl_entry_line(member, entry))
+    }
+
+    fn finish(&self, report: &ScanReport) -> Result<(), ScanError> {
+        Self::write_line(&jsonl_summary_line(report))
+    }
+}
+
+/// Writes the formatted report to a file.
 ///
 /// Creates parent directories if they do not exist.
 pub struct FileSink {
     /// The file path to write the report to.
     pub path: PathBuf,
+    /// The output format to use.
+    pub format: ReportFormat,
 }
 
 impl ReportSink for FileSink {
     fn emit(&self, report: &ScanReport) -> Result<(), ScanError> {
-        let json = serde_json::to_string_pretty(report)
-            .map_err(|e| ScanError::Config(format!("JSON serialization failed: {}", e)))?;
+        let output = match self.format {
+            // Fail loudly rather than writing the JsonReporter's inline error object
+            ReportFormat::Json => serde_json::to_string_pretty(report)
+                .map_err(|e| ScanError::Config(format!("JSON serialization failed: {}", e)))?,
+            format => render(format, report),
+        };
         if let Some(parent) = self.path.parent() {
             if !parent.exists() {
                 std::fs::create_dir_all(parent)?;
             }
         }
-        std::fs::write(&self.path, &json)?;
+        std::fs::write(&self.path, &output)?;
+        Ok(())
+    }
+}
+
+/// Writes a SARIF report whose `rules` array describes the rule set the scan
+/// ran with, to `path` or to stdout when `path` is `None`.
+///
+/// `ReportFormat::Sarif` sinks describe the embedded default rules; use this
+/// sink when the scan loaded a custom rules file.
+pub struct SarifSink {
+    /// The file path to write the report to; `None` prints to stdout.
+    pub path: Option<PathBuf>,
+    /// The active rule definitions.
+    pub rules: Vec<RuleDef>,
+    /// Also emit a `pass` result for each passing check.
+    pub include_passing: bool,
+}
+
+impl ReportSink for SarifSink {
+    fn emit(&self, report: &ScanReport) -> Result<(), ScanError> {
+        let reporter = SarifReporter { rules: self.rules.clone(), include_passing: self.include_passing };
+        let output = reporter.report(report);
+        match &self.path {
+            Some(path) => {
+                if let Some(parent) = path.parent() {
+                    if !parent.exists() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                }
+                std::fs::write(path, &output)?;
+            }
+            None => print!("{}", output),
+        }
         Ok(())
     }
 }
@@ -62,6 +142,147 @@ impl ReportSink for KafkaSink {
     }
 }
 
+/// Schema for [`SqliteSink`]; the same `projects` and `scans` tables as the
+/// ui server's `db.rs` and doc-engine's SQLite sink.
+#[cfg(feature = "sqlite")]
+const SQLITE_SCHEMA: &str = "CREATE TABLE IF NOT EXISTS projects (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        root_path TEXT NOT NULL,
+        scope TEXT NOT NULL DEFAULT 'Small',
+        project_type TEXT NOT NULL DEFAULT 'OpenSource',
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        deleted INTEGER NOT NULL DEFAULT 0,
+        last_scan_id TEXT
+    );
+
+    CREATE TABLE IF NOT EXISTS scans (
+        id TEXT PRIMARY KEY,
+        project_id TEXT NOT NULL REFERENCES projects(id),
+        engine TEXT NOT NULL,
+        status TEXT NOT NULL DEFAULT 'queued',
+        started_at TEXT NOT NULL,
+        finished_at TEXT,
+        report_json TEXT,
+        config_json TEXT
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_scans_project ON scans(project_id, started_at DESC);";
+
+/// How long a writer waits for another scan's transaction on the same file.
+#[cfg(feature = "sqlite")]
+const SQLITE_BUSY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Appends the report as a completed scan to a SQLite database file.
+///
+/// Creates the file and schema on first use. `project_root` becomes a
+/// `projects` row (reused on later scans of the same root) and each report a
+/// `scans` row with `engine = "struct-engine"`. Writers take an immediate
+/// transaction and wait up to [`SQLITE_BUSY_TIMEOUT`] for one another, so
+/// concurrent scans appending to one file are serialized.
+#[cfg(feature = "sqlite")]
+pub struct SqliteSink {
+    /// The database file to append to.
+    pub path: PathBuf,
+    /// Absolute path of the scanned project, used to key the `projects` row.
+    pub project_root: PathBuf,
+}
+
+#[cfg(feature = "sqlite")]
+impl ReportSink for SqliteSink {
+    fn emit(&self, report: &ScanReport) -> Result<(), ScanError> {
+        use rusqlite::{params, Connection, OptionalExtension, TransactionBehavior};
+
+        let sql_err = |e: rusqlite::Error| {
+            ScanError::Config(format!("SQLite error in '{}': {}", self.path.display(), e))
+        };
+        let json = serde_json::to_string(report)
+            .map_err(|e| ScanError::Config(format!("JSON serialization failed: {}", e)))?;
+        let config_json = serde_json::json!({ "project_kind": report.project_kind }).to_string();
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let mut conn = Connection::open(&self.path).map_err(sql_err)?;
+        conn.busy_timeout(SQLITE_BUSY_TIMEOUT).map_err(sql_err)?;
+        conn.execute_batch("PRAGMA foreign_keys=ON;").map_err(sql_err)?;
+        conn.execute_batch(SQLITE_SCHEMA).map_err(sql_err)?;
+
+        let now = iso8601_now();
+        let root = self.project_root.display().to_string();
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate).map_err(sql_err)?;
+        let existing: Option<String> = tx
+            .query_row(
+                "SELECT id FROM projects WHERE root_path = ?1 AND deleted = 0",
+                params![root],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(sql_err)?;
+        let project_id = match existing {
+            Some(id) => id,
+            None => {
+                let id = uuid::Uuid::new_v4().to_string();
+                let name = self.project_root
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| root.clone());
+                tx.execute(
+                    "INSERT INTO projects (id, name, root_path, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?4)",
+                    params![id, name, root, now],
+                )
+                .map_err(sql_err)?;
+                id
+            }
+        };
+
+        let scan_id = uuid::Uuid::new_v4().to_string();
+        tx.execute(
+            "INSERT INTO scans (id, project_id, engine, status, started_at, finished_at, report_json, config_json)
+             VALUES (?1, ?2, 'struct-engine', 'completed', ?3, ?3, ?4, ?5)",
+            params![scan_id, project_id, now, json, config_json],
+        )
+        .map_err(sql_err)?;
+        tx.execute(
+            "UPDATE projects SET last_scan_id = ?1, updated_at = ?2 WHERE id = ?3",
+            params![scan_id, now, project_id],
+        )
+        .map_err(sql_err)?;
+        tx.commit().map_err(sql_err)?;
+        Ok(())
+    }
+}
+
+/// Current UTC time as ISO 8601 (e.g. "2026-02-10T14:30:00Z"), using Howard
+/// Hinnant's civil_from_days algorithm so no date crate is needed.
+#[cfg(feature = "sqlite")]
+fn iso8601_now() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let day_secs = secs % 86400;
+
+    let z = (secs / 86400) as i64 + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe as i64 + era * 400 + if m <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        y, m, d, day_secs / 3600, (day_secs % 3600) / 60, day_secs % 60
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,9 +303,11 @@ mod tests {
                 passed: 1,
                 failed: 0,
                 skipped: 0,
+                suppressed: 0,
             },
             project_kind: ProjectKind::Library,
             member_reports: vec![],
+            dependencies: vec![],
         }
     }
 
@@ -95,6 +318,20 @@ mod tests {
         assert!(sink.emit(&report).is_ok());
     }
 
+    #[test]
+    fn test_stdout_sink_jsonl() {
+        let sink = StdoutSink { format: ReportFormat::JsonLines };
+        let report = make_report();
+        assert!(sink.emit(&report).is_ok());
+    }
+
+    #[test]
+    fn test_jsonl_sink_streams_entries() {
+        let report = make_report();
+        assert!(JsonLinesSink.emit_entry(Some("crates/a"), &report.results[0]).is_ok());
+        assert!(JsonLinesSink.finish(&report).is_ok());
+    }
+
     #[test]
     fn test_stdout_sink_json() {
         let sink = StdoutSink { format: ReportFormat::Json };
@@ -109,7 +346,7 @@ mod tests {
         std::fs::create_dir_all(&dir).unwrap();
         let path = dir.join("report.json");
 
-        let sink = FileSink { path: path.clone() };
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Json };
         let report = make_report();
         sink.emit(&report).unwrap();
 
@@ -126,7 +363,7 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
         let path = dir.join("a").join("b").join("report.json");
 
-        let sink = FileSink { path: path.clone() };
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Json };
         let report = make_report();
         sink.emit(&report).unwrap();
 
@@ -142,7 +379,7 @@ mod tests {
         std::fs::create_dir_all(&dir).unwrap();
         let path = dir.join("report.json");
 
-        let sink = FileSink { path: path.clone() };
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Json };
         let report = make_report();
         sink.emit(&report).unwrap();
 
@@ -155,6 +392,62 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn test_file_sink_junit() {
+        let dir = std::env::temp_dir().join("struct_engine_sink_test_junit");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("junit.xml");
+
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Junit };
+        let report = make_report();
+        sink.emit(&report).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("<testsuites name=\"struct-engine\" tests=\"1\""));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_file_sink_sarif() {
+        let dir = std::env::temp_dir().join("struct_engine_sink_test_sarif");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("results.sarif");
+
+        let sink = FileSink { path: path.clone(), format: ReportFormat::Sarif { include_passing: true } };
+        let report = make_report();
+        sink.emit(&report).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let sarif: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(sarif["runs"][0]["results"][0]["kind"], "pass");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_sarif_sink_uses_active_rules() {
+        let dir = std::env::temp_dir().join("struct_engine_sink_test_sarif_rules");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("results.sarif");
+
+        // A custom rule set that downgrades check 1 to info
+        let mut rule = crate::core::rules::parse_rules(crate::core::rules::DEFAULT_RULES).unwrap().rules.remove(0);
+        rule.description = "Cargo.toml exists".to_string();
+        rule.severity = crate::api::types::Severity::Info;
+        let sink = SarifSink { path: Some(path.clone()), rules: vec![rule], include_passing: true };
+        sink.emit(&make_report()).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let sarif: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        let rule = &sarif["runs"][0]["tool"]["driver"]["rules"][0];
+        assert_eq!(rule["id"], "SE001");
+        assert_eq!(rule["defaultConfiguration"]["level"], "note");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[cfg(feature = "kafka")]
     #[test]
     fn test_kafka_sink_construction() {
@@ -182,4 +475,40 @@ mod tests {
         let result = sink.emit(&report);
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_sink_serializes_concurrent_scans() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("structure.sqlite");
+        let project_root = dir.path().join("my_crate");
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let sink = super::SqliteSink { path: path.clone(), project_root: project_root.clone() };
+                std::thread::spawn(move || sink.emit(&make_report()))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap().unwrap();
+        }
+
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        let (projects, name): (i64, String) = conn
+            .query_row("SELECT COUNT(*), MAX(name) FROM projects", [], |r| Ok((r.get(0)?, r.get(1)?)))
+            .unwrap();
+        assert_eq!(projects, 1);
+        assert_eq!(name, "my_crate");
+
+        let mut stmt = conn.prepare("SELECT engine, status, report_json FROM scans").unwrap();
+        let rows: Vec<(String, String, String)> = stmt
+            .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|(engine, status, _)| engine == "struct-engine" && status == "completed"));
+        let stored: serde_json::Value = serde_json::from_str(&rows[0].2).unwrap();
+        assert_eq!(stored["summary"]["passed"], 1);
+    }
 }
@@ -1,12 +1,14 @@
 use std::path::PathBuf;
 use std::process;
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 
-use struct_engine::{scan_with_config, ScanConfig, ProjectKind, StdoutSink, ReportFormat};
+use struct_engine::{format_workspace_text, load_rules, report_schema, scan_streaming, scan_with_config, scan_workspace, write_baseline, ConfigFile, RuleDef, RuleSet, ScanConfig, ScanReport, ScanSummary, WorkspaceReport, Severity, ProjectKind, StdoutSink, FileSink, JsonLinesSink, SarifSink, ReportFormat};
 use struct_engine::api::traits::ReportSink;
 #[cfg(feature = "kafka")]
 use struct_engine::{KafkaConfig, KafkaSink};
+#[cfg(feature = "sqlite")]
+use struct_engine::SqliteSink;
 
 #[derive(Parser)]
 #[command(name = "struct-engine", version, about = "Rust package structure compliance engine")]
@@ -18,59 +20,149 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Scan a Rust project for structure compliance
-    Scan {
-        /// Path to the project root
-        path: PathBuf,
+    Scan(Box<ScanArgs>),
+    /// Print the JSON Schema of the `--json` scan report
+    Schema,
+    /// List the rules in the active set or explain one of them
+    Rules {
+        #[command(subcommand)]
+        action: RulesAction,
+    },
+}
 
-        /// Output as JSON
-        #[arg(long)]
-        json: bool,
+#[derive(Args)]
+struct ScanArgs {
+    /// Path to the project root
+    path: PathBuf,
+
+    /// Output as JSON (shorthand for `--format json`)
+    #[arg(long, conflicts_with_all = ["github", "csv"])]
+    json: bool,
+
+    /// Emit GitHub Actions annotations (shorthand for `--format github`)
+    #[arg(long, conflicts_with = "csv")]
+    github: bool,
+
+    /// Output one CSV row per violation (shorthand for `--format csv`)
+    #[arg(long)]
+    csv: bool,
+
+    /// Stdout format: text, json, jsonl, junit, sarif, github, or csv
+    #[arg(long, value_name = "FORMAT", default_value = "text")]
+    format: String,
+
+    /// Write the JSON report to a file (repeatable)
+    #[arg(long = "output", value_name = "PATH")]
+    outputs: Vec<PathBuf>,
+
+    /// Write a JUnit XML report to a file (repeatable)
+    #[arg(long = "junit-output", value_name = "PATH")]
+    junit_outputs: Vec<PathBuf>,
+
+    /// Write a SARIF 2.1.0 report to a file (repeatable)
+    #[arg(long = "sarif-output", value_name = "PATH")]
+    sarif_outputs: Vec<PathBuf>,
+
+    /// Include passing checks as `pass` results in SARIF output
+    #[arg(long = "sarif-passing")]
+    sarif_passing: bool,
+
+    /// Include skipped checks as `skipped` rows in CSV output
+    #[arg(long = "csv-skipped")]
+    csv_skipped: bool,
+
+    /// Run only specific checks (e.g., "1-13" or "1,5,10")
+    #[arg(long)]
+    checks: Option<String>,
+
+    /// Project kind: library, binary, both, or workspace
+    #[arg(long, value_name = "KIND")]
+    kind: Option<String>,
+
+    /// Path to a custom rules file, merged over the embedded rules by check id
+    #[arg(long)]
+    rules: Option<PathBuf>,
+
+    /// Config file to read instead of the nearest struct-engine.toml above the project path
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Recursively scan workspace members
+    #[arg(long)]
+    recursive: bool,
+
+    /// Include the declared dependency inventory in the report
+    #[arg(long = "with-deps")]
+    with_deps: bool,
+
+    /// Suppress violations recorded in this baseline file
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Write the current violations to a baseline file
+    #[arg(long = "write-baseline", value_name = "PATH")]
+    write_baseline_path: Option<PathBuf>,
+
+    /// Exit 1 only if a violation at or above this severity exists: info, warning, or error
+    #[arg(long = "fail-on", value_name = "SEVERITY")]
+    fail_on: Option<String>,
+
+    /// Append the scan to a SQLite history file, creating it on first use
+    #[cfg(feature = "sqlite")]
+    #[arg(long, value_name = "PATH")]
+    db: Option<PathBuf>,
+
+    /// Path to kafka.toml config file
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-config", value_name = "PATH")]
+    kafka_config: Option<PathBuf>,
+
+    /// Kafka broker address (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-broker", value_name = "ADDR")]
+    kafka_broker: Option<String>,
+
+    /// Kafka topic name (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-topic", value_name = "TOPIC")]
+    kafka_topic: Option<String>,
+
+    /// Kafka client ID (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-client-id", value_name = "ID")]
+    kafka_client_id: Option<String>,
+
+    /// Kafka partition (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-partition", value_name = "N")]
+    kafka_partition: Option<i32>,
+
+    /// Kafka produce timeout in ms (overrides config/env)
+    #[cfg(feature = "kafka")]
+    #[arg(long = "kafka-timeout", value_name = "MS")]
+    kafka_timeout: Option<i32>,
+}
 
-        /// Run only specific checks (e.g., "1-13" or "1,5,10")
+#[derive(Subcommand)]
+enum RulesAction {
+    /// Print id, handler, category, severity and description of every rule
+    List {
+        /// Path to a custom rules file, merged over the embedded rules by check id
         #[arg(long)]
-        checks: Option<String>,
-
-        /// Project kind: library, binary, both, or workspace
-        #[arg(long, value_name = "KIND")]
-        kind: Option<String>,
+        rules: Option<PathBuf>,
 
-        /// Path to custom rules file
+        /// Output as JSON
         #[arg(long)]
-        rules: Option<PathBuf>,
+        json: bool,
+    },
+    /// Print the full rationale of one rule and the files it inspects
+    Explain {
+        /// Check id (e.g. 51)
+        id: u8,
 
-        /// Recursively scan workspace members
+        /// Path to a custom rules file, merged over the embedded rules by check id
         #[arg(long)]
-        recursive: bool,
-
-        /// Path to kafka.toml config file
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-config", value_name = "PATH")]
-        kafka_config: Option<PathBuf>,
-
-        /// Kafka broker address (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-broker", value_name = "ADDR")]
-        kafka_broker: Option<String>,
-
-        /// Kafka topic name (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-topic", value_name = "TOPIC")]
-        kafka_topic: Option<String>,
-
-        /// Kafka client ID (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-client-id", value_name = "ID")]
-        kafka_client_id: Option<String>,
-
-        /// Kafka partition (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-partition", value_name = "N")]
-        kafka_partition: Option<i32>,
-
-        /// Kafka produce timeout in ms (overrides config/env)
-        #[cfg(feature = "kafka")]
-        #[arg(long = "kafka-timeout", value_name = "MS")]
-        kafka_timeout: Option<i32>,
+        rules: Option<PathBuf>,
     },
 }
 
@@ -102,9 +194,126 @@ fn parse_checks(input: &str) -> Result<Vec<u8>, String> {
     Ok(result)
 }
 
+fn severity_name(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+/// Render the rule table printed by `struct-engine rules list`.
+fn format_rules_text(rules: &RuleSet) -> String {
+    let mut out = String::new();
+    for rule in &rules.rules {
+        out.push_str(&format!(
+            "{:>3}  {:<36} {:<15} {:<8} {}\n",
+            rule.id,
+            rule.rule_type.to_tag(),
+            rule.category,
+            severity_name(&rule.severity),
+            rule.description,
+        ));
+    }
+    out.push_str(&format!("\n{} rules\n", rules.rules.len()));
+    out
+}
+
+/// The rule list as JSON objects for `struct-engine rules list --json`.
+fn rules_to_json(rules: &RuleSet) -> serde_json::Value {
+    serde_json::Value::Array(rules.rules.iter().map(|rule| serde_json::json!({
+        "id": rule.id,
+        "handler": rule.rule_type.to_tag(),
+        "category": rule.category,
+        "severity": severity_name(&rule.severity),
+        "description": rule.description,
+    })).collect())
+}
+
+/// Render the detail printed by `struct-engine rules explain`.
+fn format_rule_explain(rule: &RuleDef) -> String {
+    let mut out = format!("Check {}: {}\n\n", rule.id, rule.description);
+    out.push_str(&format!("  handler:  {}\n", rule.rule_type.to_tag()));
+    out.push_str(&format!("  category: {}\n", rule.category));
+    out.push_str(&format!("  severity: {}\n", severity_name(&rule.severity)));
+    if let Some(kind) = &rule.project_kind {
+        out.push_str(&format!("  kind:     {:?}\n", kind).to_lowercase());
+    }
+    let inspects = match rule.rule_type.target() {
+        Some(target) => target.to_string(),
+        None => "chosen by the handler (see rationale)".to_string(),
+    };
+    out.push_str(&format!("  inspects: {}\n", inspects));
+    out.push_str(&format!("  fix hint: {}\n", rule.fix_hint.clone()
+        .unwrap_or_else(|| rule.rule_type.auto_fix_hint())));
+    out.push_str("\nRationale:\n");
+    match &rule.rationale {
+        Some(text) => {
+            for line in text.lines() {
+                out.push_str(&format!("  {}\n", line));
+            }
+        }
+        None => out.push_str(&format!("  {}\n", rule.description)),
+    }
+    out
+}
+
+/// Exit code for a completed scan. Without a threshold any failed check
+/// exits 1; with one, only an unsuppressed violation at or above it does.
+fn exit_code(report: &ScanReport, fail_on: Option<&Severity>) -> i32 {
+    failing_exit_code(&report.summary, report.max_severity(), fail_on)
+}
+
+/// Exit code for a recursive scan: like [`exit_code`], but over the combined
+/// summary and the violations of every member.
+fn workspace_exit_code(report: &WorkspaceReport, fail_on: Option<&Severity>) -> i32 {
+    failing_exit_code(&report.summary, report.max_severity(), fail_on)
+}
+
+fn failing_exit_code(summary: &ScanSummary, max_severity: Option<Severity>, fail_on: Option<&Severity>) -> i32 {
+    let failing = match fail_on {
+        Some(threshold) => max_severity.is_some_and(|s| s >= *threshold),
+        None => summary.failed > 0,
+    };
+    if failing { 1 } else { 0 }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use struct_engine::{CheckEntry, CheckId, CheckResult, ScanSummary, Violation};
+
+    fn report_with(severities: &[Severity]) -> ScanReport {
+        let results: Vec<CheckEntry> = severities.iter().enumerate().map(|(i, sev)| {
+            let id = CheckId(i as u8 + 1);
+            CheckEntry {
+                id,
+                category: "structure".to_string(),
+                description: "test".to_string(),
+                result: CheckResult::Fail {
+                    violations: vec![Violation {
+                        check_id: id,
+                        path: None,
+                        message: "bad".to_string(),
+                        severity: sev.clone(),
+                        rule_type: String::new(),
+                        expected: None,
+                        actual: None,
+                        fix_hint: String::new(),
+                        suppressed: false,
+                    }],
+                },
+            }
+        }).collect();
+        let failed = results.len() as u8;
+        ScanReport {
+            results,
+            summary: ScanSummary { total: failed, passed: 0, failed, skipped: 0, suppressed: 0 },
+            project_kind: ProjectKind::Library,
+            member_reports: vec![],
+            dependencies: vec![],
+        }
+    }
 
     #[test]
     fn test_parse_single() {
@@ -135,27 +344,50 @@ mod tests {
     fn test_parse_invalid_number() {
         assert!(parse_checks("abc").is_err());
     }
+
+    #[test]
+    fn test_exit_code_default_fails_on_any_failure() {
+        assert_eq!(exit_code(&report_with(&[Severity::Info]), None), 1);
+        assert_eq!(exit_code(&report_with(&[]), None), 0);
+    }
+
+    #[test]
+    fn test_exit_code_fail_on_error_ignores_warnings() {
+        let report = report_with(&[Severity::Warning, Severity::Info]);
+        assert_eq!(exit_code(&report, Some(&Severity::Error)), 0);
+    }
+
+    #[test]
+    fn test_exit_code_fail_on_warning() {
+        let report = report_with(&[Severity::Warning, Severity::Info]);
+        assert_eq!(exit_code(&report, Some(&Severity::Warning)), 1);
+        assert_eq!(exit_code(&report_with(&[Severity::Info]), Some(&Severity::Warning)), 0);
+    }
 }
 
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Scan {
-            path, json, checks, kind, rules, recursive,
-            #[cfg(feature = "kafka")]
-            kafka_config,
-            #[cfg(feature = "kafka")]
-            kafka_broker,
-            #[cfg(feature = "kafka")]
-            kafka_topic,
-            #[cfg(feature = "kafka")]
-            kafka_client_id,
-            #[cfg(feature = "kafka")]
-            kafka_partition,
-            #[cfg(feature = "kafka")]
-            kafka_timeout,
-        } => {
+        Commands::Scan(args) => {
+            let ScanArgs {
+                path, json, github, csv, format, outputs, junit_outputs, sarif_outputs, sarif_passing, csv_skipped, checks, kind, rules, config, recursive, with_deps,
+                baseline, write_baseline_path, fail_on,
+                #[cfg(feature = "sqlite")]
+                db,
+                #[cfg(feature = "kafka")]
+                kafka_config,
+                #[cfg(feature = "kafka")]
+                kafka_broker,
+                #[cfg(feature = "kafka")]
+                kafka_topic,
+                #[cfg(feature = "kafka")]
+                kafka_client_id,
+                #[cfg(feature = "kafka")]
+                kafka_partition,
+                #[cfg(feature = "kafka")]
+                kafka_timeout,
+            } = *args;
             // Canonicalize path early so auto-detection can read Cargo.toml
             let root = match path.canonicalize() {
                 Ok(p) => p,
@@ -165,6 +397,26 @@ fn main() {
                 }
             };
 
+            // Resolution order: defaults -> config file -> env -> CLI flags
+            let mut file_config = match config.or_else(|| ConfigFile::discover(&root)) {
+                Some(ref p) => match ConfigFile::from_file(p) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        process::exit(2);
+                    }
+                },
+                None => ConfigFile::default(),
+            };
+            file_config.merge_env();
+
+            let kind = kind.or(file_config.kind);
+            let checks = checks.or(file_config.checks);
+            let rules = rules.or(file_config.rules);
+            let baseline = baseline.or(file_config.baseline);
+            let recursive = recursive || file_config.recursive;
+            let with_deps = with_deps || file_config.with_deps;
+
             // Parse project kind: explicit --kind overrides auto-detection
             let pk = match kind.as_deref() {
                 Some("library") | Some("lib") => Some(ProjectKind::Library),
@@ -178,6 +430,37 @@ fn main() {
                 }
             };
 
+            // Resolve stdout format: --json, --github and --csv are shorthands for --format
+            let stdout_format = if json {
+                ReportFormat::Json
+            } else if github {
+                ReportFormat::GithubActions
+            } else if csv {
+                ReportFormat::Csv { include_skipped: csv_skipped }
+            } else {
+                match ReportFormat::from_name(&format) {
+                    Some(ReportFormat::Sarif { .. }) => ReportFormat::Sarif { include_passing: sarif_passing },
+                    Some(ReportFormat::Csv { .. }) => ReportFormat::Csv { include_skipped: csv_skipped },
+                    Some(f) => f,
+                    None => {
+                        eprintln!("Error: unknown format '{}' (use 'text', 'json', 'jsonl', 'junit', 'sarif', 'github', or 'csv')", format);
+                        process::exit(2);
+                    }
+                }
+            };
+
+            // Parse --fail-on threshold; omitted keeps "any failed check exits 1"
+            let fail_on = match fail_on.as_deref() {
+                Some(name) => match Severity::from_name(name) {
+                    Some(sev) => Some(sev),
+                    None => {
+                        eprintln!("Error: unknown severity '{}' (use 'info', 'warning', or 'error')", name);
+                        process::exit(2);
+                    }
+                },
+                None => None,
+            };
+
             // Parse check filter
             let check_ids = match checks {
                 Some(ref s) => match parse_checks(s) {
@@ -195,61 +478,133 @@ fn main() {
                 checks: check_ids,
                 rules_path: rules,
                 recursive,
+                with_deps,
+                baseline_path: baseline,
             };
 
-            match scan_with_config(&root, &config) {
-                Ok(report) => {
-                    let format = if json { ReportFormat::Json } else { ReportFormat::Text };
-                    let stdout_sink = StdoutSink { format };
-                    if let Err(e) = stdout_sink.emit(&report) {
+            // Every sink receives the same report from a single scan; jsonl
+            // streams to stdout while scanning instead of via a stdout sink,
+            // and recursive text output prints the workspace rollup instead
+            let streaming = stdout_format == ReportFormat::JsonLines;
+            let workspace_text = recursive && stdout_format == ReportFormat::Text;
+            let sarif_stdout = matches!(stdout_format, ReportFormat::Sarif { .. });
+
+            // SARIF describes the active rule set, including a custom --rules file
+            let sarif_rules: Vec<RuleDef> = if sarif_stdout || !sarif_outputs.is_empty() {
+                match load_rules(config.rules_path.as_deref()) {
+                    Ok(set) => set.rules,
+                    Err(e) => {
                         eprintln!("Error: {}", e);
                         process::exit(2);
                     }
+                }
+            } else {
+                Vec::new()
+            };
+
+            let mut sinks: Vec<Box<dyn ReportSink>> = Vec::new();
+            if sarif_stdout {
+                sinks.push(Box::new(SarifSink {
+                    path: None,
+                    rules: sarif_rules.clone(),
+                    include_passing: sarif_passing,
+                }));
+            } else if !streaming && !workspace_text {
+                sinks.push(Box::new(StdoutSink { format: stdout_format }));
+            }
+            for path in outputs {
+                sinks.push(Box::new(FileSink { path, format: ReportFormat::Json }));
+            }
+            for path in junit_outputs {
+                sinks.push(Box::new(FileSink { path, format: ReportFormat::Junit }));
+            }
+            for path in sarif_outputs {
+                sinks.push(Box::new(SarifSink {
+                    path: Some(path),
+                    rules: sarif_rules.clone(),
+                    include_passing: sarif_passing,
+                }));
+            }
+
+            // SQLite sink: append to the local scan history when --db is set
+            #[cfg(feature = "sqlite")]
+            if let Some(path) = db {
+                sinks.push(Box::new(SqliteSink { path, project_root: root.clone() }));
+            }
 
-                    // Kafka sink: emit report if any kafka flag is present
-                    #[cfg(feature = "kafka")]
-                    {
-                        let has_kafka = kafka_config.is_some()
-                            || kafka_broker.is_some()
-                            || kafka_topic.is_some()
-                            || kafka_client_id.is_some()
-                            || kafka_partition.is_some()
-                            || kafka_timeout.is_some();
-
-                        if has_kafka {
-                            // Resolution order: file -> env -> CLI flags
-                            let mut kconfig = match kafka_config {
-                                Some(ref p) => match KafkaConfig::from_file(p) {
-                                    Ok(c) => c,
-                                    Err(e) => {
-                                        eprintln!("Error: {}", e);
-                                        process::exit(2);
-                                    }
-                                },
-                                None => KafkaConfig::default(),
-                            };
-
-                            kconfig.merge_env();
-
-                            if let Some(ref v) = kafka_broker { kconfig.broker = v.clone(); }
-                            if let Some(ref v) = kafka_topic { kconfig.topic = v.clone(); }
-                            if let Some(ref v) = kafka_client_id { kconfig.client_id = v.clone(); }
-                            if let Some(v) = kafka_partition { kconfig.partition = v; }
-                            if let Some(v) = kafka_timeout { kconfig.timeout_ms = v; }
-
-                            let kafka_sink = KafkaSink { config: kconfig };
-                            if let Err(e) = kafka_sink.emit(&report) {
-                                eprintln!("Kafka error: {}", e);
+            // Kafka sink: emit report if any kafka flag is present
+            #[cfg(feature = "kafka")]
+            {
+                let has_kafka = kafka_config.is_some()
+                    || kafka_broker.is_some()
+                    || kafka_topic.is_some()
+                    || kafka_client_id.is_some()
+                    || kafka_partition.is_some()
+                    || kafka_timeout.is_some();
+
+                if has_kafka {
+                    // Resolution order: file -> env -> CLI flags
+                    let mut kconfig = match kafka_config {
+                        Some(ref p) => match KafkaConfig::from_file(p) {
+                            Ok(c) => c,
+                            Err(e) => {
+                                eprintln!("Error: {}", e);
                                 process::exit(2);
                             }
+                        },
+                        None => KafkaConfig::default(),
+                    };
+
+                    kconfig.merge_env();
+
+                    if let Some(ref v) = kafka_broker { kconfig.broker = v.clone(); }
+                    if let Some(ref v) = kafka_topic { kconfig.topic = v.clone(); }
+                    if let Some(ref v) = kafka_client_id { kconfig.client_id = v.clone(); }
+                    if let Some(v) = kafka_partition { kconfig.partition = v; }
+                    if let Some(v) = kafka_timeout { kconfig.timeout_ms = v; }
+
+                    sinks.push(Box::new(KafkaSink { config: kconfig }));
+                }
+            }
+
+            // Recursive scans (except streaming) go through the workspace
+            // scan so the combined summary drives output and the exit code
+            let result = if streaming {
+                scan_streaming(&root, &config, &JsonLinesSink).map(|r| (r, None))
+            } else if recursive {
+                scan_workspace(&root, &config).map(|ws| (ws.to_scan_report(), Some(ws)))
+            } else {
+                scan_with_config(&root, &config).map(|r| (r, None))
+            };
+            match result {
+                Ok((report, workspace)) => {
+                    if let Some(ws) = workspace.as_ref().filter(|_| workspace_text) {
+                        print!("{}", format_workspace_text(ws));
+                    }
+
+                    if let Some(ref path) = write_baseline_path {
+                        if let Err(e) = write_baseline(&report, path) {
+                            eprintln!("Error: cannot write baseline '{}': {}", path.display(), e);
+                            process::exit(2);
                         }
                     }
 
-                    if report.summary.failed > 0 {
-                        process::exit(1);
-                    } else {
-                        process::exit(0);
+                    // Emit to every sink even if one fails, then report the failure
+                    let mut sink_failed = false;
+                    for sink in &sinks {
+                        if let Err(e) = sink.emit(&report) {
+                            eprintln!("Error: {}", e);
+                            sink_failed = true;
+                        }
                     }
+                    if sink_failed {
+                        process::exit(2);
+                    }
+
+                    process::exit(match workspace {
+                        Some(ws) => workspace_exit_code(&ws, fail_on.as_ref()),
+                        None => exit_code(&report, fail_on.as_ref()),
+                    });
                 }
                 Err(e) => {
                     eprintln!("Error: {}", e);
@@ -257,5 +612,37 @@ fn main() {
                 }
             }
         }
+        Commands::Rules { action } => {
+            let rules_path = match &action {
+                RulesAction::List { rules, .. } | RulesAction::Explain { rules, .. } => rules.clone(),
+            };
+            let ruleset = match load_rules(rules_path.as_deref()) {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(2);
+                }
+            };
+            match action {
+                RulesAction::List { json: true, .. } => {
+                    println!("{}", serde_json::to_string_pretty(&rules_to_json(&ruleset)).unwrap_or_default());
+                }
+                RulesAction::List { .. } => print!("{}", format_rules_text(&ruleset)),
+                RulesAction::Explain { id, .. } => match ruleset.rules.iter().find(|r| r.id == id) {
+                    Some(rule) => print!("{}", format_rule_explain(rule)),
+                    None => {
+                        eprintln!("Error: no rule with id {}", id);
+                        process::exit(2);
+                    }
+                },
+            }
+        }
+        Commands::Schema => match serde_json::to_string_pretty(&report_schema()) {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("Error: JSON serialization failed: {}", e);
+                process::exit(2);
+            }
+        },
     }
 }
@@ -1,30 +1,48 @@
 use std::path::Path;
 
-use crate::api::traits::{ComplianceEngine, Reporter};
+use crate::api::traits::{ComplianceEngine, Reporter, StreamingReportSink};
 use crate::core::engine::StructComplianceEngine;
-use crate::core::reporter::{TextReporter, JsonReporter};
+use crate::core::reporter::{TextReporter, JsonReporter, workspace_text};
+use crate::core::reporter;
 
 // Re-export all public types from API
 pub use crate::api::types::{
     CheckId, CheckResult, ProjectKind, Severity, Violation, ScanContext, ScanError,
     ScanConfig, ScanReport, ScanSummary, CheckEntry, RuleSet, RuleDef, RuleType,
-    CargoManifest, BinTarget, TestTarget, BenchTarget, ExampleTarget,
-    MemberReport, FileIndex, ReportFormat,
+    CargoManifest, BinTarget, TestTarget, BenchTarget, ExampleTarget, DepInfo, DepKind, DepSource,
+    MemberReport, FileIndex, ReportFormat, BaselineEntry, WorkspaceReport, ProgressEvent,
 };
 
+// Re-export config file discovery and loading
+pub use crate::core::config_file::{ConfigFile, CONFIG_FILE_NAME};
+
 // Re-export sink implementations
-pub use crate::core::sink::{StdoutSink, FileSink};
+pub use crate::core::sink::{StdoutSink, FileSink, JsonLinesSink, SarifSink};
 #[cfg(feature = "kafka")]
 pub use crate::core::sink::KafkaSink;
+#[cfg(feature = "sqlite")]
+pub use crate::core::sink::SqliteSink;
 #[cfg(feature = "kafka")]
 pub use swe_messaging::KafkaConfig;
 
 // Re-export detect_project_kind for library consumers
 pub use crate::core::engine::detect_project_kind;
 
+// Re-export baseline helpers (--baseline / --write-baseline)
+pub use crate::core::engine::{load_baseline, baseline_entries, write_baseline};
+
 // Re-export rule_count for test assertions
 pub use crate::core::rules::default_rule_count;
 
+/// Load the rule set a scan would use: the embedded rules, with the custom
+/// rules file at `rules_path` merged over them by check id.
+///
+/// Backs `struct-engine rules list` and `struct-engine rules explain`.
+pub fn load_rules(rules_path: Option<&Path>) -> Result<RuleSet, ScanError> {
+    let config = ScanConfig { rules_path: rules_path.map(Path::to_path_buf), ..ScanConfig::default() };
+    crate::core::engine::load_ruleset(&config)
+}
+
 /// Scan a project directory using default configuration.
 ///
 /// Equivalent to calling [`scan_with_config`] with [`ScanConfig::default()`].
@@ -40,6 +58,50 @@ pub fn scan_with_config(root: &Path, config: &ScanConfig) -> Result<ScanReport,
     StructComplianceEngine.scan_with_config(root, config)
 }
 
+/// Scan a project directory, reporting progress as checks start and finish.
+///
+/// Checks still run in parallel; their events are relayed so that `on_event`
+/// is called synchronously on the calling thread: first with the check
+/// count, then per check. [`scan_with_config`] is this with a no-op callback.
+pub fn scan_with_progress(
+    root: &Path,
+    config: &ScanConfig,
+    on_event: &mut dyn FnMut(ProgressEvent),
+) -> Result<ScanReport, ScanError> {
+    StructComplianceEngine.scan_with_progress(root, config, on_event)
+}
+
+/// Scan a project directory, streaming each check entry to `sink` as soon as
+/// it is computed.
+///
+/// Entries of workspace members follow the root's; `sink.finish` receives the
+/// complete report once every member has been scanned. The report is also
+/// returned for exit-code decisions and non-streaming sinks.
+pub fn scan_streaming(
+    root: &Path,
+    config: &ScanConfig,
+    sink: &dyn StreamingReportSink,
+) -> Result<ScanReport, ScanError> {
+    let report = StructComplianceEngine.scan_streaming(root, config, sink)?;
+    sink.finish(&report)?;
+    Ok(report)
+}
+
+/// Scan a workspace root and each member separately.
+///
+/// Members are discovered from `[workspace] members` in the root
+/// `Cargo.toml`; the returned [`WorkspaceReport`] holds the root report, one
+/// report per member, and a combined summary counting each check once.
+pub fn scan_workspace(root: &Path, config: &ScanConfig) -> Result<WorkspaceReport, ScanError> {
+    crate::core::engine::scan_workspace(root, config)
+}
+
+/// Format a workspace report as text: the combined summary, then the root
+/// and each member in the [`format_report_text`] layout.
+pub fn format_workspace_text(report: &WorkspaceReport) -> String {
+    workspace_text(report)
+}
+
 /// Format a scan report as human-readable text.
 ///
 /// Groups results by category and appends a pass/fail/skip summary line.
@@ -53,3 +115,20 @@ pub fn format_report_text(report: &ScanReport) -> String {
 pub fn format_report_json(report: &ScanReport) -> String {
     JsonReporter.report(report)
 }
+
+/// Format a scan report in any [`ReportFormat`], exactly as the sinks write it.
+///
+/// SARIF uses the built-in rule metadata for its `rules` array; use a
+/// [`SarifSink`] to describe a custom rule set.
+pub fn format_report(report: &ScanReport, format: ReportFormat) -> String {
+    crate::core::sink::render(format, report)
+}
+
+/// JSON Schema describing the output of [`format_report_json`].
+///
+/// Covers `ScanReport` and the `ScanSummary`, `CheckEntry`, `Violation`,
+/// `MemberReport` and `DepInfo` types it contains; printed by
+/// `struct-engine schema`.
+pub fn report_schema() -> serde_json::Value {
+    reporter::report_schema()
+}
@@ -251,6 +251,42 @@ fn test_cli_kind_library() {
         .success();
 }
 
+#[test]
+fn test_cli_config_file_sets_kind_and_checks() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "struct-engine.toml", "kind = \"binary\"\nchecks = \"1-3\"\n");
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--json")
+        .env_remove("STRUCT_ENGINE_KIND")
+        .output()
+        .unwrap();
+    let val: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(val["project_kind"], "binary");
+    assert_eq!(val["results"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_cli_kind_flag_overrides_config_file() {
+    let tmp = create_minimal_project();
+    let config = tmp.path().join("ci.toml");
+    fs::write(&config, "kind = \"binary\"\nchecks = \"1-3\"\n").unwrap();
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--config")
+        .arg(&config)
+        .arg("--kind")
+        .arg("library")
+        .arg("--json")
+        .env_remove("STRUCT_ENGINE_KIND")
+        .output()
+        .unwrap();
+    let val: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(val["project_kind"], "library");
+}
+
 #[test]
 fn test_cli_total_checks() {
     let tmp = tempfile::TempDir::new().unwrap();
@@ -284,10 +320,87 @@ path = "Cargo.toml"
         .arg(tmp.path())
         .arg("--rules")
         .arg(&rules_path)
+        .arg("--checks")
+        .arg("1")
         .assert()
         .success();
 }
 
+fn warning_only_rules(root: &Path) -> std::path::PathBuf {
+    let rules_path = root.join("warning_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 1
+category = "custom"
+description = "NOTICE file exists"
+severity = "warning"
+type = "file_exists"
+path = "NOTICE"
+"#).unwrap();
+    rules_path
+}
+
+#[test]
+fn test_cli_fail_on_error_ignores_warnings() {
+    let tmp = create_minimal_project();
+    let rules_path = warning_only_rules(tmp.path());
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--rules")
+        .arg(&rules_path)
+        .arg("--checks")
+        .arg("1")
+        .arg("--fail-on")
+        .arg("error")
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn test_cli_fail_on_warning() {
+    let tmp = create_minimal_project();
+    let rules_path = warning_only_rules(tmp.path());
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--rules")
+        .arg(&rules_path)
+        .arg("--checks")
+        .arg("1")
+        .arg("--fail-on")
+        .arg("warning")
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn test_cli_fail_on_omitted_keeps_default() {
+    let tmp = create_minimal_project();
+    let rules_path = warning_only_rules(tmp.path());
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--rules")
+        .arg(&rules_path)
+        .arg("--checks")
+        .arg("1")
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn test_cli_fail_on_unknown_severity() {
+    let tmp = create_minimal_project();
+    cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--fail-on")
+        .arg("fatal")
+        .assert()
+        .code(2);
+}
+
 #[test]
 fn test_cli_text_format() {
     let tmp = create_minimal_project();
@@ -304,6 +417,61 @@ fn test_cli_text_format() {
     );
 }
 
+#[test]
+fn test_cli_github_annotations() {
+    let tmp = TempDir::new().unwrap();
+    write_file(tmp.path(), "Cargo.toml", "[package]\nname = \"x\"\nversion = \"0.1.0\"\n");
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--github")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.is_empty());
+    assert!(stdout
+        .lines()
+        .all(|l| l.starts_with("::error") || l.starts_with("::warning") || l.starts_with("::notice")));
+}
+
+#[test]
+fn test_cli_csv_output() {
+    let tmp = TempDir::new().unwrap();
+    write_file(tmp.path(), "Cargo.toml", "[package]\nname = \"x\"\nversion = \"0.1.0\"\n");
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--csv")
+        .arg("--csv-skipped")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.split("\r\n");
+    assert_eq!(lines.next(), Some("check_id,category,severity,file,line,message"));
+    assert!(stdout.contains(",error,") || stdout.contains(",warning,"), "{}", stdout);
+}
+
+#[test]
+fn test_cli_recursive_prints_combined_summary_first() {
+    let tmp = TempDir::new().unwrap();
+    write_file(tmp.path(), "Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n");
+    write_file(tmp.path(), "crates/a/Cargo.toml", "[package]\nname = \"a\"\nversion = \"0.1.0\"\n");
+    write_file(tmp.path(), "crates/a/src/lib.rs", "");
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--recursive")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let combined = stdout.find("Combined: ").expect("combined summary");
+    let member = stdout.find("crates/a (").expect("member section");
+    assert!(combined < member);
+    assert!(stdout.contains("(1 member(s), virtual)"));
+}
+
 #[test]
 fn test_cli_metadata_checks_json() {
     let tmp = create_minimal_project();
@@ -378,3 +546,77 @@ fn test_cli_rustboot_rules() {
         );
     }
 }
+
+#[test]
+fn test_cli_custom_rules_unknown_handler() {
+    let tmp = create_minimal_project();
+    let rules_path = tmp.path().join("custom_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 60
+category = "custom"
+description = "Custom check"
+severity = "warning"
+type = "builtin"
+handler = "no_such_handler"
+"#).unwrap();
+
+    let output = cmd()
+        .arg("scan")
+        .arg(tmp.path())
+        .arg("--rules")
+        .arg(&rules_path)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("no_such_handler"), "{}", stderr);
+}
+
+#[test]
+fn test_cli_rules_list_json() {
+    let output = cmd().args(["rules", "list", "--json"]).output().unwrap();
+    assert!(output.status.success());
+    let rules: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(rules.len(), default_rule_count());
+    let rule = rules.iter().find(|r| r["id"] == 51).unwrap();
+    assert_eq!(rule["handler"], "builtin:msrv_declared");
+    assert_eq!(rule["category"], "cargo_metadata");
+    assert_eq!(rule["severity"], "warning");
+}
+
+#[test]
+fn test_cli_rules_list_includes_custom_rules() {
+    let tmp = create_minimal_project();
+    let rules_path = tmp.path().join("custom_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 60
+category = "custom"
+description = "Custom check"
+severity = "info"
+type = "file_exists"
+path = "NOTICE"
+"#).unwrap();
+
+    let output = cmd().args(["rules", "list"]).arg("--rules").arg(&rules_path).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Custom check"), "{}", stdout);
+    assert!(stdout.contains(&format!("{} rules", default_rule_count() + 1)), "{}", stdout);
+}
+
+#[test]
+fn test_cli_rules_explain() {
+    let output = cmd().args(["rules", "explain", "51"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Check 51:"), "{}", stdout);
+    assert!(stdout.contains("valid MAJOR.MINOR"), "{}", stdout);
+
+    let output = cmd().args(["rules", "explain", "1"]).output().unwrap();
+    assert!(String::from_utf8(output.stdout).unwrap().contains("inspects: Cargo.toml"));
+
+    let output = cmd().args(["rules", "explain", "250"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -1,10 +1,13 @@
 use std::fs;
 use std::path::Path;
+use std::sync::Mutex;
 use tempfile::TempDir;
 
 use struct_engine::{
-    default_rule_count, scan, scan_with_config, ScanConfig, ProjectKind, CheckResult,
+    default_rule_count, format_report_json, report_schema, scan, scan_streaming, scan_with_config, write_baseline, ScanConfig, ProjectKind,
+    CheckResult, CheckEntry, ScanError, ScanReport, Severity,
 };
+use struct_engine::api::traits::StreamingReportSink;
 
 fn write_file(root: &Path, relative: &str, content: &str) {
     let full = root.join(relative);
@@ -189,6 +192,8 @@ fn test_check_filter() {
         checks: Some(vec![1, 2, 3]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert_eq!(report.results.len(), 3);
@@ -204,6 +209,8 @@ fn test_structure_checks_pass() {
         checks: Some(vec![1, 2, 3]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     for entry in &report.results {
@@ -222,6 +229,8 @@ fn test_cargo_metadata_checks_pass() {
         checks: Some(vec![9, 10, 11, 12, 13]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     for entry in &report.results {
@@ -240,6 +249,8 @@ fn test_naming_snake_case_pass() {
         checks: Some(vec![27]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert!(matches!(report.results[0].result, CheckResult::Pass));
@@ -253,6 +264,8 @@ fn test_hygiene_checks_pass() {
         checks: Some(vec![43, 44]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     for entry in &report.results {
@@ -271,6 +284,8 @@ fn test_documentation_checks() {
         checks: Some(vec![39, 42]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     for entry in &report.results {
@@ -291,6 +306,8 @@ fn test_project_kind_skip() {
         checks: Some(vec![4, 5]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     for entry in &report.results {
@@ -343,10 +360,155 @@ path = "Cargo.toml"
         checks: None,
         rules_path: Some(rules_path),
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
-    assert_eq!(report.results.len(), 1);
-    assert!(matches!(report.results[0].result, CheckResult::Pass));
+    // Custom rules override the embedded rule with the same id; the rest still run
+    assert_eq!(report.results.len(), default_rule_count());
+    let check_1 = report.results.iter().find(|e| e.id.0 == 1).unwrap();
+    assert_eq!(check_1.category, "custom");
+    assert!(matches!(check_1.result, CheckResult::Pass));
+}
+
+#[test]
+fn test_custom_rules_add_new_check() {
+    let tmp = create_minimal_project();
+    let rules_path = tmp.path().join("custom_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 60
+category = "custom"
+description = "NOTICE file exists"
+severity = "warning"
+type = "file_exists"
+path = "NOTICE"
+"#).unwrap();
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        rules_path: Some(rules_path),
+        ..ScanConfig::default()
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert_eq!(report.results.len(), default_rule_count() + 1);
+    let added = report.results.iter().find(|e| e.id.0 == 60).unwrap();
+    assert!(matches!(added.result, CheckResult::Fail { .. }));
+}
+
+#[test]
+fn test_custom_rules_unknown_handlers_rejected() {
+    let tmp = create_minimal_project();
+    let rules_path = tmp.path().join("custom_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 60
+category = "custom"
+description = "First"
+severity = "warning"
+type = "builtin"
+handler = "no_such_handler"
+
+[[rules]]
+id = 61
+category = "custom"
+description = "Second"
+severity = "warning"
+type = "builtin"
+handler = "also_missing"
+"#).unwrap();
+
+    let config = ScanConfig {
+        rules_path: Some(rules_path),
+        ..ScanConfig::default()
+    };
+    let err = scan_with_config(tmp.path(), &config).unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("no_such_handler") && msg.contains("also_missing"), "{}", msg);
+}
+
+#[test]
+fn test_with_deps_reports_dependencies() {
+    let tmp = create_minimal_project();
+    let manifest = fs::read_to_string(tmp.path().join("Cargo.toml")).unwrap();
+    write_file(tmp.path(), "Cargo.toml", &format!(
+        "{}\n[dependencies]\nserde = {{ version = \"1\", features = [\"derive\"] }}\n\n[dev-dependencies]\ntempfile = \"3\"\n",
+        manifest
+    ));
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![1]),
+        rules_path: None,
+        recursive: false,
+        with_deps: true,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert_eq!(report.dependencies.len(), 2);
+
+    let json: serde_json::Value = serde_json::from_str(&struct_engine::format_report_json(&report)).unwrap();
+    let deps = json["dependencies"].as_array().unwrap();
+    assert!(deps.iter().any(|d| d["name"] == "serde" && d["version_req"] == "1" && d["kind"] == "normal"));
+    assert!(deps.iter().any(|d| d["name"] == "tempfile" && d["kind"] == "dev"));
+}
+
+#[test]
+fn test_without_deps_omits_dependencies() {
+    let tmp = create_minimal_project();
+    let manifest = fs::read_to_string(tmp.path().join("Cargo.toml")).unwrap();
+    write_file(tmp.path(), "Cargo.toml", &format!("{}\n[dependencies]\nregex = \"1\"\n", manifest));
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![1]),
+        ..ScanConfig::default()
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(report.dependencies.is_empty());
+
+    let json: serde_json::Value = serde_json::from_str(&struct_engine::format_report_json(&report)).unwrap();
+    assert!(json.get("dependencies").is_none());
+}
+
+/// Collects streamed entries so tests can compare them with the final report.
+#[derive(Default)]
+struct CollectingSink {
+    entries: Mutex<Vec<(Option<String>, u8)>>,
+    finished: Mutex<Option<u8>>,
+}
+
+impl StreamingReportSink for CollectingSink {
+    fn emit_entry(&self, member: Option<&str>, entry: &CheckEntry) -> Result<(), ScanError> {
+        self.entries.lock().unwrap().push((member.map(str::to_string), entry.id.0));
+        Ok(())
+    }
+
+    fn finish(&self, report: &ScanReport) -> Result<(), ScanError> {
+        *self.finished.lock().unwrap() = Some(report.summary.total);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_scan_streaming_emits_every_entry() {
+    let tmp = create_minimal_project();
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        ..ScanConfig::default()
+    };
+    let sink = CollectingSink::default();
+    let report = scan_streaming(tmp.path(), &config, &sink).unwrap();
+
+    let mut streamed: Vec<u8> = sink.entries.lock().unwrap().iter()
+        .inspect(|(member, _)| assert!(member.is_none()))
+        .map(|(_, id)| *id)
+        .collect();
+    streamed.sort();
+    let mut expected: Vec<u8> = report.results.iter().map(|e| e.id.0).collect();
+    expected.sort();
+    assert_eq!(streamed, expected);
+    assert_eq!(*sink.finished.lock().unwrap(), Some(report.summary.total));
 }
 
 #[test]
@@ -359,6 +521,8 @@ fn test_rustboot_project_with_rustboot_rules() {
         checks: Some(vec![1, 2, 3, 4, 5]),
         rules_path: Some(rules_path),
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     for entry in &report.results {
@@ -379,6 +543,8 @@ fn test_rustboot_naming_check() {
         checks: Some(vec![28]),
         rules_path: Some(rules_path),
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert!(
@@ -396,6 +562,8 @@ fn test_lib_path_check_pass() {
         checks: Some(vec![19]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert!(
@@ -413,6 +581,8 @@ fn test_module_names_match_pass() {
         checks: Some(vec![31]),
         rules_path: None,
         recursive: false,
+        with_deps: false,
+        baseline_path: None,
     };
     let report = scan_with_config(tmp.path(), &config).unwrap();
     assert!(
@@ -421,3 +591,938 @@ fn test_module_names_match_pass() {
         report.results[0].result
     );
 }
+
+#[test]
+fn test_test_modules_private_pass() {
+    let tmp = create_minimal_project();
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![46]),
+        rules_path: None,
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(
+        matches!(report.results[0].result, CheckResult::Pass),
+        "Check 46 (test modules private) should pass: {:?}",
+        report.results[0].result
+    );
+}
+
+#[test]
+fn test_test_modules_private_error() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/utils.rs", r#"pub fn helper() {}
+
+#[cfg(test)]
+pub mod tests {
+    #[test]
+    fn test_helper() {}
+}
+"#);
+    write_file(tmp.path(), "main/src/lib.rs", "pub mod utils;\npub mod test;\n");
+    write_file(tmp.path(), "main/src/test.rs", "pub fn fixture() {}\n");
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![46]),
+        rules_path: None,
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    match &report.results[0].result {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 2, "{:?}", violations);
+            assert!(violations.iter().any(|v| v.message.contains("Module 'tests' is gated by #[cfg(test)]")));
+            assert!(violations.iter().any(|v| v.message.contains("Public module 'test' is not gated")));
+        }
+        other => panic!("Check 46 should fail: {:?}", other),
+    }
+}
+
+fn max_module_depth_rules(root: &Path, extra: &str) -> std::path::PathBuf {
+    let rules_path = root.join("depth_rules.toml");
+    std::fs::write(&rules_path, format!(r#"
+[[rules]]
+id = 47
+category = "structure"
+description = "Source files are nested at most max_depth directories below src/"
+severity = "warning"
+type = "builtin"
+handler = "max_module_depth"
+{extra}
+"#)).unwrap();
+    rules_path
+}
+
+#[test]
+fn test_max_module_depth_pass_default() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/a/b/c/d/e/leaf.rs", "pub fn leaf() {}\n");
+    let rules_path = max_module_depth_rules(tmp.path(), "");
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![47]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(matches!(report.results[0].result, CheckResult::Pass), "{:?}", report.results[0].result);
+}
+
+#[test]
+fn test_max_module_depth_error() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/a/b/c/deep.rs", "pub fn deep() {}\n");
+    write_file(tmp.path(), "main/src/a/b/shallow.rs", "pub fn shallow() {}\n");
+    write_file(tmp.path(), "main/src/fixtures/x/y/z/sample.rs", "pub fn sample() {}\n");
+    let rules_path = max_module_depth_rules(
+        tmp.path(),
+        "exclude_paths = [\"main/src/fixtures/\"]\n\n[rules.params]\nmax_depth = 2",
+    );
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![47]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    match &report.results[0].result {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 1, "{:?}", violations);
+            assert!(violations[0].message.contains("main/src/a/b/c/deep.rs"));
+            assert_eq!(violations[0].actual.as_deref(), Some("depth 3"));
+        }
+        other => panic!("Check 47 should fail: {:?}", other),
+    }
+}
+
+fn examples_documented_rules(root: &Path) -> std::path::PathBuf {
+    let rules_path = root.join("examples_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 48
+category = "documentation"
+description = "Example files start with a comment explaining what they demonstrate"
+severity = "warning"
+type = "builtin"
+handler = "examples_documented"
+"#).unwrap();
+    rules_path
+}
+
+#[test]
+fn test_examples_documented_pass() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "examples/basic.rs", "//! Scans the current directory.\n\nfn main() {}\n");
+    write_file(tmp.path(), "examples/server/main.rs", "\n// Starts a local server.\nfn main() {}\n");
+    write_file(tmp.path(), "examples/server/routes.rs", "pub fn routes() {}\n");
+    let rules_path = examples_documented_rules(tmp.path());
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![48]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(matches!(report.results[0].result, CheckResult::Pass), "{:?}", report.results[0].result);
+}
+
+#[test]
+fn test_examples_documented_fail_reports_path() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "examples/documented.rs", "//! Shows the happy path.\nfn main() {}\n");
+    write_file(tmp.path(), "examples/bare.rs", "fn main() {\n    // not a header\n}\n");
+    let rules_path = examples_documented_rules(tmp.path());
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![48]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    match &report.results[0].result {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 1, "{:?}", violations);
+            assert!(violations[0].message.contains("examples/bare.rs"));
+            assert_eq!(violations[0].severity, Severity::Warning);
+        }
+        other => panic!("Check 48 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_examples_documented_skip_without_examples() {
+    let tmp = create_minimal_project();
+    let rules_path = examples_documented_rules(tmp.path());
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![48]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(matches!(report.results[0].result, CheckResult::Skip { .. }), "{:?}", report.results[0].result);
+}
+
+fn macro_density_rules(root: &Path, params: &str) -> std::path::PathBuf {
+    let rules_path = root.join("macro_rules.toml");
+    std::fs::write(&rules_path, format!(r#"
+[[rules]]
+id = 49
+category = "structure"
+description = "Source files are not dominated by macro invocations"
+severity = "warning"
+type = "builtin"
+handler = "macro_density"
+
+[rules.params]
+min_lines = 0
+{params}
+"#)).unwrap();
+    rules_path
+}
+
+#[test]
+fn test_macro_density_pass_plain_code() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/plain.rs",
+        "pub fn add(a: u32, b: u32) -> u32 {\n    let sum = a + b;\n    println!(\"{}\", sum);\n    sum\n}\n");
+    let rules_path = macro_density_rules(tmp.path(), "");
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![49]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(matches!(report.results[0].result, CheckResult::Pass), "{:?}", report.results[0].result);
+}
+
+#[test]
+fn test_macro_density_reports_densest_files_first() {
+    let tmp = create_minimal_project();
+    // 5 of 5 code lines are inside view! { .. }
+    write_file(tmp.path(), "main/src/ui.rs",
+        "// Page markup\nview! {\n    <div>\n        <p>\"hello\"</p>\n    </div>\n}\n");
+    // 3 of 4 code lines are inside html! [ .. ]
+    write_file(tmp.path(), "main/src/partial.rs",
+        "pub fn render() {}\nhtml! [\n    <span/>\n]\n");
+    // 1 of 3 code lines is a macro
+    write_file(tmp.path(), "main/src/mostly_plain.rs",
+        "pub fn one() -> u8 { 1 }\npub fn two() -> u8 { 2 }\nassert_eq!(1, 1);\n");
+    let rules_path = macro_density_rules(tmp.path(), "max_files = 1");
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![49]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    match &report.results[0].result {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 1, "{:?}", violations);
+            assert!(violations[0].message.contains("main/src/ui.rs"));
+            assert!(violations[0].message.contains("100%"));
+            assert_eq!(violations[0].actual.as_deref(), Some("ratio 1.00"));
+            assert_eq!(violations[0].severity, Severity::Warning);
+        }
+        other => panic!("Check 49 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_macro_density_respects_max_ratio() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/partial.rs",
+        "pub fn render() {}\nhtml! [\n    <span/>\n]\n");
+    let rules_path = macro_density_rules(tmp.path(), "max_ratio = 0.8");
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![49]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(matches!(report.results[0].result, CheckResult::Pass), "{:?}", report.results[0].result);
+}
+
+#[test]
+fn test_baseline_suppresses_known_violations() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/a/old.rs", "pub fn old() {}\n");
+    let rules_path = max_module_depth_rules(tmp.path(), "\n[rules.params]\nmax_depth = 0");
+    let baseline_path = tmp.path().join("baseline/struct-baseline.json");
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![47]),
+        rules_path: Some(rules_path.clone()),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert_eq!(report.summary.failed, 1);
+    write_baseline(&report, &baseline_path).unwrap();
+
+    // A new violation appears after the snapshot
+    write_file(tmp.path(), "main/src/b/new.rs", "pub fn new() {}\n");
+    let config = ScanConfig {
+        baseline_path: Some(baseline_path.clone()),
+        ..config
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert_eq!(report.summary.failed, 1);
+    assert_eq!(report.summary.suppressed, 0);
+    match &report.results[0].result {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 2, "{:?}", violations);
+            let old = violations.iter().find(|v| v.message.contains("old.rs")).unwrap();
+            let new = violations.iter().find(|v| v.message.contains("new.rs")).unwrap();
+            assert!(old.suppressed);
+            assert!(!new.suppressed);
+        }
+        other => panic!("Check 47 should fail: {:?}", other),
+    }
+
+    // Once the new violation is fixed, only baselined violations remain
+    std::fs::remove_file(tmp.path().join("main/src/b/new.rs")).unwrap();
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert_eq!(report.summary.failed, 0);
+    assert_eq!(report.summary.suppressed, 1);
+    assert!(matches!(report.results[0].result, CheckResult::Fail { .. }));
+}
+
+#[test]
+fn test_baseline_missing_file_is_config_error() {
+    let tmp = create_minimal_project();
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![1]),
+        rules_path: None,
+        recursive: false,
+        with_deps: false,
+        baseline_path: Some(tmp.path().join("missing.json")),
+    };
+    let err = scan_with_config(tmp.path(), &config).unwrap_err();
+    assert!(err.to_string().contains("missing.json"), "{}", err);
+}
+
+#[test]
+fn test_max_severity_ignores_baselined_violations() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/a/old.rs", "pub fn old() {}\n");
+    let rules_path = max_module_depth_rules(tmp.path(), "\n[rules.params]\nmax_depth = 0");
+    let baseline_path = tmp.path().join("struct-baseline.json");
+
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![47]),
+        rules_path: Some(rules_path),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert_eq!(report.max_severity(), Some(Severity::Warning));
+    write_baseline(&report, &baseline_path).unwrap();
+
+    let config = ScanConfig { baseline_path: Some(baseline_path), ..config };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert_eq!(report.max_severity(), None);
+}
+
+#[test]
+fn test_severity_ordering() {
+    assert!(Severity::Info < Severity::Warning);
+    assert!(Severity::Warning < Severity::Error);
+    assert_eq!(Severity::from_name("warning"), Some(Severity::Warning));
+    assert_eq!(Severity::from_name("fatal"), None);
+}
+
+fn description_quality_rules(root: &Path, params: &str) -> std::path::PathBuf {
+    let rules_path = root.join("description_rules.toml");
+    std::fs::write(&rules_path, format!(r#"
+[[rules]]
+id = 50
+category = "cargo_metadata"
+description = "package.description is meaningful"
+severity = "warning"
+type = "builtin"
+handler = "description_quality"
+{params}
+"#)).unwrap();
+    rules_path
+}
+
+fn scan_description(root: &Path, params: &str) -> CheckResult {
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![50]),
+        rules_path: Some(description_quality_rules(root, params)),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+fn set_description(root: &Path, line: &str) {
+    let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    let manifest = manifest.replace("description = \"A test project for struct-engine\"\n", line);
+    fs::write(root.join("Cargo.toml"), manifest).unwrap();
+}
+
+#[test]
+fn test_description_quality_pass() {
+    let tmp = create_minimal_project();
+    assert!(matches!(scan_description(tmp.path(), ""), CheckResult::Pass));
+}
+
+#[test]
+fn test_description_quality_fail_placeholder() {
+    let tmp = create_minimal_project();
+    set_description(tmp.path(), "description = \"TODO.\"\n");
+    match scan_description(tmp.path(), "") {
+        CheckResult::Fail { violations } => {
+            assert!(violations[0].message.contains("placeholder"), "{}", violations[0].message);
+            assert_eq!(violations[0].actual.as_deref(), Some("'TODO.'"));
+        }
+        other => panic!("Check 50 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_description_quality_fail_crate_name() {
+    let tmp = create_minimal_project();
+    set_description(tmp.path(), "description = \"test_project\"\n");
+    match scan_description(tmp.path(), "") {
+        CheckResult::Fail { violations } => {
+            assert!(violations[0].message.contains("crate name"), "{}", violations[0].message);
+        }
+        other => panic!("Check 50 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_description_quality_fail_missing() {
+    let tmp = create_minimal_project();
+    set_description(tmp.path(), "");
+    match scan_description(tmp.path(), "") {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations[0].actual.as_deref(), Some("missing"));
+        }
+        other => panic!("Check 50 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_description_quality_respects_params() {
+    let tmp = create_minimal_project();
+    set_description(tmp.path(), "description = \"Parses logs\"\n");
+    assert!(matches!(scan_description(tmp.path(), ""), CheckResult::Fail { .. }));
+    assert!(matches!(
+        scan_description(tmp.path(), "\n[rules.params]\nmin_length = 5"),
+        CheckResult::Pass
+    ));
+    assert!(matches!(
+        scan_description(tmp.path(), "\n[rules.params]\nmin_length = 5\nplaceholders = [\"parses logs\"]"),
+        CheckResult::Fail { .. }
+    ));
+}
+
+#[test]
+fn test_description_quality_skips_unpublished_crates() {
+    let tmp = create_minimal_project();
+    set_description(tmp.path(), "description = \"TODO\"\npublish = false\n");
+    assert!(matches!(scan_description(tmp.path(), ""), CheckResult::Skip { .. }));
+}
+
+fn scan_msrv(root: &Path) -> CheckResult {
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![51]),
+        rules_path: None,
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+fn set_rust_version(root: &Path, line: &str) {
+    let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    let manifest = manifest.replace("rust-version = \"1.70\"\n", line);
+    fs::write(root.join("Cargo.toml"), manifest).unwrap();
+}
+
+#[test]
+fn test_msrv_declared_pass() {
+    let tmp = create_minimal_project();
+    assert!(matches!(scan_msrv(tmp.path()), CheckResult::Pass));
+    set_rust_version(tmp.path(), "rust-version = \"1.70.0\"\n");
+    assert!(matches!(scan_msrv(tmp.path()), CheckResult::Pass));
+}
+
+#[test]
+fn test_msrv_declared_fail_missing() {
+    let tmp = create_minimal_project();
+    set_rust_version(tmp.path(), "");
+    match scan_msrv(tmp.path()) {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations[0].message, "package.rust-version is missing");
+            assert_eq!(violations[0].actual.as_deref(), Some("missing"));
+        }
+        other => panic!("Check 51 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_msrv_declared_fail_invalid_version() {
+    for bad in ["stable", "1", "1.70-beta", "1.070"] {
+        let tmp = create_minimal_project();
+        set_rust_version(tmp.path(), &format!("rust-version = \"{}\"\n", bad));
+        match scan_msrv(tmp.path()) {
+            CheckResult::Fail { violations } => {
+                assert!(violations[0].message.contains("is not a valid version"), "{}", violations[0].message);
+                assert_eq!(violations[0].actual.as_deref(), Some(format!("'{}'", bad).as_str()));
+            }
+            other => panic!("Check 51 should fail for '{}': {:?}", bad, other),
+        }
+    }
+}
+
+#[test]
+fn test_msrv_declared_skips_workspace_only_manifest() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "Cargo.toml", "[workspace]\nmembers = []\n");
+    assert!(matches!(scan_msrv(tmp.path()), CheckResult::Skip { .. }));
+}
+
+fn unsafe_safety_rules(root: &Path) -> std::path::PathBuf {
+    let rules_path = root.join("unsafe_rules.toml");
+    std::fs::write(&rules_path, r#"
+[[rules]]
+id = 52
+category = "structure"
+description = "unsafe code carries a SAFETY: comment"
+severity = "warning"
+type = "builtin"
+handler = "unsafe_safety_comments"
+"#).unwrap();
+    rules_path
+}
+
+fn scan_unsafe(root: &Path) -> CheckResult {
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![52]),
+        rules_path: Some(unsafe_safety_rules(root)),
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+#[test]
+fn test_unsafe_safety_comments_pass_documented_and_lexical_edge_cases() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/ffi.rs", r##"pub trait RawRead {
+    unsafe fn read_raw(&self) -> u8;
+}
+
+/// Reads a byte.
+///
+/// # Safety
+/// `ptr` must be valid for reads.
+#[inline]
+pub unsafe fn read(ptr: *const u8) -> u8 {
+    // SAFETY: the caller guarantees `ptr` is valid for reads.
+    unsafe { *ptr }
+}
+
+pub fn first(bytes: &[u8]) -> u8 {
+    let v = unsafe { *bytes.as_ptr() }; // SAFETY: checked non-empty by callers
+    v
+}
+
+pub struct Wrapper(*mut u8);
+
+// SAFETY: the pointer is never shared across threads without a lock.
+unsafe impl Send for Wrapper {}
+
+pub type Callback = unsafe fn(u8) -> u8;
+
+pub const MESSAGE: &str = "unsafe { } is not code here";
+pub const RAW: &str = r#"unsafe impl "quoted" Sync"#;
+pub const QUOTE: char = '\'';
+// unsafe { mentioned only in a comment }
+/* unsafe impl in a block comment */
+pub fn lifetimes<'a>(s: &'a str) -> &'a str { s }
+"##);
+
+    assert!(matches!(scan_unsafe(tmp.path()), CheckResult::Pass), "{:?}", scan_unsafe(tmp.path()));
+}
+
+#[test]
+fn test_unsafe_safety_comments_fail_reports_each_location() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/ffi.rs", r#"pub fn first(bytes: &[u8]) -> u8 {
+    // reads the first byte
+    unsafe { *bytes.as_ptr() }
+}
+
+pub struct Wrapper(*mut u8);
+
+unsafe impl Send for Wrapper {}
+
+// SAFETY: this comment is separated by a blank line
+
+pub fn second(bytes: &[u8]) -> u8 {
+    unsafe { *bytes.as_ptr().add(1) }
+}
+"#);
+
+    match scan_unsafe(tmp.path()) {
+        CheckResult::Fail { violations } => {
+            let actual: Vec<&str> = violations.iter().filter_map(|v| v.actual.as_deref()).collect();
+            assert_eq!(actual, vec!["main/src/ffi.rs:3", "main/src/ffi.rs:8", "main/src/ffi.rs:13"]);
+            assert!(violations[0].message.contains("unsafe block"), "{}", violations[0].message);
+            assert!(violations[1].message.contains("unsafe impl"), "{}", violations[1].message);
+        }
+        other => panic!("Check 52 should fail: {:?}", other),
+    }
+}
+
+fn scan_proc_macro(root: &Path) -> CheckResult {
+    let config = ScanConfig {
+        project_kind: Some(ProjectKind::Library),
+        checks: Some(vec![53]),
+        rules_path: None,
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+fn set_lib_section(root: &Path, lib: &str) {
+    let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    let manifest = manifest.replace("[lib]\npath = \"main/src/lib.rs\"\n", lib);
+    fs::write(root.join("Cargo.toml"), manifest).unwrap();
+}
+
+const DERIVE_LIB: &str = r#"use proc_macro::TokenStream;
+
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive_builder(input: TokenStream) -> TokenStream {
+    input
+}
+
+pub(crate) fn helper() {}
+"#;
+
+#[test]
+fn test_proc_macro_crate_pass() {
+    let tmp = create_minimal_project();
+    set_lib_section(tmp.path(), "[lib]\npath = \"main/src/lib.rs\"\nproc-macro = true\n");
+    write_file(tmp.path(), "main/src/lib.rs", DERIVE_LIB);
+    assert!(matches!(scan_proc_macro(tmp.path()), CheckResult::Pass));
+}
+
+#[test]
+fn test_proc_macro_crate_skips_normal_library() {
+    let tmp = create_minimal_project();
+    assert!(matches!(scan_proc_macro(tmp.path()), CheckResult::Skip { .. }));
+}
+
+#[test]
+fn test_proc_macro_crate_fail_missing_flag() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/lib.rs", DERIVE_LIB);
+    match scan_proc_macro(tmp.path()) {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].path.as_deref(), Some(Path::new("Cargo.toml")));
+            assert!(violations[0].message.contains("main/src/lib.rs:3"), "{}", violations[0].message);
+        }
+        other => panic!("Check 53 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_proc_macro_crate_fail_normal_crate_type_and_exports() {
+    let tmp = create_minimal_project();
+    set_lib_section(tmp.path(), "[lib]\npath = \"main/src/lib.rs\"\nproc-macro = true\ncrate-type = [\"proc-macro\", \"rlib\"]\n");
+    write_file(tmp.path(), "main/src/lib.rs", &format!("{}\npub struct Options {{\n    pub name: String,\n}}\n\npub use proc_macro::Span;\n", DERIVE_LIB));
+    match scan_proc_macro(tmp.path()) {
+        CheckResult::Fail { violations } => {
+            let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+            assert_eq!(violations.len(), 3, "{:?}", messages);
+            assert!(messages[0].contains("also declared as a normal library"), "{}", messages[0]);
+            assert_eq!(messages[1], "public struct `Options` in proc-macro crate at main/src/lib.rs:10");
+            assert_eq!(messages[2], "public use `proc_macro::Span` in proc-macro crate at main/src/lib.rs:14");
+        }
+        other => panic!("Check 53 should fail: {:?}", other),
+    }
+}
+
+fn scan_forbid_unsafe(root: &Path, kind: ProjectKind) -> CheckResult {
+    let config = ScanConfig {
+        project_kind: Some(kind),
+        checks: Some(vec![54]),
+        rules_path: None,
+        recursive: false,
+        with_deps: false,
+        baseline_path: None,
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+#[test]
+fn test_forbid_unsafe_pass_with_forbid_attribute() {
+    let tmp = create_minimal_project();
+    let lib = fs::read_to_string(tmp.path().join("main/src/lib.rs")).unwrap();
+    write_file(tmp.path(), "main/src/lib.rs", &format!("//! Test crate.\n#![forbid(unsafe_code)]\n\n{}", lib));
+    assert!(matches!(scan_forbid_unsafe(tmp.path(), ProjectKind::Library), CheckResult::Pass));
+}
+
+#[test]
+fn test_forbid_unsafe_pass_with_deny_among_lints() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/lib.rs", "#![deny(missing_docs, unsafe_code)]\n");
+    assert!(matches!(scan_forbid_unsafe(tmp.path(), ProjectKind::Library), CheckResult::Pass));
+}
+
+#[test]
+fn test_forbid_unsafe_pass_with_justification() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "docs/unsafe-justification.md", "# Unsafe code\n\nFFI bindings need raw pointers.\n");
+    assert!(matches!(scan_forbid_unsafe(tmp.path(), ProjectKind::Library), CheckResult::Pass));
+}
+
+#[test]
+fn test_forbid_unsafe_fail_when_neither_present() {
+    let tmp = create_minimal_project();
+    // A commented-out attribute does not count
+    write_file(tmp.path(), "main/src/lib.rs", "// #![forbid(unsafe_code)]\npub fn add() {}\n");
+    match scan_forbid_unsafe(tmp.path(), ProjectKind::Library) {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].path.as_deref(), Some(Path::new("main/src/lib.rs")));
+            assert!(violations[0].message.contains("docs/unsafe-justification.md"), "{}", violations[0].message);
+        }
+        other => panic!("Check 54 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_forbid_unsafe_skips_binary() {
+    let tmp = create_minimal_project();
+    assert!(matches!(scan_forbid_unsafe(tmp.path(), ProjectKind::Binary), CheckResult::Skip { .. }));
+}
+
+fn scan_wildcard_deps(root: &Path) -> CheckResult {
+    let config = ScanConfig {
+        checks: Some(vec![55]),
+        ..ScanConfig::default()
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+#[test]
+fn test_no_wildcard_deps_pass_with_pinned_deps() {
+    let tmp = create_minimal_project();
+    let manifest = fs::read_to_string(tmp.path().join("Cargo.toml")).unwrap();
+    write_file(tmp.path(), "Cargo.toml", &format!(
+        "{}\n[dependencies]\nserde = {{ version = \"1.0\", features = [\"derive\"] }}\nlocal = {{ path = \"../local\" }}\nforked = {{ git = \"https://example.com/forked\" }}\n\n[dev-dependencies]\ntempfile = \"3\"\n",
+        manifest
+    ));
+    assert!(matches!(scan_wildcard_deps(tmp.path()), CheckResult::Pass));
+}
+
+#[test]
+fn test_no_wildcard_deps_fail_with_wildcard_and_empty() {
+    let tmp = create_minimal_project();
+    let manifest = fs::read_to_string(tmp.path().join("Cargo.toml")).unwrap();
+    write_file(tmp.path(), "Cargo.toml", &format!(
+        "{}\n[dependencies]\nregex = \"*\"\nserde = \"1\"\n\n[build-dependencies]\ncc = {{ version = \"\" }}\n",
+        manifest
+    ));
+    match scan_wildcard_deps(tmp.path()) {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 2);
+            let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+            assert!(messages.iter().any(|m| m.contains("'regex' in [dependencies]") && m.contains("\"*\"")), "{:?}", messages);
+            assert!(messages.iter().any(|m| m.contains("'cc' in [build-dependencies]") && m.contains("no version")), "{:?}", messages);
+        }
+        other => panic!("Check 55 should fail: {:?}", other),
+    }
+}
+
+fn scan_orphan_modules(root: &Path) -> CheckResult {
+    let config = ScanConfig {
+        checks: Some(vec![56]),
+        ..ScanConfig::default()
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+#[test]
+fn test_no_orphan_modules_pass_minimal_project() {
+    let tmp = create_minimal_project();
+    assert!(matches!(scan_orphan_modules(tmp.path()), CheckResult::Pass));
+}
+
+#[test]
+fn test_no_orphan_modules_follows_nested_path_and_cfg_gated_mods() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/lib.rs", r#"pub mod utils;
+pub mod net;
+#[cfg(feature = "gpu")]
+mod gpu;
+#[path = "generated/bindings.rs"]
+mod bindings;
+
+mod inline {
+    mod nested;
+}
+
+// mod commented_out;
+const NOT_A_MOD: &str = "mod fake;";
+"#);
+    write_file(tmp.path(), "main/src/net/mod.rs", "mod tcp;\n");
+    write_file(tmp.path(), "main/src/net/tcp.rs", "pub fn connect() {}\n");
+    write_file(tmp.path(), "main/src/gpu.rs", "mod kernels;\n");
+    write_file(tmp.path(), "main/src/gpu/kernels.rs", "pub fn launch() {}\n");
+    write_file(tmp.path(), "main/src/generated/bindings.rs", "mod extra;\n");
+    write_file(tmp.path(), "main/src/generated/extra.rs", "pub fn extra() {}\n");
+    write_file(tmp.path(), "main/src/inline/nested.rs", "pub fn nested() {}\n");
+    write_file(tmp.path(), "main/src/bin/tool.rs", "mod cli;\nfn main() {}\n");
+    write_file(tmp.path(), "main/src/bin/cli.rs", "pub fn run() {}\n");
+
+    assert!(matches!(scan_orphan_modules(tmp.path()), CheckResult::Pass), "{:?}", scan_orphan_modules(tmp.path()));
+}
+
+#[test]
+fn test_no_orphan_modules_fail_reports_each_orphan() {
+    let tmp = create_minimal_project();
+    write_file(tmp.path(), "main/src/lib.rs", "pub mod utils;\n// mod legacy;\nconst S: &str = \"mod fake;\";\n");
+    write_file(tmp.path(), "main/src/legacy.rs", "mod helpers;\n");
+    write_file(tmp.path(), "main/src/legacy/helpers.rs", "pub fn helper() {}\n");
+    write_file(tmp.path(), "main/src/fake.rs", "pub fn fake() {}\n");
+
+    match scan_orphan_modules(tmp.path()) {
+        CheckResult::Fail { violations } => {
+            let mut paths: Vec<String> = violations.iter()
+                .filter_map(|v| v.path.as_ref().map(|p| p.to_string_lossy().replace('\\', "/")))
+                .collect();
+            paths.sort();
+            assert_eq!(paths, vec!["main/src/fake.rs", "main/src/legacy.rs", "main/src/legacy/helpers.rs"]);
+            assert!(violations[0].message.contains("not reachable"), "{}", violations[0].message);
+        }
+        other => panic!("Check 56 should fail: {:?}", other),
+    }
+}
+
+fn create_edition_workspace(c_edition: &str) -> TempDir {
+    let tmp = tempfile::Builder::new().prefix("test_").tempdir().unwrap();
+    let root = tmp.path();
+    write_file(root, "Cargo.toml", r#"[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+edition = "2021"
+"#);
+    write_file(root, "crates/a/Cargo.toml", "[package]\nname = \"a\"\nedition = \"2021\"\n");
+    write_file(root, "crates/b/Cargo.toml", "[package]\nname = \"b\"\nedition.workspace = true\n");
+    write_file(root, "crates/c/Cargo.toml", &format!("[package]\nname = \"c\"\nedition = \"{}\"\n", c_edition));
+    for member in ["a", "b", "c"] {
+        write_file(root, &format!("crates/{}/src/lib.rs", member), "");
+    }
+    tmp
+}
+
+fn scan_consistent_edition(root: &Path) -> CheckResult {
+    let config = ScanConfig {
+        checks: Some(vec![57]),
+        ..ScanConfig::default()
+    };
+    scan_with_config(root, &config).unwrap().results[0].result.clone()
+}
+
+#[test]
+fn test_workspace_consistent_edition_pass_with_inherited_edition() {
+    let tmp = create_edition_workspace("2021");
+    assert!(matches!(scan_consistent_edition(tmp.path()), CheckResult::Pass));
+}
+
+#[test]
+fn test_workspace_consistent_edition_fail_flags_outlier() {
+    let tmp = create_edition_workspace("2018");
+    match scan_consistent_edition(tmp.path()) {
+        CheckResult::Fail { violations } => {
+            assert_eq!(violations.len(), 1);
+            let msg = &violations[0].message;
+            assert!(msg.contains("crates/c (2018)"), "{}", msg);
+            assert!(!msg.contains("crates/a") && !msg.contains("crates/b"), "{}", msg);
+            assert_eq!(violations[0].expected.as_deref(), Some("edition 2021 in every member"));
+        }
+        other => panic!("Check 57 should fail: {:?}", other),
+    }
+}
+
+#[test]
+fn test_workspace_consistent_edition_skipped_for_single_crate() {
+    let tmp = create_minimal_project();
+    assert!(matches!(scan_consistent_edition(tmp.path()), CheckResult::Skip { .. }));
+}
+
+#[test]
+fn test_json_report_matches_schema() {
+    let tmp = create_minimal_project();
+    let manifest = fs::read_to_string(tmp.path().join("Cargo.toml")).unwrap();
+    write_file(tmp.path(), "Cargo.toml", &format!("{}\n[dependencies]\nregex = \"*\"\n", manifest));
+    let config = ScanConfig { with_deps: true, ..ScanConfig::default() };
+    let report = scan_with_config(tmp.path(), &config).unwrap();
+    assert!(report.results.iter().any(|e| matches!(e.result, CheckResult::Fail { .. })));
+    assert!(!report.dependencies.is_empty());
+
+    let schema = report_schema();
+    let validator = jsonschema::JSONSchema::compile(&schema).unwrap();
+    let instance: serde_json::Value = serde_json::from_str(&format_report_json(&report)).unwrap();
+    if let Err(errors) = validator.validate(&instance) {
+        let errors: Vec<String> = errors.map(|e| format!("{} at {}", e, e.instance_path)).collect();
+        panic!("report does not match its schema: {:?}", errors);
+    };
+}
@@ -9,10 +9,12 @@ pub async fn get_ai_status() -> Result<AiStatus, ApiError> {
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse AI status response".into(),
+        fields: Vec::new(),
     })?;
     AiStatus::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "AI status response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
@@ -25,10 +27,12 @@ pub async fn send_chat(message: &str) -> Result<ChatMessage, ApiError> {
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse chat response".into(),
+        fields: Vec::new(),
     })?;
     ChatMessage::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "chat response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
@@ -41,10 +45,12 @@ pub async fn run_audit(path: &str, scope: &str) -> Result<AuditResult, ApiError>
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse audit response".into(),
+        fields: Vec::new(),
     })?;
     AuditResult::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "audit response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
@@ -60,9 +66,11 @@ pub async fn generate_commands(requirements_json: &str, project_context: &str) -
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse command generation response".into(),
+        fields: Vec::new(),
     })?;
     CommandGenResult::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "command generation response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
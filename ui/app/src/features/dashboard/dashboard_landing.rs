@@ -5,6 +5,7 @@ use crate::features::dashboard::project_card::project_card;
 use crate::features::dashboard::category_chart::category_chart;
 use crate::features::dashboard::trend_chart::trend_chart;
 use crate::features::dashboard::engine_summary::engine_summary;
+use crate::util::clipboard::copy_markdown_button;
 
 /// Main dashboard page composing project cards, engine summary, and trend chart (FR-200..203).
 #[component]
@@ -28,6 +29,9 @@ pub fn dashboard_landing() -> View {
             .dashboard { display: flex; flex-direction: column; gap: var(--space-6); }
             .dashboard__grid { display: grid; grid-template-columns: repeat(auto-fill, minmax(300px, 1fr)); gap: var(--space-4); }
             .dashboard__details { display: grid; grid-template-columns: 1fr 1fr; gap: var(--space-4); }
+            @media (max-width: 768px) {
+                .dashboard__grid, .dashboard__details { grid-template-columns: 1fr; }
+            }
         }
 
         <div class="dashboard" data-testid="dashboard-landing">
@@ -40,6 +44,7 @@ pub fn dashboard_landing() -> View {
                 }
             </Grid>
             if s.selected_project.get().is_some() {
+                {copy_markdown_button(store::summary_markdown(&s), "dashboard-copy-markdown".into())}
                 <div class="dashboard__details" data-testid="dashboard-details">
                     {engine_summary(s.category_breakdown.clone())}
                     {category_chart(s.category_breakdown.clone())}
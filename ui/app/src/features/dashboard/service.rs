@@ -1,5 +1,6 @@
 use rsc_compat::prelude::*;
 use crate::util::api::{api_get, ApiError};
+use crate::util::auth::query_param;
 use crate::features::dashboard::types::{
     DashboardProject, ComplianceSummary, TrendPoint, CategoryBreakdown,
 };
@@ -12,6 +13,7 @@ pub async fn fetch_projects() -> Result<Vec<DashboardProject>, ApiError> {
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse projects response".into(),
+        fields: Vec::new(),
     })?;
 
     let items = parsed.get("projects")
@@ -57,6 +59,7 @@ pub async fn fetch_trends(project_id: &str, since: &str) -> Result<Vec<TrendPoin
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse trends response".into(),
+        fields: Vec::new(),
     })?;
 
     let items = parsed.get("trends")
@@ -86,6 +89,7 @@ pub async fn fetch_categories(project_id: &str) -> Result<Vec<CategoryBreakdown>
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse categories response".into(),
+        fields: Vec::new(),
     })?;
 
     let items = parsed.get("categories")
@@ -116,8 +120,13 @@ pub async fn load_dashboard(store: &DashboardStore) {
         Ok(projects) => {
             store.projects.set(projects.clone());
 
-            // Auto-select the first project and load its details
-            if let Some(first) = projects.first() {
+            // Select the project requested via `?project=` (fleet drill-down),
+            // falling back to the first project, and load its details
+            let requested = query_param("project");
+            let first = requested
+                .and_then(|id| projects.iter().find(|p| p.id == id))
+                .or_else(|| projects.first());
+            if let Some(first) = first {
                 store.selected_project.set(Some(first.clone()));
 
                 // Load trend data for the past 30 days
@@ -2,6 +2,7 @@ use rsc_compat::prelude::*;
 use crate::features::dashboard::types::{
     DashboardProject, TrendPoint, CategoryBreakdown,
 };
+use crate::util::markdown::{self, SummaryRow};
 
 /// Central reactive store for the dashboard feature.
 /// All dashboard components read from these signals to ensure consistency.
@@ -86,6 +87,23 @@ pub fn project_count(store: &DashboardStore) -> Signal<usize> {
     derived(move || projects.get().len())
 }
 
+/// Derived signal: markdown summary of the selected project's category breakdown
+/// and compliance percentage, for "Copy as Markdown".
+pub fn summary_markdown(store: &DashboardStore) -> Signal<String> {
+    let selected = store.selected_project.clone();
+    let categories = store.category_breakdown.clone();
+    derived(move || {
+        let project = selected.get().unwrap_or_default();
+        let rows: Vec<SummaryRow> = categories.get().iter().map(|c| SummaryRow {
+            category: c.category.clone(),
+            passed: c.passed,
+            failed: c.failed,
+            skipped: c.skipped,
+        }).collect();
+        markdown::summary_markdown(&project.name, &rows, project.compliance_summary.percentage())
+    })
+}
+
 /// Select a project by its ID, updating the store's selected_project signal.
 /// Returns true if the project was found and selected.
 pub fn select_project(store: &DashboardStore, project_id: &str) -> bool {
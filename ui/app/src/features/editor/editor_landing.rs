@@ -3,6 +3,7 @@ use crate::features::editor::store::{self, EditorStore};
 use crate::features::editor::markdown_editor::markdown_editor;
 use crate::features::editor::markdown_preview::markdown_preview;
 use crate::features::editor::validation_panel::validation_panel;
+use crate::util::api::field_message;
 
 /// Editor landing page (FR-900..903).
 /// Provides a split-pane SRS editor with live preview,
@@ -17,6 +18,7 @@ pub fn editor_landing() -> View {
             .editor__panes { display: grid; grid-template-columns: 1fr 1fr; gap: var(--space-4); min-height: 500px; }
             .editor__actions { display: flex; gap: var(--space-3); align-items: center; }
             .editor__status { font-size: var(--font-size-sm); color: var(--color-text-muted); }
+            .editor__field-error { font-size: var(--font-size-sm); color: var(--color-error); }
         }
         <div class="editor" data-testid="editor-landing">
             <div class="editor__panes" data-testid="editor-panes">
@@ -30,6 +32,17 @@ pub fn editor_landing() -> View {
                 )}
                 {markdown_preview(s.content.clone())}
             </div>
+            {
+                if let Some(msg) = field_message(&s.field_errors.get(), "content") {
+                    view! {
+                        <span class="editor__field-error" data-testid="editor-content-error">
+                            {msg}
+                        </span>
+                    }
+                } else {
+                    view! {}
+                }
+            }
             {validation_panel(s.validation.clone())}
             <div class="editor__actions" data-testid="editor-actions">
                 <Button
@@ -1,6 +1,7 @@
 use rsc_compat::prelude::*;
 use crate::features::editor::types::ValidationResult;
 use crate::features::editor::service;
+use crate::util::api::{report_error, FieldError};
 
 /// Central reactive store for the editor feature.
 /// All editor components read from these signals to ensure consistency.
@@ -25,7 +26,11 @@ pub struct EditorStore {
     pub loading: Signal<bool>,
 
     /// Last error message from a failed operation, if any.
+    /// Shown as a toast; errors tied to a request field go to `field_errors`.
     pub error: Signal<Option<String>>,
+
+    /// Per-field validation errors from the last validate/save, shown inline.
+    pub field_errors: Signal<Vec<FieldError>>,
 }
 
 impl EditorStore {
@@ -39,6 +44,7 @@ impl EditorStore {
             dirty: signal(false),
             loading: signal(false),
             error: signal(None),
+            field_errors: signal(Vec::new()),
         }
     }
 }
@@ -82,14 +88,15 @@ pub fn can_save(store: &EditorStore) -> Signal<bool> {
 pub async fn validate(store: &EditorStore) {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
     let content = store.content.get().clone();
     match service::validate_srs(&content).await {
         Ok(result) => {
             store.validation.set(Some(result));
         }
-        Err(msg) => {
-            store.error.set(Some(msg));
+        Err(err) => {
+            report_error(err, &store.error, &store.field_errors);
         }
     }
 
@@ -100,6 +107,7 @@ pub async fn validate(store: &EditorStore) {
 pub async fn load(store: &EditorStore, project_id: &str) {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
     match service::load_srs(project_id).await {
         Ok(doc) => {
@@ -109,8 +117,8 @@ pub async fn load(store: &EditorStore, project_id: &str) {
             store.saved.set(true);
             store.validation.set(None);
         }
-        Err(msg) => {
-            store.error.set(Some(msg));
+        Err(err) => {
+            report_error(err, &store.error, &store.field_errors);
         }
     }
 
@@ -129,6 +137,7 @@ pub async fn save(store: &EditorStore) {
 
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
     let content = store.content.get().clone();
     match service::save_srs(&pid, &content).await {
@@ -136,8 +145,8 @@ pub async fn save(store: &EditorStore) {
             store.dirty.set(false);
             store.saved.set(true);
         }
-        Err(msg) => {
-            store.error.set(Some(msg));
+        Err(err) => {
+            report_error(err, &store.error, &store.field_errors);
         }
     }
 
@@ -153,4 +162,5 @@ pub fn reset_store(store: &EditorStore) {
     store.dirty.set(false);
     store.loading.set(false);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 }
@@ -0,0 +1,170 @@
+use rsc_compat::prelude::*;
+use crate::features::fleet::store::{self, FleetStore};
+use crate::features::fleet::service;
+use crate::features::fleet::types::{FleetEntry, FleetSort};
+use crate::features::fleet::sparkline::sparkline;
+use crate::page::app::AppRoute;
+
+/// Arrow marking the active sort column.
+fn sort_marker(active: FleetSort, descending: bool, column: FleetSort) -> &'static str {
+    match (active == column, descending) {
+        (false, _) => "",
+        (true, false) => " \u{25B2}",
+        (true, true) => " \u{25BC}",
+    }
+}
+
+/// Arrow shown for a row's trend against the previous scan.
+fn trend_arrow(entry: &FleetEntry) -> &'static str {
+    match entry.trend.as_deref() {
+        Some("up") => "\u{2191}",
+        Some("down") => "\u{2193}",
+        Some("flat") => "\u{2192}",
+        _ => "",
+    }
+}
+
+/// Fleet overview listing every project's latest coverage (FR-204).
+#[component]
+pub fn fleet_landing() -> View {
+    let s = use_context::<FleetStore>();
+    let route = use_context::<AppRoute>();
+
+    effect({
+        let s = s.clone();
+        move || {
+            // Reading the engine filter re-runs the load whenever it changes.
+            let _engine = s.engine_filter.get();
+            let s = s.clone();
+            spawn(async move { service::load_fleet(&s).await; });
+        }
+    });
+
+    let visible = store::visible_entries(&s);
+    let tags = store::all_tags(&s);
+
+    let open_project = {
+        let route = route.clone();
+        move |id: String| route.go(&format!("/dashboard?project={id}"))
+    };
+
+    view! {
+        style {
+            .fleet { display: flex; flex-direction: column; gap: var(--space-4); }
+            .fleet__filters { display: flex; gap: var(--space-3); align-items: flex-end; flex-wrap: wrap; }
+            .fleet__sortable { cursor: pointer; user-select: none; }
+            .fleet__row { cursor: pointer; }
+            .fleet__row:hover { background: var(--color-hover); }
+            .fleet__coverage { font-variant-numeric: tabular-nums; font-weight: 600; }
+            .fleet__trend--up { color: var(--color-success); }
+            .fleet__trend--down { color: var(--color-error); }
+            .fleet__tags { display: flex; gap: var(--space-1); flex-wrap: wrap; }
+            .fleet__empty { padding: var(--space-8); text-align: center; color: var(--color-text-muted); }
+            .fleet__error { color: var(--color-error); font-size: var(--font-size-sm); }
+        }
+
+        <div class="fleet" data-testid="fleet-landing">
+            if s.loading.get() {
+                <Progress indeterminate={true} data-testid="fleet-loading" />
+            }
+            {
+                if let Some(err) = s.error.get() {
+                    view! { <div class="fleet__error" data-testid="fleet-error">{err}</div> }
+                } else {
+                    view! {}
+                }
+            }
+            <div class="fleet__filters" data-testid="fleet-filters">
+                <FormField label="Engine">
+                    <Select
+                        value={s.engine_filter.get().unwrap_or_default()}
+                        on:change={let s2 = s.clone(); move |v: String| s2.engine_filter.set(if v.is_empty() { None } else { Some(v) })}
+                        data-testid="fleet-filter-engine"
+                    >
+                        <option value="">"All Engines"</option>
+                        <option value="doc-engine">"doc-engine"</option>
+                        <option value="struct-engine">"struct-engine"</option>
+                    </Select>
+                </FormField>
+                <FormField label="Tag">
+                    <Select
+                        value={s.tag_filter.get().unwrap_or_default()}
+                        on:change={let s2 = s.clone(); move |v: String| s2.tag_filter.set(if v.is_empty() { None } else { Some(v) })}
+                        data-testid="fleet-filter-tag"
+                    >
+                        <option value="">"All Tags"</option>
+                        for tag in tags.get().iter() {
+                            <option value={tag.clone()}>{tag.clone()}</option>
+                        }
+                    </Select>
+                </FormField>
+            </div>
+            if s.entries.get().is_empty() && !s.loading.get() {
+                <Card class="fleet__empty" data-testid="fleet-empty">
+                    <p>"No projects yet. Register a project to compare its compliance across the fleet."</p>
+                    <Button label="Go to Projects" variant="primary"
+                            on:click={let route = route.clone(); move || route.go("/projects")}
+                            data-testid="fleet-empty-projects" />
+                </Card>
+            } else if visible.get().is_empty() {
+                <div class="fleet__empty" data-testid="fleet-no-matches">
+                    "No projects match the selected tag."
+                </div>
+            } else {
+                <Table data-testid="fleet-table">
+                    <thead>
+                        <tr>
+                            <th class="fleet__sortable" on:click={let s2 = s.clone(); move || s2.sort_by(FleetSort::Name)} data-testid="fleet-sort-name">
+                                "Project" {sort_marker(s.sort.get(), s.descending.get(), FleetSort::Name)}
+                            </th>
+                            <th>"Status"</th>
+                            <th class="fleet__sortable" on:click={let s2 = s.clone(); move || s2.sort_by(FleetSort::Coverage)} data-testid="fleet-sort-coverage">
+                                "Coverage" {sort_marker(s.sort.get(), s.descending.get(), FleetSort::Coverage)}
+                            </th>
+                            <th class="fleet__sortable" on:click={let s2 = s.clone(); move || s2.sort_by(FleetSort::Trend)} data-testid="fleet-sort-trend">
+                                "Trend" {sort_marker(s.sort.get(), s.descending.get(), FleetSort::Trend)}
+                            </th>
+                            <th>"Engine"</th>
+                            <th>"Tags"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        for entry in visible.get().iter() {
+                            <tr class="fleet__row"
+                                on:click={let open = open_project.clone(); let id = entry.project_id.clone(); move || open(id.clone())}
+                                tabindex="0"
+                                role="link"
+                                aria-label={format!("Open dashboard for {}", entry.name)}
+                                data-testid={format!("fleet-row-{}", entry.project_id)}>
+                                <td data-testid="fleet-name">{&entry.name}</td>
+                                <td>
+                                    <Badge variant={entry.status()} data-testid="fleet-status">
+                                        {entry.status_label()}
+                                    </Badge>
+                                </td>
+                                <td class="fleet__coverage" data-testid="fleet-coverage">
+                                    {entry.coverage.map(|c| format!("{c:.1}%")).unwrap_or_else(|| "--".into())}
+                                </td>
+                                <td data-testid="fleet-trend">
+                                    <span class:fleet__trend--up={entry.trend.as_deref() == Some("up")}
+                                          class:fleet__trend--down={entry.trend.as_deref() == Some("down")}>
+                                        {trend_arrow(entry)}
+                                    </span>
+                                    {sparkline(entry.history.clone())}
+                                </td>
+                                <td data-testid="fleet-engine">{entry.engine.as_deref().unwrap_or("--")}</td>
+                                <td>
+                                    <div class="fleet__tags">
+                                        for tag in entry.tags.iter() {
+                                            <Badge variant="neutral" data-testid="fleet-tag">{tag.clone()}</Badge>
+                                        }
+                                    </div>
+                                </td>
+                            </tr>
+                        }
+                    </tbody>
+                </Table>
+            }
+        </div>
+    }
+}
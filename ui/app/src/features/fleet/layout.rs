@@ -0,0 +1,33 @@
+use rsc_compat::prelude::*;
+
+/// Fleet feature layout wrapper.
+/// Provides the page heading and consistent spacing for the fleet route.
+#[component]
+pub fn fleet_layout(children: Children) -> View {
+    view! {
+        style {
+            .fleet-layout {
+                display: flex;
+                flex-direction: column;
+                gap: var(--space-6);
+                width: 100%;
+                max-width: 1400px;
+                margin: 0 auto;
+            }
+
+            .fleet-layout__heading {
+                font-size: var(--font-size-2xl);
+                font-weight: 700;
+                color: var(--color-text);
+                margin: 0;
+            }
+        }
+
+        <section class="fleet-layout" data-testid="fleet-layout" aria-label="Fleet Overview">
+            <h1 class="fleet-layout__heading" data-testid="fleet-heading">
+                "Fleet Overview"
+            </h1>
+            {children}
+        </section>
+    }
+}
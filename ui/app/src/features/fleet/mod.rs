@@ -0,0 +1,9 @@
+pub mod types;
+pub mod store;
+pub mod service;
+pub mod layout;
+pub mod fleet_landing;
+pub mod sparkline;
+
+#[cfg(test)]
+mod tests;
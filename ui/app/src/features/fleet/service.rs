@@ -0,0 +1,64 @@
+use rsc_compat::prelude::*;
+use crate::util::api::{api_get, ApiError};
+use crate::features::fleet::types::FleetEntry;
+use crate::features::fleet::store::FleetStore;
+
+/// Fetch every project's latest compliance, optionally scoped to one engine.
+/// GET /api/v1/fleet?engine={engine}
+pub async fn fetch_fleet(engine: Option<&str>) -> Result<Vec<FleetEntry>, ApiError> {
+    let path = match engine {
+        Some(engine) => format!("/fleet?engine={engine}"),
+        None => "/fleet".to_string(),
+    };
+    let response = api_get(&path).await?;
+    let parsed = json_parse(&response).ok_or_else(|| ApiError {
+        code: "PARSE_ERROR".into(),
+        message: "failed to parse fleet response".into(),
+        fields: Vec::new(),
+    })?;
+
+    let items = parsed.as_array().cloned().unwrap_or_default();
+    let mut entries = Vec::new();
+
+    for item in items.iter() {
+        let strings = |key: &str| -> Vec<String> {
+            item.get(key)
+                .and_then(|v| v.as_array())
+                .map(|a| a.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                .unwrap_or_default()
+        };
+
+        entries.push(FleetEntry {
+            project_id: item.get("project_id").and_then(|v| v.as_str()).unwrap_or_default().into(),
+            name: item.get("name").and_then(|v| v.as_str()).unwrap_or_default().into(),
+            tags: strings("tags"),
+            scan_id: item.get("scan_id").and_then(|v| v.as_str()).map(String::from),
+            engine: item.get("engine").and_then(|v| v.as_str()).map(String::from),
+            passed: item.get("passed").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+            failed: item.get("failed").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+            skipped: item.get("skipped").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+            coverage: item.get("coverage").and_then(|v| v.as_f64()),
+            trend: item.get("trend").and_then(|v| v.as_str()).map(String::from),
+            history: item.get("history")
+                .and_then(|v| v.as_array())
+                .map(|a| a.iter().filter_map(|v| v.as_f64()).collect())
+                .unwrap_or_default(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Load the fleet for the store's engine filter. Updates the store signals directly.
+pub async fn load_fleet(store: &FleetStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    let engine = store.engine_filter.get();
+    match fetch_fleet(engine.as_deref()).await {
+        Ok(entries) => store.entries.set(entries),
+        Err(e) => store.error.set(Some(e.message.clone())),
+    }
+
+    store.loading.set(false);
+}
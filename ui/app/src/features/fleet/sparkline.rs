@@ -0,0 +1,52 @@
+use rsc_compat::prelude::*;
+
+/// Width of the sparkline viewBox.
+const WIDTH: f64 = 96.0;
+/// Height of the sparkline viewBox.
+const HEIGHT: f64 = 24.0;
+
+/// SVG polyline points for coverage percentages plotted on a 0..100 scale.
+pub fn sparkline_points(history: &[f64]) -> String {
+    let step = if history.len() > 1 { WIDTH / (history.len() - 1) as f64 } else { 0.0 };
+    history
+        .iter()
+        .enumerate()
+        .map(|(i, pct)| {
+            let y = HEIGHT - (pct.clamp(0.0, 100.0) / 100.0) * HEIGHT;
+            format!("{:.1},{:.1}", i as f64 * step, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Inline coverage trend line for a fleet row (FR-204).
+#[component]
+pub fn sparkline(history: Vec<f64>) -> View {
+    let points = sparkline_points(&history);
+    let label = match (history.first(), history.last()) {
+        (Some(first), Some(last)) => format!("Coverage from {first:.1}% to {last:.1}% over {} scans", history.len()),
+        _ => "No scan history".to_string(),
+    };
+
+    view! {
+        style {
+            .sparkline { display: block; }
+            .sparkline__line { fill: none; stroke: var(--color-primary); stroke-width: 1.5; }
+            .sparkline__empty { color: var(--color-text-muted); font-size: var(--font-size-xs); }
+        }
+
+        if history.len() < 2 {
+            <span class="sparkline__empty" data-testid="fleet-sparkline-empty">"—"</span>
+        } else {
+            <svg class="sparkline"
+                 viewBox={format!("0 0 {WIDTH} {HEIGHT}")}
+                 width={WIDTH.to_string()}
+                 height={HEIGHT.to_string()}
+                 role="img"
+                 aria-label={label}
+                 data-testid="fleet-sparkline">
+                <polyline class="sparkline__line" points={points} />
+            </svg>
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+use rsc_compat::prelude::*;
+use crate::features::fleet::types::{FleetEntry, FleetSort};
+
+/// Reactive store for the fleet overview (FR-204).
+#[derive(Clone)]
+pub struct FleetStore {
+    /// Entries returned by the fleet API for the active engine filter.
+    pub entries: Signal<Vec<FleetEntry>>,
+    /// Active sort column.
+    pub sort: Signal<FleetSort>,
+    /// Sort descending instead of ascending.
+    pub descending: Signal<bool>,
+    /// Only show projects carrying this tag.
+    pub tag_filter: Signal<Option<String>>,
+    /// Only consider scans from this engine (applied server-side).
+    pub engine_filter: Signal<Option<String>>,
+    /// Whether a fleet request is in flight.
+    pub loading: Signal<bool>,
+    /// Last error message from a failed request, if any.
+    pub error: Signal<Option<String>>,
+}
+
+impl FleetStore {
+    /// Creates a new store sorted by coverage, worst first.
+    pub fn new() -> Self {
+        Self {
+            entries: signal(Vec::new()),
+            sort: signal(FleetSort::default()),
+            descending: signal(false),
+            tag_filter: signal(None),
+            engine_filter: signal(None),
+            loading: signal(false),
+            error: signal(None),
+        }
+    }
+
+    /// Sort by `sort`, flipping the direction when it is already active.
+    pub fn sort_by(&self, sort: FleetSort) {
+        if self.sort.get() == sort {
+            self.descending.set(!self.descending.get());
+        } else {
+            self.sort.set(sort);
+            self.descending.set(false);
+        }
+    }
+}
+
+/// Derived signal: entries matching the tag filter, in the active sort order.
+/// Entries missing the sorted value stay last regardless of direction.
+pub fn visible_entries(store: &FleetStore) -> Signal<Vec<FleetEntry>> {
+    let entries = store.entries.clone();
+    let sort = store.sort.clone();
+    let descending = store.descending.clone();
+    let tag_filter = store.tag_filter.clone();
+    derived(move || {
+        let tag = tag_filter.get();
+        let mut list: Vec<FleetEntry> = entries
+            .get()
+            .iter()
+            .filter(|e| tag.as_ref().map_or(true, |t| e.tags.contains(t)))
+            .cloned()
+            .collect();
+
+        let sort = sort.get();
+        let descending = descending.get();
+        list.sort_by(|a, b| {
+            let ordering = match sort {
+                FleetSort::Coverage => match (a.coverage, b.coverage) {
+                    (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+                    (Some(_), None) => return Ordering::Less,
+                    (None, Some(_)) => return Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                },
+                FleetSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+                FleetSort::Trend => match (a.trend.is_some(), b.trend.is_some()) {
+                    (true, false) => return Ordering::Less,
+                    (false, true) => return Ordering::Greater,
+                    _ => a.trend_rank().cmp(&b.trend_rank()),
+                },
+            };
+            let ordering = if descending { ordering.reverse() } else { ordering };
+            ordering.then_with(|| a.name.cmp(&b.name))
+        });
+        list
+    })
+}
+
+/// Derived signal: every tag used across the loaded projects, sorted.
+pub fn all_tags(store: &FleetStore) -> Signal<Vec<String>> {
+    let entries = store.entries.clone();
+    derived(move || {
+        let mut tags: Vec<String> = entries
+            .get()
+            .iter()
+            .flat_map(|e| e.tags.iter().cloned())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    })
+}
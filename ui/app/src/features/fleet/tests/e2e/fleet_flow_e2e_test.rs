@@ -0,0 +1,41 @@
+use rsc_e2e_test::*;
+use crate::test_common::{test_config, setup_auth};
+
+/// Fleet landing must render its filters.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn fleet_landing_renders(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/fleet", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='fleet-landing']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='fleet-filters']").await;
+}
+
+/// Sidebar must link to the fleet overview.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn fleet_nav_opens_landing(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/dashboard", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.click("[data-testid='nav-fleet']").await.unwrap();
+    ctx.wait_for("[data-testid='fleet-landing']").await.unwrap();
+    ctx.assert_element_exists("[data-testid='fleet-landing']").await;
+}
+
+/// Admins land on the fleet overview instead of the dashboard.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn fleet_is_admin_landing(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.set_local_storage("swe_auth_admin", "true").await.unwrap();
+    ctx.navigate("/", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='fleet-landing']").await.unwrap();
+    ctx.assert_element_exists("[data-testid='fleet-landing']").await;
+}
+
+/// The engine filter must offer both engines.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn fleet_engine_filter_selects(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/fleet", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='fleet-filter-engine']").await.unwrap();
+    ctx.select("[data-testid='fleet-filter-engine']", "struct-engine").await.unwrap();
+    ctx.wait_for("[data-testid='fleet-landing']").await.unwrap();
+}
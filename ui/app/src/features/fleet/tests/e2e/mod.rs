@@ -0,0 +1 @@
+mod fleet_flow_e2e_test;
@@ -0,0 +1,79 @@
+/// A project's row in the fleet overview, as returned by `GET /api/v1/fleet`.
+#[derive(Clone, Default)]
+pub struct FleetEntry {
+    pub project_id: String,
+    pub name: String,
+    pub tags: Vec<String>,
+    /// Latest completed scan; `None` when the project has not been scanned.
+    pub scan_id: Option<String>,
+    pub engine: Option<String>,
+    pub passed: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    /// Percentage of applicable (non-skipped) checks that passed.
+    pub coverage: Option<f64>,
+    /// `up`, `down` or `flat` against the previous scan.
+    pub trend: Option<String>,
+    /// Coverage of recent scans, oldest first.
+    pub history: Vec<f64>,
+}
+
+impl FleetEntry {
+    /// Status chip variant for the latest coverage.
+    pub fn status(&self) -> &'static str {
+        match self.coverage {
+            Some(pct) if pct >= 90.0 => "success",
+            Some(pct) if pct >= 70.0 => "warning",
+            Some(_) => "error",
+            None => "neutral",
+        }
+    }
+
+    /// Status chip label for the latest coverage.
+    pub fn status_label(&self) -> &'static str {
+        match self.status() {
+            "success" => "Healthy",
+            "warning" => "At risk",
+            "error" => "Failing",
+            _ => "Not scanned",
+        }
+    }
+
+    /// Sort rank of the trend: falling first.
+    pub fn trend_rank(&self) -> u8 {
+        match self.trend.as_deref() {
+            Some("down") => 0,
+            Some("flat") => 1,
+            _ => 2,
+        }
+    }
+}
+
+/// Column the fleet table is sorted by.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum FleetSort {
+    #[default]
+    Coverage,
+    Name,
+    Trend,
+}
+
+impl FleetSort {
+    /// Parse the value of the sort selector.
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "name" => Self::Name,
+            "trend" => Self::Trend,
+            _ => Self::Coverage,
+        }
+    }
+
+    /// Value used by the sort selector.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Coverage => "coverage",
+            Self::Name => "name",
+            Self::Trend => "trend",
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod ai;
 pub mod dashboard;
 pub mod editor;
+pub mod fleet;
 pub mod projects;
 pub mod reports;
 pub mod scaffold;
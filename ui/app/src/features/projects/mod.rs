@@ -4,6 +4,7 @@ pub mod service;
 pub mod layout;
 pub mod project_landing;
 pub mod project_form;
+pub mod project_alerts;
 pub mod project_list;
 
 #[cfg(test)]
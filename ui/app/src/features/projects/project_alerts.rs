@@ -0,0 +1,303 @@
+use std::rc::Rc;
+use rsc_compat::prelude::*;
+use crate::features::projects::types::{AlertSettings, SaveAlertsRequest};
+use crate::features::projects::store;
+use crate::util::api::{field_message, FieldError};
+
+/// Threshold alert settings panel for a project (FR-105, admin only).
+///
+/// Edits the notification webhook URL and the thresholds a completed scan is
+/// checked against (max failures, min coverage, any new failure), shows whether
+/// the latest scan is within them, and sends a test notification on demand.
+///
+/// Reference: docs/1-requirements/srs.md FR-105
+#[component]
+pub fn project_alerts(
+    /// ID of the project whose alert settings are edited.
+    project_id: String,
+) -> View {
+    let projects_store = use_context::<store::ProjectsStore>();
+    effect({
+        let s = projects_store.clone();
+        let id = project_id.clone();
+        move || store::load_alerts(&s, id.clone())
+    });
+
+    let alerts = store::use_alerts(&projects_store);
+    let loading = store::use_loading(&projects_store);
+    let error = store::use_alert_error(&projects_store);
+    let notice = store::use_alert_notice(&projects_store);
+    let field_errors = store::use_alert_field_errors(&projects_store);
+
+    // Form field signals, filled from the settings once they load.
+    let webhook_url = signal(String::new());
+    let max_failures = signal(String::new());
+    let min_coverage = signal(String::new());
+    let on_new_failures = signal(false);
+    effect({
+        let alerts = alerts.clone();
+        let (url, max, min, on_new) =
+            (webhook_url.clone(), max_failures.clone(), min_coverage.clone(), on_new_failures.clone());
+        move || {
+            if let Some(a) = alerts.get() {
+                url.set(a.webhook_url.clone().unwrap_or_default());
+                max.set(a.max_failures.map(|v| v.to_string()).unwrap_or_default());
+                min.set(a.min_coverage.map(|v| v.to_string()).unwrap_or_default());
+                on_new.set(a.on_new_failures);
+            }
+        }
+    });
+
+    let configured = derived({
+        let alerts = alerts.clone();
+        move || alerts.get().map(|a| a.configured).unwrap_or(false)
+    });
+
+    let handle_save: Rc<dyn Fn()> = Rc::new({
+        let s = projects_store.clone();
+        let id = project_id.clone();
+        let (url, max, min, on_new) =
+            (webhook_url.clone(), max_failures.clone(), min_coverage.clone(), on_new_failures.clone());
+        move || {
+            match validate_alerts(&url.get(), &max.get(), &min.get(), on_new.get()) {
+                Ok(req) => store::save_alerts(&s, id.clone(), req),
+                Err(fields) => s.alert_field_errors.set(fields),
+            }
+        }
+    });
+    let handle_test: Rc<dyn Fn()> = Rc::new({
+        let s = projects_store.clone();
+        let id = project_id.clone();
+        move || store::send_test_alert(&s, id.clone())
+    });
+    let handle_remove: Rc<dyn Fn()> = Rc::new({
+        let s = projects_store.clone();
+        let id = project_id.clone();
+        move || store::delete_alerts(&s, id.clone())
+    });
+
+    view! {
+        style {
+            .project-alerts {
+                display: flex;
+                flex-direction: column;
+                gap: var(--space-3);
+                padding-top: var(--space-4);
+                border-top: 1px solid var(--color-border);
+            }
+
+            .project-alerts__header {
+                display: flex;
+                justify-content: space-between;
+                align-items: center;
+            }
+
+            .project-alerts__title {
+                font-size: var(--font-size-md);
+                font-weight: 600;
+                margin: 0;
+            }
+
+            .project-alerts__empty,
+            .project-alerts__reasons {
+                color: var(--color-text-secondary);
+                font-size: var(--font-size-sm);
+            }
+
+            .project-alerts__field-error {
+                color: var(--color-error);
+                font-size: var(--font-size-sm);
+            }
+
+            .project-alerts__actions {
+                display: flex;
+                gap: var(--space-3);
+                justify-content: flex-end;
+            }
+        }
+
+        <div class="project-alerts" data-testid="project-alerts">
+            <div class="project-alerts__header">
+                <h3 class="project-alerts__title">"Alerts"</h3>
+                {status_view(&alerts.get())}
+            </div>
+
+            {reasons_view(&alerts.get())}
+
+            if !configured.get() {
+                <p class="project-alerts__empty" data-testid="project-alerts-empty">
+                    "No alerting configured. Set a webhook URL and at least one threshold to be notified when a scan breaches it."
+                </p>
+            }
+
+            if let Some(err) = error.get() {
+                <div class="toast toast--danger" role="alert" data-testid="project-alerts-error">{err}</div>
+            }
+            if let Some(msg) = notice.get() {
+                <div class="toast toast--success" role="status" data-testid="project-alerts-notice">{msg}</div>
+            }
+
+            <div class="form-group">
+                <FormField label="Webhook URL">
+                    <Input
+                        value={webhook_url.clone()}
+                        on:input={let u = webhook_url.clone(); move |v: String| u.set(v)}
+                        placeholder="https://hooks.example.com/compliance"
+                        disabled={loading.get()}
+                        data-testid="project-alerts-webhook-url"
+                    />
+                    {field_error_view(&field_errors.get(), "webhook_url")}
+                </FormField>
+
+                <FormField label="Max failed checks">
+                    <Input
+                        value={max_failures.clone()}
+                        on:input={let m = max_failures.clone(); move |v: String| m.set(v)}
+                        placeholder="e.g. 0"
+                        disabled={loading.get()}
+                        data-testid="project-alerts-max-failures"
+                    />
+                    {field_error_view(&field_errors.get(), "max_failures")}
+                </FormField>
+
+                <FormField label="Min coverage (%)">
+                    <Input
+                        value={min_coverage.clone()}
+                        on:input={let m = min_coverage.clone(); move |v: String| m.set(v)}
+                        placeholder="e.g. 80"
+                        disabled={loading.get()}
+                        data-testid="project-alerts-min-coverage"
+                    />
+                    {field_error_view(&field_errors.get(), "min_coverage")}
+                </FormField>
+
+                <Checkbox
+                    label="Alert on any new failure"
+                    checked={on_new_failures.get()}
+                    on:change={let o = on_new_failures.clone(); move |_v: String| o.set(!o.get())}
+                    data-testid="project-alerts-on-new-failures"
+                />
+
+                <div class="project-alerts__actions">
+                    if configured.get() {
+                        <Button
+                            label="Remove Alerts"
+                            variant="danger"
+                            on:click={let h = handle_remove.clone(); move || h()}
+                            disabled={loading.get()}
+                            data-testid="project-alerts-remove"
+                        />
+                        <Button
+                            label="Send test notification"
+                            variant="secondary"
+                            on:click={let h = handle_test.clone(); move || h()}
+                            disabled={loading.get()}
+                            data-testid="project-alerts-test"
+                        />
+                    }
+                    <Button
+                        label="Save Alerts"
+                        variant="primary"
+                        on:click={let h = handle_save.clone(); move || h()}
+                        disabled={loading.get()}
+                        data-testid="project-alerts-save"
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Check the alert form before it is sent, mirroring the server's rules:
+/// an http(s) webhook URL, a whole-number failure limit, coverage within
+/// 0-100, and at least one threshold.
+pub fn validate_alerts(
+    webhook_url: &str,
+    max_failures: &str,
+    min_coverage: &str,
+    on_new_failures: bool,
+) -> Result<SaveAlertsRequest, Vec<FieldError>> {
+    let mut fields = Vec::new();
+
+    let webhook_url = webhook_url.trim();
+    if !(webhook_url.starts_with("http://") || webhook_url.starts_with("https://")) {
+        reject(&mut fields, "webhook_url", "must be an http:// or https:// URL");
+    }
+    let max_failures = match max_failures.trim() {
+        "" => None,
+        v => match v.parse::<u32>() {
+            Ok(n) => Some(n),
+            Err(_) => {
+                reject(&mut fields, "max_failures", "must be a whole number");
+                None
+            }
+        },
+    };
+    let min_coverage = match min_coverage.trim() {
+        "" => None,
+        v => match v.parse::<f64>() {
+            Ok(pct) if (0.0..=100.0).contains(&pct) => Some(pct),
+            _ => {
+                reject(&mut fields, "min_coverage", "must be a percentage between 0 and 100");
+                None
+            }
+        },
+    };
+    if fields.is_empty() && max_failures.is_none() && min_coverage.is_none() && !on_new_failures {
+        // The server reports a missing threshold against max_failures too.
+        reject(&mut fields, "max_failures", "set at least one of max failures, min coverage, or alert on any new failure");
+    }
+
+    if !fields.is_empty() {
+        return Err(fields);
+    }
+    Ok(SaveAlertsRequest {
+        webhook_url: webhook_url.to_string(),
+        max_failures,
+        min_coverage,
+        on_new_failures,
+    })
+}
+
+/// Record a validation failure on one form field.
+fn reject(fields: &mut Vec<FieldError>, field: &str, message: &str) {
+    fields.push(FieldError { field: field.into(), message: message.into() });
+}
+
+/// OK / Breached chip for the latest scan; nothing when there is no status.
+fn status_view(alerts: &Option<AlertSettings>) -> View {
+    match alerts.as_ref().and_then(|a| a.status.as_deref()) {
+        Some("breached") => view! {
+            <span class="badge badge--danger" data-testid="project-alerts-status">"Breached"</span>
+        },
+        Some(_) => view! {
+            <span class="badge badge--success" data-testid="project-alerts-status">"OK"</span>
+        },
+        None => view! {},
+    }
+}
+
+/// Why the latest scan breaches the thresholds, if it does.
+fn reasons_view(alerts: &Option<AlertSettings>) -> View {
+    match alerts.as_ref().filter(|a| !a.reasons.is_empty()) {
+        Some(a) => {
+            let text = format!("Latest scan: {}", a.reasons.join("; "));
+            view! {
+                <p class="project-alerts__reasons" data-testid="project-alerts-reasons">{text}</p>
+            }
+        }
+        None => view! {},
+    }
+}
+
+/// Inline message under an input for a field that failed validation, if any.
+fn field_error_view(fields: &[FieldError], field: &str) -> View {
+    match field_message(fields, field) {
+        Some(msg) => view! {
+            <span class="project-alerts__field-error" data-testid={format!("project-alerts-{}-error", field.replace('_', "-"))}>
+                {msg}
+            </span>
+        },
+        None => view! {},
+    }
+}
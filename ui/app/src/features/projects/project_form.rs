@@ -4,6 +4,7 @@ use crate::features::projects::types::{
     ProjectScope, ProjectType, CreateProjectRequest, UpdateProjectRequest, Project,
 };
 use crate::features::projects::store;
+use crate::util::api::{field_message, FieldError};
 
 /// Project creation and editing form component (FR-100, FR-102).
 ///
@@ -43,6 +44,7 @@ pub fn project_form(
     let projects_store = use_context::<store::ProjectsStore>();
     let loading = store::use_loading(&projects_store);
     let error = store::use_error(&projects_store);
+    let field_errors = store::use_field_errors(&projects_store);
 
     // Derived validation: name and root_path are required.
     let name_v = name.clone();
@@ -64,6 +66,7 @@ pub fn project_form(
     let project_type_submit = project_type.clone();
     let name_submit = name.clone();
     let root_path_submit = root_path.clone();
+    let dismiss_store = projects_store.clone();
     let handle_submit: Rc<dyn Fn()> = Rc::new(move || {
         if !form_valid.get() {
             return;
@@ -109,12 +112,9 @@ pub fn project_form(
                 margin: 0 0 var(--space-2) 0;
             }
 
-            .project-form__error {
+            .project-form__field-error {
                 color: var(--color-error);
                 font-size: var(--font-size-sm);
-                padding: var(--space-2) var(--space-3);
-                background: var(--color-error-bg);
-                border-radius: var(--radius-md);
             }
 
             .project-form__actions {
@@ -135,10 +135,14 @@ pub fn project_form(
             </h2>
 
             if has_error.get() {
-                <div aria-live="polite" class="live-region">
-                    <div class="project-form__error" data-testid="project-form-error">
-                        {error_message.get()}
-                    </div>
+                <div class="toast toast--danger" role="alert" data-testid="project-form-error">
+                    {error_message.get()}
+                    <button
+                        class="toast__dismiss"
+                        on:click={let ps = dismiss_store.clone(); move || store::clear_error(&ps)}
+                    >
+                        "Dismiss"
+                    </button>
                 </div>
             }
 
@@ -151,6 +155,7 @@ pub fn project_form(
                         disabled={loading.get()}
                         data-testid="project-form-name"
                     />
+                    {field_error_view(&field_errors.get(), "name")}
                 </FormField>
 
                 <FormField label="Root Path">
@@ -161,6 +166,7 @@ pub fn project_form(
                         disabled={editing || loading.get()}
                         data-testid="project-form-root-path"
                     />
+                    {field_error_view(&field_errors.get(), "root_path")}
                 </FormField>
 
                 <FormField label="Scope">
@@ -174,6 +180,7 @@ pub fn project_form(
                             <option value={s.value()}>{s.label()}</option>
                         }
                     </Select>
+                    {field_error_view(&field_errors.get(), "scope")}
                 </FormField>
 
                 <FormField label="Project Type">
@@ -187,6 +194,7 @@ pub fn project_form(
                             <option value={pt.value()}>{pt.label()}</option>
                         }
                     </Select>
+                    {field_error_view(&field_errors.get(), "project_type")}
                 </FormField>
 
                 <div class="project-form__actions">
@@ -209,3 +217,15 @@ pub fn project_form(
         </div>
     }
 }
+
+/// Inline message under an input for a field the server rejected, if any.
+fn field_error_view(fields: &[FieldError], field: &str) -> View {
+    match field_message(fields, field) {
+        Some(msg) => view! {
+            <span class="project-form__field-error" data-testid={format!("project-form-{}-error", field.replace('_', "-"))}>
+                {msg}
+            </span>
+        },
+        None => view! {},
+    }
+}
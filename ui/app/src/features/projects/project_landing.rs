@@ -1,13 +1,17 @@
 use std::rc::Rc;
 use rsc_compat::prelude::*;
 use crate::features::projects::store::{self, ProjectsStore};
+use crate::features::projects::project_alerts::project_alerts;
 use crate::features::projects::project_form::project_form;
 use crate::features::projects::project_list::project_list;
+use crate::util::auth::{is_admin, is_read_only};
 
-/// Projects management page (FR-100..104).
+/// Projects management page (FR-100..105).
 #[component]
 pub fn projects_landing() -> View {
     let s = use_context::<ProjectsStore>();
+    let read_only = is_read_only();
+    let admin = is_admin();
     effect({
         let s = s.clone();
         move || { store::load_projects(&s); }
@@ -16,6 +20,7 @@ pub fn projects_landing() -> View {
     let s_btn = s.clone();
     let s_form = s.clone();
     let s_cancel = s.clone();
+    let s_alerts = s.clone();
     let s_edit = s.clone();
     let s_delete = s.clone();
 
@@ -28,12 +33,14 @@ pub fn projects_landing() -> View {
         <div class="projects" data-testid="projects-landing">
             <div class="projects__header">
                 <h2>"Projects"</h2>
-                <Button label="New Project" variant="primary" on:click={
-                    let s = s_btn.clone();
-                    move || store::open_create_form(&s)
-                } data-testid="new-project-btn" />
+                if !read_only {
+                    <Button label="New Project" variant="primary" on:click={
+                        let s = s_btn.clone();
+                        move || store::open_create_form(&s)
+                    } data-testid="new-project-btn" />
+                }
             </div>
-            if store::use_form_open(&s_form).get() {
+            if !read_only && store::use_form_open(&s_form).get() {
                 {project_form(
                     store::use_selected_project(&s_form).get(),
                     store::use_editing(&s_form).get(),
@@ -43,20 +50,25 @@ pub fn projects_landing() -> View {
                     })),
                 )}
             }
+            if !read_only && admin && store::use_editing(&s_alerts).get() {
+                if let Some(p) = store::use_selected_project(&s_alerts).get() {
+                    {project_alerts(p.id)}
+                }
+            }
             {project_list(
                 store::use_projects(&s),
-                Some(Box::new({
+                (!read_only).then(|| Box::new({
                     let s = s_edit.clone();
                     move |id: String| {
                         if let Some(p) = s.projects.get().iter().find(|p| p.id == id).cloned() {
                             store::open_edit_form(&s, p);
                         }
                     }
-                })),
-                Some(Box::new({
+                }) as Box<dyn Fn(String)>),
+                (!read_only).then(|| Box::new({
                     let s = s_delete.clone();
                     move |id: String| store::delete_project(&s, id)
-                })),
+                }) as Box<dyn Fn(String)>),
             )}
         </div>
     }
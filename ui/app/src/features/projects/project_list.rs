@@ -2,6 +2,8 @@ use rsc_compat::prelude::*;
 use crate::features::projects::types::Project;
 
 /// Table displaying all projects with sortable columns (FR-101).
+///
+/// Edit/Delete buttons are only rendered when the matching callback is provided.
 #[component]
 pub fn project_list(
     projects: Signal<Vec<Project>>,
@@ -32,16 +34,20 @@ pub fn project_list(
                             {project.last_scan_id.as_deref().unwrap_or("Never")}
                         </td>
                         <td class="project-list__actions">
-                            <Button label="Edit" variant="secondary" on:click={
-                                let on_edit = on_edit.clone();
-                                let pid = project.id.clone();
-                                move || { if let Some(ref cb) = on_edit { cb(pid.clone()) } }
-                            } data-testid="edit-btn" />
-                            <Button label="Delete" variant="danger" on:click={
-                                let on_delete = on_delete.clone();
-                                let pid = project.id.clone();
-                                move || { if let Some(ref cb) = on_delete { cb(pid.clone()) } }
-                            } data-testid="delete-btn" />
+                            if on_edit.is_some() {
+                                <Button label="Edit" variant="secondary" on:click={
+                                    let on_edit = on_edit.clone();
+                                    let pid = project.id.clone();
+                                    move || { if let Some(ref cb) = on_edit { cb(pid.clone()) } }
+                                } data-testid="edit-btn" />
+                            }
+                            if on_delete.is_some() {
+                                <Button label="Delete" variant="danger" on:click={
+                                    let on_delete = on_delete.clone();
+                                    let pid = project.id.clone();
+                                    move || { if let Some(ref cb) = on_delete { cb(pid.clone()) } }
+                                } data-testid="delete-btn" />
+                            }
                         </td>
                     </tr>
                 }
@@ -1,9 +1,9 @@
-use crate::util::api::{api_get, api_post, api_patch, api_delete, ApiError};
+use crate::util::api::{api_get, api_post, api_patch, api_put, api_delete, ApiError};
 use crate::features::projects::types::{
-    Project, CreateProjectRequest, UpdateProjectRequest,
+    Project, ProjectPage, CreateProjectRequest, UpdateProjectRequest, AlertSettings, SaveAlertsRequest,
 };
 
-/// API service for project CRUD operations (FR-100..FR-104).
+/// API service for project CRUD operations and alert settings (FR-100..FR-105).
 ///
 /// All functions delegate to the shared API client in `crate::util::api`,
 /// which handles JWT injection, error parsing, and response deserialization.
@@ -23,6 +23,7 @@ pub async fn create_project(req: &CreateProjectRequest) -> Result<Project, ApiEr
         .ok_or_else(|| ApiError {
             code: "PARSE_ERROR".into(),
             message: "failed to parse project response".into(),
+            fields: Vec::new(),
         })?;
     Ok(project)
 }
@@ -30,16 +31,18 @@ pub async fn create_project(req: &CreateProjectRequest) -> Result<Project, ApiEr
 /// List all projects.
 /// `GET /api/v1/projects` (FR-101)
 ///
-/// Returns a Vec of all projects with their compliance summaries.
+/// Returns the projects with their compliance summaries, unwrapped from
+/// the paginated envelope. Requests the server's largest page size.
 pub async fn list_projects() -> Result<Vec<Project>, ApiError> {
-    let response = api_get("/projects").await?;
-    let projects: Vec<Project> = serde_json::from_str(&response)
+    let response = api_get("/projects?limit=500").await?;
+    let page: ProjectPage = serde_json::from_str(&response)
         .ok()
         .ok_or_else(|| ApiError {
             code: "PARSE_ERROR".into(),
             message: "failed to parse projects list response".into(),
+            fields: Vec::new(),
         })?;
-    Ok(projects)
+    Ok(page.items)
 }
 
 /// Retrieve a single project by ID.
@@ -54,6 +57,7 @@ pub async fn get_project(id: &str) -> Result<Project, ApiError> {
         .ok_or_else(|| ApiError {
             code: "PARSE_ERROR".into(),
             message: "failed to parse project response".into(),
+            fields: Vec::new(),
         })?;
     Ok(project)
 }
@@ -72,6 +76,7 @@ pub async fn update_project(id: &str, req: &UpdateProjectRequest) -> Result<Proj
         .ok_or_else(|| ApiError {
             code: "PARSE_ERROR".into(),
             message: "failed to parse project response".into(),
+            fields: Vec::new(),
         })?;
     Ok(project)
 }
@@ -86,3 +91,53 @@ pub async fn delete_project(id: &str) -> Result<(), ApiError> {
     api_delete(&path).await?;
     Ok(())
 }
+
+/// Fetch a project's threshold alert settings and latest status.
+/// `GET /api/v1/projects/{id}/alerts` (FR-105)
+pub async fn get_alerts(id: &str) -> Result<AlertSettings, ApiError> {
+    let path = format!("/projects/{id}/alerts");
+    let response = api_get(&path).await?;
+    let alerts: AlertSettings = serde_json::from_str(&response)
+        .ok()
+        .ok_or_else(|| ApiError {
+            code: "PARSE_ERROR".into(),
+            message: "failed to parse alert settings response".into(),
+            fields: Vec::new(),
+        })?;
+    Ok(alerts)
+}
+
+/// Store a project's threshold alert settings (admin only).
+/// `PUT /api/v1/projects/{id}/alerts` (FR-105)
+///
+/// Returns an ApiError with code "VALIDATION_ERROR" and per-field errors if
+/// the webhook URL or thresholds are invalid.
+pub async fn save_alerts(id: &str, req: &SaveAlertsRequest) -> Result<AlertSettings, ApiError> {
+    let path = format!("/projects/{id}/alerts");
+    let body = serde_json::to_string(req).unwrap_or_default();
+    let response = api_put(&path, &body).await?;
+    let alerts: AlertSettings = serde_json::from_str(&response)
+        .ok()
+        .ok_or_else(|| ApiError {
+            code: "PARSE_ERROR".into(),
+            message: "failed to parse alert settings response".into(),
+            fields: Vec::new(),
+        })?;
+    Ok(alerts)
+}
+
+/// Remove a project's threshold alert settings (admin only).
+/// `DELETE /api/v1/projects/{id}/alerts` (FR-105)
+pub async fn delete_alerts(id: &str) -> Result<(), ApiError> {
+    let path = format!("/projects/{id}/alerts");
+    api_delete(&path).await?;
+    Ok(())
+}
+
+/// Send a test notification to the project's stored webhook (admin only).
+/// `POST /api/v1/projects/{id}/alerts/test` (FR-105)
+pub async fn send_test_alert(id: &str) -> Result<(), ApiError> {
+    let path = format!("/projects/{id}/alerts/test");
+    api_post(&path, "{}").await?;
+    Ok(())
+}
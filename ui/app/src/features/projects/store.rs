@@ -1,10 +1,11 @@
 use rsc_compat::prelude::*;
 use crate::features::projects::types::{
-    Project, CreateProjectRequest, UpdateProjectRequest,
+    Project, CreateProjectRequest, UpdateProjectRequest, AlertSettings, SaveAlertsRequest,
 };
 use crate::features::projects::service;
+use crate::util::api::{report_error, FieldError};
 
-/// Reactive state store for the Projects feature (FR-100..FR-104).
+/// Reactive state store for the Projects feature (FR-100..FR-105).
 ///
 /// Manages the project list, selection state, form visibility, and async
 /// loading indicators. All mutations go through named action functions
@@ -29,7 +30,23 @@ pub struct ProjectsStore {
     pub loading: Signal<bool>,
 
     /// The most recent error message from a failed operation, or None if no error.
+    /// Shown as a toast; errors tied to a form input go to `field_errors` instead.
     pub error: Signal<Option<String>>,
+
+    /// Per-field validation errors from the last create/update, shown inline.
+    pub field_errors: Signal<Vec<FieldError>>,
+
+    /// Alert settings of the project being edited, or None until loaded.
+    pub alerts: Signal<Option<AlertSettings>>,
+
+    /// Error from the last alert settings request not tied to a field.
+    pub alert_error: Signal<Option<String>>,
+
+    /// Per-field validation errors from the last alert settings save.
+    pub alert_field_errors: Signal<Vec<FieldError>>,
+
+    /// Confirmation shown after a successful save or test notification.
+    pub alert_notice: Signal<Option<String>>,
 }
 
 impl ProjectsStore {
@@ -42,6 +59,11 @@ impl ProjectsStore {
             editing: signal(false),
             loading: signal(false),
             error: signal(None),
+            field_errors: signal(Vec::new()),
+            alerts: signal(None),
+            alert_error: signal(None),
+            alert_field_errors: signal(Vec::new()),
+            alert_notice: signal(None),
         }
     }
 }
@@ -76,12 +98,37 @@ pub fn use_error(store: &ProjectsStore) -> Signal<Option<String>> {
     store.error.clone()
 }
 
+/// Returns a read-only reference to the field errors signal.
+pub fn use_field_errors(store: &ProjectsStore) -> Signal<Vec<FieldError>> {
+    store.field_errors.clone()
+}
+
+/// Returns a read-only reference to the alert settings signal.
+pub fn use_alerts(store: &ProjectsStore) -> Signal<Option<AlertSettings>> {
+    store.alerts.clone()
+}
+
+/// Returns a read-only reference to the alert error signal.
+pub fn use_alert_error(store: &ProjectsStore) -> Signal<Option<String>> {
+    store.alert_error.clone()
+}
+
+/// Returns a read-only reference to the alert field errors signal.
+pub fn use_alert_field_errors(store: &ProjectsStore) -> Signal<Vec<FieldError>> {
+    store.alert_field_errors.clone()
+}
+
+/// Returns a read-only reference to the alert notice signal.
+pub fn use_alert_notice(store: &ProjectsStore) -> Signal<Option<String>> {
+    store.alert_notice.clone()
+}
+
 /// Open the project form in creation mode with no pre-selected project.
 pub fn open_create_form(store: &ProjectsStore) {
     store.selected_project.set(None);
     store.editing.set(false);
     store.form_open.set(true);
-    store.error.set(None);
+    clear_error(store);
 }
 
 /// Open the project form in editing mode with the given project pre-filled.
@@ -89,7 +136,7 @@ pub fn open_edit_form(store: &ProjectsStore, project: Project) {
     store.selected_project.set(Some(project));
     store.editing.set(true);
     store.form_open.set(true);
-    store.error.set(None);
+    clear_error(store);
 }
 
 /// Close the project form and reset selection state.
@@ -97,12 +144,13 @@ pub fn close_form(store: &ProjectsStore) {
     store.form_open.set(false);
     store.editing.set(false);
     store.selected_project.set(None);
-    store.error.set(None);
+    clear_error(store);
 }
 
-/// Clear the current error.
+/// Clear the current error and any field errors.
 pub fn clear_error(store: &ProjectsStore) {
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 }
 
 /// Fetch all projects from the API and update the store.
@@ -132,16 +180,17 @@ pub fn load_projects(store: &ProjectsStore) {
 /// `POST /api/v1/projects` (FR-100)
 ///
 /// On success, the new project is appended to the projects list and the form
-/// is closed. On failure, the error is displayed in the form.
+/// is closed. On failure, field errors are shown inline and anything else as a toast.
 pub fn create_project(store: &ProjectsStore, req: CreateProjectRequest) {
     store.loading.set(true);
-    store.error.set(None);
+    clear_error(store);
 
     let projects_sig = store.projects.clone();
     let form_open_sig = store.form_open.clone();
     let editing_sig = store.editing.clone();
     let selected_sig = store.selected_project.clone();
     let error_sig = store.error.clone();
+    let field_errors_sig = store.field_errors.clone();
     let loading_sig = store.loading.clone();
 
     spawn(async move {
@@ -154,7 +203,7 @@ pub fn create_project(store: &ProjectsStore, req: CreateProjectRequest) {
                 error_sig.set(None);
             }
             Err(err) => {
-                error_sig.set(Some(err.message));
+                report_error(err, &error_sig, &field_errors_sig);
             }
         }
         loading_sig.set(false);
@@ -165,16 +214,17 @@ pub fn create_project(store: &ProjectsStore, req: CreateProjectRequest) {
 /// `PATCH /api/v1/projects/{id}` (FR-102)
 ///
 /// On success, the project is replaced in the list and the form is closed.
-/// On failure, the error is displayed in the form.
+/// On failure, field errors are shown inline and anything else as a toast.
 pub fn update_project(store: &ProjectsStore, id: String, req: UpdateProjectRequest) {
     store.loading.set(true);
-    store.error.set(None);
+    clear_error(store);
 
     let projects_sig = store.projects.clone();
     let form_open_sig = store.form_open.clone();
     let editing_sig = store.editing.clone();
     let selected_sig = store.selected_project.clone();
     let error_sig = store.error.clone();
+    let field_errors_sig = store.field_errors.clone();
     let loading_sig = store.loading.clone();
 
     spawn(async move {
@@ -191,7 +241,7 @@ pub fn update_project(store: &ProjectsStore, id: String, req: UpdateProjectReque
                 error_sig.set(None);
             }
             Err(err) => {
-                error_sig.set(Some(err.message));
+                report_error(err, &error_sig, &field_errors_sig);
             }
         }
         loading_sig.set(false);
@@ -230,3 +280,98 @@ pub fn delete_project(store: &ProjectsStore, id: String) {
         loading_sig.set(false);
     });
 }
+
+/// Clear alert errors and notices before a new alert request.
+fn clear_alert_messages(store: &ProjectsStore) {
+    store.alert_error.set(None);
+    store.alert_field_errors.set(Vec::new());
+    store.alert_notice.set(None);
+}
+
+/// Fetch a project's alert settings and latest threshold status.
+/// `GET /api/v1/projects/{id}/alerts` (FR-105)
+pub fn load_alerts(store: &ProjectsStore, id: String) {
+    store.alerts.set(None);
+    clear_alert_messages(store);
+
+    let alerts_sig = store.alerts.clone();
+    let error_sig = store.alert_error.clone();
+
+    spawn(async move {
+        match service::get_alerts(&id).await {
+            Ok(alerts) => alerts_sig.set(Some(alerts)),
+            Err(err) => error_sig.set(Some(err.message)),
+        }
+    });
+}
+
+/// Save a project's alert settings.
+/// `PUT /api/v1/projects/{id}/alerts` (FR-105)
+///
+/// On failure, field errors are shown inline and anything else above the form.
+pub fn save_alerts(store: &ProjectsStore, id: String, req: SaveAlertsRequest) {
+    store.loading.set(true);
+    clear_alert_messages(store);
+
+    let alerts_sig = store.alerts.clone();
+    let error_sig = store.alert_error.clone();
+    let field_errors_sig = store.alert_field_errors.clone();
+    let notice_sig = store.alert_notice.clone();
+    let loading_sig = store.loading.clone();
+
+    spawn(async move {
+        match service::save_alerts(&id, &req).await {
+            Ok(alerts) => {
+                alerts_sig.set(Some(alerts));
+                notice_sig.set(Some("Alert settings saved".into()));
+            }
+            Err(err) => {
+                report_error(err, &error_sig, &field_errors_sig);
+            }
+        }
+        loading_sig.set(false);
+    });
+}
+
+/// Remove a project's alert settings, then reload them so the panel shows
+/// the unconfigured state.
+/// `DELETE /api/v1/projects/{id}/alerts` (FR-105)
+pub fn delete_alerts(store: &ProjectsStore, id: String) {
+    store.loading.set(true);
+    clear_alert_messages(store);
+
+    let alerts_sig = store.alerts.clone();
+    let error_sig = store.alert_error.clone();
+    let loading_sig = store.loading.clone();
+
+    spawn(async move {
+        let result = match service::delete_alerts(&id).await {
+            Ok(()) => service::get_alerts(&id).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(alerts) => alerts_sig.set(Some(alerts)),
+            Err(err) => error_sig.set(Some(err.message)),
+        }
+        loading_sig.set(false);
+    });
+}
+
+/// Send a test notification to the project's stored webhook.
+/// `POST /api/v1/projects/{id}/alerts/test` (FR-105)
+pub fn send_test_alert(store: &ProjectsStore, id: String) {
+    store.loading.set(true);
+    clear_alert_messages(store);
+
+    let error_sig = store.alert_error.clone();
+    let notice_sig = store.alert_notice.clone();
+    let loading_sig = store.loading.clone();
+
+    spawn(async move {
+        match service::send_test_alert(&id).await {
+            Ok(()) => notice_sig.set(Some("Test notification sent".into())),
+            Err(err) => error_sig.set(Some(err.message)),
+        }
+        loading_sig.set(false);
+    });
+}
@@ -59,3 +59,59 @@ async fn project_detail_shows_info(ctx: BrowserTestContext) {
     ctx.wait_for("[data-testid='new-project-btn']").await.unwrap();
     ctx.assert_element_visible("[data-testid='new-project-btn']").await;
 }
+
+/// A root path the server rejects must show its message inline under that input.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn create_project_shows_field_error_inline(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/projects", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='new-project-btn']").await.unwrap();
+    ctx.click("[data-testid='new-project-btn']").await.unwrap();
+    ctx.wait_for("[data-testid='project-form']").await.unwrap();
+    ctx.fill("[data-testid='project-form-name']", "E2E Bad Path").await.unwrap();
+    ctx.fill("[data-testid='project-form-root-path']", "../outside").await.unwrap();
+    ctx.click("[data-testid='project-form-submit']").await.unwrap();
+    ctx.wait_for("[data-testid='project-form-root-path-error']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='project-form-root-path-error']").await;
+}
+
+/// Editing a project as an admin must show the alert settings panel, flagged
+/// as unconfigured until alerts are saved.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn project_alerts_panel_shows_for_admin(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.set_local_storage("swe_auth_admin", "true").await.unwrap();
+    ctx.navigate("/projects", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='new-project-btn']").await.unwrap();
+    ctx.click("[data-testid='new-project-btn']").await.unwrap();
+    ctx.fill("[data-testid='project-form-name']", "E2E Alerts Project").await.unwrap();
+    ctx.fill("[data-testid='project-form-root-path']", "/tmp/e2e-alerts").await.unwrap();
+    ctx.click("[data-testid='project-form-submit']").await.unwrap();
+    ctx.wait_for("[data-testid='edit-btn']").await.unwrap();
+    ctx.click("[data-testid='edit-btn']").await.unwrap();
+    ctx.wait_for("[data-testid='project-alerts']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='project-alerts']").await;
+    ctx.wait_for("[data-testid='project-alerts-empty']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='project-alerts-empty']").await;
+}
+
+/// A webhook URL that is not http(s) must show its message inline before any request.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn project_alerts_validates_webhook_url(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.set_local_storage("swe_auth_admin", "true").await.unwrap();
+    ctx.navigate("/projects", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='new-project-btn']").await.unwrap();
+    ctx.click("[data-testid='new-project-btn']").await.unwrap();
+    ctx.fill("[data-testid='project-form-name']", "E2E Alerts Validation").await.unwrap();
+    ctx.fill("[data-testid='project-form-root-path']", "/tmp/e2e-alerts-validation").await.unwrap();
+    ctx.click("[data-testid='project-form-submit']").await.unwrap();
+    ctx.wait_for("[data-testid='edit-btn']").await.unwrap();
+    ctx.click("[data-testid='edit-btn']").await.unwrap();
+    ctx.wait_for("[data-testid='project-alerts']").await.unwrap();
+    ctx.fill("[data-testid='project-alerts-webhook-url']", "ftp://hooks.example.com").await.unwrap();
+    ctx.fill("[data-testid='project-alerts-max-failures']", "0").await.unwrap();
+    ctx.click("[data-testid='project-alerts-save']").await.unwrap();
+    ctx.wait_for("[data-testid='project-alerts-webhook-url-error']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='project-alerts-webhook-url-error']").await;
+}
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 
-/// Types for the Projects feature (FR-100..FR-104).
+/// Types for the Projects feature (FR-100..FR-105).
 ///
 /// These types mirror the API models defined in the backend `api/projects.rs`
 /// and are used throughout the projects feature for type-safe data handling.
@@ -144,6 +144,15 @@ pub struct Project {
     pub compliance_summary: Option<ComplianceSummary>,
 }
 
+/// One page of projects as returned by `GET /api/v1/projects?limit=&offset=` (FR-101).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProjectPage {
+    pub items: Vec<Project>,
+    pub total: u64,
+    pub limit: u32,
+    pub offset: u64,
+}
+
 /// Request payload for creating a new project via `POST /api/v1/projects` (FR-100).
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateProjectRequest {
@@ -161,3 +170,31 @@ pub struct UpdateProjectRequest {
     pub scope: Option<ProjectScope>,
     pub project_type: Option<ProjectType>,
 }
+
+/// A project's threshold alert settings and the status of its latest scan.
+/// Maps to the JSON object returned by `GET /api/v1/projects/{id}/alerts` (FR-105).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AlertSettings {
+    pub project_id: String,
+    /// False when the project has no alerting configured.
+    pub configured: bool,
+    pub webhook_url: Option<String>,
+    pub max_failures: Option<u32>,
+    pub min_coverage: Option<f64>,
+    pub on_new_failures: bool,
+    pub updated_at: Option<String>,
+    /// `ok` or `breached` for the latest scan; None when unconfigured or not yet scanned.
+    pub status: Option<String>,
+    pub reasons: Vec<String>,
+    pub latest_scan_id: Option<String>,
+}
+
+/// Request payload for `PUT /api/v1/projects/{id}/alerts` (FR-105).
+/// At least one of the thresholds must be set.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SaveAlertsRequest {
+    pub webhook_url: String,
+    pub max_failures: Option<u32>,
+    pub min_coverage: Option<f64>,
+    pub on_new_failures: bool,
+}
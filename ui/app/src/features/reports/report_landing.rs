@@ -2,12 +2,29 @@ use rsc_compat::prelude::*;
 use crate::features::reports::store::{self, ReportsStore};
 use super::report_export::report_export;
 use super::report_comparison::report_comparison_view;
+use crate::util::auth::{query_param, share_report_link};
+use crate::util::flags::{use_flags, SCAN_DIFF};
 
 /// Reports management page (FR-700..704).
 #[component]
 pub fn reports_landing() -> View {
     let s = use_context::<ReportsStore>();
     let show_comparison = signal(false);
+    // The comparison view is dark-launched behind the server's diff endpoint.
+    let comparison_enabled = use_flags().is_enabled(SCAN_DIFF);
+
+    // Open a shared deep link (`/reports?scan=<id>&view=public`) directly.
+    effect({
+        let s = s.clone();
+        move || {
+            if let Some(scan_id) = query_param("scan") {
+                if s.selected_scan_id.get().is_none() {
+                    s.selected_scan_id.set(Some(scan_id));
+                    store::export(&s);
+                }
+            }
+        }
+    });
 
     let format_change_cb: Option<Box<dyn Fn(String)>> = Some(Box::new({
         let s = s.clone();
@@ -50,10 +67,23 @@ pub fn reports_landing() -> View {
         view! {}
     };
 
+    let share_view = if let Some(ref scan_id) = s.selected_scan_id.get() {
+        let link = share_report_link(scan_id);
+        view! {
+            div(class="reports__share", data-testid="reports-share") {
+                span { "Share link: " }
+                a(href=link.clone(), data-testid="reports-share-link") { (link) }
+            }
+        }
+    } else {
+        view! {}
+    };
+
     view! {
         style {
             .reports { display: flex; flex-direction: column; gap: var(--space-4); }
             .reports__actions { display: flex; gap: var(--space-3); }
+            .reports__share { font-size: var(--font-size-sm); color: var(--color-text-muted); }
         }
         div(class="reports", data-testid="reports-landing") {
             (report_export(
@@ -66,16 +96,22 @@ pub fn reports_landing() -> View {
             ))
 
             div(class="reports__actions") {
-                button(
-                    class="btn btn--secondary",
-                    on:click={
-                        let sc = show_comparison.clone();
-                        move || sc.set(true)
-                    },
-                    data-testid="reports-open-comparison-btn"
-                ) {
-                    "Compare Reports"
-                }
+                (if comparison_enabled {
+                    view! {
+                        button(
+                            class="btn btn--secondary",
+                            on:click={
+                                let sc = show_comparison.clone();
+                                move || sc.set(true)
+                            },
+                            data-testid="reports-open-comparison-btn"
+                        ) {
+                            "Compare Reports"
+                        }
+                    }
+                } else {
+                    view! {}
+                })
                 button(
                     class="btn btn--secondary",
                     disabled=s.selected_scan_id.get().is_none() || s.loading.get(),
@@ -89,16 +125,22 @@ pub fn reports_landing() -> View {
                 }
             }
 
-            (report_comparison_view(
-                s.selected_scan_id.get(),
-                s.compare_scan_id.get(),
-                s.comparison.get(),
-                s.loading.get(),
-                scan_b_cb,
-                compare_cb,
-                close_cb,
-                show_comparison.get(),
-            ))
+            (share_view)
+
+            (if comparison_enabled {
+                report_comparison_view(
+                    s.selected_scan_id.get(),
+                    s.compare_scan_id.get(),
+                    s.comparison.get(),
+                    s.loading.get(),
+                    scan_b_cb,
+                    compare_cb,
+                    close_cb,
+                    show_comparison.get(),
+                )
+            } else {
+                view! {}
+            })
 
             (error_view)
         }
@@ -10,10 +10,12 @@ pub async fn export_report(scan_id: &str, format: &str) -> Result<ReportData, Ap
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse report response".into(),
+        fields: Vec::new(),
     })?;
     ReportData::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "report response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
@@ -25,10 +27,12 @@ pub async fn get_audit_report(scan_id: &str) -> Result<ReportData, ApiError> {
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse audit report response".into(),
+        fields: Vec::new(),
     })?;
     ReportData::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "audit report response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
@@ -40,9 +44,11 @@ pub async fn compare_reports(scan_a_id: &str, scan_b_id: &str) -> Result<ReportC
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse comparison response".into(),
+        fields: Vec::new(),
     })?;
     ReportComparison::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "comparison response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
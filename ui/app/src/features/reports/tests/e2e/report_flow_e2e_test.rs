@@ -30,3 +30,37 @@ async fn report_export_button(ctx: BrowserTestContext) {
     ctx.wait_for("[data-testid='report-export-btn']").await.unwrap();
     ctx.assert_element_visible("[data-testid='report-export-btn']").await;
 }
+
+/// Shared `?view=public` links must render reports without logging in.
+#[e2e(config = "test_config", route = "/reports?view=public", wait_for = "[data-testid='app-shell']")]
+async fn report_public_view_renders_without_login(ctx: BrowserTestContext) {
+    ctx.wait_for("[data-testid='reports-landing']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='reports-landing']").await;
+    ctx.assert_element_visible("[data-testid='read-only-banner']").await;
+}
+
+/// Scan report deep links must preselect the shared scan.
+#[e2e(config = "test_config", route = "/reports?scan=00000000&view=public", wait_for = "[data-testid='app-shell']")]
+async fn report_public_deep_link_shows_share_link(ctx: BrowserTestContext) {
+    ctx.wait_for("[data-testid='reports-share-link']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='reports-share-link']").await;
+}
+
+/// The comparison view is gated by the `scan_diff` flag; a client override turns it on.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn report_comparison_enabled_by_flag_override(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.set_local_storage("swe_flag_overrides", r#"{"scan_diff":true}"#).await.unwrap();
+    ctx.navigate("/reports", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='reports-open-comparison-btn']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='reports-open-comparison-btn']").await;
+}
+
+/// `?dev_flags=1` must show the dev flags overlay.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn dev_flags_overlay_renders(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/reports?dev_flags=1", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='dev-flags-overlay']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='dev-flags-reset']").await;
+}
@@ -4,6 +4,7 @@ use crate::features::scaffold::srs_upload::srs_upload;
 use crate::features::scaffold::scaffold_preview::scaffold_preview;
 use crate::features::scaffold::phase_filter::phase_filter;
 use crate::features::scaffold::scaffold_progress::scaffold_progress;
+use crate::util::api::field_message;
 
 /// Scaffolding interface page (FR-500..504).
 #[component]
@@ -22,7 +23,18 @@ pub fn scaffold_landing() -> View {
                     move || store::parse(&s)
                 })),
                 s.loading.get(),
+                field_message(&s.field_errors.get(), "content"),
             ))
+            (if let Some(err) = s.error.get() {
+                view! {
+                    div(class="toast toast--danger", role="alert", data-testid="scaffold-error-toast") {
+                        (err)
+                        button(class="toast__dismiss", on:click={ let e = s.error.clone(); move || e.set(None) }) { "Dismiss" }
+                    }
+                }
+            } else {
+                view! {}
+            })
             (if !s.parsed_domains.get().is_empty() {
                 let s2 = s.clone();
                 view! {
@@ -1,13 +1,13 @@
 use rsc_compat::prelude::*;
-use crate::util::api::api_post;
+use crate::util::api::{api_post, ApiError};
 use crate::features::scaffold::types::{ParsedDomain, ScaffoldResult};
 
 /// Parse SRS content (FR-500).
-pub async fn parse_srs(content: &str) -> Result<Vec<ParsedDomain>, String> {
+pub async fn parse_srs(content: &str) -> Result<Vec<ParsedDomain>, ApiError> {
     let body = json_stringify(&json!({ "content": content }));
-    let response = api_post("/scaffold/parse", &body).await.map_err(|e| e.message)?;
+    let response = api_post("/scaffold/parse", &body).await?;
     let parsed: Vec<ParsedDomain> = serde_json::from_str(&response)
-        .map_err(|e| format!("parse error: {e}"))?;
+        .map_err(|e| parse_error(format!("parse error: {e}")))?;
     Ok(parsed)
 }
 
@@ -18,13 +18,21 @@ pub async fn execute_scaffold(
     phases: &[String],
     file_types: &[String],
     force: bool,
-) -> Result<ScaffoldResult, String> {
+) -> Result<ScaffoldResult, ApiError> {
     let body = json_stringify(&json!({
         "srs_path": srs_path, "output_dir": output_dir,
         "phases": phases, "file_types": file_types, "force": force,
     }));
-    let response = api_post("/scaffold/execute", &body).await.map_err(|e| e.message)?;
+    let response = api_post("/scaffold/execute", &body).await?;
     let result: ScaffoldResult = serde_json::from_str(&response)
-        .map_err(|e| format!("parse error: {e}"))?;
+        .map_err(|e| parse_error(format!("parse error: {e}")))?;
     Ok(result)
 }
+
+fn parse_error(message: String) -> ApiError {
+    ApiError {
+        code: "PARSE_ERROR".into(),
+        message,
+        fields: Vec::new(),
+    }
+}
@@ -1,11 +1,14 @@
 use rsc_compat::prelude::*;
 
 /// SRS content upload/paste component (FR-500).
+///
+/// `error` is the server's message for the `content` field, shown under the input.
 #[component]
 pub fn srs_upload(
     content: Signal<String>,
     on_parse: Option<Box<dyn Fn()>>,
     loading: bool,
+    error: Option<String>,
 ) -> View {
     let content_input = content.clone();
     let content_check = content.clone();
@@ -14,6 +17,7 @@ pub fn srs_upload(
         style {
             .srs-upload { display: flex; flex-direction: column; gap: var(--space-3); }
             .srs-upload__textarea { min-height: 200px; font-family: var(--font-family-mono); font-size: var(--font-size-sm); }
+            .srs-upload__error { font-size: var(--font-size-sm); color: var(--color-error); }
         }
         div(class="srs-upload", data-testid="srs-upload") {
             FormField(label="SRS Markdown Content") {
@@ -25,6 +29,13 @@ pub fn srs_upload(
                     placeholder="Paste your SRS markdown content here...",
                     data-testid="srs-content-input",
                 )
+                (if let Some(msg) = error {
+                    view! {
+                        span(class="srs-upload__error", data-testid="srs-content-error") { (msg) }
+                    }
+                } else {
+                    view! {}
+                })
             }
             Button(
                 label="Parse SRS",
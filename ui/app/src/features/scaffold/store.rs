@@ -3,6 +3,7 @@ use crate::features::scaffold::types::{
     ParsedDomain, ScaffoldResult,
 };
 use crate::features::scaffold::service;
+use crate::util::api::{report_error, FieldError};
 
 /// Central reactive store for the scaffold feature.
 /// All scaffold components read from these signals to ensure consistency.
@@ -27,7 +28,11 @@ pub struct ScaffoldStore {
     pub loading: Signal<bool>,
 
     /// Last error message from a failed operation, if any.
+    /// Shown as a toast; errors tied to the SRS input go to `field_errors`.
     pub error: Signal<Option<String>>,
+
+    /// Per-field validation errors from the last parse, shown inline.
+    pub field_errors: Signal<Vec<FieldError>>,
 }
 
 impl ScaffoldStore {
@@ -42,6 +47,7 @@ impl ScaffoldStore {
             selected_file_types: signal(Vec::new()),
             loading: signal(false),
             error: signal(None),
+            field_errors: signal(Vec::new()),
         }
     }
 }
@@ -121,11 +127,13 @@ pub fn toggle_file_type(store: &ScaffoldStore, file_type: &str) {
 pub fn parse(store: &ScaffoldStore) {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
     let content = store.srs_content.get().clone();
     let parsed_domains = store.parsed_domains.clone();
     let loading = store.loading.clone();
     let error = store.error.clone();
+    let field_errors = store.field_errors.clone();
 
     spawn(async move {
         match service::parse_srs(&content).await {
@@ -133,8 +141,8 @@ pub fn parse(store: &ScaffoldStore) {
                 parsed_domains.set(domains);
                 loading.set(false);
             }
-            Err(msg) => {
-                error.set(Some(msg));
+            Err(err) => {
+                report_error(err, &error, &field_errors);
                 loading.set(false);
             }
         }
@@ -158,8 +166,8 @@ pub fn execute(store: &ScaffoldStore) {
                 scaffold_result.set(Some(result));
                 loading.set(false);
             }
-            Err(msg) => {
-                error.set(Some(msg));
+            Err(err) => {
+                error.set(Some(err.message));
                 loading.set(false);
             }
         }
@@ -175,4 +183,5 @@ pub fn reset_store(store: &ScaffoldStore) {
     store.selected_file_types.set(Vec::new());
     store.loading.set(false);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 }
@@ -6,6 +6,7 @@ pub mod scan_landing;
 pub mod scan_trigger;
 pub mod scan_progress;
 pub mod scan_history;
+pub mod scan_result;
 pub mod use_scans_ws;
 
 #[cfg(test)]
@@ -1,5 +1,8 @@
 use rsc_compat::prelude::*;
 use super::types::Scan;
+use crate::util::auth::share_report_link;
+use crate::util::clipboard::copy_markdown_button;
+use crate::util::markdown::{coverage_from_report, rows_from_report, summary_markdown};
 
 /// Table of past scans ordered by timestamp (FR-305).
 #[component]
@@ -16,6 +19,7 @@ pub fn scan_history(scans: Signal<Vec<Scan>>) -> View {
             _ => "warning",
         };
         let is_completed = scan.status == "completed";
+        let markdown = signal(scan_markdown(scan));
         view! {
             tr(data-testid=format!("scan-row-{}", scan_id)) {
                 td(data-testid="scan-id") { (scan_id_short) }
@@ -28,13 +32,17 @@ pub fn scan_history(scans: Signal<Vec<Scan>>) -> View {
                         (status)
                     }
                 }
-                td(data-testid="scan-started") { (started_at) }
+                td(class="scan-history__secondary", data-testid="scan-started") { (started_at) }
                 td {
                     (if is_completed {
                         view! {
                             a(href=format!("/violations?scan={}", scan_id), data-testid="scan-view-link") {
                                 "View"
                             }
+                            a(href=share_report_link(&scan_id), data-testid="scan-share-link") {
+                                "Share"
+                            }
+                            (copy_markdown_button(markdown.clone(), "scan-copy-markdown".into()))
                         }
                     } else {
                         view! {}
@@ -49,6 +57,9 @@ pub fn scan_history(scans: Signal<Vec<Scan>>) -> View {
             .scan-history__status--completed { color: var(--color-success); }
             .scan-history__status--failed { color: var(--color-error); }
             .scan-history__status--running { color: var(--color-warning); }
+            @media (max-width: 768px) {
+                .scan-history__secondary { display: none; }
+            }
         }
         Table(data-testid="scan-history") {
             thead {
@@ -56,7 +67,7 @@ pub fn scan_history(scans: Signal<Vec<Scan>>) -> View {
                     th { "ID" }
                     th { "Engine" }
                     th { "Status" }
-                    th { "Started" }
+                    th(class="scan-history__secondary") { "Started" }
                     th { "Actions" }
                 }
             }
@@ -66,3 +77,13 @@ pub fn scan_history(scans: Signal<Vec<Scan>>) -> View {
         }
     }
 }
+
+/// Markdown summary of a completed scan's report, for pasting into PRs and chat.
+fn scan_markdown(scan: &Scan) -> String {
+    let report = scan.report.as_deref().and_then(json_parse).unwrap_or_default();
+    summary_markdown(
+        &format!("{} scan {}", scan.engine, scan.id),
+        &rows_from_report(&report),
+        coverage_from_report(&report),
+    )
+}
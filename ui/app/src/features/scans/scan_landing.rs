@@ -4,12 +4,15 @@ use super::types::ScanRequest;
 use super::scan_trigger::scan_trigger;
 use super::scan_progress::scan_progress_bar;
 use super::scan_history::scan_history;
-use super::use_scans_ws::use_scan_progress;
+use super::scan_result::scan_result;
+use super::use_scans_ws::{use_scan_progress, WsState};
+use crate::util::auth::is_read_only;
 
 /// Scans management page (FR-300..305).
 #[component]
 pub fn scans_landing() -> View {
     let s = use_context::<ScansStore>();
+    let read_only = is_read_only();
 
     // Load scan history on mount (using empty project_id as placeholder).
     {
@@ -26,11 +29,25 @@ pub fn scans_landing() -> View {
         let active = s.active_scan.clone();
         derived(move || active.get().map(|scan| scan.id.clone()))
     };
-    let (scan_progress, _) = use_scan_progress(scan_id_sig);
+    let (scan_progress, ws_state) = use_scan_progress(scan_id_sig);
+
+    // Fetch the finished scan (with its report) once the progress stream completes.
+    {
+        let s2 = s.clone();
+        let ws_state = ws_state.clone();
+        effect(move || {
+            if ws_state.get() == WsState::Completed {
+                store::refresh_active_scan(&s2);
+                if let Some(ref pid) = s2.selected_project_id.get() {
+                    store::load_history(&s2, pid);
+                }
+            }
+        });
+    }
 
     let is_running = {
         let active = s.active_scan.clone();
-        derived(move || active.get().is_some())
+        derived(move || active.get().map_or(false, |scan| !scan.is_finished()))
     };
 
     view! {
@@ -38,27 +55,56 @@ pub fn scans_landing() -> View {
             .scans { display: flex; flex-direction: column; gap: var(--space-4); }
         }
         div(class="scans", data-testid="scans-landing") {
-            (scan_trigger(
-                Some(Box::new({
-                    let s2 = s.clone();
-                    move |engine: String, scope: String, checks: Option<String>| {
-                        let req = ScanRequest {
-                            project_id: s2.selected_project_id.get().unwrap_or_default(),
-                            engine,
-                            checks,
-                            phase: Some(scope),
-                            module: None,
-                        };
-                        store::trigger_scan(&s2, req);
-                    }
-                })),
-                is_running.get(),
-            ))
+            (if read_only {
+                view! {}
+            } else {
+                scan_trigger(
+                    Some(Box::new({
+                        let s2 = s.clone();
+                        move |engine: String, scope: String, checks: Option<String>, incremental: bool| {
+                            let req = ScanRequest {
+                                project_id: s2.selected_project_id.get().unwrap_or_default(),
+                                engine,
+                                checks,
+                                phase: Some(scope),
+                                module: None,
+                                incremental,
+                            };
+                            store::trigger_scan(&s2, req);
+                        }
+                    })),
+                    is_running.get(),
+                )
+            })
             (if is_running.get() {
-                scan_progress_bar(scan_progress.clone())
+                let focus = s.recheck_source.get().map(|source| {
+                    let count = source.failed_check_ids().len();
+                    format!("Rechecking {count} failed check(s) from scan {}", source.id)
+                });
+                view! {
+                    (if let Some(title) = focus {
+                        view! { div(data-testid="recheck-progress-title") { (title) } }
+                    } else {
+                        view! {}
+                    })
+                    (scan_progress_bar(scan_progress.clone()))
+                }
             } else {
                 view! {}
             })
+            (match s.active_scan.get() {
+                Some(scan) if scan.is_finished() => {
+                    let s2 = s.clone();
+                    let source = scan.clone();
+                    scan_result(
+                        scan,
+                        s.recheck_source.get(),
+                        Some(Box::new(move || store::recheck_failures(&s2, source.clone()))),
+                        read_only || s.loading.get(),
+                    )
+                }
+                _ => view! {},
+            })
             h3 { "Scan History" }
             (scan_history(s.scan_history.clone()))
         }
@@ -0,0 +1,113 @@
+use rsc_compat::prelude::*;
+use super::types::{CheckOutcome, Scan};
+
+/// Result card for the finished active scan, with a "recheck failures" action (FR-306).
+///
+/// For a partial recheck, shows a before/after table of just the rechecked
+/// checks against `baseline` and links back to the full original scan.
+#[component]
+pub fn scan_result(
+    scan: Scan,
+    baseline: Option<Scan>,
+    on_recheck: Option<Box<dyn Fn()>>,
+    disabled: bool,
+) -> View {
+    let failed = scan.failed_check_ids().len();
+    let scan_id_short = short_id(&scan.id);
+    let recheck_label = format!("Recheck failures ({failed})");
+    let before_after = baseline
+        .as_ref()
+        .filter(|_| scan.partial)
+        .map(|b| before_after_rows(b, &scan.check_outcomes()));
+
+    view! {
+        style {
+            .scan-result { display: flex; flex-direction: column; gap: var(--space-3); padding: var(--space-4); }
+            .scan-result__header { display: flex; gap: var(--space-3); align-items: center; }
+            .scan-result__actions { display: flex; gap: var(--space-3); align-items: center; }
+        }
+        Card(class="scan-result", data-testid="scan-result") {
+            div(class="scan-result__header") {
+                h3 { (format!("{} scan {}", scan.engine, scan_id_short)) }
+                (if scan.partial {
+                    view! {
+                        Badge(variant="info", data-testid="scan-result-partial") { "Partial recheck" }
+                    }
+                } else {
+                    view! {}
+                })
+            }
+            div(class="scan-result__actions") {
+                Button(
+                    label=recheck_label,
+                    variant="secondary",
+                    disabled=disabled || failed == 0,
+                    on:click=move || { if let Some(ref cb) = on_recheck { cb() } },
+                    data-testid="scan-recheck-btn",
+                )
+                (if let Some(ref original) = scan.recheck_of {
+                    view! {
+                        a(href=format!("/violations?scan={}", original), data-testid="scan-result-original-link") {
+                            (format!("View full original scan {}", short_id(original)))
+                        }
+                    }
+                } else {
+                    view! {}
+                })
+            }
+            (if let Some(rows) = before_after {
+                let table_rows = rows.into_iter().map(|(outcome, before)| {
+                    let after_variant = status_variant(&outcome.status);
+                    let before_variant = status_variant(&before);
+                    view! {
+                        tr(data-testid=format!("recheck-row-{}", outcome.check_id)) {
+                            td { (outcome.check_id.to_string()) }
+                            td { (outcome.description) }
+                            td { Badge(variant=before_variant, data-testid="recheck-before") { (before) } }
+                            td { Badge(variant=after_variant, data-testid="recheck-after") { (outcome.status) } }
+                        }
+                    }
+                }).collect::<Vec<_>>();
+                view! {
+                    Table(data-testid="recheck-comparison") {
+                        thead {
+                            tr {
+                                th { "Check" }
+                                th { "Description" }
+                                th { "Before" }
+                                th { "After" }
+                            }
+                        }
+                        tbody { (table_rows) }
+                    }
+                }
+            } else {
+                view! {}
+            })
+        }
+    }
+}
+
+/// Pair each rechecked outcome with its status in the baseline scan ("--" if absent).
+fn before_after_rows(baseline: &Scan, after: &[CheckOutcome]) -> Vec<(CheckOutcome, String)> {
+    let before = baseline.check_outcomes();
+    after.iter().map(|outcome| {
+        let prior = before.iter()
+            .find(|b| b.check_id == outcome.check_id)
+            .map(|b| b.status.clone())
+            .unwrap_or_else(|| "--".into());
+        (outcome.clone(), prior)
+    }).collect()
+}
+
+fn status_variant(status: &str) -> &'static str {
+    match status {
+        "pass" => "success",
+        "fail" => "danger",
+        _ => "warning",
+    }
+}
+
+fn short_id(id: &str) -> String {
+    if id.len() >= 8 { id[..8].to_string() } else { id.to_string() }
+}
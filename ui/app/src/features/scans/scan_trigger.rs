@@ -1,18 +1,22 @@
 use rsc_compat::prelude::*;
+use crate::util::flags::{use_flags, INCREMENTAL_SCAN};
 
 /// Scan trigger form with engine/scope selection (FR-300).
 #[component]
 pub fn scan_trigger(
-    on_trigger: Option<Box<dyn Fn(String, String, Option<String>)>>,
+    on_trigger: Option<Box<dyn Fn(String, String, Option<String>, bool)>>,
     disabled: bool,
 ) -> View {
     let engine = signal("doc-engine".to_string());
     let checks = signal(String::new());
+    let incremental = signal(false);
+    let show_incremental = use_flags().is_enabled(INCREMENTAL_SCAN);
 
     let engine_change = engine.clone();
     let checks_input = checks.clone();
     let engine_click = engine.clone();
     let checks_click = checks.clone();
+    let incremental_click = incremental.clone();
 
     view! {
         style {
@@ -33,6 +37,21 @@ pub fn scan_trigger(
                     data-testid="scan-checks-input",
                 )
             }
+            (if show_incremental && engine.get() == "doc-engine" {
+                view! {
+                    Checkbox(
+                        label="Incremental",
+                        checked=incremental.get(),
+                        on:change={
+                            let i = incremental.clone();
+                            move |_v: String| i.set(!i.get())
+                        },
+                        data-testid="scan-incremental-toggle",
+                    )
+                }
+            } else {
+                view! {}
+            })
             Button(
                 label="Run Scan",
                 variant="primary",
@@ -40,9 +59,11 @@ pub fn scan_trigger(
                 on:click={
                     let checks_click = checks_click.clone();
                     let engine_click = engine_click.clone();
+                    let incremental_click = incremental_click.clone();
                     move || {
                         let c = if checks_click.get().is_empty() { None } else { Some(checks_click.get().clone()) };
-                        if let Some(ref cb) = on_trigger { cb(engine_click.get().clone(), String::new(), c) }
+                        let inc = show_incremental && engine_click.get() == "doc-engine" && incremental_click.get();
+                        if let Some(ref cb) = on_trigger { cb(engine_click.get().clone(), String::new(), c, inc) }
                     }
                 },
                 data-testid="scan-run-btn",
@@ -11,10 +11,12 @@ pub async fn create_scan(request: &ScanRequest) -> Result<Scan, ApiError> {
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse scan creation response".into(),
+        fields: Vec::new(),
     })?;
     Scan::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "scan response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
@@ -27,25 +29,47 @@ pub async fn get_scan(scan_id: &str) -> Result<Scan, ApiError> {
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse scan response".into(),
+        fields: Vec::new(),
     })?;
     Scan::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "scan response missing required fields".into(),
+        fields: Vec::new(),
+    })
+}
+
+/// Re-run only the failed checks of a completed scan.
+/// Returns the new partial scan, linked to the original via `recheck_of`.
+/// Maps to: POST /api/v1/scans/{id}/recheck (FR-306)
+pub async fn recheck_scan(scan_id: &str) -> Result<Scan, ApiError> {
+    let path = format!("/scans/{scan_id}/recheck");
+    let response = api_post(&path, "{}").await?;
+    let parsed = json_parse(&response).ok_or_else(|| ApiError {
+        code: "PARSE_ERROR".into(),
+        message: "failed to parse recheck response".into(),
+        fields: Vec::new(),
+    })?;
+    Scan::from_json(&parsed).ok_or_else(|| ApiError {
+        code: "PARSE_ERROR".into(),
+        message: "scan response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
 /// List all scans for a given project, ordered by timestamp descending.
 /// Maps to: GET /api/v1/projects/{id}/scans (FR-305)
 pub async fn list_project_scans(project_id: &str) -> Result<Vec<Scan>, ApiError> {
-    let path = format!("/projects/{project_id}/scans");
+    let path = format!("/projects/{project_id}/scans?limit=500");
     let response = api_get(&path).await?;
     let parsed = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse scan list response".into(),
+        fields: Vec::new(),
     })?;
-    let array = parsed.as_array().ok_or_else(|| ApiError {
+    let array = parsed.get("items").and_then(|items| items.as_array()).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
-        message: "expected array of scans".into(),
+        message: "expected paginated list of scans".into(),
+        fields: Vec::new(),
     })?;
 
     let mut scans = Vec::new();
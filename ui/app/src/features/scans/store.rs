@@ -6,6 +6,7 @@ use crate::features::scans::service;
 ///
 /// Signals:
 ///   active_scan        — The currently running scan (None when idle)
+///   recheck_source     — The scan whose failures the active scan re-runs (None for full scans)
 ///   scan_history       — List of past scan records for the selected project
 ///   selected_project_id — The project whose scans are displayed
 ///   loading            — Whether an async operation is in flight
@@ -13,6 +14,7 @@ use crate::features::scans::service;
 #[derive(Clone)]
 pub struct ScansStore {
     pub active_scan: Signal<Option<Scan>>,
+    pub recheck_source: Signal<Option<Scan>>,
     pub scan_history: Signal<Vec<Scan>>,
     pub selected_project_id: Signal<Option<String>>,
     pub loading: Signal<bool>,
@@ -24,6 +26,7 @@ impl ScansStore {
     pub fn new() -> Self {
         Self {
             active_scan: signal(None),
+            recheck_source: signal(None),
             scan_history: signal(Vec::new()),
             selected_project_id: signal(None),
             loading: signal(false),
@@ -37,6 +40,7 @@ impl ScansStore {
 pub fn trigger_scan(store: &ScansStore, request: ScanRequest) {
     store.loading.set(true);
     store.error.set(None);
+    store.recheck_source.set(None);
 
     let active_scan = store.active_scan.clone();
     let loading = store.loading.clone();
@@ -57,6 +61,32 @@ pub fn trigger_scan(store: &ScansStore, request: ScanRequest) {
     });
 }
 
+/// Re-run only the failing checks of `scan`. On success the new partial scan
+/// becomes the active scan and `scan` is kept as the before/after baseline.
+pub fn recheck_failures(store: &ScansStore, scan: Scan) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    let active_scan = store.active_scan.clone();
+    let recheck_source = store.recheck_source.clone();
+    let loading = store.loading.clone();
+    let error = store.error.clone();
+
+    spawn(async move {
+        match service::recheck_scan(&scan.id).await {
+            Ok(recheck) => {
+                recheck_source.set(Some(scan));
+                active_scan.set(Some(recheck));
+                loading.set(false);
+            }
+            Err(api_error) => {
+                error.set(Some(api_error.message));
+                loading.set(false);
+            }
+        }
+    });
+}
+
 /// Load scan history for the given project and populate scan_history signal.
 /// Clears any prior error on invocation; sets error on failure.
 pub fn load_history(store: &ScansStore, project_id: &str) {
@@ -107,6 +137,7 @@ pub fn refresh_active_scan(store: &ScansStore) {
 /// Clear the active scan signal (e.g., when a scan completes and user dismisses it).
 pub fn clear_active_scan(store: &ScansStore) {
     store.active_scan.set(None);
+    store.recheck_source.set(None);
 }
 
 /// Clear the error signal.
@@ -53,3 +53,33 @@ async fn scan_status_updates(ctx: BrowserTestContext) {
     ctx.wait_for("[data-testid='scan-history']").await.unwrap();
     ctx.assert_element_exists("[data-testid='scan-history']").await;
 }
+
+/// Completed scans must offer "Copy as Markdown" with a confirmation toast.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn scan_copy_markdown_shows_toast(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/scans", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='scan-engine-select']").await.unwrap();
+    ctx.select("[data-testid='scan-engine-select']", "doc-engine").await.unwrap();
+    ctx.click("[data-testid='scan-run-btn']").await.unwrap();
+    ctx.wait_for("[data-testid='scan-copy-markdown']").await.unwrap();
+    ctx.click("[data-testid='scan-copy-markdown']").await.unwrap();
+    ctx.wait_for("[data-testid='scan-copy-markdown-toast']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='scan-copy-markdown-toast']").await;
+}
+
+/// Rechecking failures must show a partial result with a before/after table and a link to the original scan.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn scan_recheck_failures_links_original(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/scans", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='scan-engine-select']").await.unwrap();
+    ctx.select("[data-testid='scan-engine-select']", "doc-engine").await.unwrap();
+    ctx.click("[data-testid='scan-run-btn']").await.unwrap();
+    ctx.wait_for("[data-testid='scan-result']").await.unwrap();
+    ctx.wait_for("[data-testid='scan-recheck-btn']").await.unwrap();
+    ctx.click("[data-testid='scan-recheck-btn']").await.unwrap();
+    ctx.wait_for("[data-testid='scan-result-partial']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='scan-result-original-link']").await;
+    ctx.assert_element_exists("[data-testid='recheck-comparison']").await;
+}
@@ -84,6 +84,10 @@ pub struct Scan {
     pub started_at: String,
     pub finished_at: Option<String>,
     pub report: Option<String>,
+    /// Original scan id when this scan re-ran only that scan's failed checks.
+    pub recheck_of: Option<String>,
+    /// True when only a subset of checks ran (a recheck of failures).
+    pub partial: bool,
 }
 
 impl Scan {
@@ -96,10 +100,47 @@ impl Scan {
             status: value.get_str("status").unwrap_or_default().into(),
             started_at: value.get_str("started_at").unwrap_or_default().into(),
             finished_at: value.get_str("finished_at").map(|s| s.into()),
-            report: value.get_str("report").map(|s| s.into()),
+            report: value.get("report").filter(|v| !v.is_null()).map(|v| {
+                // The API returns the report as a JSON object; keep it as raw JSON text.
+                v.as_str().map(String::from).unwrap_or_else(|| v.to_string())
+            }),
+            recheck_of: value.get_str("recheck_of").map(|s| s.into()),
+            partial: value.get_bool("partial").unwrap_or(false),
         })
     }
 
+    /// Per-check outcomes from the report, in report order. Empty until the scan completes.
+    pub fn check_outcomes(&self) -> Vec<CheckOutcome> {
+        let report = self.report.as_deref().and_then(json_parse).unwrap_or_default();
+        let results = report.get("results")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        results.iter().map(|entry| CheckOutcome {
+            check_id: entry.get_u32("id").unwrap_or_default(),
+            description: entry.get_str("description").unwrap_or_default().into(),
+            status: entry.get("result")
+                .and_then(|r| r.get_str("status"))
+                .unwrap_or_default()
+                .into(),
+        }).collect()
+    }
+
+    /// Ids of the checks that failed in this scan's report.
+    pub fn failed_check_ids(&self) -> Vec<u32> {
+        self.check_outcomes()
+            .into_iter()
+            .filter(|o| o.status == "fail")
+            .map(|o| o.check_id)
+            .collect()
+    }
+
+    /// True once the server has recorded a finish time.
+    pub fn is_finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+
     /// Compute duration string between started_at and finished_at.
     /// Returns "--" if the scan has not finished.
     pub fn duration_display(&self) -> String {
@@ -135,6 +176,15 @@ impl Scan {
     }
 }
 
+/// Outcome of a single check within a scan report.
+#[derive(Clone, Debug)]
+pub struct CheckOutcome {
+    pub check_id: u32,
+    pub description: String,
+    /// "pass", "fail" or "skip".
+    pub status: String,
+}
+
 /// Request body for triggering a new scan (POST /api/v1/scans).
 #[derive(Clone, Debug)]
 pub struct ScanRequest {
@@ -143,6 +193,9 @@ pub struct ScanRequest {
     pub checks: Option<String>,
     pub phase: Option<String>,
     pub module: Option<String>,
+    /// Reuse cached results for unchanged files (`incremental_scan` flag).
+    /// Only doc-engine caches results, so struct-engine requests never set it.
+    pub incremental: bool,
 }
 
 impl ScanRequest {
@@ -171,6 +224,10 @@ impl ScanRequest {
             }
         }
 
+        if self.incremental {
+            obj.as_object_mut().unwrap().insert("incremental".to_string(), JsonValue::Bool(true));
+        }
+
         json_stringify(&obj)
     }
 }
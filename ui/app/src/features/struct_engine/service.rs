@@ -10,6 +10,7 @@ pub async fn get_struct_results(scan_id: &str) -> Result<Vec<StructCheck>, ApiEr
     let parsed: JsonValue = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse struct scan response".into(),
+        fields: Vec::new(),
     })?;
     let empty_vec = vec![];
     let checks = parsed
@@ -27,10 +28,12 @@ pub async fn get_crate_layout(project_id: &str) -> Result<CrateNode, ApiError> {
     let parsed: JsonValue = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse crate layout response".into(),
+        fields: Vec::new(),
     })?;
     CrateNode::from_json(&parsed).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "crate layout response missing required fields".into(),
+        fields: Vec::new(),
     })
 }
 
@@ -42,6 +45,7 @@ pub async fn get_project_kind(project_id: &str) -> Result<String, ApiError> {
     let parsed: JsonValue = json_parse(&response).ok_or_else(|| ApiError {
         code: "PARSE_ERROR".into(),
         message: "failed to parse project kind response".into(),
+        fields: Vec::new(),
     })?;
     parsed.get("kind")
         .and_then(|v| v.as_str())
@@ -49,5 +53,6 @@ pub async fn get_project_kind(project_id: &str) -> Result<String, ApiError> {
         .ok_or_else(|| ApiError {
             code: "PARSE_ERROR".into(),
             message: "project kind response missing 'kind' field".into(),
+            fields: Vec::new(),
         })
 }
@@ -35,7 +35,12 @@ impl ViolationsStore {
     /// Create a new store with default initial state.
     pub fn new() -> Self {
         let all_violations = signal(Vec::<ViolationEntry>::new());
-        let filter = signal(ViolationFilter::default());
+        // Restore a shared filtered view from the URL, then keep the URL in sync
+        let filter = signal(filter_from_query(&window_location_search()));
+        effect({
+            let filter = filter.clone();
+            move || write_filter_to_url(&filter.get())
+        });
         let sort_field = signal(SortField::default());
         let sort_direction = signal(SortDirection::default());
         let selected_scan_id = signal(Option::<String>::None);
@@ -219,6 +224,85 @@ impl ViolationsStore {
     }
 }
 
+/// Severity levels the filter bar can toggle.
+const SEVERITIES: &[&str] = &["Error", "Warning", "Info"];
+
+/// Query-string keys owned by the violations filter.
+const FILTER_KEYS: &[&str] = &["category", "severity"];
+
+/// Serialize the shareable filter state (category and severities) into a
+/// query string without the leading `?`. Default values are left out.
+pub fn filter_to_query(filter: &ViolationFilter) -> String {
+    let mut pairs = Vec::new();
+    if let Some(ref category) = filter.category {
+        pairs.push(format!("category={category}"));
+    }
+    if filter.severities != ViolationFilter::default().severities {
+        pairs.push(format!("severity={}", filter.severities.join(",")));
+    }
+    pairs.join("&")
+}
+
+/// Restore the filter state from a query string (with or without the leading
+/// `?`). A malformed category or severity list falls back to its default.
+pub fn filter_from_query(query: &str) -> ViolationFilter {
+    let mut filter = ViolationFilter::default();
+    for (key, value) in query.trim_start_matches('?').split('&').filter_map(|pair| pair.split_once('=')) {
+        match key {
+            "category" => {
+                let valid = !value.is_empty()
+                    && value.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+                if valid {
+                    filter.category = Some(value.to_string());
+                }
+            }
+            "severity" => {
+                let levels: Vec<String> = value
+                    .split(',')
+                    .filter(|level| !level.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                if levels.iter().all(|level| SEVERITIES.contains(&level.as_str())) {
+                    filter.severities = SEVERITIES
+                        .iter()
+                        .filter(|level| levels.iter().any(|l| l == *level))
+                        .map(|level| level.to_string())
+                        .collect();
+                }
+            }
+            _ => {}
+        }
+    }
+    filter
+}
+
+/// Replace the filter params in the current URL without adding a history
+/// entry. Other params are kept; nothing happens outside `/violations`.
+fn write_filter_to_url(filter: &ViolationFilter) {
+    let Some(window) = web_sys::window() else { return };
+    let location = window.location();
+    let Ok(path) = location.pathname() else { return };
+    if path != "/violations" {
+        return;
+    }
+    let search = location.search().unwrap_or_default();
+    let mut pairs: Vec<String> = search
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| !FILTER_KEYS.contains(&pair.split('=').next().unwrap_or_default()))
+        .map(str::to_string)
+        .collect();
+    let query = filter_to_query(filter);
+    if !query.is_empty() {
+        pairs.push(query);
+    }
+    let url = if pairs.is_empty() { path } else { format!("{path}?{}", pairs.join("&")) };
+    if let Ok(history) = window.history() {
+        let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url));
+    }
+}
+
 /// Provide the violations store via context for child components.
 pub fn provide_violations_store() -> ViolationsStore {
     let store = ViolationsStore::new();
@@ -230,3 +314,32 @@ pub fn provide_violations_store() -> ViolationsStore {
 pub fn use_violations_store() -> ViolationsStore {
     use_context::<ViolationsStore>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_query_round_trip() {
+        let filter = ViolationFilter {
+            category: Some("design_documentation".into()),
+            severities: vec!["Error".into(), "Warning".into()],
+            search: String::new(),
+        };
+        let query = filter_to_query(&filter);
+        assert_eq!(query, "category=design_documentation&severity=Error,Warning");
+
+        let restored = filter_from_query(&format!("?{query}"));
+        assert_eq!(restored.category.as_deref(), Some("design_documentation"));
+        assert_eq!(restored.severities, filter.severities);
+    }
+
+    #[test]
+    fn test_filter_from_malformed_query_uses_defaults() {
+        let restored = filter_from_query("?category=../etc&severity=Error,Fatal&severity");
+        let defaults = ViolationFilter::default();
+        assert_eq!(restored.category, None);
+        assert_eq!(restored.severities, defaults.severities);
+        assert_eq!(filter_to_query(&defaults), "");
+    }
+}
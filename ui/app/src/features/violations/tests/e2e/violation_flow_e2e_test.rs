@@ -46,3 +46,12 @@ async fn search_violations(ctx: BrowserTestContext) {
         "Search input should contain typed text"
     );
 }
+
+/// The CSV download action must be rendered above the violation table.
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn download_csv_renders(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/violations", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.wait_for("[data-testid='violations-download-csv']").await.unwrap();
+    ctx.assert_element_visible("[data-testid='violations-download-csv']").await;
+}
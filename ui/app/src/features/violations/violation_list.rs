@@ -1,7 +1,9 @@
 use rsc_compat::prelude::*;
 use crate::features::violations::types::ViolationEntry;
+use crate::util::csv::{download_csv, violations_to_csv};
 
-/// Sortable table of violations with severity badges (FR-400..401).
+/// Sortable table of violations with severity badges (FR-400..401) and a
+/// "Download CSV" action for the rows currently shown.
 #[component]
 pub fn violation_list(
     violations: Signal<Vec<ViolationEntry>>,
@@ -24,7 +26,7 @@ pub fn violation_list(
                 data-testid=row_testid,
             ) {
                 td(data-testid="violation-check-id") { (check_id) }
-                td(data-testid="violation-category") { (category) }
+                td(class="violation-list__secondary", data-testid="violation-category") { (category) }
                 td {
                     Badge(
                         variant=badge_variant,
@@ -38,13 +40,43 @@ pub fn violation_list(
             }
         }
     }).collect::<Vec<_>>();
+    let download_error = signal(None::<String>);
 
     view! {
+        style {
+            .violation-list__actions { display: flex; justify-content: flex-end; gap: var(--space-2); }
+            @media (max-width: 768px) {
+                .violation-list__secondary { display: none; }
+            }
+        }
+        div(class="violation-list__actions") {
+            button(
+                class="btn btn--secondary btn--sm",
+                disabled=violations.get().is_empty(),
+                on:click={
+                    let violations = violations.clone();
+                    let download_error = download_error.clone();
+                    move || {
+                        let csv = violations_to_csv(&violations.get());
+                        download_error.set(download_csv("violations.csv", &csv).err());
+                    }
+                },
+                data-testid="violations-download-csv",
+            ) {
+                "Download CSV"
+            }
+            (match download_error.get() {
+                Some(e) => view! {
+                    div(class="toast toast--danger", role="alert", data-testid="violations-download-error") { (e) }
+                },
+                None => view! {},
+            })
+        }
         Table(data-testid="violation-list") {
             thead {
                 tr {
                     th { "Check ID" }
-                    th { "Category" }
+                    th(class="violation-list__secondary") { "Category" }
                     th { "Severity" }
                     th { "File" }
                     th { "Message" }
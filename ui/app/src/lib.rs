@@ -25,9 +25,11 @@ pub fn start() {
 
 use crate::page::app::app_shell;
 use crate::util::auth::{auth_provider, AuthState};
+use crate::util::flags::FlagsState;
 use crate::features::ai::store::AiStore;
 use crate::features::dashboard::store::DashboardStore;
 use crate::features::editor::store::EditorStore;
+use crate::features::fleet::store::FleetStore;
 use crate::features::projects::store::ProjectsStore;
 use crate::features::reports::store::ReportsStore;
 use crate::features::scaffold::store::ScaffoldStore;
@@ -51,6 +53,7 @@ pub fn app() -> View {
     provide_context(AiStore::new());
     provide_context(DashboardStore::new());
     provide_context(EditorStore::new());
+    provide_context(FleetStore::new());
     provide_context(ProjectsStore::new());
     provide_context(ReportsStore::new());
     provide_context(ScaffoldStore::new());
@@ -60,6 +63,9 @@ pub fn app() -> View {
     provide_context(TemplatesStore::new());
     provide_context(ViolationsStore::new());
 
+    // Feature flags are fetched by app_shell once the user is authenticated.
+    provide_context(FlagsState::new());
+
     // Call component functions directly (the view! macro renders PascalCase
     // tags as plain HTML elements, so we invoke them as Rust functions).
     auth_provider(auth_state, vec![app_shell()])
@@ -1,8 +1,10 @@
 use rsc_compat::prelude::*;
-use crate::util::auth::use_auth;
+use crate::util::auth::{use_auth, is_admin, is_read_only};
+use crate::util::flags::{use_flags, load_flags, is_dev_flags_view, dev_flags_overlay};
 use crate::features::ai::ai_landing::ai_landing;
 use crate::features::dashboard::dashboard_landing::dashboard_landing;
 use crate::features::editor::editor_landing::editor_landing;
+use crate::features::fleet::fleet_landing::fleet_landing;
 use crate::features::projects::project_landing::projects_landing;
 use crate::features::reports::report_landing::reports_landing;
 use crate::features::scaffold::scaffold_landing::scaffold_landing;
@@ -13,9 +15,45 @@ use crate::features::templates::template_landing::templates_landing;
 use crate::features::violations::violation_landing::violations_landing;
 
 /// Navigate to a path and update the route signal.
+///
+/// Any query string stays in the URL; the signal only holds the pathname.
 fn go(route: &Signal<String>, path: &str) {
     navigate(path);
-    route.set(path.to_string());
+    route.set(path.split('?').next().unwrap_or(path).to_string());
+}
+
+/// The client-side route signal, provided so feature pages can navigate.
+#[derive(Clone)]
+pub struct AppRoute(pub Signal<String>);
+
+impl AppRoute {
+    /// Navigate to `path`, re-rendering the content area.
+    pub fn go(&self, path: &str) {
+        go(&self.0, path);
+    }
+}
+
+/// Resolve the bare root path to the landing page for the current user:
+/// the fleet overview for admins, the dashboard for everyone else.
+fn landing_route(route: &str, admin: bool) -> &str {
+    match route {
+        "/" if admin => "/fleet",
+        "/" => "/dashboard",
+        _ => route,
+    }
+}
+
+/// Routes that require an authenticated user; hidden in the read-only view.
+const AUTH_ONLY_ROUTES: &[&str] = &["/editor", "/scaffold", "/templates", "/ai"];
+
+/// Resolve the route to render, falling back to the dashboard for auth-only
+/// routes when the app is in read-only mode.
+fn visible_route(route: &str, read_only: bool) -> &str {
+    if read_only && AUTH_ONLY_ROUTES.contains(&route) {
+        "/dashboard"
+    } else {
+        route
+    }
 }
 
 /// Root application layout with sidebar navigation and client-side SPA router.
@@ -28,8 +66,17 @@ fn go(route: &Signal<String>, path: &str) {
 pub fn app_shell() -> View {
     let _auth = use_auth();
     let route = signal(use_route());
+    let read_only = is_read_only();
+    let admin = is_admin();
+    provide_context(AppRoute(route.clone()));
+    let flags = use_flags();
+    effect({
+        let flags = flags.clone();
+        move || load_flags(&flags)
+    });
 
     // --- helper clones for each nav on:click closure ---
+    let r_fleet        = route.clone();
     let r_dashboard    = route.clone();
     let r_projects     = route.clone();
     let r_scans        = route.clone();
@@ -45,6 +92,11 @@ pub fn app_shell() -> View {
     // Clone for route matching in the content area.
     let r_view = route.clone();
 
+    // Mobile nav drawer: the sidebar collapses behind a menu toggle below 768px.
+    let nav_open = signal(false);
+    let nav_toggle = nav_open.clone();
+    let nav_close = nav_open.clone();
+
     view! {
         style {
             .app {
@@ -116,23 +168,124 @@ pub fn app_shell() -> View {
                 overflow-y: auto;
             }
 
+            .app__read-only {
+                margin: var(--space-3);
+                padding: var(--space-2) var(--space-3);
+                border-radius: var(--radius-md);
+                background: var(--color-warning-bg);
+                color: var(--color-warning);
+                font-size: var(--font-size-xs);
+                font-weight: 600;
+                text-align: center;
+            }
+
             .app__footer {
                 padding: var(--space-3) var(--space-6);
                 font-size: var(--font-size-xs);
                 color: var(--color-text-muted);
                 border-top: 1px solid var(--color-border);
             }
+
+            .app__topbar {
+                display: none;
+            }
+
+            .app__menu-toggle {
+                padding: var(--space-2) var(--space-3);
+                border: 1px solid var(--color-border);
+                border-radius: var(--radius-md);
+                background: none;
+                color: var(--color-text);
+                font-family: inherit;
+                font-size: var(--font-size-lg);
+                line-height: 1;
+                cursor: pointer;
+            }
+
+            @media (max-width: 768px) {
+                .app {
+                    flex-direction: column;
+                }
+
+                .app__topbar {
+                    display: flex;
+                    align-items: center;
+                    justify-content: space-between;
+                    padding: var(--space-3) var(--space-4);
+                    background: var(--color-surface);
+                    border-bottom: 1px solid var(--color-border);
+                    font-weight: 700;
+                    color: var(--color-primary);
+                }
+
+                .app__sidebar {
+                    display: none;
+                    width: 100%;
+                    border-right: none;
+                    border-bottom: 1px solid var(--color-border);
+                }
+
+                .app__sidebar--open {
+                    display: flex;
+                }
+
+                .app__sidebar .app__logo {
+                    display: none;
+                }
+
+                .app__content {
+                    padding: var(--space-4);
+                }
+
+                .app__content table {
+                    display: block;
+                    max-width: 100%;
+                    overflow-x: auto;
+                }
+
+                .app__content th:first-child,
+                .app__content td:first-child {
+                    position: sticky;
+                    left: 0;
+                    z-index: 1;
+                    background: var(--color-surface);
+                }
+            }
         }
 
         <SkipLink target="main-content" />
         <div class="app" data-testid="app-shell">
-            <nav class="app__sidebar" data-testid="sidebar" role="navigation" aria-label="Main navigation">
+            <header class="app__topbar" data-testid="topbar">
+                "swe-compliance"
+                <button class="app__menu-toggle"
+                        on:click={move || nav_toggle.set(!nav_toggle.get())}
+                        aria-controls="app-sidebar"
+                        aria-expanded={nav_open.get().to_string()}
+                        aria-label="Toggle navigation"
+                        data-testid="nav-toggle">
+                    "\u{2630}"
+                </button>
+            </header>
+            <nav id="app-sidebar"
+                 class="app__sidebar"
+                 class:app__sidebar--open={nav_open.get()}
+                 data-testid="sidebar"
+                 role="navigation"
+                 aria-label="Main navigation">
                 <div class="app__logo" data-testid="app-logo">
                     "swe-compliance"
                 </div>
-                <div class="app__nav">
+                <div class="app__nav" on:click={move || nav_close.set(false)}>
                     <a class="app__nav-item"
-                       class:app__nav-item--active={route.get().starts_with("/dashboard") || route.get() == "/"}
+                       class:app__nav-item--active={route.get().starts_with("/fleet") || (admin && route.get() == "/")}
+                       on:click={move || go(&r_fleet, "/fleet")}
+                       role="link"
+                       tabindex="0"
+                       data-testid="nav-fleet">
+                        "Fleet"
+                    </a>
+                    <a class="app__nav-item"
+                       class:app__nav-item--active={route.get().starts_with("/dashboard") || (!admin && route.get() == "/")}
                        on:click={move || go(&r_dashboard, "/dashboard")}
                        role="link"
                        tabindex="0"
@@ -163,22 +316,26 @@ pub fn app_shell() -> View {
                        data-testid="nav-violations">
                         "Violations"
                     </a>
-                    <a class="app__nav-item"
-                       class:app__nav-item--active={route.get().starts_with("/scaffold")}
-                       on:click={move || go(&r_scaffold, "/scaffold")}
-                       role="link"
-                       tabindex="0"
-                       data-testid="nav-scaffold">
-                        "Scaffold"
-                    </a>
-                    <a class="app__nav-item"
-                       class:app__nav-item--active={route.get().starts_with("/templates")}
-                       on:click={move || go(&r_templates, "/templates")}
-                       role="link"
-                       tabindex="0"
-                       data-testid="nav-templates">
-                        "Templates"
-                    </a>
+                    if !read_only {
+                        <a class="app__nav-item"
+                           class:app__nav-item--active={route.get().starts_with("/scaffold")}
+                           on:click={move || go(&r_scaffold, "/scaffold")}
+                           role="link"
+                           tabindex="0"
+                           data-testid="nav-scaffold">
+                            "Scaffold"
+                        </a>
+                    }
+                    if !read_only {
+                        <a class="app__nav-item"
+                           class:app__nav-item--active={route.get().starts_with("/templates")}
+                           on:click={move || go(&r_templates, "/templates")}
+                           role="link"
+                           tabindex="0"
+                           data-testid="nav-templates">
+                            "Templates"
+                        </a>
+                    }
                     <a class="app__nav-item"
                        class:app__nav-item--active={route.get().starts_with("/reports")}
                        on:click={move || go(&r_reports, "/reports")}
@@ -187,22 +344,26 @@ pub fn app_shell() -> View {
                        data-testid="nav-reports">
                         "Reports"
                     </a>
-                    <a class="app__nav-item"
-                       class:app__nav-item--active={route.get().starts_with("/ai")}
-                       on:click={move || go(&r_ai, "/ai")}
-                       role="link"
-                       tabindex="0"
-                       data-testid="nav-ai">
-                        "AI Features"
-                    </a>
-                    <a class="app__nav-item"
-                       class:app__nav-item--active={route.get().starts_with("/editor")}
-                       on:click={move || go(&r_editor, "/editor")}
-                       role="link"
-                       tabindex="0"
-                       data-testid="nav-editor">
-                        "SRS Editor"
-                    </a>
+                    if !read_only {
+                        <a class="app__nav-item"
+                           class:app__nav-item--active={route.get().starts_with("/ai")}
+                           on:click={move || go(&r_ai, "/ai")}
+                           role="link"
+                           tabindex="0"
+                           data-testid="nav-ai">
+                            "AI Features"
+                        </a>
+                    }
+                    if !read_only {
+                        <a class="app__nav-item"
+                           class:app__nav-item--active={route.get().starts_with("/editor")}
+                           on:click={move || go(&r_editor, "/editor")}
+                           role="link"
+                           tabindex="0"
+                           data-testid="nav-editor">
+                            "SRS Editor"
+                        </a>
+                    }
                     <a class="app__nav-item"
                        class:app__nav-item--active={route.get().starts_with("/specs")}
                        on:click={move || go(&r_specs, "/specs")}
@@ -220,13 +381,19 @@ pub fn app_shell() -> View {
                         "Struct Engine"
                     </a>
                 </div>
+                if read_only {
+                    <div class="app__read-only" data-testid="read-only-banner">
+                        "Read-only view"
+                    </div>
+                }
                 <div class="app__footer">
                     "v0.1.0"
                 </div>
             </nav>
             <main id="main-content" class="app__content" data-testid="main-content" role="main">
-                {match r_view.get().as_str() {
-                    "/" | "/dashboard" => dashboard_landing(),
+                {match visible_route(landing_route(r_view.get().as_str(), admin), read_only) {
+                    "/dashboard"      => dashboard_landing(),
+                    "/fleet"          => fleet_landing(),
                     "/projects"       => projects_landing(),
                     "/scans"          => scans_landing(),
                     "/editor"         => editor_landing(),
@@ -240,6 +407,9 @@ pub fn app_shell() -> View {
                     _                 => dashboard_landing(),
                 }}
             </main>
+            if is_dev_flags_view() {
+                {dev_flags_overlay(flags.clone())}
+            }
         </div>
     }
 }
@@ -1,5 +1,5 @@
 use rsc_compat::prelude::*;
-use crate::util::auth::get_token;
+use crate::util::auth::{get_token, is_read_only};
 
 /// Base URL for API requests (proxied through rsc dev server).
 const API_BASE: &str = "/api/v1";
@@ -14,9 +14,46 @@ enum Method {
 }
 
 /// Shared API client error.
+///
+/// Parsed from the server's structured error body (FR-1204). `fields` lists the
+/// request fields the server rejected, for inline display next to form inputs.
 pub struct ApiError {
     pub code: String,
     pub message: String,
+    pub fields: Vec<FieldError>,
+}
+
+/// A server-side validation failure on one request field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl ApiError {
+    /// Message the server gave for `field`, if it rejected that field.
+    pub fn field_message(&self, field: &str) -> Option<String> {
+        field_message(&self.fields, field)
+    }
+}
+
+/// Message for `field` among a store's current field errors.
+pub fn field_message(fields: &[FieldError], field: &str) -> Option<String> {
+    fields.iter().find(|f| f.field == field).map(|f| f.message.clone())
+}
+
+/// Route a failed request's error into a store's signals.
+///
+/// Field errors go to `fields` so forms can show them inline next to their
+/// inputs; an error not tied to any field goes to `toast`.
+pub fn report_error(err: ApiError, toast: &Signal<Option<String>>, fields: &Signal<Vec<FieldError>>) {
+    if err.fields.is_empty() {
+        fields.set(Vec::new());
+        toast.set(Some(err.message));
+    } else {
+        toast.set(None);
+        fields.set(err.fields);
+    }
 }
 
 /// Perform a GET request with JWT header injection.
@@ -71,39 +108,90 @@ async fn api_request(method: Method, path: &str, body: Option<&str>) -> Result<S
 
     match response.status {
         200..=299 => Ok(response.body),
+        401 if is_read_only() => Err(ApiError {
+            code: "READ_ONLY".into(),
+            message: "not available in the read-only view — sign in to access it".into(),
+            fields: Vec::new(),
+        }),
         401 => Err(ApiError {
             code: "UNAUTHORIZED".into(),
             message: "session expired — please log in again".into(),
+            fields: Vec::new(),
         }),
         status => {
             let error = parse_error_body(&response.body).unwrap_or_else(|| ApiError {
                 code: format!("HTTP_{status}"),
                 message: response.body.clone(),
+                fields: Vec::new(),
             });
             Err(error)
         }
     }
 }
 
-/// Parse structured error response from the API.
+/// Parse structured error response from the API, including any field errors.
 fn parse_error_body(body: &str) -> Option<ApiError> {
     let parsed = json_parse(body)?;
     let error = parsed.get("error")?;
+    let fields = error
+        .get("fields")
+        .and_then(|f| f.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|f| {
+                    Some(FieldError {
+                        field: f.get("field")?.as_str()?.to_string(),
+                        message: f.get("message")?.as_str()?.to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
     Some(ApiError {
         code: error.get("code")?.as_str()?.to_string(),
         message: error.get("message")?.as_str()?.to_string(),
+        fields,
     })
 }
 
 /// Create a WebSocket connection with JWT query parameter.
+///
+/// The `token` query parameter is only appended when a token is present, so
+/// read-only viewers connect without an empty credential.
 pub fn api_ws(path: &str) -> WebSocket {
-    let token = get_token().unwrap_or_default();
     let protocol = if window_location_protocol() == "https:" {
         "wss:"
     } else {
         "ws:"
     };
     let host = window_location_host();
-    let url = format!("{protocol}//{host}{API_BASE}{path}?token={token}");
+    let url = match get_token() {
+        Some(token) => format!("{protocol}//{host}{API_BASE}{path}?token={token}"),
+        None => format!("{protocol}//{host}{API_BASE}{path}"),
+    };
     websocket_connect(&url)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_body_reads_field_errors() {
+        let body = r#"{"error": {"code": "VALIDATION_ERROR", "message": "name is required",
+            "fields": [{"field": "name", "message": "name is required"}]}}"#;
+        let err = parse_error_body(body).unwrap();
+        assert_eq!(err.code, "VALIDATION_ERROR");
+        assert_eq!(err.fields.len(), 1);
+        assert_eq!(err.field_message("name").as_deref(), Some("name is required"));
+        assert_eq!(err.field_message("root_path"), None);
+    }
+
+    #[test]
+    fn test_parse_error_body_without_fields() {
+        let body = r#"{"error": {"code": "NOT_FOUND", "message": "project not found"}}"#;
+        let err = parse_error_body(body).unwrap();
+        assert_eq!(err.message, "project not found");
+        assert!(err.fields.is_empty());
+    }
+}
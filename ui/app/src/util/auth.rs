@@ -6,6 +6,10 @@ pub struct AuthState {
     pub token: Option<String>,
     pub username: Option<String>,
     pub authenticated: bool,
+    /// True when edit/trigger controls must be hidden (`?view=public` or no token).
+    pub read_only: bool,
+    /// True when the server reported the user as an admin at login.
+    pub admin: bool,
 }
 
 impl Default for AuthState {
@@ -14,11 +18,15 @@ impl Default for AuthState {
         let token = local_storage_get("swe_auth_token");
         let username = local_storage_get("swe_auth_username");
         let authenticated = token.is_some();
+        let read_only = is_public_view() || !authenticated;
+        let admin = authenticated && is_admin();
 
         Self {
             token,
             username,
             authenticated,
+            read_only,
+            admin,
         }
     }
 }
@@ -31,7 +39,7 @@ pub fn auth_provider(
 ) -> View {
     provide_context(state.clone());
 
-    if state.get().authenticated {
+    if state.get().authenticated || is_public_view() {
         View::fragment(children)
     } else {
         login_form(state)
@@ -77,15 +85,21 @@ pub fn login_form(auth_state: Signal<AuthState>) -> View {
                     let token = parsed.get("token")
                         .and_then(|v| v.as_str())
                         .unwrap_or_default();
+                    let admin = parsed.get("admin")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
                     let user = username2.get().clone();
 
                     local_storage_set("swe_auth_token", token);
                     local_storage_set("swe_auth_username", &user);
+                    local_storage_set("swe_auth_admin", &admin.to_string());
 
                     auth_state2.set(AuthState {
                         token: Some(token.into()),
                         username: Some(user),
                         authenticated: true,
+                        read_only: false,
+                        admin,
                     });
                 }
             } else {
@@ -194,4 +208,39 @@ pub fn get_token() -> Option<String> {
 pub fn logout() {
     local_storage_remove("swe_auth_token");
     local_storage_remove("swe_auth_username");
+    local_storage_remove("swe_auth_admin");
+}
+
+/// True when the signed-in user is an admin; admins land on the fleet overview.
+pub fn is_admin() -> bool {
+    local_storage_get("swe_auth_admin").as_deref() == Some("true")
+}
+
+/// Read a query-string parameter from the current URL.
+pub fn query_param(name: &str) -> Option<String> {
+    window_location_search()
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
+
+/// True when the page was opened through a shareable `?view=public` link.
+pub fn is_public_view() -> bool {
+    query_param("view").as_deref() == Some("public")
+}
+
+/// True when the app renders without edit/trigger controls.
+pub fn is_read_only() -> bool {
+    is_public_view() || get_token().is_none()
+}
+
+/// Build a shareable deep link that opens a scan report in the read-only view.
+pub fn share_report_link(scan_id: &str) -> String {
+    format!(
+        "{}//{}/reports?scan={scan_id}&view=public",
+        window_location_protocol(),
+        window_location_host(),
+    )
 }
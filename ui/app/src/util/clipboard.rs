@@ -0,0 +1,53 @@
+use rsc_compat::prelude::*;
+
+/// Write text to the system clipboard via the async Clipboard API.
+pub async fn copy_text(text: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or_else(|| "window unavailable".to_string())?;
+    let promise = window.navigator().clipboard().write_text(text);
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("clipboard write failed: {e:?}"))
+}
+
+/// "Copy as Markdown" button that copies `markdown` and confirms with a toast.
+#[component]
+pub fn copy_markdown_button(markdown: Signal<String>, testid: String) -> View {
+    let toast = signal(None::<Result<(), String>>);
+    let toast_testid = format!("{testid}-toast");
+
+    view! {
+        span(class="copy-markdown") {
+            button(
+                class="btn btn--secondary btn--sm",
+                on:click={
+                    let markdown = markdown.clone();
+                    let toast = toast.clone();
+                    move || {
+                        let text = markdown.get();
+                        let toast = toast.clone();
+                        spawn(async move { toast.set(Some(copy_text(&text).await)); });
+                    }
+                },
+                data-testid=testid
+            ) {
+                "Copy as Markdown"
+            }
+            (match toast.get() {
+                Some(Ok(())) => view! {
+                    div(class="toast toast--success", role="status", data-testid=toast_testid.clone()) {
+                        span { "Summary copied as Markdown" }
+                        button(class="toast__dismiss", on:click={ let toast = toast.clone(); move || toast.set(None) }) { "Dismiss" }
+                    }
+                },
+                Some(Err(e)) => view! {
+                    div(class="toast toast--danger", role="alert", data-testid=toast_testid.clone()) {
+                        span { (e) }
+                        button(class="toast__dismiss", on:click={ let toast = toast.clone(); move || toast.set(None) }) { "Dismiss" }
+                    }
+                },
+                None => view! {},
+            })
+        }
+    }
+}
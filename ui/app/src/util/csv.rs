@@ -0,0 +1,101 @@
+use wasm_bindgen::JsCast;
+
+use crate::features::violations::types::ViolationEntry;
+
+/// Serialize violations to RFC 4180 CSV with a header row.
+/// Columns: check id, category, severity, file, message.
+pub fn violations_to_csv(violations: &[ViolationEntry]) -> String {
+    let mut csv = String::from("check_id,category,severity,file,message\r\n");
+    for v in violations {
+        let fields = [
+            v.check_id.to_string(),
+            v.category.clone(),
+            v.severity.clone(),
+            v.file_path.clone().unwrap_or_default(),
+            v.message.clone(),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| escape_field(f)).collect();
+        csv.push_str(&line.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Quote a field when it contains a comma, quote or line break, doubling
+/// embedded quotes (RFC 4180 section 2).
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Offer `csv` as a file download through a Blob and a temporary object URL.
+pub fn download_csv(filename: &str, csv: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or_else(|| "window unavailable".to_string())?;
+    let document = window.document().ok_or_else(|| "document unavailable".to_string())?;
+
+    let parts = js_sys::Array::of1(&wasm_bindgen::JsValue::from_str(csv));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("text/csv;charset=utf-8");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)
+        .map_err(|e| format!("cannot create CSV blob: {e:?}"))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(|e| format!("cannot create download URL: {e:?}"))?;
+
+    let anchor: web_sys::HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(|e| format!("cannot create link: {e:?}"))?
+        .dyn_into()
+        .map_err(|_| "cannot create link".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+    let _ = web_sys::Url::revoke_object_url(&url);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(check_id: u32, file_path: Option<&str>, message: &str) -> ViolationEntry {
+        ViolationEntry {
+            check_id,
+            category: "structure".into(),
+            description: "ignored".into(),
+            severity: "Error".into(),
+            file_path: file_path.map(str::to_string),
+            message: message.into(),
+        }
+    }
+
+    #[test]
+    fn test_violations_to_csv_plain_fields() {
+        let csv = violations_to_csv(&[entry(1, Some("docs/README.md"), "missing file"), entry(2, None, "no SRS")]);
+        assert_eq!(
+            csv,
+            "check_id,category,severity,file,message\r\n\
+             1,structure,Error,docs/README.md,missing file\r\n\
+             2,structure,Error,,no SRS\r\n"
+        );
+    }
+
+    #[test]
+    fn test_violations_to_csv_quotes_commas_and_quotes() {
+        let csv = violations_to_csv(&[entry(3, Some("a,b.md"), "expected \"Status\", found none")]);
+        assert!(csv.ends_with("3,structure,Error,\"a,b.md\",\"expected \"\"Status\"\", found none\"\r\n"));
+    }
+
+    #[test]
+    fn test_violations_to_csv_quotes_line_breaks() {
+        let csv = violations_to_csv(&[entry(4, None, "line one\nline two")]);
+        assert!(csv.ends_with("4,structure,Error,,\"line one\nline two\"\r\n"));
+    }
+
+    #[test]
+    fn test_violations_to_csv_empty_list_is_header_only() {
+        assert_eq!(violations_to_csv(&[]), "check_id,category,severity,file,message\r\n");
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use rsc_compat::prelude::*;
+use crate::util::api::api_get;
+use crate::util::auth::query_param;
+
+/// Incremental-scan toggle on the scan trigger form.
+pub const INCREMENTAL_SCAN: &str = "incremental_scan";
+/// Scan-to-scan report comparison view.
+pub const SCAN_DIFF: &str = "scan_diff";
+
+/// localStorage key holding client-side flag overrides as a JSON object.
+const OVERRIDES_KEY: &str = "swe_flag_overrides";
+
+/// Feature flags fetched from `GET /api/v1/flags`, plus client-side overrides
+/// set from the dev flags overlay.
+#[derive(Clone)]
+pub struct FlagsState {
+    /// Flags reported by the server; empty until the fetch completes.
+    pub server: Signal<BTreeMap<String, bool>>,
+    /// Local overrides (persisted in localStorage), taking precedence over the server.
+    pub overrides: Signal<BTreeMap<String, bool>>,
+}
+
+impl FlagsState {
+    /// Creates a state with no server flags and the persisted overrides.
+    pub fn new() -> Self {
+        Self {
+            server: signal(BTreeMap::new()),
+            overrides: signal(parse_flags(&local_storage_get(OVERRIDES_KEY).unwrap_or_default())),
+        }
+    }
+
+    /// Whether `name` is on. Unknown or not-yet-loaded flags are off.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        if let Some(value) = self.overrides.get().get(name) {
+            return *value;
+        }
+        self.server.get().get(name).copied().unwrap_or(false)
+    }
+
+    /// Every flag name known to the server or overridden locally.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.server.get().keys().cloned().collect();
+        for name in self.overrides.get().keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names.sort();
+        names
+    }
+}
+
+/// Hook to access feature flags from any component.
+pub fn use_flags() -> FlagsState {
+    use_context::<FlagsState>()
+}
+
+/// Fetch flags from the server. On failure all server flags stay off.
+pub fn load_flags(state: &FlagsState) {
+    let server = state.server.clone();
+    spawn(async move {
+        if let Ok(body) = api_get("/flags").await {
+            server.set(parse_flags(&body));
+        }
+    });
+}
+
+/// Set (`Some`) or clear (`None`) a client-side override and persist it.
+pub fn set_override(state: &FlagsState, name: &str, value: Option<bool>) {
+    let mut overrides = state.overrides.get().clone();
+    match value {
+        Some(v) => { overrides.insert(name.to_string(), v); }
+        None => { overrides.remove(name); }
+    }
+    local_storage_set(OVERRIDES_KEY, &json_stringify(&json!(overrides)));
+    state.overrides.set(overrides);
+}
+
+/// Clear every client-side override.
+pub fn clear_overrides(state: &FlagsState) {
+    local_storage_remove(OVERRIDES_KEY);
+    state.overrides.set(BTreeMap::new());
+}
+
+/// True when the page was opened with `?dev_flags=1`.
+pub fn is_dev_flags_view() -> bool {
+    query_param("dev_flags").as_deref() == Some("1")
+}
+
+/// Parse a `{"flag": bool}` object, ignoring non-boolean entries.
+fn parse_flags(body: &str) -> BTreeMap<String, bool> {
+    json_parse(body)
+        .and_then(|v| v.as_object().cloned())
+        .map(|obj| {
+            obj.iter()
+                .filter_map(|(k, v)| v.as_bool().map(|b| (k.clone(), b)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Hidden overlay for toggling client-side flag overrides during testing.
+/// Rendered only when the page is opened with `?dev_flags=1`.
+#[component]
+pub fn dev_flags_overlay(state: FlagsState) -> View {
+    let rows = state.names().into_iter().map(|name| {
+        let enabled = state.is_enabled(&name);
+        let overridden = state.overrides.get().contains_key(&name);
+        let testid = format!("dev-flag-{name}");
+        view! {
+            label(class="checkbox", data-testid=testid) {
+                input(
+                    type="checkbox",
+                    checked=enabled,
+                    on:change={
+                        let state = state.clone();
+                        let name = name.clone();
+                        move |_v: String| set_override(&state, &name, Some(!enabled))
+                    },
+                )
+                (name.clone())
+                (if overridden { " (override)" } else { "" })
+            }
+        }
+    }).collect::<Vec<_>>();
+
+    view! {
+        style {
+            .dev-flags {
+                position: fixed;
+                right: var(--space-4);
+                bottom: var(--space-4);
+                z-index: 1000;
+                display: flex;
+                flex-direction: column;
+                gap: var(--space-2);
+                padding: var(--space-3);
+                background: var(--color-surface);
+                border: 1px solid var(--color-border);
+                border-radius: var(--radius-md);
+                font-size: var(--font-size-sm);
+            }
+            .dev-flags__title { font-weight: 600; }
+        }
+        div(class="dev-flags", data-testid="dev-flags-overlay") {
+            div(class="dev-flags__title") { "Dev flags" }
+            (rows)
+            button(
+                class="btn btn--secondary btn--sm",
+                on:click={
+                    let state = state.clone();
+                    move || clear_overrides(&state)
+                },
+                data-testid="dev-flags-reset",
+            ) {
+                "Reset overrides"
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use rsc_compat::prelude::*;
+
+/// One category row of a compliance summary table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SummaryRow {
+    pub category: String,
+    pub passed: u32,
+    pub failed: u32,
+    pub skipped: u32,
+}
+
+/// Build per-category rows from an engine report's `results` array.
+/// Categories keep the order in which they first appear in the report.
+pub fn rows_from_report(report: &JsonValue) -> Vec<SummaryRow> {
+    let mut rows: Vec<SummaryRow> = Vec::new();
+    let results = report.get("results")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    for entry in results.iter() {
+        let category = entry.get("category").and_then(|v| v.as_str()).unwrap_or("uncategorized");
+        let status = entry.get("result")
+            .and_then(|r| r.get("status"))
+            .and_then(|s| s.as_str())
+            .unwrap_or("");
+
+        let idx = match rows.iter().position(|r| r.category == category) {
+            Some(idx) => idx,
+            None => {
+                rows.push(SummaryRow { category: category.into(), passed: 0, failed: 0, skipped: 0 });
+                rows.len() - 1
+            }
+        };
+        match status {
+            "pass" => rows[idx].passed += 1,
+            "fail" => rows[idx].failed += 1,
+            _ => rows[idx].skipped += 1,
+        }
+    }
+
+    rows
+}
+
+/// Pass percentage (0.0..100.0) from an engine report's `summary` block.
+pub fn coverage_from_report(report: &JsonValue) -> f64 {
+    let summary = match report.get("summary") {
+        Some(summary) => summary,
+        None => return 0.0,
+    };
+    let passed = summary.get("passed").and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let total = summary.get("total").and_then(|v| v.as_f64()).unwrap_or(0.0);
+    if total == 0.0 { 0.0 } else { passed / total * 100.0 }
+}
+
+/// Format a compliance summary as a GitHub-flavoured markdown table.
+///
+/// `coverage` is the pass percentage (0.0..100.0) shown under the table.
+pub fn summary_markdown(title: &str, rows: &[SummaryRow], coverage: f64) -> String {
+    let mut out = format!("### {}\n\n", escape_cell(title));
+    out.push_str("| Category | Passed | Failed | Skipped |\n");
+    out.push_str("|----------|-------:|-------:|--------:|\n");
+
+    let (mut passed, mut failed, mut skipped) = (0u32, 0u32, 0u32);
+    for row in rows {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            escape_cell(&row.category), row.passed, row.failed, row.skipped
+        ));
+        passed += row.passed;
+        failed += row.failed;
+        skipped += row.skipped;
+    }
+    out.push_str(&format!("| **Total** | {passed} | {failed} | {skipped} |\n\n"));
+    out.push_str(&format!("**Coverage:** {coverage:.1}%\n"));
+    out
+}
+
+/// Escape characters that would break a markdown table cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(category: &str, passed: u32, failed: u32, skipped: u32) -> SummaryRow {
+        SummaryRow { category: category.into(), passed, failed, skipped }
+    }
+
+    #[test]
+    fn test_summary_markdown_table() {
+        let md = summary_markdown("demo", &[row("structure", 5, 1, 0), row("naming", 2, 0, 1)], 77.8);
+        assert_eq!(
+            md,
+            "### demo\n\n\
+             | Category | Passed | Failed | Skipped |\n\
+             |----------|-------:|-------:|--------:|\n\
+             | structure | 5 | 1 | 0 |\n\
+             | naming | 2 | 0 | 1 |\n\
+             | **Total** | 7 | 1 | 1 |\n\n\
+             **Coverage:** 77.8%\n"
+        );
+    }
+
+    #[test]
+    fn test_summary_markdown_escapes_pipes() {
+        let md = summary_markdown("a|b", &[row("x|y", 1, 0, 0)], 100.0);
+        assert!(md.starts_with("### a\\|b\n"));
+        assert!(md.contains("| x\\|y | 1 | 0 | 0 |"));
+    }
+
+    #[test]
+    fn test_rows_from_report_groups_by_category() {
+        let report: JsonValue = serde_json::from_str(r#"{"results": [
+            {"category": "structure", "result": {"status": "pass"}},
+            {"category": "naming", "result": {"status": "fail", "violations": []}},
+            {"category": "structure", "result": {"status": "skip", "reason": "n/a"}}
+        ]}"#).unwrap();
+
+        assert_eq!(
+            rows_from_report(&report),
+            vec![row("structure", 1, 0, 1), row("naming", 0, 1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_coverage_from_report() {
+        let report: JsonValue = serde_json::from_str(r#"{"summary": {"total": 8, "passed": 6}}"#).unwrap();
+        assert_eq!(coverage_from_report(&report), 75.0);
+        assert_eq!(coverage_from_report(&JsonValue::Null), 0.0);
+    }
+}
@@ -1,2 +1,6 @@
 pub mod api;
 pub mod auth;
+pub mod clipboard;
+pub mod csv;
+pub mod flags;
+pub mod markdown;
@@ -31,6 +31,16 @@ async fn nav_links_present(ctx: BrowserTestContext) {
     ctx.assert_element_exists("[data-testid='nav-struct-engine']").await;
 }
 
+/// The mobile menu toggle controls the sidebar (NFR-302).
+#[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
+async fn nav_toggle_controls_sidebar(ctx: BrowserTestContext) {
+    setup_auth(&ctx).await;
+    ctx.navigate("/", Some("[data-testid='app-shell']")).await.unwrap();
+    ctx.assert_element_exists("[data-testid='topbar']").await;
+    ctx.assert_element_exists("[data-testid='nav-toggle'][aria-controls='app-sidebar']").await;
+    ctx.assert_element_exists("#app-sidebar[data-testid='sidebar']").await;
+}
+
 /// Default route must render the dashboard landing with its specific content.
 #[e2e(config = "test_config", route = "/", wait_for = "[data-testid='login-screen']")]
 async fn default_route_shows_dashboard(ctx: BrowserTestContext) {
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::db::{AlertRow, Db, ScanRow};
+use crate::routes::scans::{failed_check_ids, summary_coverage};
+
+/// How long a webhook receiver gets to accept a notification.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Webhook payload sent when a completed scan breaches a project's thresholds (FR-105).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThresholdBreach {
+    /// Always `threshold_breached`.
+    pub event: String,
+    pub project_id: String,
+    pub scan_id: String,
+    pub engine: String,
+    pub summary: serde_json::Value,
+    /// Percentage of applicable (non-skipped) checks that passed.
+    pub coverage: Option<f64>,
+    /// Scan the new failures were measured against.
+    pub previous_scan_id: Option<String>,
+    /// Why the alert fired, one entry per breached threshold.
+    pub reasons: Vec<String>,
+}
+
+/// Compare a completed scan against the project's thresholds.
+///
+/// `previous` is the completed scan of the same engine before `scan`, used for
+/// `on_new_failures`. Returns `None` when no threshold is breached or the scan
+/// has no readable report.
+pub fn threshold_breach(
+    alert: &AlertRow,
+    scan: &ScanRow,
+    previous: Option<&ScanRow>,
+) -> Option<ThresholdBreach> {
+    let parse = |row: &ScanRow| {
+        row.report_json
+            .as_deref()
+            .and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok())
+    };
+    let report = parse(scan)?;
+    let summary = report.get("summary")?.clone();
+    let failed = summary.get("failed").and_then(|v| v.as_u64()).unwrap_or(0);
+    let coverage = summary_coverage(&summary);
+
+    let mut reasons = Vec::new();
+    if let Some(max) = alert.max_failures {
+        if failed > u64::from(max) {
+            reasons.push(format!("{failed} failed checks exceed the limit of {max}"));
+        }
+    }
+    if let (Some(min), Some(pct)) = (alert.min_coverage, coverage) {
+        if pct < min {
+            reasons.push(format!("coverage {pct:.1}% is below the minimum of {min}%"));
+        }
+    }
+    if alert.on_new_failures {
+        if let Some(previous_report) = previous.and_then(parse) {
+            let before = failed_check_ids(&previous_report);
+            let new: Vec<String> = failed_check_ids(&report)
+                .into_iter()
+                .filter(|id| !before.contains(id))
+                .map(|id| id.to_string())
+                .collect();
+            if !new.is_empty() {
+                reasons.push(format!("newly failing checks: {}", new.join(", ")));
+            }
+        }
+    }
+
+    if reasons.is_empty() {
+        return None;
+    }
+    Some(ThresholdBreach {
+        event: "threshold_breached".into(),
+        project_id: scan.project_id.clone(),
+        scan_id: scan.id.clone(),
+        engine: scan.engine.clone(),
+        summary,
+        coverage,
+        previous_scan_id: previous.map(|p| p.id.clone()),
+        reasons,
+    })
+}
+
+/// POST a JSON notification to a webhook URL.
+pub async fn post_webhook(url: &str, payload: &impl Serialize) -> Result<(), String> {
+    let response = reqwest::Client::new()
+        .post(url)
+        .timeout(WEBHOOK_TIMEOUT)
+        .header(reqwest::header::USER_AGENT, concat!("swe-compliance-server/", env!("CARGO_PKG_VERSION")))
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| format!("request to {url} failed: {e}"))?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("webhook returned {}", response.status()))
+    }
+}
+
+/// Payload for `POST /api/v1/projects/{id}/alerts/test`, so receivers can be
+/// checked without waiting for a breach.
+pub fn test_notification(project_id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "event": "test",
+        "project_id": project_id,
+        "reasons": ["test notification from swe-compliance"],
+    })
+}
+
+/// Fire the project's threshold webhook if the finished scan breaches it.
+///
+/// Best effort: lookup and delivery failures are logged, never returned.
+pub async fn notify_threshold_breach(db: &Db, scan_id: &str) {
+    let scan = match db.get_scan(scan_id) {
+        Ok(scan) => scan,
+        Err(e) => {
+            tracing::warn!("threshold check skipped for scan {scan_id}: {e}");
+            return;
+        }
+    };
+    let alert = match db.get_alerts(&scan.project_id) {
+        Ok(Some(alert)) => alert,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::warn!("threshold check skipped for scan {scan_id}: {e}");
+            return;
+        }
+    };
+    let previous = if alert.on_new_failures {
+        db.previous_completed_scan(&scan).ok().flatten()
+    } else {
+        None
+    };
+
+    if let Some(breach) = threshold_breach(&alert, &scan, previous.as_ref()) {
+        match post_webhook(&alert.webhook_url, &breach).await {
+            Ok(()) => tracing::info!("sent threshold alert for scan {scan_id}"),
+            Err(e) => tracing::warn!("failed to send threshold alert for scan {scan_id}: {e}"),
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use axum::extract::{FromRequestParts, Query};
 use axum::http::request::Parts;
-use axum::http::HeaderMap;
+use axum::http::{HeaderMap, Method};
 use chrono::{Duration, Utc};
+use jsonwebtoken::errors::ErrorKind;
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 
@@ -15,10 +16,13 @@ pub struct Claims {
     pub iat: usize,
 }
 
-/// Issue a JWT token for a given username.
-pub fn issue_token(secret: &str, username: &str) -> Result<String, AppError> {
+/// Token lifetime used when `SWE_TOKEN_TTL_SECS` is unset (24 hours).
+pub const DEFAULT_TOKEN_TTL_SECS: u64 = 86_400;
+
+/// Issue a JWT token for a given username, expiring `ttl_secs` from now.
+pub fn issue_token(secret: &str, username: &str, ttl_secs: u64) -> Result<String, AppError> {
     let now = Utc::now();
-    let exp = now + Duration::hours(24);
+    let exp = now + Duration::seconds(ttl_secs as i64);
     let claims = Claims {
         sub: username.to_string(),
         iat: now.timestamp() as usize,
@@ -33,20 +37,30 @@ pub fn issue_token(secret: &str, username: &str) -> Result<String, AppError> {
 }
 
 /// Validate a JWT token and return the claims.
+///
+/// The signature and `exp` claim are both checked; tokens are issued and
+/// validated by the same server, so no clock-skew leeway is allowed.
 pub fn validate_token(secret: &str, token: &str) -> Result<Claims, AppError> {
+    let mut validation = Validation::default();
+    validation.leeway = 0;
     decode::<Claims>(
         token,
         &DecodingKey::from_secret(secret.as_bytes()),
-        &Validation::default(),
+        &validation,
     )
     .map(|data| data.claims)
-    .map_err(|e| AppError::Unauthorized(format!("invalid token: {e}")))
+    .map_err(|e| match e.kind() {
+        ErrorKind::ExpiredSignature => AppError::Unauthorized("token expired".into()),
+        _ => AppError::Unauthorized(format!("invalid token: {e}")),
+    })
 }
 
 /// Authenticated user extracted from request.
 #[derive(Debug, Clone)]
 pub struct AuthUser {
     pub username: String,
+    /// True when the caller has no token and was admitted via public-read mode.
+    pub anonymous: bool,
 }
 
 /// Query parameter for WebSocket JWT authentication.
@@ -69,6 +83,7 @@ impl<S: Send + Sync> FromRequestParts<S> for AuthUser {
             let claims = validate_token(&secret.0, &token)?;
             return Ok(AuthUser {
                 username: claims.sub,
+                anonymous: false,
             });
         }
 
@@ -80,6 +95,19 @@ impl<S: Send + Sync> FromRequestParts<S> for AuthUser {
             let claims = validate_token(&secret.0, &token)?;
             return Ok(AuthUser {
                 username: claims.sub,
+                anonymous: false,
+            });
+        }
+
+        // Anonymous read-only access for public dashboards
+        let public_read = parts
+            .extensions
+            .get::<PublicRead>()
+            .is_some_and(|p| p.0);
+        if public_read && is_public_read_route(&parts.method, parts.uri.path()) {
+            return Ok(AuthUser {
+                username: "anonymous".into(),
+                anonymous: true,
             });
         }
 
@@ -99,3 +127,45 @@ fn extract_bearer_token(headers: &HeaderMap) -> Option<String> {
 /// Wrapper for JWT secret stored in request extensions.
 #[derive(Debug, Clone)]
 pub struct JwtSecret(pub String);
+
+/// Whether unauthenticated read-only access is enabled, stored in request extensions.
+#[derive(Debug, Clone, Copy)]
+pub struct PublicRead(pub bool);
+
+/// Route patterns served to anonymous callers when public-read mode is enabled.
+/// Mutations, AI, editor, templates and WebSocket routes always require a token.
+const PUBLIC_READ_ROUTES: &[&str] = &[
+    "/api/v1/projects",
+    "/api/v1/projects/{id}",
+    "/api/v1/projects/{id}/scans",
+    "/api/v1/projects/{id}/trends",
+    "/api/v1/fleet",
+    "/api/v1/scans/{id}",
+    "/api/v1/scans/{id}/report",
+    "/api/v1/scans/{id}/diff",
+    "/api/v1/scans/{id}/audit-report",
+    "/api/v1/scans/{id}/violations",
+    "/api/v1/flags",
+];
+
+/// Classify a request as part of the public read-only route subset.
+pub fn is_public_read_route(method: &Method, path: &str) -> bool {
+    if method != Method::GET {
+        return false;
+    }
+    let path = path.trim_end_matches('/');
+    PUBLIC_READ_ROUTES
+        .iter()
+        .any(|pattern| route_matches(pattern, path))
+}
+
+/// Match a path against a route pattern where `{param}` matches one segment.
+fn route_matches(pattern: &str, path: &str) -> bool {
+    let pattern_segments: Vec<&str> = pattern.split('/').collect();
+    let path_segments: Vec<&str> = path.split('/').collect();
+    pattern_segments.len() == path_segments.len()
+        && pattern_segments
+            .iter()
+            .zip(&path_segments)
+            .all(|(p, s)| (p.starts_with('{') && !s.is_empty()) || p == s)
+}
@@ -1,17 +1,52 @@
 use std::path::PathBuf;
 
+use crate::auth::DEFAULT_TOKEN_TTL_SECS;
+
 /// Server configuration loaded from environment variables.
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub jwt_secret: String,
+    /// Lifetime of issued JWTs in seconds; clients refresh before it lapses.
+    pub token_ttl_secs: u64,
     pub db_path: PathBuf,
     pub cors_origins: Vec<String>,
     pub rate_limit_per_min: u32,
     pub max_concurrent_scans: usize,
     pub template_dir: Option<PathBuf>,
     pub ai_enabled: bool,
+    /// Allow unauthenticated GETs on the read-only dashboard routes.
+    pub public_read: bool,
+    /// GitHub commit status reporting; `None` when no token is configured.
+    pub github: Option<GitHubConfig>,
+    /// JSON file of feature flags (`{"flag": true}`); `None` means all flags off.
+    pub flags_path: Option<PathBuf>,
+    /// Usernames allowed to call admin endpoints such as flag reload.
+    pub admin_users: Vec<String>,
+}
+
+/// GitHub API credentials for posting commit statuses.
+#[derive(Debug, Clone)]
+pub struct GitHubConfig {
+    /// Personal access token or GitHub App installation token.
+    pub token: String,
+    /// API base URL; override for GitHub Enterprise.
+    pub api_url: String,
+    /// Public dashboard URL used to link statuses to scan reports.
+    pub public_url: Option<String>,
+}
+
+impl GitHubConfig {
+    pub fn from_env() -> Option<Self> {
+        let token = std::env::var("SWE_GITHUB_TOKEN").ok().filter(|t| !t.is_empty())?;
+        Some(Self {
+            token,
+            api_url: std::env::var("SWE_GITHUB_API_URL")
+                .unwrap_or_else(|_| "https://api.github.com".into()),
+            public_url: std::env::var("SWE_PUBLIC_URL").ok(),
+        })
+    }
 }
 
 impl ServerConfig {
@@ -24,6 +59,11 @@ impl ServerConfig {
                 .unwrap_or(8081),
             jwt_secret: std::env::var("SWE_JWT_SECRET")
                 .unwrap_or_else(|_| "dev-secret-change-in-production".into()),
+            token_ttl_secs: std::env::var("SWE_TOKEN_TTL_SECS")
+                .ok()
+                .and_then(|t| t.parse().ok())
+                .filter(|t| *t > 0)
+                .unwrap_or(DEFAULT_TOKEN_TTL_SECS),
             db_path: std::env::var("SWE_DB_PATH")
                 .map(PathBuf::from)
                 .unwrap_or_else(|_| PathBuf::from("swe-compliance.db")),
@@ -42,6 +82,19 @@ impl ServerConfig {
             ai_enabled: std::env::var("DOC_ENGINE_AI_ENABLED")
                 .map(|v| v != "0" && v.to_lowercase() != "false")
                 .unwrap_or(false),
+            public_read: std::env::var("SWE_PUBLIC_READ")
+                .map(|v| v != "0" && v.to_lowercase() != "false")
+                .unwrap_or(false),
+            github: GitHubConfig::from_env(),
+            flags_path: std::env::var("SWE_FLAGS_PATH").ok().map(PathBuf::from),
+            admin_users: std::env::var("SWE_ADMIN_USERS")
+                .map(|s| {
+                    s.split(',')
+                        .map(|u| u.trim().to_string())
+                        .filter(|u| !u.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
         }
     }
 }
@@ -26,6 +26,8 @@ pub struct ProjectRow {
     pub updated_at: String,
     pub deleted: bool,
     pub last_scan_id: Option<String>,
+    /// Group labels used to scope fleet views, sorted.
+    pub tags: Vec<String>,
 }
 
 /// Scan record stored in SQLite.
@@ -41,6 +43,59 @@ pub struct ScanRow {
     pub config_json: Option<String>,
 }
 
+/// Threshold alert settings for a project (FR-105).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRow {
+    pub project_id: String,
+    /// URL the breach notification is POSTed to.
+    pub webhook_url: String,
+    /// Alert when a scan has more failed checks than this.
+    pub max_failures: Option<u32>,
+    /// Alert when coverage falls below this percentage.
+    pub min_coverage: Option<f64>,
+    /// Alert when a check fails that did not fail in the previous scan.
+    pub on_new_failures: bool,
+    pub updated_at: String,
+}
+
+/// Scan event subscription for a project (FR-106).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookRow {
+    pub id: String,
+    pub project_id: String,
+    /// URL the scan event is POSTed to.
+    pub url: String,
+    /// Events this webhook receives, e.g. `scan_completed`.
+    pub events: Vec<String>,
+    pub created_at: String,
+}
+
+/// One webhook notification and its delivery state (FR-106).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryRow {
+    pub id: String,
+    pub webhook_id: String,
+    pub scan_id: String,
+    pub event: String,
+    pub payload: String,
+    /// `pending`, `delivered` or `failed` (retries exhausted).
+    pub status: String,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+}
+
+/// One persisted turn of an AI chat session (FR-800).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessageRow {
+    pub session_id: String,
+    /// `user` or `assistant`.
+    pub role: String,
+    pub content: String,
+    pub created_at: String,
+}
+
 /// SRS content record.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SrsRow {
@@ -84,11 +139,66 @@ impl Db {
 
             CREATE INDEX IF NOT EXISTS idx_scans_project ON scans(project_id, started_at DESC);
 
+            CREATE TABLE IF NOT EXISTS project_tags (
+                project_id TEXT NOT NULL REFERENCES projects(id),
+                tag TEXT NOT NULL,
+                PRIMARY KEY (project_id, tag)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_project_tags_tag ON project_tags(tag);
+
+            CREATE TABLE IF NOT EXISTS project_alerts (
+                project_id TEXT PRIMARY KEY REFERENCES projects(id),
+                webhook_url TEXT NOT NULL,
+                max_failures INTEGER,
+                min_coverage REAL,
+                on_new_failures INTEGER NOT NULL DEFAULT 0,
+                updated_at TEXT NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS webhooks (
+                id TEXT PRIMARY KEY,
+                project_id TEXT NOT NULL REFERENCES projects(id),
+                url TEXT NOT NULL,
+                events TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_webhooks_project ON webhooks(project_id);
+
+            CREATE TABLE IF NOT EXISTS webhook_deliveries (
+                id TEXT PRIMARY KEY,
+                webhook_id TEXT NOT NULL REFERENCES webhooks(id),
+                scan_id TEXT NOT NULL REFERENCES scans(id),
+                event TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'pending',
+                attempts INTEGER NOT NULL DEFAULT 0,
+                last_error TEXT,
+                created_at TEXT NOT NULL,
+                delivered_at TEXT
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_webhook
+                ON webhook_deliveries(webhook_id, created_at);
+
             CREATE TABLE IF NOT EXISTS srs_content (
                 project_id TEXT PRIMARY KEY REFERENCES projects(id),
                 content TEXT NOT NULL,
                 updated_at TEXT NOT NULL
-            );",
+            );
+
+            CREATE TABLE IF NOT EXISTS chat_messages (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                username TEXT NOT NULL,
+                session_id TEXT NOT NULL,
+                role TEXT NOT NULL,
+                content TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_chat_messages_session
+                ON chat_messages(username, session_id, id);",
         )
         .map_err(|e| AppError::Internal(format!("migration failed: {e}")))?;
 
@@ -126,18 +236,22 @@ impl Db {
             updated_at: now,
             deleted: false,
             last_scan_id: None,
+            tags: Vec::new(),
         })
     }
 
-    pub fn list_projects(&self) -> Result<Vec<ProjectRow>, AppError> {
+    /// One page of live projects, newest first.
+    pub fn list_projects(&self, limit: u32, offset: u64) -> Result<Vec<ProjectRow>, AppError> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, name, root_path, scope, project_type, created_at, updated_at, deleted, last_scan_id
-             FROM projects WHERE deleted = 0 ORDER BY created_at DESC",
+            "SELECT id, name, root_path, scope, project_type, created_at, updated_at, deleted, last_scan_id,
+                    (SELECT json_group_array(tag) FROM project_tags WHERE project_id = projects.id)
+             FROM projects WHERE deleted = 0 ORDER BY created_at DESC
+             LIMIT ?1 OFFSET ?2",
         )?;
 
         let rows = stmt
-            .query_map([], |row| {
+            .query_map(params![limit as i64, offset as i64], |row| {
                 Ok(ProjectRow {
                     id: row.get(0)?,
                     name: row.get(1)?,
@@ -148,6 +262,7 @@ impl Db {
                     updated_at: row.get(6)?,
                     deleted: row.get(7)?,
                     last_scan_id: row.get(8)?,
+                    tags: tags_from_json(row.get(9)?),
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -155,10 +270,18 @@ impl Db {
         Ok(rows)
     }
 
+    pub fn count_projects(&self) -> Result<u64, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM projects WHERE deleted = 0", [], |row| row.get(0))?;
+        Ok(count as u64)
+    }
+
     pub fn get_project(&self, id: &str) -> Result<ProjectRow, AppError> {
         let conn = self.conn.lock().unwrap();
         conn.query_row(
-            "SELECT id, name, root_path, scope, project_type, created_at, updated_at, deleted, last_scan_id
+            "SELECT id, name, root_path, scope, project_type, created_at, updated_at, deleted, last_scan_id,
+                    (SELECT json_group_array(tag) FROM project_tags WHERE project_id = projects.id)
              FROM projects WHERE id = ?1 AND deleted = 0",
             params![id],
             |row| {
@@ -172,6 +295,7 @@ impl Db {
                     updated_at: row.get(6)?,
                     deleted: row.get(7)?,
                     last_scan_id: row.get(8)?,
+                    tags: tags_from_json(row.get(9)?),
                 })
             },
         )
@@ -224,6 +348,21 @@ impl Db {
         Ok(())
     }
 
+    /// Replace a project's tags with `tags`.
+    pub fn set_project_tags(&self, id: &str, tags: &[String]) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM project_tags WHERE project_id = ?1", params![id])?;
+        for tag in tags {
+            tx.execute(
+                "INSERT OR IGNORE INTO project_tags (project_id, tag) VALUES (?1, ?2)",
+                params![id, tag],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
     // ── Scans ──
 
     pub fn create_scan(
@@ -300,15 +439,101 @@ impl Db {
         .map_err(|_| AppError::NotFound(format!("scan {scan_id} not found")))
     }
 
-    pub fn list_scans_for_project(&self, project_id: &str) -> Result<Vec<ScanRow>, AppError> {
+    /// One page of a project's scans, newest first.
+    pub fn list_scans_for_project(
+        &self,
+        project_id: &str,
+        limit: u32,
+        offset: u64,
+    ) -> Result<Vec<ScanRow>, AppError> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
             "SELECT id, project_id, engine, status, started_at, finished_at, report_json, config_json
-             FROM scans WHERE project_id = ?1 ORDER BY started_at DESC",
+             FROM scans WHERE project_id = ?1 ORDER BY started_at DESC
+             LIMIT ?2 OFFSET ?3",
         )?;
 
         let rows = stmt
-            .query_map(params![project_id], |row| {
+            .query_map(params![project_id, limit as i64, offset as i64], |row| {
+                Ok(ScanRow {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    engine: row.get(2)?,
+                    status: row.get(3)?,
+                    started_at: row.get(4)?,
+                    finished_at: row.get(5)?,
+                    report_json: row.get(6)?,
+                    config_json: row.get(7)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(rows)
+    }
+
+    pub fn count_scans_for_project(&self, project_id: &str) -> Result<u64, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM scans WHERE project_id = ?1",
+            params![project_id],
+            |row| row.get(0),
+        )?;
+        Ok(count as u64)
+    }
+
+    /// The completed scan of the same project and engine that ran before `scan`.
+    pub fn previous_completed_scan(&self, scan: &ScanRow) -> Result<Option<ScanRow>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT id, project_id, engine, status, started_at, finished_at, report_json, config_json
+             FROM scans
+             WHERE project_id = ?1 AND engine = ?2 AND status = 'completed'
+               AND (started_at < ?3 OR (started_at = ?3 AND id < ?4))
+             ORDER BY started_at DESC, id DESC
+             LIMIT 1",
+            params![scan.project_id, scan.engine, scan.started_at, scan.id],
+            |row| {
+                Ok(ScanRow {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    engine: row.get(2)?,
+                    status: row.get(3)?,
+                    started_at: row.get(4)?,
+                    finished_at: row.get(5)?,
+                    report_json: row.get(6)?,
+                    config_json: row.get(7)?,
+                })
+            },
+        );
+
+        match result {
+            Ok(row) => Ok(Some(row)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(AppError::Internal(format!("db error: {e}"))),
+        }
+    }
+
+    /// One page of a project's completed scans, oldest first, starting after
+    /// the `(started_at, id)` cursor of the previous page (FR-308).
+    pub fn list_completed_scans_page(
+        &self,
+        project_id: &str,
+        after: Option<(&str, &str)>,
+        limit: usize,
+    ) -> Result<Vec<ScanRow>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let (after_ts, after_id) = after.unwrap_or(("", ""));
+        let mut stmt = conn.prepare(
+            "SELECT id, project_id, engine, status, started_at, finished_at, report_json, config_json
+             FROM scans
+             WHERE project_id = ?1 AND status = 'completed'
+               AND (started_at > ?2 OR (started_at = ?2 AND id > ?3))
+             ORDER BY started_at ASC, id ASC
+             LIMIT ?4",
+        )?;
+
+        let rows = stmt
+            .query_map(params![project_id, after_ts, after_id, limit as i64], |row| {
                 Ok(ScanRow {
                     id: row.get(0)?,
                     project_id: row.get(1)?,
@@ -370,6 +595,303 @@ impl Db {
         Ok(trends)
     }
 
+    /// Latest completed scan of every visible project plus the summaries of
+    /// its last `history` completed scans, in one query, optionally scoped to
+    /// one engine and/or one tag (FR-204).
+    pub fn fleet(
+        &self,
+        engine: Option<&str>,
+        tag: Option<&str>,
+        history: usize,
+    ) -> Result<Vec<FleetRow>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "WITH ranked AS (
+                SELECT id, project_id, engine, started_at, report_json,
+                       ROW_NUMBER() OVER (
+                           PARTITION BY project_id ORDER BY started_at DESC, id DESC
+                       ) AS rn
+                FROM scans
+                WHERE status = 'completed' AND (?1 IS NULL OR engine = ?1)
+             )
+             SELECT p.id, p.name,
+                    (SELECT json_group_array(tag) FROM project_tags WHERE project_id = p.id),
+                    latest.id, latest.engine, latest.started_at, latest.report_json,
+                    (SELECT json_group_array(
+                                json_extract(
+                                    CASE WHEN json_valid(r.report_json) THEN r.report_json END,
+                                    '$.summary'
+                                ) ORDER BY r.rn DESC)
+                     FROM ranked r WHERE r.project_id = p.id AND r.rn <= ?3)
+             FROM projects p
+             LEFT JOIN ranked latest ON latest.project_id = p.id AND latest.rn = 1
+             WHERE p.deleted = 0
+               AND (?2 IS NULL OR EXISTS (
+                   SELECT 1 FROM project_tags t WHERE t.project_id = p.id AND t.tag = ?2
+               ))",
+        )?;
+
+        let rows = stmt
+            .query_map(params![engine, tag, history as i64], |row| {
+                Ok(FleetRow {
+                    project_id: row.get(0)?,
+                    name: row.get(1)?,
+                    tags: tags_from_json(row.get(2)?),
+                    scan_id: row.get(3)?,
+                    engine: row.get(4)?,
+                    started_at: row.get(5)?,
+                    report_json: row.get(6)?,
+                    history: row
+                        .get::<_, Option<String>>(7)?
+                        .and_then(|j| serde_json::from_str(&j).ok())
+                        .unwrap_or_default(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(rows)
+    }
+
+    // ── Alerts ──
+
+    pub fn get_alerts(&self, project_id: &str) -> Result<Option<AlertRow>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT project_id, webhook_url, max_failures, min_coverage, on_new_failures, updated_at
+             FROM project_alerts WHERE project_id = ?1",
+            params![project_id],
+            |row| {
+                Ok(AlertRow {
+                    project_id: row.get(0)?,
+                    webhook_url: row.get(1)?,
+                    max_failures: row.get(2)?,
+                    min_coverage: row.get(3)?,
+                    on_new_failures: row.get(4)?,
+                    updated_at: row.get(5)?,
+                })
+            },
+        );
+
+        match result {
+            Ok(row) => Ok(Some(row)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(AppError::Internal(format!("db error: {e}"))),
+        }
+    }
+
+    pub fn save_alerts(
+        &self,
+        project_id: &str,
+        webhook_url: &str,
+        max_failures: Option<u32>,
+        min_coverage: Option<f64>,
+        on_new_failures: bool,
+    ) -> Result<AlertRow, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO project_alerts
+                 (project_id, webhook_url, max_failures, min_coverage, on_new_failures, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(project_id) DO UPDATE SET
+                 webhook_url = ?2, max_failures = ?3, min_coverage = ?4,
+                 on_new_failures = ?5, updated_at = ?6",
+            params![project_id, webhook_url, max_failures, min_coverage, on_new_failures, now],
+        )?;
+
+        Ok(AlertRow {
+            project_id: project_id.into(),
+            webhook_url: webhook_url.into(),
+            max_failures,
+            min_coverage,
+            on_new_failures,
+            updated_at: now,
+        })
+    }
+
+    pub fn delete_alerts(&self, project_id: &str) -> Result<(), AppError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "DELETE FROM project_alerts WHERE project_id = ?1",
+            params![project_id],
+        )?;
+        Ok(())
+    }
+
+    // ── Webhooks ──
+
+    pub fn create_webhook(
+        &self,
+        project_id: &str,
+        url: &str,
+        events: &[String],
+    ) -> Result<WebhookRow, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO webhooks (id, project_id, url, events, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![id, project_id, url, events.join(","), now],
+        )?;
+
+        Ok(WebhookRow {
+            id,
+            project_id: project_id.into(),
+            url: url.into(),
+            events: events.to_vec(),
+            created_at: now,
+        })
+    }
+
+    /// Webhooks on a project subscribed to `event`, oldest first.
+    pub fn webhooks_for_event(
+        &self,
+        project_id: &str,
+        event: &str,
+    ) -> Result<Vec<WebhookRow>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, project_id, url, events, created_at
+             FROM webhooks WHERE project_id = ?1 ORDER BY created_at",
+        )?;
+
+        let rows = stmt
+            .query_map(params![project_id], |row| {
+                let events: String = row.get(3)?;
+                Ok(WebhookRow {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    url: row.get(2)?,
+                    events: events.split(',').map(str::to_string).collect(),
+                    created_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(rows
+            .into_iter()
+            .filter(|hook| hook.events.iter().any(|e| e == event))
+            .collect())
+    }
+
+    /// Record a notification before it is sent, so it survives a failed attempt.
+    pub fn create_webhook_delivery(
+        &self,
+        webhook_id: &str,
+        scan_id: &str,
+        event: &str,
+        payload: &str,
+    ) -> Result<WebhookDeliveryRow, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO webhook_deliveries (id, webhook_id, scan_id, event, payload, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![id, webhook_id, scan_id, event, payload, now],
+        )?;
+
+        Ok(WebhookDeliveryRow {
+            id,
+            webhook_id: webhook_id.into(),
+            scan_id: scan_id.into(),
+            event: event.into(),
+            payload: payload.into(),
+            status: "pending".into(),
+            attempts: 0,
+            last_error: None,
+            created_at: now,
+            delivered_at: None,
+        })
+    }
+
+    /// Record one delivery attempt. `error` is `None` on success; `status`
+    /// is the delivery's state after the attempt.
+    pub fn record_webhook_attempt(
+        &self,
+        delivery_id: &str,
+        status: &str,
+        error: Option<&str>,
+    ) -> Result<(), AppError> {
+        let conn = self.conn.lock().unwrap();
+        let delivered_at = (status == "delivered").then(|| Utc::now().to_rfc3339());
+        conn.execute(
+            "UPDATE webhook_deliveries
+             SET status = ?2, attempts = attempts + 1, last_error = ?3, delivered_at = ?4
+             WHERE id = ?1",
+            params![delivery_id, status, error, delivered_at],
+        )?;
+        Ok(())
+    }
+
+    /// Deliveries still `pending`, oldest first, each with its webhook's URL.
+    pub fn pending_webhook_deliveries(&self) -> Result<Vec<(String, WebhookDeliveryRow)>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT w.url, d.id, d.webhook_id, d.scan_id, d.event, d.payload, d.status, d.attempts,
+                    d.last_error, d.created_at, d.delivered_at
+             FROM webhook_deliveries d JOIN webhooks w ON w.id = d.webhook_id
+             WHERE d.status = 'pending' ORDER BY d.created_at",
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    WebhookDeliveryRow {
+                        id: row.get(1)?,
+                        webhook_id: row.get(2)?,
+                        scan_id: row.get(3)?,
+                        event: row.get(4)?,
+                        payload: row.get(5)?,
+                        status: row.get(6)?,
+                        attempts: row.get(7)?,
+                        last_error: row.get(8)?,
+                        created_at: row.get(9)?,
+                        delivered_at: row.get(10)?,
+                    },
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(rows)
+    }
+
+    pub fn list_webhook_deliveries(
+        &self,
+        webhook_id: &str,
+    ) -> Result<Vec<WebhookDeliveryRow>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, webhook_id, scan_id, event, payload, status, attempts, last_error,
+                    created_at, delivered_at
+             FROM webhook_deliveries WHERE webhook_id = ?1 ORDER BY created_at",
+        )?;
+
+        let rows = stmt
+            .query_map(params![webhook_id], |row| {
+                Ok(WebhookDeliveryRow {
+                    id: row.get(0)?,
+                    webhook_id: row.get(1)?,
+                    scan_id: row.get(2)?,
+                    event: row.get(3)?,
+                    payload: row.get(4)?,
+                    status: row.get(5)?,
+                    attempts: row.get(6)?,
+                    last_error: row.get(7)?,
+                    created_at: row.get(8)?,
+                    delivered_at: row.get(9)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(rows)
+    }
+
     // ── SRS Content ──
 
     pub fn get_srs(&self, project_id: &str) -> Result<Option<SrsRow>, AppError> {
@@ -409,6 +931,63 @@ impl Db {
             updated_at: now,
         })
     }
+
+    // ── Chat Sessions ──
+
+    /// Append one turn to `username`'s chat session.
+    pub fn append_chat_message(
+        &self,
+        username: &str,
+        session_id: &str,
+        role: &str,
+        content: &str,
+    ) -> Result<ChatMessageRow, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO chat_messages (username, session_id, role, content, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![username, session_id, role, content, now],
+        )?;
+
+        Ok(ChatMessageRow {
+            session_id: session_id.into(),
+            role: role.into(),
+            content: content.into(),
+            created_at: now,
+        })
+    }
+
+    /// The most recent `limit` turns of `username`'s chat session, oldest first.
+    /// Sessions are private: another user's session id yields no messages.
+    pub fn recent_chat_messages(
+        &self,
+        username: &str,
+        session_id: &str,
+        limit: u32,
+    ) -> Result<Vec<ChatMessageRow>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT session_id, role, content, created_at FROM chat_messages
+             WHERE username = ?1 AND session_id = ?2
+             ORDER BY id DESC LIMIT ?3",
+        )?;
+
+        let mut rows = stmt
+            .query_map(params![username, session_id, limit], |row| {
+                Ok(ChatMessageRow {
+                    session_id: row.get(0)?,
+                    role: row.get(1)?,
+                    content: row.get(2)?,
+                    created_at: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.reverse();
+        Ok(rows)
+    }
 }
 
 /// A single data point for trend charts.
@@ -420,3 +999,27 @@ pub struct TrendPoint {
     pub failed: u32,
     pub skipped: u32,
 }
+
+/// One project's latest completed scan and recent history, as read for the fleet view.
+#[derive(Debug, Clone)]
+pub struct FleetRow {
+    pub project_id: String,
+    pub name: String,
+    pub tags: Vec<String>,
+    /// `None` when the project has no completed scan (for the requested engine).
+    pub scan_id: Option<String>,
+    pub engine: Option<String>,
+    pub started_at: Option<String>,
+    pub report_json: Option<String>,
+    /// Report summaries of the most recent completed scans, oldest first.
+    pub history: Vec<serde_json::Value>,
+}
+
+/// Decode a `json_group_array(tag)` column into sorted tags.
+fn tags_from_json(json: Option<String>) -> Vec<String> {
+    let mut tags: Vec<String> = json
+        .and_then(|j| serde_json::from_str(&j).ok())
+        .unwrap_or_default();
+    tags.sort();
+    tags
+}
@@ -1,3 +1,4 @@
+use axum::extract::rejection::JsonRejection;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Serialize;
@@ -12,10 +13,34 @@ pub struct ErrorBody {
 pub struct ErrorDetail {
     pub code: String,
     pub message: String,
+    /// Per-field validation failures; empty for errors not tied to a field.
+    pub fields: Vec<FieldError>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
 }
 
+/// A validation failure on one request field, for inline display in forms.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { field: field.into(), message: message.into() }
+    }
+}
+
+/// Fail with every collected field error at once, so a form can show them all.
+pub fn check_fields(errors: Vec<FieldError>) -> Result<(), AppError> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::Validation(errors))
+    }
+}
+
 /// Application error type mapping to HTTP responses.
 #[derive(Debug, thiserror::Error)]
 pub enum AppError {
@@ -25,6 +50,12 @@ pub enum AppError {
     #[error("bad request: {0}")]
     BadRequest(String),
 
+    #[error("validation failed: {0:?}")]
+    Validation(Vec<FieldError>),
+
+    #[error("invalid request body: {0}")]
+    InvalidBody(#[from] JsonRejection),
+
     #[error("unauthorized: {0}")]
     Unauthorized(String),
 
@@ -35,7 +66,7 @@ pub enum AppError {
     Conflict(String),
 
     #[error("rate limited")]
-    RateLimited,
+    RateLimited { limit_per_min: u32, retry_after_secs: u64 },
 
     #[error("service unavailable: {0}")]
     ServiceUnavailable(String),
@@ -52,17 +83,38 @@ pub enum AppError {
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        let mut fields = Vec::new();
+        let mut retry_after = None;
         let (status, code, message) = match &self {
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg.clone()),
             AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, "BAD_REQUEST", msg.clone()),
+            AppError::Validation(errors) => {
+                fields = errors.clone();
+                let summary = errors
+                    .iter()
+                    .map(|e| e.message.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                (StatusCode::BAD_REQUEST, "VALIDATION_ERROR", summary)
+            }
+            AppError::InvalidBody(rejection) => {
+                let message = rejection.body_text();
+                if let Some(field) = missing_field(&message) {
+                    fields.push(FieldError::new(field, "is required"));
+                }
+                (rejection.status(), "INVALID_BODY", message)
+            }
             AppError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED", msg.clone()),
             AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, "FORBIDDEN", msg.clone()),
             AppError::Conflict(msg) => (StatusCode::CONFLICT, "CONFLICT", msg.clone()),
-            AppError::RateLimited => (
-                StatusCode::TOO_MANY_REQUESTS,
-                "RATE_LIMITED",
-                "rate limit exceeded — max 100 requests per minute".into(),
-            ),
+            AppError::RateLimited { limit_per_min, retry_after_secs } => {
+                retry_after = Some(*retry_after_secs);
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    "RATE_LIMITED",
+                    format!("rate limit exceeded — max {limit_per_min} requests per minute"),
+                )
+            }
             AppError::ServiceUnavailable(msg) => {
                 (StatusCode::SERVICE_UNAVAILABLE, "SERVICE_UNAVAILABLE", msg.clone())
             }
@@ -86,14 +138,33 @@ impl IntoResponse for AppError {
             error: ErrorDetail {
                 code: code.into(),
                 message,
+                fields,
                 details: None,
             },
         };
 
-        (status, axum::Json(body)).into_response()
+        let mut response = (status, axum::Json(body)).into_response();
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(axum::http::header::RETRY_AFTER, secs.into());
+        }
+        response
     }
 }
 
+impl From<FieldError> for AppError {
+    fn from(err: FieldError) -> Self {
+        AppError::Validation(vec![err])
+    }
+}
+
+/// Field name from a serde "missing field `name`" deserialization message.
+fn missing_field(message: &str) -> Option<&str> {
+    let rest = &message[message.find("missing field `")? + "missing field `".len()..];
+    rest.split('`').next()
+}
+
 impl From<rusqlite::Error> for AppError {
     fn from(err: rusqlite::Error) -> Self {
         AppError::Internal(format!("database error: {err}"))
@@ -0,0 +1,20 @@
+use axum::extract::FromRequest;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// `axum::Json` whose rejections render as the FR-1204 error body.
+///
+/// Malformed JSON, a wrong content type or a missing/mistyped field comes back
+/// as `{error: {code: "INVALID_BODY", message, fields}}` instead of axum's
+/// plain-text rejection.
+#[derive(Debug, Clone, Copy, Default, FromRequest)]
+#[from_request(via(axum::Json), rejection(AppError))]
+pub struct Json<T>(pub T);
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use serde::{Serialize, Serializer};
+
+use crate::error::AppError;
+use crate::routes::AppState;
+
+/// Incremental (mtime-based) scanning.
+pub const INCREMENTAL_SCAN: &str = "incremental_scan";
+/// Scan-to-scan report diff endpoint.
+pub const SCAN_DIFF: &str = "scan_diff";
+
+/// Flags the server knows about; always reported, defaulting to off.
+pub const KNOWN_FLAGS: &[&str] = &[INCREMENTAL_SCAN, SCAN_DIFF];
+
+/// Feature-flag store loaded from a JSON file (`{"flag_name": true, ...}`).
+///
+/// The file is read at startup and re-read on `reload`. Handlers never read the
+/// store directly; they take a [`FlagSnapshot`] so one request sees one flag set.
+#[derive(Debug, Clone)]
+pub struct FeatureFlags {
+    path: Option<PathBuf>,
+    current: Arc<RwLock<Arc<BTreeMap<String, bool>>>>,
+}
+
+impl FeatureFlags {
+    /// Load flags from `path`. `None` or a missing file means every flag is off.
+    pub fn load(path: Option<PathBuf>) -> Result<Self, AppError> {
+        let flags = match &path {
+            Some(p) => read_flags(p)?,
+            None => with_known_flags(BTreeMap::new()),
+        };
+        Ok(Self {
+            path,
+            current: Arc::new(RwLock::new(Arc::new(flags))),
+        })
+    }
+
+    /// Build an in-memory store (no backing file), for tests and embedding.
+    pub fn from_map(flags: BTreeMap<String, bool>) -> Self {
+        Self {
+            path: None,
+            current: Arc::new(RwLock::new(Arc::new(with_known_flags(flags)))),
+        }
+    }
+
+    /// Re-read the backing file. On error the previous flags stay in effect.
+    pub fn reload(&self) -> Result<FlagSnapshot, AppError> {
+        if let Some(path) = &self.path {
+            let flags = Arc::new(read_flags(path)?);
+            *self.current.write().expect("flag lock poisoned") = flags;
+        }
+        Ok(self.snapshot())
+    }
+
+    /// The flag set currently in effect.
+    pub fn snapshot(&self) -> FlagSnapshot {
+        FlagSnapshot(self.current.read().expect("flag lock poisoned").clone())
+    }
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self::from_map(BTreeMap::new())
+    }
+}
+
+/// Immutable view of the flags taken when a request starts.
+#[derive(Debug, Clone)]
+pub struct FlagSnapshot(Arc<BTreeMap<String, bool>>);
+
+impl Serialize for FlagSnapshot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (*self.0).serialize(serializer)
+    }
+}
+
+impl FlagSnapshot {
+    /// Whether `name` is on. Unknown flags are off.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.0.get(name).copied().unwrap_or(false)
+    }
+
+    /// Reject a request to a dark-launched endpoint whose flag is off.
+    /// Responds 404 so the endpoint is indistinguishable from one that does not exist.
+    pub fn require(&self, name: &str) -> Result<(), AppError> {
+        if self.is_enabled(name) {
+            Ok(())
+        } else {
+            Err(AppError::NotFound(format!("feature '{name}' is not enabled")))
+        }
+    }
+}
+
+impl FromRequestParts<AppState> for FlagSnapshot {
+    type Rejection = AppError;
+
+    async fn from_request_parts(_parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        Ok(state.flags.snapshot())
+    }
+}
+
+/// Read a flags file, treating a missing file as "all flags off".
+fn read_flags(path: &Path) -> Result<BTreeMap<String, bool>, AppError> {
+    let flags = match std::fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str::<BTreeMap<String, bool>>(&content).map_err(|e| {
+            AppError::BadRequest(format!("invalid flags file {}: {e}", path.display()))
+        })?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            tracing::warn!("flags file {} not found; all flags off", path.display());
+            BTreeMap::new()
+        }
+        Err(e) => {
+            return Err(AppError::Internal(format!(
+                "cannot read flags file {}: {e}",
+                path.display()
+            )))
+        }
+    };
+    Ok(with_known_flags(flags))
+}
+
+/// Add every known flag that the source did not mention, defaulting to off.
+fn with_known_flags(mut flags: BTreeMap<String, bool>) -> BTreeMap<String, bool> {
+    for name in KNOWN_FLAGS {
+        flags.entry((*name).to_string()).or_insert(false);
+    }
+    flags
+}
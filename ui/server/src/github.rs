@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use crate::config::GitHubConfig;
+
+/// Maximum length GitHub accepts for a commit status description.
+const MAX_DESCRIPTION_LEN: usize = 140;
+
+/// Commit status payload for `POST /repos/{owner}/{repo}/statuses/{sha}`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommitStatus {
+    /// One of `success`, `failure`, `error`, or `pending`.
+    pub state: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+    /// Status label shown on the commit, e.g. `swe-compliance/doc-engine`.
+    pub context: String,
+}
+
+/// Check that a repository reference has the `owner/name` form.
+pub fn is_valid_repo(repo: &str) -> bool {
+    let valid_part = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    };
+    match repo.split_once('/') {
+        Some((owner, name)) => valid_part(owner) && valid_part(name),
+        None => false,
+    }
+}
+
+/// Check that a commit reference is a 7-40 character hex SHA.
+pub fn is_valid_commit_sha(sha: &str) -> bool {
+    (7..=40).contains(&sha.len()) && sha.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Build the commit status for a finished scan.
+///
+/// `report_json` is the stored engine report, or `None` when the scan failed
+/// before producing one.
+pub fn status_for_scan(
+    engine: &str,
+    report_json: Option<&str>,
+    target_url: Option<String>,
+) -> CommitStatus {
+    let context = format!("swe-compliance/{engine}");
+    let summary = report_json
+        .and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok())
+        .and_then(|v| v.get("summary").cloned());
+
+    let (state, mut description) = match summary {
+        Some(summary) => {
+            let count = |key: &str| summary.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            let (passed, failed, skipped) = (count("passed"), count("failed"), count("skipped"));
+            let state = if failed > 0 { "failure" } else { "success" };
+            (
+                state,
+                format!("{passed} passed, {failed} failed, {skipped} skipped"),
+            )
+        }
+        None => ("error", format!("{engine} scan did not produce a report")),
+    };
+
+    description.truncate(MAX_DESCRIPTION_LEN);
+    CommitStatus {
+        state: state.into(),
+        description,
+        target_url,
+        context,
+    }
+}
+
+/// Link to the scan report in the dashboard, when a public URL is configured.
+pub fn report_url(config: &GitHubConfig, scan_id: &str) -> Option<String> {
+    config
+        .public_url
+        .as_ref()
+        .map(|base| format!("{}/reports?scan={scan_id}", base.trim_end_matches('/')))
+}
+
+/// POST a commit status to the GitHub API.
+pub async fn post_commit_status(
+    config: &GitHubConfig,
+    repo: &str,
+    sha: &str,
+    status: &CommitStatus,
+) -> Result<(), String> {
+    let url = format!(
+        "{}/repos/{repo}/statuses/{sha}",
+        config.api_url.trim_end_matches('/')
+    );
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .bearer_auth(&config.token)
+        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+        .header(reqwest::header::USER_AGENT, concat!("swe-compliance-server/", env!("CARGO_PKG_VERSION")))
+        .json(status)
+        .send()
+        .await
+        .map_err(|e| format!("request to {url} failed: {e}"))?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        let code = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Err(format!("GitHub returned {code}: {body}"))
+    }
+}
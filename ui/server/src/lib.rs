@@ -1,7 +1,14 @@
+pub mod alerts;
 pub mod auth;
 pub mod config;
 pub mod db;
 pub mod error;
+pub mod extract;
+pub mod flags;
+pub mod github;
+pub mod metrics;
 pub mod middleware;
+pub mod pagination;
 pub mod routes;
+pub mod webhooks;
 pub mod ws;
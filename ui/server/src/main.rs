@@ -4,8 +4,11 @@ use tracing_subscriber::EnvFilter;
 
 use swe_compliance_server::config::ServerConfig;
 use swe_compliance_server::db::Db;
+use swe_compliance_server::flags::FeatureFlags;
+use swe_compliance_server::metrics::Metrics;
 use swe_compliance_server::middleware::{cors_layer, ScanSemaphore};
 use swe_compliance_server::routes::{build_router, AppState};
+use swe_compliance_server::webhooks;
 use swe_compliance_server::ws::WsBroadcaster;
 
 #[tokio::main]
@@ -30,11 +33,22 @@ async fn main() {
     let db = Db::open(&config.db_path).expect("failed to initialize database");
     tracing::info!("Database initialized at {}", config.db_path.display());
 
+    // Resume webhook deliveries left pending by a previous run
+    let resumed = webhooks::redeliver_pending(&db);
+    if resumed > 0 {
+        tracing::info!("Resumed {resumed} pending webhook deliveries");
+    }
+
+    // Load feature flags
+    let flags = FeatureFlags::load(config.flags_path.clone()).expect("failed to load feature flags");
+
     // Build application state
     let state = AppState {
         db,
         ws_broadcaster: WsBroadcaster::new(),
         scan_semaphore: ScanSemaphore::new(config.max_concurrent_scans),
+        flags,
+        metrics: Metrics::new(),
         config: config.clone(),
     };
 
@@ -52,7 +66,8 @@ async fn main() {
         .await
         .expect("failed to bind");
 
-    axum::serve(listener, app)
+    // Connection info lets the rate limiter key unauthenticated callers by IP
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .await
         .expect("server error");
 }
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::{MatchedPath, Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+
+/// Prometheus collectors for scan throughput and HTTP traffic.
+///
+/// Cheap to clone; every clone records into the same registry.
+#[derive(Clone)]
+pub struct Metrics {
+    inner: Arc<MetricsInner>,
+}
+
+struct MetricsInner {
+    registry: Registry,
+    scans_started: IntCounter,
+    scans_completed: IntCounter,
+    scans_failed: IntCounter,
+    scan_duration: Histogram,
+    scans_in_flight: IntGauge,
+    http_requests: IntCounterVec,
+}
+
+impl std::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Metrics").finish_non_exhaustive()
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let scans_started =
+            IntCounter::new("swe_scans_started_total", "Scans launched").unwrap();
+        let scans_completed =
+            IntCounter::new("swe_scans_completed_total", "Scans that produced a report").unwrap();
+        let scans_failed =
+            IntCounter::new("swe_scans_failed_total", "Scans that errored or panicked").unwrap();
+        let scan_duration = Histogram::with_opts(
+            HistogramOpts::new("swe_scan_duration_seconds", "Wall-clock scan duration")
+                .buckets(vec![0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0]),
+        )
+        .unwrap();
+        let scans_in_flight =
+            IntGauge::new("swe_scans_in_flight", "Scans currently holding a scan permit").unwrap();
+        let http_requests = IntCounterVec::new(
+            Opts::new("swe_http_requests_total", "HTTP requests by route and status"),
+            &["route", "status"],
+        )
+        .unwrap();
+
+        registry.register(Box::new(scans_started.clone())).unwrap();
+        registry.register(Box::new(scans_completed.clone())).unwrap();
+        registry.register(Box::new(scans_failed.clone())).unwrap();
+        registry.register(Box::new(scan_duration.clone())).unwrap();
+        registry.register(Box::new(scans_in_flight.clone())).unwrap();
+        registry.register(Box::new(http_requests.clone())).unwrap();
+
+        Self {
+            inner: Arc::new(MetricsInner {
+                registry,
+                scans_started,
+                scans_completed,
+                scans_failed,
+                scan_duration,
+                scans_in_flight,
+                http_requests,
+            }),
+        }
+    }
+
+    pub fn scan_started(&self) {
+        self.inner.scans_started.inc();
+    }
+
+    /// Record a finished scan and how long it ran.
+    pub fn scan_finished(&self, succeeded: bool, elapsed: Duration) {
+        if succeeded {
+            self.inner.scans_completed.inc();
+        } else {
+            self.inner.scans_failed.inc();
+        }
+        self.inner.scan_duration.observe(elapsed.as_secs_f64());
+    }
+
+    pub fn set_scans_in_flight(&self, count: usize) {
+        self.inner.scans_in_flight.set(count as i64);
+    }
+
+    pub fn http_request(&self, route: &str, status: u16) {
+        self.inner
+            .http_requests
+            .with_label_values(&[route, &status.to_string()])
+            .inc();
+    }
+
+    /// Content type of [`Metrics::render`] output.
+    pub fn content_type(&self) -> String {
+        TextEncoder::new().format_type().to_string()
+    }
+
+    /// Encode every collector in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut buf = Vec::new();
+        TextEncoder::new()
+            .encode(&self.inner.registry.gather(), &mut buf)
+            .expect("text encoding of gathered metrics cannot fail");
+        String::from_utf8(buf).expect("prometheus text output is UTF-8")
+    }
+}
+
+/// Count every routed request by its route pattern (not the raw path, so ids
+/// do not explode label cardinality) and response status.
+pub async fn track_http_metrics(
+    State(metrics): State<Metrics>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".into());
+
+    let response = next.run(request).await;
+    metrics.http_request(&route, response.status().as_u16());
+    response
+}
@@ -1,13 +1,15 @@
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use axum::extract::Request;
+use axum::extract::{ConnectInfo, Request};
 use axum::http::HeaderValue;
 use axum::middleware::Next;
 use axum::response::Response;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 
+use crate::auth::{validate_token, JwtSecret};
 use crate::error::AppError;
 
 /// Build CORS layer with configurable origins (NFR-203).
@@ -40,13 +42,27 @@ pub fn cors_layer(origins: &[String]) -> CorsLayer {
         .max_age(std::time::Duration::from_secs(3600))
 }
 
+/// Length of one rate limiting window.
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
 /// Per-user rate limiting state (FR-1205).
+///
+/// Each caller gets its own fixed one-minute window of `max_per_min`
+/// requests, keyed by username for authenticated requests and by client IP
+/// otherwise. Buckets idle for a full window are evicted, at most once per
+/// window, so memory stays bounded by the callers seen in the last minute.
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
-    state: Arc<Mutex<HashMap<String, RateWindow>>>,
+    state: Arc<Mutex<RateBuckets>>,
     max_per_min: u32,
 }
 
+#[derive(Debug)]
+struct RateBuckets {
+    windows: HashMap<String, RateWindow>,
+    last_eviction: Instant,
+}
+
 #[derive(Debug)]
 struct RateWindow {
     count: u32,
@@ -56,25 +72,43 @@ struct RateWindow {
 impl RateLimiter {
     pub fn new(max_per_min: u32) -> Self {
         Self {
-            state: Arc::new(Mutex::new(HashMap::new())),
+            state: Arc::new(Mutex::new(RateBuckets {
+                windows: HashMap::new(),
+                last_eviction: Instant::now(),
+            })),
             max_per_min,
         }
     }
 
-    fn check(&self, user: &str) -> Result<(), AppError> {
+    fn check(&self, key: &str) -> Result<(), AppError> {
         let mut state = self.state.lock().unwrap();
         let now = Instant::now();
-        let window = state.entry(user.to_string()).or_insert(RateWindow {
+
+        if now.duration_since(state.last_eviction) >= RATE_WINDOW {
+            state
+                .windows
+                .retain(|_, w| now.duration_since(w.window_start) < RATE_WINDOW);
+            state.last_eviction = now;
+        }
+
+        let window = state.windows.entry(key.to_string()).or_insert(RateWindow {
             count: 0,
             window_start: now,
         });
 
-        if now.duration_since(window.window_start).as_secs() >= 60 {
+        let elapsed = now.duration_since(window.window_start);
+        if elapsed >= RATE_WINDOW {
             window.count = 1;
             window.window_start = now;
             Ok(())
         } else if window.count >= self.max_per_min {
-            Err(AppError::RateLimited)
+            // Round up so clients never retry before the window resets
+            let remaining = RATE_WINDOW - elapsed;
+            let retry_after_secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+            Err(AppError::RateLimited {
+                limit_per_min: self.max_per_min,
+                retry_after_secs,
+            })
         } else {
             window.count += 1;
             Ok(())
@@ -82,6 +116,27 @@ impl RateLimiter {
     }
 }
 
+/// Bucket key for a request: `user:<name>` when it carries a valid JWT,
+/// otherwise `ip:<addr>` from the connection (`ip:unknown` without one).
+fn rate_limit_key(request: &Request) -> String {
+    let username = request
+        .headers()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .zip(request.extensions().get::<JwtSecret>())
+        .and_then(|(token, secret)| validate_token(&secret.0, token).ok())
+        .map(|claims| claims.sub);
+
+    match username {
+        Some(name) => format!("user:{name}"),
+        None => match request.extensions().get::<ConnectInfo<SocketAddr>>() {
+            Some(ConnectInfo(addr)) => format!("ip:{}", addr.ip()),
+            None => "ip:unknown".into(),
+        },
+    }
+}
+
 /// Rate limiting middleware (FR-1205).
 pub async fn rate_limit_middleware(
     request: Request,
@@ -92,17 +147,8 @@ pub async fn rate_limit_middleware(
         .get::<RateLimiter>()
         .cloned();
 
-    // Extract user from JWT claims if present
-    let user = request
-        .headers()
-        .get("authorization")
-        .and_then(|v| v.to_str().ok())
-        .and_then(|v| v.strip_prefix("Bearer "))
-        .map(|t| t.to_string())
-        .unwrap_or_else(|| "anonymous".into());
-
     if let Some(limiter) = rate_limiter {
-        limiter.check(&user)?;
+        limiter.check(&rate_limit_key(&request))?;
     }
 
     Ok(next.run(request).await)
@@ -112,15 +158,22 @@ pub async fn rate_limit_middleware(
 #[derive(Debug, Clone)]
 pub struct ScanSemaphore {
     semaphore: Arc<tokio::sync::Semaphore>,
+    max_concurrent: usize,
 }
 
 impl ScanSemaphore {
     pub fn new(max_concurrent: usize) -> Self {
         Self {
             semaphore: Arc::new(tokio::sync::Semaphore::new(max_concurrent)),
+            max_concurrent,
         }
     }
 
+    /// Number of permits currently held, i.e. scans in flight.
+    pub fn in_flight(&self) -> usize {
+        self.max_concurrent - self.semaphore.available_permits()
+    }
+
     pub async fn acquire(&self) -> Result<tokio::sync::OwnedSemaphorePermit, AppError> {
         self.semaphore
             .clone()
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{check_fields, AppError, FieldError};
+
+/// Page size used when `?limit=` is omitted.
+pub const DEFAULT_PAGE_LIMIT: u32 = 50;
+
+/// Largest page a client may request; larger limits are capped to this.
+pub const MAX_PAGE_LIMIT: u32 = 500;
+
+/// `?limit=&offset=` query parameters for list endpoints.
+///
+/// Kept as raw strings so a negative or non-numeric value is reported as a
+/// `VALIDATION_ERROR` naming the field instead of axum's plain-text rejection.
+#[derive(Debug, Default, Deserialize)]
+pub struct PageQuery {
+    pub limit: Option<String>,
+    pub offset: Option<String>,
+}
+
+/// A validated page window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageParams {
+    pub limit: u32,
+    pub offset: u64,
+}
+
+impl PageQuery {
+    /// Validate the raw parameters, applying the default and cap on `limit`.
+    pub fn params(&self) -> Result<PageParams, AppError> {
+        let mut errors = Vec::new();
+
+        let limit = match self.limit.as_deref() {
+            None => DEFAULT_PAGE_LIMIT,
+            Some(raw) => match raw.trim().parse::<u32>() {
+                Ok(0) | Err(_) => {
+                    errors.push(FieldError::new("limit", "must be a positive integer"));
+                    DEFAULT_PAGE_LIMIT
+                }
+                Ok(n) => n.min(MAX_PAGE_LIMIT),
+            },
+        };
+
+        let offset = match self.offset.as_deref() {
+            None => 0,
+            Some(raw) => raw.trim().parse::<u64>().unwrap_or_else(|_| {
+                errors.push(FieldError::new("offset", "must be a non-negative integer"));
+                0
+            }),
+        };
+
+        check_fields(errors)?;
+        Ok(PageParams { limit, offset })
+    }
+}
+
+/// List response envelope: one page of `items` plus the unpaged `total`.
+#[derive(Debug, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    pub limit: u32,
+    pub offset: u64,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: u64, params: PageParams) -> Self {
+        Self { items, total, limit: params.limit, offset: params.offset }
+    }
+}
@@ -1,11 +1,11 @@
 use axum::extract::ws::WebSocketUpgrade;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use serde::{Deserialize, Serialize};
 
 use crate::auth::AuthUser;
 use crate::error::AppError;
+use crate::extract::Json;
 use crate::routes::AppState;
 use crate::ws::handle_ai_chat_ws;
 
@@ -17,15 +17,22 @@ pub struct AiStatusResponse {
 }
 
 /// Chat request (FR-800).
+///
+/// Passing back the `session_id` of an earlier response continues that
+/// conversation; without one a new session is started.
 #[derive(Debug, Deserialize)]
 pub struct ChatRequest {
     pub message: String,
+    #[serde(default)]
+    pub session_id: Option<String>,
 }
 
 /// Chat response.
 #[derive(Debug, Serialize)]
 pub struct ChatResponse {
     pub response: String,
+    /// Session to send with the next message to keep the conversation context.
+    pub session_id: String,
 }
 
 /// Audit request (FR-802).
@@ -41,6 +48,17 @@ pub struct AuditResponse {
     pub summary: String,
     pub scan_results: serde_json::Value,
     pub recommendations: Vec<String>,
+    pub findings: Vec<AuditFinding>,
+}
+
+/// Structured audit finding rendered by the dashboard.
+#[derive(Debug, Serialize)]
+pub struct AuditFinding {
+    pub check_id: Option<doc_engine_scan::CheckId>,
+    pub severity: doc_engine_scan::Severity,
+    pub title: String,
+    pub detail: String,
+    pub suggested_fix: String,
 }
 
 /// Command generation request (FR-804).
@@ -89,10 +107,13 @@ pub async fn ai_status(
 }
 
 /// POST /api/v1/ai/chat — proxy to ComplianceChat (FR-800).
+///
+/// The session's recent turns, stored per user in the `Db`, are sent along
+/// with the new message; both the message and the reply are then appended.
 pub async fn ai_chat(
-    _user: AuthUser,
+    user: AuthUser,
     State(state): State<AppState>,
-    Json(_body): Json<ChatRequest>,
+    Json(body): Json<ChatRequest>,
 ) -> Result<Json<ChatResponse>, AppError> {
     if !state.config.ai_enabled || !has_api_key() {
         return Err(AppError::ServiceUnavailable(
@@ -102,23 +123,45 @@ pub async fn ai_chat(
 
     #[cfg(feature = "ai")]
     {
-        use doc_engine_compliance_chat::saf::ComplianceChat;
+        use doc_engine_compliance_chat::{ChatMessage, ChatRole, ComplianceChat, ComplianceChatConfig};
+
+        let config = ComplianceChatConfig::default();
+        let session_id = body
+            .session_id
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        // Recent window only; ComplianceChat trims again to history_size
+        let limit = u32::try_from(config.history_size).unwrap_or(u32::MAX);
+        let mut messages: Vec<ChatMessage> = state
+            .db
+            .recent_chat_messages(&user.username, &session_id, limit)?
+            .into_iter()
+            .filter_map(|row| {
+                ChatRole::from_name(&row.role).map(|role| ChatMessage { role, content: row.content })
+            })
+            .collect();
+        messages.push(ChatMessage::user(body.message.clone()));
 
-        let config = doc_engine_compliance_chat::api::types::ComplianceChatConfig::default();
         let chat = ComplianceChat::new(config)
             .await
             .map_err(|e| AppError::Internal(format!("failed to initialize chat: {e}")))?;
 
         let response = chat
-            .chat(&body.message)
+            .chat_with_history(&messages)
             .await
             .map_err(|e| AppError::Internal(format!("chat error: {e}")))?;
 
-        return Ok(Json(ChatResponse { response }));
+        // Persist only completed exchanges so a failed call leaves no dangling turn
+        state.db.append_chat_message(&user.username, &session_id, ChatRole::User.as_str(), &body.message)?;
+        state.db.append_chat_message(&user.username, &session_id, ChatRole::Assistant.as_str(), &response)?;
+
+        return Ok(Json(ChatResponse { response, session_id }));
     }
 
     #[cfg(not(feature = "ai"))]
     {
+        let _ = (user, body);
         Err(AppError::ServiceUnavailable(
             "AI features require the server to be compiled with the 'ai' feature flag".into(),
         ))
@@ -173,6 +216,17 @@ pub async fn ai_audit(
             summary: result.summary,
             scan_results: result.scan_results,
             recommendations: result.recommendations,
+            findings: result
+                .findings
+                .into_iter()
+                .map(|f| AuditFinding {
+                    check_id: f.check_id,
+                    severity: f.severity,
+                    title: f.title,
+                    detail: f.detail,
+                    suggested_fix: f.suggested_fix,
+                })
+                .collect(),
         }));
     }
 
@@ -1,9 +1,9 @@
 use axum::extract::{Path as AxumPath, State};
-use axum::Json;
 use serde::{Deserialize, Serialize};
 
 use crate::auth::AuthUser;
-use crate::error::AppError;
+use crate::error::{AppError, FieldError};
+use crate::extract::Json;
 use crate::routes::AppState;
 
 /// SRS validation request (FR-901).
@@ -48,7 +48,7 @@ pub async fn validate_srs(
     Json(body): Json<ValidateSrsRequest>,
 ) -> Result<Json<ValidateSrsResponse>, AppError> {
     if body.content.trim().is_empty() {
-        return Err(AppError::BadRequest("SRS content is required".into()));
+        return Err(FieldError::new("content", "SRS content is required").into());
     }
 
     let content = body.content.clone();
@@ -123,6 +123,10 @@ pub async fn save_srs(
     // Validate project exists
     let _ = state.db.get_project(&id)?;
 
+    if body.content.trim().is_empty() {
+        return Err(FieldError::new("content", "SRS content must not be empty").into());
+    }
+
     let row = state.db.save_srs(&id, &body.content)?;
 
     Ok(Json(SrsContentResponse {
@@ -0,0 +1,25 @@
+use axum::extract::State;
+use axum::Json;
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::flags::FlagSnapshot;
+use crate::routes::AppState;
+
+/// GET /api/v1/flags — feature flags in effect, so the frontend can discover experiments.
+pub async fn get_flags(_user: AuthUser, flags: FlagSnapshot) -> Json<FlagSnapshot> {
+    Json(flags)
+}
+
+/// POST /api/v1/admin/flags/reload — re-read the flags file without a restart.
+pub async fn reload_flags(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<FlagSnapshot>, AppError> {
+    if user.anonymous || !state.config.admin_users.contains(&user.username) {
+        return Err(AppError::Forbidden("admin access required".into()));
+    }
+    let flags = state.flags.reload()?;
+    tracing::info!("feature flags reloaded by {}", user.username);
+    Ok(Json(flags))
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+
+use crate::auth::AuthUser;
+use crate::error::{AppError, FieldError};
+use crate::extract::Json;
+use crate::routes::scans::summary_coverage;
+use crate::routes::AppState;
+
+/// Fleet query parameters.
+#[derive(Debug, Deserialize)]
+pub struct FleetQuery {
+    /// Only consider scans from this engine.
+    pub engine: Option<String>,
+    /// Only include projects carrying this tag.
+    pub tag: Option<String>,
+}
+
+/// One project's row in the fleet comparison (FR-204).
+#[derive(Debug, Serialize)]
+pub struct FleetEntry {
+    pub project_id: String,
+    pub name: String,
+    pub tags: Vec<String>,
+    /// Latest completed scan; `None` when the project has not been scanned.
+    pub scan_id: Option<String>,
+    pub engine: Option<String>,
+    pub started_at: Option<String>,
+    pub passed: Option<u64>,
+    pub failed: Option<u64>,
+    pub skipped: Option<u64>,
+    /// Percentage of applicable (non-skipped) checks that passed.
+    pub coverage: Option<f64>,
+    /// `up`, `down` or `flat` against the previous completed scan; `None`
+    /// without two scans to compare.
+    pub trend: Option<String>,
+    /// Coverage of the last [`FLEET_HISTORY_SCANS`] completed scans, oldest
+    /// first, for sparklines; scans with no applicable checks are left out.
+    pub history: Vec<f64>,
+}
+
+/// Completed scans per project included in [`FleetEntry::history`].
+pub const FLEET_HISTORY_SCANS: usize = 12;
+
+/// GET /api/v1/fleet — latest compliance of every project, worst coverage first (FR-204).
+///
+/// Projects without a completed scan come last, ordered by name.
+pub async fn get_fleet(
+    _user: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<FleetQuery>,
+) -> Result<Json<Vec<FleetEntry>>, AppError> {
+    if let Some(engine) = query.engine.as_deref() {
+        if engine != "doc-engine" && engine != "struct-engine" {
+            return Err(FieldError::new(
+                "engine",
+                "engine must be 'doc-engine' or 'struct-engine'",
+            )
+            .into());
+        }
+    }
+
+    let rows = state.db.fleet(
+        query.engine.as_deref(),
+        query.tag.as_deref(),
+        FLEET_HISTORY_SCANS,
+    )?;
+    let mut entries: Vec<FleetEntry> = rows
+        .into_iter()
+        .map(|row| {
+            let summary = report_summary(row.report_json.as_deref());
+            let coverage = summary.as_ref().and_then(summary_coverage);
+            let previous = row
+                .history
+                .len()
+                .checked_sub(2)
+                .and_then(|i| summary_coverage(&row.history[i]));
+            let trend = coverage.zip(previous).map(|(now, before)| {
+                match now.partial_cmp(&before) {
+                    Some(Ordering::Greater) => "up",
+                    Some(Ordering::Less) => "down",
+                    _ => "flat",
+                }
+                .to_string()
+            });
+            let count = |key: &str| summary.as_ref().and_then(|s| s.get(key)?.as_u64());
+
+            FleetEntry {
+                passed: count("passed"),
+                failed: count("failed"),
+                skipped: count("skipped"),
+                project_id: row.project_id,
+                name: row.name,
+                tags: row.tags,
+                scan_id: row.scan_id,
+                engine: row.engine,
+                started_at: row.started_at,
+                coverage,
+                trend,
+                history: row.history.iter().filter_map(summary_coverage).collect(),
+            }
+        })
+        .collect();
+
+    entries.sort_by(|a, b| {
+        let by_coverage = match (a.coverage, b.coverage) {
+            (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        by_coverage.then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(Json(entries))
+}
+
+fn report_summary(report_json: Option<&str>) -> Option<serde_json::Value> {
+    report_json
+        .and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok())
+        .and_then(|report| report.get("summary").cloned())
+}
@@ -1,10 +1,10 @@
 use axum::extract::State;
 use axum::http::StatusCode;
-use axum::Json;
 use serde::{Deserialize, Serialize};
 
-use crate::auth::{issue_token, JwtSecret};
+use crate::auth::{issue_token, AuthUser, JwtSecret};
 use crate::error::AppError;
+use crate::extract::Json;
 use crate::routes::AppState;
 
 /// Health check response (FR-1202).
@@ -40,15 +40,19 @@ pub struct LoginRequest {
     pub password: String,
 }
 
-/// Login response body.
+/// Login and refresh response body.
 #[derive(Debug, Serialize)]
 pub struct LoginResponse {
     pub token: String,
     pub expires_in: u64,
+    /// Whether the user is listed in `SWE_ADMIN_USERS`; the frontend lands
+    /// admins on the fleet view.
+    pub admin: bool,
 }
 
 /// POST /api/v1/auth/login — issue JWT token (FR-1201).
 pub async fn login(
+    State(state): State<AppState>,
     axum::Extension(secret): axum::Extension<JwtSecret>,
     Json(body): Json<LoginRequest>,
 ) -> Result<Json<LoginResponse>, AppError> {
@@ -60,9 +64,29 @@ pub async fn login(
         ));
     }
 
-    let token = issue_token(&secret.0, &body.username)?;
+    let token = issue_token(&secret.0, &body.username, state.config.token_ttl_secs)?;
     Ok(Json(LoginResponse {
         token,
-        expires_in: 86400, // 24 hours
+        expires_in: state.config.token_ttl_secs,
+        admin: state.config.admin_users.contains(&body.username),
+    }))
+}
+
+/// POST /api/v1/auth/refresh — exchange a valid, unexpired JWT for a fresh
+/// one with a full lifetime (FR-1201).
+pub async fn refresh(
+    user: AuthUser,
+    State(state): State<AppState>,
+    axum::Extension(secret): axum::Extension<JwtSecret>,
+) -> Result<Json<LoginResponse>, AppError> {
+    if user.anonymous {
+        return Err(AppError::Unauthorized("missing authentication".into()));
+    }
+
+    let token = issue_token(&secret.0, &user.username, state.config.token_ttl_secs)?;
+    Ok(Json(LoginResponse {
+        token,
+        expires_in: state.config.token_ttl_secs,
+        admin: state.config.admin_users.contains(&user.username),
     }))
 }
@@ -0,0 +1,16 @@
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+
+use crate::routes::AppState;
+
+/// GET /metrics — Prometheus scrape endpoint.
+///
+/// The in-flight gauge is sampled from the scan semaphore at scrape time.
+pub async fn get_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    state.metrics.set_scans_in_flight(state.scan_semaphore.in_flight());
+    (
+        [(header::CONTENT_TYPE, state.metrics.content_type())],
+        state.metrics.render(),
+    )
+}
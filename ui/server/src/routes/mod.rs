@@ -8,15 +8,20 @@ pub mod reports;
 pub mod ai;
 pub mod editor;
 pub mod specs;
+pub mod flags;
+pub mod fleet;
+pub mod metrics;
 
 use axum::middleware;
 use axum::routing::{delete, get, patch, post, put};
 use axum::Router;
 use tower_http::services::{ServeDir, ServeFile};
 
-use crate::auth::JwtSecret;
+use crate::auth::{JwtSecret, PublicRead};
 use crate::middleware::{rate_limit_middleware, RateLimiter, ScanSemaphore};
 use crate::db::Db;
+use crate::flags::FeatureFlags;
+use crate::metrics::{track_http_metrics, Metrics};
 use crate::ws::WsBroadcaster;
 use crate::config::ServerConfig;
 
@@ -27,17 +32,22 @@ pub struct AppState {
     pub config: ServerConfig,
     pub ws_broadcaster: WsBroadcaster,
     pub scan_semaphore: ScanSemaphore,
+    pub flags: FeatureFlags,
+    pub metrics: Metrics,
 }
 
 /// Build the complete router with all API routes.
 pub fn build_router(state: AppState) -> Router {
     let jwt_secret = JwtSecret(state.config.jwt_secret.clone());
     let rate_limiter = RateLimiter::new(state.config.rate_limit_per_min);
+    let public_read = PublicRead(state.config.public_read);
 
     // Public routes (no auth required)
     let public = Router::new()
         .route("/health", get(health::health_check))
-        .route("/api/v1/auth/login", post(health::login));
+        .route("/metrics", get(metrics::get_metrics))
+        .route("/api/v1/auth/login", post(health::login))
+        .route("/api/v1/auth/refresh", post(health::refresh));
 
     // Protected API routes
     let api = Router::new()
@@ -47,12 +57,23 @@ pub fn build_router(state: AppState) -> Router {
         .route("/api/v1/projects/{id}", get(projects::get_project))
         .route("/api/v1/projects/{id}", patch(projects::update_project))
         .route("/api/v1/projects/{id}", delete(projects::delete_project))
+        .route("/api/v1/projects/{id}/alerts", get(projects::get_alerts))
+        .route("/api/v1/projects/{id}/alerts", put(projects::save_alerts))
+        .route("/api/v1/projects/{id}/alerts", delete(projects::delete_alerts))
+        .route("/api/v1/projects/{id}/alerts/test", post(projects::test_alerts))
+        .route("/api/v1/projects/{id}/webhooks", post(projects::create_webhook))
         // Scans
         .route("/api/v1/scans", post(scans::create_scan))
         .route("/api/v1/scans/{id}", get(scans::get_scan))
+        .route("/api/v1/scans/{id}/status", get(scans::scan_status))
         .route("/api/v1/scans/{id}/progress", get(scans::scan_progress_ws))
+        .route("/api/v1/scans/{id}/recheck", post(scans::recheck_scan))
+        .route("/api/v1/scans/{id}/diff", get(scans::diff_scans))
         .route("/api/v1/projects/{id}/scans", get(scans::list_project_scans))
+        .route("/api/v1/projects/{id}/scans/export.ndjson", get(scans::export_project_scans))
         .route("/api/v1/projects/{id}/trends", get(scans::get_trends))
+        // Fleet
+        .route("/api/v1/fleet", get(fleet::get_fleet))
         // Violations
         .route("/api/v1/scans/{id}/violations", get(violations::get_violations))
         // Reports
@@ -76,6 +97,9 @@ pub fn build_router(state: AppState) -> Router {
         .route("/api/v1/projects/{id}/srs", put(editor::save_srs))
         // Specs
         .route("/api/v1/projects/{id}/specs", get(specs::get_specs))
+        // Feature flags
+        .route("/api/v1/flags", get(flags::get_flags))
+        .route("/api/v1/admin/flags/reload", post(flags::reload_flags))
         .layer(middleware::from_fn(rate_limit_middleware));
 
     // Serve frontend static files from ui/app/dist/, falling back to
@@ -89,8 +113,13 @@ pub fn build_router(state: AppState) -> Router {
     Router::new()
         .merge(public)
         .merge(api)
+        .route_layer(middleware::from_fn_with_state(
+            state.metrics.clone(),
+            track_http_metrics,
+        ))
         .fallback_service(spa_fallback)
         .layer(axum::Extension(jwt_secret))
         .layer(axum::Extension(rate_limiter))
+        .layer(axum::Extension(public_read))
         .with_state(state)
 }
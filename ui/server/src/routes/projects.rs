@@ -1,11 +1,12 @@
 use std::path::Path;
 
-use axum::extract::{Path as AxumPath, State};
-use axum::Json;
+use axum::extract::{Path as AxumPath, Query, State};
 use serde::{Deserialize, Serialize};
 
 use crate::auth::AuthUser;
-use crate::error::AppError;
+use crate::error::{check_fields, AppError, FieldError};
+use crate::extract::Json;
+use crate::pagination::{Page, PageQuery};
 use crate::routes::AppState;
 
 /// Create project request (FR-100).
@@ -15,6 +16,8 @@ pub struct CreateProjectRequest {
     pub root_path: String,
     pub scope: Option<String>,
     pub project_type: Option<String>,
+    /// Group labels used to scope the fleet view (FR-204).
+    pub tags: Option<Vec<String>>,
 }
 
 /// Update project request (FR-102).
@@ -23,6 +26,8 @@ pub struct UpdateProjectRequest {
     pub name: Option<String>,
     pub scope: Option<String>,
     pub project_type: Option<String>,
+    /// Replaces the project's tags when present.
+    pub tags: Option<Vec<String>>,
 }
 
 /// Project response with optional compliance summary.
@@ -36,6 +41,7 @@ pub struct ProjectResponse {
     pub created_at: String,
     pub updated_at: String,
     pub last_scan_id: Option<String>,
+    pub tags: Vec<String>,
     pub compliance_summary: Option<serde_json::Value>,
 }
 
@@ -45,34 +51,40 @@ pub async fn create_project(
     State(state): State<AppState>,
     Json(body): Json<CreateProjectRequest>,
 ) -> Result<(axum::http::StatusCode, Json<ProjectResponse>), AppError> {
+    let mut errors = Vec::new();
     if body.name.trim().is_empty() {
-        return Err(AppError::BadRequest("name is required".into()));
+        errors.push(FieldError::new("name", "name is required"));
     }
 
     // Path traversal prevention (NFR-201)
     let root = &body.root_path;
     if root.contains("..") || root.contains('\0') {
-        return Err(AppError::BadRequest(
-            "root_path must not contain path traversal sequences".into(),
+        errors.push(FieldError::new(
+            "root_path",
+            "root_path must not contain path traversal sequences",
+        ));
+    } else if !Path::new(root).exists() {
+        errors.push(FieldError::new(
+            "root_path",
+            format!("root_path does not exist: {root}"),
         ));
-    }
-
-    // Validate root_path exists
-    if !Path::new(root).exists() {
-        return Err(AppError::BadRequest(format!(
-            "root_path does not exist: {root}"
-        )));
     }
 
     let scope = body.scope.as_deref().unwrap_or("Small");
     let project_type = body.project_type.as_deref().unwrap_or("OpenSource");
 
-    validate_scope(scope)?;
-    validate_project_type(project_type)?;
+    errors.extend(validate_scope(scope));
+    errors.extend(validate_project_type(project_type));
+    errors.extend(body.tags.as_deref().and_then(validate_tags));
+    check_fields(errors)?;
 
-    let row = state
+    let mut row = state
         .db
         .create_project(&body.name, root, scope, project_type)?;
+    if let Some(tags) = &body.tags {
+        state.db.set_project_tags(&row.id, &normalize_tags(tags))?;
+        row = state.db.get_project(&row.id)?;
+    }
 
     Ok((
         axum::http::StatusCode::CREATED,
@@ -80,12 +92,15 @@ pub async fn create_project(
     ))
 }
 
-/// GET /api/v1/projects (FR-101).
+/// GET /api/v1/projects?limit=&offset= (FR-101).
 pub async fn list_projects(
     _user: AuthUser,
     State(state): State<AppState>,
-) -> Result<Json<Vec<ProjectResponse>>, AppError> {
-    let rows = state.db.list_projects()?;
+    Query(query): Query<PageQuery>,
+) -> Result<Json<Page<ProjectResponse>>, AppError> {
+    let page = query.params()?;
+    let total = state.db.count_projects()?;
+    let rows = state.db.list_projects(page.limit, page.offset)?;
 
     let projects: Vec<ProjectResponse> = rows
         .into_iter()
@@ -102,7 +117,7 @@ pub async fn list_projects(
         })
         .collect();
 
-    Ok(Json(projects))
+    Ok(Json(Page::new(projects, total, page)))
 }
 
 /// GET /api/v1/projects/{id} (FR-101).
@@ -131,13 +146,22 @@ pub async fn update_project(
     AxumPath(id): AxumPath<String>,
     Json(body): Json<UpdateProjectRequest>,
 ) -> Result<Json<ProjectResponse>, AppError> {
-    if let Some(scope) = &body.scope {
-        validate_scope(scope)?;
-    }
-    if let Some(pt) = &body.project_type {
-        validate_project_type(pt)?;
+    let mut errors = Vec::new();
+    if let Some(name) = &body.name {
+        if name.trim().is_empty() {
+            errors.push(FieldError::new("name", "name must not be empty"));
+        }
     }
+    errors.extend(body.scope.as_deref().and_then(validate_scope));
+    errors.extend(body.project_type.as_deref().and_then(validate_project_type));
+    errors.extend(body.tags.as_deref().and_then(validate_tags));
+    check_fields(errors)?;
 
+    if let Some(tags) = &body.tags {
+        // Validate the project exists before touching its tags
+        let _ = state.db.get_project(&id)?;
+        state.db.set_project_tags(&id, &normalize_tags(tags))?;
+    }
     let row = state.db.update_project(
         &id,
         body.name.as_deref(),
@@ -158,6 +182,233 @@ pub async fn delete_project(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+/// Threshold alert settings request (FR-105).
+#[derive(Debug, Deserialize)]
+pub struct AlertsRequest {
+    pub webhook_url: String,
+    pub max_failures: Option<u32>,
+    pub min_coverage: Option<f64>,
+    #[serde(default)]
+    pub on_new_failures: bool,
+}
+
+/// A project's threshold alert settings; `configured` is false when none are stored.
+#[derive(Debug, Serialize)]
+pub struct AlertsResponse {
+    pub project_id: String,
+    pub configured: bool,
+    pub webhook_url: Option<String>,
+    pub max_failures: Option<u32>,
+    pub min_coverage: Option<f64>,
+    pub on_new_failures: bool,
+    pub updated_at: Option<String>,
+    /// `ok` or `breached` for the latest scan; `None` when alerts are not
+    /// configured or the latest scan did not complete.
+    pub status: Option<String>,
+    /// Why the latest scan breaches the thresholds; empty unless `breached`.
+    pub reasons: Vec<String>,
+    pub latest_scan_id: Option<String>,
+}
+
+/// Build the alert settings response, judging the project's latest scan.
+fn alerts_response(
+    state: &AppState,
+    project: crate::db::ProjectRow,
+    row: Option<crate::db::AlertRow>,
+) -> AlertsResponse {
+    let Some(row) = row else {
+        return AlertsResponse {
+            project_id: project.id,
+            configured: false,
+            webhook_url: None,
+            max_failures: None,
+            min_coverage: None,
+            on_new_failures: false,
+            updated_at: None,
+            status: None,
+            reasons: Vec::new(),
+            latest_scan_id: project.last_scan_id,
+        };
+    };
+
+    let latest = project
+        .last_scan_id
+        .as_deref()
+        .and_then(|id| state.db.get_scan(id).ok())
+        .filter(|scan| scan.status == "completed");
+    let (status, reasons) = match &latest {
+        Some(scan) => {
+            let previous = state.db.previous_completed_scan(scan).ok().flatten();
+            match crate::alerts::threshold_breach(&row, scan, previous.as_ref()) {
+                Some(breach) => (Some("breached".to_string()), breach.reasons),
+                None => (Some("ok".to_string()), Vec::new()),
+            }
+        }
+        None => (None, Vec::new()),
+    };
+
+    AlertsResponse {
+        project_id: row.project_id,
+        configured: true,
+        webhook_url: Some(row.webhook_url),
+        max_failures: row.max_failures,
+        min_coverage: row.min_coverage,
+        on_new_failures: row.on_new_failures,
+        updated_at: Some(row.updated_at),
+        status,
+        reasons,
+        latest_scan_id: project.last_scan_id,
+    }
+}
+
+/// GET /api/v1/projects/{id}/alerts — threshold alert settings (FR-105).
+pub async fn get_alerts(
+    _user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+) -> Result<Json<AlertsResponse>, AppError> {
+    let project = state.db.get_project(&id)?;
+    let row = state.db.get_alerts(&id)?;
+    Ok(Json(alerts_response(&state, project, row)))
+}
+
+/// PUT /api/v1/projects/{id}/alerts — replace threshold alert settings (FR-105).
+pub async fn save_alerts(
+    user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+    Json(body): Json<AlertsRequest>,
+) -> Result<Json<AlertsResponse>, AppError> {
+    require_admin(&state, &user)?;
+
+    let mut errors = Vec::new();
+    let url = body.webhook_url.trim();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        errors.push(FieldError::new(
+            "webhook_url",
+            "webhook_url must be an http:// or https:// URL",
+        ));
+    }
+    if let Some(min) = body.min_coverage {
+        if !(0.0..=100.0).contains(&min) {
+            errors.push(FieldError::new(
+                "min_coverage",
+                "min_coverage must be between 0 and 100",
+            ));
+        }
+    }
+    if body.max_failures.is_none() && body.min_coverage.is_none() && !body.on_new_failures {
+        errors.push(FieldError::new(
+            "max_failures",
+            "set at least one of max_failures, min_coverage, or on_new_failures",
+        ));
+    }
+    check_fields(errors)?;
+
+    let project = state.db.get_project(&id)?;
+    let row = state.db.save_alerts(
+        &id,
+        url,
+        body.max_failures,
+        body.min_coverage,
+        body.on_new_failures,
+    )?;
+    Ok(Json(alerts_response(&state, project, Some(row))))
+}
+
+/// DELETE /api/v1/projects/{id}/alerts — stop threshold alerts (FR-105).
+pub async fn delete_alerts(
+    user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+) -> Result<axum::http::StatusCode, AppError> {
+    require_admin(&state, &user)?;
+
+    // Validate project exists
+    let _ = state.db.get_project(&id)?;
+
+    state.db.delete_alerts(&id)?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/projects/{id}/alerts/test — send a test notification to the stored webhook (FR-105).
+pub async fn test_alerts(
+    user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+) -> Result<axum::http::StatusCode, AppError> {
+    require_admin(&state, &user)?;
+
+    // Validate project exists
+    let _ = state.db.get_project(&id)?;
+
+    let row = state
+        .db
+        .get_alerts(&id)?
+        .ok_or_else(|| AppError::BadRequest("no alerts configured for this project".into()))?;
+    crate::alerts::post_webhook(&row.webhook_url, &crate::alerts::test_notification(&id))
+        .await
+        .map_err(|e| AppError::BadRequest(format!("test notification failed: {e}")))?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Scan event webhook registration request (FR-106).
+#[derive(Debug, Deserialize)]
+pub struct WebhookRequest {
+    pub url: String,
+    /// Events to deliver; all of [`crate::webhooks::WEBHOOK_EVENTS`] when omitted.
+    pub events: Option<Vec<String>>,
+}
+
+/// POST /api/v1/projects/{id}/webhooks — register a scan event webhook (FR-106).
+pub async fn create_webhook(
+    user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+    Json(body): Json<WebhookRequest>,
+) -> Result<(axum::http::StatusCode, Json<crate::db::WebhookRow>), AppError> {
+    require_admin(&state, &user)?;
+
+    let mut errors = Vec::new();
+    let url = body.url.trim();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        errors.push(FieldError::new("url", "url must be an http:// or https:// URL"));
+    }
+    let events: Vec<String> = match &body.events {
+        None => crate::webhooks::WEBHOOK_EVENTS.iter().map(|e| e.to_string()).collect(),
+        Some(events) => events.iter().map(|e| e.trim().to_string()).collect(),
+    };
+    if events.is_empty() {
+        errors.push(FieldError::new("events", "subscribe to at least one event"));
+    } else if let Some(unknown) = events
+        .iter()
+        .find(|e| !crate::webhooks::WEBHOOK_EVENTS.contains(&e.as_str()))
+    {
+        errors.push(FieldError::new(
+            "events",
+            format!(
+                "unknown event '{unknown}'; expected one of: {}",
+                crate::webhooks::WEBHOOK_EVENTS.join(", ")
+            ),
+        ));
+    }
+    check_fields(errors)?;
+
+    // Validate project exists
+    let _ = state.db.get_project(&id)?;
+
+    let row = state.db.create_webhook(&id, url, &events)?;
+    Ok((axum::http::StatusCode::CREATED, Json(row)))
+}
+
+/// Alert settings and webhooks carry delivery URLs, so only admins may change them.
+fn require_admin(state: &AppState, user: &AuthUser) -> Result<(), AppError> {
+    if user.anonymous || !state.config.admin_users.contains(&user.username) {
+        return Err(AppError::Forbidden("admin access required".into()));
+    }
+    Ok(())
+}
+
 fn project_row_to_response(
     row: crate::db::ProjectRow,
     compliance_summary: Option<serde_json::Value>,
@@ -171,24 +422,50 @@ fn project_row_to_response(
         created_at: row.created_at,
         updated_at: row.updated_at,
         last_scan_id: row.last_scan_id,
+        tags: row.tags,
         compliance_summary,
     }
 }
 
-fn validate_scope(scope: &str) -> Result<(), AppError> {
+fn validate_scope(scope: &str) -> Option<FieldError> {
     match scope {
-        "Small" | "Medium" | "Large" => Ok(()),
-        _ => Err(AppError::BadRequest(format!(
-            "invalid scope '{scope}' — must be Small, Medium, or Large"
-        ))),
+        "Small" | "Medium" | "Large" => None,
+        _ => Some(FieldError::new(
+            "scope",
+            format!("invalid scope '{scope}' — must be Small, Medium, or Large"),
+        )),
     }
 }
 
-fn validate_project_type(pt: &str) -> Result<(), AppError> {
+fn validate_project_type(pt: &str) -> Option<FieldError> {
     match pt {
-        "OpenSource" | "Internal" => Ok(()),
-        _ => Err(AppError::BadRequest(format!(
-            "invalid project_type '{pt}' — must be OpenSource or Internal"
-        ))),
+        "OpenSource" | "Internal" => None,
+        _ => Some(FieldError::new(
+            "project_type",
+            format!("invalid project_type '{pt}' — must be OpenSource or Internal"),
+        )),
     }
 }
+
+/// Longest accepted tag, in characters.
+const MAX_TAG_LEN: usize = 64;
+
+fn validate_tags(tags: &[String]) -> Option<FieldError> {
+    tags.iter()
+        .map(|t| t.trim())
+        .find(|t| t.is_empty() || t.chars().count() > MAX_TAG_LEN)
+        .map(|t| {
+            FieldError::new(
+                "tags",
+                format!("invalid tag '{t}' — tags must be 1-{MAX_TAG_LEN} characters"),
+            )
+        })
+}
+
+/// Trim tags and drop duplicates.
+fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut tags: Vec<String> = tags.iter().map(|t| t.trim().to_string()).collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
@@ -13,6 +13,9 @@ pub struct ReportQuery {
 }
 
 /// GET /api/v1/scans/{id}/report — generate report in requested format (FR-700..702).
+///
+/// Without `?format=` the stored JSON is returned inline; an explicit format is
+/// served as a download.
 pub async fn get_report(
     _user: AuthUser,
     State(state): State<AppState>,
@@ -32,32 +35,82 @@ pub async fn get_report(
         .report_json
         .ok_or_else(|| AppError::NotFound("no report data available".into()))?;
 
-    let format = query.format.as_deref().unwrap_or("json");
-
-    match format {
-        "json" => Ok((
+    let Some(format) = query.format.as_deref() else {
+        return Ok((
             [(axum::http::header::CONTENT_TYPE, "application/json")],
             report_json_str,
         )
-            .into_response()),
-        "markdown" => {
-            let report: serde_json::Value = serde_json::from_str(&report_json_str)
-                .map_err(|e| AppError::Internal(format!("{e}")))?;
-            let md = report_to_markdown(&report, &scan.engine);
-            Ok((
-                [(axum::http::header::CONTENT_TYPE, "text/markdown")],
-                md,
-            )
-                .into_response())
+            .into_response());
+    };
+
+    if format == "pdf" {
+        // PDF generation placeholder — requires printpdf or genpdf crate
+        return Err(AppError::BadRequest(
+            "PDF export is not yet implemented — use json, html, markdown, sarif, or junit format".into(),
+        ));
+    }
+    let Some((content_type, extension)) = export_format(format) else {
+        return Err(AppError::BadRequest(format!(
+            "unsupported format '{format}' — use json, html, markdown, sarif, or junit"
+        )));
+    };
+
+    let body = render_report(&scan.engine, format, &report_json_str)?;
+    let disposition = format!("attachment; filename=\"{}-{}.{extension}\"", scan.engine, scan.id);
+    Ok((
+        [
+            (axum::http::header::CONTENT_TYPE, content_type.to_string()),
+            (axum::http::header::CONTENT_DISPOSITION, disposition),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Content type and download file extension of each `?format=` value.
+fn export_format(format: &str) -> Option<(&'static str, &'static str)> {
+    match format {
+        "json" => Some(("application/json", "json")),
+        "markdown" => Some(("text/markdown", "md")),
+        "html" => Some(("text/html; charset=utf-8", "html")),
+        "sarif" => Some(("application/sarif+json", "sarif")),
+        "junit" => Some(("application/xml", "xml")),
+        _ => None,
+    }
+}
+
+/// Re-serialize a stored report through the engine's own reporter.
+///
+/// HTML comes from doc-engine, SARIF from struct-engine and JUnit from
+/// either; asking an engine for a format it has no reporter for is a bad
+/// request.
+fn render_report(engine: &str, format: &str, report_json: &str) -> Result<String, AppError> {
+    let unreadable = |e: serde_json::Error| AppError::Internal(format!("stored {engine} report is unreadable: {e}"));
+    match (engine, format) {
+        (_, "json") => Ok(report_json.to_string()),
+        (_, "markdown") => {
+            let report: serde_json::Value = serde_json::from_str(report_json).map_err(unreadable)?;
+            Ok(report_to_markdown(&report, engine))
+        }
+        ("doc-engine", "html") => {
+            let report: doc_engine_scan::ScanReport = serde_json::from_str(report_json).map_err(unreadable)?;
+            Ok(doc_engine_scan::format_report_html(&report))
+        }
+        ("doc-engine", "junit") => {
+            let report: doc_engine_scan::ScanReport = serde_json::from_str(report_json).map_err(unreadable)?;
+            Ok(doc_engine_scan::format_report_junit(&report))
         }
-        "pdf" => {
-            // PDF generation placeholder — requires printpdf or genpdf crate
-            Err(AppError::BadRequest(
-                "PDF export is not yet implemented — use json or markdown format".into(),
-            ))
+        ("struct-engine", "sarif" | "junit") => {
+            let report: struct_engine::ScanReport = serde_json::from_str(report_json).map_err(unreadable)?;
+            let format = if format == "sarif" {
+                struct_engine::ReportFormat::Sarif { include_passing: false }
+            } else {
+                struct_engine::ReportFormat::Junit
+            };
+            Ok(struct_engine::format_report(&report, format))
         }
         _ => Err(AppError::BadRequest(format!(
-            "unsupported format '{format}' — use json, markdown, or pdf"
+            "format '{format}' is not available for {engine} reports"
         ))),
     }
 }
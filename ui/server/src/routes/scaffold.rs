@@ -1,10 +1,10 @@
 use std::path::PathBuf;
 
-use axum::Json;
 use serde::{Deserialize, Serialize};
 
 use crate::auth::AuthUser;
-use crate::error::AppError;
+use crate::error::{check_fields, AppError, FieldError};
+use crate::extract::Json;
 
 /// Parse SRS request (FR-500).
 #[derive(Debug, Deserialize)]
@@ -35,7 +35,7 @@ pub async fn parse_srs(
     Json(body): Json<ParseSrsRequest>,
 ) -> Result<Json<Vec<ParsedDomain>>, AppError> {
     if body.content.trim().is_empty() {
-        return Err(AppError::BadRequest("SRS content is required".into()));
+        return Err(FieldError::new("content", "SRS content is required").into());
     }
 
     let content = body.content.clone();
@@ -95,19 +95,26 @@ pub async fn execute_scaffold(
     Json(body): Json<ExecuteScaffoldRequest>,
 ) -> Result<Json<ScaffoldResponse>, AppError> {
     // Path traversal prevention
-    if body.srs_path.contains("..") || body.output_dir.contains("..") {
-        return Err(AppError::BadRequest(
-            "paths must not contain path traversal sequences".into(),
+    let mut errors = Vec::new();
+    let srs_path = PathBuf::from(&body.srs_path);
+    if body.srs_path.contains("..") {
+        errors.push(FieldError::new(
+            "srs_path",
+            "path must not contain path traversal sequences",
+        ));
+    } else if !srs_path.exists() {
+        errors.push(FieldError::new(
+            "srs_path",
+            format!("SRS file not found: {}", body.srs_path),
         ));
     }
-
-    let srs_path = PathBuf::from(&body.srs_path);
-    if !srs_path.exists() {
-        return Err(AppError::BadRequest(format!(
-            "SRS file not found: {}",
-            body.srs_path
-        )));
+    if body.output_dir.contains("..") {
+        errors.push(FieldError::new(
+            "output_dir",
+            "path must not contain path traversal sequences",
+        ));
     }
+    check_fields(errors)?;
 
     let output_dir = PathBuf::from(&body.output_dir);
     let phases = body.phases.unwrap_or_else(|| {
@@ -142,6 +149,9 @@ pub async fn execute_scaffold(
             features: vec![],
             exclude_features: None,
             command_map_path: None,
+            template_dir: None,
+            dry_run: false,
+            merge: false,
         };
 
         doc_engine_scaffold::scaffold_from_srs(&config)
@@ -1,15 +1,18 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use axum::extract::ws::WebSocketUpgrade;
 use axum::extract::{Path as AxumPath, Query, State};
 use axum::response::IntoResponse;
-use axum::Json;
 use serde::{Deserialize, Serialize};
 
 use crate::auth::AuthUser;
-use crate::error::AppError;
+use crate::error::{check_fields, AppError, FieldError};
+use crate::extract::Json;
+use crate::flags::{FlagSnapshot, INCREMENTAL_SCAN, SCAN_DIFF};
+use crate::pagination::{Page, PageQuery};
 use crate::routes::AppState;
-use crate::ws::{handle_scan_progress_ws, ProgressMessage};
+use crate::ws::{handle_scan_progress_ws, ProgressMessage, WsBroadcaster, WsMessage};
 
 /// Create scan request (FR-300, FR-301).
 #[derive(Debug, Deserialize)]
@@ -19,6 +22,14 @@ pub struct CreateScanRequest {
     pub checks: Option<String>,
     pub phase: Option<String>,
     pub module: Option<String>,
+    /// GitHub repository (`owner/name`) the scanned tree was checked out from.
+    pub repo: Option<String>,
+    /// Commit SHA to attach the scan result to as a commit status.
+    pub commit_sha: Option<String>,
+    /// Reuse cached results for unchanged files. Doc-engine only; ignored
+    /// unless the `incremental_scan` flag is on.
+    #[serde(default)]
+    pub incremental: bool,
 }
 
 /// Scan response.
@@ -31,6 +42,118 @@ pub struct ScanResponse {
     pub started_at: String,
     pub finished_at: Option<String>,
     pub report: Option<serde_json::Value>,
+    /// Scan whose failed checks this scan re-runs; set only for partial rechecks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recheck_of: Option<String>,
+    /// True when only a subset of checks ran (a recheck of failed checks).
+    pub partial: bool,
+}
+
+impl ScanResponse {
+    fn from_row(row: crate::db::ScanRow) -> Self {
+        let report = row
+            .report_json
+            .as_ref()
+            .and_then(|j| serde_json::from_str(j).ok());
+        let recheck_of = row
+            .config_json
+            .as_ref()
+            .and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok())
+            .and_then(|c| c.get("recheck_of").and_then(|v| v.as_str()).map(String::from));
+
+        ScanResponse {
+            id: row.id,
+            project_id: row.project_id,
+            engine: row.engine,
+            status: row.status,
+            started_at: row.started_at,
+            finished_at: row.finished_at,
+            report,
+            partial: recheck_of.is_some(),
+            recheck_of,
+        }
+    }
+}
+
+/// Long-poll query parameters for scan status.
+#[derive(Debug, Deserialize)]
+pub struct ScanStatusQuery {
+    /// Seconds to hold the request while the scan is running; capped at [`MAX_STATUS_WAIT_SECS`].
+    pub wait: Option<u64>,
+}
+
+/// Query parameters for comparing two scans.
+#[derive(Debug, Deserialize)]
+pub struct DiffQuery {
+    /// Baseline scan the path scan is compared against.
+    pub against: Option<String>,
+}
+
+/// Upper bound on how long a status request may be held open.
+pub const MAX_STATUS_WAIT_SECS: u64 = 60;
+
+/// Scan status response (FR-307).
+#[derive(Debug, Serialize)]
+pub struct ScanStatusResponse {
+    pub id: String,
+    pub status: String,
+    pub completed_checks: u32,
+    pub total_checks: Option<u32>,
+}
+
+/// Export query parameters.
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    /// `summary` (default) or `full` to include each scan's complete report.
+    pub detail: Option<String>,
+}
+
+/// Completed scans fetched per database round-trip while exporting.
+const EXPORT_PAGE_SIZE: usize = 200;
+
+/// One NDJSON line of a scan history export (FR-308).
+#[derive(Debug, Serialize)]
+pub struct ScanExportLine {
+    pub scan_id: String,
+    pub project_id: String,
+    pub engine: String,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub summary: Option<serde_json::Value>,
+    /// Percentage of applicable (non-skipped) checks that passed.
+    pub coverage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<serde_json::Value>,
+}
+
+impl ScanExportLine {
+    fn from_row(row: crate::db::ScanRow, full: bool) -> Self {
+        let report: Option<serde_json::Value> = row
+            .report_json
+            .as_deref()
+            .and_then(|j| serde_json::from_str(j).ok());
+        let summary = report.as_ref().and_then(|r| r.get("summary").cloned());
+        let coverage = summary.as_ref().and_then(summary_coverage);
+
+        ScanExportLine {
+            scan_id: row.id,
+            project_id: row.project_id,
+            engine: row.engine,
+            started_at: row.started_at,
+            finished_at: row.finished_at,
+            summary,
+            coverage,
+            report: if full { report } else { None },
+        }
+    }
+}
+
+/// Percentage of applicable (non-skipped) checks that passed in a report
+/// `summary`; `None` when nothing applicable ran.
+pub(crate) fn summary_coverage(summary: &serde_json::Value) -> Option<f64> {
+    let passed = summary.get("passed")?.as_u64()?;
+    let failed = summary.get("failed")?.as_u64()?;
+    (passed + failed > 0).then(|| passed as f64 * 100.0 / (passed + failed) as f64)
 }
 
 /// Trend query parameters.
@@ -43,15 +166,151 @@ pub struct TrendQuery {
 pub async fn create_scan(
     _user: AuthUser,
     State(state): State<AppState>,
-    Json(body): Json<CreateScanRequest>,
+    flags: FlagSnapshot,
+    Json(mut body): Json<CreateScanRequest>,
 ) -> Result<(axum::http::StatusCode, Json<ScanResponse>), AppError> {
+    let mut errors = Vec::new();
+
+    // Dark-launched: without the flag an incremental request runs a full scan
+    body.incremental &= flags.is_enabled(INCREMENTAL_SCAN);
+
     // Validate engine type
     if body.engine != "doc-engine" && body.engine != "struct-engine" {
-        return Err(AppError::BadRequest(
-            "engine must be 'doc-engine' or 'struct-engine'".into(),
+        errors.push(FieldError::new(
+            "engine",
+            "engine must be 'doc-engine' or 'struct-engine'",
         ));
     }
 
+    // Validate GitHub commit metadata: both or neither
+    let github_target = match (&body.repo, &body.commit_sha) {
+        (Some(repo), Some(sha)) => {
+            if !crate::github::is_valid_repo(repo) {
+                errors.push(FieldError::new("repo", "repo must be in 'owner/name' form"));
+            }
+            if !crate::github::is_valid_commit_sha(sha) {
+                errors.push(FieldError::new(
+                    "commit_sha",
+                    "commit_sha must be a 7-40 character hex SHA",
+                ));
+            }
+            Some((repo.clone(), sha.clone()))
+        }
+        (None, None) => None,
+        (Some(_), None) => {
+            errors.push(FieldError::new(
+                "commit_sha",
+                "repo and commit_sha must be provided together",
+            ));
+            None
+        }
+        (None, Some(_)) => {
+            errors.push(FieldError::new(
+                "repo",
+                "repo and commit_sha must be provided together",
+            ));
+            None
+        }
+    };
+    check_fields(errors)?;
+
+    let response = launch_scan(&state, body, github_target, None).await?;
+    Ok((axum::http::StatusCode::ACCEPTED, Json(response)))
+}
+
+/// POST /api/v1/scans/{id}/recheck — re-run only the checks that failed in a prior scan.
+///
+/// The new scan is limited to the failed check ids and records the original
+/// scan id, so its response reports `partial: true` and `recheck_of`.
+pub async fn recheck_scan(
+    _user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+) -> Result<(axum::http::StatusCode, Json<ScanResponse>), AppError> {
+    let original = state.db.get_scan(&id)?;
+
+    if original.status != "completed" {
+        return Err(AppError::BadRequest(format!(
+            "scan is not completed (status: {})",
+            original.status
+        )));
+    }
+
+    let report: serde_json::Value = original
+        .report_json
+        .as_deref()
+        .and_then(|j| serde_json::from_str(j).ok())
+        .ok_or_else(|| AppError::NotFound("no report data available".into()))?;
+
+    let failed = failed_check_ids(&report);
+    if failed.is_empty() {
+        return Err(AppError::BadRequest("scan has no failed checks to recheck".into()));
+    }
+
+    // Keep the original phase/module filters so the recheck runs in the same context
+    let original_config: serde_json::Value = original
+        .config_json
+        .as_deref()
+        .and_then(|j| serde_json::from_str(j).ok())
+        .unwrap_or_default();
+    let config_str = |key: &str| {
+        original_config
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(String::from)
+    };
+
+    let checks = failed
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    // No GitHub target: a partial result must not overwrite the commit status
+    let body = CreateScanRequest {
+        project_id: original.project_id,
+        engine: original.engine,
+        checks: Some(checks),
+        phase: config_str("phase"),
+        module: config_str("module"),
+        repo: None,
+        commit_sha: None,
+        incremental: false,
+    };
+
+    let response = launch_scan(&state, body, None, Some(id)).await?;
+    Ok((axum::http::StatusCode::ACCEPTED, Json(response)))
+}
+
+/// Ids of checks with `status: "fail"` in a scan report, in report order.
+pub(crate) fn failed_check_ids(report: &serde_json::Value) -> Vec<u64> {
+    report
+        .get("results")
+        .and_then(|r| r.as_array())
+        .map(|results| {
+            results
+                .iter()
+                .filter(|entry| {
+                    entry
+                        .get("result")
+                        .and_then(|r| r.get("status"))
+                        .and_then(|s| s.as_str())
+                        == Some("fail")
+                })
+                .filter_map(|entry| entry.get("id").and_then(|v| v.as_u64()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Record a scan and run it in a background task; progress is streamed over
+/// the scan's WebSocket channel.
+async fn launch_scan(
+    state: &AppState,
+    body: CreateScanRequest,
+    github_target: Option<(String, String)>,
+    recheck_of: Option<String>,
+) -> Result<ScanResponse, AppError> {
     // Validate project exists
     let project = state.db.get_project(&body.project_id)?;
 
@@ -59,15 +318,21 @@ pub async fn create_scan(
         "checks": body.checks,
         "phase": body.phase,
         "module": body.module,
+        "repo": body.repo,
+        "commit_sha": body.commit_sha,
+        "recheck_of": recheck_of,
+        "incremental": body.incremental,
     }))
     .unwrap();
 
-    // Acquire scan semaphore permit
-    let _permit = state.scan_semaphore.acquire().await?;
+    // Acquire scan semaphore permit; it moves into the scan task below so it
+    // is held until the scan finishes
+    let permit = state.scan_semaphore.acquire().await?;
 
     let scan = state
         .db
         .create_scan(&body.project_id, &body.engine, Some(&config_json))?;
+    state.metrics.scan_started();
 
     let scan_id = scan.id.clone();
     let response = ScanResponse {
@@ -78,7 +343,10 @@ pub async fn create_scan(
         started_at: scan.started_at.clone(),
         finished_at: None,
         report: None,
+        partial: recheck_of.is_some(),
+        recheck_of,
     };
+    let partial = response.partial;
 
     // Create WebSocket broadcast channel for this scan
     let tx = state.ws_broadcaster.create_channel(&scan_id).await;
@@ -90,48 +358,76 @@ pub async fn create_scan(
     let root_path = PathBuf::from(&project.root_path);
     let scope_str = project.scope.clone();
     let ptype_str = project.project_type.clone();
-    let checks_filter = body.checks.clone();
-    let phase_filter = body.phase.clone();
-    let module_filter = body.module.clone();
+    let github = state.config.github.clone();
+    let status_engine = body.engine.clone();
+    let metrics = state.metrics.clone();
+
+    let mut relay = ProgressRelay::new(&scan_id, broadcaster.clone());
 
     tokio::spawn(async move {
+        let started = std::time::Instant::now();
         let result = tokio::task::spawn_blocking(move || {
             if engine == "doc-engine" {
                 run_doc_scan(
                     &root_path,
                     &scope_str,
                     &ptype_str,
-                    checks_filter.as_deref(),
-                    phase_filter.as_deref(),
-                    module_filter.as_deref(),
+                    &body,
+                    &mut |event| relay.relay(event),
                 )
             } else {
-                run_struct_scan(&root_path, checks_filter.as_deref())
+                run_struct_scan(&root_path, body.checks.as_deref())
             }
         })
         .await;
+        metrics.scan_finished(matches!(result, Ok(Ok(_))), started.elapsed());
+        drop(permit);
 
-        match result {
+        let report_json = match result {
             Ok(Ok(report_json)) => {
                 let _ = db.finish_scan(&scan_id, "completed", Some(&report_json));
+                Some(report_json)
             }
             Ok(Err(err)) => {
                 let error_json = serde_json::json!({"error": err}).to_string();
                 let _ = db.finish_scan(&scan_id, "failed", Some(&error_json));
+                None
             }
             Err(e) => {
                 let error_json =
                     serde_json::json!({"error": format!("task panicked: {e}")}).to_string();
                 let _ = db.finish_scan(&scan_id, "failed", Some(&error_json));
+                None
             }
+        };
+
+        // Best-effort GitHub commit status for scans started from a known commit
+        if let (Some(github), Some((repo, sha))) = (github, github_target) {
+            let status = crate::github::status_for_scan(
+                &status_engine,
+                report_json.as_deref(),
+                crate::github::report_url(&github, &scan_id),
+            );
+            match crate::github::post_commit_status(&github, &repo, &sha, &status).await {
+                Ok(()) => tracing::info!("posted {} status for {repo}@{sha}", status.state),
+                Err(e) => tracing::warn!("failed to post GitHub status for {repo}@{sha}: {e}"),
+            }
+        }
+
+        // Threshold alerts judge full scans only; a recheck's summary covers a subset
+        if report_json.is_some() && !partial {
+            crate::alerts::notify_threshold_breach(&db, &scan_id).await;
         }
 
+        // Scan event webhooks deliver and retry in their own tasks
+        crate::webhooks::notify_scan_finished(&db, &scan_id).await;
+
         // Signal completion on the WebSocket channel
         let _ = tx.send("__DONE__".into());
         broadcaster.remove_channel(&scan_id).await;
     });
 
-    Ok((axum::http::StatusCode::ACCEPTED, Json(response)))
+    Ok(response)
 }
 
 /// GET /api/v1/scans/{id} — return scan result or in_progress status (FR-303, FR-1100).
@@ -141,21 +437,114 @@ pub async fn get_scan(
     AxumPath(id): AxumPath<String>,
 ) -> Result<Json<ScanResponse>, AppError> {
     let scan = state.db.get_scan(&id)?;
+    Ok(Json(ScanResponse::from_row(scan)))
+}
 
-    let report = scan
-        .report_json
-        .as_ref()
-        .and_then(|j| serde_json::from_str(j).ok());
-
-    Ok(Json(ScanResponse {
-        id: scan.id,
-        project_id: scan.project_id,
-        engine: scan.engine,
-        status: scan.status,
-        started_at: scan.started_at,
-        finished_at: scan.finished_at,
-        report,
-    }))
+/// GET /api/v1/scans/{id}/status?wait=N — long-poll scan status (FR-307).
+///
+/// While the scan is running the request is held for up to `wait` seconds and
+/// returns as soon as progress is reported or the scan finishes.
+pub async fn scan_status(
+    _user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+    Query(query): Query<ScanStatusQuery>,
+) -> Result<Json<ScanStatusResponse>, AppError> {
+    let scan = state.db.get_scan(&id)?;
+    let wait = query.wait.unwrap_or(0).min(MAX_STATUS_WAIT_SECS);
+
+    if scan.status == "running" && wait > 0 {
+        if let Some(mut rx) = state.ws_broadcaster.subscribe(&id).await {
+            let timeout = std::time::Duration::from_secs(wait);
+            // Any message is a change: a new completed check or the completion marker
+            let _ = tokio::time::timeout(timeout, rx.recv()).await;
+        }
+    }
+
+    // Re-read so a scan that finished while waiting reports its final status
+    let scan = state.db.get_scan(&id)?;
+    let response = if scan.status == "running" {
+        let progress = state.ws_broadcaster.latest_progress(&id).await;
+        ScanStatusResponse {
+            id: scan.id,
+            status: scan.status,
+            completed_checks: progress.as_ref().map_or(0, |p| p.current),
+            total_checks: progress.map(|p| p.total),
+        }
+    } else {
+        let checks = scan
+            .report_json
+            .as_deref()
+            .and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok())
+            .and_then(|r| r.get("results").and_then(|v| v.as_array()).map(|a| a.len() as u32));
+        ScanStatusResponse {
+            id: scan.id,
+            status: scan.status,
+            completed_checks: checks.unwrap_or(0),
+            total_checks: checks,
+        }
+    };
+
+    Ok(Json(response))
+}
+
+/// GET /api/v1/projects/{id}/scans/export.ndjson — stream completed scan history (FR-308).
+///
+/// Rows are read a page at a time and written as they are fetched, so the
+/// export never holds a project's full history in memory.
+pub async fn export_project_scans(
+    _user: AuthUser,
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<String>,
+    Query(query): Query<ExportQuery>,
+) -> Result<axum::response::Response, AppError> {
+    let full = match query.detail.as_deref() {
+        None | Some("summary") => false,
+        Some("full") => true,
+        Some(_) => {
+            return Err(FieldError::new("detail", "detail must be 'summary' or 'full'").into());
+        }
+    };
+
+    // Validate project exists
+    let _ = state.db.get_project(&id)?;
+
+    // State: Some(cursor) while pages remain; the cursor is None before the first page
+    let db = state.db.clone();
+    let pages = futures::stream::unfold(Some(None::<(String, String)>), move |page| {
+        let db = db.clone();
+        let project_id = id.clone();
+        async move {
+            let cursor = page?;
+            let after = cursor.as_ref().map(|(ts, scan_id)| (ts.as_str(), scan_id.as_str()));
+            let rows = match db.list_completed_scans_page(&project_id, after, EXPORT_PAGE_SIZE) {
+                Ok(rows) => rows,
+                Err(e) => return Some((Err(std::io::Error::other(e.to_string())), None)),
+            };
+            if rows.is_empty() {
+                return None;
+            }
+
+            let next = (rows.len() == EXPORT_PAGE_SIZE).then(|| {
+                let last = &rows[rows.len() - 1];
+                Some((last.started_at.clone(), last.id.clone()))
+            });
+            let mut chunk = String::new();
+            for row in rows {
+                if let Ok(line) = serde_json::to_string(&ScanExportLine::from_row(row, full)) {
+                    chunk.push_str(&line);
+                    chunk.push('\n');
+                }
+            }
+            Some((Ok(chunk), next))
+        }
+    });
+
+    Ok((
+        [(axum::http::header::CONTENT_TYPE, "application/x-ndjson")],
+        axum::body::Body::from_stream(pages),
+    )
+        .into_response())
 }
 
 /// WS /api/v1/scans/{id}/progress — stream per-check progress (FR-302).
@@ -169,37 +558,67 @@ pub async fn scan_progress_ws(
     ws.on_upgrade(move |socket| handle_scan_progress_ws(socket, broadcaster, id))
 }
 
-/// GET /api/v1/projects/{id}/scans — scan history (FR-305).
+/// GET /api/v1/projects/{id}/scans?limit=&offset= — scan history (FR-305).
 pub async fn list_project_scans(
     _user: AuthUser,
     State(state): State<AppState>,
     AxumPath(id): AxumPath<String>,
-) -> Result<Json<Vec<ScanResponse>>, AppError> {
+    Query(query): Query<PageQuery>,
+) -> Result<Json<Page<ScanResponse>>, AppError> {
+    let page = query.params()?;
     // Validate project exists
     let _ = state.db.get_project(&id)?;
 
-    let scans = state.db.list_scans_for_project(&id)?;
-    let responses: Vec<ScanResponse> = scans
-        .into_iter()
-        .map(|s| {
-            let report = s
-                .report_json
-                .as_ref()
-                .and_then(|j| serde_json::from_str(j).ok());
-
-            ScanResponse {
-                id: s.id,
-                project_id: s.project_id,
-                engine: s.engine,
-                status: s.status,
-                started_at: s.started_at,
-                finished_at: s.finished_at,
-                report,
-            }
-        })
-        .collect();
+    let total = state.db.count_scans_for_project(&id)?;
+    let scans = state.db.list_scans_for_project(&id, page.limit, page.offset)?;
+    let responses: Vec<ScanResponse> = scans.into_iter().map(ScanResponse::from_row).collect();
 
-    Ok(Json(responses))
+    Ok(Json(Page::new(responses, total, page)))
+}
+
+/// GET /api/v1/scans/{id}/diff?against={other_id} — checks that regressed or
+/// were fixed between the `against` scan and this one (FR-309).
+///
+/// Dark-launched behind the `scan_diff` flag; responds 404 while it is off.
+pub async fn diff_scans(
+    _user: AuthUser,
+    State(state): State<AppState>,
+    flags: FlagSnapshot,
+    AxumPath(id): AxumPath<String>,
+    Query(query): Query<DiffQuery>,
+) -> Result<Json<doc_engine_scan::ReportDiff>, AppError> {
+    flags.require(SCAN_DIFF)?;
+
+    let Some(against) = query.against.filter(|a| !a.trim().is_empty()) else {
+        return Err(AppError::Validation(vec![FieldError::new(
+            "against",
+            "against must name the scan to compare with",
+        )]));
+    };
+
+    let new = doc_report(&state.db.get_scan(&id)?)?;
+    let old = doc_report(&state.db.get_scan(against.trim())?)?;
+    Ok(Json(doc_engine_scan::diff_reports(&old, &new)))
+}
+
+/// Parse a completed doc-engine scan's stored report.
+fn doc_report(scan: &crate::db::ScanRow) -> Result<doc_engine_scan::ScanReport, AppError> {
+    if scan.engine != "doc-engine" {
+        return Err(AppError::BadRequest(format!(
+            "scan {} was run by {}; only doc-engine scans can be diffed",
+            scan.id, scan.engine
+        )));
+    }
+    if scan.status != "completed" {
+        return Err(AppError::BadRequest(format!(
+            "scan {} is {}, not completed",
+            scan.id, scan.status
+        )));
+    }
+    scan.report_json
+        .as_deref()
+        .and_then(|json| serde_json::from_str(json).ok())
+        .ok_or_else(|| AppError::Internal(format!("scan {} has an unreadable report", scan.id)))
 }
 
 /// GET /api/v1/projects/{id}/trends — aggregate pass/fail/skip over time (FR-202).
@@ -214,14 +633,74 @@ pub async fn get_trends(
     Ok(Json(trends))
 }
 
-/// Run a doc-engine scan.
+/// Forwards doc-engine progress events from the blocking scan thread to the
+/// scan's WebSocket channel: a `check_started`/`check_finished` log plus the
+/// `progress` counter after each finished check.
+struct ProgressRelay {
+    scan_id: String,
+    broadcaster: WsBroadcaster,
+    runtime: tokio::runtime::Handle,
+    current: u32,
+    total: u32,
+}
+
+impl ProgressRelay {
+    /// Must be created on the runtime; the scan thread sends through its handle.
+    fn new(scan_id: &str, broadcaster: WsBroadcaster) -> Self {
+        Self {
+            scan_id: scan_id.to_string(),
+            broadcaster,
+            runtime: tokio::runtime::Handle::current(),
+            current: 0,
+            total: 0,
+        }
+    }
+
+    fn relay(&mut self, event: doc_engine_scan::ProgressEvent) {
+        use doc_engine_scan::{CheckResult, ProgressEvent};
+
+        let messages = match event {
+            ProgressEvent::ScanStarted { total } => {
+                self.total = total as u32;
+                return;
+            }
+            ProgressEvent::CheckStarted { id, description } => {
+                vec![WsMessage::CheckStarted { id: u32::from(id.0), name: description }]
+            }
+            ProgressEvent::CheckFinished { id, description, result } => {
+                let status = match result {
+                    CheckResult::Pass => "pass",
+                    CheckResult::Fail { .. } => "fail",
+                    CheckResult::Skip { .. } => "skip",
+                };
+                self.current += 1;
+                vec![
+                    WsMessage::CheckFinished { id: u32::from(id.0), status: status.to_string() },
+                    WsMessage::Progress(ProgressMessage {
+                        scan_id: self.scan_id.clone(),
+                        check_id: u32::from(id.0),
+                        check_description: description,
+                        status: status.to_string(),
+                        current: self.current,
+                        total: self.total,
+                    }),
+                ]
+            }
+        };
+        for msg in &messages {
+            self.runtime.block_on(self.broadcaster.send(&self.scan_id, msg));
+        }
+    }
+}
+
+/// Run a doc-engine scan with the filters of `request`, reporting per-check
+/// progress to `on_event`.
 fn run_doc_scan(
     root: &std::path::Path,
     scope: &str,
     project_type: &str,
-    checks: Option<&str>,
-    phases: Option<&str>,
-    module: Option<&str>,
+    request: &CreateScanRequest,
+    on_event: &mut dyn FnMut(doc_engine_scan::ProgressEvent),
 ) -> Result<String, String> {
     use doc_engine_scan::{ProjectScope, ProjectType, ScanConfig};
 
@@ -236,19 +715,19 @@ fn run_doc_scan(
         _ => Some(ProjectType::OpenSource),
     };
 
-    let checks_vec: Option<Vec<u8>> = checks.map(|c| {
+    let checks_vec: Option<Vec<u8>> = request.checks.as_deref().map(|c| {
         c.split(',')
             .filter_map(|s| s.trim().parse().ok())
             .collect()
     });
 
-    let phases_vec: Option<Vec<String>> = phases.map(|p| {
+    let phases_vec: Option<Vec<String>> = request.phase.as_deref().map(|p| {
         p.split(',')
             .map(|s| s.trim().to_string())
             .collect()
     });
 
-    let module_vec: Option<Vec<String>> = module.map(|m| {
+    let module_vec: Option<Vec<String>> = request.module.as_deref().map(|m| {
         m.split(',')
             .map(|s| s.trim().to_string())
             .collect()
@@ -261,9 +740,15 @@ fn run_doc_scan(
         rules_path: None,
         phases: phases_vec,
         module_filter: module_vec,
+        // Same location the CLI uses by default, so both share one cache
+        cache_dir: request.incremental.then(|| root.join("target/doc-engine-cache")),
+        jobs: None,
+        exclude_globs: Vec::new(),
+        severity_overrides: HashMap::new(),
+        check_timeout_ms: None,
     };
 
-    match doc_engine_scan::scan_with_config(root, &config) {
+    match doc_engine_scan::scan_with_progress(root, &config, on_event) {
         Ok(report) => {
             let json = doc_engine_scan::format_report_json(&report);
             Ok(json)
@@ -289,6 +774,7 @@ fn run_struct_scan(
         project_kind: None,
         checks: checks_vec,
         rules_path: None,
+        ..ScanConfig::default()
     };
 
     match struct_engine::scan_with_config(root, &config) {
@@ -1,11 +1,11 @@
 use std::path::{Path, PathBuf};
 
 use axum::extract::{Path as AxumPath, State};
-use axum::Json;
 use serde::{Deserialize, Serialize};
 
 use crate::auth::AuthUser;
 use crate::error::AppError;
+use crate::extract::Json;
 use crate::routes::AppState;
 
 /// Template metadata.
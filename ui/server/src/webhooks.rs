@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::alerts::post_webhook;
+use crate::db::{Db, WebhookDeliveryRow};
+
+/// Scan events a webhook can subscribe to (FR-106).
+pub const WEBHOOK_EVENTS: &[&str] = &["scan_completed", "scan_failed"];
+
+/// Retries after the first failed attempt before a delivery is marked `failed`.
+const MAX_RETRIES: u32 = 3;
+
+/// Wait before the first retry; doubled for each retry after that.
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Payload POSTed to a webhook when a scan finishes (FR-106).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScanEvent {
+    /// `scan_completed` or `scan_failed`.
+    pub event: String,
+    pub scan_id: String,
+    pub project_id: String,
+    /// The report summary; `None` for failed scans.
+    pub summary: Option<serde_json::Value>,
+}
+
+/// Queue a notification for every webhook subscribed to the finished scan's
+/// event and deliver them in the background.
+///
+/// Each delivery is recorded before the first attempt, and
+/// [`redeliver_pending`] resumes unfinished ones at startup, so neither an
+/// outage nor a restart loses an event. Lookup failures are logged, never
+/// returned.
+pub async fn notify_scan_finished(db: &Db, scan_id: &str) {
+    let scan = match db.get_scan(scan_id) {
+        Ok(scan) => scan,
+        Err(e) => {
+            tracing::warn!("webhooks skipped for scan {scan_id}: {e}");
+            return;
+        }
+    };
+    let event = if scan.status == "completed" { "scan_completed" } else { "scan_failed" };
+    let hooks = match db.webhooks_for_event(&scan.project_id, event) {
+        Ok(hooks) => hooks,
+        Err(e) => {
+            tracing::warn!("webhooks skipped for scan {scan_id}: {e}");
+            return;
+        }
+    };
+    if hooks.is_empty() {
+        return;
+    }
+
+    let summary = scan
+        .report_json
+        .as_deref()
+        .filter(|_| event == "scan_completed")
+        .and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok())
+        .and_then(|report| report.get("summary").cloned());
+    let payload = ScanEvent {
+        event: event.into(),
+        scan_id: scan.id.clone(),
+        project_id: scan.project_id.clone(),
+        summary,
+    };
+    let payload_json = serde_json::to_string(&payload).unwrap();
+
+    for hook in hooks {
+        match db.create_webhook_delivery(&hook.id, &scan.id, event, &payload_json) {
+            Ok(delivery) => {
+                let db = db.clone();
+                tokio::spawn(async move { deliver(&db, &hook.url, delivery).await });
+            }
+            Err(e) => tracing::warn!("failed to record webhook delivery for scan {scan_id}: {e}"),
+        }
+    }
+}
+
+/// Resume every delivery still `pending`, e.g. one whose retries were cut
+/// short by a server restart. Returns how many were re-queued.
+pub fn redeliver_pending(db: &Db) -> usize {
+    let pending = match db.pending_webhook_deliveries() {
+        Ok(pending) => pending,
+        Err(e) => {
+            tracing::warn!("pending webhook deliveries not resumed: {e}");
+            return 0;
+        }
+    };
+    let count = pending.len();
+    for (url, delivery) in pending {
+        let db = db.clone();
+        tokio::spawn(async move { deliver(&db, &url, delivery).await });
+    }
+    count
+}
+
+/// POST a recorded delivery, retrying with exponential backoff.
+///
+/// A resumed delivery continues from its recorded attempt count, so the
+/// retry budget and backoff span restarts.
+async fn deliver(db: &Db, url: &str, delivery: WebhookDeliveryRow) {
+    let payload: serde_json::Value = serde_json::from_str(&delivery.payload).unwrap_or_default();
+    let mut backoff = RETRY_BACKOFF * 2u32.pow(delivery.attempts);
+
+    for attempt in delivery.attempts..=MAX_RETRIES {
+        match post_webhook(url, &payload).await {
+            Ok(()) => {
+                let _ = db.record_webhook_attempt(&delivery.id, "delivered", None);
+                tracing::info!("delivered {} webhook for scan {}", delivery.event, delivery.scan_id);
+                return;
+            }
+            Err(e) => {
+                let exhausted = attempt == MAX_RETRIES;
+                let status = if exhausted { "failed" } else { "pending" };
+                let _ = db.record_webhook_attempt(&delivery.id, status, Some(&e));
+                tracing::warn!(
+                    "webhook delivery {} attempt {} failed: {e}",
+                    delivery.id,
+                    attempt + 1
+                );
+                if exhausted {
+                    return;
+                }
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+    }
+}
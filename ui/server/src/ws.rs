@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 use futures::{SinkExt, StreamExt};
@@ -17,22 +17,52 @@ pub struct ProgressMessage {
     pub total: u32,
 }
 
+/// Message sent over a scan's WebSocket channel, tagged by `type`.
+///
+/// `progress` keeps every field of [`ProgressMessage`] at the top level, so
+/// clients that read only the counters are unaffected by the check events.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WsMessage {
+    Progress(ProgressMessage),
+    /// A check began running.
+    CheckStarted { id: u32, name: String },
+    /// A check's result is final; `status` is `pass`, `fail` or `skip`.
+    CheckFinished { id: u32, status: String },
+}
+
+/// Broadcast buffer per scan; a full doc-engine scan sends three messages per check.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Finished scans whose message log is kept for clients that connect late.
+const FINISHED_LOG_RETENTION: usize = 32;
+
 /// Manages WebSocket broadcast channels for scan progress.
 #[derive(Debug, Clone)]
 pub struct WsBroadcaster {
     channels: Arc<RwLock<HashMap<String, broadcast::Sender<String>>>>,
+    /// Most recent progress per scan, so pollers joining mid-scan see the current count.
+    latest: Arc<RwLock<HashMap<String, ProgressMessage>>>,
+    /// Every message sent per scan, replayed to clients that connect after it
+    /// started. A fast scan can finish before the client's socket is open.
+    logs: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    /// Finished scans with a retained log, oldest first.
+    finished: Arc<RwLock<VecDeque<String>>>,
 }
 
 impl WsBroadcaster {
     pub fn new() -> Self {
         Self {
             channels: Arc::new(RwLock::new(HashMap::new())),
+            latest: Arc::new(RwLock::new(HashMap::new())),
+            logs: Arc::new(RwLock::new(HashMap::new())),
+            finished: Arc::new(RwLock::new(VecDeque::new())),
         }
     }
 
     /// Create a new broadcast channel for a scan.
     pub async fn create_channel(&self, scan_id: &str) -> broadcast::Sender<String> {
-        let (tx, _) = broadcast::channel(256);
+        let (tx, _) = broadcast::channel(CHANNEL_CAPACITY);
         self.channels
             .write()
             .await
@@ -49,15 +79,68 @@ impl WsBroadcaster {
             .map(|tx| tx.subscribe())
     }
 
-    /// Remove a channel when a scan completes.
+    /// Subscribe to a scan's channel together with the messages already sent.
+    ///
+    /// The log and the receiver are taken atomically with respect to
+    /// [`send`](Self::send), so no message is missed or seen twice. Returns
+    /// `None` for an unknown scan; a finished scan whose log is still
+    /// retained yields its log and no receiver.
+    pub async fn subscribe_with_log(
+        &self,
+        scan_id: &str,
+    ) -> Option<(Vec<String>, Option<broadcast::Receiver<String>>)> {
+        let channels = self.channels.write().await;
+        let rx = channels.get(scan_id).map(|tx| tx.subscribe());
+        let log = self.logs.read().await.get(scan_id).cloned();
+        match (log, rx) {
+            (None, None) => None,
+            (log, rx) => Some((log.unwrap_or_default(), rx)),
+        }
+    }
+
+    /// Remove a channel when a scan completes. Its message log is retained
+    /// for the most recent finished scans only.
     pub async fn remove_channel(&self, scan_id: &str) {
         self.channels.write().await.remove(scan_id);
+        self.latest.write().await.remove(scan_id);
+
+        let mut finished = self.finished.write().await;
+        finished.push_back(scan_id.to_string());
+        while finished.len() > FINISHED_LOG_RETENTION {
+            if let Some(expired) = finished.pop_front() {
+                self.logs.write().await.remove(&expired);
+            }
+        }
+    }
+
+    /// The last progress message sent for a running scan, if any.
+    pub async fn latest_progress(&self, scan_id: &str) -> Option<ProgressMessage> {
+        self.latest.read().await.get(scan_id).cloned()
     }
 
     /// Send a progress message to all subscribers of a scan.
     pub async fn send_progress(&self, scan_id: &str, msg: &ProgressMessage) {
-        if let Some(tx) = self.channels.read().await.get(scan_id) {
+        self.send(scan_id, &WsMessage::Progress(msg.clone())).await;
+    }
+
+    /// Send a message to all subscribers of a scan; progress messages also
+    /// become the scan's latest progress.
+    pub async fn send(&self, scan_id: &str, msg: &WsMessage) {
+        if let WsMessage::Progress(progress) = msg {
+            self.latest
+                .write()
+                .await
+                .insert(scan_id.to_string(), progress.clone());
+        }
+        let channels = self.channels.read().await;
+        if let Some(tx) = channels.get(scan_id) {
             if let Ok(json) = serde_json::to_string(msg) {
+                self.logs
+                    .write()
+                    .await
+                    .entry(scan_id.to_string())
+                    .or_default()
+                    .push(json.clone());
                 let _ = tx.send(json);
             }
         }
@@ -68,8 +151,7 @@ impl WsBroadcaster {
 pub async fn handle_scan_progress_ws(socket: WebSocket, broadcaster: WsBroadcaster, scan_id: String) {
     let (mut sender, mut receiver) = socket.split();
 
-    let rx = broadcaster.subscribe(&scan_id).await;
-    let Some(mut rx) = rx else {
+    let Some((log, rx)) = broadcaster.subscribe_with_log(&scan_id).await else {
         let _ = sender
             .send(Message::Text(
                 serde_json::json!({"error": "scan not found or already completed"}).to_string().into(),
@@ -78,8 +160,21 @@ pub async fn handle_scan_progress_ws(socket: WebSocket, broadcaster: WsBroadcast
         return;
     };
 
-    // Forward broadcast messages to the WebSocket client
+    // Replay what was sent before this client connected, then forward live messages
     let send_task = tokio::spawn(async move {
+        for msg in log {
+            if sender.send(Message::Text(msg.into())).await.is_err() {
+                return;
+            }
+        }
+        let Some(mut rx) = rx else {
+            let _ = sender
+                .send(Message::Text(
+                    serde_json::json!({"status": "completed"}).to_string().into(),
+                ))
+                .await;
+            return;
+        };
         while let Ok(msg) = rx.recv().await {
             if msg == "__DONE__" {
                 let _ = sender
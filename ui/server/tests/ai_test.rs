@@ -1,5 +1,6 @@
 use axum::http::StatusCode;
 use serde_json::json;
+use swe_compliance_server::db::Db;
 use tower::ServiceExt;
 
 mod common;
@@ -15,7 +16,7 @@ async fn test_ai_status_disabled() {
     let body = common::body_json(response).await;
 
     // AI is disabled in test config
-    assert_eq!(body["enabled"].as_bool().unwrap(), false);
+    assert!(!body["enabled"].as_bool().unwrap());
     assert!(body["provider"].is_null());
 }
 
@@ -196,3 +197,37 @@ async fn test_ai_status_returns_json_structure() {
     assert!(body.get("provider").is_some());
     assert!(body["enabled"].is_boolean());
 }
+
+#[tokio::test]
+async fn test_ai_chat_accepts_session_id() {
+    let (app, _tmp) = common::test_app();
+
+    let req = common::post_json(
+        "/api/v1/ai/chat",
+        &json!({"message": "And for deployment docs?", "session_id": "s-1"}),
+    );
+    let response = app.oneshot(req).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[test]
+fn test_chat_session_keeps_recent_window_per_user() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+
+    for i in 0..3 {
+        db.append_chat_message("alice", "s-1", "user", &format!("question {i}")).unwrap();
+        db.append_chat_message("alice", "s-1", "assistant", &format!("answer {i}")).unwrap();
+    }
+    db.append_chat_message("alice", "s-2", "user", "other session").unwrap();
+
+    // Oldest dropped, order preserved
+    let recent = db.recent_chat_messages("alice", "s-1", 3).unwrap();
+    let contents: Vec<&str> = recent.iter().map(|m| m.content.as_str()).collect();
+    assert_eq!(contents, vec!["answer 1", "question 2", "answer 2"]);
+    assert_eq!(recent[1].role, "user");
+
+    assert_eq!(db.recent_chat_messages("alice", "s-1", 20).unwrap().len(), 6);
+    assert!(db.recent_chat_messages("bob", "s-1", 20).unwrap().is_empty());
+}
@@ -0,0 +1,268 @@
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::json;
+use swe_compliance_server::alerts::{notify_threshold_breach, threshold_breach};
+use swe_compliance_server::db::{AlertRow, Db, ScanRow};
+use tower::ServiceExt;
+
+fn alert(max_failures: Option<u32>, min_coverage: Option<f64>, on_new_failures: bool) -> AlertRow {
+    AlertRow {
+        project_id: "p1".into(),
+        webhook_url: "http://127.0.0.1:1/hook".into(),
+        max_failures,
+        min_coverage,
+        on_new_failures,
+        updated_at: String::new(),
+    }
+}
+
+/// A completed scan whose report fails the given check ids out of `total` applicable checks.
+fn scan(id: &str, failed: &[u64], total: u64) -> ScanRow {
+    let results: Vec<_> = (1..=total)
+        .map(|check| {
+            let status = if failed.contains(&check) { "fail" } else { "pass" };
+            json!({"id": check, "result": {"status": status}})
+        })
+        .collect();
+    let report = json!({
+        "summary": {"total": total, "passed": total - failed.len() as u64, "failed": failed.len(), "skipped": 0},
+        "results": results,
+    });
+    ScanRow {
+        id: id.into(),
+        project_id: "p1".into(),
+        engine: "doc-engine".into(),
+        status: "completed".into(),
+        started_at: String::new(),
+        finished_at: None,
+        report_json: Some(report.to_string()),
+        config_json: None,
+    }
+}
+
+/// Test that exceeding max_failures and falling below min_coverage are both reported.
+#[test]
+fn test_threshold_breach_limits() {
+    let breach = threshold_breach(&alert(Some(1), Some(80.0), false), &scan("s1", &[1, 2], 4), None)
+        .expect("thresholds should be breached");
+
+    assert_eq!(breach.event, "threshold_breached");
+    assert_eq!(breach.scan_id, "s1");
+    assert_eq!(breach.coverage, Some(50.0));
+    assert_eq!(
+        breach.reasons,
+        vec![
+            "2 failed checks exceed the limit of 1",
+            "coverage 50.0% is below the minimum of 80%",
+        ]
+    );
+}
+
+/// Test that a scan within every limit does not alert.
+#[test]
+fn test_threshold_breach_within_limits() {
+    let alert = alert(Some(2), Some(50.0), false);
+    assert!(threshold_breach(&alert, &scan("s1", &[1, 2], 4), None).is_none());
+}
+
+/// Test that on_new_failures compares against the previous scan.
+#[test]
+fn test_threshold_breach_new_failures() {
+    let alert = alert(None, None, true);
+    let previous = scan("s1", &[1], 4);
+
+    let breach = threshold_breach(&alert, &scan("s2", &[1, 3], 4), Some(&previous))
+        .expect("check 3 is newly failing");
+    assert_eq!(breach.reasons, vec!["newly failing checks: 3"]);
+    assert_eq!(breach.previous_scan_id.as_deref(), Some("s1"));
+
+    assert!(threshold_breach(&alert, &scan("s2", &[1], 4), Some(&previous)).is_none());
+    assert!(threshold_breach(&alert, &scan("s2", &[1, 3], 4), None).is_none());
+}
+
+/// Test that alert settings round-trip through the project settings endpoints.
+#[tokio::test]
+async fn test_alerts_save_get_delete() {
+    // testuser is an admin in this app
+    let (app, tmp) = common::test_app_with_flags("{}");
+    let project = Db::open(&tmp.path().join("test.db"))
+        .unwrap()
+        .create_project("alerts", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/alerts", project.id);
+
+    let response = app.clone().oneshot(common::get(&uri)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(common::body_json(response).await["configured"], false);
+
+    let body = json!({"webhook_url": "https://hooks.example.com/x", "max_failures": 10, "min_coverage": 80.0});
+    let response = app.clone().oneshot(common::put_json(&uri, &body)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = common::body_json(app.clone().oneshot(common::get(&uri)).await.unwrap()).await;
+    assert_eq!(body["configured"], true);
+    assert_eq!(body["webhook_url"], "https://hooks.example.com/x");
+    assert_eq!(body["max_failures"], 10);
+    assert_eq!(body["min_coverage"], 80.0);
+    assert_eq!(body["on_new_failures"], false);
+
+    let response = app.clone().oneshot(common::delete(&uri)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let body = common::body_json(app.oneshot(common::get(&uri)).await.unwrap()).await;
+    assert_eq!(body["configured"], false);
+}
+
+/// Test that invalid alert settings report each invalid field.
+#[tokio::test]
+async fn test_alerts_validation() {
+    let (app, tmp) = common::test_app_with_flags("{}");
+    let project = Db::open(&tmp.path().join("test.db"))
+        .unwrap()
+        .create_project("alerts", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/alerts", project.id);
+
+    let body = json!({"webhook_url": "ftp://x", "min_coverage": 120.0});
+    let response = app.oneshot(common::put_json(&uri, &body)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = common::body_json(response).await;
+    let fields: Vec<&str> = body["error"]["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["webhook_url", "min_coverage"]);
+}
+
+/// Test that non-admin users cannot change alert settings.
+#[tokio::test]
+async fn test_alerts_save_requires_admin() {
+    let (app, tmp) = common::test_app();
+    let project = Db::open(&tmp.path().join("test.db"))
+        .unwrap()
+        .create_project("alerts", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/alerts", project.id);
+
+    let body = json!({"webhook_url": "https://hooks.example.com/x", "max_failures": 0});
+    let response = app.oneshot(common::put_json(&uri, &body)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+/// Test that a breaching scan is POSTed to the project's webhook.
+#[tokio::test]
+async fn test_notify_threshold_breach_posts_payload() {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<serde_json::Value>();
+    let receiver = axum::Router::new().route(
+        "/hook",
+        axum::routing::post(move |axum::Json(payload): axum::Json<serde_json::Value>| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send(payload);
+                StatusCode::NO_CONTENT
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+
+    let tmp = tempfile::TempDir::new().unwrap();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("alerts", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    db.save_alerts(&project.id, &format!("http://{addr}/hook"), Some(0), None, false)
+        .unwrap();
+    let scan = db.create_scan(&project.id, "doc-engine", None).unwrap();
+    let report = json!({"summary": {"total": 2, "passed": 1, "failed": 1, "skipped": 0}, "results": []});
+    db.finish_scan(&scan.id, "completed", Some(&report.to_string()))
+        .unwrap();
+
+    notify_threshold_breach(&db, &scan.id).await;
+
+    let payload = rx.try_recv().expect("webhook should have received the breach");
+    assert_eq!(payload["event"], "threshold_breached");
+    assert_eq!(payload["scan_id"], scan.id);
+    assert_eq!(payload["project_id"], project.id);
+    assert_eq!(payload["summary"]["failed"], 1);
+    assert_eq!(payload["reasons"][0], "1 failed checks exceed the limit of 0");
+}
+
+/// Test that alert settings report whether the latest completed scan breaches them.
+#[tokio::test]
+async fn test_alerts_status_reflects_latest_scan() {
+    let (app, tmp) = common::test_app_with_flags("{}");
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("alerts", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    db.save_alerts(&project.id, "https://hooks.example.com/x", Some(0), None, false)
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/alerts", project.id);
+
+    let body = common::body_json(app.clone().oneshot(common::get(&uri)).await.unwrap()).await;
+    assert_eq!(body["status"], serde_json::Value::Null);
+
+    let scan = db.create_scan(&project.id, "doc-engine", None).unwrap();
+    let report = json!({"summary": {"total": 2, "passed": 1, "failed": 1, "skipped": 0}, "results": []});
+    db.finish_scan(&scan.id, "completed", Some(&report.to_string()))
+        .unwrap();
+
+    let body = common::body_json(app.oneshot(common::get(&uri)).await.unwrap()).await;
+    assert_eq!(body["status"], "breached");
+    assert_eq!(body["latest_scan_id"], scan.id);
+    assert_eq!(body["reasons"][0], "1 failed checks exceed the limit of 0");
+}
+
+/// Test that a test notification requires stored alert settings.
+#[tokio::test]
+async fn test_alerts_test_notification_requires_settings() {
+    let (app, tmp) = common::test_app_with_flags("{}");
+    let project = Db::open(&tmp.path().join("test.db"))
+        .unwrap()
+        .create_project("alerts", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/alerts/test", project.id);
+
+    let response = app.oneshot(common::post_json(&uri, &json!({}))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Test that a test notification is POSTed to the stored webhook.
+#[tokio::test]
+async fn test_alerts_test_notification_posts_payload() {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<serde_json::Value>();
+    let receiver = axum::Router::new().route(
+        "/hook",
+        axum::routing::post(move |axum::Json(payload): axum::Json<serde_json::Value>| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send(payload);
+                StatusCode::NO_CONTENT
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+
+    let (app, tmp) = common::test_app_with_flags("{}");
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("alerts", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    db.save_alerts(&project.id, &format!("http://{addr}/hook"), None, None, true)
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/alerts/test", project.id);
+
+    let response = app.oneshot(common::post_json(&uri, &json!({}))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let payload = rx.try_recv().expect("webhook should have received the test notification");
+    assert_eq!(payload["event"], "test");
+    assert_eq!(payload["project_id"], project.id);
+}
@@ -7,9 +7,11 @@ use http_body_util::BodyExt;
 use serde_json::Value;
 use tempfile::TempDir;
 
-use swe_compliance_server::auth::{issue_token, JwtSecret};
+use swe_compliance_server::auth::{issue_token, Claims, DEFAULT_TOKEN_TTL_SECS};
 use swe_compliance_server::config::ServerConfig;
 use swe_compliance_server::db::Db;
+use swe_compliance_server::flags::FeatureFlags;
+use swe_compliance_server::metrics::Metrics;
 use swe_compliance_server::middleware::ScanSemaphore;
 use swe_compliance_server::routes::{build_router, AppState};
 use swe_compliance_server::ws::WsBroadcaster;
@@ -25,12 +27,17 @@ pub fn test_app() -> (Router, TempDir) {
         host: "127.0.0.1".into(),
         port: 0,
         jwt_secret: TEST_JWT_SECRET.into(),
+        token_ttl_secs: DEFAULT_TOKEN_TTL_SECS,
         db_path: db_path.clone(),
         cors_origins: vec!["*".into()],
         rate_limit_per_min: 1000,
         max_concurrent_scans: 5,
         template_dir: None,
         ai_enabled: false,
+        public_read: false,
+        github: None,
+        flags_path: None,
+        admin_users: vec![],
     };
 
     let db = Db::open(&db_path).expect("failed to open test database");
@@ -40,6 +47,8 @@ pub fn test_app() -> (Router, TempDir) {
         config,
         ws_broadcaster: WsBroadcaster::new(),
         scan_semaphore: ScanSemaphore::new(5),
+        flags: FeatureFlags::default(),
+        metrics: Metrics::new(),
     };
 
     let app = build_router(state);
@@ -55,12 +64,17 @@ pub fn test_app_with_templates(template_dir: PathBuf) -> (Router, TempDir) {
         host: "127.0.0.1".into(),
         port: 0,
         jwt_secret: TEST_JWT_SECRET.into(),
+        token_ttl_secs: DEFAULT_TOKEN_TTL_SECS,
         db_path: db_path.clone(),
         cors_origins: vec!["*".into()],
         rate_limit_per_min: 1000,
         max_concurrent_scans: 5,
         template_dir: Some(template_dir),
         ai_enabled: false,
+        public_read: false,
+        github: None,
+        flags_path: None,
+        admin_users: vec![],
     };
 
     let db = Db::open(&db_path).expect("failed to open test database");
@@ -70,6 +84,123 @@ pub fn test_app_with_templates(template_dir: PathBuf) -> (Router, TempDir) {
         config,
         ws_broadcaster: WsBroadcaster::new(),
         scan_semaphore: ScanSemaphore::new(5),
+        flags: FeatureFlags::default(),
+        metrics: Metrics::new(),
+    };
+
+    let app = build_router(state);
+    (app, tmp)
+}
+
+/// Create a test app with anonymous public-read access enabled.
+pub fn test_app_public_read() -> (Router, TempDir) {
+    let tmp = TempDir::new().expect("failed to create temp dir");
+    let db_path = tmp.path().join("test.db");
+
+    let config = ServerConfig {
+        host: "127.0.0.1".into(),
+        port: 0,
+        jwt_secret: TEST_JWT_SECRET.into(),
+        token_ttl_secs: DEFAULT_TOKEN_TTL_SECS,
+        db_path: db_path.clone(),
+        cors_origins: vec!["*".into()],
+        rate_limit_per_min: 1000,
+        max_concurrent_scans: 5,
+        template_dir: None,
+        ai_enabled: false,
+        public_read: true,
+        github: None,
+        flags_path: None,
+        admin_users: vec![],
+    };
+
+    let db = Db::open(&db_path).expect("failed to open test database");
+
+    let state = AppState {
+        db,
+        config,
+        ws_broadcaster: WsBroadcaster::new(),
+        scan_semaphore: ScanSemaphore::new(5),
+        flags: FeatureFlags::default(),
+        metrics: Metrics::new(),
+    };
+
+    let app = build_router(state);
+    (app, tmp)
+}
+
+/// Create a test app whose feature flags are read from `flags_json`.
+/// `testuser` (the user behind [`test_token`]) is an admin.
+pub fn test_app_with_flags(flags_json: &str) -> (Router, TempDir) {
+    let tmp = TempDir::new().expect("failed to create temp dir");
+    let db_path = tmp.path().join("test.db");
+    let flags_path = tmp.path().join("flags.json");
+    std::fs::write(&flags_path, flags_json).expect("failed to write flags file");
+
+    let config = ServerConfig {
+        host: "127.0.0.1".into(),
+        port: 0,
+        jwt_secret: TEST_JWT_SECRET.into(),
+        token_ttl_secs: DEFAULT_TOKEN_TTL_SECS,
+        db_path: db_path.clone(),
+        cors_origins: vec!["*".into()],
+        rate_limit_per_min: 1000,
+        max_concurrent_scans: 5,
+        template_dir: None,
+        ai_enabled: false,
+        public_read: false,
+        github: None,
+        flags_path: Some(flags_path.clone()),
+        admin_users: vec!["testuser".into()],
+    };
+
+    let db = Db::open(&db_path).expect("failed to open test database");
+    let flags = FeatureFlags::load(Some(flags_path)).expect("failed to load test flags");
+
+    let state = AppState {
+        db,
+        config,
+        ws_broadcaster: WsBroadcaster::new(),
+        scan_semaphore: ScanSemaphore::new(5),
+        flags,
+        metrics: Metrics::new(),
+    };
+
+    let app = build_router(state);
+    (app, tmp)
+}
+
+/// Create a test app that admits `rate_limit_per_min` API requests per caller per minute.
+pub fn test_app_with_rate_limit(rate_limit_per_min: u32) -> (Router, TempDir) {
+    let tmp = TempDir::new().expect("failed to create temp dir");
+    let db_path = tmp.path().join("test.db");
+
+    let config = ServerConfig {
+        host: "127.0.0.1".into(),
+        port: 0,
+        jwt_secret: TEST_JWT_SECRET.into(),
+        token_ttl_secs: DEFAULT_TOKEN_TTL_SECS,
+        db_path: db_path.clone(),
+        cors_origins: vec!["*".into()],
+        rate_limit_per_min,
+        max_concurrent_scans: 5,
+        template_dir: None,
+        ai_enabled: false,
+        public_read: false,
+        github: None,
+        flags_path: None,
+        admin_users: vec![],
+    };
+
+    let db = Db::open(&db_path).expect("failed to open test database");
+
+    let state = AppState {
+        db,
+        config,
+        ws_broadcaster: WsBroadcaster::new(),
+        scan_semaphore: ScanSemaphore::new(5),
+        flags: FeatureFlags::default(),
+        metrics: Metrics::new(),
     };
 
     let app = build_router(state);
@@ -78,7 +209,24 @@ pub fn test_app_with_templates(template_dir: PathBuf) -> (Router, TempDir) {
 
 /// Issue a valid JWT token for testing.
 pub fn test_token() -> String {
-    issue_token(TEST_JWT_SECRET, "testuser").expect("failed to issue test token")
+    issue_token(TEST_JWT_SECRET, "testuser", DEFAULT_TOKEN_TTL_SECS)
+        .expect("failed to issue test token")
+}
+
+/// Sign a JWT for `testuser` whose `exp` is an hour in the past.
+pub fn expired_token() -> String {
+    let now = chrono::Utc::now();
+    let claims = Claims {
+        sub: "testuser".into(),
+        iat: (now - chrono::Duration::hours(2)).timestamp() as usize,
+        exp: (now - chrono::Duration::hours(1)).timestamp() as usize,
+    };
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes()),
+    )
+    .expect("failed to sign expired test token")
 }
 
 /// Build a GET request with auth.
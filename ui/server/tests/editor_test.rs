@@ -142,3 +142,24 @@ async fn test_save_srs_nonexistent_project() {
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn test_save_srs_empty_content_returns_field_error() {
+    let (app, tmp) = common::test_app();
+
+    let db = swe_compliance_server::db::Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("test", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+
+    let req = common::put_json(
+        &format!("/api/v1/projects/{}/srs", project.id),
+        &json!({"content": "  "}),
+    );
+    let response = app.oneshot(req).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = common::body_json(response).await;
+    assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+    assert_eq!(body["error"]["fields"][0]["field"], "content");
+}
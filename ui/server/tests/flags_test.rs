@@ -0,0 +1,131 @@
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::json;
+use tower::ServiceExt;
+
+/// Test that known flags are reported as off when no flags file is configured.
+#[tokio::test]
+async fn test_flags_default_off() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app.oneshot(common::get("/api/v1/flags")).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    assert_eq!(body["incremental_scan"], false);
+    assert_eq!(body["scan_diff"], false);
+}
+
+/// Test that flags from the flags file are exposed, including unknown experiments.
+#[tokio::test]
+async fn test_flags_from_file() {
+    let (app, _tmp) = common::test_app_with_flags(r#"{"scan_diff": true, "new_dashboard": true}"#);
+
+    let response = app.oneshot(common::get("/api/v1/flags")).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    assert_eq!(body["scan_diff"], true);
+    assert_eq!(body["incremental_scan"], false);
+    assert_eq!(body["new_dashboard"], true);
+}
+
+/// Test that an admin reload picks up changes to the flags file.
+#[tokio::test]
+async fn test_flags_reload() {
+    let (app, tmp) = common::test_app_with_flags(r#"{"incremental_scan": false}"#);
+    std::fs::write(tmp.path().join("flags.json"), r#"{"incremental_scan": true}"#).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/admin/flags/reload", &json!({})))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(common::body_json(response).await["incremental_scan"], true);
+
+    let response = app.oneshot(common::get("/api/v1/flags")).await.unwrap();
+    assert_eq!(common::body_json(response).await["incremental_scan"], true);
+}
+
+/// Test that a malformed flags file is rejected and the previous flags stay in effect.
+#[tokio::test]
+async fn test_flags_reload_invalid_keeps_previous() {
+    let (app, tmp) = common::test_app_with_flags(r#"{"scan_diff": true}"#);
+    std::fs::write(tmp.path().join("flags.json"), "not json").unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/admin/flags/reload", &json!({})))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app.oneshot(common::get("/api/v1/flags")).await.unwrap();
+    assert_eq!(common::body_json(response).await["scan_diff"], true);
+}
+
+/// Test that non-admin users cannot reload flags.
+#[tokio::test]
+async fn test_flags_reload_requires_admin() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(common::post_json("/api/v1/admin/flags/reload", &json!({})))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+/// Run a doc-engine scan with `incremental: true` and report whether the result cache was written.
+async fn incremental_scan_writes_cache(flags_json: &str) -> bool {
+    let (app, tmp) = common::test_app_with_flags(flags_json);
+
+    let project_body = json!({
+        "name": "incremental",
+        "root_path": tmp.path().to_str().unwrap(),
+        "scope": "Small",
+        "project_type": "OpenSource"
+    });
+    let response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/projects", &project_body))
+        .await
+        .unwrap();
+    let project_id = common::body_json(response).await["id"].as_str().unwrap().to_string();
+
+    let scan_body = json!({"project_id": project_id, "engine": "doc-engine", "incremental": true});
+    let response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/scans", &scan_body))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let scan_id = common::body_json(response).await["id"].as_str().unwrap().to_string();
+
+    // Each long-poll returns on the next progress message; keep polling until the scan ends
+    let mut status = json!("running");
+    for _ in 0..500 {
+        let response = app
+            .clone()
+            .oneshot(common::get(&format!("/api/v1/scans/{scan_id}/status?wait=5")))
+            .await
+            .unwrap();
+        status = common::body_json(response).await["status"].clone();
+        if status != "running" {
+            break;
+        }
+    }
+    assert_eq!(status, "completed");
+
+    tmp.path().join("target/doc-engine-cache").exists()
+}
+
+/// Test that an incremental scan request uses the result cache only when its flag is on.
+#[tokio::test]
+async fn test_incremental_scan_gated_by_flag() {
+    assert!(incremental_scan_writes_cache(r#"{"incremental_scan": true}"#).await);
+    assert!(!incremental_scan_writes_cache(r#"{"incremental_scan": false}"#).await);
+}
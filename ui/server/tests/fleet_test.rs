@@ -0,0 +1,131 @@
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::json;
+use swe_compliance_server::db::Db;
+use tower::ServiceExt;
+
+/// Helper to create a tagged project and complete one scan per `(engine, passed, failed)`.
+fn seed_project(
+    db: &Db,
+    tmp: &tempfile::TempDir,
+    name: &str,
+    tags: &[&str],
+    scans: &[(&str, u64, u64)],
+) -> String {
+    let project = db
+        .create_project(name, tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+    db.set_project_tags(&project.id, &tags).unwrap();
+    for (engine, passed, failed) in scans {
+        let report = json!({
+            "summary": {"total": passed + failed + 1, "passed": passed, "failed": failed, "skipped": 1}
+        })
+        .to_string();
+        let scan = db.create_scan(&project.id, engine, None).unwrap();
+        db.finish_scan(&scan.id, "completed", Some(&report)).unwrap();
+    }
+    project.id
+}
+
+/// Test that the fleet lists every project's latest scan, worst coverage first.
+#[tokio::test]
+async fn test_fleet_sorted_by_coverage_ascending() {
+    let (app, tmp) = common::test_app();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let healthy = seed_project(&db, &tmp, "healthy", &[], &[("doc-engine", 5, 5), ("doc-engine", 9, 1)]);
+    let failing = seed_project(&db, &tmp, "failing", &[], &[("doc-engine", 8, 2), ("doc-engine", 1, 3)]);
+    let unscanned = seed_project(&db, &tmp, "unscanned", &[], &[]);
+
+    let response = app.oneshot(common::get("/api/v1/fleet")).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 3);
+
+    assert_eq!(entries[0]["project_id"], failing.as_str());
+    assert_eq!(entries[0]["coverage"], 25.0);
+    assert_eq!(entries[0]["passed"], 1);
+    assert_eq!(entries[0]["failed"], 3);
+    assert_eq!(entries[0]["skipped"], 1);
+    assert_eq!(entries[0]["trend"], "down");
+    assert_eq!(entries[0]["history"], json!([80.0, 25.0]));
+
+    assert_eq!(entries[1]["project_id"], healthy.as_str());
+    assert_eq!(entries[1]["coverage"], 90.0);
+    assert_eq!(entries[1]["trend"], "up");
+
+    assert_eq!(entries[2]["project_id"], unscanned.as_str());
+    assert!(entries[2]["scan_id"].is_null());
+    assert!(entries[2]["coverage"].is_null());
+    assert!(entries[2]["trend"].is_null());
+    assert_eq!(entries[2]["history"], json!([]));
+}
+
+/// Test that `engine` scopes the latest scan and `tag` scopes the projects.
+#[tokio::test]
+async fn test_fleet_filters_by_engine_and_tag() {
+    let (app, tmp) = common::test_app();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let backend = seed_project(
+        &db,
+        &tmp,
+        "backend",
+        &["platform"],
+        &[("struct-engine", 2, 2), ("doc-engine", 10, 0)],
+    );
+    seed_project(&db, &tmp, "frontend", &["web"], &[("struct-engine", 1, 0)]);
+
+    let response = app
+        .clone()
+        .oneshot(common::get("/api/v1/fleet?engine=struct-engine&tag=platform"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["project_id"], backend.as_str());
+    assert_eq!(entries[0]["engine"], "struct-engine");
+    assert_eq!(entries[0]["coverage"], 50.0);
+    assert_eq!(entries[0]["tags"], json!(["platform"]));
+    assert!(entries[0]["trend"].is_null());
+    assert_eq!(entries[0]["history"], json!([50.0]));
+
+    let response = app
+        .oneshot(common::get("/api/v1/fleet?tag=missing"))
+        .await
+        .unwrap();
+    let body = common::body_json(response).await;
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+/// Test that an unknown engine is rejected.
+#[tokio::test]
+async fn test_fleet_rejects_unknown_engine() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(common::get("/api/v1/fleet?engine=lint-engine"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Test that deleted projects are left out of the fleet.
+#[tokio::test]
+async fn test_fleet_excludes_deleted_projects() {
+    let (app, tmp) = common::test_app();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let deleted = seed_project(&db, &tmp, "retired", &[], &[("doc-engine", 1, 1)]);
+    db.delete_project(&deleted).unwrap();
+
+    let response = app.oneshot(common::get("/api/v1/fleet")).await.unwrap();
+
+    let body = common::body_json(response).await;
+    assert!(body.as_array().unwrap().is_empty());
+}
@@ -0,0 +1,73 @@
+use swe_compliance_server::config::GitHubConfig;
+use swe_compliance_server::github::{
+    is_valid_commit_sha, is_valid_repo, report_url, status_for_scan,
+};
+
+fn github_config(public_url: Option<&str>) -> GitHubConfig {
+    GitHubConfig {
+        token: "test-token".into(),
+        api_url: "https://api.github.com".into(),
+        public_url: public_url.map(String::from),
+    }
+}
+
+/// Test repository reference validation.
+#[test]
+fn test_is_valid_repo() {
+    assert!(is_valid_repo("sweengineeringlabs/swe-compliance"));
+    assert!(is_valid_repo("owner/repo.name_2"));
+    assert!(!is_valid_repo("no-slash"));
+    assert!(!is_valid_repo("/repo"));
+    assert!(!is_valid_repo("owner/"));
+    assert!(!is_valid_repo("owner/repo/extra"));
+}
+
+/// Test commit SHA validation.
+#[test]
+fn test_is_valid_commit_sha() {
+    assert!(is_valid_commit_sha("abcdef0"));
+    assert!(is_valid_commit_sha("0123456789abcdef0123456789abcdef01234567"));
+    assert!(!is_valid_commit_sha("abc"));
+    assert!(!is_valid_commit_sha("ghijklm"));
+}
+
+/// Test a report with failures maps to a failure status.
+#[test]
+fn test_status_for_failed_scan() {
+    let report = r#"{"summary": {"total": 5, "passed": 3, "failed": 2, "skipped": 0}}"#;
+    let status = status_for_scan("doc-engine", Some(report), None);
+
+    assert_eq!(status.state, "failure");
+    assert_eq!(status.description, "3 passed, 2 failed, 0 skipped");
+    assert_eq!(status.context, "swe-compliance/doc-engine");
+}
+
+/// Test a clean report maps to a success status.
+#[test]
+fn test_status_for_passing_scan() {
+    let report = r#"{"summary": {"total": 4, "passed": 3, "failed": 0, "skipped": 1}}"#;
+    let status = status_for_scan("struct-engine", Some(report), Some("http://x".into()));
+
+    assert_eq!(status.state, "success");
+    assert_eq!(status.target_url.as_deref(), Some("http://x"));
+}
+
+/// Test a scan without a report maps to an error status.
+#[test]
+fn test_status_for_errored_scan() {
+    let status = status_for_scan("doc-engine", None, None);
+    assert_eq!(status.state, "error");
+
+    let status = status_for_scan("doc-engine", Some(r#"{"error": "boom"}"#), None);
+    assert_eq!(status.state, "error");
+}
+
+/// Test report links are built from the public URL.
+#[test]
+fn test_report_url() {
+    assert_eq!(
+        report_url(&github_config(Some("https://compliance.example.com/")), "scan-1").as_deref(),
+        Some("https://compliance.example.com/reports?scan=scan-1")
+    );
+    assert!(report_url(&github_config(None), "scan-1").is_none());
+}
@@ -39,6 +39,136 @@ async fn test_login_with_valid_credentials() {
     assert!(body["token"].is_string());
     assert!(!body["token"].as_str().unwrap().is_empty());
     assert_eq!(body["expires_in"], 86400);
+    assert_eq!(body["admin"], false);
+}
+
+/// Test that logging in as a configured admin user reports `admin: true`.
+#[tokio::test]
+async fn test_login_reports_admin_users() {
+    let (app, _tmp) = common::test_app_with_flags("{}");
+    let request_body = json!({
+        "username": "testuser",
+        "password": "testpass123"
+    });
+
+    let response = app
+        .oneshot(common::post_json_no_auth("/api/v1/auth/login", &request_body))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    assert_eq!(body["admin"], true);
+}
+
+/// Build a POST /api/v1/auth/refresh request carrying `token`.
+fn refresh_request(token: &str) -> axum::http::Request<axum::body::Body> {
+    axum::http::Request::builder()
+        .method(axum::http::Method::POST)
+        .uri("/api/v1/auth/refresh")
+        .header(axum::http::header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(axum::body::Body::empty())
+        .unwrap()
+}
+
+/// Test that a valid token is exchanged for a fresh, usable one.
+#[tokio::test]
+async fn test_refresh_with_valid_token() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .clone()
+        .oneshot(refresh_request(&common::test_token()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    let token = body["token"].as_str().unwrap().to_string();
+    assert!(!token.is_empty());
+    assert_eq!(body["expires_in"], 86400);
+    assert_eq!(body["admin"], false);
+
+    // The refreshed token authenticates protected routes.
+    let request = axum::http::Request::builder()
+        .method(axum::http::Method::GET)
+        .uri("/api/v1/projects")
+        .header(axum::http::header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Test that an expired token cannot be refreshed.
+#[tokio::test]
+async fn test_refresh_with_expired_token() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(refresh_request(&common::expired_token()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body = common::body_json(response).await;
+    assert!(body["error"]["message"].as_str().unwrap().contains("token expired"));
+}
+
+/// Test that a token with a tampered signature cannot be refreshed.
+#[tokio::test]
+async fn test_refresh_with_tampered_token() {
+    let (app, _tmp) = common::test_app();
+
+    // Splice another user's claims onto testuser's signature.
+    let genuine = common::test_token();
+    let other = swe_compliance_server::auth::issue_token(
+        common::TEST_JWT_SECRET,
+        "mallory",
+        swe_compliance_server::auth::DEFAULT_TOKEN_TTL_SECS,
+    )
+    .unwrap();
+    let genuine_parts: Vec<&str> = genuine.split('.').collect();
+    let other_parts: Vec<&str> = other.split('.').collect();
+    let token = format!("{}.{}.{}", genuine_parts[0], other_parts[1], genuine_parts[2]);
+
+    let response = app.oneshot(refresh_request(&token)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body = common::body_json(response).await;
+    assert!(body["error"]["message"].as_str().unwrap().contains("invalid token"));
+}
+
+/// Test that refresh requires a token.
+#[tokio::test]
+async fn test_refresh_without_token() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(common::post_json_no_auth("/api/v1/auth/refresh", &json!({})))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test that protected endpoints reject an expired token with 401.
+#[tokio::test]
+async fn test_protected_endpoint_with_expired_token() {
+    let (app, _tmp) = common::test_app();
+
+    let request = axum::http::Request::builder()
+        .method(axum::http::Method::GET)
+        .uri("/api/v1/projects")
+        .header(
+            axum::http::header::AUTHORIZATION,
+            format!("Bearer {}", common::expired_token()),
+        )
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
 
 /// Test that POST /api/v1/auth/login with empty username returns 400.
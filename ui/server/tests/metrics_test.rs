@@ -0,0 +1,63 @@
+mod common;
+
+use axum::http::{header, StatusCode};
+use serde_json::json;
+use tower::ServiceExt;
+
+/// Test that GET /metrics is public and reports scan and HTTP metrics after a scan runs.
+#[tokio::test]
+async fn test_metrics_after_scan() {
+    let (app, tmp) = common::test_app();
+
+    let project_body = json!({
+        "name": "metrics-project",
+        "root_path": tmp.path().to_str().unwrap()
+    });
+    let project_response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/projects", &project_body))
+        .await
+        .unwrap();
+    let project = common::body_json(project_response).await;
+    let project_id = project["id"].as_str().unwrap();
+
+    let scan_body = json!({ "project_id": project_id, "engine": "doc-engine" });
+    let scan_response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/scans", &scan_body))
+        .await
+        .unwrap();
+    assert_eq!(scan_response.status(), StatusCode::ACCEPTED);
+    let scan = common::body_json(scan_response).await;
+    let scan_id = scan["id"].as_str().unwrap();
+
+    // Long-poll until the scan leaves the running state
+    for _ in 0..30 {
+        let response = app
+            .clone()
+            .oneshot(common::get(&format!("/api/v1/scans/{scan_id}/status?wait=1")))
+            .await
+            .unwrap();
+        let status = common::body_json(response).await;
+        if status["status"] != "running" {
+            break;
+        }
+    }
+
+    let response = app
+        .oneshot(common::get_no_auth("/metrics"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let content_type = response.headers()[header::CONTENT_TYPE].to_str().unwrap().to_string();
+    assert!(content_type.starts_with("text/plain; version=0.0.4"), "{content_type}");
+
+    let body = common::body_string(response).await;
+    assert!(body.contains("swe_scans_started_total 1"));
+    assert!(body.contains("swe_scans_completed_total"));
+    assert!(body.contains("swe_scans_failed_total"));
+    assert!(body.contains("swe_scan_duration_seconds_count 1"));
+    assert!(body.contains("swe_scans_in_flight 0"));
+    assert!(body.contains("swe_http_requests_total{route=\"/api/v1/scans\",status=\"202\"} 1"));
+}
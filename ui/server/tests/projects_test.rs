@@ -227,7 +227,7 @@ async fn test_list_projects_returns_created_projects() {
     assert_eq!(res.status(), StatusCode::OK);
 
     let body = common::body_json(res).await;
-    let projects = body.as_array().unwrap();
+    let projects = body["items"].as_array().unwrap();
     assert_eq!(projects.len(), 2);
 
     // Projects are ordered by created_at DESC, so Beta should be first
@@ -244,8 +244,63 @@ async fn test_list_projects_when_empty() {
     assert_eq!(res.status(), StatusCode::OK);
 
     let body = common::body_json(res).await;
-    let projects = body.as_array().unwrap();
+    let projects = body["items"].as_array().unwrap();
     assert_eq!(projects.len(), 0);
+    assert_eq!(body["total"], 0);
+    assert_eq!(body["limit"], 50);
+    assert_eq!(body["offset"], 0);
+}
+
+#[tokio::test]
+async fn test_list_projects_paginates_with_total() {
+    let (app, tmp) = common::test_app();
+    let root = tmp.path().to_str().unwrap();
+
+    for name in ["One", "Two", "Three"] {
+        let req = common::post_json(
+            "/api/v1/projects",
+            &serde_json::json!({ "name": name, "root_path": root }),
+        );
+        app.clone().oneshot(req).await.unwrap();
+    }
+
+    let req = common::get("/api/v1/projects?limit=2&offset=2");
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = common::body_json(res).await;
+    let projects = body["items"].as_array().unwrap();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0]["name"], "One");
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["limit"], 2);
+    assert_eq!(body["offset"], 2);
+}
+
+#[tokio::test]
+async fn test_list_projects_caps_limit() {
+    let (app, _tmp) = common::test_app();
+
+    let req = common::get("/api/v1/projects?limit=10000");
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = common::body_json(res).await;
+    assert_eq!(body["limit"], 500);
+}
+
+#[tokio::test]
+async fn test_list_projects_rejects_invalid_page_params() {
+    let (app, _tmp) = common::test_app();
+
+    for query in ["offset=-1", "offset=abc", "limit=0", "limit=ten"] {
+        let req = common::get(&format!("/api/v1/projects?{query}"));
+        let res = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST, "query: {query}");
+
+        let body = common::body_json(res).await;
+        assert_eq!(body["error"]["code"], "VALIDATION_ERROR", "query: {query}");
+    }
 }
 
 #[tokio::test]
@@ -592,7 +647,7 @@ async fn test_list_projects_excludes_deleted() {
     assert_eq!(res.status(), StatusCode::OK);
 
     let body = common::body_json(res).await;
-    let projects = body.as_array().unwrap();
+    let projects = body["items"].as_array().unwrap();
     assert_eq!(projects.len(), 1);
     assert_eq!(projects[0]["name"], "Project One");
 }
@@ -698,7 +753,7 @@ async fn test_full_crud_workflow() {
     let res = app.clone().oneshot(req).await.unwrap();
     assert_eq!(res.status(), StatusCode::OK);
     let body = common::body_json(res).await;
-    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body["items"].as_array().unwrap().len(), 1);
 
     // Update
     let req = common::patch_json(
@@ -729,5 +784,114 @@ async fn test_full_crud_workflow() {
     let res = app.oneshot(req).await.unwrap();
     assert_eq!(res.status(), StatusCode::OK);
     let body = common::body_json(res).await;
-    assert_eq!(body.as_array().unwrap().len(), 0);
+    assert_eq!(body["items"].as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_create_project_reports_each_invalid_field() {
+    let (app, _tmp) = common::test_app();
+
+    let req = common::post_json(
+        "/api/v1/projects",
+        &serde_json::json!({
+            "name": " ",
+            "root_path": "../escape",
+            "scope": "Huge"
+        }),
+    );
+
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let body = common::body_json(res).await;
+    assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+    let fields = body["error"]["fields"].as_array().unwrap();
+    assert_eq!(fields.len(), 3);
+    assert_eq!(fields[0]["field"], "name");
+    assert_eq!(fields[1]["field"], "root_path");
+    assert_eq!(fields[2]["field"], "scope");
+    assert!(fields[2]["message"].as_str().unwrap().contains("invalid scope"));
+}
+
+#[tokio::test]
+async fn test_create_project_missing_field_returns_structured_error() {
+    let (app, _tmp) = common::test_app();
+
+    let req = common::post_json(
+        "/api/v1/projects",
+        &serde_json::json!({ "name": "no-root" }),
+    );
+
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let body = common::body_json(res).await;
+    assert_eq!(body["error"]["code"], "INVALID_BODY");
+    assert_eq!(body["error"]["fields"][0]["field"], "root_path");
+}
+
+#[tokio::test]
+async fn test_not_found_error_has_empty_fields() {
+    let (app, _tmp) = common::test_app();
+
+    let res = app
+        .oneshot(common::get("/api/v1/projects/nonexistent-id"))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let body = common::body_json(res).await;
+    assert_eq!(body["error"]["fields"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_project_tags_set_on_create_and_replaced_on_update() {
+    let (app, tmp) = common::test_app();
+    let root = tmp.path().to_str().unwrap();
+
+    let req = common::post_json(
+        "/api/v1/projects",
+        &serde_json::json!({
+            "name": "Tagged Project",
+            "root_path": root,
+            "tags": ["platform", " backend ", "platform"]
+        }),
+    );
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let body = common::body_json(res).await;
+    assert_eq!(body["tags"], serde_json::json!(["backend", "platform"]));
+    let project_id = body["id"].as_str().unwrap();
+
+    let req = common::patch_json(
+        &format!("/api/v1/projects/{}", project_id),
+        &serde_json::json!({ "tags": ["web"] }),
+    );
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let req = common::get(&format!("/api/v1/projects/{}", project_id));
+    let body = common::body_json(app.oneshot(req).await.unwrap()).await;
+    assert_eq!(body["tags"], serde_json::json!(["web"]));
+}
+
+#[tokio::test]
+async fn test_create_project_with_empty_tag() {
+    let (app, tmp) = common::test_app();
+    let root = tmp.path().to_str().unwrap();
+
+    let req = common::post_json(
+        "/api/v1/projects",
+        &serde_json::json!({
+            "name": "Bad Tags",
+            "root_path": root,
+            "tags": ["ok", "  "]
+        }),
+    );
+
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let body = common::body_json(res).await;
+    assert_eq!(body["error"]["fields"][0]["field"], "tags");
 }
@@ -0,0 +1,125 @@
+mod common;
+
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use serde_json::json;
+use tower::ServiceExt;
+
+/// Test that project listing is served without a token in public-read mode.
+#[tokio::test]
+async fn test_public_read_allows_anonymous_project_list() {
+    let (app, _tmp) = common::test_app_public_read();
+
+    let response = app
+        .oneshot(common::get_no_auth("/api/v1/projects"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    assert!(body["items"].is_array());
+}
+
+/// Test that project listing still requires a token when public-read is disabled.
+#[tokio::test]
+async fn test_public_read_disabled_rejects_anonymous() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(common::get_no_auth("/api/v1/projects"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test that anonymous callers can read project scan history in public-read mode.
+#[tokio::test]
+async fn test_public_read_allows_anonymous_scan_history() {
+    let (app, tmp) = common::test_app_public_read();
+
+    let project = common::body_json(
+        app.clone()
+            .oneshot(common::post_json(
+                "/api/v1/projects",
+                &json!({"name": "public", "root_path": tmp.path().to_str().unwrap()}),
+            ))
+            .await
+            .unwrap(),
+    )
+    .await;
+    let project_id = project["id"].as_str().unwrap();
+
+    let response = app
+        .oneshot(common::get_no_auth(&format!("/api/v1/projects/{project_id}/scans")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Test that mutations still require a token in public-read mode.
+#[tokio::test]
+async fn test_public_read_rejects_anonymous_mutation() {
+    let (app, tmp) = common::test_app_public_read();
+
+    let response = app
+        .oneshot(common::post_json_no_auth(
+            "/api/v1/projects",
+            &json!({"name": "anon", "root_path": tmp.path().to_str().unwrap()}),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test that deletes still require a token in public-read mode.
+#[tokio::test]
+async fn test_public_read_rejects_anonymous_delete() {
+    let (app, _tmp) = common::test_app_public_read();
+
+    let request = Request::builder()
+        .method(Method::DELETE)
+        .uri("/api/v1/projects/some-id")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test that AI and editor routes are never public.
+#[tokio::test]
+async fn test_public_read_rejects_anonymous_ai_and_srs() {
+    let (app, _tmp) = common::test_app_public_read();
+
+    let response = app
+        .clone()
+        .oneshot(common::get_no_auth("/api/v1/ai/status"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .oneshot(common::get_no_auth("/api/v1/projects/some-id/srs"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test that an invalid token is rejected even on public routes.
+#[tokio::test]
+async fn test_public_read_rejects_invalid_token() {
+    let (app, _tmp) = common::test_app_public_read();
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/api/v1/projects")
+        .header(header::AUTHORIZATION, "Bearer not-a-valid-token")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use std::net::SocketAddr;
+
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{header, Method, Request, StatusCode};
+use tower::ServiceExt;
+
+use swe_compliance_server::auth::{issue_token, DEFAULT_TOKEN_TTL_SECS};
+
+/// Build a GET request authenticated as `username`.
+fn get_as(uri: &str, username: &str) -> Request<Body> {
+    get_as_with_ttl(uri, username, DEFAULT_TOKEN_TTL_SECS)
+}
+
+/// Build a GET request with a token for `username` expiring in `ttl_secs`.
+fn get_as_with_ttl(uri: &str, username: &str, ttl_secs: u64) -> Request<Body> {
+    let token = issue_token(common::TEST_JWT_SECRET, username, ttl_secs).unwrap();
+    Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap()
+}
+
+/// Build an unauthenticated GET request arriving from `addr`.
+fn get_from(uri: &str, addr: &str) -> Request<Body> {
+    let mut request = common::get_no_auth(uri);
+    let addr: SocketAddr = addr.parse().unwrap();
+    request.extensions_mut().insert(ConnectInfo(addr));
+    request
+}
+
+/// Test that one user exhausting the limit gets 429 with Retry-After while another user is unaffected.
+#[tokio::test]
+async fn test_rate_limit_is_per_user() {
+    let (app, _tmp) = common::test_app_with_rate_limit(3);
+
+    for _ in 0..3 {
+        let response = app.clone().oneshot(get_as("/api/v1/projects", "alice")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app.clone().oneshot(get_as("/api/v1/projects", "alice")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response.headers()[header::RETRY_AFTER]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((1..=60).contains(&retry_after));
+    let body = common::body_json(response).await;
+    assert_eq!(body["error"]["code"], "RATE_LIMITED");
+    assert!(body["error"]["message"].as_str().unwrap().contains("max 3 requests"));
+
+    let response = app.clone().oneshot(get_as("/api/v1/projects", "bob")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Test that distinct tokens for the same user share that user's bucket.
+#[tokio::test]
+async fn test_rate_limit_keys_by_username_not_token() {
+    let (app, _tmp) = common::test_app_with_rate_limit(2);
+
+    for ttl_secs in [600, 1200] {
+        let response = app.clone().oneshot(get_as_with_ttl("/api/v1/projects", "alice", ttl_secs)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app.oneshot(get_as_with_ttl("/api/v1/projects", "alice", 1800)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+/// Test that unauthenticated callers are limited per client IP.
+#[tokio::test]
+async fn test_rate_limit_falls_back_to_client_ip() {
+    let (app, _tmp) = common::test_app_with_rate_limit(1);
+
+    // The limiter runs before authentication, so the first request is admitted and then rejected as 401
+    let response = app.clone().oneshot(get_from("/api/v1/projects", "10.0.0.1:5000")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = app.clone().oneshot(get_from("/api/v1/projects", "10.0.0.1:5001")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    let response = app.oneshot(get_from("/api/v1/projects", "10.0.0.2:5000")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
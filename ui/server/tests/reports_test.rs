@@ -215,6 +215,166 @@ async fn test_invalid_report_format() {
         .contains("unsupported format"));
 }
 
+/// Create a completed scan whose stored report is a real engine report.
+fn setup_engine_scan(tmp: &tempfile::TempDir, engine: &str, report: serde_json::Value) -> String {
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("export-project", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let scan = db.create_scan(&project.id, engine, None).unwrap();
+    db.finish_scan(&scan.id, "completed", Some(&report.to_string()))
+        .unwrap();
+    scan.id
+}
+
+fn failing_violation() -> serde_json::Value {
+    json!({"check_id": 1, "path": "Cargo.toml", "message": "Cargo.toml is missing", "severity": "error"})
+}
+
+/// Test HTML export re-renders a doc-engine report as a downloadable page.
+#[tokio::test]
+async fn test_html_report_format() {
+    let (app, tmp) = common::test_app();
+    let scan_id = setup_engine_scan(&tmp, "doc-engine", json!({
+        "standard": "ISO/IEC/IEEE 15289:2019",
+        "clause": "9.2",
+        "tool": "doc-engine",
+        "tool_version": "0.1.0",
+        "timestamp": "2024-01-01T00:00:00Z",
+        "project_root": "/tmp/project",
+        "results": [
+            {"id": 1, "category": "structure", "description": "docs/ exists",
+             "result": {"status": "fail", "violations": [failing_violation()]}}
+        ],
+        "summary": {"total": 1, "passed": 0, "failed": 1, "skipped": 0},
+        "project_type": "open_source",
+        "project_scope": "small"
+    }));
+
+    let response = app
+        .clone()
+        .oneshot(common::get(&format!("/api/v1/scans/{}/report?format=html", scan_id)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers();
+    assert!(headers
+        .get(axum::http::header::CONTENT_TYPE)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("text/html"));
+    assert!(headers
+        .get(axum::http::header::CONTENT_DISPOSITION)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .contains(&format!("doc-engine-{}.html", scan_id)));
+
+    let body = common::body_string(response).await;
+    assert!(body.contains("<h1>doc-engine scan results</h1>"));
+    assert!(body.contains("Cargo.toml is missing"));
+}
+
+/// Test JUnit export of a doc-engine report is XML with one test case per check.
+#[tokio::test]
+async fn test_junit_report_format_for_doc_engine() {
+    let (app, tmp) = common::test_app();
+    let scan_id = setup_engine_scan(&tmp, "doc-engine", json!({
+        "standard": "ISO/IEC/IEEE 15289:2019",
+        "clause": "9.2",
+        "tool": "doc-engine",
+        "tool_version": "0.1.0",
+        "timestamp": "2024-01-01T00:00:00Z",
+        "project_root": "/tmp/project",
+        "results": [
+            {"id": 1, "category": "structure", "description": "docs/ exists",
+             "result": {"status": "fail", "violations": [failing_violation()]}}
+        ],
+        "summary": {"total": 1, "passed": 0, "failed": 1, "skipped": 0},
+        "project_type": "open_source",
+        "project_scope": "small"
+    }));
+
+    let response = app
+        .clone()
+        .oneshot(common::get(&format!("/api/v1/scans/{}/report?format=junit", scan_id)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get(axum::http::header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "application/xml"
+    );
+
+    let body = common::body_string(response).await;
+    assert!(body.contains("<testsuite name=\"doc-engine\" tests=\"1\" failures=\"1\" skipped=\"0\">"));
+    assert!(body.contains("Cargo.toml is missing"));
+}
+
+/// Test SARIF export of a struct-engine report is JSON with a `runs` array.
+#[tokio::test]
+async fn test_sarif_report_format() {
+    let (app, tmp) = common::test_app();
+    let scan_id = setup_engine_scan(&tmp, "struct-engine", json!({
+        "results": [
+            {"id": 1, "category": "structure", "description": "Cargo.toml exists",
+             "result": {"status": "fail", "violations": [failing_violation()]}}
+        ],
+        "summary": {"total": 1, "passed": 0, "failed": 1, "skipped": 0},
+        "project_kind": "library"
+    }));
+
+    let response = app
+        .clone()
+        .oneshot(common::get(&format!("/api/v1/scans/{}/report?format=sarif", scan_id)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get(axum::http::header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "application/sarif+json"
+    );
+
+    let body = common::body_json(response).await;
+    let runs = body["runs"].as_array().expect("SARIF must have a runs array");
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0]["results"][0]["message"]["text"], "Cargo.toml is missing");
+}
+
+/// Test a format the scan's engine has no reporter for returns 400.
+#[tokio::test]
+async fn test_sarif_format_unavailable_for_doc_engine() {
+    let (app, tmp) = common::test_app();
+    let (_project_id, scan_id) = setup_completed_scan(&tmp).await;
+
+    let response = app
+        .clone()
+        .oneshot(common::get(&format!("/api/v1/scans/{}/report?format=sarif", scan_id)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = common::body_json(response).await;
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("not available for doc-engine reports"));
+}
+
 /// Test report for running scan returns 400.
 #[tokio::test]
 async fn test_report_for_running_scan() {
@@ -1,7 +1,9 @@
 mod common;
 
 use axum::http::StatusCode;
+use futures::StreamExt;
 use serde_json::json;
+use swe_compliance_server::db::Db;
 use tower::ServiceExt;
 
 /// Test creating a scan with valid data returns 202 with scan details.
@@ -217,8 +219,9 @@ async fn test_list_scans_for_project_empty() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let body = common::body_json(response).await;
-    assert!(body.is_array());
-    assert_eq!(body.as_array().unwrap().len(), 0);
+    assert!(body["items"].is_array());
+    assert_eq!(body["items"].as_array().unwrap().len(), 0);
+    assert_eq!(body["total"], 0);
 }
 
 /// Test listing scans for a project returns scans after creation.
@@ -265,10 +268,85 @@ async fn test_list_scans_for_project_with_scans() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let body = common::body_json(response).await;
-    assert!(body.is_array());
-    assert_eq!(body.as_array().unwrap().len(), 1);
-    assert_eq!(body[0]["project_id"], project_id);
-    assert_eq!(body[0]["engine"], "doc-engine");
+    assert_eq!(body["items"].as_array().unwrap().len(), 1);
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["items"][0]["project_id"], project_id);
+    assert_eq!(body["items"][0]["engine"], "doc-engine");
+}
+
+/// Test scan history pages with limit/offset and reports the unpaged total.
+#[tokio::test]
+async fn test_list_scans_for_project_paginates() {
+    let (app, tmp) = common::test_app();
+
+    let project_body = json!({
+        "name": "test-project",
+        "root_path": tmp.path().to_str().unwrap(),
+        "scope": "Small",
+        "project_type": "OpenSource"
+    });
+    let project_response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/projects", &project_body))
+        .await
+        .unwrap();
+    let project = common::body_json(project_response).await;
+    let project_id = project["id"].as_str().unwrap();
+
+    for _ in 0..3 {
+        let scan_body = json!({ "project_id": project_id, "engine": "doc-engine" });
+        app.clone()
+            .oneshot(common::post_json("/api/v1/scans", &scan_body))
+            .await
+            .unwrap();
+    }
+
+    let response = app
+        .clone()
+        .oneshot(common::get(&format!(
+            "/api/v1/projects/{}/scans?limit=2&offset=1",
+            project_id
+        )))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = common::body_json(response).await;
+    assert_eq!(body["items"].as_array().unwrap().len(), 2);
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["limit"], 2);
+    assert_eq!(body["offset"], 1);
+}
+
+/// Test a negative offset on scan history is a 400, not a 500.
+#[tokio::test]
+async fn test_list_scans_for_project_negative_offset() {
+    let (app, tmp) = common::test_app();
+
+    let project_body = json!({
+        "name": "test-project",
+        "root_path": tmp.path().to_str().unwrap()
+    });
+    let project_response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/projects", &project_body))
+        .await
+        .unwrap();
+    let project = common::body_json(project_response).await;
+    let project_id = project["id"].as_str().unwrap();
+
+    let response = app
+        .oneshot(common::get(&format!(
+            "/api/v1/projects/{}/scans?offset=-1",
+            project_id
+        )))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = common::body_json(response).await;
+    assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+    assert_eq!(body["error"]["fields"][0]["field"], "offset");
 }
 
 /// Test listing scans for nonexistent project returns 404.
@@ -376,3 +454,556 @@ async fn test_get_trends_for_nonexistent_project() {
     let body = common::body_json(response).await;
     assert!(body["error"]["message"].as_str().unwrap().contains("not found"));
 }
+
+/// Test creating a scan with GitHub commit metadata is accepted.
+#[tokio::test]
+async fn test_create_scan_with_commit_metadata() {
+    let (app, tmp) = common::test_app();
+
+    let project = common::body_json(
+        app.clone()
+            .oneshot(common::post_json(
+                "/api/v1/projects",
+                &json!({"name": "gh", "root_path": tmp.path().to_str().unwrap()}),
+            ))
+            .await
+            .unwrap(),
+    )
+    .await;
+
+    let scan_body = json!({
+        "project_id": project["id"],
+        "engine": "doc-engine",
+        "repo": "sweengineeringlabs/swe-compliance",
+        "commit_sha": "0123456789abcdef0123456789abcdef01234567"
+    });
+
+    let response = app
+        .oneshot(common::post_json("/api/v1/scans", &scan_body))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+/// Test that commit metadata must be complete and well-formed.
+#[tokio::test]
+async fn test_create_scan_with_invalid_commit_metadata() {
+    let (app, tmp) = common::test_app();
+
+    let project = common::body_json(
+        app.clone()
+            .oneshot(common::post_json(
+                "/api/v1/projects",
+                &json!({"name": "gh", "root_path": tmp.path().to_str().unwrap()}),
+            ))
+            .await
+            .unwrap(),
+    )
+    .await;
+
+    let cases = [
+        (json!({"repo": "owner/name"}), "must be provided together"),
+        (json!({"repo": "not-a-repo", "commit_sha": "abcdef0"}), "owner/name"),
+        (json!({"repo": "owner/name", "commit_sha": "xyz"}), "hex SHA"),
+    ];
+
+    for (extra, expected) in cases {
+        let mut scan_body = json!({"project_id": project["id"], "engine": "doc-engine"});
+        for (k, v) in extra.as_object().unwrap() {
+            scan_body[k] = v.clone();
+        }
+
+        let response = app
+            .clone()
+            .oneshot(common::post_json("/api/v1/scans", &scan_body))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = common::body_json(response).await;
+        assert!(
+            body["error"]["message"].as_str().unwrap().contains(expected),
+            "expected '{expected}' in {body}"
+        );
+    }
+}
+
+/// Test that every invalid field is reported at once in `error.fields`.
+#[tokio::test]
+async fn test_create_scan_reports_all_invalid_fields() {
+    let (app, _tmp) = common::test_app();
+
+    let scan_body = json!({
+        "project_id": "p1",
+        "engine": "bogus-engine",
+        "repo": "not-a-repo",
+        "commit_sha": "xyz",
+    });
+    let response = app
+        .oneshot(common::post_json("/api/v1/scans", &scan_body))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = common::body_json(response).await;
+    assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+    let fields: Vec<&str> = body["error"]["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["engine", "repo", "commit_sha"]);
+}
+
+/// Helper to seed a completed doc-engine scan whose report has the given check outcomes.
+fn seed_completed_scan(tmp: &tempfile::TempDir, results: serde_json::Value) -> String {
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("recheck", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let config = json!({"checks": null, "phase": null, "module": "core"}).to_string();
+    let scan = db.create_scan(&project.id, "doc-engine", Some(&config)).unwrap();
+    let report = json!({"summary": {}, "results": results});
+    db.finish_scan(&scan.id, "completed", Some(&report.to_string()))
+        .unwrap();
+    scan.id
+}
+
+/// Test that a recheck starts a partial scan of only the failed checks, linked to the original.
+#[tokio::test]
+async fn test_recheck_runs_failed_checks_only() {
+    let (app, tmp) = common::test_app();
+    let scan_id = seed_completed_scan(
+        &tmp,
+        json!([
+            {"id": 1, "category": "a", "description": "one", "result": {"status": "pass"}},
+            {"id": 4, "category": "a", "description": "four", "result": {"status": "fail", "violations": []}},
+            {"id": 7, "category": "a", "description": "seven", "result": {"status": "skip", "reason": "n/a"}},
+            {"id": 9, "category": "a", "description": "nine", "result": {"status": "fail", "violations": []}}
+        ]),
+    );
+
+    let response = app
+        .clone()
+        .oneshot(common::post_json(&format!("/api/v1/scans/{scan_id}/recheck"), &json!({})))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let body = common::body_json(response).await;
+    assert_ne!(body["id"], scan_id.as_str());
+    assert_eq!(body["recheck_of"], scan_id.as_str());
+    assert_eq!(body["partial"], true);
+    assert_eq!(body["engine"], "doc-engine");
+
+    // The stored scan keeps the link and the limited check set
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let recheck = db.get_scan(body["id"].as_str().unwrap()).unwrap();
+    let config: serde_json::Value = serde_json::from_str(recheck.config_json.as_deref().unwrap()).unwrap();
+    assert_eq!(config["checks"], "4,9");
+    assert_eq!(config["module"], "core");
+    assert_eq!(config["recheck_of"], scan_id.as_str());
+}
+
+/// Test that a scan without failures cannot be rechecked.
+#[tokio::test]
+async fn test_recheck_without_failures_returns_400() {
+    let (app, tmp) = common::test_app();
+    let scan_id = seed_completed_scan(
+        &tmp,
+        json!([{"id": 1, "category": "a", "description": "one", "result": {"status": "pass"}}]),
+    );
+
+    let response = app
+        .oneshot(common::post_json(&format!("/api/v1/scans/{scan_id}/recheck"), &json!({})))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Test that rechecking an unknown scan returns 404.
+#[tokio::test]
+async fn test_recheck_unknown_scan_returns_404() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(common::post_json("/api/v1/scans/does-not-exist/recheck", &json!({})))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// Test that the status of a finished scan is returned immediately with its check count.
+#[tokio::test]
+async fn test_scan_status_completed_returns_immediately() {
+    let (app, tmp) = common::test_app();
+    let scan_id = seed_completed_scan(
+        &tmp,
+        json!([
+            {"id": 1, "category": "a", "description": "one", "result": {"status": "pass"}},
+            {"id": 2, "category": "a", "description": "two", "result": {"status": "fail", "violations": []}}
+        ]),
+    );
+
+    let started = std::time::Instant::now();
+    let response = app
+        .oneshot(common::get(&format!("/api/v1/scans/{scan_id}/status?wait=30")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    let body = common::body_json(response).await;
+    assert_eq!(body["id"], scan_id.as_str());
+    assert_eq!(body["status"], "completed");
+    assert_eq!(body["completed_checks"], 2);
+    assert_eq!(body["total_checks"], 2);
+}
+
+/// Test that long-polling a launched scan returns once it has finished.
+#[tokio::test]
+async fn test_scan_status_waits_for_completion() {
+    let (app, tmp) = common::test_app();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("poll", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(common::post_json(
+            "/api/v1/scans",
+            &json!({"project_id": project.id, "engine": "struct-engine"}),
+        ))
+        .await
+        .unwrap();
+    let scan = common::body_json(response).await;
+    let scan_id = scan["id"].as_str().unwrap();
+
+    let mut status = String::new();
+    for _ in 0..5 {
+        let response = app
+            .clone()
+            .oneshot(common::get(&format!("/api/v1/scans/{scan_id}/status?wait=10")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = common::body_json(response).await;
+        status = body["status"].as_str().unwrap().to_string();
+        if status != "running" {
+            break;
+        }
+    }
+
+    assert_ne!(status, "running");
+}
+
+/// Test that a running scan with no live progress channel reports running without blocking.
+#[tokio::test]
+async fn test_scan_status_running_without_channel() {
+    let (app, tmp) = common::test_app();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("poll", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let scan = db.create_scan(&project.id, "doc-engine", None).unwrap();
+
+    let response = app
+        .oneshot(common::get(&format!("/api/v1/scans/{}/status?wait=1", scan.id)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_json(response).await;
+    assert_eq!(body["status"], "running");
+    assert_eq!(body["completed_checks"], 0);
+    assert!(body["total_checks"].is_null());
+}
+
+/// Test that polling an unknown scan returns 404.
+#[tokio::test]
+async fn test_scan_status_unknown_scan_returns_404() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(common::get("/api/v1/scans/does-not-exist/status"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// Helper to seed `count` completed scans and one running scan for a new project.
+fn seed_scan_history(tmp: &tempfile::TempDir, count: usize) -> String {
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("history", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let report = json!({
+        "summary": {"total": 4, "passed": 3, "failed": 1, "skipped": 0},
+        "results": [{"id": 1, "category": "a", "description": "one", "result": {"status": "pass"}}]
+    })
+    .to_string();
+    for _ in 0..count {
+        let scan = db.create_scan(&project.id, "doc-engine", None).unwrap();
+        db.finish_scan(&scan.id, "completed", Some(&report)).unwrap();
+    }
+    db.create_scan(&project.id, "doc-engine", None).unwrap();
+    project.id
+}
+
+/// Test that the NDJSON export streams one summary line per completed scan.
+#[tokio::test]
+async fn test_export_scans_ndjson_summary() {
+    let (app, tmp) = common::test_app();
+    let project_id = seed_scan_history(&tmp, 3);
+
+    let response = app
+        .oneshot(common::get(&format!("/api/v1/projects/{project_id}/scans/export.ndjson")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "application/x-ndjson"
+    );
+    let body = common::body_string(response).await;
+    let lines: Vec<serde_json::Value> = body
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0]["project_id"], project_id.as_str());
+    assert_eq!(lines[0]["summary"]["passed"], 3);
+    assert_eq!(lines[0]["coverage"], 75.0);
+    assert!(lines[0].get("report").is_none());
+}
+
+/// Test that `detail=full` includes each scan's report and pages cover every scan once.
+#[tokio::test]
+async fn test_export_scans_ndjson_full_detail_across_pages() {
+    let (app, tmp) = common::test_app();
+    let project_id = seed_scan_history(&tmp, 450);
+
+    let response = app
+        .oneshot(common::get(&format!(
+            "/api/v1/projects/{project_id}/scans/export.ndjson?detail=full"
+        )))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = common::body_string(response).await;
+    let lines: Vec<serde_json::Value> = body
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 450);
+    assert!(lines.iter().all(|l| l["report"]["results"].is_array()));
+
+    let ids: std::collections::HashSet<&str> =
+        lines.iter().map(|l| l["scan_id"].as_str().unwrap()).collect();
+    assert_eq!(ids.len(), 450);
+}
+
+/// Test that an unknown detail level is rejected with a field error.
+#[tokio::test]
+async fn test_export_scans_invalid_detail_returns_400() {
+    let (app, tmp) = common::test_app();
+    let project_id = seed_scan_history(&tmp, 1);
+
+    let response = app
+        .oneshot(common::get(&format!(
+            "/api/v1/projects/{project_id}/scans/export.ndjson?detail=everything"
+        )))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = common::body_json(response).await;
+    assert_eq!(body["error"]["fields"][0]["field"], "detail");
+}
+
+/// Test that exporting an unknown project returns 404.
+#[tokio::test]
+async fn test_export_scans_unknown_project_returns_404() {
+    let (app, _tmp) = common::test_app();
+
+    let response = app
+        .oneshot(common::get("/api/v1/projects/does-not-exist/scans/export.ndjson"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// Build a stored doc-engine report whose checks pass (`true`) or fail (`false`).
+fn doc_report_json(outcomes: &[(u8, bool)]) -> String {
+    let results: Vec<serde_json::Value> = outcomes
+        .iter()
+        .map(|&(id, passed)| {
+            let result = if passed {
+                json!({"status": "pass"})
+            } else {
+                json!({"status": "fail", "violations": [{
+                    "check_id": id, "path": null, "message": "broken", "severity": "error"
+                }]})
+            };
+            json!({"id": id, "category": "structure", "description": format!("check {id}"), "result": result})
+        })
+        .collect();
+    json!({
+        "standard": "ISO/IEC/IEEE 15289:2019",
+        "clause": "9.2",
+        "tool": "doc-engine",
+        "tool_version": "0.1.0",
+        "timestamp": "2026-01-01T00:00:00Z",
+        "project_root": "/tmp/project",
+        "results": results,
+        "summary": {"total": outcomes.len(), "passed": 0, "failed": 0, "skipped": 0},
+        "project_type": "open_source",
+        "project_scope": "small",
+    })
+    .to_string()
+}
+
+/// Test that the scan diff reports fixed and regressed checks.
+#[tokio::test]
+async fn test_diff_scans_reports_fixed_and_regressed() {
+    let (app, tmp) = common::test_app_with_flags(r#"{"scan_diff": true}"#);
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("diff", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let old = db.create_scan(&project.id, "doc-engine", None).unwrap();
+    db.finish_scan(&old.id, "completed", Some(&doc_report_json(&[(1, false), (2, true), (3, true)])))
+        .unwrap();
+    let new = db.create_scan(&project.id, "doc-engine", None).unwrap();
+    db.finish_scan(&new.id, "completed", Some(&doc_report_json(&[(1, true), (2, false), (3, true)])))
+        .unwrap();
+
+    let response = app
+        .oneshot(common::get(&format!("/api/v1/scans/{}/diff?against={}", new.id, old.id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = common::body_json(response).await;
+    assert_eq!(body["summary"]["fixed"], 1);
+    assert_eq!(body["summary"]["regressed"], 1);
+    assert_eq!(body["summary"]["unchanged"], 1);
+    assert_eq!(body["checks"][0]["status"], "fixed");
+    assert_eq!(body["checks"][1]["status"], "regressed");
+}
+
+/// Test that the scan diff is hidden while the `scan_diff` flag is off.
+#[tokio::test]
+async fn test_diff_scans_not_found_when_flag_off() {
+    let (app, tmp) = common::test_app();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("diff", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let old = db.create_scan(&project.id, "doc-engine", None).unwrap();
+    let new = db.create_scan(&project.id, "doc-engine", None).unwrap();
+
+    let response = app
+        .oneshot(common::get(&format!("/api/v1/scans/{}/diff?against={}", new.id, old.id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// Test that the scan diff requires an `against` scan.
+#[tokio::test]
+async fn test_diff_scans_requires_against() {
+    let (app, tmp) = common::test_app_with_flags(r#"{"scan_diff": true}"#);
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("diff", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let scan = db.create_scan(&project.id, "doc-engine", None).unwrap();
+
+    let response = app
+        .oneshot(common::get(&format!("/api/v1/scans/{}/diff", scan.id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = common::body_json(response).await;
+    assert_eq!(body["error"]["fields"][0]["field"], "against");
+}
+
+/// Test the progress WebSocket streams a per-check log for a doc-engine scan.
+#[tokio::test]
+async fn test_scan_progress_ws_streams_check_events() {
+    let (app, tmp) = common::test_app();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = app.clone();
+    tokio::spawn(async move { axum::serve(listener, server).await.unwrap() });
+
+    let project_body = json!({
+        "name": "ws-project",
+        "root_path": tmp.path().to_str().unwrap(),
+        "scope": "Small",
+        "project_type": "OpenSource"
+    });
+    let project_response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/projects", &project_body))
+        .await
+        .unwrap();
+    let project = common::body_json(project_response).await;
+
+    let scan_body = json!({
+        "project_id": project["id"],
+        "engine": "doc-engine",
+        "checks": "1,2,3"
+    });
+    let scan_response = app
+        .clone()
+        .oneshot(common::post_json("/api/v1/scans", &scan_body))
+        .await
+        .unwrap();
+    let scan = common::body_json(scan_response).await;
+
+    // The log is replayed on connect, so a scan that already finished still streams
+    let url = format!(
+        "ws://{addr}/api/v1/scans/{}/progress?token={}",
+        scan["id"].as_str().unwrap(),
+        common::test_token()
+    );
+    let (mut ws, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+    let mut frames = Vec::new();
+    let read = async {
+        while let Some(Ok(msg)) = ws.next().await {
+            if let tokio_tungstenite::tungstenite::Message::Text(text) = msg {
+                let frame: serde_json::Value = serde_json::from_str(&text).unwrap();
+                let done = frame["status"] == "completed";
+                frames.push(frame);
+                if done {
+                    break;
+                }
+            }
+        }
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(30), read).await.unwrap();
+
+    assert!(frames
+        .iter()
+        .any(|f| f["type"] == "check_started" && f["id"].is_u64() && f["name"].is_string()));
+    let finished = frames.iter().filter(|f| f["type"] == "check_finished").count();
+    assert_eq!(finished, 3);
+    // The counter message keeps its original fields
+    let last_progress = frames.iter().rev().find(|f| f["type"] == "progress").unwrap();
+    assert_eq!(last_progress["current"], 3);
+    assert_eq!(last_progress["total"], 3);
+    assert!(last_progress["check_description"].is_string());
+    assert_eq!(frames.last().unwrap()["status"], "completed");
+}
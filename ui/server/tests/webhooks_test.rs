@@ -0,0 +1,175 @@
+mod common;
+
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use serde_json::json;
+use tower::ServiceExt;
+
+use swe_compliance_server::db::Db;
+
+/// Test that a registered webhook receives the scan event after a scan completes.
+#[tokio::test]
+async fn test_webhook_delivered_after_scan_completes() {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<serde_json::Value>();
+    let receiver = axum::Router::new().route(
+        "/hook",
+        axum::routing::post(move |axum::Json(payload): axum::Json<serde_json::Value>| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send(payload);
+                StatusCode::NO_CONTENT
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+
+    let (app, tmp) = common::test_app_with_flags("{}");
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("hooks", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+
+    let uri = format!("/api/v1/projects/{}/webhooks", project.id);
+    let body = json!({"url": format!("http://{addr}/hook"), "events": ["scan_completed"]});
+    let response = app.clone().oneshot(common::post_json(&uri, &body)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let hook = common::body_json(response).await;
+    assert_eq!(hook["events"], json!(["scan_completed"]));
+    let hook_id = hook["id"].as_str().unwrap().to_string();
+
+    let scan_body = json!({"project_id": project.id, "engine": "doc-engine"});
+    let response = app
+        .oneshot(common::post_json("/api/v1/scans", &scan_body))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let scan = common::body_json(response).await;
+
+    let payload = tokio::time::timeout(Duration::from_secs(30), rx.recv())
+        .await
+        .expect("webhook should be delivered after the scan completes")
+        .unwrap();
+    assert_eq!(payload["event"], "scan_completed");
+    assert_eq!(payload["scan_id"], scan["id"]);
+    assert_eq!(payload["project_id"], project.id);
+    assert!(payload["summary"]["total"].is_number());
+
+    // The delivery is recorded once the receiver accepts it
+    let mut delivery = None;
+    for _ in 0..50 {
+        let rows = db.list_webhook_deliveries(&hook_id).unwrap();
+        if rows.first().is_some_and(|d| d.status == "delivered") {
+            delivery = rows.into_iter().next();
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let delivery = delivery.expect("delivery should be recorded as delivered");
+    assert_eq!(delivery.attempts, 1);
+    assert!(delivery.delivered_at.is_some());
+}
+
+/// Test that deliveries left pending by a previous run are resumed and only
+/// use the retries they have left.
+#[tokio::test]
+async fn test_pending_webhook_deliveries_resumed_on_startup() {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<serde_json::Value>();
+    let receiver = axum::Router::new().route(
+        "/hook",
+        axum::routing::post(move |axum::Json(payload): axum::Json<serde_json::Value>| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send(payload);
+                StatusCode::NO_CONTENT
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+
+    let tmp = tempfile::TempDir::new().unwrap();
+    let db = Db::open(&tmp.path().join("test.db")).unwrap();
+    let project = db
+        .create_project("hooks", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let scan = db.create_scan(&project.id, "doc-engine", None).unwrap();
+    let hook = db
+        .create_webhook(&project.id, &format!("http://{addr}/hook"), &["scan_failed".to_string()])
+        .unwrap();
+
+    // One delivery failed once before the "restart"; another already went out
+    let payload = json!({"event": "scan_failed", "scan_id": scan.id, "project_id": project.id, "summary": null});
+    let pending = db
+        .create_webhook_delivery(&hook.id, &scan.id, "scan_failed", &payload.to_string())
+        .unwrap();
+    db.record_webhook_attempt(&pending.id, "pending", Some("connection refused")).unwrap();
+    let delivered = db
+        .create_webhook_delivery(&hook.id, &scan.id, "scan_failed", &payload.to_string())
+        .unwrap();
+    db.record_webhook_attempt(&delivered.id, "delivered", None).unwrap();
+
+    assert_eq!(swe_compliance_server::webhooks::redeliver_pending(&db), 1);
+
+    let received = tokio::time::timeout(Duration::from_secs(10), rx.recv())
+        .await
+        .expect("pending delivery should be resumed")
+        .unwrap();
+    assert_eq!(received["scan_id"], scan.id);
+
+    let mut resumed = None;
+    for _ in 0..50 {
+        let rows = db.list_webhook_deliveries(&hook.id).unwrap();
+        if let Some(row) = rows.into_iter().find(|d| d.id == pending.id && d.status == "delivered") {
+            resumed = Some(row);
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let resumed = resumed.expect("resumed delivery should be recorded as delivered");
+    assert_eq!(resumed.attempts, 2);
+    assert!(rx.try_recv().is_err(), "delivered rows must not be sent again");
+    assert!(db.pending_webhook_deliveries().unwrap().is_empty());
+}
+
+/// Test that webhook registration validates the URL and event filter.
+#[tokio::test]
+async fn test_webhook_rejects_invalid_request() {
+    let (app, tmp) = common::test_app_with_flags("{}");
+    let project = Db::open(&tmp.path().join("test.db"))
+        .unwrap()
+        .create_project("hooks", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/webhooks", project.id);
+
+    let body = json!({"url": "ftp://hooks.example.com/x", "events": ["scan_exploded"]});
+    let response = app.oneshot(common::post_json(&uri, &body)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = common::body_json(response).await;
+    let fields: Vec<&str> = body["error"]["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["url", "events"]);
+}
+
+/// Test that non-admin users cannot register webhooks.
+#[tokio::test]
+async fn test_webhook_requires_admin() {
+    let (app, tmp) = common::test_app();
+    let project = Db::open(&tmp.path().join("test.db"))
+        .unwrap()
+        .create_project("hooks", tmp.path().to_str().unwrap(), "Small", "OpenSource")
+        .unwrap();
+    let uri = format!("/api/v1/projects/{}/webhooks", project.id);
+
+    let body = json!({"url": "https://hooks.example.com/x"});
+    let response = app.oneshot(common::post_json(&uri, &body)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}